@@ -0,0 +1,7 @@
+CREATE TABLE dataset_dependencies(
+    downstream_dataset_id VARCHAR(100) NOT NULL,
+    upstream_dataset_id VARCHAR(100) NOT NULL,
+    PRIMARY KEY (downstream_dataset_id, upstream_dataset_id)
+);
+
+CREATE INDEX dataset_dependencies_upstream_dataset_id_idx ON dataset_dependencies(upstream_dataset_id);
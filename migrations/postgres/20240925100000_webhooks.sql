@@ -0,0 +1,37 @@
+CREATE TABLE webhook_subscriptions(
+    id UUID PRIMARY KEY,
+    dataset_id VARCHAR(100),
+    account_id VARCHAR(100),
+    target_url TEXT NOT NULL,
+    label TEXT NOT NULL,
+    event_types TEXT NOT NULL,
+    secret TEXT NOT NULL,
+    enabled BOOLEAN NOT NULL,
+    created_at timestamptz NOT NULL,
+    CHECK ((dataset_id IS NULL) <> (account_id IS NULL))
+);
+
+CREATE INDEX webhook_subscriptions_dataset_id_idx ON webhook_subscriptions(dataset_id);
+CREATE INDEX webhook_subscriptions_account_id_idx ON webhook_subscriptions(account_id);
+
+/* ------------------------------ */
+
+CREATE TYPE webhook_delivery_status AS ENUM ('pending', 'delivered', 'dead_lettered');
+
+CREATE TABLE webhook_deliveries(
+    id UUID PRIMARY KEY,
+    subscription_id UUID NOT NULL,
+    event_id UUID NOT NULL,
+    event_type VARCHAR(50) NOT NULL,
+    payload TEXT NOT NULL,
+    status webhook_delivery_status NOT NULL,
+    attempts INTEGER NOT NULL,
+    next_attempt_at timestamptz,
+    last_attempt_at timestamptz,
+    last_response_code INTEGER,
+    last_error TEXT,
+    created_at timestamptz NOT NULL
+);
+
+CREATE INDEX webhook_deliveries_subscription_id_idx ON webhook_deliveries(subscription_id, created_at);
+CREATE INDEX webhook_deliveries_due_idx ON webhook_deliveries(next_attempt_at) WHERE status = 'pending';
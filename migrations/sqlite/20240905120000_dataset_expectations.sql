@@ -0,0 +1,16 @@
+CREATE TABLE dataset_expectations(
+    dataset_id VARCHAR(100) NOT NULL PRIMARY KEY,
+    expectations TEXT NOT NULL
+);
+
+CREATE TABLE dataset_expectation_checks(
+    id VARCHAR(36) NOT NULL PRIMARY KEY,
+    dataset_id VARCHAR(100) NOT NULL,
+    checked_at timestamptz NOT NULL,
+    num_records BIGINT NOT NULL,
+    num_quarantined BIGINT NOT NULL,
+    new_head VARCHAR(100),
+    outcomes TEXT NOT NULL
+);
+
+CREATE INDEX dataset_expectation_checks_dataset_id_idx ON dataset_expectation_checks(dataset_id, checked_at);
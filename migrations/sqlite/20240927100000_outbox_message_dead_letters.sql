@@ -0,0 +1,21 @@
+CREATE TABLE outbox_message_dead_letters(
+    producer_name VARCHAR(200) NOT NULL,
+    consumer_name VARCHAR(200) NOT NULL,
+    message_id BIGINT NOT NULL,
+    content_json JSONB NOT NULL,
+    attempts INTEGER NOT NULL,
+    last_error TEXT NOT NULL,
+    dead_lettered_at timestamptz NOT NULL,
+    PRIMARY KEY(producer_name, consumer_name, message_id)
+);
+
+CREATE INDEX outbox_message_dead_letters_message_id_idx ON outbox_message_dead_letters(message_id);
+
+CREATE TABLE outbox_consumer_retries(
+    producer_name VARCHAR(200) NOT NULL,
+    consumer_name VARCHAR(200) NOT NULL,
+    message_id BIGINT NOT NULL,
+    attempts INTEGER NOT NULL,
+    next_attempt_at timestamptz NOT NULL,
+    PRIMARY KEY(producer_name, consumer_name)
+);
@@ -0,0 +1,4 @@
+-- Marks that the dependencies of datasets created before the database was configured were persisted
+CREATE TABLE dataset_dependencies_bootstrap(
+    id INTEGER PRIMARY KEY CHECK (id = 1)
+);
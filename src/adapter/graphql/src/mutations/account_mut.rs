@@ -8,8 +8,9 @@
 // by the Apache License, Version 2.0.
 
 use kamu_accounts::Account;
+use kamu_webhooks::WebhookSubscriptionScope;
 
-use super::AccountFlowsMut;
+use super::{AccountFlowsMut, WebhooksMut};
 use crate::prelude::*;
 
 #[derive(Debug, Clone)]
@@ -28,4 +29,9 @@ impl AccountMut {
     async fn flows(&self) -> AccountFlowsMut {
         AccountFlowsMut::new(self.account.clone())
     }
+
+    /// Access to the mutable webhook subscriptions of this account
+    async fn webhooks(&self) -> WebhooksMut {
+        WebhooksMut::new(WebhookSubscriptionScope::Account(self.account.id.clone()))
+    }
 }
@@ -0,0 +1,194 @@
+// Copyright Kamu Data, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+use messaging_outbox::{
+    OutboxAdminService,
+    OutboxMessageID,
+    ReplayDeadLetterError,
+    SkipDeadLetterError,
+};
+
+use crate::prelude::*;
+use crate::AdminGuard;
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+pub struct AdminMut;
+
+#[Object]
+impl AdminMut {
+    /// Manual handling of messages that durable consumers failed to process
+    #[allow(clippy::unused_async)]
+    #[graphql(guard = "AdminGuard::new()")]
+    async fn outbox(&self) -> Result<AdminOutboxMut> {
+        Ok(AdminOutboxMut)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+pub struct AdminOutboxMut;
+
+#[Object]
+impl AdminOutboxMut {
+    /// Delivers a dead-lettered message to its consumer once again. The
+    /// message is removed from dead letters if the consumer succeeds.
+    async fn replay_dead_letter(
+        &self,
+        ctx: &Context<'_>,
+        producer_name: String,
+        consumer_name: String,
+        message_id: i64,
+    ) -> Result<ReplayDeadLetterResult> {
+        let admin_service = from_catalog::<dyn OutboxAdminService>(ctx).unwrap();
+
+        match admin_service
+            .replay_dead_letter(
+                &producer_name,
+                &consumer_name,
+                OutboxMessageID::new(message_id),
+            )
+            .await
+        {
+            Ok(()) => Ok(ReplayDeadLetterResult::Success(
+                ReplayDeadLetterResultSuccess { message_id },
+            )),
+            Err(ReplayDeadLetterError::NotFound(_)) => {
+                Ok(ReplayDeadLetterResult::NotFound(DeadLetterNotFound {
+                    producer_name,
+                    consumer_name,
+                    message_id,
+                }))
+            }
+            Err(ReplayDeadLetterError::ConsumerFailed(e)) => Ok(
+                ReplayDeadLetterResult::ConsumerFailed(ReplayDeadLetterResultConsumerFailed {
+                    message_id,
+                    reason: e.reason,
+                }),
+            ),
+            Err(ReplayDeadLetterError::Internal(e)) => Err(GqlError::Internal(e)),
+        }
+    }
+
+    /// Removes a message from dead letters without delivering it
+    async fn skip_dead_letter(
+        &self,
+        ctx: &Context<'_>,
+        producer_name: String,
+        consumer_name: String,
+        message_id: i64,
+    ) -> Result<SkipDeadLetterResult> {
+        let admin_service = from_catalog::<dyn OutboxAdminService>(ctx).unwrap();
+
+        match admin_service
+            .skip_dead_letter(
+                &producer_name,
+                &consumer_name,
+                OutboxMessageID::new(message_id),
+            )
+            .await
+        {
+            Ok(()) => Ok(SkipDeadLetterResult::Success(SkipDeadLetterResultSuccess {
+                message_id,
+            })),
+            Err(SkipDeadLetterError::NotFound(_)) => {
+                Ok(SkipDeadLetterResult::NotFound(DeadLetterNotFound {
+                    producer_name,
+                    consumer_name,
+                    message_id,
+                }))
+            }
+            Err(SkipDeadLetterError::Internal(e)) => Err(GqlError::Internal(e)),
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[derive(Interface, Debug, Clone)]
+#[graphql(field(name = "message", ty = "String"))]
+pub enum ReplayDeadLetterResult {
+    Success(ReplayDeadLetterResultSuccess),
+    NotFound(DeadLetterNotFound),
+    ConsumerFailed(ReplayDeadLetterResultConsumerFailed),
+}
+
+#[derive(SimpleObject, Debug, Clone)]
+#[graphql(complex)]
+pub struct ReplayDeadLetterResultSuccess {
+    pub message_id: i64,
+}
+
+#[ComplexObject]
+impl ReplayDeadLetterResultSuccess {
+    async fn message(&self) -> String {
+        "Success".to_string()
+    }
+}
+
+#[derive(SimpleObject, Debug, Clone)]
+#[graphql(complex)]
+pub struct ReplayDeadLetterResultConsumerFailed {
+    pub message_id: i64,
+    pub reason: String,
+}
+
+#[ComplexObject]
+impl ReplayDeadLetterResultConsumerFailed {
+    async fn message(&self) -> String {
+        format!(
+            "Consumer failed to process message {} again: {}",
+            self.message_id, self.reason
+        )
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[derive(Interface, Debug, Clone)]
+#[graphql(field(name = "message", ty = "String"))]
+pub enum SkipDeadLetterResult {
+    Success(SkipDeadLetterResultSuccess),
+    NotFound(DeadLetterNotFound),
+}
+
+#[derive(SimpleObject, Debug, Clone)]
+#[graphql(complex)]
+pub struct SkipDeadLetterResultSuccess {
+    pub message_id: i64,
+}
+
+#[ComplexObject]
+impl SkipDeadLetterResultSuccess {
+    async fn message(&self) -> String {
+        "Success".to_string()
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[derive(SimpleObject, Debug, Clone)]
+#[graphql(complex)]
+pub struct DeadLetterNotFound {
+    pub producer_name: String,
+    pub consumer_name: String,
+    pub message_id: i64,
+}
+
+#[ComplexObject]
+impl DeadLetterNotFound {
+    async fn message(&self) -> String {
+        format!(
+            "Message {} of producer '{}' is not dead-lettered for consumer '{}'",
+            self.message_id, self.producer_name, self.consumer_name
+        )
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -0,0 +1,101 @@
+// Copyright Kamu Data, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+use kamu_datasets::{DatasetExpectationService, SetDatasetExpectationsError};
+use opendatafabric as odf;
+
+use crate::prelude::*;
+use crate::utils;
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+pub struct DatasetExpectationsMut {
+    dataset_handle: odf::DatasetHandle,
+}
+
+#[Object]
+impl DatasetExpectationsMut {
+    #[graphql(skip)]
+    pub fn new(dataset_handle: odf::DatasetHandle) -> Self {
+        Self { dataset_handle }
+    }
+
+    /// Replaces all data quality expectations of the dataset
+    async fn set_expectations(
+        &self,
+        ctx: &Context<'_>,
+        expectations: Vec<DatasetExpectationInput>,
+    ) -> Result<SetDatasetExpectationsResult> {
+        utils::check_dataset_write_access(ctx, &self.dataset_handle).await?;
+
+        let expectation_service = from_catalog::<dyn DatasetExpectationService>(ctx).unwrap();
+
+        let expectations: Vec<kamu_datasets::DatasetExpectation> =
+            expectations.into_iter().map(Into::into).collect();
+
+        match expectation_service
+            .set_dataset_expectations(&self.dataset_handle.id, expectations.clone())
+            .await
+        {
+            Ok(()) => Ok(SetDatasetExpectationsResult::Success(
+                SetDatasetExpectationsResultSuccess {
+                    expectations: expectations.into_iter().map(Into::into).collect(),
+                },
+            )),
+            Err(SetDatasetExpectationsError::InvalidExpectation(e)) => {
+                Ok(SetDatasetExpectationsResult::InvalidExpectation(
+                    SetDatasetExpectationsResultInvalidExpectation {
+                        expectation_name: e.expectation_name,
+                        reason: e.reason,
+                    },
+                ))
+            }
+            Err(SetDatasetExpectationsError::Internal(e)) => Err(GqlError::Internal(e)),
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[derive(Interface, Debug, Clone)]
+#[graphql(field(name = "message", ty = "String"))]
+pub enum SetDatasetExpectationsResult {
+    Success(SetDatasetExpectationsResultSuccess),
+    InvalidExpectation(SetDatasetExpectationsResultInvalidExpectation),
+}
+
+#[derive(SimpleObject, Debug, Clone)]
+#[graphql(complex)]
+pub struct SetDatasetExpectationsResultSuccess {
+    pub expectations: Vec<DatasetExpectation>,
+}
+
+#[ComplexObject]
+impl SetDatasetExpectationsResultSuccess {
+    async fn message(&self) -> String {
+        "Success".to_string()
+    }
+}
+
+#[derive(SimpleObject, Debug, Clone)]
+#[graphql(complex)]
+pub struct SetDatasetExpectationsResultInvalidExpectation {
+    pub expectation_name: String,
+    pub reason: String,
+}
+
+#[ComplexObject]
+impl SetDatasetExpectationsResultInvalidExpectation {
+    async fn message(&self) -> String {
+        format!(
+            "Invalid expectation '{}': {}",
+            self.expectation_name, self.reason
+        )
+    }
+}
@@ -10,11 +10,18 @@
 use chrono::{DateTime, Utc};
 use domain::{DeleteDatasetError, RenameDatasetError};
 use kamu_core::{self as domain};
+use kamu_webhooks::WebhookSubscriptionScope;
 use opendatafabric as odf;
 
-use super::{DatasetEnvVarsMut, DatasetFlowsMut, DatasetMetadataMut};
+use super::{
+    DatasetEnvVarsMut,
+    DatasetExpectationsMut,
+    DatasetFlowsMut,
+    DatasetMetadataMut,
+    WebhooksMut,
+};
 use crate::prelude::*;
-use crate::utils::ensure_dataset_env_vars_enabled;
+use crate::utils::{self, ensure_dataset_env_vars_enabled};
 use crate::LoggedInGuard;
 
 #[derive(Debug, Clone)]
@@ -47,6 +54,20 @@ impl DatasetMut {
         Ok(DatasetEnvVarsMut::new(self.dataset_handle.clone()))
     }
 
+    /// Access to the mutable data quality expectations of this dataset
+    async fn expectations(&self) -> DatasetExpectationsMut {
+        DatasetExpectationsMut::new(self.dataset_handle.clone())
+    }
+
+    /// Access to the mutable webhook subscriptions of this dataset
+    async fn webhooks(&self, ctx: &Context<'_>) -> Result<WebhooksMut> {
+        utils::check_dataset_write_access(ctx, &self.dataset_handle).await?;
+
+        Ok(WebhooksMut::new(WebhookSubscriptionScope::Dataset(
+            self.dataset_handle.id.clone(),
+        )))
+    }
+
     /// Rename the dataset
     #[graphql(guard = "LoggedInGuard::new()")]
     async fn rename(&self, ctx: &Context<'_>, new_name: DatasetName) -> Result<RenameResult> {
@@ -181,6 +181,10 @@ impl DatasetFlowConfigsMut {
                     compaction_input.max_slice_size,
                     compaction_input.max_slice_records,
                     compaction_input.recursive,
+                    compaction_input
+                        .data_layout
+                        .map(Into::into)
+                        .unwrap_or_default(),
                 ) {
                     Ok(rule) => CompactionRule::Full(rule),
                     Err(e) => {
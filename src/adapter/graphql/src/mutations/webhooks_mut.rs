@@ -0,0 +1,236 @@
+// Copyright Kamu Data, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+use kamu_webhooks::{
+    CreateWebhookSubscriptionError,
+    DeleteWebhookSubscriptionError,
+    RedeliverWebhookDeliveryError,
+    UpdateWebhookSubscriptionError,
+    WebhookSubscriptionScope,
+    WebhookSubscriptionService,
+};
+use url::Url;
+
+use crate::prelude::*;
+use crate::queries::{get_scoped_subscription, WebhookDelivery, WebhookSubscription};
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Manages webhook subscriptions of a dataset or an account. Access is
+/// verified by the owning object.
+pub struct WebhooksMut {
+    scope: WebhookSubscriptionScope,
+}
+
+#[Object]
+impl WebhooksMut {
+    #[graphql(skip)]
+    pub fn new(scope: WebhookSubscriptionScope) -> Self {
+        Self { scope }
+    }
+
+    /// Registers a new subscription. The returned subscription contains the
+    /// secret used to sign the requests.
+    async fn create_subscription(
+        &self,
+        ctx: &Context<'_>,
+        target_url: String,
+        label: String,
+        event_types: Vec<WebhookEventType>,
+    ) -> Result<CreateWebhookSubscriptionResult> {
+        let target_url = match Url::parse(&target_url) {
+            Ok(target_url) => target_url,
+            Err(e) => {
+                return Ok(CreateWebhookSubscriptionResult::Invalid(
+                    CreateWebhookSubscriptionResultInvalid {
+                        reason: format!("Malformed target URL: {e}"),
+                    },
+                ))
+            }
+        };
+
+        let subscription_service = from_catalog::<dyn WebhookSubscriptionService>(ctx).unwrap();
+
+        match subscription_service
+            .create_subscription(
+                self.scope.clone(),
+                target_url,
+                label,
+                event_types.into_iter().map(Into::into).collect(),
+            )
+            .await
+        {
+            Ok(subscription) => Ok(CreateWebhookSubscriptionResult::Success(
+                CreateWebhookSubscriptionResultSuccess {
+                    subscription: WebhookSubscription::new(subscription),
+                },
+            )),
+            Err(CreateWebhookSubscriptionError::InvalidSubscription(e)) => {
+                Ok(CreateWebhookSubscriptionResult::Invalid(
+                    CreateWebhookSubscriptionResultInvalid { reason: e.reason },
+                ))
+            }
+            Err(CreateWebhookSubscriptionError::Internal(e)) => Err(GqlError::Internal(e)),
+        }
+    }
+
+    /// Access to a subscription by its ID, if it belongs to this scope
+    async fn subscription(
+        &self,
+        ctx: &Context<'_>,
+        id: WebhookSubscriptionID,
+    ) -> Result<Option<WebhookSubscriptionMut>> {
+        Ok(get_scoped_subscription(ctx, &self.scope, &id)
+            .await?
+            .map(|_| WebhookSubscriptionMut::new(id)))
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+pub struct WebhookSubscriptionMut {
+    subscription_id: WebhookSubscriptionID,
+}
+
+#[Object]
+impl WebhookSubscriptionMut {
+    #[graphql(skip)]
+    pub fn new(subscription_id: WebhookSubscriptionID) -> Self {
+        Self { subscription_id }
+    }
+
+    /// Pauses or resumes notifications
+    async fn set_enabled(
+        &self,
+        ctx: &Context<'_>,
+        enabled: bool,
+    ) -> Result<Option<WebhookSubscription>> {
+        let subscription_service = from_catalog::<dyn WebhookSubscriptionService>(ctx).unwrap();
+
+        match subscription_service
+            .set_subscription_enabled(&self.subscription_id, enabled)
+            .await
+        {
+            Ok(subscription) => Ok(Some(WebhookSubscription::new(subscription))),
+            Err(UpdateWebhookSubscriptionError::NotFound(_)) => Ok(None),
+            Err(UpdateWebhookSubscriptionError::Internal(e)) => Err(GqlError::Internal(e)),
+        }
+    }
+
+    /// Removes the subscription along with its delivery history
+    async fn delete(&self, ctx: &Context<'_>) -> Result<bool> {
+        let subscription_service = from_catalog::<dyn WebhookSubscriptionService>(ctx).unwrap();
+
+        match subscription_service
+            .delete_subscription(&self.subscription_id)
+            .await
+        {
+            Ok(()) => Ok(true),
+            Err(DeleteWebhookSubscriptionError::NotFound(_)) => Ok(false),
+            Err(DeleteWebhookSubscriptionError::Internal(e)) => Err(GqlError::Internal(e)),
+        }
+    }
+
+    /// Schedules another round of attempts for a delivery, typically one
+    /// that was dead-lettered
+    async fn redeliver(
+        &self,
+        ctx: &Context<'_>,
+        delivery_id: WebhookDeliveryID,
+    ) -> Result<RedeliverWebhookResult> {
+        let subscription_service = from_catalog::<dyn WebhookSubscriptionService>(ctx).unwrap();
+
+        match subscription_service
+            .redeliver(&self.subscription_id, &delivery_id)
+            .await
+        {
+            Ok(delivery) => Ok(RedeliverWebhookResult::Success(
+                RedeliverWebhookResultSuccess {
+                    delivery: WebhookDelivery::new(delivery),
+                },
+            )),
+            Err(RedeliverWebhookDeliveryError::NotFound(_)) => Ok(
+                RedeliverWebhookResult::NotFound(RedeliverWebhookResultNotFound { delivery_id }),
+            ),
+            Err(RedeliverWebhookDeliveryError::Internal(e)) => Err(GqlError::Internal(e)),
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[derive(Interface, Debug, Clone)]
+#[graphql(field(name = "message", ty = "String"))]
+pub enum CreateWebhookSubscriptionResult {
+    Success(CreateWebhookSubscriptionResultSuccess),
+    Invalid(CreateWebhookSubscriptionResultInvalid),
+}
+
+#[derive(SimpleObject, Debug, Clone)]
+#[graphql(complex)]
+pub struct CreateWebhookSubscriptionResultSuccess {
+    pub subscription: WebhookSubscription,
+}
+
+#[ComplexObject]
+impl CreateWebhookSubscriptionResultSuccess {
+    async fn message(&self) -> String {
+        "Success".to_string()
+    }
+}
+
+#[derive(SimpleObject, Debug, Clone)]
+#[graphql(complex)]
+pub struct CreateWebhookSubscriptionResultInvalid {
+    pub reason: String,
+}
+
+#[ComplexObject]
+impl CreateWebhookSubscriptionResultInvalid {
+    async fn message(&self) -> String {
+        format!("Invalid webhook subscription: {}", self.reason)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[derive(Interface, Debug, Clone)]
+#[graphql(field(name = "message", ty = "String"))]
+pub enum RedeliverWebhookResult {
+    Success(RedeliverWebhookResultSuccess),
+    NotFound(RedeliverWebhookResultNotFound),
+}
+
+#[derive(SimpleObject, Debug, Clone)]
+#[graphql(complex)]
+pub struct RedeliverWebhookResultSuccess {
+    pub delivery: WebhookDelivery,
+}
+
+#[ComplexObject]
+impl RedeliverWebhookResultSuccess {
+    async fn message(&self) -> String {
+        "Success".to_string()
+    }
+}
+
+#[derive(SimpleObject, Debug, Clone)]
+#[graphql(complex)]
+pub struct RedeliverWebhookResultNotFound {
+    pub delivery_id: WebhookDeliveryID,
+}
+
+#[ComplexObject]
+impl RedeliverWebhookResultNotFound {
+    async fn message(&self) -> String {
+        format!("Webhook delivery {} not found", self.delivery_id)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -13,11 +13,13 @@ use kamu_accounts::{
     DEFAULT_ACCOUNT_ID,
     DEFAULT_ACCOUNT_NAME,
 };
+use kamu_webhooks::WebhookSubscriptionScope;
 use opendatafabric as odf;
 use tokio::sync::OnceCell;
 
 use super::AccountFlows;
 use crate::prelude::*;
+use crate::queries::Webhooks;
 use crate::utils::check_logged_account_id_match;
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -186,6 +188,16 @@ impl Account {
             self.get_full_account_info(ctx).await?.clone(),
         )))
     }
+
+    /// Access to the webhook subscriptions of this account
+    #[allow(clippy::unused_async)]
+    async fn webhooks(&self, ctx: &Context<'_>) -> Result<Webhooks> {
+        check_logged_account_id_match(ctx, &self.account_id)?;
+
+        Ok(Webhooks::new(WebhookSubscriptionScope::Account(
+            self.account_id.clone().into(),
+        )))
+    }
 }
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -7,6 +7,7 @@
 // the Business Source License, use of this software will be governed
 // by the Apache License, Version 2.0.
 
+use super::AdminOutbox;
 use crate::prelude::*;
 use crate::AdminGuard;
 
@@ -19,4 +20,11 @@ impl Admin {
     async fn self_test(&self) -> Result<String> {
         Ok("OK".to_string())
     }
+
+    /// Durable message delivery between system components
+    #[allow(clippy::unused_async)]
+    #[graphql(guard = "AdminGuard::new()")]
+    async fn outbox(&self) -> Result<AdminOutbox> {
+        Ok(AdminOutbox)
+    }
 }
@@ -0,0 +1,158 @@
+// Copyright Kamu Data, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+use chrono::{DateTime, Utc};
+use database_common::DatabasePaginationOpts;
+use messaging_outbox::{OutboxAdminService, OutboxConsumerLag, OutboxMessageDeadLetter};
+
+use crate::prelude::*;
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Inspection of durable message delivery between system components
+pub struct AdminOutbox;
+
+#[Object]
+impl AdminOutbox {
+    const DEFAULT_PER_PAGE: i64 = 15;
+
+    /// Progress of every durable consumer relative to its producer
+    async fn consumers(&self, ctx: &Context<'_>) -> Result<Vec<OutboxConsumer>> {
+        let admin_service = from_catalog::<dyn OutboxAdminService>(ctx).unwrap();
+        let lags = admin_service.get_consumer_lags().await?;
+
+        Ok(lags.into_iter().map(OutboxConsumer::new).collect())
+    }
+
+    /// Messages that consumers failed to process after all retry attempts
+    async fn dead_letters(
+        &self,
+        ctx: &Context<'_>,
+        page: Option<i64>,
+        per_page: Option<i64>,
+    ) -> Result<OutboxDeadLetterConnection> {
+        let page = page.unwrap_or(0);
+        let per_page = per_page.unwrap_or(Self::DEFAULT_PER_PAGE);
+
+        let admin_service = from_catalog::<dyn OutboxAdminService>(ctx).unwrap();
+        let listing = admin_service
+            .list_dead_letters(&DatabasePaginationOpts {
+                offset: (page * per_page),
+                limit: per_page,
+            })
+            .await?;
+
+        let dead_letters: Vec<_> = listing
+            .list
+            .into_iter()
+            .map(OutboxDeadLetter::new)
+            .collect();
+
+        Ok(OutboxDeadLetterConnection::new(
+            dead_letters,
+            usize::try_from(page).unwrap(),
+            usize::try_from(per_page).unwrap(),
+            listing.total_count,
+        ))
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[derive(Clone, Debug)]
+pub struct OutboxConsumer {
+    lag: OutboxConsumerLag,
+}
+
+#[Object]
+impl OutboxConsumer {
+    #[graphql(skip)]
+    pub fn new(lag: OutboxConsumerLag) -> Self {
+        Self { lag }
+    }
+
+    async fn producer_name(&self) -> &String {
+        &self.lag.producer_name
+    }
+
+    async fn consumer_name(&self) -> &String {
+        &self.lag.consumer_name
+    }
+
+    /// ID of the last message processed or dead-lettered by the consumer
+    async fn last_consumed_message_id(&self) -> i64 {
+        self.lag.last_consumed_message_id.into_inner()
+    }
+
+    /// ID of the last message posted by the producer
+    async fn latest_produced_message_id(&self) -> i64 {
+        self.lag.latest_produced_message_id.into_inner()
+    }
+
+    /// Number of messages the consumer is behind the producer
+    async fn unconsumed_messages(&self) -> usize {
+        self.lag.unconsumed_messages
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[derive(Clone, Debug)]
+pub struct OutboxDeadLetter {
+    dead_letter: OutboxMessageDeadLetter,
+}
+
+#[Object]
+impl OutboxDeadLetter {
+    #[graphql(skip)]
+    pub fn new(dead_letter: OutboxMessageDeadLetter) -> Self {
+        Self { dead_letter }
+    }
+
+    async fn producer_name(&self) -> &String {
+        &self.dead_letter.producer_name
+    }
+
+    async fn consumer_name(&self) -> &String {
+        &self.dead_letter.consumer_name
+    }
+
+    async fn message_id(&self) -> i64 {
+        self.dead_letter.message_id.into_inner()
+    }
+
+    /// JSON content of the message
+    async fn content(&self) -> String {
+        self.dead_letter.content_json.to_string()
+    }
+
+    /// Number of attempts made so far, including manual replays
+    async fn attempts(&self) -> u32 {
+        self.dead_letter.attempts
+    }
+
+    /// Reason of the last failed attempt
+    async fn last_error(&self) -> &String {
+        &self.dead_letter.last_error
+    }
+
+    async fn dead_lettered_at(&self) -> DateTime<Utc> {
+        self.dead_letter.dead_lettered_at
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+page_based_connection!(
+    OutboxDeadLetter,
+    OutboxDeadLetterConnection,
+    OutboxDeadLetterEdge
+);
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -8,5 +8,7 @@
 // by the Apache License, Version 2.0.
 
 mod admin;
+mod admin_outbox;
 
 pub(crate) use admin::*;
+pub(crate) use admin_outbox::*;
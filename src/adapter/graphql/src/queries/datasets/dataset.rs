@@ -9,11 +9,12 @@
 
 use chrono::prelude::*;
 use kamu_core::{self as domain, MetadataChainExt, SearchSeedVisitor, ServerUrlConfig};
+use kamu_webhooks::WebhookSubscriptionScope;
 use opendatafabric as odf;
 
 use crate::prelude::*;
 use crate::queries::*;
-use crate::utils::ensure_dataset_env_vars_enabled;
+use crate::utils::{self, ensure_dataset_env_vars_enabled};
 
 #[derive(Debug, Clone)]
 pub struct Dataset {
@@ -103,6 +104,21 @@ impl Dataset {
         Ok(DatasetEnvVars::new(self.dataset_handle.clone()))
     }
 
+    /// Access to the data quality expectations of this dataset
+    async fn expectations(&self) -> DatasetExpectations {
+        DatasetExpectations::new(self.dataset_handle.clone())
+    }
+
+    /// Access to the webhook subscriptions of this dataset. Requires write
+    /// access, as subscriptions expose their signing secrets.
+    async fn webhooks(&self, ctx: &Context<'_>) -> Result<Webhooks> {
+        utils::check_dataset_write_access(ctx, &self.dataset_handle).await?;
+
+        Ok(Webhooks::new(WebhookSubscriptionScope::Dataset(
+            self.dataset_handle.id.clone(),
+        )))
+    }
+
     /// Access to the flow configurations of this dataset
     async fn flows(&self) -> DatasetFlows {
         DatasetFlows::new(self.dataset_handle.clone())
@@ -0,0 +1,87 @@
+// Copyright Kamu Data, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+use database_common::DatabasePaginationOpts;
+use kamu_datasets::DatasetExpectationService;
+use opendatafabric as odf;
+
+use crate::prelude::*;
+use crate::utils;
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+pub struct DatasetExpectations {
+    dataset_handle: odf::DatasetHandle,
+}
+
+#[Object]
+impl DatasetExpectations {
+    const DEFAULT_PER_PAGE: i64 = 15;
+
+    #[graphql(skip)]
+    pub fn new(dataset_handle: odf::DatasetHandle) -> Self {
+        Self { dataset_handle }
+    }
+
+    /// Data quality expectations evaluated against every new slice of data
+    async fn list(&self, ctx: &Context<'_>) -> Result<Vec<DatasetExpectation>> {
+        utils::check_dataset_read_access(ctx, &self.dataset_handle).await?;
+
+        let expectation_service = from_catalog::<dyn DatasetExpectationService>(ctx).unwrap();
+        let expectations = expectation_service
+            .get_dataset_expectations(&self.dataset_handle.id)
+            .await
+            .int_err()?;
+
+        Ok(expectations.into_iter().map(Into::into).collect())
+    }
+
+    /// Results of evaluating the expectations during past ingests, most
+    /// recent first
+    async fn checks(
+        &self,
+        ctx: &Context<'_>,
+        page: Option<i64>,
+        per_page: Option<i64>,
+    ) -> Result<DatasetExpectationsCheckConnection> {
+        utils::check_dataset_read_access(ctx, &self.dataset_handle).await?;
+
+        let page = page.unwrap_or(0);
+        let per_page = per_page.unwrap_or(Self::DEFAULT_PER_PAGE);
+
+        let expectation_service = from_catalog::<dyn DatasetExpectationService>(ctx).unwrap();
+        let listing = expectation_service
+            .get_expectations_checks_by_dataset_id(
+                &self.dataset_handle.id,
+                Some(DatabasePaginationOpts {
+                    offset: (page * per_page),
+                    limit: per_page,
+                }),
+            )
+            .await
+            .int_err()?;
+
+        let checks: Vec<_> = listing.list.into_iter().map(Into::into).collect();
+
+        Ok(DatasetExpectationsCheckConnection::new(
+            checks,
+            usize::try_from(page).unwrap(),
+            usize::try_from(per_page).unwrap(),
+            listing.total_count,
+        ))
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+page_based_connection!(
+    DatasetExpectationsCheck,
+    DatasetExpectationsCheckConnection,
+    DatasetExpectationsCheckEdge
+);
@@ -46,16 +46,9 @@ impl FlowStartCondition {
 
                 // For each dataset trigger, add accumulated changes since trigger first fired
                 for trigger in matching_triggers {
-                    if let fs::FlowTrigger::InputDatasetFlow(dataset_trigger) = trigger
-                        && let fs::FlowResult::DatasetUpdate(dataset_update) =
-                            &dataset_trigger.flow_result
-                        && let fs::FlowResultDatasetUpdate::Changed(update_result) = dataset_update
-                    {
+                    if let Some((dataset_id, old_head)) = trigger.input_dataset_change() {
                         total_increment += dataset_changes_service
-                            .get_increment_since(
-                                &dataset_trigger.dataset_id,
-                                update_result.old_head.as_ref(),
-                            )
+                            .get_increment_since(dataset_id, old_head)
                             .await
                             .int_err()?;
                     }
@@ -8,7 +8,7 @@
 // by the Apache License, Version 2.0.
 
 use kamu_core::DatasetRepository;
-use kamu_flow_system as fs;
+use {kamu_flow_system as fs, opendatafabric as odf};
 
 use crate::prelude::*;
 use crate::queries::{Account, Dataset};
@@ -21,6 +21,7 @@ pub(crate) enum FlowTrigger {
     AutoPolling(FlowTriggerAutoPolling),
     Push(FlowTriggerPush),
     InputDatasetFlow(FlowTriggerInputDatasetFlow),
+    InputDatasetUpdate(FlowTriggerInputDatasetUpdate),
 }
 
 impl FlowTrigger {
@@ -33,22 +34,34 @@ impl FlowTrigger {
             fs::FlowTrigger::AutoPolling(auto_polling) => Self::AutoPolling(auto_polling.into()),
             fs::FlowTrigger::Push(push) => Self::Push(push.into()),
             fs::FlowTrigger::InputDatasetFlow(input) => {
-                let dataset_repository = from_catalog::<dyn DatasetRepository>(ctx).unwrap();
-                let hdl = dataset_repository
-                    .resolve_dataset_ref(&input.dataset_id.as_local_ref())
-                    .await
-                    .int_err()?;
-                let account = Account::from_dataset_alias(ctx, &hdl.alias)
-                    .await?
-                    .expect("Account must exist");
+                let dataset = Self::resolve_dataset(&input.dataset_id, ctx).await?;
                 Self::InputDatasetFlow(FlowTriggerInputDatasetFlow::new(
-                    Dataset::new(account, hdl),
+                    dataset,
                     input.flow_type.into(),
                     input.flow_id.into(),
                 ))
             }
+            fs::FlowTrigger::InputDatasetUpdate(input) => {
+                let dataset = Self::resolve_dataset(&input.dataset_id, ctx).await?;
+                Self::InputDatasetUpdate(FlowTriggerInputDatasetUpdate { dataset })
+            }
         })
     }
+
+    async fn resolve_dataset(
+        dataset_id: &odf::DatasetID,
+        ctx: &Context<'_>,
+    ) -> Result<Dataset, InternalError> {
+        let dataset_repository = from_catalog::<dyn DatasetRepository>(ctx).unwrap();
+        let hdl = dataset_repository
+            .resolve_dataset_ref(&dataset_id.as_local_ref())
+            .await
+            .int_err()?;
+        let account = Account::from_dataset_alias(ctx, &hdl.alias)
+            .await?
+            .expect("Account must exist");
+        Ok(Dataset::new(account, hdl))
+    }
 }
 
 #[derive(SimpleObject)]
@@ -95,4 +108,9 @@ impl FlowTriggerInputDatasetFlow {
     }
 }
 
+#[derive(SimpleObject)]
+pub(crate) struct FlowTriggerInputDatasetUpdate {
+    dataset: Dataset,
+}
+
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
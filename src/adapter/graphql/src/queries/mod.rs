@@ -16,6 +16,7 @@ mod datasets;
 mod flows;
 mod search;
 mod tasks;
+mod webhooks;
 
 pub(crate) use access_tokens::*;
 pub(crate) use accounts::*;
@@ -26,3 +27,4 @@ pub(crate) use datasets::*;
 pub(crate) use flows::*;
 pub(crate) use search::*;
 pub(crate) use tasks::*;
+pub(crate) use webhooks::*;
@@ -0,0 +1,76 @@
+// Copyright Kamu Data, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+use chrono::{DateTime, Utc};
+
+use crate::prelude::*;
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[derive(Clone, Debug)]
+pub struct WebhookDelivery {
+    delivery: kamu_webhooks::WebhookDelivery,
+}
+
+#[Object]
+impl WebhookDelivery {
+    #[graphql(skip)]
+    pub fn new(delivery: kamu_webhooks::WebhookDelivery) -> Self {
+        Self { delivery }
+    }
+
+    /// Unique identifier of the delivery
+    async fn id(&self) -> WebhookDeliveryID {
+        self.delivery.id.into()
+    }
+
+    /// Type of the event being delivered
+    async fn event_type(&self) -> WebhookEventType {
+        self.delivery.event_type.into()
+    }
+
+    /// JSON body sent to the subscriber
+    async fn payload(&self) -> String {
+        self.delivery.payload.to_string()
+    }
+
+    async fn status(&self) -> WebhookDeliveryStatus {
+        self.delivery.status.into()
+    }
+
+    /// Number of attempts made so far
+    async fn attempts(&self) -> u32 {
+        self.delivery.attempts
+    }
+
+    /// Time of the next scheduled attempt, if any
+    async fn next_attempt_at(&self) -> Option<DateTime<Utc>> {
+        self.delivery.next_attempt_at
+    }
+
+    async fn last_attempt_at(&self) -> Option<DateTime<Utc>> {
+        self.delivery.last_attempt_at
+    }
+
+    /// HTTP status code returned by the subscriber on the last attempt
+    async fn last_response_code(&self) -> Option<u16> {
+        self.delivery.last_response_code
+    }
+
+    /// Reason of the last failed attempt
+    async fn last_error(&self) -> Option<&String> {
+        self.delivery.last_error.as_ref()
+    }
+
+    async fn created_at(&self) -> DateTime<Utc> {
+        self.delivery.created_at
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -0,0 +1,119 @@
+// Copyright Kamu Data, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+use chrono::{DateTime, Utc};
+use database_common::DatabasePaginationOpts;
+use kamu_webhooks::{WebhookSubscriptionService, WebhooksConfig};
+
+use super::WebhookDelivery;
+use crate::prelude::*;
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[derive(Clone, Debug)]
+pub struct WebhookSubscription {
+    subscription: kamu_webhooks::WebhookSubscription,
+}
+
+#[Object]
+impl WebhookSubscription {
+    const DEFAULT_PER_PAGE: i64 = 15;
+
+    #[graphql(skip)]
+    pub fn new(subscription: kamu_webhooks::WebhookSubscription) -> Self {
+        Self { subscription }
+    }
+
+    /// Unique identifier of the subscription
+    async fn id(&self) -> WebhookSubscriptionID {
+        self.subscription.id.into()
+    }
+
+    /// URL that receives the notifications
+    async fn target_url(&self) -> String {
+        self.subscription.target_url.to_string()
+    }
+
+    /// Human-readable description of the subscription
+    async fn label(&self) -> &String {
+        &self.subscription.label
+    }
+
+    /// Types of events the subscriber is notified about
+    async fn event_types(&self) -> Vec<WebhookEventType> {
+        self.subscription
+            .event_types
+            .iter()
+            .copied()
+            .map(Into::into)
+            .collect()
+    }
+
+    /// Secret used to sign the requests, allowing the subscriber to verify
+    /// their origin
+    async fn secret(&self, ctx: &Context<'_>) -> Result<String> {
+        let config = from_catalog::<WebhooksConfig>(ctx).unwrap();
+        let secret = self
+            .subscription
+            .get_exposed_secret(config.secret_decryption_keys())
+            .int_err()?;
+        Ok(secret)
+    }
+
+    /// Whether notifications are currently sent
+    async fn enabled(&self) -> bool {
+        self.subscription.enabled
+    }
+
+    async fn created_at(&self) -> DateTime<Utc> {
+        self.subscription.created_at
+    }
+
+    /// History of deliveries of this subscription, newest first
+    async fn deliveries(
+        &self,
+        ctx: &Context<'_>,
+        status: Option<WebhookDeliveryStatus>,
+        page: Option<i64>,
+        per_page: Option<i64>,
+    ) -> Result<WebhookDeliveryConnection> {
+        let page = page.unwrap_or(0);
+        let per_page = per_page.unwrap_or(Self::DEFAULT_PER_PAGE);
+
+        let subscription_service = from_catalog::<dyn WebhookSubscriptionService>(ctx).unwrap();
+        let listing = subscription_service
+            .list_deliveries(
+                &self.subscription.id,
+                status.map(Into::into),
+                Some(DatabasePaginationOpts {
+                    offset: (page * per_page),
+                    limit: per_page,
+                }),
+            )
+            .await
+            .int_err()?;
+
+        let deliveries: Vec<_> = listing.list.into_iter().map(WebhookDelivery::new).collect();
+
+        Ok(WebhookDeliveryConnection::new(
+            deliveries,
+            usize::try_from(page).unwrap(),
+            usize::try_from(per_page).unwrap(),
+            listing.total_count,
+        ))
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+page_based_connection!(
+    WebhookDelivery,
+    WebhookDeliveryConnection,
+    WebhookDeliveryEdge
+);
@@ -0,0 +1,78 @@
+// Copyright Kamu Data, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+use kamu_webhooks::{
+    GetWebhookSubscriptionError,
+    WebhookSubscriptionScope,
+    WebhookSubscriptionService,
+};
+
+use super::WebhookSubscription;
+use crate::prelude::*;
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Webhook subscriptions of a dataset or an account. Access is verified by
+/// the owning object.
+pub struct Webhooks {
+    scope: WebhookSubscriptionScope,
+}
+
+#[Object]
+impl Webhooks {
+    #[graphql(skip)]
+    pub fn new(scope: WebhookSubscriptionScope) -> Self {
+        Self { scope }
+    }
+
+    /// Lists all subscriptions, oldest first
+    async fn subscriptions(&self, ctx: &Context<'_>) -> Result<Vec<WebhookSubscription>> {
+        let subscription_service = from_catalog::<dyn WebhookSubscriptionService>(ctx).unwrap();
+
+        let subscriptions = subscription_service
+            .list_subscriptions(&self.scope)
+            .await
+            .int_err()?;
+
+        Ok(subscriptions
+            .into_iter()
+            .map(WebhookSubscription::new)
+            .collect())
+    }
+
+    /// Returns a subscription by its ID, if it belongs to this scope
+    async fn subscription(
+        &self,
+        ctx: &Context<'_>,
+        id: WebhookSubscriptionID,
+    ) -> Result<Option<WebhookSubscription>> {
+        Ok(get_scoped_subscription(ctx, &self.scope, &id)
+            .await?
+            .map(WebhookSubscription::new))
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Subscriptions of other scopes are reported as missing
+pub(crate) async fn get_scoped_subscription(
+    ctx: &Context<'_>,
+    scope: &WebhookSubscriptionScope,
+    id: &WebhookSubscriptionID,
+) -> Result<Option<kamu_webhooks::WebhookSubscription>> {
+    let subscription_service = from_catalog::<dyn WebhookSubscriptionService>(ctx).unwrap();
+
+    match subscription_service.get_subscription(id).await {
+        Ok(subscription) if subscription.scope == *scope => Ok(Some(subscription)),
+        Ok(_) | Err(GetWebhookSubscriptionError::NotFound(_)) => Ok(None),
+        Err(GetWebhookSubscriptionError::Internal(e)) => Err(GqlError::Internal(e)),
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -0,0 +1,249 @@
+// Copyright Kamu Data, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+use chrono::{DateTime, Utc};
+use kamu_datasets as domain;
+
+use crate::prelude::*;
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[derive(SimpleObject, Debug, Clone, PartialEq)]
+pub struct DatasetExpectation {
+    /// Unique name of the expectation within the dataset
+    pub name: String,
+    pub rule: ExpectationRule,
+    pub policy: ExpectationPolicy,
+}
+
+impl From<domain::DatasetExpectation> for DatasetExpectation {
+    fn from(v: domain::DatasetExpectation) -> Self {
+        Self {
+            name: v.name,
+            rule: v.rule.into(),
+            policy: v.policy.into(),
+        }
+    }
+}
+
+#[derive(InputObject, Debug)]
+pub struct DatasetExpectationInput {
+    pub name: String,
+    pub rule: ExpectationRuleInput,
+    pub policy: ExpectationPolicy,
+}
+
+impl From<DatasetExpectationInput> for domain::DatasetExpectation {
+    fn from(v: DatasetExpectationInput) -> Self {
+        Self {
+            name: v.name,
+            rule: v.rule.into(),
+            policy: v.policy.into(),
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[derive(Union, Debug, Clone, PartialEq)]
+pub enum ExpectationRule {
+    NotNull(ExpectationRuleNotNull),
+    Unique(ExpectationRuleUnique),
+    AllowedValues(ExpectationRuleAllowedValues),
+    Range(ExpectationRuleRange),
+    Regex(ExpectationRuleRegex),
+    RowCount(ExpectationRuleRowCount),
+}
+
+#[derive(OneofObject, Debug)]
+pub enum ExpectationRuleInput {
+    NotNull(ExpectationRuleNotNull),
+    Unique(ExpectationRuleUnique),
+    AllowedValues(ExpectationRuleAllowedValues),
+    Range(ExpectationRuleRange),
+    Regex(ExpectationRuleRegex),
+    RowCount(ExpectationRuleRowCount),
+}
+
+/// Column must not contain nulls
+#[derive(SimpleObject, InputObject, Debug, Clone, PartialEq, Eq)]
+#[graphql(input_name = "ExpectationRuleNotNullInput")]
+pub struct ExpectationRuleNotNull {
+    pub column: String,
+}
+
+/// Combination of key columns must be unique within the slice
+#[derive(SimpleObject, InputObject, Debug, Clone, PartialEq, Eq)]
+#[graphql(input_name = "ExpectationRuleUniqueInput")]
+pub struct ExpectationRuleUnique {
+    pub columns: Vec<String>,
+}
+
+/// Non-null values of the column must be one of the listed values
+#[derive(SimpleObject, InputObject, Debug, Clone, PartialEq, Eq)]
+#[graphql(input_name = "ExpectationRuleAllowedValuesInput")]
+pub struct ExpectationRuleAllowedValues {
+    pub column: String,
+    pub values: Vec<String>,
+}
+
+/// Non-null numeric values of the column must be within inclusive bounds
+#[derive(SimpleObject, InputObject, Debug, Clone, PartialEq)]
+#[graphql(input_name = "ExpectationRuleRangeInput")]
+pub struct ExpectationRuleRange {
+    pub column: String,
+    pub min: Option<f64>,
+    pub max: Option<f64>,
+}
+
+/// Non-null values of the column must match the regular expression
+#[derive(SimpleObject, InputObject, Debug, Clone, PartialEq, Eq)]
+#[graphql(input_name = "ExpectationRuleRegexInput")]
+pub struct ExpectationRuleRegex {
+    pub column: String,
+    pub pattern: String,
+}
+
+/// Number of records in the slice must be within inclusive bounds
+#[derive(SimpleObject, InputObject, Debug, Clone, PartialEq, Eq)]
+#[graphql(input_name = "ExpectationRuleRowCountInput")]
+pub struct ExpectationRuleRowCount {
+    pub min: Option<u64>,
+    pub max: Option<u64>,
+}
+
+impl From<domain::ExpectationRule> for ExpectationRule {
+    fn from(v: domain::ExpectationRule) -> Self {
+        match v {
+            domain::ExpectationRule::NotNull { column } => {
+                Self::NotNull(ExpectationRuleNotNull { column })
+            }
+            domain::ExpectationRule::Unique { columns } => {
+                Self::Unique(ExpectationRuleUnique { columns })
+            }
+            domain::ExpectationRule::AllowedValues { column, values } => {
+                Self::AllowedValues(ExpectationRuleAllowedValues { column, values })
+            }
+            domain::ExpectationRule::Range { column, min, max } => {
+                Self::Range(ExpectationRuleRange { column, min, max })
+            }
+            domain::ExpectationRule::Regex { column, pattern } => {
+                Self::Regex(ExpectationRuleRegex { column, pattern })
+            }
+            domain::ExpectationRule::RowCount { min, max } => {
+                Self::RowCount(ExpectationRuleRowCount { min, max })
+            }
+        }
+    }
+}
+
+impl From<ExpectationRuleInput> for domain::ExpectationRule {
+    fn from(v: ExpectationRuleInput) -> Self {
+        match v {
+            ExpectationRuleInput::NotNull(r) => Self::NotNull { column: r.column },
+            ExpectationRuleInput::Unique(r) => Self::Unique { columns: r.columns },
+            ExpectationRuleInput::AllowedValues(r) => Self::AllowedValues {
+                column: r.column,
+                values: r.values,
+            },
+            ExpectationRuleInput::Range(r) => Self::Range {
+                column: r.column,
+                min: r.min,
+                max: r.max,
+            },
+            ExpectationRuleInput::Regex(r) => Self::Regex {
+                column: r.column,
+                pattern: r.pattern,
+            },
+            ExpectationRuleInput::RowCount(r) => Self::RowCount {
+                min: r.min,
+                max: r.max,
+            },
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[derive(Enum, Debug, Copy, Clone, PartialEq, Eq)]
+pub enum ExpectationPolicy {
+    /// Reject the whole ingest
+    Fail,
+    /// Exclude violating rows from the slice and keep them aside
+    Quarantine,
+    /// Only record the violation
+    Warn,
+}
+
+impl From<domain::ExpectationPolicy> for ExpectationPolicy {
+    fn from(v: domain::ExpectationPolicy) -> Self {
+        match v {
+            domain::ExpectationPolicy::Fail => Self::Fail,
+            domain::ExpectationPolicy::Quarantine => Self::Quarantine,
+            domain::ExpectationPolicy::Warn => Self::Warn,
+        }
+    }
+}
+
+impl From<ExpectationPolicy> for domain::ExpectationPolicy {
+    fn from(v: ExpectationPolicy) -> Self {
+        match v {
+            ExpectationPolicy::Fail => Self::Fail,
+            ExpectationPolicy::Quarantine => Self::Quarantine,
+            ExpectationPolicy::Warn => Self::Warn,
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[derive(SimpleObject, Debug, Clone, PartialEq, Eq)]
+pub struct ExpectationOutcome {
+    pub expectation_name: String,
+    pub policy: ExpectationPolicy,
+    /// Number of violating rows, or `1` for slice-level rules
+    pub num_violations: u64,
+}
+
+impl From<domain::ExpectationOutcome> for ExpectationOutcome {
+    fn from(v: domain::ExpectationOutcome) -> Self {
+        Self {
+            expectation_name: v.expectation_name,
+            policy: v.policy.into(),
+            num_violations: v.num_violations,
+        }
+    }
+}
+
+#[derive(SimpleObject, Debug, Clone, PartialEq, Eq)]
+pub struct DatasetExpectationsCheck {
+    pub checked_at: DateTime<Utc>,
+    /// Number of records in the slice before any rows were quarantined
+    pub num_records: u64,
+    /// Number of records excluded from the slice by quarantine rules
+    pub num_quarantined: u64,
+    /// Whether the ingest was rejected due to violations of failing rules
+    pub rejected: bool,
+    /// Head of the dataset after the commit, if the slice was committed
+    pub new_head: Option<Multihash>,
+    pub outcomes: Vec<ExpectationOutcome>,
+}
+
+impl From<domain::DatasetExpectationsCheck> for DatasetExpectationsCheck {
+    fn from(v: domain::DatasetExpectationsCheck) -> Self {
+        Self {
+            checked_at: v.checked_at,
+            num_records: v.num_records,
+            num_quarantined: v.num_quarantined,
+            rejected: v.is_rejected(),
+            new_head: v.new_head.map(Into::into),
+            outcomes: v.outcomes.into_iter().map(Into::into).collect(),
+        }
+    }
+}
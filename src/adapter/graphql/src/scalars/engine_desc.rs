@@ -15,6 +15,8 @@ use crate::prelude::*;
 
 #[derive(SimpleObject)]
 pub struct EngineDesc {
+    /// Identifier of the engine as used in transformations, e.g. "datafusion"
+    pub id: String,
     /// A short name of the engine, e.g. "Spark", "Flink".
     /// Intended for use in UI for quick engine identification and selection.
     pub name: String,
@@ -29,6 +31,7 @@ pub struct EngineDesc {
 impl From<domain::EngineDesc> for EngineDesc {
     fn from(value: domain::EngineDesc) -> Self {
         Self {
+            id: value.id,
             name: value.name,
             dialect: value.dialect.into(),
             latest_image: value.latest_image,
@@ -60,9 +60,9 @@ impl From<kamu_flow_system::FlowConfigurationState> for FlowConfiguration {
             compaction: if let FlowConfigurationRule::CompactionRule(compaction_args) = &value.rule
             {
                 match compaction_args {
-                    CompactionRule::Full(compaction_rule) => {
-                        Some(FlowConfigurationCompaction::Full((*compaction_rule).into()))
-                    }
+                    CompactionRule::Full(compaction_rule) => Some(
+                        FlowConfigurationCompaction::Full(compaction_rule.clone().into()),
+                    ),
                     CompactionRule::MetadataOnly(compaction_rule) => Some(
                         FlowConfigurationCompaction::MetadataOnly((*compaction_rule).into()),
                     ),
@@ -172,6 +172,7 @@ pub struct CompactionFull {
     pub max_slice_size: u64,
     pub max_slice_records: u64,
     pub recursive: bool,
+    pub data_layout: CompactionDataLayout,
 }
 
 impl From<CompactionRuleFull> for CompactionFull {
@@ -180,10 +181,69 @@ impl From<CompactionRuleFull> for CompactionFull {
             max_slice_records: value.max_slice_records(),
             max_slice_size: value.max_slice_size(),
             recursive: value.recursive(),
+            data_layout: value.data_layout().clone().into(),
         }
     }
 }
 
+#[derive(SimpleObject, Clone, PartialEq, Eq)]
+pub struct CompactionDataLayout {
+    /// Columns records are sorted by within compacted slices
+    pub sort_by: Vec<String>,
+    /// Maximum number of records in a Parquet row group
+    pub max_row_group_size: Option<u64>,
+    /// Whether Parquet page index is written
+    pub page_index: Option<bool>,
+    /// Columns Parquet bloom filters are written for
+    pub bloom_filter_columns: Vec<String>,
+    /// Compression of data files
+    pub compression: Option<CompactionCompression>,
+}
+
+impl From<kamu_core::CompactionDataLayout> for CompactionDataLayout {
+    fn from(value: kamu_core::CompactionDataLayout) -> Self {
+        Self {
+            sort_by: value.sort_by,
+            max_row_group_size: value.max_row_group_size,
+            page_index: value.page_index,
+            bloom_filter_columns: value.bloom_filter_columns,
+            compression: value.compression.map(Into::into),
+        }
+    }
+}
+
+#[derive(SimpleObject, Clone, PartialEq, Eq)]
+pub struct CompactionCompression {
+    pub codec: CompactionCompressionCodec,
+    pub level: Option<i32>,
+}
+
+impl From<kamu_core::CompactionCompression> for CompactionCompression {
+    fn from(value: kamu_core::CompactionCompression) -> Self {
+        match value {
+            kamu_core::CompactionCompression::Uncompressed => Self {
+                codec: CompactionCompressionCodec::Uncompressed,
+                level: None,
+            },
+            kamu_core::CompactionCompression::Snappy => Self {
+                codec: CompactionCompressionCodec::Snappy,
+                level: None,
+            },
+            kamu_core::CompactionCompression::Zstd { level } => Self {
+                codec: CompactionCompressionCodec::Zstd,
+                level: Some(level),
+            },
+        }
+    }
+}
+
+#[derive(Enum, Clone, Copy, PartialEq, Eq)]
+pub enum CompactionCompressionCodec {
+    Uncompressed,
+    Snappy,
+    Zstd,
+}
+
 #[derive(SimpleObject, Clone, PartialEq, Eq)]
 pub struct CompactionMetadataOnly {
     pub recursive: bool,
@@ -370,6 +430,55 @@ pub struct CompactionConditionFull {
     pub max_slice_size: u64,
     pub max_slice_records: u64,
     pub recursive: bool,
+    /// Layout of the data files produced by compaction
+    pub data_layout: Option<CompactionDataLayoutInput>,
+}
+
+#[derive(InputObject, Clone)]
+pub struct CompactionDataLayoutInput {
+    /// Columns to sort records by within compacted slices
+    pub sort_by: Option<Vec<String>>,
+    /// Maximum number of records in a Parquet row group
+    pub max_row_group_size: Option<u64>,
+    /// Whether to write Parquet page index
+    pub page_index: Option<bool>,
+    /// Columns to write Parquet bloom filters for
+    pub bloom_filter_columns: Option<Vec<String>>,
+    /// Compression of data files
+    pub compression: Option<CompactionCompressionInput>,
+}
+
+impl From<CompactionDataLayoutInput> for kamu_core::CompactionDataLayout {
+    fn from(value: CompactionDataLayoutInput) -> Self {
+        Self {
+            sort_by: value.sort_by.unwrap_or_default(),
+            max_row_group_size: value.max_row_group_size,
+            page_index: value.page_index,
+            bloom_filter_columns: value.bloom_filter_columns.unwrap_or_default(),
+            compression: value.compression.map(Into::into),
+        }
+    }
+}
+
+#[derive(InputObject, Clone)]
+pub struct CompactionCompressionInput {
+    pub codec: CompactionCompressionCodec,
+    /// Compression level, only applicable to `ZSTD`
+    pub level: Option<i32>,
+}
+
+impl From<CompactionCompressionInput> for kamu_core::CompactionCompression {
+    fn from(value: CompactionCompressionInput) -> Self {
+        match value.codec {
+            CompactionCompressionCodec::Uncompressed => Self::Uncompressed,
+            CompactionCompressionCodec::Snappy => Self::Snappy,
+            CompactionCompressionCodec::Zstd => Self::Zstd {
+                level: value
+                    .level
+                    .unwrap_or(kamu_core::DEFAULT_ZSTD_COMPRESSION_LEVEL),
+            },
+        }
+    }
 }
 
 #[derive(InputObject)]
@@ -474,6 +583,11 @@ impl FlowRunConfiguration {
                                             compaction_input.max_slice_size,
                                             compaction_input.max_slice_records,
                                             compaction_input.recursive,
+                                            compaction_input
+                                                .data_layout
+                                                .clone()
+                                                .map(Into::into)
+                                                .unwrap_or_default(),
                                         )
                                         .map_err(|_| {
                                             FlowInvalidRunConfigurations {
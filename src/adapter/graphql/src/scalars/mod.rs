@@ -14,6 +14,7 @@ mod data_query;
 mod data_schema;
 mod dataset_endpoints;
 mod dataset_env_var;
+mod dataset_expectation;
 mod dataset_id_name;
 mod dataset_visibility;
 mod engine_desc;
@@ -27,6 +28,7 @@ mod os_path;
 mod pagination;
 mod task_id;
 mod task_status_outcome;
+mod webhook;
 
 pub(crate) use access_token::*;
 pub(crate) use account::*;
@@ -35,6 +37,7 @@ pub(crate) use data_query::*;
 pub(crate) use data_schema::*;
 pub(crate) use dataset_endpoints::*;
 pub(crate) use dataset_env_var::*;
+pub(crate) use dataset_expectation::*;
 pub(crate) use dataset_id_name::*;
 pub(crate) use dataset_visibility::*;
 pub(crate) use engine_desc::*;
@@ -48,6 +51,7 @@ pub(crate) use os_path::*;
 pub(crate) use pagination::*;
 pub(crate) use task_id::*;
 pub(crate) use task_status_outcome::*;
+pub(crate) use webhook::*;
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
 
@@ -423,6 +423,10 @@ pub enum FetchStep {
     Container(FetchStepContainer),
     Mqtt(FetchStepMqtt),
     EthereumLogs(FetchStepEthereumLogs),
+    Sql(FetchStepSql),
+    HttpPaginated(FetchStepHttpPaginated),
+    EthereumBlocks(FetchStepEthereumBlocks),
+    EthereumTransactions(FetchStepEthereumTransactions),
 }
 
 impl From<odf::FetchStep> for FetchStep {
@@ -433,6 +437,10 @@ impl From<odf::FetchStep> for FetchStep {
             odf::FetchStep::Container(v) => Self::Container(v.into()),
             odf::FetchStep::Mqtt(v) => Self::Mqtt(v.into()),
             odf::FetchStep::EthereumLogs(v) => Self::EthereumLogs(v.into()),
+            odf::FetchStep::Sql(v) => Self::Sql(v.into()),
+            odf::FetchStep::HttpPaginated(v) => Self::HttpPaginated(v.into()),
+            odf::FetchStep::EthereumBlocks(v) => Self::EthereumBlocks(v.into()),
+            odf::FetchStep::EthereumTransactions(v) => Self::EthereumTransactions(v.into()),
         }
     }
 }
@@ -534,6 +542,86 @@ impl From<odf::FetchStepEthereumLogs> for FetchStepEthereumLogs {
     }
 }
 
+#[derive(SimpleObject, Debug, Clone, PartialEq, Eq)]
+pub struct FetchStepSql {
+    pub url: String,
+    pub query: String,
+    pub cursor_column: Option<String>,
+}
+
+impl From<odf::FetchStepSql> for FetchStepSql {
+    fn from(v: odf::FetchStepSql) -> Self {
+        Self {
+            url: v.url.into(),
+            query: v.query.into(),
+            cursor_column: v.cursor_column.map(Into::into),
+        }
+    }
+}
+
+#[derive(SimpleObject, Debug, Clone, PartialEq, Eq)]
+pub struct FetchStepHttpPaginated {
+    pub url: String,
+    pub headers: Option<Vec<RequestHeader>>,
+    pub records_path: Option<String>,
+    pub pagination: HttpPagination,
+    pub high_water_mark: Option<HttpHighWaterMark>,
+}
+
+impl From<odf::FetchStepHttpPaginated> for FetchStepHttpPaginated {
+    fn from(v: odf::FetchStepHttpPaginated) -> Self {
+        Self {
+            url: v.url.into(),
+            headers: v.headers.map(|v| v.into_iter().map(Into::into).collect()),
+            records_path: v.records_path.map(Into::into),
+            pagination: v.pagination.into(),
+            high_water_mark: v.high_water_mark.map(Into::into),
+        }
+    }
+}
+
+#[derive(SimpleObject, Debug, Clone, PartialEq, Eq)]
+pub struct FetchStepEthereumBlocks {
+    pub chain_id: Option<u64>,
+    pub node_url: Option<String>,
+    pub from_block: Option<u64>,
+    pub to_block: Option<u64>,
+}
+
+impl From<odf::FetchStepEthereumBlocks> for FetchStepEthereumBlocks {
+    fn from(v: odf::FetchStepEthereumBlocks) -> Self {
+        Self {
+            chain_id: v.chain_id.map(Into::into),
+            node_url: v.node_url.map(Into::into),
+            from_block: v.from_block.map(Into::into),
+            to_block: v.to_block.map(Into::into),
+        }
+    }
+}
+
+#[derive(SimpleObject, Debug, Clone, PartialEq, Eq)]
+pub struct FetchStepEthereumTransactions {
+    pub chain_id: Option<u64>,
+    pub node_url: Option<String>,
+    pub from_block: Option<u64>,
+    pub to_block: Option<u64>,
+    pub to_address: Option<String>,
+    pub signature: Option<String>,
+}
+
+impl From<odf::FetchStepEthereumTransactions> for FetchStepEthereumTransactions {
+    fn from(v: odf::FetchStepEthereumTransactions) -> Self {
+        Self {
+            chain_id: v.chain_id.map(Into::into),
+            node_url: v.node_url.map(Into::into),
+            from_block: v.from_block.map(Into::into),
+            to_block: v.to_block.map(Into::into),
+            to_address: v.to_address.map(Into::into),
+            signature: v.signature.map(Into::into),
+        }
+    }
+}
+
 #[derive(Enum, Debug, Clone, Copy, PartialEq, Eq)]
 pub enum SourceOrdering {
     ByEventTime,
@@ -558,6 +646,89 @@ impl Into<odf::SourceOrdering> for SourceOrdering {
     }
 }
 
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// HttpHighWaterMark
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[derive(SimpleObject, Debug, Clone, PartialEq, Eq)]
+pub struct HttpHighWaterMark {
+    pub field: String,
+    pub param: String,
+}
+
+impl From<odf::HttpHighWaterMark> for HttpHighWaterMark {
+    fn from(v: odf::HttpHighWaterMark) -> Self {
+        Self {
+            field: v.field.into(),
+            param: v.param.into(),
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// HttpPagination
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[derive(Union, Debug, Clone, PartialEq, Eq)]
+pub enum HttpPagination {
+    LinkHeader(HttpPaginationLinkHeader),
+    BodyCursor(HttpPaginationBodyCursor),
+    OffsetLimit(HttpPaginationOffsetLimit),
+}
+
+impl From<odf::HttpPagination> for HttpPagination {
+    fn from(v: odf::HttpPagination) -> Self {
+        match v {
+            odf::HttpPagination::LinkHeader(v) => Self::LinkHeader(v.into()),
+            odf::HttpPagination::BodyCursor(v) => Self::BodyCursor(v.into()),
+            odf::HttpPagination::OffsetLimit(v) => Self::OffsetLimit(v.into()),
+        }
+    }
+}
+
+#[derive(SimpleObject, Debug, Clone, PartialEq, Eq)]
+pub struct HttpPaginationLinkHeader {
+    pub _dummy: Option<String>,
+}
+
+impl From<odf::HttpPaginationLinkHeader> for HttpPaginationLinkHeader {
+    fn from(v: odf::HttpPaginationLinkHeader) -> Self {
+        Self { _dummy: None }
+    }
+}
+
+#[derive(SimpleObject, Debug, Clone, PartialEq, Eq)]
+pub struct HttpPaginationBodyCursor {
+    pub cursor_path: String,
+    pub cursor_param: String,
+}
+
+impl From<odf::HttpPaginationBodyCursor> for HttpPaginationBodyCursor {
+    fn from(v: odf::HttpPaginationBodyCursor) -> Self {
+        Self {
+            cursor_path: v.cursor_path.into(),
+            cursor_param: v.cursor_param.into(),
+        }
+    }
+}
+
+#[derive(SimpleObject, Debug, Clone, PartialEq, Eq)]
+pub struct HttpPaginationOffsetLimit {
+    pub offset_param: String,
+    pub limit_param: String,
+    pub page_size: u64,
+}
+
+impl From<odf::HttpPaginationOffsetLimit> for HttpPaginationOffsetLimit {
+    fn from(v: odf::HttpPaginationOffsetLimit) -> Self {
+        Self {
+            offset_param: v.offset_param.into(),
+            limit_param: v.limit_param.into(),
+            page_size: v.page_size.into(),
+        }
+    }
+}
+
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
 // MergeStrategy
 // https://github.com/kamu-data/open-data-fabric/blob/master/open-data-fabric.md#mergestrategy-schema
@@ -811,6 +982,11 @@ impl From<odf::PrepStepPipe> for PrepStepPipe {
 pub enum CompressionFormat {
     Gzip,
     Zip,
+    Zstd,
+    Xz,
+    Bzip2,
+    Tar,
+    Auto,
 }
 
 impl From<odf::CompressionFormat> for CompressionFormat {
@@ -818,6 +994,11 @@ impl From<odf::CompressionFormat> for CompressionFormat {
         match v {
             odf::CompressionFormat::Gzip => Self::Gzip,
             odf::CompressionFormat::Zip => Self::Zip,
+            odf::CompressionFormat::Zstd => Self::Zstd,
+            odf::CompressionFormat::Xz => Self::Xz,
+            odf::CompressionFormat::Bzip2 => Self::Bzip2,
+            odf::CompressionFormat::Tar => Self::Tar,
+            odf::CompressionFormat::Auto => Self::Auto,
         }
     }
 }
@@ -827,6 +1008,11 @@ impl Into<odf::CompressionFormat> for CompressionFormat {
         match self {
             Self::Gzip => odf::CompressionFormat::Gzip,
             Self::Zip => odf::CompressionFormat::Zip,
+            Self::Zstd => odf::CompressionFormat::Zstd,
+            Self::Xz => odf::CompressionFormat::Xz,
+            Self::Bzip2 => odf::CompressionFormat::Bzip2,
+            Self::Tar => odf::CompressionFormat::Tar,
+            Self::Auto => odf::CompressionFormat::Auto,
         }
     }
 }
@@ -0,0 +1,90 @@
+// Copyright Kamu Data, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+use std::ops::Deref;
+
+use uuid::Uuid;
+
+use crate::prelude::*;
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+macro_rules! uuid_scalar {
+    ($name: ident) => {
+        #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+        pub struct $name(Uuid);
+
+        impl From<Uuid> for $name {
+            fn from(value: Uuid) -> Self {
+                $name(value)
+            }
+        }
+
+        impl From<$name> for Uuid {
+            fn from(val: $name) -> Self {
+                val.0
+            }
+        }
+
+        impl Deref for $name {
+            type Target = Uuid;
+            fn deref(&self) -> &Self::Target {
+                &self.0
+            }
+        }
+
+        #[Scalar]
+        impl ScalarType for $name {
+            fn parse(value: Value) -> InputValueResult<Self> {
+                if let Value::String(value) = &value {
+                    let val = Uuid::try_parse(value.as_str())?;
+                    Ok(val.into())
+                } else {
+                    Err(InputValueError::expected_type(value))
+                }
+            }
+
+            fn to_value(&self) -> Value {
+                Value::String(self.0.to_string())
+            }
+        }
+
+        impl std::fmt::Display for $name {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                write!(f, "{}", self.0)
+            }
+        }
+    };
+}
+
+uuid_scalar!(WebhookSubscriptionID);
+uuid_scalar!(WebhookDeliveryID);
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[derive(Enum, Debug, Copy, Clone, Eq, PartialEq)]
+#[graphql(remote = "kamu_webhooks::WebhookEventType")]
+pub enum WebhookEventType {
+    DatasetDataAppended,
+    DatasetDeleted,
+    FlowSucceeded,
+    FlowFailed,
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[derive(Enum, Debug, Copy, Clone, Eq, PartialEq)]
+#[graphql(remote = "kamu_webhooks::WebhookDeliveryStatus")]
+pub enum WebhookDeliveryStatus {
+    Pending,
+    Delivered,
+    DeadLettered,
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -13,8 +13,10 @@ mod test_error_handling;
 mod test_gql_account_flow_configs;
 mod test_gql_data;
 mod test_gql_dataset_env_vars;
+mod test_gql_dataset_expectations;
 mod test_gql_dataset_flow_configs;
 mod test_gql_dataset_flow_runs;
+mod test_gql_dataset_webhooks;
 mod test_gql_datasets;
 mod test_gql_metadata;
 mod test_gql_metadata_chain;
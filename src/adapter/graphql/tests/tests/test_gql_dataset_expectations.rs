@@ -0,0 +1,370 @@
+// Copyright Kamu Data, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+use async_graphql::value;
+use database_common::{DatabaseTransactionRunner, NoOpDatabasePlugin};
+use dill::Component;
+use indoc::indoc;
+use kamu::testing::MetadataFactory;
+use kamu::{
+    CreateDatasetFromSnapshotUseCaseImpl,
+    DatasetRepositoryLocalFs,
+    DatasetRepositoryWriter,
+    DependencyGraphServiceInMemory,
+};
+use kamu_core::{auth, CreateDatasetFromSnapshotUseCase, CreateDatasetResult, DatasetRepository};
+use kamu_datasets::{
+    DatasetExpectationService,
+    ExpectationOutcome,
+    ExpectationPolicy,
+    ExpectationsCheckResult,
+};
+use kamu_datasets_inmem::InMemoryDatasetExpectationRepository;
+use kamu_datasets_services::DatasetExpectationServiceImpl;
+use messaging_outbox::DummyOutboxImpl;
+use opendatafabric::DatasetKind;
+use time_source::SystemTimeSourceDefault;
+
+use crate::utils::authentication_catalogs;
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[test_log::test(tokio::test)]
+async fn test_set_and_get_dataset_expectations() {
+    let harness = DatasetExpectationsHarness::new().await;
+    let created_dataset = harness.create_dataset().await;
+    let dataset_id = created_dataset.dataset_handle.id.to_string();
+
+    let schema = kamu_adapter_graphql::schema_quiet();
+
+    let res = schema
+        .execute(
+            async_graphql::Request::new(
+                indoc!(
+                    r#"
+                    mutation {
+                        datasets {
+                            byId(datasetId: "<dataset_id>") {
+                                expectations {
+                                    setExpectations(expectations: [
+                                        {
+                                            name: "id-not-null",
+                                            rule: { notNull: { column: "id" } },
+                                            policy: FAIL
+                                        },
+                                        {
+                                            name: "price-positive",
+                                            rule: { range: { column: "price", min: 0 } },
+                                            policy: QUARANTINE
+                                        }
+                                    ]) {
+                                        message
+                                    }
+                                }
+                            }
+                        }
+                    }
+                    "#
+                )
+                .replace("<dataset_id>", &dataset_id),
+            )
+            .data(harness.catalog_authorized.clone()),
+        )
+        .await;
+
+    assert!(res.is_ok(), "{res:?}");
+    assert_eq!(
+        res.data,
+        value!({
+            "datasets": {
+                "byId": {
+                    "expectations": {
+                        "setExpectations": {
+                            "message": "Success"
+                        }
+                    }
+                }
+            }
+        })
+    );
+
+    let res = schema
+        .execute(
+            async_graphql::Request::new(
+                indoc!(
+                    r#"
+                    query {
+                        datasets {
+                            byId(datasetId: "<dataset_id>") {
+                                expectations {
+                                    list {
+                                        name
+                                        policy
+                                        rule {
+                                            __typename
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                    "#
+                )
+                .replace("<dataset_id>", &dataset_id),
+            )
+            .data(harness.catalog_authorized.clone()),
+        )
+        .await;
+
+    assert!(res.is_ok(), "{res:?}");
+    assert_eq!(
+        res.data,
+        value!({
+            "datasets": {
+                "byId": {
+                    "expectations": {
+                        "list": [
+                            {
+                                "name": "id-not-null",
+                                "policy": "FAIL",
+                                "rule": { "__typename": "ExpectationRuleNotNull" },
+                            },
+                            {
+                                "name": "price-positive",
+                                "policy": "QUARANTINE",
+                                "rule": { "__typename": "ExpectationRuleRange" },
+                            },
+                        ]
+                    }
+                }
+            }
+        })
+    );
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[test_log::test(tokio::test)]
+async fn test_set_invalid_dataset_expectations() {
+    let harness = DatasetExpectationsHarness::new().await;
+    let created_dataset = harness.create_dataset().await;
+
+    let schema = kamu_adapter_graphql::schema_quiet();
+
+    let res = schema
+        .execute(
+            async_graphql::Request::new(
+                indoc!(
+                    r#"
+                    mutation {
+                        datasets {
+                            byId(datasetId: "<dataset_id>") {
+                                expectations {
+                                    setExpectations(expectations: [
+                                        {
+                                            name: "rows",
+                                            rule: { rowCount: { min: 1 } },
+                                            policy: QUARANTINE
+                                        }
+                                    ]) {
+                                        message
+                                    }
+                                }
+                            }
+                        }
+                    }
+                    "#
+                )
+                .replace(
+                    "<dataset_id>",
+                    &created_dataset.dataset_handle.id.to_string(),
+                ),
+            )
+            .data(harness.catalog_authorized.clone()),
+        )
+        .await;
+
+    assert!(res.is_ok(), "{res:?}");
+    assert_eq!(
+        res.data,
+        value!({
+            "datasets": {
+                "byId": {
+                    "expectations": {
+                        "setExpectations": {
+                            "message": "Invalid expectation 'rows': Row count expectations apply to the whole slice and cannot quarantine rows"
+                        }
+                    }
+                }
+            }
+        })
+    );
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[test_log::test(tokio::test)]
+async fn test_get_dataset_expectations_checks() {
+    let harness = DatasetExpectationsHarness::new().await;
+    let created_dataset = harness.create_dataset().await;
+
+    let expectation_service = harness
+        .catalog_authorized
+        .get_one::<dyn DatasetExpectationService>()
+        .unwrap();
+
+    expectation_service
+        .record_expectations_check(
+            &created_dataset.dataset_handle.id,
+            ExpectationsCheckResult {
+                num_records: 10,
+                num_quarantined: 0,
+                new_head: None,
+                outcomes: vec![ExpectationOutcome {
+                    expectation_name: "id-not-null".to_string(),
+                    policy: ExpectationPolicy::Fail,
+                    num_violations: 2,
+                }],
+            },
+        )
+        .await
+        .unwrap();
+
+    let schema = kamu_adapter_graphql::schema_quiet();
+
+    let res = schema
+        .execute(
+            async_graphql::Request::new(
+                indoc!(
+                    r#"
+                    query {
+                        datasets {
+                            byId(datasetId: "<dataset_id>") {
+                                expectations {
+                                    checks(page: 0, perPage: 5) {
+                                        totalCount
+                                        nodes {
+                                            numRecords
+                                            numQuarantined
+                                            rejected
+                                            newHead
+                                            outcomes {
+                                                expectationName
+                                                policy
+                                                numViolations
+                                            }
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                    "#
+                )
+                .replace(
+                    "<dataset_id>",
+                    &created_dataset.dataset_handle.id.to_string(),
+                ),
+            )
+            .data(harness.catalog_authorized.clone()),
+        )
+        .await;
+
+    assert!(res.is_ok(), "{res:?}");
+    assert_eq!(
+        res.data,
+        value!({
+            "datasets": {
+                "byId": {
+                    "expectations": {
+                        "checks": {
+                            "totalCount": 1,
+                            "nodes": [{
+                                "numRecords": 10,
+                                "numQuarantined": 0,
+                                "rejected": true,
+                                "newHead": null,
+                                "outcomes": [{
+                                    "expectationName": "id-not-null",
+                                    "policy": "FAIL",
+                                    "numViolations": 2,
+                                }],
+                            }]
+                        }
+                    }
+                }
+            }
+        })
+    );
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+struct DatasetExpectationsHarness {
+    _tempdir: tempfile::TempDir,
+    catalog_authorized: dill::Catalog,
+}
+
+impl DatasetExpectationsHarness {
+    async fn new() -> Self {
+        let tempdir = tempfile::tempdir().unwrap();
+        let datasets_dir = tempdir.path().join("datasets");
+        std::fs::create_dir(&datasets_dir).unwrap();
+
+        let catalog_base = {
+            let mut b = dill::CatalogBuilder::new();
+
+            b.add::<DummyOutboxImpl>()
+                .add_builder(
+                    DatasetRepositoryLocalFs::builder()
+                        .with_root(datasets_dir)
+                        .with_multi_tenant(false),
+                )
+                .bind::<dyn DatasetRepository, DatasetRepositoryLocalFs>()
+                .bind::<dyn DatasetRepositoryWriter, DatasetRepositoryLocalFs>()
+                .add::<CreateDatasetFromSnapshotUseCaseImpl>()
+                .add::<SystemTimeSourceDefault>()
+                .add::<auth::AlwaysHappyDatasetActionAuthorizer>()
+                .add::<DependencyGraphServiceInMemory>()
+                .add::<DatabaseTransactionRunner>()
+                .add::<DatasetExpectationServiceImpl>()
+                .add::<InMemoryDatasetExpectationRepository>();
+
+            NoOpDatabasePlugin::init_database_components(&mut b);
+
+            b.build()
+        };
+
+        let (_, catalog_authorized) = authentication_catalogs(&catalog_base).await;
+
+        Self {
+            _tempdir: tempdir,
+            catalog_authorized,
+        }
+    }
+
+    async fn create_dataset(&self) -> CreateDatasetResult {
+        let create_dataset_from_snapshot = self
+            .catalog_authorized
+            .get_one::<dyn CreateDatasetFromSnapshotUseCase>()
+            .unwrap();
+
+        create_dataset_from_snapshot
+            .execute(
+                MetadataFactory::dataset_snapshot()
+                    .kind(DatasetKind::Root)
+                    .name("foo")
+                    .push_event(MetadataFactory::set_polling_source().build())
+                    .build(),
+                Default::default(),
+            )
+            .await
+            .unwrap()
+    }
+}
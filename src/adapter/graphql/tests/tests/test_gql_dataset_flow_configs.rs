@@ -729,6 +729,147 @@ async fn test_crud_compaction_root_dataset() {
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
 
+#[test_log::test(tokio::test)]
+async fn test_crud_compaction_data_layout() {
+    let harness = FlowConfigHarness::with_overrides(FlowRunsHarnessOverrides {
+        transform_service_mock: Some(MockTransformService::with_set_transform()),
+        polling_service_mock: Some(MockPollingIngestService::with_active_polling_source()),
+    })
+    .await;
+    let create_result = harness.create_root_dataset().await;
+
+    let schema = kamu_adapter_graphql::schema_quiet();
+
+    let mutation_code = indoc!(
+        r#"
+        mutation {
+            datasets {
+                byId (datasetId: "<id>") {
+                    flows {
+                        configs {
+                            setConfigCompaction (
+                                datasetFlowType: "HARD_COMPACTION",
+                                compactionArgs: {
+                                    full: {
+                                        maxSliceSize: 1000000,
+                                        maxSliceRecords: 10000,
+                                        recursive: false,
+                                        dataLayout: {
+                                            sortBy: ["city", "date"],
+                                            maxRowGroupSize: 1000,
+                                            bloomFilterColumns: ["city"],
+                                            compression: { codec: <codec>, level: <level> }
+                                        }
+                                    }
+                                }
+                            ) {
+                                __typename
+                                message
+                                ... on SetFlowConfigSuccess {
+                                    config {
+                                        compaction {
+                                            ... on CompactionFull {
+                                                dataLayout {
+                                                    sortBy
+                                                    maxRowGroupSize
+                                                    pageIndex
+                                                    bloomFilterColumns
+                                                    compression {
+                                                        codec
+                                                        level
+                                                    }
+                                                }
+                                            }
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+        "#
+    )
+    .replace("<id>", &create_result.dataset_handle.id.to_string());
+
+    let res = schema
+        .execute(
+            async_graphql::Request::new(
+                mutation_code
+                    .replace("<codec>", "ZSTD")
+                    .replace("<level>", "9"),
+            )
+            .data(harness.catalog_authorized.clone()),
+        )
+        .await;
+
+    assert!(res.is_ok(), "{res:?}");
+    assert_eq!(
+        res.data,
+        value!({
+            "datasets": {
+                "byId": {
+                    "flows": {
+                        "configs": {
+                            "setConfigCompaction": {
+                                "__typename": "SetFlowConfigSuccess",
+                                "message": "Success",
+                                "config": {
+                                    "compaction": {
+                                        "dataLayout": {
+                                            "sortBy": ["city", "date"],
+                                            "maxRowGroupSize": 1000,
+                                            "pageIndex": null,
+                                            "bloomFilterColumns": ["city"],
+                                            "compression": {
+                                                "codec": "ZSTD",
+                                                "level": 9,
+                                            }
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        })
+    );
+
+    let res = schema
+        .execute(
+            async_graphql::Request::new(
+                mutation_code
+                    .replace("<codec>", "ZSTD")
+                    .replace("<level>", "30"),
+            )
+            .data(harness.catalog_authorized.clone()),
+        )
+        .await;
+
+    assert!(res.is_ok(), "{res:?}");
+    assert_eq!(
+        res.data,
+        value!({
+            "datasets": {
+                "byId": {
+                    "flows": {
+                        "configs": {
+                            "setConfigCompaction": {
+                                "__typename": "FlowInvalidCompactionConfig",
+                                "message": "Invalid data layout: Zstd compression level must be between 1 and 22, got 30",
+                            }
+                        }
+                    }
+                }
+            }
+        })
+    );
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
 #[test_log::test(tokio::test)]
 async fn test_transform_config_validation() {
     let harness = FlowConfigHarness::with_overrides(FlowRunsHarnessOverrides {
@@ -0,0 +1,438 @@
+// Copyright Kamu Data, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+use async_graphql::value;
+use database_common::{DatabaseTransactionRunner, NoOpDatabasePlugin};
+use dill::Component;
+use indoc::indoc;
+use kamu::testing::MetadataFactory;
+use kamu::{
+    CreateDatasetFromSnapshotUseCaseImpl,
+    DatasetRepositoryLocalFs,
+    DatasetRepositoryWriter,
+    DependencyGraphServiceInMemory,
+};
+use kamu_core::{auth, CreateDatasetFromSnapshotUseCase, CreateDatasetResult, DatasetRepository};
+use kamu_webhooks::WebhooksConfig;
+use kamu_webhooks_inmem::{
+    InMemoryWebhookDeliveryRepository,
+    InMemoryWebhookSubscriptionRepository,
+};
+use kamu_webhooks_services::WebhookSubscriptionServiceImpl;
+use messaging_outbox::DummyOutboxImpl;
+use opendatafabric::DatasetKind;
+use time_source::SystemTimeSourceDefault;
+
+use crate::utils::authentication_catalogs;
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[test_log::test(tokio::test)]
+async fn test_create_and_list_dataset_webhooks() {
+    let harness = DatasetWebhooksHarness::new().await;
+    let created_dataset = harness.create_dataset().await;
+    let dataset_id = created_dataset.dataset_handle.id.to_string();
+
+    let res = harness
+        .create_subscription(
+            &dataset_id,
+            "https://example.com/hooks",
+            "[FLOW_FAILED, DATASET_DATA_APPENDED]",
+        )
+        .await;
+
+    assert!(res.is_ok(), "{res:?}");
+    assert_eq!(
+        res.data,
+        value!({
+            "datasets": {
+                "byId": {
+                    "webhooks": {
+                        "createSubscription": {
+                            "message": "Success",
+                            "subscription": {
+                                "label": "ci",
+                                "eventTypes": ["DATASET_DATA_APPENDED", "FLOW_FAILED"],
+                            }
+                        }
+                    }
+                }
+            }
+        })
+    );
+
+    let res = harness
+        .execute(
+            indoc!(
+                r#"
+                query {
+                    datasets {
+                        byId(datasetId: "<dataset_id>") {
+                            webhooks {
+                                subscriptions {
+                                    targetUrl
+                                    label
+                                    secret
+                                    enabled
+                                    deliveries {
+                                        totalCount
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+                "#
+            )
+            .replace("<dataset_id>", &dataset_id),
+        )
+        .await;
+
+    assert!(res.is_ok(), "{res:?}");
+
+    // Secret is stored encrypted, but exposed to the owner in plain text
+    let mut data = res.data.into_json().unwrap();
+    let secret = data["datasets"]["byId"]["webhooks"]["subscriptions"][0]
+        .as_object_mut()
+        .unwrap()
+        .remove("secret")
+        .unwrap();
+    assert_eq!(secret.as_str().unwrap().len(), 64);
+
+    assert_eq!(
+        async_graphql::Value::from_json(data).unwrap(),
+        value!({
+            "datasets": {
+                "byId": {
+                    "webhooks": {
+                        "subscriptions": [{
+                            "targetUrl": "https://example.com/hooks",
+                            "label": "ci",
+                            "enabled": true,
+                            "deliveries": {
+                                "totalCount": 0
+                            }
+                        }]
+                    }
+                }
+            }
+        })
+    );
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[test_log::test(tokio::test)]
+async fn test_create_invalid_dataset_webhooks() {
+    let harness = DatasetWebhooksHarness::new().await;
+    let created_dataset = harness.create_dataset().await;
+    let dataset_id = created_dataset.dataset_handle.id.to_string();
+
+    for (target_url, event_types) in [
+        ("not a url", "[FLOW_FAILED]"),
+        ("ftp://example.com/hooks", "[FLOW_FAILED]"),
+        ("https://example.com/hooks", "[]"),
+    ] {
+        let res = harness
+            .create_subscription(&dataset_id, target_url, event_types)
+            .await;
+
+        assert!(res.is_ok(), "{res:?}");
+        let json = res.data.into_json().unwrap();
+        let result = &json["datasets"]["byId"]["webhooks"]["createSubscription"];
+        assert!(
+            result["message"]
+                .as_str()
+                .unwrap()
+                .starts_with("Invalid webhook subscription"),
+            "{result}"
+        );
+        assert!(result["subscription"].is_null(), "{result}");
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[test_log::test(tokio::test)]
+async fn test_disable_and_delete_dataset_webhook() {
+    let harness = DatasetWebhooksHarness::new().await;
+    let created_dataset = harness.create_dataset().await;
+    let dataset_id = created_dataset.dataset_handle.id.to_string();
+
+    let res = harness
+        .create_subscription(&dataset_id, "https://example.com/hooks", "[FLOW_FAILED]")
+        .await;
+    assert!(res.is_ok(), "{res:?}");
+
+    let res = harness
+        .execute(
+            indoc!(
+                r#"
+                query {
+                    datasets {
+                        byId(datasetId: "<dataset_id>") {
+                            webhooks {
+                                subscriptions {
+                                    id
+                                }
+                            }
+                        }
+                    }
+                }
+                "#
+            )
+            .replace("<dataset_id>", &dataset_id),
+        )
+        .await;
+    assert!(res.is_ok(), "{res:?}");
+    let json = res.data.into_json().unwrap();
+    let subscription_id = json["datasets"]["byId"]["webhooks"]["subscriptions"][0]["id"]
+        .as_str()
+        .unwrap()
+        .to_string();
+
+    let res = harness
+        .execute(
+            indoc!(
+                r#"
+                mutation {
+                    datasets {
+                        byId(datasetId: "<dataset_id>") {
+                            webhooks {
+                                subscription(id: "<subscription_id>") {
+                                    setEnabled(enabled: false) {
+                                        enabled
+                                    }
+                                    redeliver(deliveryId: "00000000-0000-0000-0000-000000000000") {
+                                        message
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+                "#
+            )
+            .replace("<dataset_id>", &dataset_id)
+            .replace("<subscription_id>", &subscription_id),
+        )
+        .await;
+
+    assert!(res.is_ok(), "{res:?}");
+    assert_eq!(
+        res.data,
+        value!({
+            "datasets": {
+                "byId": {
+                    "webhooks": {
+                        "subscription": {
+                            "setEnabled": {
+                                "enabled": false
+                            },
+                            "redeliver": {
+                                "message": "Webhook delivery 00000000-0000-0000-0000-000000000000 not found"
+                            }
+                        }
+                    }
+                }
+            }
+        })
+    );
+
+    let res = harness
+        .execute(
+            indoc!(
+                r#"
+                mutation {
+                    datasets {
+                        byId(datasetId: "<dataset_id>") {
+                            webhooks {
+                                subscription(id: "<subscription_id>") {
+                                    delete
+                                }
+                            }
+                        }
+                    }
+                }
+                "#
+            )
+            .replace("<dataset_id>", &dataset_id)
+            .replace("<subscription_id>", &subscription_id),
+        )
+        .await;
+
+    assert!(res.is_ok(), "{res:?}");
+    assert_eq!(
+        res.data,
+        value!({
+            "datasets": {
+                "byId": {
+                    "webhooks": {
+                        "subscription": {
+                            "delete": true
+                        }
+                    }
+                }
+            }
+        })
+    );
+
+    let res = harness
+        .execute(
+            indoc!(
+                r#"
+                query {
+                    datasets {
+                        byId(datasetId: "<dataset_id>") {
+                            webhooks {
+                                subscription(id: "<subscription_id>") {
+                                    id
+                                }
+                            }
+                        }
+                    }
+                }
+                "#
+            )
+            .replace("<dataset_id>", &dataset_id)
+            .replace("<subscription_id>", &subscription_id),
+        )
+        .await;
+
+    assert!(res.is_ok(), "{res:?}");
+    assert_eq!(
+        res.data,
+        value!({
+            "datasets": {
+                "byId": {
+                    "webhooks": {
+                        "subscription": null
+                    }
+                }
+            }
+        })
+    );
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+struct DatasetWebhooksHarness {
+    _tempdir: tempfile::TempDir,
+    catalog_authorized: dill::Catalog,
+}
+
+impl DatasetWebhooksHarness {
+    async fn new() -> Self {
+        let tempdir = tempfile::tempdir().unwrap();
+        let datasets_dir = tempdir.path().join("datasets");
+        std::fs::create_dir(&datasets_dir).unwrap();
+
+        let catalog_base = {
+            let mut b = dill::CatalogBuilder::new();
+
+            b.add::<DummyOutboxImpl>()
+                .add_builder(
+                    DatasetRepositoryLocalFs::builder()
+                        .with_root(datasets_dir)
+                        .with_multi_tenant(false),
+                )
+                .bind::<dyn DatasetRepository, DatasetRepositoryLocalFs>()
+                .bind::<dyn DatasetRepositoryWriter, DatasetRepositoryLocalFs>()
+                .add::<CreateDatasetFromSnapshotUseCaseImpl>()
+                .add::<SystemTimeSourceDefault>()
+                .add::<auth::AlwaysHappyDatasetActionAuthorizer>()
+                .add::<DependencyGraphServiceInMemory>()
+                .add::<DatabaseTransactionRunner>()
+                .add::<WebhookSubscriptionServiceImpl>()
+                .add_value(WebhooksConfig {
+                    secret_encryption_key: Some("QfnEDcnUtGSW2pwVXaFPvZOwxyFm2BOC".to_string()),
+                    ..WebhooksConfig::default()
+                })
+                .add::<InMemoryWebhookSubscriptionRepository>()
+                .add::<InMemoryWebhookDeliveryRepository>();
+
+            NoOpDatabasePlugin::init_database_components(&mut b);
+
+            b.build()
+        };
+
+        let (_, catalog_authorized) = authentication_catalogs(&catalog_base).await;
+
+        Self {
+            _tempdir: tempdir,
+            catalog_authorized,
+        }
+    }
+
+    async fn create_dataset(&self) -> CreateDatasetResult {
+        let create_dataset_from_snapshot = self
+            .catalog_authorized
+            .get_one::<dyn CreateDatasetFromSnapshotUseCase>()
+            .unwrap();
+
+        create_dataset_from_snapshot
+            .execute(
+                MetadataFactory::dataset_snapshot()
+                    .kind(DatasetKind::Root)
+                    .name("foo")
+                    .push_event(MetadataFactory::set_polling_source().build())
+                    .build(),
+                Default::default(),
+            )
+            .await
+            .unwrap()
+    }
+
+    async fn create_subscription(
+        &self,
+        dataset_id: &str,
+        target_url: &str,
+        event_types: &str,
+    ) -> async_graphql::Response {
+        self.execute(
+            indoc!(
+                r#"
+                mutation {
+                    datasets {
+                        byId(datasetId: "<dataset_id>") {
+                            webhooks {
+                                createSubscription(
+                                    targetUrl: "<target_url>",
+                                    label: "ci",
+                                    eventTypes: <event_types>
+                                ) {
+                                    message
+                                    ... on CreateWebhookSubscriptionResultSuccess {
+                                        subscription {
+                                            label
+                                            eventTypes
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+                "#
+            )
+            .replace("<dataset_id>", dataset_id)
+            .replace("<target_url>", target_url)
+            .replace("<event_types>", event_types),
+        )
+        .await
+    }
+
+    async fn execute(&self, query: String) -> async_graphql::Response {
+        kamu_adapter_graphql::schema_quiet()
+            .execute(async_graphql::Request::new(query).data(self.catalog_authorized.clone()))
+            .await
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
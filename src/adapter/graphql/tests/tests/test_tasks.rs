@@ -82,6 +82,7 @@ async fn test_task_get_existing() {
             dataset_id: DatasetID::new_seeded_ed25519(b"foo"),
             fetch_uncacheable: false,
         }),
+        trace_parent: None,
         created_at: Utc::now(),
         ran_at: None,
         cancellation_requested_at: None,
@@ -147,6 +148,7 @@ async fn test_task_list_by_dataset() {
             dataset_id: dataset_id.clone(),
             fetch_uncacheable: false,
         }),
+        trace_parent: None,
         created_at: Utc::now(),
         ran_at: None,
         cancellation_requested_at: None,
@@ -231,6 +233,7 @@ async fn test_task_create_probe() {
         status: TaskStatus::Queued,
         cancellation_requested: false,
         logical_plan: expected_logical_plan.clone(),
+        trace_parent: None,
         created_at: Utc::now(),
         ran_at: None,
         cancellation_requested_at: None,
@@ -16,13 +16,17 @@
 // the Business Source License, use of this software will be governed
 // by the Apache License, Version 2.0.
 
+use std::sync::Arc;
+
 use axum::extract::{Extension, Query};
 use chrono::{DateTime, Utc};
-use database_common_macros::transactional_handler;
+use database_common::DatabaseTransactionRunner;
 use dill::Catalog;
 use http::HeaderMap;
 use http_common::*;
+use internal_error::ResultIntoInternal;
 use kamu_core::*;
+use kamu_datasets::DatasetExpectationService;
 use opendatafabric::DatasetRef;
 use time_source::SystemTimeSource;
 use tokio::io::AsyncRead;
@@ -53,7 +57,6 @@ struct IngestTaskArguments {
 // until their data was processed. We may still provide a "synchronous" version
 // of push for convenience that waits for passed data to be flushed as part of
 // some block.
-#[transactional_handler]
 pub async fn dataset_ingest_handler(
     Extension(catalog): Extension<Catalog>,
     Extension(dataset_ref): Extension<DatasetRef>,
@@ -61,6 +64,22 @@ pub async fn dataset_ingest_handler(
     headers: HeaderMap,
     body_stream: axum::extract::BodyStream,
 ) -> Result<(), ApiError> {
+    // Unlike other failures, rejection by expectations commits the transaction
+    // to keep the record of failed checks, so the error is returned after that
+    DatabaseTransactionRunner::new(catalog)
+        .transactional(|catalog| async move {
+            dataset_ingest(catalog, dataset_ref, params, headers, body_stream).await
+        })
+        .await?
+}
+
+async fn dataset_ingest(
+    catalog: Catalog,
+    dataset_ref: DatasetRef,
+    params: IngestQueryParams,
+    headers: HeaderMap,
+    body_stream: axum::extract::BodyStream,
+) -> Result<Result<(), ApiError>, ApiError> {
     let is_ingest_from_upload = params.upload_token.is_some();
 
     let arguments = if let Some(upload_token) = params.upload_token {
@@ -107,8 +126,28 @@ pub async fn dataset_ingest_handler(
                 Some(time_source.now())
             });
 
+    let dataset_repo = catalog.get_one::<dyn DatasetRepository>().unwrap();
+    let dataset_handle = dataset_repo
+        .resolve_dataset_ref(&dataset_ref)
+        .await
+        .map_err(|e| match e {
+            GetDatasetError::NotFound(e) => ApiError::not_found(e),
+            GetDatasetError::Internal(e) => e.api_err(),
+        })?;
+
+    let expectation_svc = catalog.get_one::<dyn DatasetExpectationService>().unwrap();
+    let dataset_expectations = expectation_svc
+        .get_dataset_expectations(&dataset_handle.id)
+        .await
+        .int_err()
+        .api_err()?;
+
+    let expectations_checks = Arc::new(ExpectationsChecksCollector::for_dataset(
+        dataset_handle.id.clone(),
+    ));
+
     let ingest_svc = catalog.get_one::<dyn PushIngestService>().unwrap();
-    match ingest_svc
+    let ingest_result = ingest_svc
         .ingest_from_file_stream(
             &dataset_ref,
             params.source_name.as_deref(),
@@ -117,22 +156,35 @@ pub async fn dataset_ingest_handler(
                 media_type: arguments.media_type,
                 source_event_time,
                 auto_create_push_source: is_ingest_from_upload,
+                dataset_expectations,
                 schema_inference: SchemaInferenceOpts::default(),
             },
-            None,
+            Some(expectations_checks.clone()),
         )
-        .await
-    {
+        .await;
+
+    let res = match ingest_result {
         // Per note above, we're not including any extra information about the result
         // of the ingest operation at this point to accommodate async execution
         Ok(_) => Ok(()),
-        Err(PushIngestError::ReadError(e)) => Err(ApiError::bad_request(e)),
-        Err(PushIngestError::SourceNotFound(e)) => Err(ApiError::bad_request(e)),
+        Err(PushIngestError::ExpectationsFailed(e)) => Err(ApiError::bad_request(e)),
+        Err(PushIngestError::ReadError(e)) => return Err(ApiError::bad_request(e)),
+        Err(PushIngestError::SourceNotFound(e)) => return Err(ApiError::bad_request(e)),
         Err(PushIngestError::UnsupportedMediaType(_)) => {
-            Err(ApiError::new_unsupported_media_type())
+            return Err(ApiError::new_unsupported_media_type())
         }
-        Err(e) => Err(e.api_err()),
+        Err(e) => return Err(e.api_err()),
+    };
+
+    for (dataset_id, check) in expectations_checks.take() {
+        expectation_svc
+            .record_expectations_check(&dataset_id, check)
+            .await
+            .int_err()
+            .api_err()?;
     }
+
+    Ok(res)
 }
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -0,0 +1,475 @@
+// Copyright Kamu Data, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+use std::sync::Arc;
+use std::time::Duration;
+
+use axum::extract::ws::{CloseFrame, Message, WebSocket};
+use axum::extract::{Extension, Query};
+use database_common::DatabaseTransactionRunner;
+use dill::Catalog;
+use futures::TryStreamExt;
+use http_common::*;
+use internal_error::{ErrorIntoInternal, ResultIntoInternal};
+use kamu_core::*;
+use kamu_datasets::DatasetExpectationService;
+use opendatafabric::{DatasetHandle, DatasetRef, MetadataEvent, Multihash, ReadStep};
+use serde::{Deserialize, Serialize};
+use time_source::SystemTimeSource;
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[derive(Debug, Clone)]
+pub struct IngestStreamConfig {
+    /// Number of buffered records that triggers a commit
+    pub max_batch_records: u64,
+    /// Size of buffered data in bytes that triggers a commit
+    pub max_batch_bytes: usize,
+    /// Time since the first record was buffered after which a commit is
+    /// triggered
+    pub max_batch_delay: Duration,
+}
+
+impl Default for IngestStreamConfig {
+    fn default() -> Self {
+        Self {
+            max_batch_records: 10_000,
+            max_batch_bytes: 16 * 1024 * 1024,
+            max_batch_delay: Duration::from_secs(10),
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct IngestStreamQueryParams {
+    source_name: Option<String>,
+    media_type: Option<String>,
+}
+
+/// Sent to the client after the buffered records were committed
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct IngestStreamAck {
+    /// Number of records that were committed
+    pub num_records: u64,
+    /// Head of the dataset after the commit, absent when the records did not
+    /// produce any new data
+    pub new_head: Option<String>,
+    /// Offsets assigned to the new records
+    pub offset_interval: Option<IngestStreamOffsetInterval>,
+}
+
+#[derive(Debug, Serialize, Deserialize, PartialEq, Eq)]
+pub struct IngestStreamOffsetInterval {
+    pub start: u64,
+    pub end: u64,
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+// Clients send records in a line-delimited format (e.g. NDJSON or CSV without
+// a header) as text or binary messages, each containing one or more complete
+// records. Records are buffered and committed as one block when one of the
+// thresholds of `IngestStreamConfig` is reached, after which the client
+// receives an `IngestStreamAck`. Messages are not read from the socket while
+// a commit is in progress, which provides back-pressure to the client.
+//
+// Write access is checked again on every commit, and the socket is closed with
+// the policy violation code once it is revoked.
+pub async fn dataset_ingest_stream_handler(
+    ws: axum::extract::ws::WebSocketUpgrade,
+    Extension(catalog): Extension<Catalog>,
+    Extension(dataset_ref): Extension<DatasetRef>,
+    Query(params): Query<IngestStreamQueryParams>,
+) -> Result<axum::response::Response, ApiError> {
+    let media_type = params.media_type.map(MediaType);
+
+    let dataset_handle = DatabaseTransactionRunner::new(catalog.clone())
+        .transactional(|catalog| async move {
+            let dataset_repo = catalog.get_one::<dyn DatasetRepository>().unwrap();
+            let dataset_handle = dataset_repo
+                .resolve_dataset_ref(&dataset_ref)
+                .await
+                .map_err(|e| match e {
+                    GetDatasetError::NotFound(e) => ApiError::not_found(e),
+                    GetDatasetError::Internal(e) => e.api_err(),
+                })?;
+
+            let dataset_action_authorizer = catalog
+                .get_one::<dyn auth::DatasetActionAuthorizer>()
+                .unwrap();
+            dataset_action_authorizer
+                .check_action_allowed(&dataset_handle, auth::DatasetAction::Write)
+                .await
+                .map_err(|e| match e {
+                    auth::DatasetActionUnauthorizedError::Access(_) => ApiError::new_forbidden(),
+                    auth::DatasetActionUnauthorizedError::Internal(e) => e.api_err(),
+                })?;
+
+            ensure_line_delimited_format(
+                &catalog,
+                &dataset_handle,
+                params.source_name.as_deref(),
+                media_type.as_ref(),
+            )
+            .await?;
+
+            Ok::<_, ApiError>(dataset_handle)
+        })
+        .await?;
+
+    let config = catalog
+        .get_one::<IngestStreamConfig>()
+        .map(|c| c.as_ref().clone())
+        .unwrap_or_default();
+
+    Ok(ws.on_upgrade(move |socket| {
+        IngestStreamSession {
+            socket,
+            catalog,
+            dataset_handle,
+            source_name: params.source_name,
+            media_type,
+            config,
+            buffer: Vec::new(),
+            buffered_records: 0,
+        }
+        .serve()
+    }))
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+struct IngestStreamSession {
+    socket: WebSocket,
+    catalog: Catalog,
+    dataset_handle: DatasetHandle,
+    source_name: Option<String>,
+    media_type: Option<MediaType>,
+    config: IngestStreamConfig,
+    buffer: Vec<u8>,
+    buffered_records: u64,
+}
+
+impl IngestStreamSession {
+    async fn serve(mut self) {
+        match self.serve_inner().await {
+            Ok(()) => tracing::debug!("Ingest stream finished"),
+            Err(err) => {
+                tracing::error!(error = ?err, "Ingest stream aborted with error");
+
+                let (code, reason) = match &err {
+                    PushIngestError::ReadError(_)
+                    | PushIngestError::BadInputSchema(_)
+                    | PushIngestError::ExpectationsFailed(_)
+                    | PushIngestError::IncompatibleSchema(_)
+                    | PushIngestError::SourceNotFound(_)
+                    | PushIngestError::UnsupportedMediaType(_) => {
+                        (axum::extract::ws::close_code::INVALID, close_reason(&err))
+                    }
+                    PushIngestError::Access(_) => {
+                        (axum::extract::ws::close_code::POLICY, close_reason(&err))
+                    }
+                    _ => (
+                        axum::extract::ws::close_code::ERROR,
+                        "Internal error".to_string(),
+                    ),
+                };
+
+                // Ignoring the error as the client might have already disconnected
+                let _ = self
+                    .socket
+                    .send(Message::Close(Some(CloseFrame {
+                        code,
+                        reason: reason.into(),
+                    })))
+                    .await;
+            }
+        }
+    }
+
+    async fn serve_inner(&mut self) -> Result<(), PushIngestError> {
+        let mut batch_deadline = None;
+
+        loop {
+            let message = if let Some(deadline) = batch_deadline {
+                tokio::select! {
+                    message = self.socket.recv() => message,
+                    () = tokio::time::sleep_until(deadline) => {
+                        self.commit(true).await?;
+                        batch_deadline = None;
+                        continue;
+                    }
+                }
+            } else {
+                self.socket.recv().await
+            };
+
+            let data = match message {
+                Some(Ok(Message::Text(text))) => text.into_bytes(),
+                Some(Ok(Message::Binary(data))) => data,
+                Some(Ok(Message::Ping(_) | Message::Pong(_))) => continue,
+                Some(Ok(Message::Close(_)) | Err(_)) | None => {
+                    // Records received before the client went away are still committed,
+                    // but there is no one to acknowledge them to
+                    return self.commit(false).await;
+                }
+            };
+
+            self.buffer_records(&data);
+
+            if batch_deadline.is_none() && self.buffered_records != 0 {
+                batch_deadline = Some(tokio::time::Instant::now() + self.config.max_batch_delay);
+            }
+
+            if self.buffered_records >= self.config.max_batch_records
+                || self.buffer.len() >= self.config.max_batch_bytes
+            {
+                self.commit(true).await?;
+                batch_deadline = None;
+            }
+        }
+    }
+
+    fn buffer_records(&mut self, data: &[u8]) {
+        self.buffered_records += data
+            .split(|b| *b == b'\n')
+            .filter(|line| !line.trim_ascii().is_empty())
+            .count() as u64;
+
+        self.buffer.extend_from_slice(data);
+        if !data.ends_with(b"\n") {
+            self.buffer.push(b'\n');
+        }
+    }
+
+    async fn commit(&mut self, send_ack: bool) -> Result<(), PushIngestError> {
+        if self.buffered_records == 0 {
+            return Ok(());
+        }
+
+        let num_records = std::mem::take(&mut self.buffered_records);
+        let data = std::mem::take(&mut self.buffer);
+
+        tracing::debug!(
+            num_records,
+            num_bytes = data.len(),
+            "Committing buffered records"
+        );
+
+        let dataset_handle = &self.dataset_handle;
+        let source_name = self.source_name.as_deref();
+        let media_type = self.media_type.clone();
+
+        let ingest_result = DatabaseTransactionRunner::new(self.catalog.clone())
+            .transactional(|catalog| async move {
+                // Access might have been revoked since the socket was opened
+                catalog
+                    .get_one::<dyn auth::DatasetActionAuthorizer>()
+                    .int_err()?
+                    .check_action_allowed(dataset_handle, auth::DatasetAction::Write)
+                    .await?;
+
+                let expectation_svc = catalog
+                    .get_one::<dyn DatasetExpectationService>()
+                    .int_err()?;
+                let dataset_expectations = expectation_svc
+                    .get_dataset_expectations(&dataset_handle.id)
+                    .await
+                    .int_err()?;
+
+                let expectations_checks = Arc::new(ExpectationsChecksCollector::for_dataset(
+                    dataset_handle.id.clone(),
+                ));
+
+                let ingest_svc = catalog.get_one::<dyn PushIngestService>().int_err()?;
+                let ingest_result = ingest_svc
+                    .ingest_from_file_stream(
+                        &dataset_handle.as_local_ref(),
+                        source_name,
+                        Box::new(std::io::Cursor::new(data)),
+                        PushIngestOpts {
+                            media_type,
+                            source_event_time: Some(
+                                catalog.get_one::<dyn SystemTimeSource>().int_err()?.now(),
+                            ),
+                            auto_create_push_source: false,
+                            dataset_expectations,
+                            schema_inference: SchemaInferenceOpts::default(),
+                        },
+                        Some(expectations_checks.clone()),
+                    )
+                    .await;
+
+                // Checks of a rejected batch are recorded as well, so the transaction is
+                // committed when the expectations failed and the error is returned after it
+                let ingest_result = match ingest_result {
+                    Ok(res) => res,
+                    Err(PushIngestError::ExpectationsFailed(e)) => {
+                        record_expectations_checks(expectation_svc.as_ref(), &expectations_checks)
+                            .await?;
+                        return Ok(Err(e));
+                    }
+                    Err(e) => return Err(e),
+                };
+
+                record_expectations_checks(expectation_svc.as_ref(), &expectations_checks).await?;
+
+                match ingest_result {
+                    PushIngestResult::UpToDate => Ok(Ok((None, None))),
+                    PushIngestResult::Updated {
+                        old_head, new_head, ..
+                    } => {
+                        let offset_interval =
+                            get_new_offset_interval(&catalog, dataset_handle, &old_head, &new_head)
+                                .await?;
+                        Ok(Ok((Some(new_head.to_string()), offset_interval)))
+                    }
+                }
+            })
+            .await?;
+
+        let (new_head, offset_interval) = ingest_result?;
+
+        if send_ack {
+            let ack = IngestStreamAck {
+                num_records,
+                new_head,
+                offset_interval,
+            };
+
+            self.socket
+                .send(Message::Text(serde_json::to_string(&ack).int_err()?))
+                .await
+                .int_err()?;
+        }
+
+        Ok(())
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Records are split into batches on line boundaries, so only formats that can
+/// be read from any sequence of complete lines are accepted
+async fn ensure_line_delimited_format(
+    catalog: &Catalog,
+    dataset_handle: &DatasetHandle,
+    source_name: Option<&str>,
+    media_type: Option<&MediaType>,
+) -> Result<(), ApiError> {
+    let push_sources = catalog
+        .get_one::<dyn PushIngestService>()
+        .int_err()?
+        .get_active_push_sources(&dataset_handle.as_local_ref())
+        .await
+        .int_err()?;
+
+    let push_source = match source_name {
+        Some(source_name) => push_sources
+            .into_iter()
+            .find(|(_, block)| block.event.source_name == source_name),
+        None if push_sources.len() == 1 => push_sources.into_iter().next(),
+        // Missing source is reported by the first commit
+        None => None,
+    };
+
+    let data_format_registry = catalog.get_one::<dyn DataFormatRegistry>().int_err()?;
+    let read_step = match (push_source, media_type) {
+        (Some((_, block)), Some(media_type)) => {
+            data_format_registry.get_compatible_read_config(block.event.read, media_type)
+        }
+        (Some((_, block)), None) => Ok(block.event.read),
+        (None, Some(media_type)) => data_format_registry.get_best_effort_config(None, media_type),
+        (None, None) => return Ok(()),
+    }
+    .map_err(|_| ApiError::new_unsupported_media_type())?;
+
+    match read_step {
+        ReadStep::NdJson(_) | ReadStep::NdGeoJson(_) => Ok(()),
+        ReadStep::Csv(csv) if csv.header != Some(true) => Ok(()),
+        _ => Err(ApiError::new_unsupported_media_type()),
+    }
+}
+
+async fn record_expectations_checks(
+    expectation_svc: &dyn DatasetExpectationService,
+    expectations_checks: &ExpectationsChecksCollector,
+) -> Result<(), PushIngestError> {
+    for (dataset_id, check) in expectations_checks.take() {
+        expectation_svc
+            .record_expectations_check(&dataset_id, check)
+            .await
+            .int_err()?;
+    }
+    Ok(())
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Combines offsets of all data blocks added between the two heads
+async fn get_new_offset_interval(
+    catalog: &Catalog,
+    dataset_handle: &DatasetHandle,
+    old_head: &Multihash,
+    new_head: &Multihash,
+) -> Result<Option<IngestStreamOffsetInterval>, PushIngestError> {
+    let dataset_repo = catalog.get_one::<dyn DatasetRepository>().int_err()?;
+    let dataset = dataset_repo
+        .find_dataset_by_ref(&dataset_handle.as_local_ref())
+        .await
+        .int_err()?;
+
+    let blocks: Vec<_> = dataset
+        .as_metadata_chain()
+        .iter_blocks_interval(new_head, Some(old_head), false)
+        .try_collect()
+        .await
+        .int_err()?;
+
+    Ok(blocks
+        .into_iter()
+        .filter_map(|(_, block)| match block.event {
+            MetadataEvent::AddData(e) => e.new_data.map(|d| d.offset_interval),
+            _ => None,
+        })
+        .fold(None, |acc: Option<IngestStreamOffsetInterval>, i| {
+            Some(match acc {
+                None => IngestStreamOffsetInterval {
+                    start: i.start,
+                    end: i.end,
+                },
+                Some(acc) => IngestStreamOffsetInterval {
+                    start: acc.start.min(i.start),
+                    end: acc.end.max(i.end),
+                },
+            })
+        }))
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+// Close frame reason is limited to 123 bytes
+fn close_reason(err: &PushIngestError) -> String {
+    let mut reason = err.to_string();
+    if reason.len() > 123 {
+        let mut end = 120;
+        while !reason.is_char_boundary(end) {
+            end -= 1;
+        }
+        reason.truncate(end);
+        reason.push_str("...");
+    }
+    reason
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
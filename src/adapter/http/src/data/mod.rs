@@ -8,8 +8,10 @@
 // by the Apache License, Version 2.0.
 
 mod ingest_handler;
+mod ingest_stream_handler;
 mod query_handler;
 mod router;
 mod tail_handler;
 
+pub use ingest_stream_handler::{IngestStreamAck, IngestStreamConfig, IngestStreamOffsetInterval};
 pub use router::*;
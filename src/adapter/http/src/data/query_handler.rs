@@ -20,6 +20,7 @@ use std::fmt::Debug;
 
 use axum::extract::{Extension, Query};
 use axum::response::Json;
+use chrono::{DateTime, Utc};
 use database_common_macros::transactional_handler;
 use datafusion::arrow::array::RecordBatch;
 use datafusion::common::DFSchema;
@@ -259,6 +260,10 @@ pub struct QueryDatasetAlias {
 #[serde(rename_all = "camelCase", deny_unknown_fields)]
 pub struct QueryState {
     pub inputs: Vec<QueryDatasetState>,
+    /// States of datasets referenced via `dataset_at()` and `dataset_as_of()`
+    /// table functions
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub time_travel_inputs: Vec<QueryTimeTravelState>,
 }
 
 #[derive(Debug, serde::Serialize, serde::Deserialize)]
@@ -268,6 +273,21 @@ pub struct QueryDatasetState {
     pub block_hash: Multihash,
 }
 
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct QueryTimeTravelState {
+    pub id: DatasetID,
+    pub selector: QueryTimeTravelSelector,
+    pub block_hash: Multihash,
+}
+
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub enum QueryTimeTravelSelector {
+    Block(String),
+    SystemTime(DateTime<Utc>),
+}
+
 impl QueryState {
     fn to_state(&self) -> kamu_core::QueryState {
         kamu_core::QueryState {
@@ -276,6 +296,26 @@ impl QueryState {
                 .iter()
                 .map(|i| (i.id.clone(), i.block_hash.clone()))
                 .collect(),
+            time_travel_inputs: self
+                .time_travel_inputs
+                .iter()
+                .map(|i| {
+                    (
+                        kamu_core::TimeTravelInput {
+                            dataset_id: i.id.clone(),
+                            selector: match &i.selector {
+                                QueryTimeTravelSelector::Block(b) => {
+                                    kamu_core::TimeTravelSelector::Block(b.clone())
+                                }
+                                QueryTimeTravelSelector::SystemTime(t) => {
+                                    kamu_core::TimeTravelSelector::SystemTime(*t)
+                                }
+                            },
+                        },
+                        i.block_hash.clone(),
+                    )
+                })
+                .collect(),
         }
     }
 }
@@ -288,6 +328,22 @@ impl From<kamu_core::QueryState> for QueryState {
                 .into_iter()
                 .map(|(id, block_hash)| QueryDatasetState { id, block_hash })
                 .collect(),
+            time_travel_inputs: value
+                .time_travel_inputs
+                .into_iter()
+                .map(|(input, block_hash)| QueryTimeTravelState {
+                    id: input.dataset_id,
+                    selector: match input.selector {
+                        kamu_core::TimeTravelSelector::Block(b) => {
+                            QueryTimeTravelSelector::Block(b)
+                        }
+                        kamu_core::TimeTravelSelector::SystemTime(t) => {
+                            QueryTimeTravelSelector::SystemTime(t)
+                        }
+                    },
+                    block_hash,
+                })
+                .collect(),
         }
     }
 }
@@ -17,6 +17,7 @@
 // by the Apache License, Version 2.0.
 
 use super::ingest_handler::dataset_ingest_handler;
+use super::ingest_stream_handler::dataset_ingest_stream_handler;
 use super::query_handler::{dataset_query_handler, dataset_query_handler_post};
 use super::tail_handler::dataset_tail_handler;
 
@@ -35,6 +36,10 @@ pub fn dataset_router() -> axum::Router {
     axum::Router::new()
         .route("/tail", axum::routing::get(dataset_tail_handler))
         .route("/ingest", axum::routing::post(dataset_ingest_handler))
+        .route(
+            "/ingest/stream",
+            axum::routing::get(dataset_ingest_stream_handler),
+        )
 }
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
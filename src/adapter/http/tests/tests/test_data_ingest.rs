@@ -8,17 +8,27 @@
 // by the Apache License, Version 2.0.
 
 use chrono::{DateTime, TimeZone, Utc};
+use futures::{SinkExt, StreamExt};
 use indoc::indoc;
 use kamu::domain::*;
 use kamu::testing::DatasetDataHelper;
 use kamu::*;
 use kamu_accounts::DUMMY_ACCESS_TOKEN;
+use kamu_adapter_http::data::{IngestStreamAck, IngestStreamConfig, IngestStreamOffsetInterval};
 use kamu_adapter_http::{
     FileUploadLimitConfig,
     UploadServiceLocal,
     UploadToken,
     UploadTokenBase64Json,
 };
+use kamu_datasets::{
+    DatasetExpectation,
+    DatasetExpectationService,
+    ExpectationPolicy,
+    ExpectationRule,
+};
+use kamu_datasets_inmem::InMemoryDatasetExpectationRepository;
+use kamu_datasets_services::DatasetExpectationServiceImpl;
 use opendatafabric::{MergeStrategy, *};
 use serde_json::json;
 use url::Url;
@@ -586,6 +596,296 @@ async fn test_data_push_ingest_upload_token_actual_file_different_size() {
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
 
+#[test_group::group(engine, ingest, datafusion)]
+#[test_log::test(tokio::test)]
+async fn test_data_push_ingest_handler_expectations() {
+    let harness = DataIngestHarness::new();
+
+    let create_result = harness.create_population_dataset(true).await;
+
+    let expectation_svc = harness
+        .server_harness
+        .base_catalog()
+        .get_one::<dyn DatasetExpectationService>()
+        .unwrap();
+
+    expectation_svc
+        .set_dataset_expectations(
+            &create_result.dataset_handle.id,
+            vec![DatasetExpectation {
+                name: "population_known".to_string(),
+                rule: ExpectationRule::NotNull {
+                    column: "population".to_string(),
+                },
+                policy: ExpectationPolicy::Fail,
+            }],
+        )
+        .await
+        .unwrap();
+
+    let dataset_url = harness.dataset_http_url(&create_result.dataset_handle.alias);
+    let dataset_id = create_result.dataset_handle.id.clone();
+
+    let client = async move {
+        let cl = reqwest::Client::new();
+        let ingest_url = format!("{dataset_url}/ingest");
+
+        // Rejected - violates the failing expectation
+        let res = cl
+            .execute(
+                cl.post(&ingest_url)
+                    .json(&json!(
+                        [
+                            {
+                                "event_time": "2020-01-01T00:00:00",
+                                "city": "A",
+                                "population": 100,
+                            },
+                            {
+                                "event_time": "2020-01-02T00:00:00",
+                                "city": "B",
+                                "population": null,
+                            }
+                        ]
+                    ))
+                    .build()
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(res.status(), http::StatusCode::BAD_REQUEST);
+        assert_eq!(
+            "Data quality expectations failed:\n  population_known: 1 violation(s)",
+            res.text().await.unwrap()
+        );
+
+        // OK
+        let res = cl
+            .execute(
+                cl.post(&ingest_url)
+                    .json(&json!(
+                        [
+                            {
+                                "event_time": "2020-01-01T00:00:00",
+                                "city": "A",
+                                "population": 100,
+                            }
+                        ]
+                    ))
+                    .build()
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(res.status(), http::StatusCode::OK);
+
+        let checks = expectation_svc
+            .get_expectations_checks_by_dataset_id(&dataset_id, None)
+            .await
+            .unwrap();
+        assert_eq!(checks.total_count, 2);
+
+        // Rejected slice is recorded too, without advancing the head
+        let (rejected, accepted): (Vec<_>, Vec<_>) =
+            checks.list.iter().partition(|c| c.is_rejected());
+        assert_eq!(rejected.len(), 1);
+        assert_eq!(rejected[0].num_records, 2);
+        assert!(rejected[0].new_head.is_none());
+        assert_eq!(accepted.len(), 1);
+        assert_eq!(accepted[0].num_records, 1);
+        assert!(accepted[0].new_head.is_some());
+    };
+
+    await_client_server_flow!(harness.server_harness.api_server_run(), client);
+}
+
+#[test_group::group(engine, ingest, datafusion)]
+#[test_log::test(tokio::test)]
+async fn test_data_push_ingest_stream_handler() {
+    use tokio_tungstenite::tungstenite::Message;
+
+    let harness = DataIngestHarness::new();
+
+    let create_result = harness.create_population_dataset(true).await;
+
+    let dataset_url = harness
+        .server_harness
+        .dataset_url_with_scheme(&create_result.dataset_handle.alias, "ws");
+
+    let client = async move {
+        let dataset_helper = DatasetDataHelper::new(create_result.dataset.clone());
+        let ingest_url = format!("{dataset_url}/ingest/stream");
+        tracing::info!(%ingest_url, "Client request");
+
+        let (mut ws, _) = tokio_tungstenite::connect_async(&ingest_url).await.unwrap();
+
+        async fn recv_ack(
+            ws: &mut (impl futures::Stream<Item = Result<Message, tokio_tungstenite::tungstenite::Error>>
+                      + Unpin),
+        ) -> IngestStreamAck {
+            match ws.next().await.unwrap().unwrap() {
+                Message::Text(text) => serde_json::from_str(&text).unwrap(),
+                msg => panic!("Unexpected message: {msg:?}"),
+            }
+        }
+
+        // Batch is committed once the records threshold is reached
+        ws.send(Message::Text(
+            json!({"event_time": "2020-01-01T00:00:00", "city": "A", "population": 100})
+                .to_string(),
+        ))
+        .await
+        .unwrap();
+        ws.send(Message::Text(
+            json!({"event_time": "2020-01-02T00:00:00", "city": "B", "population": 200})
+                .to_string(),
+        ))
+        .await
+        .unwrap();
+
+        let ack = recv_ack(&mut ws).await;
+        assert_eq!(ack.num_records, 2);
+        assert!(ack.new_head.is_some());
+        assert_eq!(
+            ack.offset_interval,
+            Some(IngestStreamOffsetInterval { start: 0, end: 1 })
+        );
+
+        // Multiple records can be sent in one message
+        ws.send(Message::Text(format!(
+            "{}\n{}\n",
+            json!({"event_time": "2020-01-03T00:00:00", "city": "C", "population": 300}),
+            json!({"event_time": "2020-01-04T00:00:00", "city": "D", "population": 400}),
+        )))
+        .await
+        .unwrap();
+
+        let ack = recv_ack(&mut ws).await;
+        assert_eq!(ack.num_records, 2);
+        assert_eq!(
+            ack.offset_interval,
+            Some(IngestStreamOffsetInterval { start: 2, end: 3 })
+        );
+
+        ws.close(None).await.unwrap();
+
+        dataset_helper
+            .assert_last_data_records_eq(indoc!(
+                r#"
+                +--------+----+----------------------+----------------------+------+------------+
+                | offset | op | system_time          | event_time           | city | population |
+                +--------+----+----------------------+----------------------+------+------------+
+                | 2      | 0  | 2050-01-01T12:00:00Z | 2020-01-03T00:00:00Z | C    | 300        |
+                | 3      | 0  | 2050-01-01T12:00:00Z | 2020-01-04T00:00:00Z | D    | 400        |
+                +--------+----+----------------------+----------------------+------+------------+
+                "#
+            ))
+            .await;
+    };
+
+    await_client_server_flow!(harness.server_harness.api_server_run(), client);
+}
+
+#[test_group::group(engine, ingest, datafusion)]
+#[test_log::test(tokio::test)]
+async fn test_data_push_ingest_stream_handler_rejects_non_line_delimited_formats() {
+    let harness = DataIngestHarness::new();
+
+    let create_result = harness.create_population_dataset(true).await;
+
+    let dataset_url = harness
+        .server_harness
+        .dataset_url_with_scheme(&create_result.dataset_handle.alias, "ws");
+
+    let client = async move {
+        for media_type in ["application/json", "application/vnd.apache.parquet"] {
+            let ingest_url = format!("{dataset_url}/ingest/stream?mediaType={media_type}");
+
+            match tokio_tungstenite::connect_async(&ingest_url).await {
+                Err(tokio_tungstenite::tungstenite::Error::Http(res)) => {
+                    assert_eq!(res.status(), http::StatusCode::UNSUPPORTED_MEDIA_TYPE);
+                }
+                res => panic!("Unexpected result for {media_type}: {res:?}"),
+            }
+        }
+
+        // Line-delimited format is accepted
+        let ingest_url = format!("{dataset_url}/ingest/stream?mediaType=application/x-ndjson");
+        let (mut ws, _) = tokio_tungstenite::connect_async(&ingest_url).await.unwrap();
+        ws.close(None).await.unwrap();
+    };
+
+    await_client_server_flow!(harness.server_harness.api_server_run(), client);
+}
+
+#[test_group::group(engine, ingest, datafusion)]
+#[test_log::test(tokio::test)]
+async fn test_data_push_ingest_stream_handler_expectations() {
+    use tokio_tungstenite::tungstenite::protocol::frame::coding::CloseCode;
+    use tokio_tungstenite::tungstenite::Message;
+
+    let harness = DataIngestHarness::new();
+
+    let create_result = harness.create_population_dataset(true).await;
+
+    let expectation_svc = harness
+        .server_harness
+        .base_catalog()
+        .get_one::<dyn DatasetExpectationService>()
+        .unwrap();
+
+    expectation_svc
+        .set_dataset_expectations(
+            &create_result.dataset_handle.id,
+            vec![DatasetExpectation {
+                name: "population_known".to_string(),
+                rule: ExpectationRule::NotNull {
+                    column: "population".to_string(),
+                },
+                policy: ExpectationPolicy::Fail,
+            }],
+        )
+        .await
+        .unwrap();
+
+    let dataset_url = harness
+        .server_harness
+        .dataset_url_with_scheme(&create_result.dataset_handle.alias, "ws");
+    let dataset_id = create_result.dataset_handle.id.clone();
+
+    let client = async move {
+        let ingest_url = format!("{dataset_url}/ingest/stream");
+        let (mut ws, _) = tokio_tungstenite::connect_async(&ingest_url).await.unwrap();
+
+        ws.send(Message::Text(format!(
+            "{}\n{}\n",
+            json!({"event_time": "2020-01-01T00:00:00", "city": "A", "population": 100}),
+            json!({"event_time": "2020-01-02T00:00:00", "city": "B", "population": null}),
+        )))
+        .await
+        .unwrap();
+
+        match ws.next().await.unwrap().unwrap() {
+            Message::Close(Some(frame)) => assert_eq!(frame.code, CloseCode::Invalid),
+            msg => panic!("Unexpected message: {msg:?}"),
+        }
+
+        // Rejected batch is recorded despite the failed commit
+        let checks = expectation_svc
+            .get_expectations_checks_by_dataset_id(&dataset_id, None)
+            .await
+            .unwrap();
+        assert_eq!(checks.total_count, 1);
+        assert!(checks.list[0].is_rejected());
+        assert_eq!(checks.list[0].num_records, 2);
+        assert!(checks.list[0].new_head.is_none());
+    };
+
+    await_client_server_flow!(harness.server_harness.api_server_run(), client);
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
 struct DataIngestHarness {
     pub server_harness: ServerSideLocalFsHarness,
     pub system_time: DateTime<Utc>,
@@ -598,7 +898,13 @@ impl DataIngestHarness {
             .add::<PushIngestServiceImpl>()
             .add::<EngineProvisionerNull>()
             .add::<UploadServiceLocal>()
+            .add::<DatasetExpectationServiceImpl>()
+            .add::<InMemoryDatasetExpectationRepository>()
             .add_value(FileUploadLimitConfig::new_in_bytes(1000))
+            .add_value(IngestStreamConfig {
+                max_batch_records: 2,
+                ..Default::default()
+            })
             .build();
 
         let server_harness = ServerSideLocalFsHarness::new(ServerSideHarnessOptions {
@@ -101,6 +101,7 @@ impl Harness {
                     source_event_time: system_time,
                     new_watermark: None,
                     new_source_state: None,
+                    retract_previous: None,
                     data_staging_path: run_info_dir.path().join(".temp-data"),
                 },
             )
@@ -8,4 +8,7 @@
 // by the Apache License, Version 2.0.
 
 pub mod oauth_github;
+pub mod oauth_oidc;
+
 pub use oauth_github::*;
+pub use oauth_oidc::*;
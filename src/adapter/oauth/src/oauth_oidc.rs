@@ -0,0 +1,827 @@
+// Copyright Kamu Data, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+use dill::*;
+use internal_error::{ErrorIntoInternal, InternalError, ResultIntoInternal};
+use jsonwebtoken::jwk::JwkSet;
+use jsonwebtoken::{Algorithm, DecodingKey, Validation};
+use kamu_accounts::*;
+use opendatafabric::{AccountID, AccountName};
+use serde::Deserialize;
+use serde_json::Value;
+use thiserror::Error;
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+pub const PROVIDER_OIDC: &str = "oidc";
+
+const GRANT_TYPE_AUTHORIZATION_CODE: &str = "authorization_code";
+const GRANT_TYPE_DEVICE_CODE: &str = "urn:ietf:params:oauth:grant-type:device_code";
+
+/// How long the provider metadata and signing keys are cached before being
+/// fetched again, so that changes on the provider side are picked up
+const PROVIDER_CACHE_TTL: Duration = Duration::from_secs(60 * 60);
+
+/// Minimal interval between fetching signing keys when a token is signed with
+/// an unknown key, e.g. after key rotation
+const JWKS_MIN_REFRESH_INTERVAL: Duration = Duration::from_secs(60);
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Authentication via a generic OpenID Connect provider (e.g. Keycloak, Okta).
+///
+/// Supported login credentials:
+///  - authorization code obtained by the web UI, optionally with the PKCE code
+///    verifier
+///  - access token issued by the provider, e.g. obtained by the CLI via the
+///    device authorization flow
+///
+/// Tokens are only accepted when issued to the configured client (or one of
+/// the allowed audiences): the ID token is validated against the provider's
+/// signing keys, and a bare access token is checked via token introspection.
+/// The identity is then resolved via the provider's `userinfo` endpoint and
+/// mapped to an account using the configured claims.
+pub struct OAuthOidc {
+    config: Arc<OidcAuthenticationConfig>,
+    provider_metadata: Mutex<Option<Cached<OidcProviderMetadata>>>,
+    jwks: Mutex<Option<Cached<JwkSet>>>,
+}
+
+struct Cached<T> {
+    value: Arc<T>,
+    fetched_at: Instant,
+}
+
+impl<T> Cached<T> {
+    fn new(value: T) -> Self {
+        Self {
+            value: Arc::new(value),
+            fetched_at: Instant::now(),
+        }
+    }
+
+    fn is_older_than(&self, age: Duration) -> bool {
+        self.fetched_at.elapsed() >= age
+    }
+}
+
+#[component(pub)]
+#[interface(dyn AuthenticationProvider)]
+#[scope(Singleton)]
+impl OAuthOidc {
+    pub fn new(config: Arc<OidcAuthenticationConfig>) -> Self {
+        Self {
+            config,
+            provider_metadata: Mutex::new(None),
+            jwks: Mutex::new(None),
+        }
+    }
+
+    async fn get_provider_metadata(
+        &self,
+        client: &OidcClient,
+    ) -> Result<Arc<OidcProviderMetadata>, InternalError> {
+        if let Some(cached) = self.provider_metadata.lock().unwrap().as_ref()
+            && !cached.is_older_than(PROVIDER_CACHE_TTL)
+        {
+            return Ok(cached.value.clone());
+        }
+
+        let cached = Cached::new(client.discover(&self.config.issuer_url).await?);
+        let provider_metadata = cached.value.clone();
+        self.provider_metadata.lock().unwrap().replace(cached);
+
+        Ok(provider_metadata)
+    }
+
+    /// Returns the signing keys of the provider, fetching them again if the
+    /// cache expired or the token refers to a key that is not known yet
+    async fn get_jwks(
+        &self,
+        client: &OidcClient,
+        provider_metadata: &OidcProviderMetadata,
+        key_id: Option<&str>,
+    ) -> Result<Arc<JwkSet>, InternalError> {
+        if let Some(cached) = self.jwks.lock().unwrap().as_ref() {
+            let has_key = key_id.map_or(true, |kid| cached.value.find(kid).is_some());
+            if !cached.is_older_than(PROVIDER_CACHE_TTL)
+                && (has_key || !cached.is_older_than(JWKS_MIN_REFRESH_INTERVAL))
+            {
+                return Ok(cached.value.clone());
+            }
+        }
+
+        let cached = Cached::new(client.get_jwks(provider_metadata).await?);
+        let jwks = cached.value.clone();
+        self.jwks.lock().unwrap().replace(cached);
+
+        Ok(jwks)
+    }
+
+    fn is_allowed_audience(&self, audience: &str) -> bool {
+        audience == self.config.client_id
+            || self.config.allowed_audiences.iter().any(|a| a == audience)
+    }
+
+    /// Validates the signature, issuer, audience and expiration of the ID token
+    /// and returns its subject
+    async fn validate_id_token(
+        &self,
+        client: &OidcClient,
+        provider_metadata: &OidcProviderMetadata,
+        id_token: &str,
+    ) -> Result<String, ProviderLoginError> {
+        let header = jsonwebtoken::decode_header(id_token).map_err(invalid_credentials)?;
+
+        // Tokens must be signed with the provider's asymmetric keys
+        if matches!(
+            header.alg,
+            Algorithm::HS256 | Algorithm::HS384 | Algorithm::HS512
+        ) {
+            return Err(invalid_credentials(OidcTokenError::new(format!(
+                "ID token signing algorithm {:?} is not supported",
+                header.alg
+            ))));
+        }
+
+        let jwks = self
+            .get_jwks(client, provider_metadata, header.kid.as_deref())
+            .await?;
+
+        let jwk = match &header.kid {
+            Some(kid) => jwks.find(kid),
+            None if jwks.keys.len() == 1 => jwks.keys.first(),
+            None => None,
+        }
+        .ok_or_else(|| {
+            invalid_credentials(OidcTokenError::new(
+                "ID token is signed with an unknown key",
+            ))
+        })?;
+        let key = DecodingKey::from_jwk(jwk).map_err(invalid_credentials)?;
+
+        let mut audiences = vec![self.config.client_id.as_str()];
+        audiences.extend(self.config.allowed_audiences.iter().map(String::as_str));
+
+        let mut validation = Validation::new(header.alg);
+        validation.set_issuer(&[&provider_metadata.issuer]);
+        validation.set_audience(&audiences);
+        validation.set_required_spec_claims(&["exp", "iss", "aud", "sub"]);
+
+        let claims = jsonwebtoken::decode::<OidcIdTokenClaims>(id_token, &key, &validation)
+            .map_err(invalid_credentials)?
+            .claims;
+
+        // Authorized party identifies the client the token was issued to when the
+        // token has multiple audiences
+        if let Some(azp) = &claims.azp
+            && !self.is_allowed_audience(azp)
+        {
+            return Err(invalid_credentials(OidcTokenError::new(format!(
+                "ID token was issued to an unexpected party '{azp}'"
+            ))));
+        }
+
+        Ok(claims.sub)
+    }
+
+    /// Checks that the access token is active and was issued to an allowed
+    /// client via token introspection and returns its subject
+    async fn introspect_access_token(
+        &self,
+        client: &OidcClient,
+        provider_metadata: &OidcProviderMetadata,
+        access_token: &str,
+    ) -> Result<String, ProviderLoginError> {
+        if provider_metadata.introspection_endpoint.is_none() {
+            return Err(invalid_credentials(OidcTokenError::new(
+                "Access token cannot be verified: pass the ID token along with it, as the \
+                 provider does not support token introspection",
+            )));
+        }
+
+        let introspection = client
+            .introspect_token(provider_metadata, &self.config, access_token)
+            .await?;
+
+        if !introspection.active {
+            return Err(invalid_credentials(OidcTokenError::new(
+                "Access token is not active",
+            )));
+        }
+
+        let issued_to_us = introspection
+            .client_id
+            .iter()
+            .chain(introspection.aud.iter())
+            .any(|a| self.is_allowed_audience(a));
+        if !issued_to_us {
+            return Err(invalid_credentials(OidcTokenError::new(
+                "Access token was issued to a different client",
+            )));
+        }
+
+        introspection.sub.ok_or_else(|| {
+            invalid_credentials(OidcTokenError::new(
+                "Token introspection did not return the subject",
+            ))
+        })
+    }
+
+    fn account_from_claims(
+        &self,
+        claims: &serde_json::Map<String, Value>,
+    ) -> Result<ProviderLoginResponse, OidcClaimError> {
+        let claims_config = &self.config.claims;
+
+        let get_str = |claim: &str| claims.get(claim).and_then(Value::as_str);
+
+        let subject = get_str("sub").ok_or_else(|| OidcClaimError::missing("sub"))?;
+
+        let account_name = get_str(&claims_config.account_name)
+            .ok_or_else(|| OidcClaimError::missing(&claims_config.account_name))?;
+        let account_name = AccountName::try_from(account_name).map_err(|_| {
+            OidcClaimError::invalid(
+                &claims_config.account_name,
+                format!("'{account_name}' is not a valid account name"),
+            )
+        })?;
+
+        let display_name = get_str(&claims_config.display_name)
+            .map_or_else(|| account_name.to_string(), ToString::to_string);
+
+        // Privileges are only managed by the provider when the admin group is set
+        let is_admin = claims_config.admin_group.as_ref().map(|admin_group| {
+            match claims.get(&claims_config.groups) {
+                Some(Value::Array(groups)) => groups
+                    .iter()
+                    .any(|g| g.as_str() == Some(admin_group.as_str())),
+                Some(Value::String(group)) => group == admin_group,
+                _ => false,
+            }
+        });
+
+        Ok(ProviderLoginResponse {
+            account_name,
+            account_type: AccountType::User,
+            email: get_str(&claims_config.email).map(ToString::to_string),
+            display_name,
+            avatar_url: get_str(&claims_config.avatar_url).map(ToString::to_string),
+            // Subject is the stable identifier of the user only within the issuer, so
+            // it's namespaced to not collide with keys of GitHub or password accounts.
+            // Issuer URL is not included as keys are limited to 100 characters.
+            provider_identity_key: format!("{PROVIDER_OIDC}|{subject}"),
+            is_admin,
+        })
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[async_trait::async_trait]
+impl AuthenticationProvider for OAuthOidc {
+    fn provider_name(&self) -> &'static str {
+        PROVIDER_OIDC
+    }
+
+    fn generate_id(&self, _: &AccountName) -> AccountID {
+        // As with other OAuth providers, generate a random DID
+        AccountID::new_generated_ed25519().1
+    }
+
+    async fn login(
+        &self,
+        login_credentials_json: String,
+    ) -> Result<ProviderLoginResponse, ProviderLoginError> {
+        // Decode credentials
+        let oidc_login_credentials =
+            serde_json::from_str::<OidcLoginCredentials>(login_credentials_json.as_str())
+                .map_err(invalid_credentials)?;
+
+        let client = OidcClient::new()?;
+        let provider_metadata = self.get_provider_metadata(&client).await?;
+
+        // 2 types of login:
+        //  - we have an authorization code, which we exchange for the access token (UI
+        //    flow), the token response is expected to carry the ID token
+        //  - we have an access token already, e.g. obtained by the CLI via device
+        //    authorization flow (silent login flow), optionally with the ID token
+        let (access_token, id_token) = if let Some(code) = oidc_login_credentials.code {
+            let token = client
+                .exchange_authorization_code(
+                    &provider_metadata,
+                    &self.config,
+                    &code,
+                    oidc_login_credentials.redirect_uri.as_deref(),
+                    oidc_login_credentials.code_verifier.as_deref(),
+                )
+                .await?;
+
+            let Some(id_token) = token.id_token else {
+                return Err(invalid_credentials(OidcTokenError::new(
+                    "Provider did not return the ID token, ensure 'openid' scope is requested",
+                )));
+            };
+            (token.access_token, Some(id_token))
+        } else if let Some(access_token) = oidc_login_credentials.access_token {
+            (access_token, oidc_login_credentials.id_token)
+        } else {
+            // Either "code" or "access_token" are expected in the query
+            return Err(invalid_credentials(OidcInvalidCredentialsError {}));
+        };
+
+        // Any client of the provider can obtain tokens for the user, so ensure they
+        // were issued to us before trusting them
+        let subject = if let Some(id_token) = &id_token {
+            self.validate_id_token(&client, &provider_metadata, id_token)
+                .await?
+        } else {
+            self.introspect_access_token(&client, &provider_metadata, &access_token)
+                .await?
+        };
+
+        let claims = client
+            .get_user_info(&provider_metadata, &access_token)
+            .await?;
+
+        if claims.get("sub").and_then(Value::as_str) != Some(subject.as_str()) {
+            return Err(invalid_credentials(OidcTokenError::new(
+                "Subject of the user info does not match the token",
+            )));
+        }
+
+        self.account_from_claims(&claims)
+            .map_err(invalid_credentials)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Thin client of the OpenID Connect provider endpoints used both by the
+/// server-side provider and by the CLI device authorization flow
+pub struct OidcClient {
+    client: reqwest::Client,
+}
+
+impl OidcClient {
+    pub fn new() -> Result<Self, InternalError> {
+        let client = reqwest::Client::builder()
+            .user_agent(concat!(
+                env!("CARGO_PKG_NAME"),
+                "/",
+                env!("CARGO_PKG_VERSION"),
+            ))
+            .build()
+            .int_err()?;
+
+        Ok(Self { client })
+    }
+
+    /// Reads provider metadata from the `.well-known/openid-configuration`
+    /// document of the issuer
+    pub async fn discover(&self, issuer_url: &str) -> Result<OidcProviderMetadata, InternalError> {
+        let discovery_url = format!(
+            "{}/.well-known/openid-configuration",
+            issuer_url.trim_end_matches('/')
+        );
+
+        let provider_metadata = self
+            .client
+            .get(&discovery_url)
+            .header(http::header::ACCEPT, "application/json")
+            .send()
+            .await
+            .int_err()?
+            .error_for_status()
+            .int_err()?
+            .json::<OidcProviderMetadata>()
+            .await
+            .int_err()?;
+
+        if provider_metadata.issuer.trim_end_matches('/') != issuer_url.trim_end_matches('/') {
+            return Err(format!(
+                "Issuer '{}' in the provider metadata does not match the configured issuer '{}'",
+                provider_metadata.issuer, issuer_url
+            )
+            .int_err());
+        }
+
+        Ok(provider_metadata)
+    }
+
+    pub async fn exchange_authorization_code(
+        &self,
+        provider_metadata: &OidcProviderMetadata,
+        config: &OidcAuthenticationConfig,
+        code: &str,
+        redirect_uri: Option<&str>,
+        code_verifier: Option<&str>,
+    ) -> Result<OidcTokenResponse, OidcRequestError> {
+        let mut params = vec![
+            ("grant_type", GRANT_TYPE_AUTHORIZATION_CODE),
+            ("client_id", config.client_id.as_str()),
+            ("code", code),
+        ];
+        if let Some(client_secret) = &config.client_secret {
+            params.push(("client_secret", client_secret.as_str()));
+        }
+        if let Some(redirect_uri) = redirect_uri {
+            params.push(("redirect_uri", redirect_uri));
+        }
+        if let Some(code_verifier) = code_verifier {
+            params.push(("code_verifier", code_verifier));
+        }
+
+        self.post_form(&provider_metadata.token_endpoint, &params)
+            .await
+    }
+
+    /// Starts the device authorization flow ([RFC 8628](https://www.rfc-editor.org/rfc/rfc8628))
+    pub async fn request_device_authorization(
+        &self,
+        provider_metadata: &OidcProviderMetadata,
+        client_id: &str,
+        scopes: &[String],
+    ) -> Result<OidcDeviceAuthorization, OidcRequestError> {
+        let Some(device_authorization_endpoint) = &provider_metadata.device_authorization_endpoint
+        else {
+            return Err(OidcRequestError::Internal(
+                "Provider does not support device authorization flow".int_err(),
+            ));
+        };
+
+        let scope = scopes.join(" ");
+
+        self.post_form(
+            device_authorization_endpoint,
+            &[("client_id", client_id), ("scope", scope.as_str())],
+        )
+        .await
+    }
+
+    /// Polls the token endpoint during the device authorization flow.
+    ///
+    /// While the user did not complete the authorization the provider responds
+    /// with `authorization_pending` or `slow_down` errors.
+    pub async fn exchange_device_code(
+        &self,
+        provider_metadata: &OidcProviderMetadata,
+        client_id: &str,
+        device_code: &str,
+    ) -> Result<OidcTokenResponse, OidcRequestError> {
+        self.post_form(
+            &provider_metadata.token_endpoint,
+            &[
+                ("grant_type", GRANT_TYPE_DEVICE_CODE),
+                ("client_id", client_id),
+                ("device_code", device_code),
+            ],
+        )
+        .await
+    }
+
+    pub async fn get_jwks(
+        &self,
+        provider_metadata: &OidcProviderMetadata,
+    ) -> Result<JwkSet, InternalError> {
+        self.client
+            .get(&provider_metadata.jwks_uri)
+            .header(http::header::ACCEPT, "application/json")
+            .send()
+            .await
+            .int_err()?
+            .error_for_status()
+            .int_err()?
+            .json::<JwkSet>()
+            .await
+            .int_err()
+    }
+
+    /// Token introspection ([RFC 7662](https://www.rfc-editor.org/rfc/rfc7662))
+    pub async fn introspect_token(
+        &self,
+        provider_metadata: &OidcProviderMetadata,
+        config: &OidcAuthenticationConfig,
+        token: &str,
+    ) -> Result<OidcTokenIntrospection, OidcRequestError> {
+        let Some(introspection_endpoint) = &provider_metadata.introspection_endpoint else {
+            return Err(OidcRequestError::Internal(
+                "Provider does not expose the introspection endpoint".int_err(),
+            ));
+        };
+
+        let mut params = vec![
+            ("token", token),
+            ("token_type_hint", "access_token"),
+            ("client_id", config.client_id.as_str()),
+        ];
+        if let Some(client_secret) = &config.client_secret {
+            params.push(("client_secret", client_secret.as_str()));
+        }
+
+        self.post_form(introspection_endpoint, &params).await
+    }
+
+    pub async fn get_user_info(
+        &self,
+        provider_metadata: &OidcProviderMetadata,
+        access_token: &str,
+    ) -> Result<serde_json::Map<String, Value>, OidcRequestError> {
+        let Some(userinfo_endpoint) = &provider_metadata.userinfo_endpoint else {
+            return Err(OidcRequestError::Internal(
+                "Provider does not expose the userinfo endpoint".int_err(),
+            ));
+        };
+
+        let response = self
+            .client
+            .get(userinfo_endpoint)
+            .bearer_auth(access_token)
+            .header(http::header::ACCEPT, "application/json")
+            .send()
+            .await
+            .int_err()?;
+
+        if response.status() == http::StatusCode::UNAUTHORIZED
+            || response.status() == http::StatusCode::FORBIDDEN
+        {
+            return Err(OidcRequestError::Rejected(OidcErrorResponse {
+                error: String::from("invalid_token"),
+                error_description: None,
+            }));
+        }
+
+        Ok(response
+            .error_for_status()
+            .int_err()?
+            .json::<serde_json::Map<String, Value>>()
+            .await
+            .int_err()?)
+    }
+
+    async fn post_form<T: serde::de::DeserializeOwned>(
+        &self,
+        url: &str,
+        params: &[(&str, &str)],
+    ) -> Result<T, OidcRequestError> {
+        let response = self
+            .client
+            .post(url)
+            .header(http::header::ACCEPT, "application/json")
+            .form(params)
+            .send()
+            .await
+            .int_err()?;
+
+        let status = response.status();
+        let body = response.text().await.int_err()?;
+
+        if status.is_success() {
+            Ok(serde_json::from_str::<T>(&body).int_err()?)
+        } else if let Ok(error_response) = serde_json::from_str::<OidcErrorResponse>(&body) {
+            Err(OidcRequestError::Rejected(error_response))
+        } else {
+            Err(OidcRequestError::Internal(
+                format!("Unexpected response from OIDC provider: {status} {body}").int_err(),
+            ))
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OidcLoginCredentials {
+    pub code: Option<String>,
+    /// PKCE code verifier matching the challenge used in authorization request
+    pub code_verifier: Option<String>,
+    /// Redirect URI used in authorization request
+    pub redirect_uri: Option<String>,
+    pub access_token: Option<String>,
+    /// ID token issued along with the access token, used to verify that the
+    /// access token was issued to this server's client
+    pub id_token: Option<String>,
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct OidcProviderMetadata {
+    pub issuer: String,
+    pub authorization_endpoint: String,
+    pub token_endpoint: String,
+    pub userinfo_endpoint: Option<String>,
+    pub device_authorization_endpoint: Option<String>,
+    pub introspection_endpoint: Option<String>,
+    pub jwks_uri: String,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct OidcTokenResponse {
+    pub access_token: String,
+    pub token_type: String,
+    pub id_token: Option<String>,
+    pub expires_in: Option<u64>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct OidcTokenIntrospection {
+    pub active: bool,
+    pub sub: Option<String>,
+    pub client_id: Option<String>,
+    #[serde(default, deserialize_with = "deserialize_audience")]
+    pub aud: Vec<String>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+struct OidcIdTokenClaims {
+    sub: String,
+    azp: Option<String>,
+}
+
+/// Audience can be represented either as a single string or as an array
+fn deserialize_audience<'de, D: serde::Deserializer<'de>>(
+    deserializer: D,
+) -> Result<Vec<String>, D::Error> {
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum Audience {
+        One(String),
+        Many(Vec<String>),
+    }
+
+    Ok(match Option::<Audience>::deserialize(deserializer)? {
+        None => Vec::new(),
+        Some(Audience::One(aud)) => vec![aud],
+        Some(Audience::Many(aud)) => aud,
+    })
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct OidcDeviceAuthorization {
+    pub device_code: String,
+    pub user_code: String,
+    pub verification_uri: String,
+    pub verification_uri_complete: Option<String>,
+    pub expires_in: u64,
+    pub interval: Option<u64>,
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[derive(Debug, Error)]
+pub enum OidcRequestError {
+    #[error(transparent)]
+    Rejected(#[from] OidcErrorResponse),
+
+    #[error(transparent)]
+    Internal(#[from] InternalError),
+}
+
+impl From<OidcRequestError> for ProviderLoginError {
+    fn from(value: OidcRequestError) -> Self {
+        match value {
+            OidcRequestError::Rejected(e) => {
+                Self::InvalidCredentials(InvalidCredentialsError::new(Box::new(e)))
+            }
+            OidcRequestError::Internal(e) => Self::Internal(e),
+        }
+    }
+}
+
+/// Error response of the provider as defined by OAuth 2.0 spec
+#[derive(Debug, Clone, Deserialize)]
+pub struct OidcErrorResponse {
+    pub error: String,
+    pub error_description: Option<String>,
+}
+
+impl std::fmt::Display for OidcErrorResponse {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "OIDC provider rejected the request: {}", self.error)?;
+        if let Some(error_description) = &self.error_description {
+            write!(f, " ({error_description})")?;
+        }
+        Ok(())
+    }
+}
+
+impl std::error::Error for OidcErrorResponse {}
+
+fn invalid_credentials(e: impl std::error::Error + Send + Sync + 'static) -> ProviderLoginError {
+    ProviderLoginError::InvalidCredentials(InvalidCredentialsError::new(Box::new(e)))
+}
+
+#[derive(Debug, Error)]
+#[error("Invalid credentials: pass either authorization code or access token")]
+struct OidcInvalidCredentialsError {}
+
+#[derive(Debug, Error)]
+#[error("{message}")]
+struct OidcTokenError {
+    message: String,
+}
+
+impl OidcTokenError {
+    fn new(message: impl Into<String>) -> Self {
+        Self {
+            message: message.into(),
+        }
+    }
+}
+
+#[derive(Debug, Error)]
+#[error("Claim '{claim}' {reason}")]
+struct OidcClaimError {
+    claim: String,
+    reason: String,
+}
+
+impl OidcClaimError {
+    fn missing(claim: &str) -> Self {
+        Self {
+            claim: claim.to_string(),
+            reason: String::from("is missing"),
+        }
+    }
+
+    fn invalid(claim: &str, reason: String) -> Self {
+        Self {
+            claim: claim.to_string(),
+            reason,
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[derive(Debug, Clone)]
+pub struct OidcAuthenticationConfig {
+    /// URL of the issuer used to discover the provider metadata
+    pub issuer_url: String,
+    pub client_id: String,
+    /// Secret of a confidential client, not needed for public clients using
+    /// PKCE
+    pub client_secret: Option<String>,
+    /// Scopes requested during the authorization
+    pub scopes: Vec<String>,
+    /// Identifiers of other clients whose tokens are accepted for login, e.g. a
+    /// public client used by the CLI. Tokens issued to `client_id` are always
+    /// accepted.
+    pub allowed_audiences: Vec<String>,
+    /// Mapping of the claims to account properties
+    pub claims: OidcClaimsConfig,
+}
+
+impl OidcAuthenticationConfig {
+    pub fn new(issuer_url: impl Into<String>, client_id: impl Into<String>) -> Self {
+        Self {
+            issuer_url: issuer_url.into(),
+            client_id: client_id.into(),
+            client_secret: None,
+            scopes: ["openid", "profile", "email"]
+                .into_iter()
+                .map(String::from)
+                .collect(),
+            allowed_audiences: Vec::new(),
+            claims: OidcClaimsConfig::default(),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct OidcClaimsConfig {
+    pub account_name: String,
+    pub display_name: String,
+    pub email: String,
+    pub avatar_url: String,
+    /// Claim containing the list of groups the user belongs to
+    pub groups: String,
+    /// Members of this group are registered as administrators
+    pub admin_group: Option<String>,
+}
+
+impl Default for OidcClaimsConfig {
+    fn default() -> Self {
+        Self {
+            account_name: String::from("preferred_username"),
+            display_name: String::from("name"),
+            email: String::from("email"),
+            avatar_url: String::from("picture"),
+            groups: String::from("groups"),
+            admin_group: None,
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
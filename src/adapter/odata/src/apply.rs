@@ -0,0 +1,292 @@
+// Copyright Kamu Data, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+//! Support for a subset of the OData Data Aggregation extension (`$apply`
+//! query option).
+//!
+//! Supported transformations, chained with `/`:
+//! - `groupby((col1,col2))`
+//! - `groupby((col1,col2),aggregate(...))`
+//! - `aggregate(col with sum|min|max|average|countdistinct as alias, $count as
+//!   alias)`
+
+use datafusion::arrow::datatypes::DataType;
+use datafusion::common::Column;
+use datafusion::dataframe::DataFrame;
+use datafusion::error::DataFusionError;
+use datafusion::functions_aggregate::expr_fn::{avg, count, count_distinct, max, min, sum};
+use datafusion::logical_expr::window_function::row_number;
+use datafusion::logical_expr::ExprFunctionExt;
+use datafusion::prelude::*;
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) enum ApplyTransformation {
+    GroupBy {
+        columns: Vec<String>,
+        aggregates: Vec<AggregateExpr>,
+    },
+    Aggregate(Vec<AggregateExpr>),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) enum AggregateExpr {
+    Column {
+        column: String,
+        method: AggregateMethod,
+        alias: String,
+    },
+    Count {
+        alias: String,
+    },
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum AggregateMethod {
+    Sum,
+    Min,
+    Max,
+    Average,
+    CountDistinct,
+}
+
+#[derive(Debug)]
+pub(crate) struct UnsupportedApplyError {
+    pub expression: String,
+}
+
+impl std::fmt::Display for UnsupportedApplyError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Unsupported $apply expression: {}", self.expression)
+    }
+}
+
+impl std::error::Error for UnsupportedApplyError {}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// Parsing
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+pub(crate) fn parse_apply(apply: &str) -> Result<Vec<ApplyTransformation>, UnsupportedApplyError> {
+    split_top_level(apply, '/')
+        .into_iter()
+        .map(parse_transformation)
+        .collect()
+}
+
+fn parse_transformation(s: &str) -> Result<ApplyTransformation, UnsupportedApplyError> {
+    let unsupported = || UnsupportedApplyError {
+        expression: s.to_string(),
+    };
+
+    if let Some(args) = strip_call(s, "groupby") {
+        let args = split_top_level(args, ',');
+        let (columns, aggregates) = match args.as_slice() {
+            [columns] => (*columns, None),
+            [columns, aggregates] => (*columns, Some(*aggregates)),
+            _ => return Err(unsupported()),
+        };
+
+        let columns = columns
+            .strip_prefix('(')
+            .and_then(|s| s.strip_suffix(')'))
+            .ok_or_else(unsupported)?
+            .split(',')
+            .map(|c| parse_identifier(c).ok_or_else(unsupported))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let aggregates = match aggregates {
+            None => Vec::new(),
+            Some(a) => parse_aggregates(strip_call(a, "aggregate").ok_or_else(unsupported)?)?,
+        };
+
+        Ok(ApplyTransformation::GroupBy {
+            columns,
+            aggregates,
+        })
+    } else if let Some(args) = strip_call(s, "aggregate") {
+        Ok(ApplyTransformation::Aggregate(parse_aggregates(args)?))
+    } else {
+        Err(unsupported())
+    }
+}
+
+fn parse_aggregates(s: &str) -> Result<Vec<AggregateExpr>, UnsupportedApplyError> {
+    split_top_level(s, ',')
+        .into_iter()
+        .map(|a| {
+            parse_aggregate(a).ok_or_else(|| UnsupportedApplyError {
+                expression: a.to_string(),
+            })
+        })
+        .collect()
+}
+
+fn parse_aggregate(s: &str) -> Option<AggregateExpr> {
+    let tokens: Vec<_> = s.split_whitespace().collect();
+    match tokens.as_slice() {
+        ["$count", "as", alias] => Some(AggregateExpr::Count {
+            alias: parse_identifier(alias)?,
+        }),
+        [column, "with", method, "as", alias] => {
+            let method = match *method {
+                "sum" => AggregateMethod::Sum,
+                "min" => AggregateMethod::Min,
+                "max" => AggregateMethod::Max,
+                "average" => AggregateMethod::Average,
+                "countdistinct" => AggregateMethod::CountDistinct,
+                _ => return None,
+            };
+            Some(AggregateExpr::Column {
+                column: parse_identifier(column)?,
+                method,
+                alias: parse_identifier(alias)?,
+            })
+        }
+        _ => None,
+    }
+}
+
+fn parse_identifier(s: &str) -> Option<String> {
+    let s = s.trim();
+    if !s.is_empty() && s.chars().all(|c| c.is_alphanumeric() || c == '_') {
+        Some(s.to_string())
+    } else {
+        None
+    }
+}
+
+/// Returns arguments of a `name(args)` call expression
+fn strip_call<'a>(s: &'a str, name: &str) -> Option<&'a str> {
+    s.trim()
+        .strip_prefix(name)?
+        .trim_start()
+        .strip_prefix('(')?
+        .strip_suffix(')')
+}
+
+/// Splits the string by separator ignoring the occurrences within parentheses
+fn split_top_level(s: &str, sep: char) -> Vec<&str> {
+    let mut parts = Vec::new();
+    let mut depth = 0i32;
+    let mut start = 0;
+
+    for (i, c) in s.char_indices() {
+        match c {
+            '(' => depth += 1,
+            ')' => depth -= 1,
+            c if c == sep && depth == 0 => {
+                parts.push(s[start..i].trim());
+                start = i + c.len_utf8();
+            }
+            _ => {}
+        }
+    }
+    parts.push(s[start..].trim());
+    parts
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// Planning
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Applies transformations to the data frame.
+///
+/// Aggregated entities no longer have the offset column that is used as their
+/// key, so the result is numbered and the sequence number is stored in the
+/// `key_column`.
+pub(crate) fn apply_transformations(
+    mut df: DataFrame,
+    transformations: &[ApplyTransformation],
+    key_column: &str,
+) -> datafusion::error::Result<DataFrame> {
+    let mut order_by = Vec::new();
+
+    for transformation in transformations {
+        df = match transformation {
+            ApplyTransformation::GroupBy {
+                columns,
+                aggregates,
+            } => {
+                order_by = columns
+                    .iter()
+                    .map(|c| column(c).sort(true, false))
+                    .collect();
+
+                df.aggregate(
+                    columns.iter().map(|c| column(c)).collect(),
+                    aggregates.iter().map(aggregate_expr).collect(),
+                )?
+            }
+            ApplyTransformation::Aggregate(aggregates) => {
+                order_by = Vec::new();
+
+                df.aggregate(Vec::new(), aggregates.iter().map(aggregate_expr).collect())?
+            }
+        };
+    }
+
+    if df.schema().has_column_with_unqualified_name(key_column) {
+        return Err(DataFusionError::Plan(format!(
+            "Column '{key_column}' cannot be used in $apply results"
+        )));
+    }
+
+    const ROW_NUMBER_COLUMN: &str = "__row_number__";
+
+    let columns: Vec<_> = df
+        .schema()
+        .fields()
+        .iter()
+        .map(|f| column(f.name()))
+        .collect();
+
+    let row_number = if order_by.is_empty() {
+        row_number()
+    } else {
+        row_number().order_by(order_by).build()?
+    };
+
+    let df = df.window(vec![row_number.alias(ROW_NUMBER_COLUMN)])?;
+
+    let mut select =
+        vec![cast(column(ROW_NUMBER_COLUMN) - lit(1u64), DataType::Int64).alias(key_column)];
+    select.extend(columns);
+
+    df.select(select)
+}
+
+fn aggregate_expr(aggregate: &AggregateExpr) -> Expr {
+    match aggregate {
+        AggregateExpr::Column {
+            column: c,
+            method,
+            alias,
+        } => {
+            let c = column(c);
+            match method {
+                AggregateMethod::Sum => sum(c),
+                AggregateMethod::Min => min(c),
+                AggregateMethod::Max => max(c),
+                AggregateMethod::Average => avg(c),
+                AggregateMethod::CountDistinct => count_distinct(c),
+            }
+            .alias(alias)
+        }
+        AggregateExpr::Count { alias } => count(lit(1)).alias(alias),
+    }
+}
+
+/// Unlike [`col()`] does not normalize or split the name
+fn column(name: &str) -> Expr {
+    Expr::Column(Column::from_name(name))
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
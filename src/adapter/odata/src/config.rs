@@ -0,0 +1,31 @@
+// Copyright Kamu Data, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[derive(Debug, Clone)]
+pub struct ODataConfig {
+    /// Number of records returned per page when client does not specify
+    /// `$top`
+    pub default_records_per_page: usize,
+    /// Maximum number of records returned per page regardless of `$top`.
+    /// When results are truncated a `next` link is added to the feed.
+    pub max_records_per_page: usize,
+}
+
+impl Default for ODataConfig {
+    fn default() -> Self {
+        Self {
+            default_records_per_page: 100,
+            max_records_per_page: 10_000,
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
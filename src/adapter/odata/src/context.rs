@@ -16,24 +16,28 @@
 // the Business Source License, use of this software will be governed
 // by the Apache License, Version 2.0.
 
+use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::Arc;
 
 use axum::async_trait;
 use chrono::{DateTime, Utc};
 use datafusion::arrow::datatypes::{Schema, SchemaRef};
 use datafusion::dataframe::DataFrame;
+use datafusion::datasource::MemTable;
+use datafusion::prelude::SessionContext;
 use datafusion_odata::collection::{CollectionAddr, QueryParams};
 use datafusion_odata::context::{CollectionContext, OnUnsupported, ServiceContext};
 use dill::Catalog;
 use internal_error::ResultIntoInternal;
+use kamu_core::auth::{DatasetAction, DatasetActionAuthorizer};
 use kamu_core::*;
 use opendatafabric::*;
 
+use crate::apply::*;
+use crate::config::ODataConfig;
+
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
 
-// TODO: Externalize config
-const DEFAULT_RECORDS_PER_PAGE: usize = 100;
-const MAX_RECORDS_PER_PAGE: usize = usize::MAX;
 const KEY_COLUMN_ALIAS: &str = "__id__";
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -57,7 +61,6 @@ impl ODataServiceContext {
     }
 }
 
-// TODO: Authorization checks
 #[async_trait]
 impl ServiceContext for ODataServiceContext {
     fn service_base_url(&self) -> String {
@@ -68,6 +71,7 @@ impl ServiceContext for ODataServiceContext {
         use futures::TryStreamExt;
 
         let repo: Arc<dyn DatasetRepository> = self.catalog.get_one().unwrap();
+        let authorizer: Arc<dyn DatasetActionAuthorizer> = self.catalog.get_one().unwrap();
 
         let datasets = if let Some(account_name) = &self.account_name {
             repo.get_datasets_by_owner(account_name)
@@ -79,18 +83,25 @@ impl ServiceContext for ODataServiceContext {
 
         let mut collections: Vec<Arc<dyn CollectionContext>> = Vec::new();
         for dataset_handle in datasets {
+            if authorizer
+                .check_action_allowed(&dataset_handle, DatasetAction::Read)
+                .await
+                .is_err()
+            {
+                continue;
+            }
+
             let dataset = repo.get_dataset_by_handle(&dataset_handle);
 
-            collections.push(Arc::new(ODataCollectionContext {
-                catalog: self.catalog.clone(),
-                addr: CollectionAddr {
+            collections.push(Arc::new(ODataCollectionContext::new(
+                self.catalog.clone(),
+                CollectionAddr {
                     name: dataset_handle.alias.dataset_name.to_string(),
                     key: None,
                 },
                 dataset_handle,
                 dataset,
-                service_base_url: self.service_base_url.clone(),
-            }));
+            )));
         }
 
         collections
@@ -109,6 +120,10 @@ pub(crate) struct ODataCollectionContext {
     dataset_handle: DatasetHandle,
     dataset: Arc<dyn Dataset>,
     service_base_url: String,
+    config: ODataConfig,
+    apply: Vec<ApplyTransformation>,
+    next_page_probe: Option<usize>,
+    has_next_page: AtomicBool,
 }
 
 impl ODataCollectionContext {
@@ -118,8 +133,16 @@ impl ODataCollectionContext {
         dataset_handle: DatasetHandle,
         dataset: Arc<dyn Dataset>,
     ) -> Self {
-        let config = catalog.get_one::<ServerUrlConfig>().unwrap();
-        let service_base_url = config.protocols.odata_base_url();
+        let service_base_url = catalog
+            .get_one::<ServerUrlConfig>()
+            .unwrap()
+            .protocols
+            .odata_base_url();
+
+        let config = catalog
+            .get_one::<ODataConfig>()
+            .map(|c| c.as_ref().clone())
+            .unwrap_or_default();
 
         Self {
             catalog,
@@ -127,8 +150,82 @@ impl ODataCollectionContext {
             dataset_handle,
             dataset,
             service_base_url,
+            config,
+            apply: Vec::new(),
+            next_page_probe: None,
+            has_next_page: AtomicBool::new(false),
         }
     }
+
+    /// Sets aggregation transformations that are applied to the data before
+    /// any other query options
+    pub(crate) fn with_apply(self, apply: Vec<ApplyTransformation>) -> Self {
+        Self { apply, ..self }
+    }
+
+    /// Makes queries fetch one record beyond the page of the specified size to
+    /// find out whether the next page exists without counting all records
+    pub(crate) fn with_next_page_probe(self, page_size: usize) -> Self {
+        Self {
+            next_page_probe: Some(page_size),
+            ..self
+        }
+    }
+
+    /// Whether the last query had more records than fit into the page, see
+    /// [`Self::with_next_page_probe()`]
+    pub(crate) fn has_next_page(&self) -> bool {
+        self.has_next_page.load(Ordering::Relaxed)
+    }
+
+    pub(crate) fn config(&self) -> &ODataConfig {
+        &self.config
+    }
+
+    /// Returns the number of entities matching the query ignoring the paging
+    pub(crate) async fn count(&self, query: QueryParams) -> datafusion::error::Result<usize> {
+        let df = self.query_impl(query, usize::MAX, usize::MAX).await?;
+        df.count().await
+    }
+
+    async fn query_impl(
+        &self,
+        query: QueryParams,
+        default_records_per_page: usize,
+        max_records_per_page: usize,
+    ) -> datafusion::error::Result<DataFrame> {
+        let vocab: DatasetVocabulary = self
+            .dataset
+            .as_metadata_chain()
+            .accept_one(SearchSetVocabVisitor::new())
+            .await
+            .map_err(|e| datafusion::error::DataFusionError::External(e.into()))?
+            .into_event()
+            .map(Into::into)
+            .unwrap_or_default();
+
+        let query_svc: Arc<dyn QueryService> = self.catalog.get_one().unwrap();
+
+        let df = query_svc
+            .get_data(&self.dataset_handle.as_local_ref())
+            .await
+            .unwrap();
+
+        let df = if self.apply.is_empty() {
+            df
+        } else {
+            apply_transformations(df, &self.apply, &vocab.offset_column)?
+        };
+
+        query.apply(
+            df,
+            &self.addr,
+            &vocab.offset_column,
+            KEY_COLUMN_ALIAS,
+            default_records_per_page,
+            max_records_per_page,
+        )
+    }
 }
 
 #[async_trait]
@@ -194,37 +291,37 @@ impl CollectionContext for ODataCollectionContext {
     }
 
     async fn query(&self, query: QueryParams) -> datafusion::error::Result<DataFrame> {
-        // TODO: Convert into config value
-        let default_records_per_page: usize = std::env::var("KAMU_ODATA_DEFAULT_RECORDS_PER_PAGE")
-            .ok()
-            .and_then(|s| s.parse().ok())
-            .unwrap_or(DEFAULT_RECORDS_PER_PAGE);
-
-        let vocab: DatasetVocabulary = self
-            .dataset
-            .as_metadata_chain()
-            .accept_one(SearchSetVocabVisitor::new())
-            .await
-            .map_err(|e| datafusion::error::DataFusionError::External(e.into()))?
-            .into_event()
-            .map(Into::into)
-            .unwrap_or_default();
-
-        let query_svc: Arc<dyn QueryService> = self.catalog.get_one().unwrap();
+        let Some(page_size) = self.next_page_probe else {
+            return self
+                .query_impl(
+                    query,
+                    self.config.default_records_per_page,
+                    self.config.max_records_per_page,
+                )
+                .await;
+        };
 
-        let df = query_svc
-            .get_data(&self.dataset_handle.as_local_ref())
-            .await
-            .unwrap();
+        let df = self.query_impl(query, page_size + 1, page_size + 1).await?;
+        let (state, plan) = df.into_parts();
+        let df = DataFrame::new(state.clone(), plan);
+        let schema = df.schema().inner().clone();
+
+        // Keep only the records of the page
+        let mut num_records = 0;
+        let mut batches = Vec::new();
+        for batch in df.collect().await? {
+            let take = batch.num_rows().min(page_size - num_records);
+            if take < batch.num_rows() {
+                self.has_next_page.store(true, Ordering::Relaxed);
+            }
+            if take != 0 {
+                batches.push(batch.slice(0, take));
+                num_records += take;
+            }
+        }
 
-        query.apply(
-            df,
-            &self.addr,
-            &vocab.offset_column,
-            KEY_COLUMN_ALIAS,
-            default_records_per_page,
-            MAX_RECORDS_PER_PAGE,
-        )
+        let table = MemTable::try_new(schema, vec![batches])?;
+        SessionContext::new_with_state(state).read_table(Arc::new(table))
     }
 
     fn on_unsupported_feature(&self) -> OnUnsupported {
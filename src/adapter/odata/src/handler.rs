@@ -23,9 +23,12 @@ use database_common_macros::transactional_handler;
 use datafusion_odata::collection::{CollectionAddr, QueryParamsRaw};
 use dill::Catalog;
 use http_common::ApiError;
+use internal_error::{InternalError, ResultIntoInternal};
+use kamu_core::auth::{DatasetAction, DatasetActionAuthorizer, DatasetActionUnauthorizedError};
 use kamu_core::*;
 use opendatafabric::*;
 
+use crate::apply::parse_apply;
 use crate::context::*;
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -73,8 +76,19 @@ pub async fn odata_collection_handler_st(
     axum::extract::Path(collection_addr): axum::extract::Path<String>,
     headers: axum::http::HeaderMap,
     query: axum::extract::Query<QueryParamsRaw>,
+    axum::extract::Query(ext_params): axum::extract::Query<ODataQueryExtParams>,
+    axum::extract::RawQuery(raw_query): axum::extract::RawQuery,
 ) -> Result<axum::response::Response<String>, ApiError> {
-    odata_collection_handler_common(catalog, None, collection_addr, headers, query).await
+    odata_collection_handler_common(
+        catalog,
+        None,
+        collection_addr,
+        headers,
+        query,
+        ext_params,
+        raw_query,
+    )
+    .await
 }
 
 #[transactional_handler]
@@ -86,11 +100,54 @@ pub async fn odata_collection_handler_mt(
     )>,
     headers: axum::http::HeaderMap,
     query: axum::extract::Query<QueryParamsRaw>,
+    axum::extract::Query(ext_params): axum::extract::Query<ODataQueryExtParams>,
+    axum::extract::RawQuery(raw_query): axum::extract::RawQuery,
 ) -> Result<axum::response::Response<String>, ApiError> {
-    odata_collection_handler_common(catalog, Some(account_name), collection_addr, headers, query)
+    odata_collection_handler_common(
+        catalog,
+        Some(account_name),
+        collection_addr,
+        headers,
+        query,
+        ext_params,
+        raw_query,
+    )
+    .await
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[transactional_handler]
+pub async fn odata_collection_count_handler_st(
+    Extension(catalog): Extension<Catalog>,
+    axum::extract::Path(collection_addr): axum::extract::Path<String>,
+    axum::extract::Query(ext_params): axum::extract::Query<ODataQueryExtParams>,
+    axum::extract::RawQuery(raw_query): axum::extract::RawQuery,
+) -> Result<axum::response::Response<String>, ApiError> {
+    odata_collection_count_handler_common(catalog, None, collection_addr, ext_params, raw_query)
         .await
 }
 
+#[transactional_handler]
+pub async fn odata_collection_count_handler_mt(
+    Extension(catalog): Extension<Catalog>,
+    axum::extract::Path((account_name, collection_addr)): axum::extract::Path<(
+        AccountName,
+        String,
+    )>,
+    axum::extract::Query(ext_params): axum::extract::Query<ODataQueryExtParams>,
+    axum::extract::RawQuery(raw_query): axum::extract::RawQuery,
+) -> Result<axum::response::Response<String>, ApiError> {
+    odata_collection_count_handler_common(
+        catalog,
+        Some(account_name),
+        collection_addr,
+        ext_params,
+        raw_query,
+    )
+    .await
+}
+
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
 // Handlers Common
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -127,8 +184,121 @@ pub async fn odata_collection_handler_common(
     collection_addr: String,
     headers: axum::http::HeaderMap,
     query: axum::extract::Query<QueryParamsRaw>,
+    ext_params: ODataQueryExtParams,
+    raw_query: Option<String>,
 ) -> Result<axum::response::Response<String>, ApiError> {
-    let Some(addr) = CollectionAddr::decode(&collection_addr) else {
+    let ctx =
+        resolve_collection_context(catalog, account_name, &collection_addr, &ext_params).await?;
+
+    // Single entity requests are not paged
+    let is_entity = ctx.addr().key.is_some();
+
+    let config = ctx.config();
+    let skip = ext_params.skip.unwrap_or(0);
+    let page_size = ext_params
+        .top
+        .unwrap_or(config.default_records_per_page)
+        .min(config.max_records_per_page);
+    let server_paged = !is_entity && ext_params.top.map_or(true, |top| top > page_size);
+
+    let ctx = Arc::new(if server_paged {
+        ctx.with_next_page_probe(page_size)
+    } else {
+        ctx
+    });
+
+    let total_count = if !is_entity && ext_params.count_requested() {
+        Some(count_entities(&ctx, raw_query.as_deref()).await?)
+    } else {
+        None
+    };
+
+    let response = datafusion_odata::handlers::odata_collection_handler(
+        Extension(ctx.clone()),
+        query,
+        headers,
+    )
+    .await;
+
+    if response.status() != http::StatusCode::OK {
+        return Ok(response);
+    }
+
+    let next_link = if server_paged && ctx.has_next_page() {
+        let next_query = query_with_paging(
+            raw_query.as_deref(),
+            skip + page_size,
+            ext_params.top.map(|top| top - page_size),
+        );
+        Some(format!("{}?{}", ctx.collection_base_url(), next_query))
+    } else {
+        None
+    };
+
+    if total_count.is_none() && next_link.is_none() {
+        return Ok(response);
+    }
+
+    let (parts, body) = response.into_parts();
+    let body = write_feed_extensions(&body, total_count, next_link.as_deref())?;
+
+    Ok(axum::response::Response::from_parts(parts, body))
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+pub async fn odata_collection_count_handler_common(
+    catalog: Catalog,
+    account_name: Option<AccountName>,
+    collection_addr: String,
+    ext_params: ODataQueryExtParams,
+    raw_query: Option<String>,
+) -> Result<axum::response::Response<String>, ApiError> {
+    let ctx =
+        resolve_collection_context(catalog, account_name, &collection_addr, &ext_params).await?;
+
+    let count = count_entities(&ctx, raw_query.as_deref()).await?;
+
+    Ok(axum::response::Response::builder()
+        .header(http::header::CONTENT_TYPE, "text/plain;charset=utf-8")
+        .body(count.to_string())
+        .unwrap())
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// Helpers
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Query options that are handled by the adapter itself rather than passed
+/// through to `datafusion-odata`
+#[derive(Debug, Default, serde::Deserialize)]
+pub struct ODataQueryExtParams {
+    #[serde(rename = "$apply")]
+    apply: Option<String>,
+    #[serde(rename = "$count")]
+    count: Option<bool>,
+    /// OData v3 equivalent of `$count=true`
+    #[serde(rename = "$inlinecount")]
+    inline_count: Option<String>,
+    #[serde(rename = "$skip")]
+    skip: Option<usize>,
+    #[serde(rename = "$top")]
+    top: Option<usize>,
+}
+
+impl ODataQueryExtParams {
+    fn count_requested(&self) -> bool {
+        self.count == Some(true) || self.inline_count.as_deref() == Some("allpages")
+    }
+}
+
+async fn resolve_collection_context(
+    catalog: Catalog,
+    account_name: Option<AccountName>,
+    collection_addr: &str,
+    ext_params: &ODataQueryExtParams,
+) -> Result<ODataCollectionContext, ApiError> {
+    let Some(addr) = CollectionAddr::decode(collection_addr) else {
         return Err(ApiError::not_found_without_body());
     };
 
@@ -150,15 +320,132 @@ pub async fn odata_collection_handler_common(
     }
     .unwrap();
 
+    // Datasets that the user cannot read are reported as not found to avoid
+    // leaking their existence
+    let authorizer: Arc<dyn DatasetActionAuthorizer> = catalog.get_one().unwrap();
+    match authorizer
+        .check_action_allowed(&dataset_handle, DatasetAction::Read)
+        .await
+    {
+        Ok(()) => {}
+        Err(DatasetActionUnauthorizedError::Access(_)) => {
+            return Err(ApiError::not_found_without_body());
+        }
+        Err(DatasetActionUnauthorizedError::Internal(e)) => return Err(e.into()),
+    }
+
+    let apply = match &ext_params.apply {
+        None => Vec::new(),
+        Some(apply) => parse_apply(apply).map_err(ApiError::bad_request)?,
+    };
+
     let dataset = repo.get_dataset_by_handle(&dataset_handle);
 
-    let ctx = ODataCollectionContext::new(catalog, addr, dataset_handle, dataset);
-    let response = datafusion_odata::handlers::odata_collection_handler(
-        Extension(Arc::new(ctx)),
-        query,
-        headers,
-    )
-    .await;
+    Ok(ODataCollectionContext::new(catalog, addr, dataset_handle, dataset).with_apply(apply))
+}
 
-    Ok(response)
+/// Counts entities matching the query options ignoring the paging ones
+async fn count_entities(
+    ctx: &ODataCollectionContext,
+    raw_query: Option<&str>,
+) -> Result<usize, ApiError> {
+    let uri: http::Uri = format!("/?{}", query_without_paging(raw_query))
+        .parse()
+        .int_err()?;
+
+    let query = axum::extract::Query::<QueryParamsRaw>::try_from_uri(&uri)
+        .map_err(ApiError::bad_request)?
+        .0
+        .decode();
+
+    Ok(ctx.count(query).await.int_err()?)
+}
+
+fn is_paging_param(param: &str) -> bool {
+    let key = param.split('=').next().unwrap_or_default();
+    let key = key.replace("%24", "$");
+    key == "$skip" || key == "$top"
+}
+
+fn query_without_paging(raw_query: Option<&str>) -> String {
+    raw_query
+        .unwrap_or_default()
+        .split('&')
+        .filter(|p| !p.is_empty() && !is_paging_param(p))
+        .collect::<Vec<_>>()
+        .join("&")
+}
+
+fn query_with_paging(raw_query: Option<&str>, skip: usize, top: Option<usize>) -> String {
+    let mut params = vec![query_without_paging(raw_query), format!("$skip={skip}")];
+    if let Some(top) = top {
+        params.push(format!("$top={top}"));
+    }
+    params.retain(|p| !p.is_empty());
+    params.join("&")
+}
+
+/// Re-writes the feed adding the inline count element after its self link and
+/// the link to the next page as its last element
+fn write_feed_extensions(
+    body: &str,
+    count: Option<usize>,
+    next_link: Option<&str>,
+) -> Result<String, InternalError> {
+    use quick_xml::events::{BytesEnd, BytesStart, BytesText, Event};
+
+    let mut reader = quick_xml::Reader::from_str(body);
+    let mut writer = quick_xml::Writer::new(Vec::new());
+    let mut depth = 0;
+
+    loop {
+        match reader.read_event().int_err()? {
+            Event::Eof => break,
+            Event::Start(e) => {
+                depth += 1;
+                writer.write_event(Event::Start(e)).int_err()?;
+            }
+            Event::End(e) => {
+                if depth == 1
+                    && e.name().as_ref() == b"feed"
+                    && let Some(href) = next_link
+                {
+                    writer
+                        .write_event(Event::Empty(
+                            BytesStart::new("link")
+                                .with_attributes([("rel", "next"), ("href", href)]),
+                        ))
+                        .int_err()?;
+                }
+                depth -= 1;
+                writer.write_event(Event::End(e)).int_err()?;
+            }
+            Event::Empty(e) => {
+                let is_feed_self_link = depth == 1
+                    && e.name().as_ref() == b"link"
+                    && e.try_get_attribute("rel")
+                        .int_err()?
+                        .is_some_and(|rel| rel.value.as_ref() == b"self");
+
+                writer.write_event(Event::Empty(e)).int_err()?;
+
+                if is_feed_self_link && let Some(count) = count {
+                    writer
+                        .write_event(Event::Start(BytesStart::new("m:count")))
+                        .int_err()?;
+                    writer
+                        .write_event(Event::Text(BytesText::new(&count.to_string())))
+                        .int_err()?;
+                    writer
+                        .write_event(Event::End(BytesEnd::new("m:count")))
+                        .int_err()?;
+                }
+            }
+            e => writer.write_event(e).int_err()?,
+        }
+    }
+
+    String::from_utf8(writer.into_inner()).int_err()
 }
+
+////////
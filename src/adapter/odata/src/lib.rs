@@ -16,7 +16,11 @@
 // the Business Source License, use of this software will be governed
 // by the Apache License, Version 2.0.
 
+mod apply;
+mod config;
 mod context;
 mod handler;
 mod router;
+
+pub use config::*;
 pub use router::*;
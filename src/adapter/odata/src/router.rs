@@ -26,6 +26,10 @@ pub fn router_single_tenant() -> axum::Router {
             "/:dataset_name",
             axum::routing::get(odata_collection_handler_st),
         )
+        .route(
+            "/:dataset_name/$count",
+            axum::routing::get(odata_collection_count_handler_st),
+        )
 }
 
 pub fn router_multi_tenant() -> axum::Router {
@@ -46,4 +50,8 @@ pub fn router_multi_tenant() -> axum::Router {
             "/:account_name/:dataset_name",
             axum::routing::get(odata_collection_handler_mt),
         )
+        .route(
+            "/:account_name/:dataset_name/$count",
+            axum::routing::get(odata_collection_count_handler_mt),
+        )
 }
@@ -17,6 +17,7 @@ use kamu::domain::*;
 use kamu::testing::*;
 use kamu::*;
 use kamu_accounts::CurrentAccountSubject;
+use kamu_adapter_odata::ODataConfig;
 use messaging_outbox::DummyOutboxImpl;
 use opendatafabric::*;
 use time_source::{SystemTimeSource, SystemTimeSourceStub};
@@ -311,6 +312,250 @@ async fn test_collection_handler_by_id_not_found() {
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
 
+#[test_group::group(engine, datafusion)]
+#[test_log::test(tokio::test)]
+async fn test_service_handler_hides_unauthorized_datasets() {
+    let harness = TestHarness::new_with_authorizer(
+        MockDatasetActionAuthorizer::new().expect_check_read_a_dataset(1, false),
+    );
+
+    harness.create_simple_dataset().await;
+
+    let service_url = format!("http://{}/odata", harness.api_server.local_addr());
+
+    let client = async move {
+        let cl = reqwest::Client::new();
+        let res = cl.get(&service_url).send().await.unwrap();
+        assert_eq!(res.status(), http::StatusCode::OK);
+
+        let body = res.text().await.unwrap();
+        assert!(!body.contains("foo.bar"), "{body}");
+    };
+
+    await_client_server_flow!(harness.api_server.run(), client);
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[test_group::group(engine, datafusion)]
+#[test_log::test(tokio::test)]
+async fn test_collection_handler_unauthorized() {
+    let harness = TestHarness::new_with_authorizer(
+        MockDatasetActionAuthorizer::new().expect_check_read_a_dataset(2, false),
+    );
+
+    harness.create_simple_dataset().await;
+
+    let collection_url = format!("http://{}/odata/foo.bar", harness.api_server.local_addr());
+    let count_url = format!(
+        "http://{}/odata/foo.bar/$count",
+        harness.api_server.local_addr()
+    );
+
+    let client = async move {
+        let cl = reqwest::Client::new();
+
+        let res = cl.get(&collection_url).send().await.unwrap();
+        assert_eq!(res.status(), http::StatusCode::NOT_FOUND);
+
+        let res = cl.get(&count_url).send().await.unwrap();
+        assert_eq!(res.status(), http::StatusCode::NOT_FOUND);
+    };
+
+    await_client_server_flow!(harness.api_server.run(), client);
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[test_group::group(engine, datafusion)]
+#[test_log::test(tokio::test)]
+async fn test_collection_handler_paging() {
+    let harness = TestHarness::new_with_config(ODataConfig {
+        default_records_per_page: 2,
+        max_records_per_page: 2,
+    });
+
+    harness.create_simple_dataset().await;
+
+    let collection_url = format!("http://{}/odata/foo.bar", harness.api_server.local_addr());
+
+    let client = async move {
+        let cl = reqwest::Client::new();
+
+        // First page is truncated by the server
+        let res = cl.get(&collection_url).send().await.unwrap();
+        assert_eq!(res.status(), http::StatusCode::OK);
+
+        let body = res.text().await.unwrap();
+        assert_eq!(body.matches("<entry>").count(), 2);
+        assert!(
+            body.ends_with(
+                r#"<link rel="next" href="http://example.com/odata/foo.bar?$skip=2"/></feed>"#
+            ),
+            "{body}"
+        );
+
+        // Client asks for more than the server is willing to return in one page
+        let res = cl
+            .get(format!("{collection_url}?$top=3&$count=true"))
+            .send()
+            .await
+            .unwrap();
+        assert_eq!(res.status(), http::StatusCode::OK);
+
+        let body = res.text().await.unwrap();
+        assert_eq!(body.matches("<entry>").count(), 2);
+        assert!(
+            body.contains(
+                r#"<link rel="self" title="foo.bar" href="foo.bar"/><m:count>3</m:count>"#
+            ),
+            "{body}"
+        );
+        assert!(
+            body.ends_with(
+                r#"<link rel="next" href="http://example.com/odata/foo.bar?$count=true&amp;$skip=2&amp;$top=1"/></feed>"#
+            ),
+            "{body}"
+        );
+
+        // Last page
+        let res = cl
+            .get(format!("{collection_url}?$skip=2"))
+            .send()
+            .await
+            .unwrap();
+        assert_eq!(res.status(), http::StatusCode::OK);
+
+        let body = res.text().await.unwrap();
+        assert_eq!(body.matches("<entry>").count(), 1);
+        assert!(!body.contains(r#"rel="next""#), "{body}");
+    };
+
+    await_client_server_flow!(harness.api_server.run(), client);
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[test_group::group(engine, datafusion)]
+#[test_log::test(tokio::test)]
+async fn test_collection_count_handler() {
+    let harness = TestHarness::new();
+
+    harness.create_simple_dataset().await;
+
+    let count_url = format!(
+        "http://{}/odata/foo.bar/$count",
+        harness.api_server.local_addr()
+    );
+
+    let client = async move {
+        let cl = reqwest::Client::new();
+
+        let res = cl.get(&count_url).send().await.unwrap();
+        assert_eq!(res.status(), http::StatusCode::OK);
+        assert_eq!(res.headers()["content-type"], "text/plain;charset=utf-8");
+        assert_eq!(res.text().await.unwrap(), "3");
+
+        let res = cl
+            .get(format!("{count_url}?$filter=population%20gt%201000&$top=1"))
+            .send()
+            .await
+            .unwrap();
+        assert_eq!(res.status(), http::StatusCode::OK);
+        assert_eq!(res.text().await.unwrap(), "2");
+    };
+
+    await_client_server_flow!(harness.api_server.run(), client);
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[test_group::group(engine, datafusion)]
+#[test_log::test(tokio::test)]
+async fn test_collection_handler_apply() {
+    let harness = TestHarness::new();
+
+    harness.create_simple_dataset().await;
+
+    let collection_url = format!("http://{}/odata/foo.bar", harness.api_server.local_addr());
+
+    let client = async move {
+        let cl = reqwest::Client::new();
+
+        let res = cl
+            .get(format!(
+                "{collection_url}?$apply=aggregate(population%20with%20sum%20as%20total,$count%\
+                 20as%20n)"
+            ))
+            .send()
+            .await
+            .unwrap();
+        assert_eq!(res.status(), http::StatusCode::OK);
+
+        let body = res.text().await.unwrap();
+        assert_eq!(body.matches("<entry>").count(), 1);
+        assert!(
+            body.contains(
+                indoc!(
+                    r#"
+                    <m:properties>
+                    <d:offset m:type="Edm.Int64">0</d:offset>
+                    <d:total m:type="Edm.Int64">6000</d:total>
+                    <d:n m:type="Edm.Int64">3</d:n>
+                    </m:properties>
+                    "#
+                )
+                .replace('\n', "")
+                .as_str()
+            ),
+            "{body}"
+        );
+
+        let res = cl
+            .get(format!(
+                "{collection_url}?$apply=groupby((city),aggregate(population%20with%20max%20as%\
+                 20max_population))&$filter=max_population%20gt%201000&$orderby=city%20desc&\
+                 $count=true"
+            ))
+            .send()
+            .await
+            .unwrap();
+        assert_eq!(res.status(), http::StatusCode::OK);
+
+        let body = res.text().await.unwrap();
+        assert_eq!(body.matches("<entry>").count(), 2);
+        assert!(body.contains("<m:count>2</m:count>"), "{body}");
+        assert!(
+            body.contains(
+                indoc!(
+                    r#"
+                    <m:properties>
+                    <d:offset m:type="Edm.Int64">2</d:offset>
+                    <d:city m:type="Edm.String">C</d:city>
+                    <d:max_population m:type="Edm.Int64">3000</d:max_population>
+                    </m:properties>
+                    "#
+                )
+                .replace('\n', "")
+                .as_str()
+            ),
+            "{body}"
+        );
+        assert!(!body.contains(r#"<d:city m:type="Edm.String">A</d:city>"#));
+
+        let res = cl
+            .get(format!("{collection_url}?$apply=filter(city%20eq%20'A')"))
+            .send()
+            .await
+            .unwrap();
+        assert_eq!(res.status(), http::StatusCode::BAD_REQUEST);
+    };
+
+    await_client_server_flow!(harness.api_server.run(), client);
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
 struct TestHarness {
     temp_dir: tempfile::TempDir,
     catalog: Catalog,
@@ -323,8 +568,22 @@ impl TestHarness {
         Self::new_with_authorizer(kamu_core::auth::AlwaysHappyDatasetActionAuthorizer::new())
     }
 
+    fn new_with_config(odata_config: ODataConfig) -> Self {
+        Self::new_with(
+            kamu_core::auth::AlwaysHappyDatasetActionAuthorizer::new(),
+            odata_config,
+        )
+    }
+
     fn new_with_authorizer<TDatasetAuthorizer: auth::DatasetActionAuthorizer + 'static>(
         dataset_action_authorizer: TDatasetAuthorizer,
+    ) -> Self {
+        Self::new_with(dataset_action_authorizer, ODataConfig::default())
+    }
+
+    fn new_with<TDatasetAuthorizer: auth::DatasetActionAuthorizer + 'static>(
+        dataset_action_authorizer: TDatasetAuthorizer,
+        odata_config: ODataConfig,
     ) -> Self {
         let temp_dir = tempfile::tempdir().unwrap();
         let run_info_dir = temp_dir.path().join("run");
@@ -361,7 +620,8 @@ impl TestHarness {
                 .add::<EngineProvisionerNull>()
                 .add::<PushIngestServiceImpl>()
                 .add::<QueryServiceImpl>()
-                .add_value(ServerUrlConfig::new_test(None));
+                .add_value(ServerUrlConfig::new_test(None))
+                .add_value(odata_config);
 
             NoOpDatabasePlugin::init_database_components(&mut b);
 
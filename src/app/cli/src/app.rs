@@ -21,9 +21,12 @@ use kamu_accounts_services::PredefinedAccountsRegistrator;
 use kamu_adapter_http::{FileUploadLimitConfig, UploadServiceLocal};
 use kamu_adapter_oauth::GithubAuthenticationConfig;
 use kamu_auth_rebac_services::{MultiTenantRebacDatasetLifecycleMessageConsumer, RebacServiceImpl};
-use kamu_datasets::DatasetEnvVar;
-use kamu_flow_system_inmem::domain::FlowConfigurationUpdatedMessage;
-use kamu_flow_system_services::MESSAGE_PRODUCER_KAMU_FLOW_CONFIGURATION_SERVICE;
+use kamu_datasets::{DatasetEnvVar, SecretProvider, SecretProviderConfig};
+use kamu_flow_system_inmem::domain::{FlowConfigurationUpdatedMessage, FlowProgressMessage};
+use kamu_flow_system_services::{
+    MESSAGE_PRODUCER_KAMU_FLOW_CONFIGURATION_SERVICE,
+    MESSAGE_PRODUCER_KAMU_FLOW_PROGRESS_SERVICE,
+};
 use kamu_task_system_inmem::domain::{TaskProgressMessage, MESSAGE_PRODUCER_KAMU_TASK_EXECUTOR};
 use messaging_outbox::{register_message_dispatcher, Outbox, OutboxDispatchingImpl};
 use time_source::{SystemTimeSource, SystemTimeSourceDefault, SystemTimeSourceStub};
@@ -39,6 +42,7 @@ use crate::{
     configure_database_components,
     configure_in_memory_components,
     connect_database_initially,
+    is_dependency_graph_persisted,
     odf_server,
     spawn_password_refreshing_job,
     try_build_db_connection_settings,
@@ -101,14 +105,12 @@ pub async fn run(
         .clone()
         .and_then(try_build_db_connection_settings);
 
+    let is_dependency_graph_persisted = maybe_db_connection_settings
+        .as_ref()
+        .is_some_and(is_dependency_graph_persisted);
+
     // Configure application
     let (guards, base_catalog, cli_catalog, output_config) = {
-        let dependencies_graph_repository = prepare_dependencies_graph_repository(
-            &workspace_layout,
-            is_multi_tenant_workspace,
-            current_account.to_current_account_subject(),
-        );
-
         let mut base_catalog_builder = configure_base_catalog(
             &workspace_layout,
             is_multi_tenant_workspace,
@@ -129,15 +131,40 @@ pub async fn run(
             configure_in_memory_components(&mut base_catalog_builder);
         };
 
-        base_catalog_builder
-            .add_value(dependencies_graph_repository)
-            .bind::<dyn DependencyGraphRepository, DependencyGraphRepositoryInMemory>();
+        // Without the database the graph is rebuilt from metadata chains of all
+        // datasets
+        if !is_dependency_graph_persisted {
+            let dependencies_graph_repository = prepare_dependencies_graph_repository(
+                &workspace_layout,
+                is_multi_tenant_workspace,
+                current_account.to_current_account_subject(),
+            );
+
+            base_catalog_builder
+                .add_value(dependencies_graph_repository)
+                .bind::<dyn DependencyGraphRepository, DependencyGraphRepositoryInMemory>();
+        }
 
         let output_config = configure_output_format(&matches, &workspace_svc);
         base_catalog_builder.add_value(output_config.clone());
 
+        let maybe_otlp_config = config
+            .tracing
+            .as_ref()
+            .unwrap()
+            .otlp
+            .as_ref()
+            .unwrap()
+            .to_infra_cfg()
+            .map_err(CLIError::usage_error_from)?;
+
         let no_color_output = matches.get_flag("no-color");
-        let guards = configure_logging(&output_config, &workspace_layout, no_color_output);
+        let guards = configure_logging(
+            &output_config,
+            &workspace_layout,
+            no_color_output,
+            maybe_otlp_config,
+        )?;
 
         tracing::info!(
             version = VERSION,
@@ -151,7 +178,7 @@ pub async fn run(
             &config,
             &mut base_catalog_builder,
             is_multi_tenant_workspace,
-        );
+        )?;
 
         let base_catalog = base_catalog_builder.build();
 
@@ -168,6 +195,27 @@ pub async fn run(
             base_catalog
         };
 
+        // Persistent graph repository opens its own transactions, so it needs a catalog
+        // with the connection pool. Metadata chains are only scanned once, to bootstrap
+        // the table for datasets that existed before the database was configured.
+        let final_base_catalog = if is_dependency_graph_persisted {
+            let metadata_chains_repository = prepare_dependencies_graph_repository(
+                &workspace_layout,
+                is_multi_tenant_workspace,
+                current_account.to_current_account_subject(),
+            );
+
+            CatalogBuilder::new_chained(&final_base_catalog)
+                .add_value(DependencyGraphRepositoryPersistent::new(
+                    final_base_catalog.clone(),
+                    Arc::new(metadata_chains_repository),
+                ))
+                .bind::<dyn DependencyGraphRepository, DependencyGraphRepositoryPersistent>()
+                .build()
+        } else {
+            final_base_catalog
+        };
+
         let cli_catalog = configure_cli_catalog(&final_base_catalog, is_multi_tenant_workspace)
             .add_value(current_account.to_current_account_subject())
             .build();
@@ -322,6 +370,8 @@ pub fn configure_base_catalog(
 
     b.add::<PushIngestServiceImpl>();
 
+    b.add::<IngestMetrics>();
+
     b.add::<TransformServiceImpl>();
 
     b.add::<VerificationServiceImpl>();
@@ -338,6 +388,8 @@ pub fn configure_base_catalog(
 
     b.add::<ResetServiceImpl>();
 
+    b.add::<DatasetArchiveServiceImpl>();
+
     b.add::<ProvenanceServiceImpl>();
 
     b.add::<QueryServiceImpl>();
@@ -347,6 +399,7 @@ pub fn configure_base_catalog(
     b.add::<ObjectStoreBuilderLocalFs>();
 
     b.add::<EngineProvisionerLocal>();
+    b.add::<EngineMetrics>();
 
     b.add::<kamu_adapter_http::SmartTransferProtocolClientWs>();
 
@@ -354,7 +407,10 @@ pub fn configure_base_catalog(
 
     b.add::<kamu_task_system_services::TaskExecutorImpl>();
 
+    b.add::<kamu_task_system_services::TaskMetrics>();
+
     b.add::<DependencyGraphServiceInMemory>();
+    b.add::<DependencyGraphConsistencyChecker>();
 
     b.add::<DatasetOwnershipServiceInMemory>();
     b.add::<DatasetOwnershipServiceInMemoryStateInitializer>();
@@ -368,6 +424,7 @@ pub fn configure_base_catalog(
 
     b.add::<kamu_flow_system_services::FlowConfigurationServiceImpl>();
     b.add::<kamu_flow_system_services::FlowServiceImpl>();
+    b.add::<kamu_flow_system_services::FlowMetrics>();
     b.add_value(kamu_flow_system_inmem::domain::FlowServiceRunConfig::new(
         chrono::Duration::try_seconds(1).unwrap(),
         chrono::Duration::try_minutes(1).unwrap(),
@@ -388,6 +445,13 @@ pub fn configure_base_catalog(
     b.add::<kamu_accounts_services::AccessTokenServiceImpl>();
     b.add::<PredefinedAccountsRegistrator>();
 
+    b.add::<kamu_datasets_services::DatasetExpectationServiceImpl>();
+
+    b.add::<kamu_webhooks_services::WebhookSubscriptionServiceImpl>();
+    b.add::<kamu_webhooks_services::WebhookEventDispatcher>();
+    b.add::<kamu_webhooks_services::WebhookDeliveryWorkerImpl>();
+    b.add::<kamu_webhooks_services::WebhookSenderHttp>();
+
     // Give both CLI and server access to stored repo access tokens
     b.add::<odf_server::AccessTokenRegistryService>();
     b.add::<odf_server::CLIAccessTokenStore>();
@@ -413,6 +477,10 @@ pub fn configure_base_catalog(
     b.add::<messaging_outbox::OutboxDispatchingImpl>();
     b.bind::<dyn Outbox, OutboxDispatchingImpl>();
     b.add::<messaging_outbox::OutboxTransactionalProcessor>();
+    b.add::<messaging_outbox::OutboxAdminServiceImpl>();
+    b.add::<messaging_outbox::OutboxMetrics>();
+
+    b.add::<observability::HttpMetrics>();
 
     register_message_dispatcher::<DatasetLifecycleMessage>(
         &mut b,
@@ -423,6 +491,10 @@ pub fn configure_base_catalog(
         &mut b,
         MESSAGE_PRODUCER_KAMU_FLOW_CONFIGURATION_SERVICE,
     );
+    register_message_dispatcher::<FlowProgressMessage>(
+        &mut b,
+        MESSAGE_PRODUCER_KAMU_FLOW_PROGRESS_SERVICE,
+    );
 
     b
 }
@@ -486,7 +558,7 @@ pub fn register_config_in_catalog(
     config: &config::CLIConfig,
     catalog_builder: &mut CatalogBuilder,
     multi_tenant_workspace: bool,
-) {
+) -> Result<(), CLIError> {
     let network_ns = config.engine.as_ref().unwrap().network_ns.unwrap();
 
     // Register JupyterConfig used by some commands
@@ -513,47 +585,8 @@ pub fn register_config_in_catalog(
             .shutdown_timeout
             .unwrap()
             .into(),
-        spark_image: config
-            .engine
-            .as_ref()
-            .unwrap()
-            .images
-            .as_ref()
-            .unwrap()
-            .spark
-            .clone()
-            .unwrap(),
-        flink_image: config
-            .engine
-            .as_ref()
-            .unwrap()
-            .images
-            .as_ref()
-            .unwrap()
-            .flink
-            .clone()
-            .unwrap(),
-        datafusion_image: config
-            .engine
-            .as_ref()
-            .unwrap()
-            .images
-            .as_ref()
-            .unwrap()
-            .datafusion
-            .clone()
-            .unwrap(),
-        risingwave_image: config
-            .engine
-            .as_ref()
-            .unwrap()
-            .images
-            .as_ref()
-            .unwrap()
-            .risingwave
-            .clone()
-            .unwrap(),
     });
+    catalog_builder.add_value(config.engine.as_ref().unwrap().to_infra_cfg());
 
     catalog_builder.add_value(config.source.as_ref().unwrap().to_infra_cfg());
     catalog_builder.add_value(
@@ -595,6 +628,28 @@ pub fn register_config_in_catalog(
     });
     catalog_builder.add_value(kamu::utils::ipfs_wrapper::IpfsClient::default());
 
+    catalog_builder.add_value(
+        config
+            .protocol
+            .as_ref()
+            .unwrap()
+            .odata
+            .as_ref()
+            .unwrap()
+            .to_infra_cfg(),
+    );
+
+    catalog_builder.add_value(
+        config
+            .protocol
+            .as_ref()
+            .unwrap()
+            .ingest_stream
+            .as_ref()
+            .unwrap()
+            .to_infra_cfg(),
+    );
+
     if multi_tenant_workspace {
         let mut implicit_user_config = PredefinedAccountsConfig::new();
         implicit_user_config.predefined.push(
@@ -608,6 +663,17 @@ pub fn register_config_in_catalog(
         let mut user_config = config.users.clone().unwrap();
         user_config.merge(implicit_user_config);
         catalog_builder.add_value(user_config);
+
+        // OpenID Connect login is only enabled when the provider is configured
+        if let Some(oidc_config) = config
+            .auth
+            .as_ref()
+            .and_then(|auth| auth.oidc.as_ref())
+            .and_then(config::OidcConfig::to_infra_cfg)
+        {
+            catalog_builder.add_value(oidc_config);
+            catalog_builder.add::<kamu_adapter_oauth::OAuthOidc>();
+        }
     } else {
         if let Some(users) = &config.users {
             assert!(
@@ -627,6 +693,28 @@ pub fn register_config_in_catalog(
     catalog_builder.add_value(config.dataset_env_vars.clone().unwrap());
 
     let dataset_env_vars_config = config.dataset_env_vars.as_ref().unwrap();
+    match dataset_env_vars_config.secret_provider.as_ref() {
+        None => {}
+        Some(SecretProviderConfig::Vault(vault_config)) => {
+            let secret_provider = kamu_datasets_services::SecretProviderVault::new(vault_config)
+                .map_err(|e| {
+                    CLIError::usage_error(format!(
+                        "Invalid Vault secret provider configuration: {e}"
+                    ))
+                })?;
+            catalog_builder
+                .add_value(secret_provider)
+                .bind::<dyn SecretProvider, kamu_datasets_services::SecretProviderVault>();
+        }
+        Some(SecretProviderConfig::Files(files_config)) => {
+            catalog_builder
+                .add_value(kamu_datasets_services::SecretProviderFiles::new(
+                    files_config,
+                ))
+                .bind::<dyn SecretProvider, kamu_datasets_services::SecretProviderFiles>();
+        }
+    }
+
     match dataset_env_vars_config.encryption_key.as_ref() {
         None => {
             match dataset_env_vars_config.enabled.as_ref() {
@@ -658,10 +746,31 @@ pub fn register_config_in_catalog(
     }
 
     let outbox_config = config.outbox.as_ref().unwrap();
-    catalog_builder.add_value(messaging_outbox::OutboxConfig::new(
-        Duration::seconds(outbox_config.awaiting_step_secs.unwrap()),
-        outbox_config.batch_size.unwrap(),
-    ));
+    catalog_builder.add_value(
+        messaging_outbox::OutboxConfig::new(
+            Duration::seconds(outbox_config.awaiting_step_secs.unwrap()),
+            outbox_config.batch_size.unwrap(),
+        )
+        .with_retry_policy(messaging_outbox::OutboxRetryPolicy {
+            max_attempts: outbox_config.max_consumer_attempts.unwrap(),
+            min_backoff: Duration::seconds(outbox_config.min_retry_backoff_secs.unwrap()),
+            max_backoff: Duration::seconds(outbox_config.max_retry_backoff_secs.unwrap()),
+        }),
+    );
+
+    // Webhook signing secrets share the encryption key with dataset env vars, so
+    // that `kamu system rotate-secrets-key` rotates both
+    let mut webhooks_config = config.webhooks.as_ref().unwrap().to_infra_cfg();
+    webhooks_config
+        .secret_encryption_key
+        .clone_from(&dataset_env_vars_config.encryption_key);
+    webhooks_config.previous_secret_encryption_keys = dataset_env_vars_config
+        .previous_encryption_keys
+        .clone()
+        .unwrap_or_default();
+    catalog_builder.add_value(webhooks_config);
+
+    Ok(())
 }
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -691,11 +800,13 @@ fn configure_logging(
     output_config: &OutputConfig,
     workspace_layout: &WorkspaceLayout,
     no_color_output: bool,
-) -> Guards {
+    maybe_otlp_config: Option<observability::otlp::OtlpConfig>,
+) -> Result<Guards, CLIError> {
     use tracing_bunyan_formatter::{BunyanFormattingLayer, JsonStorageLayer};
     use tracing_log::LogTracer;
     use tracing_subscriber::fmt::format::FmtSpan;
     use tracing_subscriber::layer::SubscriberExt;
+    use tracing_subscriber::util::SubscriberInitExt;
     use tracing_subscriber::EnvFilter;
 
     if no_color_output {
@@ -721,7 +832,7 @@ fn configure_logging(
 
     // Logging may be already initialized when running under tests
     if tracing::dispatcher::has_been_set() {
-        return Guards::default();
+        return Ok(Guards::default());
     }
 
     // Use configuration from RUST_LOG env var if provided
@@ -733,22 +844,41 @@ fn configure_logging(
         },
     };
 
+    // Export spans to OpenTelemetry collector if configured. The layer is added
+    // right after the filter in both subscribers below, as its type depends on
+    // the layers it's stacked on.
+    let (maybe_otlp_layer, otlp_guard) = if let Some(mut otlp_config) = maybe_otlp_config {
+        otlp_config.service_version = Some(VERSION.to_string());
+        let (layer, guard) =
+            observability::otlp::new_layer(otlp_config).map_err(CLIError::usage_error_from)?;
+        (Some(layer), Some(guard))
+    } else {
+        (None, None)
+    };
+
     if output_config.verbosity_level > 0 {
         // Log to STDERR
-        tracing_subscriber::fmt()
-            .with_env_filter(env_filter)
-            .with_span_events(FmtSpan::NEW | FmtSpan::CLOSE)
-            .with_writer(std::io::stderr)
-            .pretty()
-            .with_ansi(!no_color_output)
+        tracing_subscriber::registry()
+            .with(env_filter)
+            .with(maybe_otlp_layer)
+            .with(
+                tracing_subscriber::fmt::layer()
+                    .with_span_events(FmtSpan::NEW | FmtSpan::CLOSE)
+                    .with_writer(std::io::stderr)
+                    .pretty()
+                    .with_ansi(!no_color_output),
+            )
             .init();
 
-        return Guards::default();
+        return Ok(Guards {
+            otlp: otlp_guard,
+            ..Guards::default()
+        });
     }
 
     if !workspace_layout.run_info_dir.exists() {
         // Running outside workspace - discard logs
-        return Guards::default();
+        return Ok(Guards::default());
     }
 
     // Configure Perfetto tracing if enabled
@@ -770,6 +900,7 @@ fn configure_logging(
 
     let subscriber = tracing_subscriber::registry()
         .with(env_filter)
+        .with(maybe_otlp_layer)
         .with(JsonStorageLayer)
         .with(maybe_perfetto_layer)
         .with(BunyanFormattingLayer::new(BINARY_NAME.to_owned(), appender));
@@ -779,10 +910,11 @@ fn configure_logging(
 
     tracing::subscriber::set_global_default(subscriber).expect("Failed to set subscriber");
 
-    Guards {
+    Ok(Guards {
         appender: Some(appender_guard),
         perfetto: perfetto_guard,
-    }
+        otlp: otlp_guard,
+    })
 }
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -870,6 +1002,7 @@ fn get_output_format_recursive<'a>(
 struct Guards {
     appender: Option<tracing_appender::non_blocking::WorkerGuard>,
     perfetto: Option<tracing_perfetto::FlushGuard>,
+    otlp: Option<observability::otlp::OtlpGuard>,
 }
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
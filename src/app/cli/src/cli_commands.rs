@@ -105,12 +105,27 @@ pub fn get_command(
             submatches.get_flag("recursive"),
             submatches.get_flag("yes"),
         )),
+        Some(("export", submatches)) => Box::new(ExportCommand::new(
+            cli_catalog.get_one()?,
+            validate_many_dataset_refs(
+                cli_catalog,
+                submatches.get_many("dataset").unwrap().cloned(),
+            )?,
+            submatches.get_one::<String>("to").unwrap(),
+            submatches.get_flag("recursive"),
+        )),
+        Some(("import", submatches)) => Box::new(ImportCommand::new(
+            cli_catalog.get_one()?,
+            submatches.get_one::<String>("archive").unwrap(),
+            submatches.get_flag("force"),
+        )),
         Some(("ingest", submatches)) => Box::new(IngestCommand::new(
             cli_catalog.get_one()?,
             cli_catalog.get_one()?,
             cli_catalog.get_one()?,
             cli_catalog.get_one()?,
             cli_catalog.get_one()?,
+            cli_catalog.get_one()?,
             validate_dataset_ref(
                 cli_catalog,
                 submatches.get_one::<DatasetRef>("dataset").unwrap().clone(),
@@ -225,6 +240,21 @@ pub fn get_command(
                     access_token: submatches.get_one("access-token").cloned().unwrap(),
                 }),
             )),
+            Some(("oidc", submatches)) => Box::new(LoginSilentCommand::new(
+                cli_catalog.get_one()?,
+                cli_catalog.get_one()?,
+                if submatches.get_flag("user") {
+                    odf_server::AccessTokenStoreScope::User
+                } else {
+                    odf_server::AccessTokenStoreScope::Workspace
+                },
+                submatches.get_one::<Url>("server").cloned(),
+                LoginSilentMode::Oidc(LoginSilentModeOidc {
+                    issuer_url: submatches.get_one("issuer").cloned().unwrap(),
+                    client_id: submatches.get_one("client-id").cloned().unwrap(),
+                    scopes: submatches.get_many("scope").unwrap().cloned().collect(),
+                }),
+            )),
             Some(("password", submatches)) => Box::new(LoginSilentCommand::new(
                 cli_catalog.get_one()?,
                 cli_catalog.get_one()?,
@@ -304,6 +334,7 @@ pub fn get_command(
                     cli_catalog.get_one()?,
                     cli_catalog.get_one()?,
                     cli_catalog.get_one()?,
+                    cli_catalog.get_one()?,
                     datasets,
                     cli_catalog.get_one()?,
                     submatches.get_flag("all"),
@@ -498,6 +529,13 @@ pub fn get_command(
                 cli_catalog.get_one()?,
                 cli_catalog.get_one()?,
             )),
+            Some(("check-dependency-graph", check_matches)) => {
+                Box::new(SystemCheckDependencyGraphCommand::new(
+                    // Only available when the graph is persisted in the database
+                    cli_catalog.get_one().ok(),
+                    check_matches.get_flag("repair"),
+                ))
+            }
             Some(("debug-token", matches)) => Box::new(DebugTokenCommand::new(
                 cli_catalog.get_one()?,
                 matches.get_one("token").cloned().unwrap(),
@@ -508,6 +546,13 @@ pub fn get_command(
                 gen_matches.get_one("subject").cloned(),
                 *gen_matches.get_one::<usize>("expiration-time-sec").unwrap(),
             )),
+            Some(("rotate-secrets-key", rotate_matches)) => Box::new(RotateSecretsKeyCommand::new(
+                cli_catalog.get_one()?,
+                cli_catalog.get_one()?,
+                cli_catalog.get_one()?,
+                cli_catalog.get_one()?,
+                rotate_matches.get_one("new-key").cloned(),
+            )),
             Some(("ipfs", ipfs_matches)) => match ipfs_matches.subcommand() {
                 Some(("add", add_matches)) => Box::new(SystemIpfsAddCommand::new(
                     cli_catalog.get_one()?,
@@ -531,6 +576,31 @@ pub fn get_command(
                 submatches.get_flag("hard"),
                 submatches.get_flag("verify"),
                 submatches.get_flag("keep-metadata-only"),
+                kamu::domain::CompactionDataLayout {
+                    sort_by: submatches
+                        .get_many("sort-by")
+                        .map(|v| v.cloned().collect())
+                        .unwrap_or_default(),
+                    max_row_group_size: submatches.get_one("max-row-group-size").copied(),
+                    page_index: submatches.get_flag("no-page-index").then_some(false),
+                    bloom_filter_columns: submatches
+                        .get_many("bloom-filter")
+                        .map(|v| v.cloned().collect())
+                        .unwrap_or_default(),
+                    compression: submatches.get_one::<String>("compression").map(
+                        |codec| match codec.as_str() {
+                            "uncompressed" => kamu::domain::CompactionCompression::Uncompressed,
+                            "snappy" => kamu::domain::CompactionCompression::Snappy,
+                            "zstd" => kamu::domain::CompactionCompression::Zstd {
+                                level: submatches
+                                    .get_one("compression-level")
+                                    .copied()
+                                    .unwrap_or(kamu::domain::DEFAULT_ZSTD_COMPRESSION_LEVEL),
+                            },
+                            _ => unreachable!(),
+                        },
+                    ),
+                },
             )),
             Some(("e2e", submatches)) => Box::new(SystemE2ECommand::new(
                 submatches
@@ -604,7 +674,9 @@ pub fn get_command(
 pub fn command_needs_transaction(arg_matches: &clap::ArgMatches) -> Result<bool, CLIError> {
     match arg_matches.subcommand() {
         Some(("system", system_matches)) => match system_matches.subcommand() {
-            Some(("generate-token", _)) => Ok(true),
+            Some(("generate-token" | "rotate-secrets-key" | "check-dependency-graph", _)) => {
+                Ok(true)
+            }
             Some(_) => Ok(false),
             None => Err(CommandInterpretationFailed.into()),
         },
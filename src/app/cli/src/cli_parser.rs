@@ -313,6 +313,66 @@ pub fn cli() -> Command {
                             kamu delete my.dataset.%
                         "#
                     )),
+                Command::new("export")
+                    .about("Exports datasets into a self-contained archive")
+                    .args([
+                        Arg::new("dataset")
+                            .required(true)
+                            .action(ArgAction::Append)
+                            .index(1)
+                            .value_parser(value_parse_dataset_ref_local)
+                            .help("Local dataset reference(s)"),
+                        Arg::new("to")
+                            .long("to")
+                            .required(true)
+                            .value_name("PATH")
+                            .help("Path of the archive file to create"),
+                        Arg::new("recursive")
+                            .short('r')
+                            .long("recursive")
+                            .action(ArgAction::SetTrue)
+                            .help("Also export all transitive dependencies of specified datasets"),
+                    ])
+                    .after_help(indoc::indoc!(
+                        r#"
+                        Archive contains the metadata chain, data slices, checkpoints and refs of every exported dataset and can be moved to another workspace without network access. Use `kamu import` to unpack it.
+
+                        **Examples:**
+
+                        Export a dataset:
+
+                            kamu export org.example.data --to bundle.tar.zst
+
+                        Export a derivative dataset along with all of its upstream dependencies:
+
+                            kamu export org.example.derivative --recursive --to bundle.tar.zst
+                        "#
+                    )),
+                Command::new("import")
+                    .about("Imports datasets from an archive created by the export command")
+                    .args([
+                        Arg::new("archive")
+                            .required(true)
+                            .index(1)
+                            .value_name("PATH")
+                            .help("Path of the archive file"),
+                        Arg::new("force")
+                            .short('f')
+                            .long("force")
+                            .action(ArgAction::SetTrue)
+                            .help("Overwrite local version with imported, even if revisions have diverged"),
+                    ])
+                    .after_help(indoc::indoc!(
+                        r#"
+                        All metadata blocks and hashes of data and checkpoint files are fully validated during import. Datasets that already exist in the workspace are updated the same way `kamu pull` would do it.
+
+                        **Examples:**
+
+                        Import datasets:
+
+                            kamu import bundle.tar.zst
+                        "#
+                    )),
                 Command::new("ingest")
                     .about("Adds data to the root dataset according to its push source configuration")
                     .args([
@@ -596,7 +656,7 @@ pub fn cli() -> Command {
                             .args([
                                 Arg::new("provider")
                                     .required(true)
-                                    .help("Name of the OAuth provider, i.e. 'github'"),
+                                    .help("Name of the OAuth provider, i.e. 'github' or 'oidc'"),
                                 Arg::new("access-token")
                                     .required(true)
                                     .help("OAuth provider access token"),
@@ -608,6 +668,33 @@ pub fn cli() -> Command {
                                     .action(ArgAction::SetTrue)
                                     .help("Store access token in the user home folder rather than in the workspace"),
                             ]),
+                        Command::new("oidc")
+                            .about("Logs in to a remote Kamu server via OpenID Connect provider using device authorization flow")
+                            .args([
+                                Arg::new("issuer")
+                                    .long("issuer")
+                                    .required(true)
+                                    .value_name("URL")
+                                    .help("Issuer URL of the OpenID Connect provider"),
+                                Arg::new("client-id")
+                                    .long("client-id")
+                                    .required(true)
+                                    .value_name("ID")
+                                    .help("Client identifier registered with the provider"),
+                                Arg::new("scope")
+                                    .long("scope")
+                                    .action(ArgAction::Append)
+                                    .value_name("SCOPE")
+                                    .default_values(["openid", "profile", "email"])
+                                    .help("Scopes to request from the provider"),
+                                Arg::new("server")
+                                    .value_parser(value_parse_url)
+                                    .help("ODF backend server URL (defaults to kamu.dev)"),
+                                Arg::new("user")
+                                    .long("user")
+                                    .action(ArgAction::SetTrue)
+                                    .help("Store access token in the user home folder rather than in the workspace"),
+                            ]),
                         Command::new("password")
                             .about("Performs non-interactive login to a remote Kamu server via login and password")
                             .args([
@@ -933,6 +1020,11 @@ pub fn cli() -> Command {
                                     s3+http://my-minio-server:9000/bucket/
                                     s3+https://my-minio-server:9000/bucket/
 
+                                For Google Cloud Storage and Azure Blob Storage repositories use:
+
+                                    gs://bucket/path/
+                                    az://container/path/
+
                                 For ODF-compatible smart repositories use:
 
                                     odf+http://odf-server/
@@ -1252,6 +1344,31 @@ pub fn cli() -> Command {
                             ]),
                         Command::new("diagnose")
                             .about("Run basic system diagnose check"),
+                        Command::new("check-dependency-graph")
+                            .about("Compare the persisted dataset dependency graph with metadata chains")
+                            .args([
+                                Arg::new("repair")
+                                    .long("repair")
+                                    .action(ArgAction::SetTrue)
+                                    .help("Overwrite the persisted graph with the one rebuilt from metadata chains"),
+                            ])
+                            .after_help(indoc::indoc!(
+                                r#"
+                                When a database is configured, dependencies between datasets are stored in it and updated incrementally, instead of being rebuilt from metadata chains of all datasets on every startup. This command rebuilds the graph from metadata chains and reports any differences with the stored one.
+
+                                An empty stored graph is populated from metadata chains automatically when it is first accessed. Run this command with `--repair` if the stored graph got out of sync, e.g. after datasets were modified without the database.
+
+                                **Examples:**
+
+                                Check the stored graph:
+
+                                    kamu system check-dependency-graph
+
+                                Fix the stored graph:
+
+                                    kamu system check-dependency-graph --repair
+                                "#
+                            )),
                         Command::new("ipfs")
                             .about("IPFS helpers")
                             .subcommand_required(true)
@@ -1286,6 +1403,30 @@ pub fn cli() -> Command {
                                     .default_value("3600")
                                     .help("Token expiration time in seconds"),
                             ]),
+                        Command::new("rotate-secrets-key")
+                            .about("Re-encrypt all stored dataset secrets with a new encryption key")
+                            .args([
+                                Arg::new("new-key")
+                                    .index(1)
+                                    .help("New 32-character encryption key, a random one is generated if not specified"),
+                            ])
+                            .after_help(indoc::indoc!(
+                                r#"
+                                Decrypts all secret dataset environment variables with the key currently specified in `datasetEnvVars.encryptionKey` config (or one of `datasetEnvVars.previousEncryptionKeys`) and encrypts them again with the new key.
+
+                                The config file that defines the current key is then updated to use the new key, while the old key is moved to `datasetEnvVars.previousEncryptionKeys`, so that secrets written by nodes still running with the old config remain readable. Running the command again re-encrypts such leftovers; once all nodes use the new key the previous keys can be removed.
+
+                                **Examples:**
+
+                                Rotate to a randomly generated key:
+
+                                    kamu system rotate-secrets-key
+
+                                Rotate to a specific key:
+
+                                    kamu system rotate-secrets-key aBcDeFgHiJkLmNoPqRsTuVwXyZ012345
+                                "#
+                            )),
                         Command::new("compact")
                             .about("Compact a dataset")
                             .args([
@@ -1319,6 +1460,36 @@ pub fn cli() -> Command {
                                     .long("verify")
                                     .action(ArgAction::SetTrue)
                                     .help("Perform verification of the dataset before running a compaction"),
+                                Arg::new("sort-by")
+                                    .long("sort-by")
+                                    .action(ArgAction::Append)
+                                    .value_name("COL")
+                                    .help("Sort records within compacted slices by the specified column(s)"),
+                                Arg::new("max-row-group-size")
+                                    .long("max-row-group-size")
+                                    .value_parser(value_parser!(u64).range(1..))
+                                    .value_name("RECORDS")
+                                    .help("Maximum amount of records in a single Parquet row group"),
+                                Arg::new("no-page-index")
+                                    .long("no-page-index")
+                                    .action(ArgAction::SetTrue)
+                                    .help("Do not write Parquet page index"),
+                                Arg::new("bloom-filter")
+                                    .long("bloom-filter")
+                                    .action(ArgAction::Append)
+                                    .value_name("COL")
+                                    .help("Write Parquet bloom filters for the specified column(s)"),
+                                Arg::new("compression")
+                                    .long("compression")
+                                    .value_parser(["uncompressed", "snappy", "zstd"])
+                                    .value_name("CODEC")
+                                    .help("Compression codec of the data files"),
+                                Arg::new("compression-level")
+                                    .long("compression-level")
+                                    .value_parser(value_parser!(i32).range(1..=22))
+                                    .value_name("LEVEL")
+                                    .requires("compression")
+                                    .help("Compression level (only applicable to 'zstd')"),
                             ])
                             .after_help(indoc::indoc!(
                                 r#"
@@ -1335,6 +1506,10 @@ pub fn cli() -> Command {
                                 Perform a history-altering hard compaction:
 
                                     kamu system compact --hard my.dataset
+
+                                Hard compaction can also optimize the layout of data files for queries that filter on specific columns. Records within the compacted slices are sorted by the specified columns and their offsets are re-assigned in the new order (slices with retractions or corrections keep the original order):
+
+                                    kamu system compact --hard --sort-by city --sort-by event_time --bloom-filter city --compression zstd --compression-level 9 my.dataset
                                 "#
                             )),
                         Command::new("e2e")
@@ -10,6 +10,7 @@
 use std::sync::Arc;
 
 use kamu::domain::{
+    CompactionDataLayout,
     CompactionOptions,
     CompactionService,
     DatasetRepository,
@@ -31,6 +32,7 @@ pub struct CompactCommand {
     is_hard: bool,
     is_verify: bool,
     keep_metadata_only: bool,
+    data_layout: CompactionDataLayout,
 }
 
 impl CompactCommand {
@@ -44,6 +46,7 @@ impl CompactCommand {
         is_hard: bool,
         is_verify: bool,
         keep_metadata_only: bool,
+        data_layout: CompactionDataLayout,
     ) -> Self {
         Self {
             dataset_repo,
@@ -55,6 +58,7 @@ impl CompactCommand {
             is_hard,
             is_verify,
             keep_metadata_only,
+            data_layout,
         }
     }
 
@@ -122,6 +126,7 @@ impl Command for CompactCommand {
                     max_slice_size: Some(self.max_slice_size),
                     max_slice_records: Some(self.max_slice_records),
                     keep_metadata_only: self.keep_metadata_only,
+                    data_layout: self.data_layout.clone(),
                 },
                 Some(listener.clone()),
             )
@@ -0,0 +1,88 @@
+// Copyright Kamu Data, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+use std::path::PathBuf;
+use std::sync::Arc;
+
+use kamu::domain::*;
+use opendatafabric::*;
+
+use super::{CLIError, Command};
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+pub struct ExportCommand {
+    dataset_archive_svc: Arc<dyn DatasetArchiveService>,
+    dataset_refs: Vec<DatasetRef>,
+    archive_path: PathBuf,
+    recursive: bool,
+}
+
+impl ExportCommand {
+    pub fn new<I>(
+        dataset_archive_svc: Arc<dyn DatasetArchiveService>,
+        dataset_refs: I,
+        archive_path: impl Into<PathBuf>,
+        recursive: bool,
+    ) -> Self
+    where
+        I: IntoIterator<Item = DatasetRef>,
+    {
+        Self {
+            dataset_archive_svc,
+            dataset_refs: dataset_refs.into_iter().collect(),
+            archive_path: archive_path.into(),
+            recursive,
+        }
+    }
+}
+
+#[async_trait::async_trait(?Send)]
+impl Command for ExportCommand {
+    async fn run(&mut self) -> Result<(), CLIError> {
+        let result = match self
+            .dataset_archive_svc
+            .export_datasets(
+                self.dataset_refs.clone(),
+                &self.archive_path,
+                ExportOptions {
+                    recursive: self.recursive,
+                },
+            )
+            .await
+        {
+            Ok(result) => Ok(result),
+            Err(ExportError::DatasetNotFound(e)) => Err(CLIError::failure(e)),
+            Err(ExportError::Access(e)) => Err(CLIError::failure(e)),
+            Err(e) => Err(CLIError::critical(e)),
+        }?;
+
+        for dataset in &result.datasets {
+            eprintln!(
+                "{} {} ({})",
+                console::style("Exported").green(),
+                dataset.alias,
+                dataset.head.as_multibase().short(),
+            );
+        }
+
+        eprintln!(
+            "{}",
+            console::style(format!(
+                "Exported {} dataset(s) into {}",
+                result.datasets.len(),
+                result.archive_path.display()
+            ))
+            .green()
+            .bold()
+        );
+
+        Ok(())
+    }
+}
@@ -0,0 +1,88 @@
+// Copyright Kamu Data, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+use std::path::PathBuf;
+use std::sync::Arc;
+
+use kamu::domain::*;
+
+use super::{CLIError, Command};
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+pub struct ImportCommand {
+    dataset_archive_svc: Arc<dyn DatasetArchiveService>,
+    archive_path: PathBuf,
+    force: bool,
+}
+
+impl ImportCommand {
+    pub fn new(
+        dataset_archive_svc: Arc<dyn DatasetArchiveService>,
+        archive_path: impl Into<PathBuf>,
+        force: bool,
+    ) -> Self {
+        Self {
+            dataset_archive_svc,
+            archive_path: archive_path.into(),
+            force,
+        }
+    }
+}
+
+#[async_trait::async_trait(?Send)]
+impl Command for ImportCommand {
+    async fn run(&mut self) -> Result<(), CLIError> {
+        if !self.archive_path.is_file() {
+            return Err(CLIError::usage_error(format!(
+                "Archive {} does not exist",
+                self.archive_path.display()
+            )));
+        }
+
+        let result = match self
+            .dataset_archive_svc
+            .import_datasets(&self.archive_path, ImportOptions { force: self.force })
+            .await
+        {
+            Ok(result) => Ok(result),
+            Err(e @ (ImportError::ArchiveInvalid(_) | ImportError::SyncFailed { .. })) => {
+                Err(CLIError::failure(e))
+            }
+            Err(e) => Err(CLIError::critical(e)),
+        }?;
+
+        for (dataset, sync_result) in &result.datasets {
+            match sync_result {
+                SyncResult::UpToDate => {
+                    eprintln!(
+                        "{} {}",
+                        console::style("Up-to-date").yellow(),
+                        dataset.alias
+                    );
+                }
+                SyncResult::Updated { num_blocks, .. } => eprintln!(
+                    "{} {} ({} block(s))",
+                    console::style("Imported").green(),
+                    dataset.alias,
+                    num_blocks,
+                ),
+            }
+        }
+
+        eprintln!(
+            "{}",
+            console::style(format!("Imported {} dataset(s)", result.datasets.len()))
+                .green()
+                .bold()
+        );
+
+        Ok(())
+    }
+}
@@ -14,6 +14,7 @@ use std::time::Duration;
 use chrono::{DateTime, Utc};
 use internal_error::ResultIntoInternal;
 use kamu::domain::*;
+use kamu_datasets::DatasetExpectationService;
 use opendatafabric::*;
 
 use super::{CLIError, Command};
@@ -27,6 +28,7 @@ pub struct IngestCommand {
     data_format_reg: Arc<dyn DataFormatRegistry>,
     dataset_repo: Arc<dyn DatasetRepository>,
     push_ingest_svc: Arc<dyn PushIngestService>,
+    dataset_expectation_svc: Arc<dyn DatasetExpectationService>,
     output_config: Arc<OutputConfig>,
     remote_alias_reg: Arc<dyn RemoteAliasesRegistry>,
     dataset_ref: DatasetRef,
@@ -43,6 +45,7 @@ impl IngestCommand {
         data_format_reg: Arc<dyn DataFormatRegistry>,
         dataset_repo: Arc<dyn DatasetRepository>,
         push_ingest_svc: Arc<dyn PushIngestService>,
+        dataset_expectation_svc: Arc<dyn DatasetExpectationService>,
         output_config: Arc<OutputConfig>,
         remote_alias_reg: Arc<dyn RemoteAliasesRegistry>,
         dataset_ref: DatasetRef,
@@ -63,6 +66,7 @@ impl IngestCommand {
             data_format_reg,
             dataset_repo,
             push_ingest_svc,
+            dataset_expectation_svc,
             output_config,
             remote_alias_reg,
             dataset_ref,
@@ -159,6 +163,12 @@ impl Command for IngestCommand {
 
         self.ensure_valid_push_target(&dataset_handle).await?;
 
+        let dataset_expectations = self
+            .dataset_expectation_svc
+            .get_dataset_expectations(&dataset_handle.id)
+            .await
+            .int_err()?;
+
         let urls = if self.stdin {
             vec![url::Url::parse("file:///dev/fd/0").unwrap()]
         } else {
@@ -201,6 +211,7 @@ impl Command for IngestCommand {
                         media_type: self.get_media_type()?,
                         source_event_time,
                         auto_create_push_source: false,
+                        dataset_expectations: dataset_expectations.clone(),
                         schema_inference: SchemaInferenceOpts::default(),
                     },
                     listener.clone(),
@@ -9,7 +9,7 @@
 
 use std::sync::Arc;
 
-use kamu_adapter_oauth::PROVIDER_GITHUB;
+use kamu_adapter_oauth::{PROVIDER_GITHUB, PROVIDER_OIDC};
 use url::Url;
 
 use crate::{odf_server, CLIError, Command};
@@ -18,6 +18,7 @@ use crate::{odf_server, CLIError, Command};
 
 pub enum LoginSilentMode {
     OAuth(LoginSilentModeOAuth),
+    Oidc(LoginSilentModeOidc),
     Password(LoginSilentModePassword),
 }
 
@@ -26,6 +27,12 @@ pub struct LoginSilentModeOAuth {
     pub access_token: String,
 }
 
+pub struct LoginSilentModeOidc {
+    pub issuer_url: String,
+    pub client_id: String,
+    pub scopes: Vec<String>,
+}
+
 pub struct LoginSilentModePassword {
     pub login: String,
     pub password: String,
@@ -70,8 +77,9 @@ impl LoginSilentCommand {
             LoginSilentMode::OAuth(github_mode) => {
                 let oauth_login_method = match github_mode.provider.to_ascii_lowercase().as_str() {
                     "github" => Ok(PROVIDER_GITHUB),
+                    "oidc" => Ok(PROVIDER_OIDC),
                     _ => Err(CLIError::usage_error(
-                        "Only 'github' provider is supported at the moment",
+                        "Only 'github' and 'oidc' providers are supported at the moment",
                     )),
                 }?;
                 self.login_service
@@ -89,6 +97,29 @@ impl LoginSilentCommand {
                     })?
             }
 
+            LoginSilentMode::Oidc(oidc_mode) => self
+                .login_service
+                .login_oidc_device(
+                    &odf_server_backend_url,
+                    &oidc_mode.issuer_url,
+                    &oidc_mode.client_id,
+                    &oidc_mode.scopes,
+                    |device_authorization| {
+                        eprintln!(
+                            "{} {} {} {}",
+                            console::style("To log in open").green().bold(),
+                            device_authorization.verification_uri,
+                            console::style("and enter the code").green().bold(),
+                            console::style(&device_authorization.user_code).bold(),
+                        );
+                    },
+                )
+                .await
+                .map_err(|e| match e {
+                    odf_server::LoginError::AccessFailed(e) => CLIError::usage_error(e.to_string()),
+                    odf_server::LoginError::Internal(e) => CLIError::failure(e),
+                })?,
+
             LoginSilentMode::Password(password_mode) => self
                 .login_service
                 .login_password(
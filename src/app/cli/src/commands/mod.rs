@@ -17,7 +17,9 @@ mod complete_command;
 mod completions_command;
 mod config_command;
 mod delete_command;
+mod export_command;
 mod gc_command;
+mod import_command;
 mod ingest_command;
 mod init_command;
 mod inspect_query_command;
@@ -47,12 +49,14 @@ mod sql_shell_command;
 mod system_api_server_gql_query_command;
 mod system_api_server_gql_schema_command;
 mod system_api_server_run_command;
+mod system_check_dependency_graph_command;
 mod system_debug_token_command;
 mod system_diagnose_command;
 mod system_e2e_command;
 mod system_generate_token_command;
 mod system_info_command;
 mod system_ipfs_add_command;
+mod system_rotate_secrets_key_command;
 mod tail_command;
 mod ui_command;
 mod upgrade_workspace_command;
@@ -67,7 +71,9 @@ pub use complete_command::*;
 pub use completions_command::*;
 pub use config_command::*;
 pub use delete_command::*;
+pub use export_command::*;
 pub use gc_command::*;
+pub use import_command::*;
 pub use ingest_command::*;
 pub use init_command::*;
 pub use inspect_query_command::*;
@@ -97,12 +103,14 @@ pub use sql_shell_command::*;
 pub use system_api_server_gql_query_command::*;
 pub use system_api_server_gql_schema_command::*;
 pub use system_api_server_run_command::*;
+pub use system_check_dependency_graph_command::*;
 pub use system_debug_token_command::*;
 pub use system_diagnose_command::*;
 pub use system_e2e_command::*;
 pub use system_generate_token_command::*;
 pub use system_info_command::*;
 pub use system_ipfs_add_command::*;
+pub use system_rotate_secrets_key_command::*;
 pub use tail_command::*;
 pub use ui_command::*;
 pub use upgrade_workspace_command::*;
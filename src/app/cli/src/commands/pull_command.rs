@@ -13,9 +13,11 @@ use std::time::Duration;
 
 use chrono::{DateTime, Utc};
 use futures::TryStreamExt;
+use internal_error::ResultIntoInternal;
 use kamu::domain::*;
 use kamu::utils::datasets_filtering::filter_datasets_by_any_pattern;
 use kamu_accounts::CurrentAccountSubject;
+use kamu_datasets::{DatasetExpectation, DatasetExpectationService};
 use opendatafabric::*;
 
 use super::{BatchError, CLIError, Command};
@@ -29,6 +31,7 @@ pub struct PullCommand {
     pull_svc: Arc<dyn PullService>,
     dataset_repo: Arc<dyn DatasetRepository>,
     search_svc: Arc<dyn SearchService>,
+    dataset_expectation_svc: Arc<dyn DatasetExpectationService>,
     output_config: Arc<OutputConfig>,
     refs: Vec<DatasetRefAnyPattern>,
     current_account_subject: Arc<CurrentAccountSubject>,
@@ -46,6 +49,7 @@ impl PullCommand {
         pull_svc: Arc<dyn PullService>,
         dataset_repo: Arc<dyn DatasetRepository>,
         search_svc: Arc<dyn SearchService>,
+        dataset_expectation_svc: Arc<dyn DatasetExpectationService>,
         output_config: Arc<OutputConfig>,
         refs: I,
         current_account_subject: Arc<CurrentAccountSubject>,
@@ -64,6 +68,7 @@ impl PullCommand {
             pull_svc,
             dataset_repo,
             search_svc,
+            dataset_expectation_svc,
             output_config,
             refs: refs.into_iter().collect(),
             current_account_subject,
@@ -127,6 +132,8 @@ impl PullCommand {
             vec![]
         };
 
+        let dataset_expectations = self.load_dataset_expectations(&dataset_refs).await?;
+
         Ok(self
             .pull_svc
             .pull_multi(
@@ -140,6 +147,7 @@ impl PullCommand {
                         fetch_uncacheable: self.fetch_uncacheable,
                         exhaust_sources: true,
                         dataset_env_vars: HashMap::new(),
+                        dataset_expectations,
                         schema_inference: SchemaInferenceOpts::default(),
                     },
                     sync_options: SyncOptions {
@@ -152,6 +160,51 @@ impl PullCommand {
             .await?)
     }
 
+    /// Loads data quality expectations of all datasets that can be ingested
+    /// into by this pull
+    async fn load_dataset_expectations(
+        &self,
+        dataset_refs: &[DatasetRefAny],
+    ) -> Result<HashMap<DatasetID, Vec<DatasetExpectation>>, CLIError> {
+        let dataset_handles: Vec<_> = if self.all || self.recursive {
+            // Upstream datasets are resolved by the pull service, so consider all
+            self.dataset_repo.get_all_datasets().try_collect().await?
+        } else {
+            let is_multi_tenant = self.dataset_repo.is_multi_tenant();
+
+            let mut dataset_handles = Vec::new();
+            for dataset_ref in dataset_refs {
+                // Remote references are synced rather than ingested into
+                let Ok(local_ref) = dataset_ref.as_local_ref(|_| !is_multi_tenant) else {
+                    continue;
+                };
+                if let Some(hdl) = self
+                    .dataset_repo
+                    .try_resolve_dataset_ref(&local_ref)
+                    .await?
+                {
+                    dataset_handles.push(hdl);
+                }
+            }
+            dataset_handles
+        };
+
+        let mut dataset_expectations = HashMap::new();
+        for hdl in dataset_handles {
+            let expectations = self
+                .dataset_expectation_svc
+                .get_dataset_expectations(&hdl.id)
+                .await
+                .int_err()?;
+
+            if !expectations.is_empty() {
+                dataset_expectations.insert(hdl.id, expectations);
+            }
+        }
+
+        Ok(dataset_expectations)
+    }
+
     async fn pull_with_progress(&self) -> Result<Vec<PullResponse>, CLIError> {
         let pull_progress =
             PrettyPullProgress::new(self.fetch_uncacheable, self.dataset_repo.is_multi_tenant());
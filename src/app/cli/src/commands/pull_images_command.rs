@@ -10,14 +10,14 @@
 use std::sync::Arc;
 
 use container_runtime::ContainerRuntime;
-use kamu::EngineProvisionerLocalConfig;
+use kamu::EngineRegistry;
 
 use super::{CLIError, Command};
 use crate::config::JupyterConfig;
 
 pub struct PullImagesCommand {
     container_runtime: Arc<ContainerRuntime>,
-    engine_config: Arc<EngineProvisionerLocalConfig>,
+    engine_registry: Arc<EngineRegistry>,
     jupyter_config: Arc<JupyterConfig>,
     list_only: bool,
 }
@@ -25,13 +25,13 @@ pub struct PullImagesCommand {
 impl PullImagesCommand {
     pub fn new(
         container_runtime: Arc<ContainerRuntime>,
-        engine_config: Arc<EngineProvisionerLocalConfig>,
+        engine_registry: Arc<EngineRegistry>,
         jupyter_config: Arc<JupyterConfig>,
         list_only: bool,
     ) -> Self {
         Self {
             container_runtime,
-            engine_config,
+            engine_registry,
             jupyter_config,
             list_only,
         }
@@ -45,14 +45,16 @@ impl Command for PullImagesCommand {
     }
 
     async fn run(&mut self) -> Result<(), CLIError> {
-        let mut images = vec![
-            self.engine_config.spark_image.as_str(),
-            self.engine_config.flink_image.as_str(),
-            self.engine_config.datafusion_image.as_str(),
-            self.engine_config.risingwave_image.as_str(),
+        let mut images: Vec<_> = self
+            .engine_registry
+            .engines()
+            .iter()
+            .map(|e| e.image.as_str())
+            .collect();
+        images.extend([
             self.jupyter_config.image.as_ref().unwrap().as_str(),
             self.jupyter_config.livy_image.as_ref().unwrap().as_str(),
-        ];
+        ]);
 
         images.sort_unstable();
         images.dedup();
@@ -24,7 +24,7 @@ use crate::WorkspaceLayout;
 
 pub struct SqlServerCommand {
     workspace_layout: Arc<WorkspaceLayout>,
-    engine_prov_config: Arc<EngineProvisionerLocalConfig>,
+    engine_registry: Arc<EngineRegistry>,
     output_config: Arc<OutputConfig>,
     container_runtime: Arc<ContainerRuntime>,
     address: IpAddr,
@@ -34,7 +34,7 @@ pub struct SqlServerCommand {
 impl SqlServerCommand {
     pub fn new(
         workspace_layout: Arc<WorkspaceLayout>,
-        engine_prov_config: Arc<EngineProvisionerLocalConfig>,
+        engine_registry: Arc<EngineRegistry>,
         output_config: Arc<OutputConfig>,
         container_runtime: Arc<ContainerRuntime>,
         address: IpAddr,
@@ -42,7 +42,7 @@ impl SqlServerCommand {
     ) -> Self {
         Self {
             workspace_layout,
-            engine_prov_config,
+            engine_registry,
             output_config,
             container_runtime,
             address,
@@ -56,7 +56,7 @@ impl Command for SqlServerCommand {
     async fn run(&mut self) -> Result<(), CLIError> {
         let sql_shell = SqlShellImpl::new(
             self.container_runtime.clone(),
-            self.engine_prov_config.spark_image.clone(),
+            self.engine_registry.get("spark").unwrap().image.clone(),
         );
 
         let spinner = if self.output_config.verbosity_level == 0 && !self.output_config.quiet {
@@ -29,7 +29,7 @@ pub const DEFAULT_MAX_ROWS_FOR_OUTPUT: usize = 40;
 pub struct SqlShellCommand {
     query_svc: Arc<dyn QueryService>,
     workspace_layout: Arc<WorkspaceLayout>,
-    engine_prov_config: Arc<EngineProvisionerLocalConfig>,
+    engine_registry: Arc<EngineRegistry>,
     output_config: Arc<OutputConfig>,
     container_runtime: Arc<ContainerRuntime>,
     command: Option<String>,
@@ -41,7 +41,7 @@ impl SqlShellCommand {
     pub fn new(
         query_svc: Arc<dyn QueryService>,
         workspace_layout: Arc<WorkspaceLayout>,
-        engine_prov_config: Arc<EngineProvisionerLocalConfig>,
+        engine_registry: Arc<EngineRegistry>,
         output_config: Arc<OutputConfig>,
         container_runtime: Arc<ContainerRuntime>,
         command: Option<&str>,
@@ -51,7 +51,7 @@ impl SqlShellCommand {
         Self {
             query_svc,
             workspace_layout,
-            engine_prov_config,
+            engine_registry,
             output_config,
             container_runtime,
             command: command.map(ToOwned::to_owned),
@@ -63,7 +63,7 @@ impl SqlShellCommand {
     async fn run_spark_shell(&self) -> Result<(), CLIError> {
         let sql_shell = SqlShellImpl::new(
             self.container_runtime.clone(),
-            self.engine_prov_config.spark_image.clone(),
+            self.engine_registry.get("spark").unwrap().image.clone(),
         );
 
         let spinner = if self.output_config.verbosity_level == 0 && !self.output_config.quiet {
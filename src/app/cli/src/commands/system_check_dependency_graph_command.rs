@@ -0,0 +1,109 @@
+// Copyright Kamu Data, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+use std::sync::Arc;
+
+use kamu::DependencyGraphConsistencyChecker;
+use thiserror::Error;
+
+use crate::{CLIError, Command};
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+pub struct SystemCheckDependencyGraphCommand {
+    maybe_consistency_checker: Option<Arc<DependencyGraphConsistencyChecker>>,
+    repair: bool,
+}
+
+impl SystemCheckDependencyGraphCommand {
+    pub fn new(
+        maybe_consistency_checker: Option<Arc<DependencyGraphConsistencyChecker>>,
+        repair: bool,
+    ) -> Self {
+        Self {
+            maybe_consistency_checker,
+            repair,
+        }
+    }
+}
+
+#[async_trait::async_trait(?Send)]
+impl Command for SystemCheckDependencyGraphCommand {
+    async fn run(&mut self) -> Result<(), CLIError> {
+        let Some(consistency_checker) = &self.maybe_consistency_checker else {
+            return Err(CLIError::usage_error(
+                "Dependency graph is only persisted when a PostgreSQL or SQLite database is \
+                 configured",
+            ));
+        };
+
+        let report = consistency_checker
+            .check(self.repair)
+            .await
+            .map_err(CLIError::critical)?;
+
+        for entry in &report.missing_dependencies {
+            eprintln!(
+                "{} {} -> {}",
+                console::style("missing:   ").yellow(),
+                entry.upstream_dataset_id,
+                entry.downstream_dataset_id,
+            );
+        }
+        for entry in &report.unexpected_dependencies {
+            eprintln!(
+                "{} {} -> {}",
+                console::style("unexpected:").yellow(),
+                entry.upstream_dataset_id,
+                entry.downstream_dataset_id,
+            );
+        }
+
+        if report.is_consistent() {
+            eprintln!(
+                "{}",
+                console::style("Dependency graph is consistent with metadata chains")
+                    .green()
+                    .bold()
+            );
+        } else if report.repaired {
+            eprintln!(
+                "{}",
+                console::style(format!(
+                    "Repaired {} missing and {} unexpected dependencies",
+                    report.missing_dependencies.len(),
+                    report.unexpected_dependencies.len(),
+                ))
+                .green()
+                .bold()
+            );
+        } else {
+            return Err(CLIError::failure(DependencyGraphInconsistentError {
+                num_missing: report.missing_dependencies.len(),
+                num_unexpected: report.unexpected_dependencies.len(),
+            }));
+        }
+
+        Ok(())
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[derive(Error, Debug)]
+#[error(
+    "Dependency graph has {num_missing} missing and {num_unexpected} unexpected dependencies, run \
+     with --repair to fix"
+)]
+struct DependencyGraphInconsistentError {
+    num_missing: usize,
+    num_unexpected: usize,
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -0,0 +1,170 @@
+// Copyright Kamu Data, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+use std::sync::Arc;
+
+use kamu_datasets::{
+    DatasetEnvVar,
+    DatasetEnvVarService,
+    DatasetEnvVarsConfig,
+    RotateEncryptionKeyError,
+};
+use kamu_webhooks::WebhookSubscriptionService;
+
+use crate::config::{ConfigScope, ConfigService};
+use crate::{CLIError, Command};
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+pub struct RotateSecretsKeyCommand {
+    dataset_env_var_service: Arc<dyn DatasetEnvVarService>,
+    webhook_subscription_service: Arc<dyn WebhookSubscriptionService>,
+    dataset_env_vars_config: Arc<DatasetEnvVarsConfig>,
+    config_svc: Arc<ConfigService>,
+    new_key: Option<String>,
+}
+
+impl RotateSecretsKeyCommand {
+    pub fn new(
+        dataset_env_var_service: Arc<dyn DatasetEnvVarService>,
+        webhook_subscription_service: Arc<dyn WebhookSubscriptionService>,
+        dataset_env_vars_config: Arc<DatasetEnvVarsConfig>,
+        config_svc: Arc<ConfigService>,
+        new_key: Option<String>,
+    ) -> Self {
+        Self {
+            dataset_env_var_service,
+            webhook_subscription_service,
+            dataset_env_vars_config,
+            config_svc,
+            new_key,
+        }
+    }
+
+    /// Finds the config file that defines the current key, so that the new key
+    /// is written where the operator expects it
+    fn find_key_scope(&self, current_key: &str) -> Option<ConfigScope> {
+        [ConfigScope::Workspace, ConfigScope::User]
+            .into_iter()
+            .find(|scope| {
+                self.config_svc
+                    .load(*scope)
+                    .dataset_env_vars
+                    .and_then(|config| config.encryption_key)
+                    .is_some_and(|key| key == current_key)
+            })
+    }
+
+    fn update_config(&self, scope: ConfigScope, current_key: &str, new_key: &str) {
+        let mut config = self.config_svc.load(scope);
+        let dataset_env_vars = config.dataset_env_vars.get_or_insert_with(Default::default);
+
+        // The current key is kept as a previous one: values written by instances
+        // that still run with the old config, or left over if the transaction
+        // fails to commit, remain readable
+        let mut previous_keys = vec![current_key.to_string()];
+        previous_keys.extend(
+            dataset_env_vars
+                .previous_encryption_keys
+                .take()
+                .into_iter()
+                .flatten()
+                .filter(|key| key != current_key && key != new_key),
+        );
+
+        dataset_env_vars.encryption_key = Some(new_key.to_string());
+        dataset_env_vars.previous_encryption_keys = Some(previous_keys);
+
+        self.config_svc.save(config, scope);
+    }
+}
+
+#[async_trait::async_trait(?Send)]
+impl Command for RotateSecretsKeyCommand {
+    fn needs_workspace(&self) -> bool {
+        false
+    }
+
+    async fn run(&mut self) -> Result<(), CLIError> {
+        if !self.dataset_env_vars_config.is_enabled() {
+            return Err(CLIError::usage_error(
+                "Dataset env vars are not enabled or the current encryption key is not configured",
+            ));
+        }
+
+        let current_key = self.dataset_env_vars_config.encryption_key.clone().unwrap();
+        let new_key = self
+            .new_key
+            .clone()
+            .unwrap_or_else(DatasetEnvVar::generate_encryption_key);
+
+        if new_key == current_key {
+            return Err(CLIError::usage_error(
+                "New encryption key must differ from the current one",
+            ));
+        }
+
+        let num_reencrypted = match self
+            .dataset_env_var_service
+            .rotate_encryption_key(&new_key)
+            .await
+        {
+            Ok(num_reencrypted) => num_reencrypted,
+            Err(e @ RotateEncryptionKeyError::InvalidEncryptionKey) => {
+                return Err(CLIError::usage_error_from(e))
+            }
+            Err(e @ RotateEncryptionKeyError::Internal(_)) => return Err(CLIError::critical(e)),
+        };
+
+        // Webhook signing secrets are encrypted with the same key
+        let num_reencrypted_webhook_secrets = self
+            .webhook_subscription_service
+            .rotate_secret_encryption_key(&new_key)
+            .await
+            .map_err(CLIError::critical)?;
+
+        eprintln!(
+            "{}",
+            console::style(format!(
+                "Re-encrypted {num_reencrypted} secret(s) and {num_reencrypted_webhook_secrets} \
+                 webhook secret(s)"
+            ))
+            .green()
+            .bold()
+        );
+
+        if let Some(scope) = self.find_key_scope(&current_key) {
+            self.update_config(scope, &current_key, &new_key);
+
+            eprintln!(
+                "Updated `datasetEnvVars.encryptionKey` in the {} config, the old key was moved \
+                 to `datasetEnvVars.previousEncryptionKeys`. Restart other running nodes to pick \
+                 up the new key.",
+                if scope == ConfigScope::User {
+                    "user"
+                } else {
+                    "workspace"
+                }
+            );
+        } else {
+            eprintln!(
+                "The current key is not defined in the user or workspace config, update your \
+                 config manually: set `datasetEnvVars.encryptionKey` to the new key and add the \
+                 old key to `datasetEnvVars.previousEncryptionKeys`"
+            );
+            if self.new_key.is_none() {
+                println!("{new_key}");
+            }
+        }
+
+        Ok(())
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
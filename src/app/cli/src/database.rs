@@ -37,12 +37,18 @@ pub fn configure_database_components(
             b.add::<kamu_accounts_postgres::PostgresAccountRepository>();
             b.add::<kamu_accounts_postgres::PostgresAccessTokenRepository>();
 
+            b.add::<kamu_datasets_postgres::PostgresDatasetDependencyRepository>();
             b.add::<kamu_datasets_postgres::PostgresDatasetEnvVarRepository>();
+            b.add::<kamu_datasets_postgres::PostgresDatasetExpectationRepository>();
 
             b.add::<kamu_flow_system_postgres::PostgresFlowConfigurationEventStore>();
 
             b.add::<kamu_messaging_outbox_postgres::PostgresOutboxMessageRepository>();
             b.add::<kamu_messaging_outbox_postgres::PostgresOutboxMessageConsumptionRepository>();
+            b.add::<kamu_messaging_outbox_postgres::PostgresOutboxMessageDeadLetterRepository>();
+
+            b.add::<kamu_webhooks_postgres::PostgresWebhookSubscriptionRepository>();
+            b.add::<kamu_webhooks_postgres::PostgresWebhookDeliveryRepository>();
 
             // TODO: Private Datasets: implement database-related version
             b.add::<kamu_auth_rebac_inmem::InMemoryRebacRepository>();
@@ -54,11 +60,16 @@ pub fn configure_database_components(
             b.add::<kamu_accounts_mysql::MySqlAccessTokenRepository>();
 
             b.add::<kamu_datasets_inmem::InMemoryDatasetEnvVarRepository>();
+            b.add::<kamu_datasets_inmem::InMemoryDatasetExpectationRepository>();
 
             b.add::<kamu_flow_system_inmem::InMemoryFlowConfigurationEventStore>();
 
             b.add::<kamu_messaging_outbox_inmem::InMemoryOutboxMessageRepository>();
             b.add::<kamu_messaging_outbox_inmem::InMemoryOutboxMessageConsumptionRepository>();
+            b.add::<kamu_messaging_outbox_inmem::InMemoryOutboxMessageDeadLetterRepository>();
+
+            b.add::<kamu_webhooks_inmem::InMemoryWebhookSubscriptionRepository>();
+            b.add::<kamu_webhooks_inmem::InMemoryWebhookDeliveryRepository>();
 
             // TODO: Private Datasets: implement database-related version
             b.add::<kamu_auth_rebac_inmem::InMemoryRebacRepository>();
@@ -71,17 +82,27 @@ pub fn configure_database_components(
             b.add::<kamu_accounts_sqlite::SqliteAccountRepository>();
             b.add::<kamu_accounts_sqlite::SqliteAccessTokenRepository>();
 
+            b.add::<kamu_datasets_sqlite::SqliteDatasetDependencyRepository>();
             b.add::<kamu_datasets_sqlite::SqliteDatasetEnvVarRepository>();
+            b.add::<kamu_datasets_sqlite::SqliteDatasetExpectationRepository>();
 
             b.add::<kamu_flow_system_sqlite::SqliteFlowSystemEventStore>();
 
             b.add::<kamu_messaging_outbox_sqlite::SqliteOutboxMessageRepository>();
             b.add::<kamu_messaging_outbox_sqlite::SqliteOutboxMessageConsumptionRepository>();
+            b.add::<kamu_messaging_outbox_sqlite::SqliteOutboxMessageDeadLetterRepository>();
+
+            b.add::<kamu_webhooks_sqlite::SqliteWebhookSubscriptionRepository>();
+            b.add::<kamu_webhooks_sqlite::SqliteWebhookDeliveryRepository>();
 
             b.add::<kamu_auth_rebac_sqlite::SqliteRebacRepository>();
         }
     }
 
+    if is_dependency_graph_persisted(&db_connection_settings) {
+        b.add::<kamu::DependencyGraphPersistenceMessageConsumer>();
+    }
+
     b.add_value(db_connection_settings);
 
     init_database_password_provider(b, raw_db_config);
@@ -89,16 +110,35 @@ pub fn configure_database_components(
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
 
+/// Whether the dataset dependency graph is stored in the database instead of
+/// being rebuilt from metadata chains on startup.
+///
+/// MySQL / MariaDB keep all dataset-related repositories in memory (see
+/// [`configure_database_components`]), so the graph is rebuilt from metadata
+/// chains there, exactly as without a database.
+pub fn is_dependency_graph_persisted(db_connection_settings: &DatabaseConnectionSettings) -> bool {
+    match db_connection_settings.provider {
+        DatabaseProvider::Postgres | DatabaseProvider::Sqlite => true,
+        DatabaseProvider::MySql | DatabaseProvider::MariaDB => false,
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
 // Public only for tests
 pub fn configure_in_memory_components(b: &mut CatalogBuilder) {
     b.add::<kamu_messaging_outbox_inmem::InMemoryOutboxMessageRepository>();
     b.add::<kamu_messaging_outbox_inmem::InMemoryOutboxMessageConsumptionRepository>();
+    b.add::<kamu_messaging_outbox_inmem::InMemoryOutboxMessageDeadLetterRepository>();
     b.add::<kamu_accounts_inmem::InMemoryAccountRepository>();
     b.add::<kamu_accounts_inmem::InMemoryAccessTokenRepository>();
     b.add::<kamu_flow_system_inmem::InMemoryFlowConfigurationEventStore>();
     b.add::<kamu_flow_system_inmem::InMemoryFlowEventStore>();
     b.add::<kamu_task_system_inmem::InMemoryTaskSystemEventStore>();
     b.add::<kamu_datasets_inmem::InMemoryDatasetEnvVarRepository>();
+    b.add::<kamu_datasets_inmem::InMemoryDatasetExpectationRepository>();
+    b.add::<kamu_webhooks_inmem::InMemoryWebhookSubscriptionRepository>();
+    b.add::<kamu_webhooks_inmem::InMemoryWebhookDeliveryRepository>();
     b.add::<kamu_auth_rebac_inmem::InMemoryRebacRepository>();
 
     NoOpDatabasePlugin::init_database_components(b);
@@ -24,6 +24,7 @@ use kamu::domain::{Protocols, ServerUrlConfig};
 use kamu_adapter_http::e2e::e2e_router;
 use kamu_flow_system_inmem::domain::FlowService;
 use kamu_task_system_inmem::domain::TaskExecutor;
+use kamu_webhooks::WebhookDeliveryWorker;
 use messaging_outbox::OutboxTransactionalProcessor;
 use time_source::SystemTimeSource;
 use tokio::sync::Notify;
@@ -39,6 +40,7 @@ pub struct APIServer {
     task_executor: Arc<dyn TaskExecutor>,
     flow_service: Arc<dyn FlowService>,
     outbox_processor: Arc<OutboxTransactionalProcessor>,
+    webhook_delivery_worker: Arc<dyn WebhookDeliveryWorker>,
     time_source: Arc<dyn SystemTimeSource>,
     maybe_shutdown_notify: Option<Arc<Notify>>,
 }
@@ -61,8 +63,14 @@ impl APIServer {
 
         let outbox_processor = cli_catalog.get_one().unwrap();
 
+        let webhook_delivery_worker = cli_catalog.get_one().unwrap();
+
         let time_source = base_catalog.get_one().unwrap();
 
+        let http_metrics = base_catalog
+            .get_one::<observability::HttpMetrics>()
+            .unwrap();
+
         let gql_schema = kamu_adapter_graphql::schema();
 
         let addr = SocketAddr::from((
@@ -104,6 +112,10 @@ impl APIServer {
                 "/graphql",
                 axum::routing::get(graphql_playground_handler).post(graphql_handler),
             )
+            .route(
+                "/metrics",
+                axum::routing::get(observability::metrics_handler),
+            )
             .route(
                 "/platform/login",
                 axum::routing::post(kamu_adapter_http::platform_login_handler),
@@ -142,9 +154,16 @@ impl APIServer {
                     multi_tenant_workspace,
                 ),
             )
+            .route_layer(axum::middleware::from_fn_with_state(
+                http_metrics,
+                observability::http_metrics_middleware,
+            ))
             .layer(
                 tower::ServiceBuilder::new()
-                    .layer(tower_http::trace::TraceLayer::new_for_http())
+                    .layer(
+                        tower_http::trace::TraceLayer::new_for_http()
+                            .make_span_with(observability::make_http_request_span::<hyper::Body>),
+                    )
                     .layer(
                         tower_http::cors::CorsLayer::new()
                             .allow_origin(tower_http::cors::Any)
@@ -174,6 +193,7 @@ impl APIServer {
             task_executor,
             flow_service,
             outbox_processor,
+            webhook_delivery_worker,
             time_source,
             maybe_shutdown_notify,
         }
@@ -201,6 +221,7 @@ impl APIServer {
             res = server_run_fut => { res.int_err() },
             res = self.outbox_processor.run() => { res.int_err() },
             res = self.task_executor.run() => { res.int_err() },
+            res = self.webhook_delivery_worker.run() => { res.int_err() },
             res = self.flow_service.run(self.time_source.now()) => { res.int_err() }
         }
     }
@@ -216,6 +237,7 @@ async fn root() -> impl axum::response::IntoResponse {
         <h1>Kamu HTTP Server</h1>
         <ul>
             <li><a href="/graphql">GraphQL Playground</li>
+            <li><a href="/metrics">Metrics</li>
         </ul>
         "#
     ))
@@ -229,10 +251,21 @@ async fn graphql_handler(
     Extension(catalog): Extension<Catalog>,
     req: async_graphql_axum::GraphQLRequest,
 ) -> Result<async_graphql_axum::GraphQLResponse, ApiError> {
-    let graphql_request = req.into_inner().data(catalog);
-    let graphql_response = schema.execute(graphql_request).await.into();
+    let http_metrics = catalog.get_one::<observability::HttpMetrics>().unwrap();
+
+    let graphql_request = req.into_inner();
+    let operation_name = graphql_request.operation_name.clone();
+
+    let start = std::time::Instant::now();
+    let graphql_response = schema.execute(graphql_request.data(catalog)).await;
+
+    http_metrics.observe_graphql_operation(
+        operation_name.as_deref(),
+        graphql_response.is_ok(),
+        start.elapsed(),
+    );
 
-    Ok(graphql_response)
+    Ok(graphql_response.into())
 }
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
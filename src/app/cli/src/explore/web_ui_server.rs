@@ -186,7 +186,10 @@ impl WebUIServer {
             .fallback(app_handler)
             .layer(
                 tower::ServiceBuilder::new()
-                    .layer(tower_http::trace::TraceLayer::new_for_http())
+                    .layer(
+                        tower_http::trace::TraceLayer::new_for_http()
+                            .make_span_with(observability::make_http_request_span::<hyper::Body>),
+                    )
                     .layer(
                         tower_http::cors::CorsLayer::new()
                             .allow_origin(tower_http::cors::Any)
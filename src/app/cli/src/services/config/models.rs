@@ -7,6 +7,8 @@
 // the Business Source License, use of this software will be governed
 // by the Apache License, Version 2.0.
 
+use std::path::PathBuf;
+
 use container_runtime::{ContainerRuntimeType, NetworkNamespaceType};
 use database_common::DatabaseProvider;
 use duration_string::DurationString;
@@ -44,6 +46,10 @@ pub struct CLIConfig {
     #[merge(strategy = merge_recursive)]
     pub users: Option<PredefinedAccountsConfig>,
 
+    /// Authentication configuration
+    #[merge(strategy = merge_recursive)]
+    pub auth: Option<AuthConfig>,
+
     /// Database connection configuration
     pub database: Option<DatabaseConfig>,
 
@@ -58,6 +64,14 @@ pub struct CLIConfig {
     /// Messaging outbox configuration
     #[merge(strategy = merge_recursive)]
     pub outbox: Option<OutboxConfig>,
+
+    /// Webhook notifications configuration
+    #[merge(strategy = merge_recursive)]
+    pub webhooks: Option<WebhooksConfig>,
+
+    /// Distributed tracing configuration
+    #[merge(strategy = merge_recursive)]
+    pub tracing: Option<TracingConfig>,
 }
 
 impl CLIConfig {
@@ -68,10 +82,13 @@ impl CLIConfig {
             protocol: None,
             frontend: None,
             users: None,
+            auth: None,
             database: None,
             uploads: None,
             dataset_env_vars: None,
             outbox: None,
+            webhooks: None,
+            tracing: None,
         }
     }
 
@@ -86,10 +103,13 @@ impl CLIConfig {
             protocol: Some(ProtocolConfig::sample()),
             frontend: Some(FrontendConfig::sample()),
             users: Some(PredefinedAccountsConfig::sample()),
+            auth: Some(AuthConfig::sample()),
             database: Some(DatabaseConfig::sample()),
             uploads: Some(UploadsConfig::sample()),
             dataset_env_vars: Some(DatasetEnvVarsConfig::sample()),
             outbox: Some(OutboxConfig::sample()),
+            webhooks: Some(WebhooksConfig::sample()),
+            tracing: Some(TracingConfig::sample()),
         }
     }
 }
@@ -102,10 +122,13 @@ impl Default for CLIConfig {
             protocol: Some(ProtocolConfig::default()),
             frontend: Some(FrontendConfig::default()),
             users: Some(PredefinedAccountsConfig::default()),
+            auth: Some(AuthConfig::default()),
             database: None,
             uploads: Some(UploadsConfig::default()),
             dataset_env_vars: Some(DatasetEnvVarsConfig::default()),
             outbox: Some(OutboxConfig::default()),
+            webhooks: Some(WebhooksConfig::default()),
+            tracing: Some(TracingConfig::default()),
         }
     }
 }
@@ -132,6 +155,10 @@ pub struct EngineConfig {
     /// UNSTABLE: Default engine images
     #[merge(strategy = merge_recursive)]
     pub images: Option<EngineImagesConfig>,
+    /// Additional engines that transformations can use. Declaring an engine
+    /// with the identifier of a built-in one replaces it. The list specified
+    /// in the higher-priority config replaces lists from the others.
+    pub engines: Option<Vec<EngineSpecConfig>>,
 }
 
 impl EngineConfig {
@@ -143,6 +170,7 @@ impl EngineConfig {
             start_timeout: None,
             shutdown_timeout: None,
             images: None,
+            engines: None,
         }
     }
 
@@ -153,6 +181,28 @@ impl EngineConfig {
             ..Self::default()
         }
     }
+
+    pub fn to_infra_cfg(&self) -> kamu::EngineRegistry {
+        let images = self.images.as_ref().unwrap();
+
+        let mut registry = kamu::EngineRegistry::builtin();
+        for (id, image) in [
+            ("spark", &images.spark),
+            ("flink", &images.flink),
+            ("datafusion", &images.datafusion),
+            ("risingwave", &images.risingwave),
+        ] {
+            let mut spec = registry.get(id).unwrap().clone();
+            spec.image.clone_from(image.as_ref().unwrap());
+            registry = registry.with_engine(spec);
+        }
+
+        for engine in self.engines.iter().flatten() {
+            registry = registry.with_engine(engine.to_infra_cfg());
+        }
+
+        registry
+    }
 }
 
 impl Default for EngineConfig {
@@ -164,6 +214,7 @@ impl Default for EngineConfig {
             start_timeout: Some(DurationString::from_string("30s".to_owned()).unwrap()),
             shutdown_timeout: Some(DurationString::from_string("5s".to_owned()).unwrap()),
             images: Some(EngineImagesConfig::default()),
+            engines: Some(Vec::new()),
         }
     }
 }
@@ -210,6 +261,73 @@ impl Default for EngineImagesConfig {
     }
 }
 
+#[skip_serializing_none]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(deny_unknown_fields, rename_all = "camelCase")]
+pub struct EngineSpecConfig {
+    /// Identifier of the engine as used in transformations, e.g. "datafusion"
+    pub id: String,
+    /// Human-readable name of the engine, defaults to the identifier
+    pub name: Option<String>,
+    /// OCI image repository and a tag of the engine image
+    pub image: String,
+    /// Language and dialect this engine is using for queries
+    pub dialect: EngineDialectConfig,
+    /// Arguments passed to the engine container, overriding the default
+    /// command of the image
+    pub args: Option<Vec<String>>,
+    /// Resource limits of the engine container
+    pub resources: Option<EngineResourcesConfig>,
+}
+
+impl EngineSpecConfig {
+    pub fn to_infra_cfg(&self) -> kamu::EngineSpec {
+        let resources = self.resources.clone().unwrap_or_default();
+
+        kamu::EngineSpec {
+            id: self.id.clone(),
+            name: self.name.clone().unwrap_or_else(|| self.id.clone()),
+            dialect: self.dialect.into(),
+            image: self.image.clone(),
+            args: self.args.clone().unwrap_or_default(),
+            resources: kamu::EngineResources {
+                memory: resources.memory,
+                cpus: resources.cpus,
+            },
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum EngineDialectConfig {
+    SqlSpark,
+    SqlFlink,
+    SqlDataFusion,
+    SqlRisingWave,
+}
+
+impl From<EngineDialectConfig> for kamu::domain::QueryDialect {
+    fn from(value: EngineDialectConfig) -> Self {
+        match value {
+            EngineDialectConfig::SqlSpark => Self::SqlSpark,
+            EngineDialectConfig::SqlFlink => Self::SqlFlink,
+            EngineDialectConfig::SqlDataFusion => Self::SqlDataFusion,
+            EngineDialectConfig::SqlRisingWave => Self::SqlRisingWave,
+        }
+    }
+}
+
+#[skip_serializing_none]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(deny_unknown_fields, rename_all = "camelCase")]
+pub struct EngineResourcesConfig {
+    /// Memory limit of the container, e.g. "512m" or "4g"
+    pub memory: Option<String>,
+    /// Number of CPUs the container can use, e.g. "1.5"
+    pub cpus: Option<String>,
+}
+
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
 // Source
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -280,8 +398,23 @@ pub struct HttpSourceConfig {
     pub user_agent: Option<String>,
     /// Timeout for the connect phase of the HTTP client
     pub connect_timeout: Option<DurationString>,
+    /// Timeout for the entire request, including reading of the response
+    /// body
+    pub request_timeout: Option<DurationString>,
     /// Maximum number of redirects to follow
     pub max_redirects: Option<usize>,
+    /// Maximum number of times a request will be retried after a connection
+    /// error, a timeout, `429 Too Many Requests` or a `5xx` server error
+    pub max_retries: Option<u32>,
+    /// Initial delay between retries that doubles with every attempt
+    pub retry_backoff: Option<DurationString>,
+    /// Maximum time to wait before retrying a request. Requests asking to
+    /// wait longer via `Retry-After` header will fail instead.
+    pub max_retry_after: Option<DurationString>,
+    /// Proxy server to route all HTTP(S) requests through
+    pub proxy: Option<Url>,
+    /// Paths to additional PEM-encoded root certificates to trust
+    pub ca_certificates: Option<Vec<PathBuf>>,
 }
 
 impl HttpSourceConfig {
@@ -289,7 +422,13 @@ impl HttpSourceConfig {
         Self {
             user_agent: None,
             connect_timeout: None,
+            request_timeout: None,
             max_redirects: None,
+            max_retries: None,
+            retry_backoff: None,
+            max_retry_after: None,
+            proxy: None,
+            ca_certificates: None,
         }
     }
 
@@ -301,7 +440,13 @@ impl HttpSourceConfig {
         kamu::ingest::HttpSourceConfig {
             user_agent: self.user_agent.clone().unwrap(),
             connect_timeout: (*self.connect_timeout.as_ref().unwrap()).into(),
+            request_timeout: self.request_timeout.map(Into::into),
             max_redirects: self.max_redirects.unwrap(),
+            max_retries: self.max_retries.unwrap(),
+            retry_backoff: (*self.retry_backoff.as_ref().unwrap()).into(),
+            max_retry_after: (*self.max_retry_after.as_ref().unwrap()).into(),
+            proxy: self.proxy.clone(),
+            ca_certificates: self.ca_certificates.clone().unwrap_or_default(),
         }
     }
 }
@@ -312,7 +457,13 @@ impl Default for HttpSourceConfig {
         Self {
             user_agent: Some(concat!("kamu-cli/", env!("CARGO_PKG_VERSION")).to_string()),
             connect_timeout: Some(DurationString::from(infra_cfg.connect_timeout)),
+            request_timeout: infra_cfg.request_timeout.map(DurationString::from),
             max_redirects: Some(infra_cfg.max_redirects),
+            max_retries: Some(infra_cfg.max_retries),
+            retry_backoff: Some(DurationString::from(infra_cfg.retry_backoff)),
+            max_retry_after: Some(DurationString::from(infra_cfg.max_retry_after)),
+            proxy: infra_cfg.proxy,
+            ca_certificates: Some(infra_cfg.ca_certificates),
         }
     }
 }
@@ -327,12 +478,16 @@ pub struct MqttSourceConfig {
     /// which we will consider that we have "caught up" and end the polling
     /// loop.
     pub broker_idle_timeout_ms: Option<u64>,
+    /// Time in milliseconds to wait for the broker to receive acknowledgements
+    /// of the messages after they were committed.
+    pub ack_timeout_ms: Option<u64>,
 }
 
 impl MqttSourceConfig {
     pub fn new() -> Self {
         Self {
             broker_idle_timeout_ms: None,
+            ack_timeout_ms: None,
         }
     }
 
@@ -343,6 +498,7 @@ impl MqttSourceConfig {
     pub fn to_infra_cfg(&self) -> kamu::ingest::MqttSourceConfig {
         kamu::ingest::MqttSourceConfig {
             broker_idle_timeout_ms: self.broker_idle_timeout_ms.unwrap(),
+            ack_timeout_ms: self.ack_timeout_ms.unwrap(),
         }
     }
 }
@@ -352,6 +508,7 @@ impl Default for MqttSourceConfig {
         let infra_cfg = kamu::ingest::MqttSourceConfig::default();
         Self {
             broker_idle_timeout_ms: Some(infra_cfg.broker_idle_timeout_ms),
+            ack_timeout_ms: Some(infra_cfg.ack_timeout_ms),
         }
     }
 }
@@ -372,6 +529,11 @@ pub struct EthereumSourceConfig {
     /// scanned even if we didn't reach the target record number. This is useful
     /// to not lose a lot of scanning progress in case of an RPC error.
     pub commit_after_blocks_scanned: Option<u64>,
+    /// Number of most recent blocks that are considered subject to chain
+    /// reorganizations. Hashes of such blocks are recorded in the source state
+    /// and verified upon the next poll to retract records of the orphaned
+    /// blocks.
+    pub confirmation_depth: Option<u64>,
 }
 
 impl EthereumSourceConfig {
@@ -380,6 +542,7 @@ impl EthereumSourceConfig {
             rpc_endpoints: Vec::new(),
             get_logs_block_stride: None,
             commit_after_blocks_scanned: None,
+            confirmation_depth: None,
         }
     }
 
@@ -396,6 +559,7 @@ impl EthereumSourceConfig {
                 .collect(),
             get_logs_block_stride: self.get_logs_block_stride.unwrap(),
             commit_after_blocks_scanned: self.commit_after_blocks_scanned.unwrap(),
+            confirmation_depth: self.confirmation_depth.unwrap(),
         }
     }
 }
@@ -407,6 +571,7 @@ impl Default for EthereumSourceConfig {
             rpc_endpoints: Vec::new(),
             get_logs_block_stride: Some(infra_cfg.get_logs_block_stride),
             commit_after_blocks_scanned: Some(infra_cfg.commit_after_blocks_scanned),
+            confirmation_depth: Some(infra_cfg.confirmation_depth),
         }
     }
 }
@@ -441,16 +606,30 @@ pub struct ProtocolConfig {
     /// IPFS configuration
     #[merge(strategy = merge_recursive)]
     pub ipfs: Option<IpfsConfig>,
+
+    /// OData configuration
+    #[merge(strategy = merge_recursive)]
+    pub odata: Option<ODataConfig>,
+
+    /// Streaming push ingest configuration
+    #[merge(strategy = merge_recursive)]
+    pub ingest_stream: Option<IngestStreamConfig>,
 }
 
 impl ProtocolConfig {
     pub fn new() -> Self {
-        Self { ipfs: None }
+        Self {
+            ipfs: None,
+            odata: None,
+            ingest_stream: None,
+        }
     }
 
     fn sample() -> Self {
         Self {
             ipfs: Some(IpfsConfig::sample()),
+            odata: Some(ODataConfig::sample()),
+            ingest_stream: Some(IngestStreamConfig::sample()),
         }
     }
 }
@@ -459,6 +638,8 @@ impl Default for ProtocolConfig {
     fn default() -> Self {
         Self {
             ipfs: Some(IpfsConfig::default()),
+            odata: Some(ODataConfig::default()),
+            ingest_stream: Some(IngestStreamConfig::default()),
         }
     }
 }
@@ -503,6 +684,98 @@ impl Default for IpfsConfig {
     }
 }
 
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[skip_serializing_none]
+#[derive(Debug, Clone, Merge, Serialize, Deserialize)]
+#[serde(deny_unknown_fields, rename_all = "camelCase")]
+pub struct ODataConfig {
+    /// Number of records returned per page when client does not specify
+    /// `$top`
+    pub default_records_per_page: Option<usize>,
+    /// Maximum number of records returned per page, larger results are
+    /// split into pages linked via `next` links
+    pub max_records_per_page: Option<usize>,
+}
+
+impl ODataConfig {
+    pub fn new() -> Self {
+        Self {
+            default_records_per_page: None,
+            max_records_per_page: None,
+        }
+    }
+
+    fn sample() -> Self {
+        Self { ..Self::default() }
+    }
+
+    pub fn to_infra_cfg(&self) -> kamu_adapter_odata::ODataConfig {
+        kamu_adapter_odata::ODataConfig {
+            default_records_per_page: self.default_records_per_page.unwrap(),
+            max_records_per_page: self.max_records_per_page.unwrap(),
+        }
+    }
+}
+
+impl Default for ODataConfig {
+    fn default() -> Self {
+        let infra_cfg = kamu_adapter_odata::ODataConfig::default();
+        Self {
+            default_records_per_page: Some(infra_cfg.default_records_per_page),
+            max_records_per_page: Some(infra_cfg.max_records_per_page),
+        }
+    }
+}
+
+#[skip_serializing_none]
+#[derive(Debug, Clone, Merge, Serialize, Deserialize)]
+#[serde(deny_unknown_fields, rename_all = "camelCase")]
+pub struct IngestStreamConfig {
+    /// Number of records received over a streaming ingest connection that
+    /// triggers a commit
+    pub max_batch_records: Option<u64>,
+    /// Size of data in bytes received over a streaming ingest connection that
+    /// triggers a commit
+    pub max_batch_bytes: Option<usize>,
+    /// Maximum time the received records are buffered before they are
+    /// committed
+    pub max_batch_delay: Option<DurationString>,
+}
+
+impl IngestStreamConfig {
+    pub fn new() -> Self {
+        Self {
+            max_batch_records: None,
+            max_batch_bytes: None,
+            max_batch_delay: None,
+        }
+    }
+
+    fn sample() -> Self {
+        Self { ..Self::default() }
+    }
+
+    pub fn to_infra_cfg(&self) -> kamu_adapter_http::data::IngestStreamConfig {
+        kamu_adapter_http::data::IngestStreamConfig {
+            max_batch_records: self.max_batch_records.unwrap(),
+            max_batch_bytes: self.max_batch_bytes.unwrap(),
+            max_batch_delay: (*self.max_batch_delay.as_ref().unwrap()).into(),
+        }
+    }
+}
+
+impl Default for IngestStreamConfig {
+    fn default() -> Self {
+        let infra_cfg = kamu_adapter_http::data::IngestStreamConfig::default();
+        Self {
+            max_batch_records: Some(infra_cfg.max_batch_records),
+            max_batch_bytes: Some(infra_cfg.max_batch_bytes),
+            max_batch_delay: Some(DurationString::from(infra_cfg.max_batch_delay)),
+        }
+    }
+}
+
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
 // Frontend
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -572,6 +845,148 @@ impl Default for JupyterConfig {
     }
 }
 
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// Auth
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[skip_serializing_none]
+#[derive(Debug, Clone, Merge, Serialize, Deserialize)]
+#[serde(deny_unknown_fields, rename_all = "camelCase")]
+pub struct AuthConfig {
+    /// OpenID Connect provider configuration (multi-tenant workspaces only)
+    #[merge(strategy = merge_recursive)]
+    pub oidc: Option<OidcConfig>,
+}
+
+impl AuthConfig {
+    pub fn new() -> Self {
+        Self { oidc: None }
+    }
+
+    fn sample() -> Self {
+        Self {
+            oidc: Some(OidcConfig::sample()),
+        }
+    }
+}
+
+impl Default for AuthConfig {
+    fn default() -> Self {
+        // OpenID Connect is disabled unless configured explicitly
+        Self::new()
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[skip_serializing_none]
+#[derive(Debug, Clone, Merge, Serialize, Deserialize)]
+#[serde(deny_unknown_fields, rename_all = "camelCase")]
+pub struct OidcConfig {
+    /// Issuer URL, e.g. `https://keycloak.example.com/realms/my-realm`
+    pub issuer_url: Option<String>,
+    /// Client identifier registered with the provider
+    pub client_id: Option<String>,
+    /// Client secret (for confidential clients)
+    pub client_secret: Option<String>,
+    /// Scopes requested during the authorization
+    pub scopes: Option<Vec<String>>,
+    /// Other clients whose tokens are accepted for login, e.g. the public
+    /// client used by the CLI
+    pub allowed_audiences: Option<Vec<String>>,
+    /// Claim used as the account name
+    pub account_name_claim: Option<String>,
+    /// Claim used as the display name
+    pub display_name_claim: Option<String>,
+    /// Claim used as the email
+    pub email_claim: Option<String>,
+    /// Claim used as the avatar URL
+    pub avatar_url_claim: Option<String>,
+    /// Claim containing groups of the user
+    pub groups_claim: Option<String>,
+    /// Members of this group are registered as administrators
+    pub admin_group: Option<String>,
+}
+
+impl OidcConfig {
+    pub fn new() -> Self {
+        Self {
+            issuer_url: None,
+            client_id: None,
+            client_secret: None,
+            scopes: None,
+            allowed_audiences: None,
+            account_name_claim: None,
+            display_name_claim: None,
+            email_claim: None,
+            avatar_url_claim: None,
+            groups_claim: None,
+            admin_group: None,
+        }
+    }
+
+    fn sample() -> Self {
+        Self {
+            issuer_url: Some(String::new()),
+            client_id: Some(String::new()),
+            client_secret: Some(String::new()),
+            admin_group: Some(String::new()),
+            ..Self::default()
+        }
+    }
+
+    /// Returns `None` unless both issuer and client are specified
+    pub fn to_infra_cfg(&self) -> Option<kamu_adapter_oauth::OidcAuthenticationConfig> {
+        let (Some(issuer_url), Some(client_id)) = (&self.issuer_url, &self.client_id) else {
+            return None;
+        };
+
+        let mut cfg = kamu_adapter_oauth::OidcAuthenticationConfig::new(issuer_url, client_id);
+        cfg.client_secret.clone_from(&self.client_secret);
+        if let Some(scopes) = &self.scopes {
+            cfg.scopes.clone_from(scopes);
+        }
+        if let Some(allowed_audiences) = &self.allowed_audiences {
+            cfg.allowed_audiences.clone_from(allowed_audiences);
+        }
+
+        let claims = &mut cfg.claims;
+        for (claim, value) in [
+            (&mut claims.account_name, &self.account_name_claim),
+            (&mut claims.display_name, &self.display_name_claim),
+            (&mut claims.email, &self.email_claim),
+            (&mut claims.avatar_url, &self.avatar_url_claim),
+            (&mut claims.groups, &self.groups_claim),
+        ] {
+            if let Some(value) = value {
+                claim.clone_from(value);
+            }
+        }
+        claims.admin_group.clone_from(&self.admin_group);
+
+        Some(cfg)
+    }
+}
+
+impl Default for OidcConfig {
+    fn default() -> Self {
+        let infra_cfg = kamu_adapter_oauth::OidcAuthenticationConfig::new("", "");
+        Self {
+            issuer_url: None,
+            client_id: None,
+            client_secret: None,
+            scopes: Some(infra_cfg.scopes),
+            allowed_audiences: None,
+            account_name_claim: Some(infra_cfg.claims.account_name),
+            display_name_claim: Some(infra_cfg.claims.display_name),
+            email_claim: Some(infra_cfg.claims.email),
+            avatar_url_claim: Some(infra_cfg.claims.avatar_url),
+            groups_claim: Some(infra_cfg.claims.groups),
+            admin_group: None,
+        }
+    }
+}
+
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
 // Database
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -671,6 +1086,197 @@ pub struct AwsIamTokenPasswordPolicyConfig {
     pub user_name: String,
 }
 
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// Webhooks
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[skip_serializing_none]
+#[derive(Debug, Clone, Merge, Serialize, Deserialize)]
+#[serde(deny_unknown_fields, rename_all = "camelCase")]
+pub struct WebhooksConfig {
+    /// How often to check for deliveries that are due
+    pub delivery_polling_interval: Option<DurationString>,
+    /// Maximum number of deliveries attempted in one iteration
+    pub delivery_batch_size: Option<usize>,
+    /// Maximum number of deliveries attempted at the same time
+    pub delivery_concurrency: Option<usize>,
+    /// Maximum time to wait for the subscriber to respond
+    pub request_timeout: Option<DurationString>,
+    /// Number of failed attempts after which a delivery is dead-lettered
+    pub max_attempts: Option<u32>,
+    /// Delay before the first retry, doubled after every subsequent failure
+    pub min_backoff: Option<DurationString>,
+    /// Upper bound of the delay between retries
+    pub max_backoff: Option<DurationString>,
+    /// Allows delivering to loopback, private and link-local addresses. Only
+    /// meant for local development, as it lets subscribers reach internal
+    /// services.
+    pub allow_private_targets: Option<bool>,
+}
+
+impl WebhooksConfig {
+    pub fn new() -> Self {
+        Self {
+            delivery_polling_interval: None,
+            delivery_batch_size: None,
+            delivery_concurrency: None,
+            request_timeout: None,
+            max_attempts: None,
+            min_backoff: None,
+            max_backoff: None,
+            allow_private_targets: None,
+        }
+    }
+
+    fn sample() -> Self {
+        Self { ..Self::default() }
+    }
+
+    pub fn to_infra_cfg(&self) -> kamu_webhooks::WebhooksConfig {
+        let to_chrono = |d: &Option<DurationString>| {
+            chrono::Duration::from_std((*d.as_ref().unwrap()).into()).unwrap()
+        };
+
+        kamu_webhooks::WebhooksConfig {
+            delivery_polling_interval: to_chrono(&self.delivery_polling_interval),
+            delivery_batch_size: self.delivery_batch_size.unwrap(),
+            delivery_concurrency: self.delivery_concurrency.unwrap(),
+            request_timeout: to_chrono(&self.request_timeout),
+            retry_policy: kamu_webhooks::WebhookRetryPolicy {
+                max_attempts: self.max_attempts.unwrap(),
+                min_backoff: to_chrono(&self.min_backoff),
+                max_backoff: to_chrono(&self.max_backoff),
+            },
+            // Encryption keys are shared with dataset env vars
+            secret_encryption_key: None,
+            previous_secret_encryption_keys: Vec::new(),
+            allow_private_targets: self.allow_private_targets.unwrap(),
+        }
+    }
+}
+
+impl Default for WebhooksConfig {
+    fn default() -> Self {
+        let infra_cfg = kamu_webhooks::WebhooksConfig::default();
+        let from_chrono = |d: chrono::Duration| DurationString::from(d.to_std().unwrap());
+
+        Self {
+            delivery_polling_interval: Some(from_chrono(infra_cfg.delivery_polling_interval)),
+            delivery_batch_size: Some(infra_cfg.delivery_batch_size),
+            delivery_concurrency: Some(infra_cfg.delivery_concurrency),
+            request_timeout: Some(from_chrono(infra_cfg.request_timeout)),
+            max_attempts: Some(infra_cfg.retry_policy.max_attempts),
+            min_backoff: Some(from_chrono(infra_cfg.retry_policy.min_backoff)),
+            max_backoff: Some(from_chrono(infra_cfg.retry_policy.max_backoff)),
+            allow_private_targets: Some(infra_cfg.allow_private_targets),
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// Tracing
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[skip_serializing_none]
+#[derive(Debug, Clone, Merge, Serialize, Deserialize)]
+#[serde(deny_unknown_fields, rename_all = "camelCase")]
+pub struct TracingConfig {
+    /// Export of spans to an OpenTelemetry collector
+    #[merge(strategy = merge_recursive)]
+    pub otlp: Option<OtlpConfig>,
+}
+
+impl TracingConfig {
+    pub fn new() -> Self {
+        Self { otlp: None }
+    }
+
+    fn sample() -> Self {
+        Self {
+            otlp: Some(OtlpConfig::sample()),
+        }
+    }
+}
+
+impl Default for TracingConfig {
+    fn default() -> Self {
+        Self {
+            otlp: Some(OtlpConfig::default()),
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[skip_serializing_none]
+#[derive(Debug, Clone, Merge, Serialize, Deserialize)]
+#[serde(deny_unknown_fields, rename_all = "camelCase")]
+pub struct OtlpConfig {
+    /// Collector endpoint, e.g. `http://localhost:4317` for gRPC or
+    /// `http://localhost:4318/v1/traces` for HTTP. Export is disabled unless
+    /// the endpoint is specified here or via `OTEL_EXPORTER_OTLP_ENDPOINT`.
+    pub endpoint: Option<Url>,
+    /// Transport used to send spans to the collector
+    pub protocol: Option<OtlpProtocolConfig>,
+    /// Name under which the spans are reported
+    pub service_name: Option<String>,
+}
+
+impl OtlpConfig {
+    pub fn new() -> Self {
+        Self {
+            endpoint: None,
+            protocol: None,
+            service_name: None,
+        }
+    }
+
+    fn sample() -> Self {
+        Self {
+            endpoint: Some(Url::parse("http://localhost:4317").unwrap()),
+            ..Self::default()
+        }
+    }
+
+    /// Returns `None` unless the endpoint is configured. Standard `OTEL_*`
+    /// environment variables take precedence over the values in the config.
+    pub fn to_infra_cfg(
+        &self,
+    ) -> Result<Option<observability::otlp::OtlpConfig>, observability::otlp::OtlpConfigError> {
+        observability::otlp::OtlpConfig::from_env_or(
+            self.endpoint.clone(),
+            self.protocol.map(Into::into),
+            self.service_name.as_deref().unwrap_or(crate::BINARY_NAME),
+        )
+    }
+}
+
+impl Default for OtlpConfig {
+    fn default() -> Self {
+        Self {
+            endpoint: None,
+            protocol: Some(OtlpProtocolConfig::Grpc),
+            service_name: Some(crate::BINARY_NAME.to_string()),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum OtlpProtocolConfig {
+    Grpc,
+    HttpProtobuf,
+}
+
+impl From<OtlpProtocolConfig> for observability::otlp::OtlpProtocol {
+    fn from(value: OtlpProtocolConfig) -> Self {
+        match value {
+            OtlpProtocolConfig::Grpc => Self::Grpc,
+            OtlpProtocolConfig::HttpProtobuf => Self::HttpProtobuf,
+        }
+    }
+}
+
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
 // Misc
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -706,6 +1312,13 @@ impl Default for UploadsConfig {
 pub struct OutboxConfig {
     pub awaiting_step_secs: Option<i64>,
     pub batch_size: Option<i64>,
+    /// Number of attempts a durable consumer gets to process a message before
+    /// it is moved to dead letters
+    pub max_consumer_attempts: Option<u32>,
+    /// Delay before the first retry of a failed message, doubled on every
+    /// following attempt
+    pub min_retry_backoff_secs: Option<i64>,
+    pub max_retry_backoff_secs: Option<i64>,
 }
 
 impl OutboxConfig {
@@ -719,6 +1332,9 @@ impl Default for OutboxConfig {
         Self {
             awaiting_step_secs: Some(1),
             batch_size: Some(20),
+            max_consumer_attempts: Some(5),
+            min_retry_backoff_secs: Some(1),
+            max_retry_backoff_secs: Some(300),
         }
     }
 }
@@ -14,6 +14,7 @@ use dill::component;
 use internal_error::{InternalError, ResultIntoInternal};
 use kamu_accounts::PROVIDER_PASSWORD;
 use kamu_adapter_http::LoginRequestBody;
+use kamu_adapter_oauth::{OidcClient, OidcDeviceAuthorization, OidcRequestError, PROVIDER_OIDC};
 use serde::Deserialize;
 use serde_json::json;
 use thiserror::Error;
@@ -174,6 +175,87 @@ impl LoginService {
         .await
     }
 
+    /// Obtains an access token from an OpenID Connect provider via device
+    /// authorization flow and exchanges it for a backend access token
+    pub async fn login_oidc_device(
+        &self,
+        odf_server_backend_url: &Url,
+        issuer_url: &str,
+        client_id: &str,
+        scopes: &[String],
+        user_code_callback: impl Fn(&OidcDeviceAuthorization),
+    ) -> Result<BackendLoginResponse, LoginError> {
+        let client = OidcClient::new()?;
+        let provider_metadata = client.discover(issuer_url).await?;
+
+        let device_authorization = client
+            .request_device_authorization(&provider_metadata, client_id, scopes)
+            .await
+            .map_err(Self::map_oidc_error)?;
+
+        user_code_callback(&device_authorization);
+        let verification_uri = device_authorization
+            .verification_uri_complete
+            .as_ref()
+            .unwrap_or(&device_authorization.verification_uri);
+        let _ = webbrowser::open(verification_uri);
+
+        let deadline = tokio::time::Instant::now()
+            + std::time::Duration::from_secs(device_authorization.expires_in);
+        let mut interval =
+            std::time::Duration::from_secs(device_authorization.interval.unwrap_or(5));
+
+        let token = loop {
+            tokio::time::sleep(interval).await;
+
+            if tokio::time::Instant::now() >= deadline {
+                return Err(LoginError::AccessFailed(LoginErrorAccessFailed {
+                    reason: String::from("Device authorization expired"),
+                }));
+            }
+
+            match client
+                .exchange_device_code(
+                    &provider_metadata,
+                    client_id,
+                    &device_authorization.device_code,
+                )
+                .await
+            {
+                Ok(token) => break token,
+                Err(OidcRequestError::Rejected(e)) if e.error == "authorization_pending" => {}
+                Err(OidcRequestError::Rejected(e)) if e.error == "slow_down" => {
+                    interval += std::time::Duration::from_secs(5);
+                }
+                Err(e) => return Err(Self::map_oidc_error(e)),
+            }
+        };
+
+        // ID token lets the server verify that the access token was issued to the
+        // client it trusts
+        let login_credentials_json = json!({
+            "accessToken": token.access_token,
+            "idToken": token.id_token,
+        })
+        .to_string();
+
+        self.invoke_login_method(
+            odf_server_backend_url,
+            PROVIDER_OIDC,
+            login_credentials_json,
+        )
+        .await
+    }
+
+    fn map_oidc_error(e: OidcRequestError) -> LoginError {
+        match e {
+            OidcRequestError::Rejected(e) => LoginError::AccessFailed(LoginErrorAccessFailed {
+                reason: e.to_string(),
+            }),
+            OidcRequestError::Internal(e) => LoginError::Internal(e),
+        }
+    }
+
     pub async fn login_password(
         &self,
         odf_server_backend_url: &Url,
@@ -26,7 +26,8 @@ async fn test_di_graph_validates() {
         &kamu_cli::config::CLIConfig::default(),
         &mut base_catalog_builder,
         false,
-    );
+    )
+    .unwrap();
     let base_catalog = base_catalog_builder.build();
 
     let multi_tenant_workspace = true;
@@ -50,6 +50,12 @@ pub trait AccountRepository: Send + Sync {
         &self,
         account_name: &AccountName,
     ) -> Result<Option<AccountID>, FindAccountIdByNameError>;
+
+    async fn set_account_is_admin(
+        &self,
+        account_id: &AccountID,
+        is_admin: bool,
+    ) -> Result<(), SetAccountIsAdminError>;
 }
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -151,3 +157,14 @@ pub enum FindAccountIdByNameError {
 }
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[derive(Error, Debug)]
+pub enum SetAccountIsAdminError {
+    #[error(transparent)]
+    NotFound(AccountNotFoundByIdError),
+
+    #[error(transparent)]
+    Internal(InternalError),
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
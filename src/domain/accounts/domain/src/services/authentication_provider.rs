@@ -38,6 +38,10 @@ pub struct ProviderLoginResponse {
     pub account_type: AccountType,
     pub avatar_url: Option<String>,
     pub provider_identity_key: String,
+    /// Whether the provider grants administrative privileges to the account.
+    /// When specified the privileges of the account are updated on every
+    /// login, `None` means the provider does not manage them.
+    pub is_admin: Option<bool>,
 }
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -197,7 +197,22 @@ impl AuthenticationService for AuthenticationServiceImpl {
 
         let account_id = match maybe_account_id {
             // Account already exists
-            Some(account_id) => account_id,
+            Some(account_id) => {
+                // Providers that manage administrative privileges are the source of truth
+                // for them, so grants and revocations take effect on the next login
+                if let Some(is_admin) = provider_response.is_admin {
+                    self.account_repository
+                        .set_account_is_admin(&account_id, is_admin)
+                        .await
+                        .map_err(|e| match e {
+                            SetAccountIsAdminError::NotFound(e) => {
+                                LoginError::Internal(e.int_err())
+                            }
+                            SetAccountIsAdminError::Internal(e) => LoginError::Internal(e),
+                        })?;
+                }
+                account_id
+            }
 
             // Account does not exist and needs to be created
             None => {
@@ -213,7 +228,7 @@ impl AuthenticationService for AuthenticationServiceImpl {
                     account_type: provider_response.account_type,
                     avatar_url: provider_response.avatar_url,
                     registered_at: Utc::now(),
-                    is_admin: false,
+                    is_admin: provider_response.is_admin.unwrap_or(false),
                     provider: String::from(login_method),
                     provider_identity_key: provider_response.provider_identity_key,
                 };
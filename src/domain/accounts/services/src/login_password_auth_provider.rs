@@ -148,6 +148,7 @@ impl AuthenticationProvider for LoginPasswordAuthProvider {
             account_type: AccountType::User,
             avatar_url: None,
             provider_identity_key: password_login_credentials.login.to_ascii_lowercase(),
+            is_admin: None,
         })
     }
 }
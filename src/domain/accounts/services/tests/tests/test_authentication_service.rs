@@ -94,6 +94,46 @@ async fn test_use_bad_access_token() {
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
 
+#[test_log::test(tokio::test)]
+async fn test_login_syncs_admin_privileges() {
+    let catalog = make_catalog();
+    let authentication_service = catalog.get_one::<dyn AuthenticationService>().unwrap();
+
+    let is_admin = |login_response: LoginResponse| {
+        let authentication_service = authentication_service.clone();
+        async move {
+            authentication_service
+                .account_by_id(&login_response.account_id)
+                .await
+                .unwrap()
+                .unwrap()
+                .is_admin
+        }
+    };
+
+    let login_response = authentication_service
+        .login("method-B", "admin".to_string())
+        .await
+        .unwrap();
+    assert!(is_admin(login_response).await);
+
+    // Provider that does not manage privileges keeps them as is
+    let login_response = authentication_service
+        .login("method-A", "dummy".to_string())
+        .await
+        .unwrap();
+    assert!(is_admin(login_response).await);
+
+    // Privileges revoked by the provider are revoked on the next login
+    let login_response = authentication_service
+        .login("method-B", "dummy".to_string())
+        .await
+        .unwrap();
+    assert!(!is_admin(login_response).await);
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
 fn make_catalog() -> dill::Catalog {
     let mut b = dill::CatalogBuilder::new();
 
@@ -156,6 +196,7 @@ impl AuthenticationProvider for DummyAuthenticationProviderA {
             account_type: AccountType::User,
             avatar_url: None,
             provider_identity_key: String::from(DEFAULT_ACCOUNT_NAME_STR),
+            is_admin: None,
         })
     }
 }
@@ -172,8 +213,9 @@ impl AuthenticationProvider for DummyAuthenticationProviderB {
 
     async fn login(
         &self,
-        _login_credentials_json: String,
+        login_credentials_json: String,
     ) -> Result<ProviderLoginResponse, ProviderLoginError> {
+        // Grants administrative privileges depending on the credentials
         Ok(ProviderLoginResponse {
             account_name: DEFAULT_ACCOUNT_NAME.clone(),
             email: None,
@@ -181,6 +223,7 @@ impl AuthenticationProvider for DummyAuthenticationProviderB {
             account_type: AccountType::User,
             avatar_url: None,
             provider_identity_key: String::from(DEFAULT_ACCOUNT_NAME_STR),
+            is_admin: Some(login_credentials_json == "admin"),
         })
     }
 }
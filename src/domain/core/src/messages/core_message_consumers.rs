@@ -15,4 +15,7 @@ pub const MESSAGE_CONSUMER_KAMU_CORE_DATASET_OWNERSHIP_SERVICE: &str =
 pub const MESSAGE_CONSUMER_KAMU_CORE_DEPENDENCY_GRAPH_SERVICE: &str =
     "dev.kamu.domain.core.services.DependencyGraphService";
 
+pub const MESSAGE_CONSUMER_KAMU_CORE_DEPENDENCY_GRAPH_REPOSITORY: &str =
+    "dev.kamu.domain.core.services.DependencyGraphRepository";
+
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -7,9 +7,12 @@
 // the Business Source License, use of this software will be governed
 // by the Apache License, Version 2.0.
 
+use chrono::{DateTime, Utc};
 use messaging_outbox::Message;
-use opendatafabric::{AccountID, DatasetID};
+use opendatafabric::serde::yaml::*;
+use opendatafabric::{AccountID, DatasetID, DatasetName, MetadataEvent, Multihash, OffsetInterval};
 use serde::{Deserialize, Serialize};
+use serde_with::{serde_as, skip_serializing_none};
 
 use crate::DatasetVisibility;
 
@@ -20,6 +23,8 @@ pub enum DatasetLifecycleMessage {
     Created(DatasetLifecycleMessageCreated),
     DependenciesUpdated(DatasetLifecycleMessageDependenciesUpdated),
     Deleted(DatasetLifecycleMessageDeleted),
+    DataUpdated(DatasetLifecycleMessageDataUpdated),
+    Renamed(DatasetLifecycleMessageRenamed),
 }
 
 impl DatasetLifecycleMessage {
@@ -45,6 +50,54 @@ impl DatasetLifecycleMessage {
     pub fn deleted(dataset_id: DatasetID) -> Self {
         Self::Deleted(DatasetLifecycleMessageDeleted { dataset_id })
     }
+
+    pub fn data_updated(
+        dataset_id: DatasetID,
+        old_head: Option<Multihash>,
+        new_head: Multihash,
+        new_offset_interval: Option<OffsetInterval>,
+        new_watermark: Option<DateTime<Utc>>,
+    ) -> Self {
+        Self::DataUpdated(DatasetLifecycleMessageDataUpdated {
+            dataset_id,
+            old_head,
+            new_head,
+            new_offset_interval,
+            new_watermark,
+        })
+    }
+
+    /// Produces a [`DatasetLifecycleMessage::DataUpdated`] message for a
+    /// committed event, if the event is a data-bearing one (`AddData` or
+    /// `ExecuteTransform`)
+    pub fn data_updated_by_event(
+        dataset_id: DatasetID,
+        old_head: Option<Multihash>,
+        new_head: Multihash,
+        event: &MetadataEvent,
+    ) -> Option<Self> {
+        let (new_data, new_watermark) = match event {
+            MetadataEvent::AddData(e) => (e.new_data.as_ref(), e.new_watermark),
+            MetadataEvent::ExecuteTransform(e) => (e.new_data.as_ref(), e.new_watermark),
+            _ => return None,
+        };
+
+        Some(Self::data_updated(
+            dataset_id,
+            old_head,
+            new_head,
+            new_data.map(|d| d.offset_interval.clone()),
+            new_watermark,
+        ))
+    }
+
+    pub fn renamed(dataset_id: DatasetID, old_name: DatasetName, new_name: DatasetName) -> Self {
+        Self::Renamed(DatasetLifecycleMessageRenamed {
+            dataset_id,
+            old_name,
+            new_name,
+        })
+    }
 }
 
 impl Message for DatasetLifecycleMessage {}
@@ -75,3 +128,29 @@ pub struct DatasetLifecycleMessageDeleted {
 }
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[serde_as]
+#[skip_serializing_none]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DatasetLifecycleMessageDataUpdated {
+    pub dataset_id: DatasetID,
+    pub old_head: Option<Multihash>,
+    pub new_head: Multihash,
+    /// Offsets of the records added by the update, absent when the update
+    /// only advanced the watermark or the source state
+    #[serde_as(as = "Option<OffsetIntervalDef>")]
+    pub new_offset_interval: Option<OffsetInterval>,
+    #[serde(default, with = "datetime_rfc3339_opt")]
+    pub new_watermark: Option<DateTime<Utc>>,
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DatasetLifecycleMessageRenamed {
+    pub dataset_id: DatasetID,
+    pub old_name: DatasetName,
+    pub new_name: DatasetName,
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
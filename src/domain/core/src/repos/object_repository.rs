@@ -103,6 +103,8 @@ pub enum ObjectRepositoryProtocol {
     LocalFs { base_dir: PathBuf },
     Http,
     S3,
+    Gcs,
+    Azure,
 }
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
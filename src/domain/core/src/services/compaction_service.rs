@@ -18,6 +18,7 @@ use crate::*;
 
 pub const DEFAULT_MAX_SLICE_SIZE: u64 = 300_000_000;
 pub const DEFAULT_MAX_SLICE_RECORDS: u64 = 10_000;
+pub const DEFAULT_ZSTD_COMPRESSION_LEVEL: i32 = 3;
 
 #[async_trait::async_trait]
 pub trait CompactionService: Send + Sync {
@@ -66,6 +67,12 @@ pub enum CompactionError {
         #[backtrace]
         InvalidDatasetKindError,
     ),
+    #[error(transparent)]
+    InvalidDataLayout(
+        #[from]
+        #[backtrace]
+        InvalidDataLayoutError,
+    ),
 }
 
 impl From<GetDatasetError> for CompactionError {
@@ -122,6 +129,20 @@ pub struct InvalidDatasetKindError {
     pub dataset_name: DatasetName,
 }
 
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+#[error("Invalid data layout: {reason}")]
+pub struct InvalidDataLayoutError {
+    pub reason: String,
+}
+
+impl InvalidDataLayoutError {
+    pub fn new(reason: impl Into<String>) -> Self {
+        Self {
+            reason: reason.into(),
+        }
+    }
+}
+
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
 // Progress bar
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -180,6 +201,7 @@ pub struct CompactionOptions {
     pub max_slice_size: Option<u64>,
     pub max_slice_records: Option<u64>,
     pub keep_metadata_only: bool,
+    pub data_layout: CompactionDataLayout,
 }
 
 impl Default for CompactionOptions {
@@ -188,6 +210,74 @@ impl Default for CompactionOptions {
             max_slice_size: Some(DEFAULT_MAX_SLICE_SIZE),
             max_slice_records: Some(DEFAULT_MAX_SLICE_RECORDS),
             keep_metadata_only: false,
+            data_layout: CompactionDataLayout::default(),
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Controls the physical layout of data files produced by hard compaction
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(default)]
+pub struct CompactionDataLayout {
+    /// Columns to sort records by within every compacted slice.
+    ///
+    /// Offsets of sorted records are re-assigned sequentially, so slices keep
+    /// their offset intervals and stay ordered by offset. Slices containing
+    /// retractions or corrections are left in the original order, as
+    /// re-ordering them would change the meaning of the changelog.
+    pub sort_by: Vec<String>,
+
+    /// Maximum number of records in a Parquet row group
+    pub max_row_group_size: Option<u64>,
+
+    /// Whether to write the Parquet page index (column and offset indexes)
+    /// that allows readers to skip individual pages
+    pub page_index: Option<bool>,
+
+    /// Columns to write Parquet bloom filters for
+    pub bloom_filter_columns: Vec<String>,
+
+    /// Compression codec of data files
+    pub compression: Option<CompactionCompression>,
+}
+
+impl CompactionDataLayout {
+    pub fn validate(&self) -> Result<(), InvalidDataLayoutError> {
+        if self.max_row_group_size == Some(0) {
+            return Err(InvalidDataLayoutError::new(
+                "Maximum row group size must be a positive number",
+            ));
         }
+
+        if let Some(CompactionCompression::Zstd { level }) = self.compression
+            && !(1..=22).contains(&level)
+        {
+            return Err(InvalidDataLayoutError::new(format!(
+                "Zstd compression level must be between 1 and 22, got {level}"
+            )));
+        }
+
+        for (name, columns) in [
+            ("sort", &self.sort_by),
+            ("bloom filter", &self.bloom_filter_columns),
+        ] {
+            let mut seen = std::collections::HashSet::new();
+            if let Some(column) = columns.iter().find(|c| !seen.insert(c.as_str())) {
+                return Err(InvalidDataLayoutError::new(format!(
+                    "Column '{column}' is specified more than once in {name} columns"
+                )));
+            }
+        }
+
+        Ok(())
     }
 }
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum CompactionCompression {
+    Uncompressed,
+    Snappy,
+    Zstd { level: i32 },
+}
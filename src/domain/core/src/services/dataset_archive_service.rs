@@ -0,0 +1,145 @@
+// Copyright Kamu Data, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+use std::path::{Path, PathBuf};
+
+use internal_error::InternalError;
+use opendatafabric::*;
+use thiserror::Error;
+
+use crate::*;
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// Service
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Packs datasets into self-contained archives that can be moved between
+/// workspaces without network connectivity and unpacks them back.
+#[async_trait::async_trait]
+pub trait DatasetArchiveService: Send + Sync {
+    /// Writes the metadata chain, data slices, checkpoints and refs of the
+    /// specified datasets into a single `.tar.zst` archive
+    async fn export_datasets(
+        &self,
+        dataset_refs: Vec<DatasetRef>,
+        archive_path: &Path,
+        options: ExportOptions,
+    ) -> Result<ExportResult, ExportError>;
+
+    /// Imports all datasets contained in the archive into the workspace,
+    /// fully validating blocks and hashes of all objects along the way
+    async fn import_datasets(
+        &self,
+        archive_path: &Path,
+        options: ImportOptions,
+    ) -> Result<ImportResult, ImportError>;
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct ExportOptions {
+    /// Whether to also include all transitive upstream dependencies of the
+    /// specified datasets
+    pub recursive: bool,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct ImportOptions {
+    /// Overwrite local datasets even if revisions have diverged
+    pub force: bool,
+}
+
+#[derive(Debug, Clone)]
+pub struct ExportResult {
+    pub archive_path: PathBuf,
+    /// Datasets written into the archive in the order they will be imported in
+    pub datasets: Vec<ArchivedDataset>,
+}
+
+#[derive(Debug)]
+pub struct ImportResult {
+    pub datasets: Vec<(ArchivedDataset, SyncResult)>,
+}
+
+/// Describes a single dataset entry of the archive manifest
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ArchivedDataset {
+    pub id: DatasetID,
+    pub alias: DatasetAlias,
+    pub head: Multihash,
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// Errors
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[derive(Debug, Error)]
+pub enum ExportError {
+    #[error(transparent)]
+    DatasetNotFound(
+        #[from]
+        #[backtrace]
+        DatasetNotFoundError,
+    ),
+    #[error(transparent)]
+    Access(
+        #[from]
+        #[backtrace]
+        AccessError,
+    ),
+    #[error(transparent)]
+    Internal(
+        #[from]
+        #[backtrace]
+        InternalError,
+    ),
+}
+
+impl From<GetDatasetError> for ExportError {
+    fn from(v: GetDatasetError) -> Self {
+        match v {
+            GetDatasetError::NotFound(e) => Self::DatasetNotFound(e),
+            GetDatasetError::Internal(e) => Self::Internal(e),
+        }
+    }
+}
+
+impl From<auth::DatasetActionUnauthorizedError> for ExportError {
+    fn from(v: auth::DatasetActionUnauthorizedError) -> Self {
+        match v {
+            auth::DatasetActionUnauthorizedError::Access(e) => Self::Access(e),
+            auth::DatasetActionUnauthorizedError::Internal(e) => Self::Internal(e),
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[derive(Debug, Error)]
+pub enum ImportError {
+    #[error(transparent)]
+    ArchiveInvalid(#[from] ArchiveInvalidError),
+    #[error("Failed to import dataset {alias}")]
+    SyncFailed {
+        alias: DatasetAlias,
+        #[source]
+        source: SyncError,
+    },
+    #[error(transparent)]
+    Internal(
+        #[from]
+        #[backtrace]
+        InternalError,
+    ),
+}
+
+#[derive(Debug, Error)]
+#[error("Invalid dataset archive: {message}")]
+pub struct ArchiveInvalidError {
+    pub message: String,
+}
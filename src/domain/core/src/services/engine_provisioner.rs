@@ -50,8 +50,16 @@ impl EngineProvisioningListener for NullEngineProvisioningListener {}
 
 #[derive(Debug, Error)]
 pub enum EngineProvisioningError {
+    #[error(transparent)]
+    UnknownEngine(#[from] UnknownEngineError),
     #[error(transparent)]
     ImagePull(#[from] ImagePullError),
     #[error(transparent)]
     Internal(#[from] InternalError),
 }
+
+#[derive(Debug, Error)]
+#[error("Unknown engine '{engine_id}'")]
+pub struct UnknownEngineError {
+    pub engine_id: String,
+}
@@ -14,10 +14,11 @@ use chrono::{DateTime, Utc};
 use datafusion::arrow::datatypes::SchemaRef;
 use datafusion::prelude::*;
 use internal_error::*;
+use kamu_datasets::ExpectationsCheckResult;
 use opendatafabric as odf;
 
 use super::MergeError;
-use crate::{AddDataParams, CommitError, OwnedFile};
+use crate::{AddDataParams, CheckpointRef, CommitError, OwnedFile};
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
 
@@ -74,6 +75,10 @@ pub struct WriteDataOpts {
     pub new_watermark: Option<DateTime<Utc>>,
     /// Data source state to store in the commit
     pub new_source_state: Option<odf::SourceState>,
+    /// Filter selecting previously written records that are no longer valid
+    /// (e.g. belong to blocks orphaned by a blockchain reorganization). Such
+    /// records will be retracted in the same commit with the new data.
+    pub retract_previous: Option<Expr>,
     // TODO: Find a better way to deal with temporary files
     /// Local FS path to which data slice will be written before committing it
     /// into the data object store of a dataset
@@ -87,6 +92,8 @@ pub struct WriteDataResult {
     pub old_head: odf::Multihash,
     pub new_head: odf::Multihash,
     pub add_data_block: Option<odf::MetadataBlockTyped<odf::AddData>>,
+    /// Set when the slice was validated against dataset expectations
+    pub expectations_check: Option<ExpectationsCheckResult>,
 }
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -101,6 +108,14 @@ pub struct StageDataResult {
     pub add_data: Option<AddDataParams>,
     /// Set when commmit will contains some data
     pub data_file: Option<OwnedFile>,
+    /// Set when the merge state checkpoint is carried over or a new one was
+    /// produced
+    pub checkpoint: Option<CheckpointRef>,
+    /// Set when the slice was validated against dataset expectations
+    pub expectations_check: Option<ExpectationsCheckResult>,
+    /// Set when some records were quarantined, will be persisted in the
+    /// dataset's info repository on commit
+    pub quarantine_file: Option<OwnedFile>,
 }
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -124,6 +139,9 @@ pub enum WriteDataError {
     #[error(transparent)]
     BadInputSchema(#[from] BadInputSchemaError),
 
+    #[error(transparent)]
+    ExpectationsFailed(#[from] ExpectationsFailedError),
+
     #[error(transparent)]
     IncompatibleSchema(#[from] IncompatibleSchemaError),
 
@@ -144,6 +162,7 @@ impl From<StageDataError> for WriteDataError {
     fn from(value: StageDataError) -> Self {
         match value {
             StageDataError::BadInputSchema(v) => WriteDataError::BadInputSchema(v),
+            StageDataError::ExpectationsFailed(v) => WriteDataError::ExpectationsFailed(v),
             StageDataError::IncompatibleSchema(v) => WriteDataError::IncompatibleSchema(v),
             StageDataError::MergeError(v) => WriteDataError::MergeError(v),
             StageDataError::EmptyCommit(v) => WriteDataError::EmptyCommit(v),
@@ -159,6 +178,9 @@ pub enum StageDataError {
     #[error(transparent)]
     BadInputSchema(#[from] BadInputSchemaError),
 
+    #[error(transparent)]
+    ExpectationsFailed(#[from] ExpectationsFailedError),
+
     #[error(transparent)]
     IncompatibleSchema(#[from] IncompatibleSchemaError),
 
@@ -239,6 +261,32 @@ pub struct EmptyCommitError {}
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
 
+#[derive(Debug, thiserror::Error)]
+pub struct ExpectationsFailedError {
+    pub check: ExpectationsCheckResult,
+}
+
+impl std::fmt::Display for ExpectationsFailedError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Data quality expectations failed:")?;
+        for outcome in self
+            .check
+            .outcomes
+            .iter()
+            .filter(|o| o.policy == kamu_datasets::ExpectationPolicy::Fail && o.is_violated())
+        {
+            write!(
+                f,
+                "\n  {}: {} violation(s)",
+                outcome.expectation_name, outcome.num_violations
+            )?;
+        }
+        Ok(())
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
 struct FmtSchema<'a>(&'a SchemaRef);
 
 impl<'a> std::fmt::Display for FmtSchema<'a> {
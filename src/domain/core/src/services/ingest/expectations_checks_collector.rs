@@ -0,0 +1,104 @@
+// Copyright Kamu Data, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+use std::sync::{Arc, Mutex};
+
+use kamu_datasets::ExpectationsCheckResult;
+use opendatafabric::{DatasetHandle, DatasetID};
+
+use crate::{
+    PollingIngestListener,
+    PollingIngestMultiListener,
+    PullMultiListener,
+    PushIngestListener,
+    SyncMultiListener,
+    TransformMultiListener,
+};
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Collects the results of data quality expectations evaluated during ingest,
+/// so that callers can record them once the ingest is over. Checks of rejected
+/// slices are collected too, to be recorded outside of the rolled back
+/// transaction.
+#[derive(Debug, Default, Clone)]
+pub struct ExpectationsChecksCollector {
+    dataset_id: Option<DatasetID>,
+    checks: Arc<Mutex<Vec<(DatasetID, ExpectationsCheckResult)>>>,
+}
+
+impl ExpectationsChecksCollector {
+    /// Creates a collector for a push ingest into the specified dataset. Use
+    /// [`Default`] to collect checks of pulls that may span multiple datasets.
+    pub fn for_dataset(dataset_id: DatasetID) -> Self {
+        Self {
+            dataset_id: Some(dataset_id),
+            checks: Arc::default(),
+        }
+    }
+
+    /// Returns all checks collected so far along with the IDs of datasets
+    /// they belong to
+    pub fn take(&self) -> Vec<(DatasetID, ExpectationsCheckResult)> {
+        std::mem::take(&mut *self.checks.lock().unwrap())
+    }
+
+    fn push(&self, check: &ExpectationsCheckResult) {
+        let Some(dataset_id) = &self.dataset_id else {
+            // Unbound collector is only meant to spawn per-dataset listeners
+            tracing::error!(
+                expectations_check = ?check,
+                "Dropping expectations check reported to a collector not bound to a dataset",
+            );
+            return;
+        };
+
+        self.checks
+            .lock()
+            .unwrap()
+            .push((dataset_id.clone(), check.clone()));
+    }
+}
+
+impl PushIngestListener for ExpectationsChecksCollector {
+    fn on_expectations_checked(&self, check: &ExpectationsCheckResult) {
+        self.push(check);
+    }
+}
+
+impl PollingIngestListener for ExpectationsChecksCollector {
+    fn on_expectations_checked(&self, check: &ExpectationsCheckResult) {
+        self.push(check);
+    }
+}
+
+impl PollingIngestMultiListener for ExpectationsChecksCollector {
+    fn begin_ingest(&self, dataset: &DatasetHandle) -> Option<Arc<dyn PollingIngestListener>> {
+        Some(Arc::new(Self {
+            dataset_id: Some(dataset.id.clone()),
+            checks: self.checks.clone(),
+        }))
+    }
+}
+
+impl PullMultiListener for ExpectationsChecksCollector {
+    fn get_ingest_listener(self: Arc<Self>) -> Option<Arc<dyn PollingIngestMultiListener>> {
+        Some(self)
+    }
+
+    fn get_transform_listener(self: Arc<Self>) -> Option<Arc<dyn TransformMultiListener>> {
+        None
+    }
+
+    fn get_sync_listener(self: Arc<Self>) -> Option<Arc<dyn SyncMultiListener>> {
+        None
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -39,6 +39,21 @@ pub trait MergeStrategy: Send + Sync {
     /// Returns the sort expression best suited for the output of this strategy
     /// to perform before writing the final result.
     fn sort_order(&self) -> Vec<Expr>;
+
+    /// Reduces the ledger to the minimal state sufficient for subsequent
+    /// merges.
+    ///
+    /// Strategies that depend only on the current state of the data rather
+    /// than its full history can return such state, which can then be
+    /// persisted and passed as `prev` into [`MergeStrategy::merge`] in place
+    /// of the full ledger. The state must have the same schema as the ledger,
+    /// and projecting a state combined with newly merged records must produce
+    /// the next state.
+    ///
+    /// Returns `None` if the strategy requires the full history.
+    fn project_state(&self, _ledger: DataFrame) -> Result<Option<DataFrame>, MergeError> {
+        Ok(None)
+    }
 }
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -9,6 +9,7 @@
 
 mod data_format_registry;
 mod data_writer;
+mod expectations_checks_collector;
 mod merge_strategy;
 mod polling_ingest_service;
 mod push_ingest_service;
@@ -16,6 +17,7 @@ mod reader;
 
 pub use data_format_registry::*;
 pub use data_writer::*;
+pub use expectations_checks_collector::*;
 pub use merge_strategy::*;
 pub use polling_ingest_service::*;
 pub use push_ingest_service::*;
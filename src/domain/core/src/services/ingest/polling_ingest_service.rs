@@ -15,7 +15,12 @@ use std::sync::Arc;
 use chrono::{DateTime, Utc};
 use container_runtime::ImagePullError;
 use internal_error::{BoxedError, InternalError};
-use kamu_datasets::{DatasetEnvVar, FindDatasetEnvVarError};
+use kamu_datasets::{
+    DatasetEnvVar,
+    DatasetExpectation,
+    ExpectationsCheckResult,
+    FindDatasetEnvVarError,
+};
 use opendatafabric::*;
 use thiserror::Error;
 
@@ -64,6 +69,9 @@ pub struct PollingIngestOptions {
     /// Dataset env vars to use if such presented in dataset metadata
     /// to use during fetch phase
     pub dataset_env_vars: HashMap<String, DatasetEnvVar>,
+    /// Data quality expectations to evaluate against every new slice, keyed
+    /// by the dataset they belong to
+    pub dataset_expectations: HashMap<DatasetID, Vec<DatasetExpectation>>,
     /// Schema inference configuration
     pub schema_inference: SchemaInferenceOpts,
 }
@@ -133,6 +141,10 @@ pub trait PollingIngestListener: Send + Sync {
     fn success(&self, result: &PollingIngestResult) {}
     fn error(&self, error: &PollingIngestError) {}
 
+    /// Called when a slice was evaluated against dataset expectations, either
+    /// after it was committed or when it was rejected
+    fn on_expectations_checked(&self, check: &ExpectationsCheckResult) {}
+
     fn get_pull_image_listener(self: Arc<Self>) -> Option<Arc<dyn PullImageListener>> {
         None
     }
@@ -273,6 +285,13 @@ pub enum PollingIngestError {
         BadInputSchemaError,
     ),
 
+    #[error(transparent)]
+    ExpectationsFailed(
+        #[from]
+        #[backtrace]
+        ExpectationsFailedError,
+    ),
+
     #[error(transparent)]
     IncompatibleSchema(
         #[from]
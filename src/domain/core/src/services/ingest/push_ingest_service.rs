@@ -11,6 +11,7 @@ use std::sync::Arc;
 
 use chrono::{DateTime, Utc};
 use internal_error::InternalError;
+use kamu_datasets::{DatasetExpectation, ExpectationsCheckResult};
 use opendatafabric::*;
 use thiserror::Error;
 use tokio::io::AsyncRead;
@@ -66,6 +67,8 @@ pub struct PushIngestOpts {
     pub source_event_time: Option<DateTime<Utc>>,
     /// Whether to automatically create a push source if it doesn't exist
     pub auto_create_push_source: bool,
+    /// Data quality expectations to evaluate against every new slice
+    pub dataset_expectations: Vec<DatasetExpectation>,
     /// Schema inference configuration
     pub schema_inference: SchemaInferenceOpts,
 }
@@ -101,6 +104,10 @@ pub trait PushIngestListener: Send + Sync {
     fn success(&self, result: &PushIngestResult) {}
     fn error(&self, error: &PushIngestError) {}
 
+    /// Called when a slice was evaluated against dataset expectations, either
+    /// after it was committed or when it was rejected
+    fn on_expectations_checked(&self, check: &ExpectationsCheckResult) {}
+
     fn get_pull_image_listener(self: Arc<Self>) -> Option<Arc<dyn PullImageListener>> {
         None
     }
@@ -164,6 +171,13 @@ pub enum PushIngestError {
         BadInputSchemaError,
     ),
 
+    #[error(transparent)]
+    ExpectationsFailed(
+        #[from]
+        #[backtrace]
+        ExpectationsFailedError,
+    ),
+
     #[error(transparent)]
     IncompatibleSchema(
         #[from]
@@ -11,6 +11,7 @@
 pub use container_runtime::{NullPullImageListener, PullImageListener};
 
 pub mod compaction_service;
+pub mod dataset_archive_service;
 pub mod dataset_changes_service;
 pub mod dataset_ownership_service;
 pub mod dependency_graph_repository;
@@ -33,6 +34,7 @@ pub mod transform_service;
 pub mod verification_service;
 
 pub use compaction_service::*;
+pub use dataset_archive_service::*;
 pub use dataset_changes_service::*;
 pub use dataset_ownership_service::*;
 pub use dependency_graph_repository::*;
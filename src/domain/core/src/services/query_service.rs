@@ -9,6 +9,7 @@
 
 use std::collections::BTreeMap;
 
+use chrono::{DateTime, Utc};
 use datafusion::arrow;
 use datafusion::parquet::schema::types::Type;
 use datafusion::prelude::{DataFrame, SessionContext};
@@ -128,12 +129,36 @@ pub struct QueryState {
     /// Last block hases of input datasets that were considered during the query
     /// planning
     pub inputs: BTreeMap<DatasetID, Multihash>,
+    /// Blocks that time-travel table functions (`dataset_at()` and
+    /// `dataset_as_of()`) were resolved to during the query planning. When
+    /// passed back via [`QueryOptions::as_of_state`] the functions will be
+    /// pinned to the same blocks.
+    pub time_travel_inputs: BTreeMap<TimeTravelInput, Multihash>,
+}
+
+/// Identifies a dataset state requested via a time-travel table function
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub struct TimeTravelInput {
+    pub dataset_id: DatasetID,
+    pub selector: TimeTravelSelector,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub enum TimeTravelSelector {
+    /// Block hash or a named reference (e.g. `head`), as used by
+    /// `dataset_at('my.dataset', '<block>')`
+    Block(String),
+    /// Last block with system time not greater than specified, as used by
+    /// `dataset_as_of('my.dataset', TIMESTAMP '<time>')`
+    SystemTime(DateTime<Utc>),
 }
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct EngineDesc {
+    /// Identifier of the engine as used in transformations, e.g. "datafusion"
+    pub id: String,
     /// A short name of the engine, e.g. "Spark", "Flink", "Datafusion"
     /// Intended for use in UI for quick engine identification and selection
     pub name: String,
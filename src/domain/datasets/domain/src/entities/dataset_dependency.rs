@@ -0,0 +1,30 @@
+// Copyright Kamu Data, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+use opendatafabric::DatasetID;
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// A single edge of the dataset dependency graph
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct DatasetDependencyEntry {
+    pub downstream_dataset_id: DatasetID,
+    pub upstream_dataset_id: DatasetID,
+}
+
+impl DatasetDependencyEntry {
+    pub fn new(downstream_dataset_id: DatasetID, upstream_dataset_id: DatasetID) -> Self {
+        Self {
+            downstream_dataset_id,
+            upstream_dataset_id,
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -9,6 +9,7 @@
 
 use aes_gcm::aead::consts::U12;
 use aes_gcm::aead::generic_array::GenericArray;
+use aes_gcm::aead::rand_core::RngCore;
 use aes_gcm::aead::{Aead, AeadCore, KeyInit, OsRng};
 use aes_gcm::aes::Aes256;
 use aes_gcm::{Aes256Gcm, AesGcm, Key};
@@ -24,7 +25,7 @@ use uuid::Uuid;
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
 
-pub const SAMPLE_DATASET_ENV_VAR_ENCRYPTION_KEY: &str = "QfnEDcnUtGSW2pwVXaFPvZOwxyFm2BOC";
+pub const DATASET_ENV_VAR_ENCRYPTION_KEY_LENGTH: usize = 32;
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
 
@@ -81,6 +82,33 @@ impl DatasetEnvVar {
         None
     }
 
+    /// Generates a random alphanumeric key suitable for encrypting secrets
+    pub fn generate_encryption_key() -> String {
+        const ALPHABET: &[u8] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789";
+        // Bytes above the largest multiple of the alphabet size are rejected
+        // to keep the distribution uniform
+        const MAX_BYTE: usize = 256 - 256 % ALPHABET.len();
+
+        let mut key = String::with_capacity(DATASET_ENV_VAR_ENCRYPTION_KEY_LENGTH);
+        let mut random_bytes = [0u8; DATASET_ENV_VAR_ENCRYPTION_KEY_LENGTH * 2];
+
+        while key.len() < DATASET_ENV_VAR_ENCRYPTION_KEY_LENGTH {
+            OsRng.fill_bytes(&mut random_bytes);
+
+            let remaining = DATASET_ENV_VAR_ENCRYPTION_KEY_LENGTH - key.len();
+            key.extend(
+                random_bytes
+                    .iter()
+                    .map(|b| usize::from(*b))
+                    .filter(|b| *b < MAX_BYTE)
+                    .map(|b| char::from(ALPHABET[b % ALPHABET.len()]))
+                    .take(remaining),
+            );
+        }
+
+        key
+    }
+
     pub fn try_asm_256_gcm_from_str(
         encryption_key: &str,
     ) -> Result<AesGcm<Aes256, U12>, ParseEncryptionKey> {
@@ -96,7 +124,7 @@ impl DatasetEnvVar {
         encryption_key: &str,
     ) -> Result<String, DatasetEnvVarEncryptionError> {
         if let Some(secret_nonce) = self.secret_nonce.as_ref() {
-            let cipher = Aes256Gcm::new(Key::<Aes256Gcm>::from_slice(encryption_key.as_bytes()));
+            let cipher = Self::try_asm_256_gcm_from_str(encryption_key)?;
             let decypted_value = cipher
                 .decrypt(
                     GenericArray::from_slice(secret_nonce.as_slice()),
@@ -112,6 +140,54 @@ impl DatasetEnvVar {
         Ok(std::str::from_utf8(&self.value).unwrap().to_string())
     }
 
+    /// Tries the keys in order and returns the value decrypted with the first
+    /// one that fits, so that secrets encrypted before a key rotation remain
+    /// readable
+    pub fn get_exposed_decrypted_value_with_any_key<'a>(
+        &self,
+        encryption_keys: impl IntoIterator<Item = &'a str>,
+    ) -> Result<String, DatasetEnvVarEncryptionError> {
+        let mut last_error = DatasetEnvVarEncryptionError::InvalidEncryptionKey;
+        for encryption_key in encryption_keys {
+            match self.get_exposed_decrypted_value(encryption_key) {
+                Ok(value) => return Ok(value),
+                Err(err) => last_error = err,
+            }
+        }
+        Err(last_error)
+    }
+
+    /// Decrypts the secret value with one of the given keys and encrypts it
+    /// again with the new key and a fresh nonce. Returns `None` for non-secret
+    /// values, as they are stored in plain text, and for values that are
+    /// already encrypted with the new key, which makes interrupted rotations
+    /// safe to repeat.
+    pub fn reencrypt_value<'a>(
+        &self,
+        encryption_keys: impl IntoIterator<Item = &'a str>,
+        new_encryption_key: &str,
+    ) -> Result<Option<(Vec<u8>, Vec<u8>)>, DatasetEnvVarEncryptionError> {
+        if self.secret_nonce.is_none() {
+            return Ok(None);
+        }
+
+        let new_cipher = Self::try_asm_256_gcm_from_str(new_encryption_key)?;
+        if self.get_exposed_decrypted_value(new_encryption_key).is_ok() {
+            return Ok(None);
+        }
+
+        let decrypted_value = self.get_exposed_decrypted_value_with_any_key(encryption_keys)?;
+
+        let new_nonce = Aes256Gcm::generate_nonce(&mut OsRng);
+        let new_value = new_cipher
+            .encrypt(&new_nonce, decrypted_value.as_bytes())
+            .map_err(|err| DatasetEnvVarEncryptionError::InvalidCipherKeyError {
+                source: Box::new(AesGcmError(err)),
+            })?;
+
+        Ok(Some((new_value, new_nonce.to_vec())))
+    }
+
     pub fn generate_new_value(
         &self,
         dataset_env_var_new_value: &DatasetEnvVarValue,
@@ -253,13 +329,22 @@ pub struct DatasetEnvVarsConfig {
     /// Some(String::from("aBcDeFgHiJkLmNoPqRsTuVwXyZ012345")) };
     /// ```
     pub encryption_key: Option<String>,
+    /// Keys that were replaced by `encryption_key` during a rotation. Secrets
+    /// encrypted with them stay readable until `kamu system
+    /// rotate-secrets-key` re-encrypts them with the current key.
+    pub previous_encryption_keys: Option<Vec<String>>,
+    /// External storage that `${{ env.X }}` references are resolved from when
+    /// the variable is not defined for the dataset itself
+    pub secret_provider: Option<SecretProviderConfig>,
 }
 
 impl DatasetEnvVarsConfig {
     pub fn sample() -> Self {
         Self {
             enabled: Some(true),
-            encryption_key: Some(SAMPLE_DATASET_ENV_VAR_ENCRYPTION_KEY.to_string()),
+            encryption_key: Some(DatasetEnvVar::generate_encryption_key()),
+            previous_encryption_keys: Some(Vec::new()),
+            secret_provider: Some(SecretProviderConfig::sample()),
         }
     }
 
@@ -276,13 +361,71 @@ impl DatasetEnvVarsConfig {
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
 
+#[skip_serializing_none]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+#[serde(rename_all = "camelCase")]
+#[serde(tag = "kind")]
+pub enum SecretProviderConfig {
+    /// HashiCorp Vault KV secrets engine (version 2)
+    Vault(VaultSecretProviderConfig),
+    /// Directory with one file per secret, as mounted by Kubernetes
+    Files(FilesSecretProviderConfig),
+}
+
+impl SecretProviderConfig {
+    pub fn sample() -> Self {
+        Self::Vault(VaultSecretProviderConfig {
+            url: String::from("http://localhost:8200"),
+            mount: Some(String::from("secret")),
+            path: String::from("kamu/datasets"),
+            token: None,
+            cache_ttl_secs: Some(60),
+        })
+    }
+}
+
+#[skip_serializing_none]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+#[serde(rename_all = "camelCase")]
+pub struct VaultSecretProviderConfig {
+    /// Address of the Vault server
+    pub url: String,
+    /// Mount point of the KV v2 secrets engine, `secret` by default
+    pub mount: Option<String>,
+    /// Path of the secret under the mount point whose keys are the names of
+    /// the variables
+    pub path: String,
+    /// Access token, falls back to `VAULT_TOKEN` environment variable if not
+    /// specified
+    pub token: Option<String>,
+    /// How long the fetched secret is reused before reading it from Vault
+    /// again, 60 seconds by default
+    pub cache_ttl_secs: Option<u64>,
+}
+
+#[skip_serializing_none]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+#[serde(rename_all = "camelCase")]
+pub struct FilesSecretProviderConfig {
+    /// Directory where every file name is the name of a variable and the
+    /// contents is its value
+    pub path: String,
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
 #[cfg(test)]
 mod tests {
     use chrono::Utc;
     use opendatafabric::DatasetID;
     use secrecy::Secret;
 
-    use crate::{DatasetEnvVar, SAMPLE_DATASET_ENV_VAR_ENCRYPTION_KEY};
+    use crate::{DatasetEnvVar, DATASET_ENV_VAR_ENCRYPTION_KEY_LENGTH};
+
+    const TEST_ENCRYPTION_KEY: &str = "QfnEDcnUtGSW2pwVXaFPvZOwxyFm2BOC";
 
     #[test]
     fn test_secret_env_var_generation() {
@@ -292,12 +435,12 @@ mod tests {
             Utc::now(),
             &crate::DatasetEnvVarValue::Secret(Secret::new(secret_value.to_string())),
             &DatasetID::new_seeded_ed25519(b"foo"),
-            SAMPLE_DATASET_ENV_VAR_ENCRYPTION_KEY,
+            TEST_ENCRYPTION_KEY,
         )
         .unwrap();
 
         let original_value = new_env_var
-            .get_exposed_decrypted_value(SAMPLE_DATASET_ENV_VAR_ENCRYPTION_KEY)
+            .get_exposed_decrypted_value(TEST_ENCRYPTION_KEY)
             .unwrap();
         assert_eq!(secret_value, original_value.as_str());
     }
@@ -310,13 +453,116 @@ mod tests {
             Utc::now(),
             &crate::DatasetEnvVarValue::Regular(value.to_string()),
             &DatasetID::new_seeded_ed25519(b"foo"),
-            SAMPLE_DATASET_ENV_VAR_ENCRYPTION_KEY,
+            TEST_ENCRYPTION_KEY,
         )
         .unwrap();
 
         let original_value = new_env_var
-            .get_exposed_decrypted_value(SAMPLE_DATASET_ENV_VAR_ENCRYPTION_KEY)
+            .get_exposed_decrypted_value(TEST_ENCRYPTION_KEY)
             .unwrap();
         assert_eq!(value, original_value.as_str());
     }
+
+    #[test]
+    fn test_secret_env_var_reencryption() {
+        let secret_value = "foo";
+        let new_encryption_key = "aBcDeFgHiJkLmNoPqRsTuVwXyZ012345";
+        let mut env_var = DatasetEnvVar::new(
+            "foo_key",
+            Utc::now(),
+            &crate::DatasetEnvVarValue::Secret(Secret::new(secret_value.to_string())),
+            &DatasetID::new_seeded_ed25519(b"foo"),
+            TEST_ENCRYPTION_KEY,
+        )
+        .unwrap();
+
+        let (new_value, new_nonce) = env_var
+            .reencrypt_value([TEST_ENCRYPTION_KEY], new_encryption_key)
+            .unwrap()
+            .unwrap();
+        assert_ne!(Some(&new_nonce), env_var.secret_nonce.as_ref());
+
+        env_var.value = new_value;
+        env_var.secret_nonce = Some(new_nonce);
+
+        assert_eq!(
+            env_var
+                .get_exposed_decrypted_value(new_encryption_key)
+                .unwrap(),
+            secret_value
+        );
+        assert!(env_var
+            .get_exposed_decrypted_value(TEST_ENCRYPTION_KEY)
+            .is_err());
+    }
+
+    #[test]
+    fn test_non_secret_env_var_reencryption_is_noop() {
+        let env_var = DatasetEnvVar::new(
+            "foo_key",
+            Utc::now(),
+            &crate::DatasetEnvVarValue::Regular("foo".to_string()),
+            &DatasetID::new_seeded_ed25519(b"foo"),
+            TEST_ENCRYPTION_KEY,
+        )
+        .unwrap();
+
+        assert!(env_var
+            .reencrypt_value([TEST_ENCRYPTION_KEY], "aBcDeFgHiJkLmNoPqRsTuVwXyZ012345")
+            .unwrap()
+            .is_none());
+    }
+
+    #[test]
+    fn test_reencryption_with_current_key_is_noop() {
+        let env_var = DatasetEnvVar::new(
+            "foo_key",
+            Utc::now(),
+            &crate::DatasetEnvVarValue::Secret(Secret::new("foo".to_string())),
+            &DatasetID::new_seeded_ed25519(b"foo"),
+            TEST_ENCRYPTION_KEY,
+        )
+        .unwrap();
+
+        assert!(env_var
+            .reencrypt_value(["aBcDeFgHiJkLmNoPqRsTuVwXyZ012345"], TEST_ENCRYPTION_KEY)
+            .unwrap()
+            .is_none());
+    }
+
+    #[test]
+    fn test_decryption_with_previous_key() {
+        let previous_encryption_key = "aBcDeFgHiJkLmNoPqRsTuVwXyZ012345";
+        let env_var = DatasetEnvVar::new(
+            "foo_key",
+            Utc::now(),
+            &crate::DatasetEnvVarValue::Secret(Secret::new("foo".to_string())),
+            &DatasetID::new_seeded_ed25519(b"foo"),
+            previous_encryption_key,
+        )
+        .unwrap();
+
+        assert_eq!(
+            env_var
+                .get_exposed_decrypted_value_with_any_key([
+                    TEST_ENCRYPTION_KEY,
+                    previous_encryption_key
+                ])
+                .unwrap(),
+            "foo"
+        );
+        assert!(env_var
+            .get_exposed_decrypted_value_with_any_key([TEST_ENCRYPTION_KEY, "too-short"])
+            .is_err());
+    }
+
+    #[test]
+    fn test_generate_encryption_key() {
+        let key = DatasetEnvVar::generate_encryption_key();
+
+        assert_eq!(key.len(), DATASET_ENV_VAR_ENCRYPTION_KEY_LENGTH);
+        assert!(key.chars().all(|c| c.is_ascii_alphanumeric()));
+        assert!(DatasetEnvVar::try_asm_256_gcm_from_str(&key).is_ok());
+        assert_ne!(key, DatasetEnvVar::generate_encryption_key());
+    }
 }
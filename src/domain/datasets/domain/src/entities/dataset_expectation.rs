@@ -0,0 +1,338 @@
+// Copyright Kamu Data, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+use std::collections::HashSet;
+
+use chrono::{DateTime, Utc};
+use opendatafabric::{DatasetID, Multihash};
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+use thiserror::Error;
+use uuid::Uuid;
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// A declarative data quality rule that is evaluated against every new slice
+/// of a root dataset before it gets committed
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+#[serde(rename_all = "camelCase")]
+pub struct DatasetExpectation {
+    /// Unique name of the expectation within the dataset
+    pub name: String,
+    pub rule: ExpectationRule,
+    pub policy: ExpectationPolicy,
+}
+
+impl DatasetExpectation {
+    pub fn validate(&self) -> Result<(), InvalidExpectationError> {
+        let invalid = |reason: &str| {
+            Err(InvalidExpectationError {
+                expectation_name: self.name.clone(),
+                reason: reason.to_string(),
+            })
+        };
+
+        if self.name.trim().is_empty() {
+            return invalid("Name cannot be empty");
+        }
+
+        match &self.rule {
+            ExpectationRule::NotNull { column }
+            | ExpectationRule::AllowedValues { column, .. }
+            | ExpectationRule::Regex { column, .. }
+            | ExpectationRule::Range { column, .. }
+                if column.is_empty() =>
+            {
+                invalid("Column name cannot be empty")
+            }
+            ExpectationRule::Unique { columns } if columns.is_empty() => {
+                invalid("At least one key column is required")
+            }
+            ExpectationRule::AllowedValues { values, .. } if values.is_empty() => {
+                invalid("At least one allowed value is required")
+            }
+            ExpectationRule::Range { min, max, .. } => match (min, max) {
+                (None, None) => invalid("Either min or max bound is required"),
+                (Some(min), Some(max)) if min > max => {
+                    invalid("Min bound is greater than max bound")
+                }
+                _ => Ok(()),
+            },
+            ExpectationRule::RowCount { min, max } => match (min, max) {
+                _ if self.policy == ExpectationPolicy::Quarantine => invalid(
+                    "Row count expectations apply to the whole slice and cannot quarantine rows",
+                ),
+                (None, None) => invalid("Either min or max bound is required"),
+                (Some(min), Some(max)) if min > max => {
+                    invalid("Min bound is greater than max bound")
+                }
+                _ => Ok(()),
+            },
+            ExpectationRule::Regex { pattern, .. } => match regex::Regex::new(pattern) {
+                Ok(_) => Ok(()),
+                Err(e) => invalid(&format!("Invalid regular expression: {e}")),
+            },
+            _ => Ok(()),
+        }
+    }
+
+    /// Validates a full set of expectations of a dataset
+    pub fn validate_all(
+        expectations: &[DatasetExpectation],
+    ) -> Result<(), InvalidExpectationError> {
+        let mut names = HashSet::new();
+        for expectation in expectations {
+            expectation.validate()?;
+
+            if !names.insert(expectation.name.as_str()) {
+                return Err(InvalidExpectationError {
+                    expectation_name: expectation.name.clone(),
+                    reason: "Duplicate expectation name".to_string(),
+                });
+            }
+        }
+        Ok(())
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[skip_serializing_none]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+#[serde(rename_all = "camelCase")]
+#[serde(tag = "kind")]
+pub enum ExpectationRule {
+    /// Column must not contain nulls
+    NotNull { column: String },
+    /// Combination of key columns must be unique within the slice
+    Unique { columns: Vec<String> },
+    /// Non-null values of the column must be one of the listed values
+    AllowedValues { column: String, values: Vec<String> },
+    /// Non-null numeric values of the column must be within inclusive bounds
+    Range {
+        column: String,
+        min: Option<f64>,
+        max: Option<f64>,
+    },
+    /// Non-null values of the column must match the regular expression
+    Regex { column: String, pattern: String },
+    /// Number of records in the slice must be within inclusive bounds
+    RowCount { min: Option<u64>, max: Option<u64> },
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum ExpectationPolicy {
+    /// Reject the whole ingest
+    Fail,
+    /// Exclude violating rows from the slice and keep them aside
+    Quarantine,
+    /// Only record the violation
+    Warn,
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Result of evaluating a single expectation against a slice
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ExpectationOutcome {
+    pub expectation_name: String,
+    pub policy: ExpectationPolicy,
+    /// Number of violating rows, or `1` for slice-level rules
+    pub num_violations: u64,
+}
+
+impl ExpectationOutcome {
+    pub fn is_violated(&self) -> bool {
+        self.num_violations != 0
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Record of evaluating all expectations of a dataset during one ingest
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DatasetExpectationsCheck {
+    pub id: Uuid,
+    pub dataset_id: DatasetID,
+    pub checked_at: DateTime<Utc>,
+    /// Number of records in the slice before any rows were quarantined
+    pub num_records: u64,
+    /// Number of records excluded from the slice by quarantine rules
+    pub num_quarantined: u64,
+    /// Head of the dataset after the commit or `None` if ingest was rejected
+    pub new_head: Option<Multihash>,
+    pub outcomes: Vec<ExpectationOutcome>,
+}
+
+impl DatasetExpectationsCheck {
+    pub fn is_rejected(&self) -> bool {
+        self.outcomes
+            .iter()
+            .any(|o| o.policy == ExpectationPolicy::Fail && o.is_violated())
+    }
+}
+
+#[cfg(feature = "sqlx")]
+#[derive(Debug, Clone, sqlx::FromRow, PartialEq, Eq)]
+pub struct DatasetExpectationsCheckRowModel {
+    pub id: Uuid,
+    pub dataset_id: DatasetID,
+    pub checked_at: DateTime<Utc>,
+    pub num_records: i64,
+    pub num_quarantined: i64,
+    pub new_head: Option<String>,
+    pub outcomes: String,
+}
+
+#[cfg(feature = "sqlx")]
+impl TryFrom<DatasetExpectationsCheckRowModel> for DatasetExpectationsCheck {
+    type Error = internal_error::InternalError;
+
+    fn try_from(value: DatasetExpectationsCheckRowModel) -> Result<Self, Self::Error> {
+        use internal_error::ResultIntoInternal;
+
+        Ok(DatasetExpectationsCheck {
+            id: value.id,
+            dataset_id: value.dataset_id,
+            checked_at: value.checked_at,
+            num_records: u64::try_from(value.num_records).int_err()?,
+            num_quarantined: u64::try_from(value.num_quarantined).int_err()?,
+            new_head: value
+                .new_head
+                .as_deref()
+                .map(Multihash::from_multibase)
+                .transpose()
+                .int_err()?,
+            outcomes: serde_json::from_str(&value.outcomes).int_err()?,
+        })
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[derive(Error, Debug)]
+#[error("Invalid expectation '{expectation_name}': {reason}")]
+pub struct InvalidExpectationError {
+    pub expectation_name: String,
+    pub reason: String,
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn expectation(rule: ExpectationRule, policy: ExpectationPolicy) -> DatasetExpectation {
+        DatasetExpectation {
+            name: "test".to_string(),
+            rule,
+            policy,
+        }
+    }
+
+    #[test]
+    fn test_expectation_serde() {
+        let expectation = expectation(
+            ExpectationRule::Range {
+                column: "price".to_string(),
+                min: Some(0.0),
+                max: None,
+            },
+            ExpectationPolicy::Quarantine,
+        );
+
+        let json = serde_json::to_string(&expectation).unwrap();
+        assert_eq!(
+            json,
+            r#"{"name":"test","rule":{"kind":"range","column":"price","min":0.0},"policy":"quarantine"}"#
+        );
+        assert_eq!(
+            serde_json::from_str::<DatasetExpectation>(&json).unwrap(),
+            expectation
+        );
+    }
+
+    #[test]
+    fn test_expectation_validation() {
+        assert!(expectation(
+            ExpectationRule::NotNull {
+                column: "id".to_string()
+            },
+            ExpectationPolicy::Fail
+        )
+        .validate()
+        .is_ok());
+
+        assert!(expectation(
+            ExpectationRule::Range {
+                column: "price".to_string(),
+                min: None,
+                max: None,
+            },
+            ExpectationPolicy::Warn
+        )
+        .validate()
+        .is_err());
+
+        assert!(expectation(
+            ExpectationRule::RowCount {
+                min: Some(10),
+                max: Some(1),
+            },
+            ExpectationPolicy::Fail
+        )
+        .validate()
+        .is_err());
+
+        assert!(expectation(
+            ExpectationRule::RowCount {
+                min: Some(1),
+                max: None,
+            },
+            ExpectationPolicy::Quarantine
+        )
+        .validate()
+        .is_err());
+
+        assert!(expectation(
+            ExpectationRule::Regex {
+                column: "email".to_string(),
+                pattern: "^[^@]+@[^@]+$".to_string(),
+            },
+            ExpectationPolicy::Warn
+        )
+        .validate()
+        .is_ok());
+
+        assert!(expectation(
+            ExpectationRule::Regex {
+                column: "email".to_string(),
+                pattern: "([a-z]+".to_string(),
+            },
+            ExpectationPolicy::Warn
+        )
+        .validate()
+        .is_err());
+
+        let not_null = expectation(
+            ExpectationRule::NotNull {
+                column: "id".to_string(),
+            },
+            ExpectationPolicy::Fail,
+        );
+        assert!(DatasetExpectation::validate_all(&[not_null.clone(), not_null]).is_err());
+    }
+}
@@ -7,8 +7,12 @@
 // the Business Source License, use of this software will be governed
 // by the Apache License, Version 2.0.
 
+mod dataset_dependency;
 mod dataset_entry;
 mod dataset_env_var;
+mod dataset_expectation;
 
+pub use dataset_dependency::*;
 pub use dataset_entry::*;
 pub use dataset_env_var::*;
+pub use dataset_expectation::*;
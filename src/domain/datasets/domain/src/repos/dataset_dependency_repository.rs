@@ -0,0 +1,81 @@
+// Copyright Kamu Data, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+use std::pin::Pin;
+
+use futures::Stream;
+use internal_error::InternalError;
+use opendatafabric::DatasetID;
+use thiserror::Error;
+
+use crate::DatasetDependencyEntry;
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[async_trait::async_trait]
+pub trait DatasetDependencyRepository: Send + Sync {
+    /// Lists all stored dependency edges, ordered by downstream dataset
+    fn list_all_dependencies(&self) -> DatasetDependencyEntryStream;
+
+    async fn get_upstream_dependencies(
+        &self,
+        downstream_dataset_id: &DatasetID,
+    ) -> Result<Vec<DatasetID>, GetDatasetDependenciesError>;
+
+    /// Replaces the full set of upstream dependencies of the dataset
+    async fn set_upstream_dependencies(
+        &self,
+        downstream_dataset_id: &DatasetID,
+        upstream_dataset_ids: &[DatasetID],
+    ) -> Result<(), SetDatasetDependenciesError>;
+
+    /// Removes all edges the dataset participates in, either as upstream or
+    /// as downstream
+    async fn delete_dependencies_of_dataset(
+        &self,
+        dataset_id: &DatasetID,
+    ) -> Result<(), DeleteDatasetDependenciesError>;
+
+    /// Whether the stored dependencies were already bootstrapped from the
+    /// metadata chains of existing datasets
+    async fn is_bootstrapped(&self) -> Result<bool, GetDatasetDependenciesError>;
+
+    async fn mark_bootstrapped(&self) -> Result<(), SetDatasetDependenciesError>;
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+pub type DatasetDependencyEntryStream<'a> =
+    Pin<Box<dyn Stream<Item = Result<DatasetDependencyEntry, InternalError>> + Send + 'a>>;
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[derive(Error, Debug)]
+pub enum GetDatasetDependenciesError {
+    #[error(transparent)]
+    Internal(InternalError),
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[derive(Error, Debug)]
+pub enum SetDatasetDependenciesError {
+    #[error(transparent)]
+    Internal(InternalError),
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[derive(Error, Debug)]
+pub enum DeleteDatasetDependenciesError {
+    #[error(transparent)]
+    Internal(InternalError),
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
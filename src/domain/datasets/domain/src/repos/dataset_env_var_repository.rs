@@ -35,6 +35,10 @@ pub trait DatasetEnvVarRepository: Send + Sync {
         pagination: &DatabasePaginationOpts,
     ) -> Result<Vec<DatasetEnvVar>, GetDatasetEnvVarError>;
 
+    /// Returns env vars of all datasets, which is only needed for maintenance
+    /// operations like re-encryption of secrets
+    async fn get_all_dataset_env_vars(&self) -> Result<Vec<DatasetEnvVar>, GetDatasetEnvVarError>;
+
     async fn get_dataset_env_var_by_id(
         &self,
         dataset_env_var_id: &Uuid,
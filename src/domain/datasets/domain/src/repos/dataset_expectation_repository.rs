@@ -0,0 +1,86 @@
+// Copyright Kamu Data, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+use database_common::DatabasePaginationOpts;
+use internal_error::InternalError;
+use opendatafabric::DatasetID;
+use thiserror::Error;
+
+use crate::{DatasetExpectation, DatasetExpectationsCheck, InvalidExpectationError};
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[async_trait::async_trait]
+pub trait DatasetExpectationRepository: Send + Sync {
+    /// Replaces the full set of expectations of the dataset
+    async fn set_dataset_expectations(
+        &self,
+        dataset_id: &DatasetID,
+        expectations: &[DatasetExpectation],
+    ) -> Result<(), SetDatasetExpectationsError>;
+
+    async fn get_dataset_expectations(
+        &self,
+        dataset_id: &DatasetID,
+    ) -> Result<Vec<DatasetExpectation>, GetDatasetExpectationsError>;
+
+    async fn save_expectations_check(
+        &self,
+        check: &DatasetExpectationsCheck,
+    ) -> Result<(), SaveDatasetExpectationsCheckError>;
+
+    async fn get_expectations_checks_count_by_dataset_id(
+        &self,
+        dataset_id: &DatasetID,
+    ) -> Result<usize, GetDatasetExpectationsChecksError>;
+
+    /// Returns the checks of the dataset, most recent first
+    async fn get_expectations_checks_by_dataset_id(
+        &self,
+        dataset_id: &DatasetID,
+        pagination: &DatabasePaginationOpts,
+    ) -> Result<Vec<DatasetExpectationsCheck>, GetDatasetExpectationsChecksError>;
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[derive(Error, Debug)]
+pub enum SetDatasetExpectationsError {
+    #[error(transparent)]
+    InvalidExpectation(InvalidExpectationError),
+
+    #[error(transparent)]
+    Internal(InternalError),
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[derive(Error, Debug)]
+pub enum GetDatasetExpectationsError {
+    #[error(transparent)]
+    Internal(InternalError),
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[derive(Error, Debug)]
+pub enum SaveDatasetExpectationsCheckError {
+    #[error(transparent)]
+    Internal(InternalError),
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[derive(Error, Debug)]
+pub enum GetDatasetExpectationsChecksError {
+    #[error(transparent)]
+    Internal(InternalError),
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
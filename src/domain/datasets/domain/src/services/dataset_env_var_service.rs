@@ -10,6 +10,7 @@
 use database_common::DatabasePaginationOpts;
 use internal_error::InternalError;
 use opendatafabric::DatasetID;
+use thiserror::Error;
 use uuid::Uuid;
 
 use crate::{
@@ -58,6 +59,15 @@ pub trait DatasetEnvVarService: Sync + Send {
         dataset_env_var_id: &Uuid,
         dataset_env_var_new_value: &DatasetEnvVarValue,
     ) -> Result<(), ModifyDatasetEnvVarError>;
+
+    /// Re-encrypts all stored secret values with the new key, returning the
+    /// number of affected variables. Values are decrypted with the current or
+    /// any of the previous keys, values already encrypted with the new key are
+    /// left intact.
+    async fn rotate_encryption_key(
+        &self,
+        new_encryption_key: &str,
+    ) -> Result<usize, RotateEncryptionKeyError>;
 }
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -66,3 +76,14 @@ pub struct DatasetEnvVarListing {
     pub list: Vec<DatasetEnvVar>,
     pub total_count: usize,
 }
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[derive(Error, Debug)]
+pub enum RotateEncryptionKeyError {
+    #[error("Invalid encryption key: must be a 32-character string")]
+    InvalidEncryptionKey,
+
+    #[error(transparent)]
+    Internal(#[from] InternalError),
+}
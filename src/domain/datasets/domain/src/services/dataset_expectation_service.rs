@@ -0,0 +1,71 @@
+// Copyright Kamu Data, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+use database_common::DatabasePaginationOpts;
+use opendatafabric::{DatasetID, Multihash};
+
+use crate::{
+    DatasetExpectation,
+    DatasetExpectationsCheck,
+    ExpectationOutcome,
+    GetDatasetExpectationsChecksError,
+    GetDatasetExpectationsError,
+    SaveDatasetExpectationsCheckError,
+    SetDatasetExpectationsError,
+};
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[async_trait::async_trait]
+pub trait DatasetExpectationService: Sync + Send {
+    async fn get_dataset_expectations(
+        &self,
+        dataset_id: &DatasetID,
+    ) -> Result<Vec<DatasetExpectation>, GetDatasetExpectationsError>;
+
+    /// Validates and replaces the full set of expectations of the dataset
+    async fn set_dataset_expectations(
+        &self,
+        dataset_id: &DatasetID,
+        expectations: Vec<DatasetExpectation>,
+    ) -> Result<(), SetDatasetExpectationsError>;
+
+    async fn record_expectations_check(
+        &self,
+        dataset_id: &DatasetID,
+        result: ExpectationsCheckResult,
+    ) -> Result<DatasetExpectationsCheck, SaveDatasetExpectationsCheckError>;
+
+    async fn get_expectations_checks_by_dataset_id(
+        &self,
+        dataset_id: &DatasetID,
+        pagination: Option<DatabasePaginationOpts>,
+    ) -> Result<DatasetExpectationsCheckListing, GetDatasetExpectationsChecksError>;
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Outcome of evaluating the expectations against a single slice as produced
+/// by the data writer
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ExpectationsCheckResult {
+    pub num_records: u64,
+    pub num_quarantined: u64,
+    pub new_head: Option<Multihash>,
+    pub outcomes: Vec<ExpectationOutcome>,
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+pub struct DatasetExpectationsCheckListing {
+    pub list: Vec<DatasetExpectationsCheck>,
+    pub total_count: usize,
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
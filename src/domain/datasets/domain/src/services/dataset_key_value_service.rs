@@ -16,8 +16,9 @@ use crate::{DatasetEnvVar, DatasetEnvVarNotFoundError, DatasetEnvVarValue};
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
 
+#[async_trait::async_trait]
 pub trait DatasetKeyValueService: Sync + Send {
-    fn find_dataset_env_var_value_by_key(
+    async fn find_dataset_env_var_value_by_key(
         &self,
         dataset_env_var_key: &str,
         dataset_env_vars: &HashMap<String, DatasetEnvVar>,
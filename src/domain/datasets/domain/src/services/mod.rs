@@ -8,7 +8,11 @@
 // by the Apache License, Version 2.0.
 
 mod dataset_env_var_service;
+mod dataset_expectation_service;
 mod dataset_key_value_service;
+mod secret_provider;
 
 pub use dataset_env_var_service::*;
+pub use dataset_expectation_service::*;
 pub use dataset_key_value_service::*;
+pub use secret_provider::*;
@@ -0,0 +1,24 @@
+// Copyright Kamu Data, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+use internal_error::InternalError;
+use secrecy::Secret;
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// External storage of secrets that `${{ env.X }}` references in dataset
+/// manifests can be resolved from, in addition to the variables stored for
+/// the dataset itself
+#[async_trait::async_trait]
+pub trait SecretProvider: Send + Sync {
+    /// Returns `None` if the provider does not hold a secret with such name
+    async fn get_secret(&self, secret_name: &str) -> Result<Option<Secret<String>>, InternalError>;
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -22,6 +22,7 @@ use kamu_datasets::{
     DeleteDatasetEnvVarError,
     GetDatasetEnvVarError,
     ModifyDatasetEnvVarError,
+    RotateEncryptionKeyError,
     SaveDatasetEnvVarError,
 };
 use opendatafabric::DatasetID;
@@ -35,6 +36,7 @@ pub struct DatasetEnvVarServiceImpl {
     dataset_env_var_repository: Arc<dyn DatasetEnvVarRepository>,
     time_source: Arc<dyn SystemTimeSource>,
     dataset_env_var_encryption_key: Secret<String>,
+    previous_encryption_keys: Vec<Secret<String>>,
 }
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -58,8 +60,22 @@ impl DatasetEnvVarServiceImpl {
                     .unwrap()
                     .clone(),
             ),
+            previous_encryption_keys: dataset_env_var_config
+                .previous_encryption_keys
+                .iter()
+                .flatten()
+                .cloned()
+                .map(Secret::new)
+                .collect(),
         }
     }
+
+    /// Current key followed by the ones it has replaced
+    fn decryption_keys(&self) -> impl Iterator<Item = &str> {
+        std::iter::once(&self.dataset_env_var_encryption_key)
+            .chain(&self.previous_encryption_keys)
+            .map(|key| key.expose_secret().as_str())
+    }
 }
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -91,7 +107,7 @@ impl DatasetEnvVarService for DatasetEnvVarServiceImpl {
         dataset_env_var: &DatasetEnvVar,
     ) -> Result<String, InternalError> {
         dataset_env_var
-            .get_exposed_decrypted_value(self.dataset_env_var_encryption_key.expose_secret())
+            .get_exposed_decrypted_value_with_any_key(self.decryption_keys())
             .int_err()
     }
 
@@ -171,4 +187,43 @@ impl DatasetEnvVarService for DatasetEnvVarServiceImpl {
             .modify_dataset_env_var(dataset_env_var_id, new_value, nonce)
             .await
     }
+
+    async fn rotate_encryption_key(
+        &self,
+        new_encryption_key: &str,
+    ) -> Result<usize, RotateEncryptionKeyError> {
+        if DatasetEnvVar::try_asm_256_gcm_from_str(new_encryption_key).is_err() {
+            return Err(RotateEncryptionKeyError::InvalidEncryptionKey);
+        }
+
+        let dataset_env_vars = self
+            .dataset_env_var_repository
+            .get_all_dataset_env_vars()
+            .await
+            .int_err()?;
+
+        let mut num_rotated = 0;
+        for dataset_env_var in dataset_env_vars {
+            let Some((new_value, new_nonce)) = dataset_env_var
+                .reencrypt_value(self.decryption_keys(), new_encryption_key)
+                .int_err()?
+            else {
+                continue;
+            };
+
+            self.dataset_env_var_repository
+                .modify_dataset_env_var(&dataset_env_var.id, new_value, Some(new_nonce))
+                .await
+                .int_err()?;
+
+            num_rotated += 1;
+        }
+
+        tracing::info!(
+            num_rotated,
+            "Re-encrypted dataset env vars with the new key"
+        );
+
+        Ok(num_rotated)
+    }
 }
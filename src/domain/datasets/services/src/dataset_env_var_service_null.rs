@@ -18,6 +18,7 @@ use kamu_datasets::{
     DeleteDatasetEnvVarError,
     GetDatasetEnvVarError,
     ModifyDatasetEnvVarError,
+    RotateEncryptionKeyError,
     SaveDatasetEnvVarError,
 };
 use opendatafabric::DatasetID;
@@ -89,4 +90,11 @@ impl DatasetEnvVarService for DatasetEnvVarServiceNull {
     ) -> Result<(), ModifyDatasetEnvVarError> {
         unreachable!()
     }
+
+    async fn rotate_encryption_key(
+        &self,
+        _new_encryption_key: &str,
+    ) -> Result<usize, RotateEncryptionKeyError> {
+        Ok(0)
+    }
 }
@@ -0,0 +1,130 @@
+// Copyright Kamu Data, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+use std::sync::Arc;
+
+use database_common::DatabasePaginationOpts;
+use dill::*;
+use kamu_datasets::{
+    DatasetExpectation,
+    DatasetExpectationRepository,
+    DatasetExpectationService,
+    DatasetExpectationsCheck,
+    DatasetExpectationsCheckListing,
+    ExpectationsCheckResult,
+    GetDatasetExpectationsChecksError,
+    GetDatasetExpectationsError,
+    SaveDatasetExpectationsCheckError,
+    SetDatasetExpectationsError,
+};
+use opendatafabric::DatasetID;
+use time_source::SystemTimeSource;
+use uuid::Uuid;
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+pub struct DatasetExpectationServiceImpl {
+    dataset_expectation_repository: Arc<dyn DatasetExpectationRepository>,
+    time_source: Arc<dyn SystemTimeSource>,
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[component(pub)]
+#[interface(dyn DatasetExpectationService)]
+impl DatasetExpectationServiceImpl {
+    pub fn new(
+        dataset_expectation_repository: Arc<dyn DatasetExpectationRepository>,
+        time_source: Arc<dyn SystemTimeSource>,
+    ) -> Self {
+        Self {
+            dataset_expectation_repository,
+            time_source,
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[async_trait::async_trait]
+impl DatasetExpectationService for DatasetExpectationServiceImpl {
+    async fn get_dataset_expectations(
+        &self,
+        dataset_id: &DatasetID,
+    ) -> Result<Vec<DatasetExpectation>, GetDatasetExpectationsError> {
+        self.dataset_expectation_repository
+            .get_dataset_expectations(dataset_id)
+            .await
+    }
+
+    async fn set_dataset_expectations(
+        &self,
+        dataset_id: &DatasetID,
+        expectations: Vec<DatasetExpectation>,
+    ) -> Result<(), SetDatasetExpectationsError> {
+        DatasetExpectation::validate_all(&expectations)
+            .map_err(SetDatasetExpectationsError::InvalidExpectation)?;
+
+        self.dataset_expectation_repository
+            .set_dataset_expectations(dataset_id, &expectations)
+            .await
+    }
+
+    async fn record_expectations_check(
+        &self,
+        dataset_id: &DatasetID,
+        result: ExpectationsCheckResult,
+    ) -> Result<DatasetExpectationsCheck, SaveDatasetExpectationsCheckError> {
+        let check = DatasetExpectationsCheck {
+            id: Uuid::new_v4(),
+            dataset_id: dataset_id.clone(),
+            checked_at: self.time_source.now(),
+            num_records: result.num_records,
+            num_quarantined: result.num_quarantined,
+            new_head: result.new_head,
+            outcomes: result.outcomes,
+        };
+
+        self.dataset_expectation_repository
+            .save_expectations_check(&check)
+            .await?;
+
+        Ok(check)
+    }
+
+    async fn get_expectations_checks_by_dataset_id(
+        &self,
+        dataset_id: &DatasetID,
+        pagination: Option<DatabasePaginationOpts>,
+    ) -> Result<DatasetExpectationsCheckListing, GetDatasetExpectationsChecksError> {
+        let total_count = self
+            .dataset_expectation_repository
+            .get_expectations_checks_count_by_dataset_id(dataset_id)
+            .await?;
+        if total_count == 0 {
+            return Ok(DatasetExpectationsCheckListing {
+                total_count,
+                list: vec![],
+            });
+        }
+        let database_pagination = pagination.unwrap_or(DatabasePaginationOpts {
+            #[allow(clippy::cast_possible_wrap)]
+            limit: total_count as i64,
+            offset: 0,
+        });
+
+        let list = self
+            .dataset_expectation_repository
+            .get_expectations_checks_by_dataset_id(dataset_id, &database_pagination)
+            .await?;
+        Ok(DatasetExpectationsCheckListing { list, total_count })
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
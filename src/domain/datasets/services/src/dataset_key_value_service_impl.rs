@@ -19,13 +19,18 @@ use kamu_datasets::{
     DatasetEnvVarsConfig,
     DatasetKeyValueService,
     FindDatasetEnvVarError,
+    SecretProvider,
 };
 use secrecy::{ExposeSecret, Secret};
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
 
+/// Resolves variables from the encrypted dataset env vars table first and
+/// then from the external [`SecretProvider`], if one is configured
 pub struct DatasetKeyValueServiceImpl {
     dataset_env_var_encryption_key: Secret<String>,
+    previous_encryption_keys: Vec<Secret<String>>,
+    secret_provider: Option<Arc<dyn SecretProvider>>,
 }
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -34,7 +39,10 @@ pub struct DatasetKeyValueServiceImpl {
 #[interface(dyn DatasetKeyValueService)]
 impl DatasetKeyValueServiceImpl {
     #[allow(clippy::needless_pass_by_value)]
-    pub fn new(dataset_env_var_config: Arc<DatasetEnvVarsConfig>) -> Self {
+    pub fn new(
+        dataset_env_var_config: Arc<DatasetEnvVarsConfig>,
+        secret_provider: Option<Arc<dyn SecretProvider>>,
+    ) -> Self {
         Self {
             dataset_env_var_encryption_key: Secret::new(
                 dataset_env_var_config
@@ -43,21 +51,37 @@ impl DatasetKeyValueServiceImpl {
                     .unwrap()
                     .clone(),
             ),
+            previous_encryption_keys: dataset_env_var_config
+                .previous_encryption_keys
+                .iter()
+                .flatten()
+                .cloned()
+                .map(Secret::new)
+                .collect(),
+            secret_provider,
         }
     }
+
+    /// Current key followed by the ones it has replaced
+    fn decryption_keys(&self) -> impl Iterator<Item = &str> {
+        std::iter::once(&self.dataset_env_var_encryption_key)
+            .chain(&self.previous_encryption_keys)
+            .map(|key| key.expose_secret().as_str())
+    }
 }
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
 
+#[async_trait::async_trait]
 impl DatasetKeyValueService for DatasetKeyValueServiceImpl {
-    fn find_dataset_env_var_value_by_key(
+    async fn find_dataset_env_var_value_by_key(
         &self,
         dataset_env_var_key: &str,
         dataset_env_vars: &HashMap<String, DatasetEnvVar>,
     ) -> Result<DatasetEnvVarValue, FindDatasetEnvVarError> {
         if let Some(existing_dataset_env_var) = dataset_env_vars.get(dataset_env_var_key) {
             let exposed_value = existing_dataset_env_var
-                .get_exposed_decrypted_value(self.dataset_env_var_encryption_key.expose_secret())
+                .get_exposed_decrypted_value_with_any_key(self.decryption_keys())
                 .map_err(|err| FindDatasetEnvVarError::Internal(err.int_err()))?;
             return if existing_dataset_env_var.secret_nonce.is_some() {
                 Ok(DatasetEnvVarValue::Secret(Secret::new(exposed_value)))
@@ -65,6 +89,11 @@ impl DatasetKeyValueService for DatasetKeyValueServiceImpl {
                 return Ok(DatasetEnvVarValue::Regular(exposed_value));
             };
         }
+        if let Some(secret_provider) = &self.secret_provider
+            && let Some(secret) = secret_provider.get_secret(dataset_env_var_key).await?
+        {
+            return Ok(DatasetEnvVarValue::Secret(secret));
+        }
         Err(FindDatasetEnvVarError::NotFound(
             DatasetEnvVarNotFoundError {
                 dataset_env_var_key: dataset_env_var_key.to_string(),
@@ -8,6 +8,7 @@
 // by the Apache License, Version 2.0.
 
 use std::collections::HashMap;
+use std::sync::Arc;
 
 use dill::*;
 use internal_error::*;
@@ -17,27 +18,31 @@ use kamu_datasets::{
     DatasetEnvVarValue,
     DatasetKeyValueService,
     FindDatasetEnvVarError,
+    SecretProvider,
 };
 use secrecy::Secret;
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
 
-pub struct DatasetKeyValueServiceSysEnv {}
+pub struct DatasetKeyValueServiceSysEnv {
+    secret_provider: Option<Arc<dyn SecretProvider>>,
+}
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
 
 #[component(pub)]
 #[interface(dyn DatasetKeyValueService)]
 impl DatasetKeyValueServiceSysEnv {
-    pub fn new() -> Self {
-        Self {}
+    pub fn new(secret_provider: Option<Arc<dyn SecretProvider>>) -> Self {
+        Self { secret_provider }
     }
 }
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
 
+#[async_trait::async_trait]
 impl DatasetKeyValueService for DatasetKeyValueServiceSysEnv {
-    fn find_dataset_env_var_value_by_key(
+    async fn find_dataset_env_var_value_by_key(
         &self,
         dataset_env_var_key: &str,
         dataset_env_vars: &HashMap<String, DatasetEnvVar>,
@@ -50,6 +55,13 @@ impl DatasetKeyValueService for DatasetKeyValueServiceSysEnv {
             ));
         }
 
+        // Then the external secret storage
+        if let Some(secret_provider) = &self.secret_provider
+            && let Some(secret) = secret_provider.get_secret(dataset_env_var_key).await?
+        {
+            return Ok(DatasetEnvVarValue::Secret(secret));
+        }
+
         // Fall back to system environment
         match std::env::var(dataset_env_var_key) {
             Ok(value_string) => Ok(DatasetEnvVarValue::Secret(Secret::new(value_string))),
@@ -14,10 +14,16 @@ pub use kamu_datasets as domain;
 
 mod dataset_env_var_service_impl;
 mod dataset_env_var_service_null;
+mod dataset_expectation_service_impl;
 mod dataset_key_value_service_impl;
 mod dataset_key_value_service_sys_env;
+mod secret_provider_files;
+mod secret_provider_vault;
 
 pub use dataset_env_var_service_impl::*;
 pub use dataset_env_var_service_null::*;
+pub use dataset_expectation_service_impl::*;
 pub use dataset_key_value_service_impl::*;
 pub use dataset_key_value_service_sys_env::*;
+pub use secret_provider_files::*;
+pub use secret_provider_vault::*;
@@ -0,0 +1,59 @@
+// Copyright Kamu Data, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+use std::path::PathBuf;
+
+use internal_error::{InternalError, ResultIntoInternal};
+use kamu_datasets::{FilesSecretProviderConfig, SecretProvider};
+use secrecy::Secret;
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Reads secrets from a directory where every file name is a variable name,
+/// which is how Kubernetes mounts `Secret` volumes
+pub struct SecretProviderFiles {
+    dir: PathBuf,
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+impl SecretProviderFiles {
+    pub fn new(config: &FilesSecretProviderConfig) -> Self {
+        Self {
+            dir: PathBuf::from(&config.path),
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[async_trait::async_trait]
+impl SecretProvider for SecretProviderFiles {
+    #[tracing::instrument(level = "debug", skip(self))]
+    async fn get_secret(&self, secret_name: &str) -> Result<Option<Secret<String>>, InternalError> {
+        // Names are only allowed to reference files directly in the secrets dir
+        if secret_name.is_empty()
+            || secret_name.starts_with('.')
+            || secret_name.contains(['/', '\\'])
+        {
+            return Ok(None);
+        }
+
+        match tokio::fs::read_to_string(self.dir.join(secret_name)).await {
+            // Mounted files often contain a trailing newline that is not part of the value
+            Ok(value) => Ok(Some(Secret::new(
+                value.trim_end_matches(['\r', '\n']).to_string(),
+            ))),
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => Ok(None),
+            Err(err) => Err(err.int_err()),
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
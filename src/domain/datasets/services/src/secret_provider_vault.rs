@@ -0,0 +1,140 @@
+// Copyright Kamu Data, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+use std::collections::HashMap;
+use std::time::{Duration, Instant};
+
+use internal_error::{InternalError, ResultIntoInternal};
+use kamu_datasets::{SecretProvider, VaultSecretProviderConfig};
+use secrecy::{ExposeSecret, Secret};
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Reads secrets from a single secret of HashiCorp Vault KV v2 engine, where
+/// every key of the secret corresponds to a variable name.
+///
+/// The whole secret is fetched at once and cached for the configured TTL to
+/// avoid a round trip to Vault on every variable lookup.
+pub struct SecretProviderVault {
+    client: reqwest::Client,
+    secret_url: String,
+    token: Secret<String>,
+    cache_ttl: Duration,
+    cache: tokio::sync::Mutex<Option<CachedSecret>>,
+}
+
+struct CachedSecret {
+    fetched_at: Instant,
+    values: HashMap<String, Secret<String>>,
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+impl SecretProviderVault {
+    pub const DEFAULT_MOUNT: &'static str = "secret";
+    pub const DEFAULT_CACHE_TTL_SECS: u64 = 60;
+
+    pub fn new(config: &VaultSecretProviderConfig) -> Result<Self, InternalError> {
+        let token = match &config.token {
+            Some(token) => token.clone(),
+            None => std::env::var("VAULT_TOKEN")
+                .map_err(|_| "Vault token is not configured and VAULT_TOKEN is not set")
+                .int_err()?,
+        };
+
+        let secret_url = format!(
+            "{}/v1/{}/data/{}",
+            config.url.trim_end_matches('/'),
+            config
+                .mount
+                .as_deref()
+                .unwrap_or(Self::DEFAULT_MOUNT)
+                .trim_matches('/'),
+            config.path.trim_matches('/'),
+        );
+
+        Ok(Self {
+            client: reqwest::Client::new(),
+            secret_url,
+            token: Secret::new(token),
+            cache_ttl: Duration::from_secs(
+                config
+                    .cache_ttl_secs
+                    .unwrap_or(Self::DEFAULT_CACHE_TTL_SECS),
+            ),
+            cache: tokio::sync::Mutex::new(None),
+        })
+    }
+
+    async fn fetch_secret(&self) -> Result<HashMap<String, Secret<String>>, InternalError> {
+        let response = self
+            .client
+            .get(&self.secret_url)
+            .header("X-Vault-Token", self.token.expose_secret())
+            .send()
+            .await
+            .int_err()?;
+
+        if response.status() == reqwest::StatusCode::NOT_FOUND {
+            return Ok(HashMap::new());
+        }
+
+        let body: serde_json::Value = response
+            .error_for_status()
+            .int_err()?
+            .json()
+            .await
+            .int_err()?;
+
+        // KV v2 wraps the key-value pairs into an additional `data` object
+        // alongside the version metadata
+        let values = body["data"]["data"]
+            .as_object()
+            .into_iter()
+            .flatten()
+            .filter_map(|(key, value)| {
+                value
+                    .as_str()
+                    .map(|value| (key.clone(), Secret::new(value.to_string())))
+            })
+            .collect();
+
+        Ok(values)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[async_trait::async_trait]
+impl SecretProvider for SecretProviderVault {
+    #[tracing::instrument(level = "debug", skip(self))]
+    async fn get_secret(&self, secret_name: &str) -> Result<Option<Secret<String>>, InternalError> {
+        // Holding the lock while fetching makes concurrent lookups wait for a
+        // single request instead of all hitting Vault at once
+        let mut cache = self.cache.lock().await;
+
+        if let Some(cached) = cache.as_ref()
+            && cached.fetched_at.elapsed() < self.cache_ttl
+        {
+            return Ok(cached.values.get(secret_name).cloned());
+        }
+
+        let values = self.fetch_secret().await?;
+        let value = values.get(secret_name).cloned();
+
+        *cache = Some(CachedSecret {
+            fetched_at: Instant::now(),
+            values,
+        });
+
+        Ok(value)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -0,0 +1,34 @@
+// Copyright Kamu Data, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+use kamu_datasets::{FilesSecretProviderConfig, SecretProvider};
+use kamu_datasets_services::SecretProviderFiles;
+use secrecy::ExposeSecret;
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[tokio::test]
+async fn test_files_provider_reads_mounted_secrets() {
+    let tmp_dir = tempfile::tempdir().unwrap();
+    std::fs::write(tmp_dir.path().join("API_KEY"), "foo\n").unwrap();
+    std::fs::write(tmp_dir.path().join(".hidden"), "bar").unwrap();
+
+    let provider = SecretProviderFiles::new(&FilesSecretProviderConfig {
+        path: tmp_dir.path().to_str().unwrap().to_string(),
+    });
+
+    let value = provider.get_secret("API_KEY").await.unwrap().unwrap();
+    assert_eq!(value.expose_secret(), "foo");
+
+    assert!(provider.get_secret("MISSING").await.unwrap().is_none());
+    assert!(provider.get_secret(".hidden").await.unwrap().is_none());
+    assert!(provider.get_secret("../API_KEY").await.unwrap().is_none());
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -0,0 +1,125 @@
+// Copyright Kamu Data, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+use std::time::Duration;
+
+use container_runtime::ContainerRuntime;
+use kamu_datasets::{SecretProvider, VaultSecretProviderConfig};
+use kamu_datasets_services::SecretProviderVault;
+use secrecy::ExposeSecret;
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+const VAULT_IMAGE: &str = "docker.io/hashicorp/vault:1.17";
+const VAULT_ROOT_TOKEN: &str = "kamu-test-root-token";
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[test_group::group(containerized)]
+#[tokio::test]
+async fn test_vault_provider_reads_kv_v2_secret() {
+    let container_runtime = ContainerRuntime::default();
+
+    container_runtime
+        .ensure_image(VAULT_IMAGE, None)
+        .await
+        .unwrap();
+
+    let server_port = 8200;
+
+    // Dev server comes with KV v2 engine mounted at `secret/`
+    let container = container_runtime
+        .run_attached(VAULT_IMAGE)
+        .random_container_name_with_prefix("kamu-test-vault-")
+        .environment_vars([
+            ("VAULT_DEV_ROOT_TOKEN_ID", VAULT_ROOT_TOKEN),
+            ("VAULT_DEV_LISTEN_ADDRESS", "0.0.0.0:8200"),
+        ])
+        .args(["server", "-dev"])
+        .expose_port(server_port)
+        .stdout(std::process::Stdio::null())
+        .stderr(std::process::Stdio::null())
+        .spawn()
+        .unwrap();
+
+    let host_port = container
+        .wait_for_host_socket(server_port, Duration::from_secs(20))
+        .await
+        .unwrap();
+
+    let url = format!(
+        "http://{}:{host_port}",
+        container_runtime.get_runtime_host_addr()
+    );
+
+    write_vault_secret(&url, "kamu/env", "foo").await;
+
+    let provider = SecretProviderVault::new(&VaultSecretProviderConfig {
+        url: url.clone(),
+        mount: None,
+        path: "kamu/env".to_string(),
+        token: Some(VAULT_ROOT_TOKEN.to_string()),
+        cache_ttl_secs: None,
+    })
+    .unwrap();
+
+    let value = provider.get_secret("API_KEY").await.unwrap().unwrap();
+    assert_eq!(value.expose_secret(), "foo");
+
+    assert!(provider.get_secret("MISSING").await.unwrap().is_none());
+
+    // Updated value is not visible until the cached secret expires
+    write_vault_secret(&url, "kamu/env", "bar").await;
+
+    let value = provider.get_secret("API_KEY").await.unwrap().unwrap();
+    assert_eq!(value.expose_secret(), "foo");
+
+    let uncached_provider = SecretProviderVault::new(&VaultSecretProviderConfig {
+        url: url.clone(),
+        mount: None,
+        path: "kamu/env".to_string(),
+        token: Some(VAULT_ROOT_TOKEN.to_string()),
+        cache_ttl_secs: Some(0),
+    })
+    .unwrap();
+
+    let value = uncached_provider
+        .get_secret("API_KEY")
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(value.expose_secret(), "bar");
+
+    let provider = SecretProviderVault::new(&VaultSecretProviderConfig {
+        url,
+        mount: None,
+        path: "kamu/missing".to_string(),
+        token: Some(VAULT_ROOT_TOKEN.to_string()),
+        cache_ttl_secs: None,
+    })
+    .unwrap();
+
+    assert!(provider.get_secret("API_KEY").await.unwrap().is_none());
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+async fn write_vault_secret(url: &str, path: &str, api_key: &str) {
+    reqwest::Client::new()
+        .post(format!("{url}/v1/secret/data/{path}"))
+        .header("X-Vault-Token", VAULT_ROOT_TOKEN)
+        .json(&serde_json::json!({ "data": { "API_KEY": api_key } }))
+        .send()
+        .await
+        .unwrap()
+        .error_for_status()
+        .unwrap();
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -8,7 +8,7 @@
 // by the Apache License, Version 2.0.
 
 use chrono::{DateTime, Utc};
-use opendatafabric::{AccountID, DatasetID};
+use opendatafabric::{AccountID, DatasetID, Multihash};
 
 use crate::*;
 
@@ -20,6 +20,7 @@ pub enum FlowTrigger {
     AutoPolling(FlowTriggerAutoPolling),
     Push(FlowTriggerPush),
     InputDatasetFlow(FlowTriggerInputDatasetFlow),
+    InputDatasetUpdate(FlowTriggerInputDatasetUpdate),
 }
 
 impl FlowTrigger {
@@ -29,6 +30,7 @@ impl FlowTrigger {
             Self::AutoPolling(t) => t.trigger_time,
             Self::Push(t) => t.trigger_time,
             Self::InputDatasetFlow(t) => t.trigger_time,
+            Self::InputDatasetUpdate(t) => t.trigger_time,
         }
     }
 
@@ -48,6 +50,26 @@ impl FlowTrigger {
         }
     }
 
+    /// For triggers caused by new data in an input dataset, returns the ID of
+    /// that dataset and its head before the change
+    pub fn input_dataset_change(&self) -> Option<(&DatasetID, Option<&Multihash>)> {
+        match self {
+            FlowTrigger::InputDatasetFlow(trigger) => {
+                if let FlowResult::DatasetUpdate(FlowResultDatasetUpdate::Changed(update_result)) =
+                    &trigger.flow_result
+                {
+                    Some((&trigger.dataset_id, update_result.old_head.as_ref()))
+                } else {
+                    None
+                }
+            }
+            FlowTrigger::InputDatasetUpdate(trigger) => {
+                Some((&trigger.dataset_id, trigger.old_head.as_ref()))
+            }
+            FlowTrigger::Manual(_) | FlowTrigger::AutoPolling(_) | FlowTrigger::Push(_) => None,
+        }
+    }
+
     /// Checks if new trigger is unique compared to the existing triggers
     pub fn is_unique_vs(&self, existing_triggers: &[FlowTrigger]) -> bool {
         // Try finding a similar existing trigger and abort early, when found
@@ -67,6 +89,17 @@ impl FlowTrigger {
                         return false;
                     }
                 }
+                // Changes of an input are accumulated since the first trigger caused by it,
+                // so direct updates never add anything on top of other triggers of that input
+                (FlowTrigger::InputDatasetUpdate(_), _)
+                | (_, FlowTrigger::InputDatasetUpdate(_))
+                    if self
+                        .input_dataset_change()
+                        .zip(existing.input_dataset_change())
+                        .is_some_and(|((this_id, _), (existing_id, _))| this_id == existing_id) =>
+                {
+                    return false
+                }
                 _ => { /* Continue comparing */ }
             }
         }
@@ -125,6 +158,18 @@ impl FlowTriggerInputDatasetFlow {
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
 
+/// Input dataset received new data outside of flows, i.e. via a direct commit,
+/// an ingest or a sync
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FlowTriggerInputDatasetUpdate {
+    pub trigger_time: DateTime<Utc>,
+    pub dataset_id: DatasetID,
+    pub old_head: Option<Multihash>,
+    pub new_head: Multihash,
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
 #[cfg(test)]
 mod tests {
     use kamu_accounts::DEFAULT_ACCOUNT_ID;
@@ -250,6 +295,54 @@ mod tests {
 
         assert!(!INPUT_DATASET_TRIGGER.is_unique_vs(&[INPUT_DATASET_TRIGGER.clone()]));
     }
+
+    #[test]
+    fn test_is_unique_input_dataset_update() {
+        let input_dataset_update_trigger =
+            FlowTrigger::InputDatasetUpdate(FlowTriggerInputDatasetUpdate {
+                trigger_time: Utc::now(),
+                dataset_id: TEST_DATASET_ID.clone(),
+                old_head: None,
+                new_head: Multihash::from_digest_sha3_256(b"some-other-slice"),
+            });
+
+        assert!(input_dataset_update_trigger.is_unique_vs(&[]));
+        assert!(input_dataset_update_trigger.is_unique_vs(&[
+            AUTO_POLLING_TRIGGER.clone(),
+            PUSH_SOURCE_TRIGGER.clone(),
+            MANUAL_TRIGGER.clone()
+        ]));
+
+        // Test update of a different dataset
+        assert!(
+            input_dataset_update_trigger.is_unique_vs(&[FlowTrigger::InputDatasetUpdate(
+                FlowTriggerInputDatasetUpdate {
+                    trigger_time: Utc::now(),
+                    dataset_id: DatasetID::new_seeded_ed25519(b"different"),
+                    old_head: None,
+                    new_head: Multihash::from_digest_sha3_256(b"some-slice"),
+                }
+            )])
+        );
+
+        // Test flow of same dataset that did not change data
+        assert!(
+            input_dataset_update_trigger.is_unique_vs(&[FlowTrigger::InputDatasetFlow(
+                FlowTriggerInputDatasetFlow {
+                    trigger_time: Utc::now(),
+                    dataset_id: TEST_DATASET_ID.clone(),
+                    flow_type: DatasetFlowType::HardCompaction,
+                    flow_id: FlowID::new(7),
+                    flow_result: FlowResult::Empty
+                }
+            )])
+        );
+
+        // Changes of the same dataset are already accounted by earlier triggers
+        assert!(!input_dataset_update_trigger.is_unique_vs(&[INPUT_DATASET_TRIGGER.clone()]));
+        assert!(!input_dataset_update_trigger.is_unique_vs(&[input_dataset_update_trigger.clone()]));
+        assert!(!INPUT_DATASET_TRIGGER.is_unique_vs(&[input_dataset_update_trigger.clone()]));
+    }
 }
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -7,22 +7,25 @@
 // the Business Source License, use of this software will be governed
 // by the Apache License, Version 2.0.
 
+use kamu_core::{CompactionDataLayout, InvalidDataLayoutError};
 use serde::{Deserialize, Serialize};
 use thiserror::Error;
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub enum CompactionRule {
     Full(CompactionRuleFull),
     MetadataOnly(CompactionRuleMetadataOnly),
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct CompactionRuleFull {
     max_slice_size: u64,
     max_slice_records: u64,
     recursive: bool,
+    #[serde(default)]
+    data_layout: CompactionDataLayout,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
@@ -35,6 +38,7 @@ impl CompactionRuleFull {
         max_slice_size: u64,
         max_slice_records: u64,
         recursive: bool,
+        data_layout: CompactionDataLayout,
     ) -> Result<Self, CompactionRuleValidationError> {
         if max_slice_size == 0 {
             return Err(CompactionRuleValidationError::MaxSliceSizeNotPositive);
@@ -42,11 +46,13 @@ impl CompactionRuleFull {
         if max_slice_records == 0 {
             return Err(CompactionRuleValidationError::MaxSliceRecordsNotPositive);
         }
+        data_layout.validate()?;
 
         Ok(Self {
             max_slice_size,
             max_slice_records,
             recursive,
+            data_layout,
         })
     }
 
@@ -64,6 +70,11 @@ impl CompactionRuleFull {
     pub fn recursive(&self) -> bool {
         self.recursive
     }
+
+    #[inline]
+    pub fn data_layout(&self) -> &CompactionDataLayout {
+        &self.data_layout
+    }
 }
 
 impl CompactionRule {
@@ -83,6 +94,14 @@ impl CompactionRule {
         }
     }
 
+    #[inline]
+    pub fn data_layout(&self) -> Option<&CompactionDataLayout> {
+        match self {
+            Self::Full(compaction_rule) => Some(compaction_rule.data_layout()),
+            _ => None,
+        }
+    }
+
     #[inline]
     pub fn recursive(&self) -> bool {
         match self {
@@ -101,6 +120,9 @@ pub enum CompactionRuleValidationError {
 
     #[error("Maximum slice size must be a positive number")]
     MaxSliceSizeNotPositive,
+
+    #[error(transparent)]
+    InvalidDataLayout(#[from] InvalidDataLayoutError),
 }
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -109,22 +131,50 @@ pub enum CompactionRuleValidationError {
 mod tests {
     use std::assert_matches::assert_matches;
 
+    use kamu_core::{CompactionCompression, CompactionDataLayout};
+
     use crate::{CompactionRuleFull, CompactionRuleValidationError};
 
     #[test]
     fn test_valid_compaction_rule() {
-        assert_matches!(CompactionRuleFull::new_checked(1, 1, false), Ok(_));
         assert_matches!(
-            CompactionRuleFull::new_checked(1_000_000, 1_000_000, false),
+            CompactionRuleFull::new_checked(1, 1, false, CompactionDataLayout::default()),
+            Ok(_)
+        );
+        assert_matches!(
+            CompactionRuleFull::new_checked(
+                1_000_000,
+                1_000_000,
+                false,
+                CompactionDataLayout::default()
+            ),
+            Ok(_)
+        );
+        assert_matches!(
+            CompactionRuleFull::new_checked(1, 20, false, CompactionDataLayout::default()),
+            Ok(_)
+        );
+        assert_matches!(
+            CompactionRuleFull::new_checked(
+                1,
+                20,
+                false,
+                CompactionDataLayout {
+                    sort_by: vec!["event_time".to_string()],
+                    max_row_group_size: Some(1000),
+                    page_index: Some(true),
+                    bloom_filter_columns: vec!["id".to_string()],
+                    compression: Some(CompactionCompression::Zstd { level: 9 }),
+                }
+            ),
             Ok(_)
         );
-        assert_matches!(CompactionRuleFull::new_checked(1, 20, false), Ok(_));
     }
 
     #[test]
     fn test_non_positive_max_slice_records() {
         assert_matches!(
-            CompactionRuleFull::new_checked(100, 0, false),
+            CompactionRuleFull::new_checked(100, 0, false, CompactionDataLayout::default()),
             Err(CompactionRuleValidationError::MaxSliceRecordsNotPositive)
         );
     }
@@ -132,10 +182,50 @@ mod tests {
     #[test]
     fn test_non_positive_max_slice_size() {
         assert_matches!(
-            CompactionRuleFull::new_checked(0, 100, false),
+            CompactionRuleFull::new_checked(0, 100, false, CompactionDataLayout::default()),
             Err(CompactionRuleValidationError::MaxSliceSizeNotPositive)
         );
     }
+
+    #[test]
+    fn test_invalid_data_layout() {
+        assert_matches!(
+            CompactionRuleFull::new_checked(
+                100,
+                100,
+                false,
+                CompactionDataLayout {
+                    compression: Some(CompactionCompression::Zstd { level: 30 }),
+                    ..Default::default()
+                }
+            ),
+            Err(CompactionRuleValidationError::InvalidDataLayout(_))
+        );
+        assert_matches!(
+            CompactionRuleFull::new_checked(
+                100,
+                100,
+                false,
+                CompactionDataLayout {
+                    max_row_group_size: Some(0),
+                    ..Default::default()
+                }
+            ),
+            Err(CompactionRuleValidationError::InvalidDataLayout(_))
+        );
+        assert_matches!(
+            CompactionRuleFull::new_checked(
+                100,
+                100,
+                false,
+                CompactionDataLayout {
+                    sort_by: vec!["id".to_string(), "id".to_string()],
+                    ..Default::default()
+                }
+            ),
+            Err(CompactionRuleValidationError::InvalidDataLayout(_))
+        );
+    }
 }
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
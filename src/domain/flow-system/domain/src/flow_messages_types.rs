@@ -11,7 +11,7 @@ use chrono::{DateTime, Utc};
 use messaging_outbox::Message;
 use serde::{Deserialize, Serialize};
 
-use crate::{FlowConfigurationRule, FlowKey};
+use crate::{FlowConfigurationRule, FlowID, FlowKey, FlowOutcome};
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
 
@@ -46,3 +46,36 @@ pub enum FlowServiceUpdateDetails {
 }
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum FlowProgressMessage {
+    Finished(FlowProgressMessageFinished),
+}
+
+impl FlowProgressMessage {
+    pub fn finished(
+        event_time: DateTime<Utc>,
+        flow_id: FlowID,
+        flow_key: FlowKey,
+        outcome: FlowOutcome,
+    ) -> Self {
+        Self::Finished(FlowProgressMessageFinished {
+            event_time,
+            flow_id,
+            flow_key,
+            outcome,
+        })
+    }
+}
+
+impl Message for FlowProgressMessage {}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FlowProgressMessageFinished {
+    pub event_time: DateTime<Utc>,
+    pub flow_id: FlowID,
+    pub flow_key: FlowKey,
+    pub outcome: FlowOutcome,
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -117,7 +117,7 @@ impl ActiveConfigsState {
     ) -> Option<CompactionRule> {
         self.dataset_compaction_rules
             .get(BorrowedFlowKeyDataset::new(dataset_id, flow_type).as_trait())
-            .copied()
+            .cloned()
     }
 
     pub fn try_get_dataset_reset_rule(
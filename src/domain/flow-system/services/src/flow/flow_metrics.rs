@@ -0,0 +1,63 @@
+// Copyright Kamu Data, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+use dill::*;
+use kamu_flow_system::{AnyFlowType, DatasetFlowType, FlowOutcome, SystemFlowType};
+use observability::*;
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+pub struct FlowMetrics {
+    pub flow_outcomes_total: CounterVec,
+}
+
+#[component(pub)]
+#[interface(dyn MetricsProvider)]
+#[scope(Singleton)]
+impl FlowMetrics {
+    pub fn new() -> Self {
+        Self {
+            flow_outcomes_total: CounterVec::new(
+                Opts::new(
+                    "kamu_flow_outcomes_total",
+                    "Number of finished flows by flow type and outcome",
+                ),
+                &["flow_type", "outcome"],
+            )
+            .unwrap(),
+        }
+    }
+
+    pub(crate) fn on_flow_finished(&self, flow_type: AnyFlowType, outcome: &FlowOutcome) {
+        let flow_type = match flow_type {
+            AnyFlowType::Dataset(DatasetFlowType::Ingest) => "Ingest",
+            AnyFlowType::Dataset(DatasetFlowType::ExecuteTransform) => "ExecuteTransform",
+            AnyFlowType::Dataset(DatasetFlowType::HardCompaction) => "HardCompaction",
+            AnyFlowType::Dataset(DatasetFlowType::Reset) => "Reset",
+            AnyFlowType::System(SystemFlowType::GC) => "GC",
+        };
+        let outcome = match outcome {
+            FlowOutcome::Success(_) => "success",
+            FlowOutcome::Failed(_) => "failed",
+            FlowOutcome::Aborted => "aborted",
+        };
+
+        self.flow_outcomes_total
+            .with_label_values(&[flow_type, outcome])
+            .inc();
+    }
+}
+
+impl MetricsProvider for FlowMetrics {
+    fn register(&self, registry: &Registry) -> Result<(), prometheus::Error> {
+        registry.register(Box::new(self.flow_outcomes_total.clone()))
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -9,7 +9,7 @@
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
 
-use std::collections::HashSet;
+use std::collections::{HashMap, HashSet};
 use std::sync::{Arc, Mutex};
 
 use chrono::{DateTime, DurationRound, Utc};
@@ -18,9 +18,13 @@ use dill::*;
 use futures::TryStreamExt;
 use internal_error::InternalError;
 use kamu_core::{
+    CompactionDataLayout,
     DatasetChangesService,
     DatasetLifecycleMessage,
+    DatasetLifecycleMessageDataUpdated,
     DatasetOwnershipService,
+    DatasetRepository,
+    DatasetRepositoryExt,
     DependencyGraphService,
     MESSAGE_PRODUCER_KAMU_CORE_DATASET_SERVICE,
 };
@@ -34,16 +38,19 @@ use messaging_outbox::{
     Outbox,
     OutboxExt,
 };
-use opendatafabric::{AccountID, DatasetID};
+use opendatafabric::{AccountID, DatasetID, Multihash};
 use time_source::SystemTimeSource;
 use tokio_stream::StreamExt;
+use tracing::Instrument as _;
 
 use super::active_configs_state::ActiveConfigsState;
+use super::flow_metrics::FlowMetrics;
 use super::flow_time_wheel::FlowTimeWheel;
 use super::pending_flows_state::PendingFlowsState;
 use crate::{
     MESSAGE_CONSUMER_KAMU_FLOW_SERVICE,
     MESSAGE_PRODUCER_KAMU_FLOW_CONFIGURATION_SERVICE,
+    MESSAGE_PRODUCER_KAMU_FLOW_PROGRESS_SERVICE,
     MESSAGE_PRODUCER_KAMU_FLOW_SERVICE,
 };
 
@@ -59,6 +66,7 @@ pub struct FlowServiceImpl {
     dataset_changes_service: Arc<dyn DatasetChangesService>,
     dependency_graph_service: Arc<dyn DependencyGraphService>,
     dataset_ownership_service: Arc<dyn DatasetOwnershipService>,
+    metrics: Option<Arc<FlowMetrics>>,
 }
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -68,6 +76,13 @@ struct State {
     active_configs: ActiveConfigsState,
     pending_flows: PendingFlowsState,
     time_wheel: FlowTimeWheel,
+    /// Traces of requests that triggered flows manually, so that execution of
+    /// the flow task can be followed as part of the same trace
+    trace_contexts: HashMap<FlowID, observability::TraceContext>,
+    /// Heads written by the last finished update flow of each dataset. The
+    /// `DataUpdated` messages about them may be consumed after the flow has
+    /// finished and already triggered the dependent flows.
+    last_flow_data_updates: HashMap<DatasetID, FlowResultDatasetUpdateChanged>,
     running: bool,
 }
 
@@ -100,6 +115,7 @@ impl FlowServiceImpl {
         dataset_changes_service: Arc<dyn DatasetChangesService>,
         dependency_graph_service: Arc<dyn DependencyGraphService>,
         dataset_ownership_service: Arc<dyn DatasetOwnershipService>,
+        metrics: Option<Arc<FlowMetrics>>,
     ) -> Self {
         Self {
             catalog,
@@ -111,6 +127,7 @@ impl FlowServiceImpl {
             dataset_changes_service,
             dependency_graph_service,
             dataset_ownership_service,
+            metrics,
         }
     }
 
@@ -343,6 +360,129 @@ impl FlowServiceImpl {
         }
     }
 
+    /// Triggers dependent transformations when an input dataset received new
+    /// data outside of the flows of this service
+    #[tracing::instrument(level = "trace", skip_all, fields(%message.dataset_id))]
+    async fn enqueue_dependent_flows_on_data_update(
+        &self,
+        target_catalog: &Catalog,
+        update_time: DateTime<Utc>,
+        message: &DatasetLifecycleMessageDataUpdated,
+    ) -> Result<(), InternalError> {
+        // Updates made by flows are handled when their tasks finish
+        let (is_updated_by_running_flow, maybe_last_flow_data_update) = {
+            let state = self.state.lock().unwrap();
+            if !state.running {
+                // Abort if running hasn't started yet
+                return Ok(());
+            }
+            (
+                [DatasetFlowType::Ingest, DatasetFlowType::ExecuteTransform]
+                    .into_iter()
+                    .any(|flow_type| {
+                        state
+                            .pending_flows
+                            .is_dataset_flow_running(&message.dataset_id, flow_type)
+                    }),
+                state
+                    .last_flow_data_updates
+                    .get(&message.dataset_id)
+                    .cloned(),
+            )
+        };
+        if is_updated_by_running_flow {
+            return Ok(());
+        }
+        if let Some(last_flow_data_update) = maybe_last_flow_data_update
+            && Self::is_head_written_by_flow(
+                target_catalog,
+                &message.dataset_id,
+                &message.new_head,
+                &last_flow_data_update,
+            )
+            .await?
+        {
+            tracing::debug!(
+                new_head = %message.new_head,
+                "Skipping data update already handled by the finished flow"
+            );
+            return Ok(());
+        }
+
+        let fk_dataset = FlowKeyDataset::new(message.dataset_id.clone(), DatasetFlowType::Ingest);
+        let dependent_dataset_flow_plans = self
+            .make_downstream_dependencies_flow_plans(&fk_dataset, None)
+            .await?;
+        if dependent_dataset_flow_plans.is_empty() {
+            return Ok(());
+        }
+
+        let trigger = FlowTrigger::InputDatasetUpdate(FlowTriggerInputDatasetUpdate {
+            trigger_time: update_time,
+            dataset_id: message.dataset_id.clone(),
+            old_head: message.old_head.clone(),
+            new_head: message.new_head.clone(),
+        });
+        for dependent_dataset_flow_plan in dependent_dataset_flow_plans {
+            self.trigger_flow_common(
+                &dependent_dataset_flow_plan.flow_key,
+                trigger.clone(),
+                dependent_dataset_flow_plan.flow_trigger_context,
+                dependent_dataset_flow_plan.maybe_config_snapshot,
+            )
+            .await?;
+        }
+
+        Ok(())
+    }
+
+    /// Checks whether the head is one of the blocks appended by the flow,
+    /// which is the case for all the intermediate commits of a multi-step
+    /// ingest, not just the final one. Only the blocks of the flow are
+    /// visited.
+    async fn is_head_written_by_flow(
+        target_catalog: &Catalog,
+        dataset_id: &DatasetID,
+        head: &Multihash,
+        flow_data_update: &FlowResultDatasetUpdateChanged,
+    ) -> Result<bool, InternalError> {
+        if *head == flow_data_update.new_head {
+            return Ok(true);
+        }
+
+        let dataset_repo = target_catalog
+            .get_one::<dyn DatasetRepository>()
+            .int_err()?;
+        let Some(dataset) = dataset_repo
+            .try_get_dataset(&dataset_id.as_local_ref())
+            .await?
+        else {
+            return Ok(false);
+        };
+
+        let chain = dataset.as_metadata_chain();
+        if !chain
+            .contains_block(&flow_data_update.new_head)
+            .await
+            .int_err()?
+        {
+            return Ok(false);
+        }
+
+        let mut flow_blocks = chain.iter_blocks_interval(
+            &flow_data_update.new_head,
+            flow_data_update.old_head.as_ref(),
+            true,
+        );
+        while let Some((block_hash, _)) = flow_blocks.try_next().await.int_err()? {
+            if block_hash == *head {
+                return Ok(true);
+            }
+        }
+
+        Ok(false)
+    }
+
     async fn trigger_flow_common(
         &self,
         flow_key: &FlowKey,
@@ -521,31 +661,24 @@ impl FlowServiceImpl {
 
         // Scan each accumulated trigger to decide
         for trigger in &flow.triggers {
-            if let FlowTrigger::InputDatasetFlow(trigger) = trigger {
-                match &trigger.flow_result {
-                    FlowResult::Empty | FlowResult::DatasetReset(_) => {}
-                    FlowResult::DatasetCompact(_) => {
-                        is_compacted = true;
-                    }
-                    FlowResult::DatasetUpdate(update) => {
-                        // Compute increment since the first trigger by this dataset.
-                        // Note: there might have been multiple updates since that time.
-                        // We are only recording the first trigger of particular dataset.
-                        if let FlowResultDatasetUpdate::Changed(update_result) = update {
-                            let increment = self
-                                .dataset_changes_service
-                                .get_increment_since(
-                                    &trigger.dataset_id,
-                                    update_result.old_head.as_ref(),
-                                )
-                                .await
-                                .int_err()?;
+            if let FlowTrigger::InputDatasetFlow(trigger) = trigger
+                && let FlowResult::DatasetCompact(_) = &trigger.flow_result
+            {
+                is_compacted = true;
+            }
 
-                            accumulated_records_count += increment.num_records;
-                            watermark_modified |= increment.updated_watermark.is_some();
-                        }
-                    }
-                }
+            // Compute increment since the first trigger by this dataset.
+            // Note: there might have been multiple updates since that time.
+            // We are only recording the first trigger of particular dataset.
+            if let Some((dataset_id, old_head)) = trigger.input_dataset_change() {
+                let increment = self
+                    .dataset_changes_service
+                    .get_increment_since(dataset_id, old_head)
+                    .await
+                    .int_err()?;
+
+                accumulated_records_count += increment.num_records;
+                watermark_modified |= increment.updated_watermark.is_some();
             }
         }
 
@@ -708,9 +841,21 @@ impl FlowServiceImpl {
         let logical_plan =
             self.make_task_logical_plan(&flow.flow_key, flow.config_snapshot.as_ref())?;
 
+        let task_span = tracing::info_span!("Scheduling flow task", flow_id = %flow.flow_id);
+        let maybe_trace_context = self
+            .state
+            .lock()
+            .unwrap()
+            .trace_contexts
+            .remove(&flow.flow_id);
+        if let Some(trace_context) = maybe_trace_context {
+            observability::set_remote_parent(&task_span, trace_context);
+        }
+
         let task = self
             .task_scheduler
             .create_task(logical_plan)
+            .instrument(task_span)
             .await
             .int_err()?;
 
@@ -745,12 +890,18 @@ impl FlowServiceImpl {
 
     async fn abort_flow_impl(&self, flow: &mut Flow) -> Result<(), InternalError> {
         // Abort flow itself
+        let was_finished = flow.outcome.is_some();
         flow.abort(self.time_source.now()).int_err()?;
         flow.save(self.flow_event_store.as_ref()).await.int_err()?;
 
+        if !was_finished && let Some(metrics) = &self.metrics {
+            metrics.on_flow_finished(flow.flow_key.get_type(), &FlowOutcome::Aborted);
+        }
+
         // Cancel associated tasks, but first drop task -> flow associations
         {
             let mut state = self.state.lock().unwrap();
+            state.trace_contexts.remove(&flow.flow_id);
             for task_id in &flow.task_ids {
                 state.pending_flows.untrack_flow_by_task(*task_id);
             }
@@ -786,6 +937,7 @@ impl FlowServiceImpl {
                     let mut max_slice_size: Option<u64> = None;
                     let mut max_slice_records: Option<u64> = None;
                     let mut keep_metadata_only = false;
+                    let mut data_layout = CompactionDataLayout::default();
 
                     if let Some(config_snapshot) = maybe_config_snapshot
                         && let FlowConfigurationSnapshot::Compaction(compaction_rule) =
@@ -795,6 +947,9 @@ impl FlowServiceImpl {
                         max_slice_records = compaction_rule.max_slice_records();
                         keep_metadata_only =
                             matches!(compaction_rule, CompactionRule::MetadataOnly(_));
+                        if let Some(rule_data_layout) = compaction_rule.data_layout() {
+                            data_layout = rule_data_layout.clone();
+                        }
                     };
 
                     Ok(LogicalPlan::HardCompactionDataset(HardCompactionDataset {
@@ -802,6 +957,7 @@ impl FlowServiceImpl {
                         max_slice_size,
                         max_slice_records,
                         keep_metadata_only,
+                        data_layout,
                     }))
                 }
                 DatasetFlowType::Reset => {
@@ -1043,17 +1199,33 @@ impl FlowService for FlowServiceImpl {
     ) -> Result<FlowState, RequestFlowError> {
         let activation_time = self.round_time(trigger_time)?;
 
-        self.trigger_flow_common(
-            &flow_key,
-            FlowTrigger::Manual(FlowTriggerManual {
-                trigger_time: activation_time,
-                initiator_account_id,
-            }),
-            FlowTriggerContext::Unconditional,
-            config_snapshot_maybe,
-        )
-        .await
-        .map_err(RequestFlowError::Internal)
+        let flow_state = self
+            .trigger_flow_common(
+                &flow_key,
+                FlowTrigger::Manual(FlowTriggerManual {
+                    trigger_time: activation_time,
+                    initiator_account_id,
+                }),
+                FlowTriggerContext::Unconditional,
+                config_snapshot_maybe,
+            )
+            .await
+            .map_err(RequestFlowError::Internal)?;
+
+        // A flow that was already pending stays attached to the first request. Flows
+        // that already have a task scheduled are not affected.
+        if flow_state.task_ids.is_empty()
+            && let Some(trace_context) = observability::current_trace_context()
+        {
+            self.state
+                .lock()
+                .unwrap()
+                .trace_contexts
+                .entry(flow_state.flow_id)
+                .or_insert(trace_context);
+        }
+
+        Ok(flow_state)
     }
 
     /// Returns states of flows associated with a given dataset
@@ -1400,6 +1572,16 @@ impl MessageConsumerT<TaskProgressMessage> for FlowServiceImpl {
                         let mut state = self.state.lock().unwrap();
                         state.pending_flows.untrack_flow_by_task(message.task_id);
                         state.pending_flows.drop_pending_flow(&flow.flow_key);
+
+                        if let FlowKey::Dataset(fk_dataset) = &flow.flow_key
+                            && let Some(FlowResult::DatasetUpdate(
+                                FlowResultDatasetUpdate::Changed(data_update),
+                            )) = flow.try_result_as_ref()
+                        {
+                            state
+                                .last_flow_data_updates
+                                .insert(fk_dataset.dataset_id.clone(), data_update.clone());
+                        }
                     }
 
                     // In case of success:
@@ -1437,6 +1619,24 @@ impl MessageConsumerT<TaskProgressMessage> for FlowServiceImpl {
                         )
                         .await?;
 
+                    if let Some(outcome) = flow.outcome.as_ref() {
+                        if let Some(metrics) = &self.metrics {
+                            metrics.on_flow_finished(flow.flow_key.get_type(), outcome);
+                        }
+
+                        outbox
+                            .post_message(
+                                MESSAGE_PRODUCER_KAMU_FLOW_PROGRESS_SERVICE,
+                                FlowProgressMessage::finished(
+                                    message.event_time,
+                                    flow.flow_id,
+                                    flow.flow_key.clone(),
+                                    outcome.clone(),
+                                ),
+                            )
+                            .await?;
+                    }
+
                     // TODO: retry logic in case of failed outcome
                 }
             }
@@ -1509,7 +1709,7 @@ impl MessageConsumerT<DatasetLifecycleMessage> for FlowServiceImpl {
     #[tracing::instrument(level = "debug", skip_all, fields(?message))]
     async fn consume_message(
         &self,
-        _: &Catalog,
+        target_catalog: &Catalog,
         message: &DatasetLifecycleMessage,
     ) -> Result<(), InternalError> {
         match message {
@@ -1524,6 +1724,7 @@ impl MessageConsumerT<DatasetLifecycleMessage> for FlowServiceImpl {
                     state
                         .active_configs
                         .drop_dataset_configs(&message.dataset_id);
+                    state.last_flow_data_updates.remove(&message.dataset_id);
 
                     // For every possible dataset flow:
                     //  - drop it from pending state
@@ -1563,8 +1764,15 @@ impl MessageConsumerT<DatasetLifecycleMessage> for FlowServiceImpl {
                 //     erases neighbors)
             }
 
+            DatasetLifecycleMessage::DataUpdated(message) => {
+                let update_time = self.round_time(self.time_source.now())?;
+                self.enqueue_dependent_flows_on_data_update(target_catalog, update_time, message)
+                    .await?;
+            }
+
             DatasetLifecycleMessage::Created(_)
-            | DatasetLifecycleMessage::DependenciesUpdated(_) => {
+            | DatasetLifecycleMessage::DependenciesUpdated(_)
+            | DatasetLifecycleMessage::Renamed(_) => {
                 // No action required
             }
         }
@@ -8,8 +8,10 @@
 // by the Apache License, Version 2.0.
 
 mod active_configs_state;
+mod flow_metrics;
 mod flow_service_impl;
 mod flow_time_wheel;
 mod pending_flows_state;
 
+pub use flow_metrics::*;
 pub use flow_service_impl::*;
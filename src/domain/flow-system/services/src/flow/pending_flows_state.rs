@@ -79,6 +79,18 @@ impl PendingFlowsState {
     pub fn try_get_flow_id_by_task(&self, task_id: TaskID) -> Option<FlowID> {
         self.pending_flows_by_tasks.get(&task_id).copied()
     }
+
+    /// Checks whether a pending flow of the dataset has already been assigned
+    /// a task
+    pub fn is_dataset_flow_running(
+        &self,
+        dataset_id: &DatasetID,
+        flow_type: DatasetFlowType,
+    ) -> bool {
+        self.pending_dataset_flows
+            .get(BorrowedFlowKeyDataset::new(dataset_id, flow_type).as_trait())
+            .is_some_and(|flow_id| self.pending_flows_by_tasks.values().any(|f| f == flow_id))
+    }
 }
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -377,7 +377,9 @@ impl MessageConsumerT<DatasetLifecycleMessage> for FlowConfigurationServiceImpl
             }
 
             DatasetLifecycleMessage::Created(_)
-            | DatasetLifecycleMessage::DependenciesUpdated(_) => {
+            | DatasetLifecycleMessage::DependenciesUpdated(_)
+            | DatasetLifecycleMessage::DataUpdated(_)
+            | DatasetLifecycleMessage::Renamed(_) => {
                 // no action required
             }
         }
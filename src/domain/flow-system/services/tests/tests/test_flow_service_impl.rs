@@ -15,6 +15,7 @@ use kamu::testing::MockDatasetChangesService;
 use kamu_core::*;
 use kamu_flow_system::*;
 use kamu_task_system::*;
+use messaging_outbox::OutboxExt;
 use opendatafabric::*;
 
 use super::{
@@ -739,6 +740,7 @@ async fn test_manual_trigger_compaction() {
                       max_slice_size: None,
                       max_slice_records: None,
                       keep_metadata_only: false,
+                      data_layout: CompactionDataLayout::default(),
                     }),
                 });
                 let task0_handle = task0_driver.run();
@@ -753,6 +755,7 @@ async fn test_manual_trigger_compaction() {
                     max_slice_size: None,
                     max_slice_records: None,
                     keep_metadata_only: false,
+                    data_layout: CompactionDataLayout::default(),
                   }),
                 });
                 let task1_handle = task1_driver.run();
@@ -1078,6 +1081,7 @@ async fn test_reset_trigger_keep_metadata_compaction_for_derivatives() {
                 max_slice_size: None,
                 max_slice_records: None,
                 keep_metadata_only: true,
+                data_layout: CompactionDataLayout::default(),
               }),
           });
           let task1_handle = task1_driver.run();
@@ -1105,6 +1109,7 @@ async fn test_reset_trigger_keep_metadata_compaction_for_derivatives() {
                 max_slice_size: None,
                 max_slice_records: None,
                 keep_metadata_only: true,
+                data_layout: CompactionDataLayout::default(),
               }),
           });
           let task2_handle = task2_driver.run();
@@ -1210,7 +1215,13 @@ async fn test_manual_trigger_compaction_with_config() {
             foo_id.clone(),
             DatasetFlowType::HardCompaction,
             CompactionRule::Full(
-                CompactionRuleFull::new_checked(max_slice_size, max_slice_records, false).unwrap(),
+                CompactionRuleFull::new_checked(
+                    max_slice_size,
+                    max_slice_records,
+                    false,
+                    CompactionDataLayout::default(),
+                )
+                .unwrap(),
             ),
         )
         .await;
@@ -1245,6 +1256,7 @@ async fn test_manual_trigger_compaction_with_config() {
                       max_slice_size: Some(max_slice_size),
                       max_slice_records: Some(max_slice_records),
                       keep_metadata_only: false,
+                      data_layout: CompactionDataLayout::default(),
                     }),
                 });
                 let task0_handle = task0_driver.run();
@@ -1330,7 +1342,13 @@ async fn test_full_hard_compaction_trigger_keep_metadata_compaction_for_derivati
             foo_id.clone(),
             DatasetFlowType::HardCompaction,
             CompactionRule::Full(
-                CompactionRuleFull::new_checked(max_slice_size, max_slice_records, true).unwrap(),
+                CompactionRuleFull::new_checked(
+                    max_slice_size,
+                    max_slice_records,
+                    true,
+                    CompactionDataLayout::default(),
+                )
+                .unwrap(),
             ),
         )
         .await;
@@ -1388,6 +1406,7 @@ async fn test_full_hard_compaction_trigger_keep_metadata_compaction_for_derivati
                 max_slice_size: Some(max_slice_size),
                 max_slice_records: Some(max_slice_records),
                 keep_metadata_only: false,
+                data_layout: CompactionDataLayout::default(),
               }),
           });
           let task0_handle = task0_driver.run();
@@ -1415,6 +1434,7 @@ async fn test_full_hard_compaction_trigger_keep_metadata_compaction_for_derivati
                 max_slice_size: None,
                 max_slice_records: None,
                 keep_metadata_only: true,
+                data_layout: CompactionDataLayout::default(),
               }),
           });
           let task1_handle = task1_driver.run();
@@ -1442,6 +1462,7 @@ async fn test_full_hard_compaction_trigger_keep_metadata_compaction_for_derivati
                 max_slice_size: None,
                 max_slice_records: None,
                 keep_metadata_only: true,
+                data_layout: CompactionDataLayout::default(),
               }),
           });
           let task2_handle = task2_driver.run();
@@ -1619,6 +1640,7 @@ async fn test_manual_trigger_keep_metadata_only_with_recursive_compaction() {
                   max_slice_size: None,
                   max_slice_records: None,
                   keep_metadata_only: true,
+                  data_layout: CompactionDataLayout::default(),
                 }),
             });
             let task0_handle = task0_driver.run();
@@ -1646,6 +1668,7 @@ async fn test_manual_trigger_keep_metadata_only_with_recursive_compaction() {
                   max_slice_size: None,
                   max_slice_records: None,
                   keep_metadata_only: true,
+                  data_layout: CompactionDataLayout::default(),
                 }),
             });
             let task1_handle = task1_driver.run();
@@ -1673,6 +1696,7 @@ async fn test_manual_trigger_keep_metadata_only_with_recursive_compaction() {
                   max_slice_size: None,
                   max_slice_records: None,
                   keep_metadata_only: true,
+                  data_layout: CompactionDataLayout::default(),
                 }),
             });
             let task2_handle = task2_driver.run();
@@ -1852,6 +1876,7 @@ async fn test_manual_trigger_keep_metadata_only_without_recursive_compaction() {
                   max_slice_size: None,
                   max_slice_records: None,
                   keep_metadata_only: true,
+                  data_layout: CompactionDataLayout::default(),
                 }),
             });
             let task0_handle = task0_driver.run();
@@ -1979,6 +2004,7 @@ async fn test_manual_trigger_keep_metadata_only_compaction_multiple_accounts() {
                   max_slice_size: None,
                   max_slice_records: None,
                   keep_metadata_only: true,
+                  data_layout: CompactionDataLayout::default(),
                 }),
             });
             let task0_handle = task0_driver.run();
@@ -2009,6 +2035,7 @@ async fn test_manual_trigger_keep_metadata_only_compaction_multiple_accounts() {
                   max_slice_size: None,
                   max_slice_records: None,
                   keep_metadata_only: true,
+                  data_layout: CompactionDataLayout::default(),
                 }),
             });
             let task1_handle = task1_driver.run();
@@ -3186,6 +3213,202 @@ async fn test_derived_dataset_triggered_initially_and_after_input_change() {
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
 
+#[test_log::test(tokio::test)]
+async fn test_data_update_of_finished_flow_does_not_trigger_dependents_again() {
+    let harness = FlowHarness::with_overrides(FlowHarnessOverrides {
+        mock_dataset_changes: Some(MockDatasetChangesService::with_increment_since(
+            DatasetIntervalIncrement {
+                num_blocks: 1,
+                num_records: 3,
+                updated_watermark: None,
+            },
+        )),
+        ..Default::default()
+    })
+    .await;
+
+    let foo_id = harness
+        .create_root_dataset(DatasetAlias {
+            dataset_name: DatasetName::new_unchecked("foo"),
+            account_name: None,
+        })
+        .await
+        .dataset_handle
+        .id;
+
+    let bar_id = harness
+        .create_derived_dataset(
+            DatasetAlias {
+                dataset_name: DatasetName::new_unchecked("bar"),
+                account_name: None,
+            },
+            vec![foo_id.clone()],
+        )
+        .await;
+
+    harness
+        .set_dataset_flow_ingest(
+            harness.now_datetime(),
+            foo_id.clone(),
+            DatasetFlowType::Ingest,
+            IngestRule {
+                fetch_uncacheable: false,
+                schedule_condition: Duration::try_milliseconds(80).unwrap().into(),
+            },
+        )
+        .await;
+
+    harness
+        .set_dataset_flow_transform_rule(
+            harness.now_datetime(),
+            bar_id.clone(),
+            DatasetFlowType::ExecuteTransform,
+            TransformRule::new_checked(1, Duration::try_seconds(1).unwrap()).unwrap(),
+        )
+        .await;
+
+    // Enforce dependency graph initialization
+    harness.eager_initialization().await;
+
+    // Remember start time
+    let start_time = harness
+        .now_datetime()
+        .duration_round(Duration::try_milliseconds(SCHEDULING_ALIGNMENT_MS).unwrap())
+        .unwrap();
+
+    // Run scheduler concurrently with manual triggers script
+    tokio::select! {
+        // Run API service
+        res = harness.flow_service.run(start_time) => res.int_err(),
+
+        // Run simulation script and task drivers
+        _ = async {
+            // Task 0: "foo" start running at 10ms, finish at 20ms
+            let task0_driver = harness.task_driver(TaskDriverArgs {
+                task_id: TaskID::new(0),
+                dataset_id: Some(foo_id.clone()),
+                run_since_start: Duration::try_milliseconds(10).unwrap(),
+                finish_in_with: Some((Duration::try_milliseconds(10).unwrap(), TaskOutcome::Success(TaskResult::Empty))),
+                expected_logical_plan: LogicalPlan::UpdateDataset(UpdateDataset {
+                  dataset_id: foo_id.clone(),
+                  fetch_uncacheable: false
+                }),
+            });
+            let task0_handle = task0_driver.run();
+
+            // Task 1: "bar" start running at 20ms, finish at 30ms
+            let task1_driver = harness.task_driver(TaskDriverArgs {
+                task_id: TaskID::new(1),
+                dataset_id: Some(bar_id.clone()),
+                run_since_start: Duration::try_milliseconds(20).unwrap(),
+                finish_in_with: Some((Duration::try_milliseconds(10).unwrap(), TaskOutcome::Success(TaskResult::Empty))),
+                expected_logical_plan: LogicalPlan::UpdateDataset(UpdateDataset {
+                  dataset_id: bar_id.clone(),
+                  fetch_uncacheable: false
+                }),
+            });
+            let task1_handle = task1_driver.run();
+
+            // Task 2: "foo" start running at 110ms, finish at 120ms with new data
+            let task2_driver = harness.task_driver(TaskDriverArgs {
+                task_id: TaskID::new(2),
+                dataset_id: Some(foo_id.clone()),
+                run_since_start: Duration::try_milliseconds(110).unwrap(),
+                finish_in_with: Some((Duration::try_milliseconds(10).unwrap(), TaskOutcome::Success(TaskResult::UpdateDatasetResult(TaskUpdateDatasetResult {
+                  pull_result: PullResult::Updated {
+                    old_head: Some(Multihash::from_digest_sha3_256(b"old-slice")),
+                    new_head: Multihash::from_digest_sha3_256(b"new-slice"),
+                  },
+                })))),
+                expected_logical_plan: LogicalPlan::UpdateDataset(UpdateDataset {
+                  dataset_id: foo_id.clone(),
+                  fetch_uncacheable: false
+                }),
+            });
+            let task2_handle = task2_driver.run();
+
+            // Task 3: "bar" start running at 130ms, finish at 140ms
+            let task3_driver = harness.task_driver(TaskDriverArgs {
+                task_id: TaskID::new(3),
+                dataset_id: Some(bar_id.clone()),
+                run_since_start: Duration::try_milliseconds(130).unwrap(),
+                finish_in_with: Some((Duration::try_milliseconds(10).unwrap(), TaskOutcome::Success(TaskResult::Empty))),
+                expected_logical_plan: LogicalPlan::UpdateDataset(UpdateDataset {
+                  dataset_id: bar_id.clone(),
+                  fetch_uncacheable: false
+                }),
+            });
+            let task3_handle = task3_driver.run();
+
+            // Main simulation script
+            let main_handle = async {
+                harness.advance_time(Duration::try_milliseconds(150).unwrap()).await;
+            };
+
+            tokio::join!(task0_handle, task1_handle, task2_handle, task3_handle, main_handle)
+
+        } => Ok(())
+    }
+    .unwrap();
+
+    let count_bar_flows = || async {
+        harness
+            .flow_service
+            .list_all_flows_by_dataset(
+                &bar_id,
+                DatasetFlowFilters::default(),
+                FlowPaginationOpts {
+                    offset: 0,
+                    limit: 100,
+                },
+            )
+            .await
+            .unwrap()
+            .total_count
+    };
+    let num_bar_flows = count_bar_flows().await;
+
+    let outbox = harness
+        .catalog
+        .get_one::<dyn messaging_outbox::Outbox>()
+        .unwrap();
+
+    // The update written by task 2 arrives after the flow has finished and
+    // already triggered "bar"
+    outbox
+        .post_message(
+            MESSAGE_PRODUCER_KAMU_CORE_DATASET_SERVICE,
+            DatasetLifecycleMessage::data_updated(
+                foo_id.clone(),
+                Some(Multihash::from_digest_sha3_256(b"old-slice")),
+                Multihash::from_digest_sha3_256(b"new-slice"),
+                None,
+                None,
+            ),
+        )
+        .await
+        .unwrap();
+    assert_eq!(count_bar_flows().await, num_bar_flows);
+
+    // An update made outside of flows triggers "bar"
+    outbox
+        .post_message(
+            MESSAGE_PRODUCER_KAMU_CORE_DATASET_SERVICE,
+            DatasetLifecycleMessage::data_updated(
+                foo_id.clone(),
+                Some(Multihash::from_digest_sha3_256(b"new-slice")),
+                Multihash::from_digest_sha3_256(b"pushed-slice"),
+                None,
+                None,
+            ),
+        )
+        .await
+        .unwrap();
+    assert_eq!(count_bar_flows().await, num_bar_flows + 1);
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
 #[test_log::test(tokio::test)]
 async fn test_throttling_manual_triggers() {
     let harness = FlowHarness::with_overrides(FlowHarnessOverrides {
@@ -5299,6 +5522,7 @@ async fn test_list_all_flow_initiators() {
                       max_slice_size: None,
                       max_slice_records: None,
                       keep_metadata_only: false,
+                      data_layout: CompactionDataLayout::default(),
                     }),
                 });
                 let task0_handle = task0_driver.run();
@@ -5313,6 +5537,7 @@ async fn test_list_all_flow_initiators() {
                     max_slice_size: None,
                     max_slice_records: None,
                     keep_metadata_only: false,
+                    data_layout: CompactionDataLayout::default(),
                   }),
                 });
                 let task1_handle = task1_driver.run();
@@ -5461,6 +5686,7 @@ async fn test_list_all_datasets_with_flow() {
                       max_slice_size: None,
                       max_slice_records: None,
                       keep_metadata_only: false,
+                      data_layout: CompactionDataLayout::default(),
                     }),
                 });
                 let task0_handle = task0_driver.run();
@@ -5475,6 +5701,7 @@ async fn test_list_all_datasets_with_flow() {
                     max_slice_size: None,
                     max_slice_records: None,
                     keep_metadata_only: false,
+                    data_layout: CompactionDataLayout::default(),
                   }),
                 });
                 let task1_handle = task1_driver.run();
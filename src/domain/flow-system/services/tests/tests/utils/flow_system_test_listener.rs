@@ -170,6 +170,14 @@ impl std::fmt::Display for FlowSystemTestListener {
                                         .cloned()
                                         .unwrap_or_else(|| i.dataset_id.to_string()),
                                 ),
+                                FlowTrigger::InputDatasetUpdate(i) => format!(
+                                    "InputUpdate({})",
+                                    state
+                                        .dataset_display_names
+                                        .get(&i.dataset_id)
+                                        .cloned()
+                                        .unwrap_or_else(|| i.dataset_id.to_string()),
+                                ),
                             }
                         )?;
                     }
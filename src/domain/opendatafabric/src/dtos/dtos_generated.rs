@@ -341,6 +341,10 @@ pub enum FetchStep {
     Container(FetchStepContainer),
     Mqtt(FetchStepMqtt),
     EthereumLogs(FetchStepEthereumLogs),
+    Sql(FetchStepSql),
+    HttpPaginated(FetchStepHttpPaginated),
+    EthereumBlocks(FetchStepEthereumBlocks),
+    EthereumTransactions(FetchStepEthereumTransactions),
 }
 
 impl_enum_with_variants!(FetchStep);
@@ -430,12 +434,155 @@ pub struct FetchStepEthereumLogs {
 
 impl_enum_variant!(FetchStep::EthereumLogs(FetchStepEthereumLogs));
 
+/// Runs a query against a relational database and fetches its results.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct FetchStepSql {
+    /// Database connection URL (can be templated), e.g.
+    /// `postgres://user:${{ env.DB_PASSWORD }}@host:5432/db`.
+    pub url: String,
+    /// SQL query which results will be fetched.
+    pub query: String,
+    /// Name of the non-decreasing column of the query results that will be
+    /// used to fetch only the rows added since the previous poll. Rows sharing
+    /// the same value of the column are always fetched together.
+    pub cursor_column: Option<String>,
+}
+
+impl_enum_variant!(FetchStep::Sql(FetchStepSql));
+
+/// Fetches JSON records from a paginated HTTP API, following the pages until
+/// they are exhausted.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct FetchStepHttpPaginated {
+    /// URL of the first page (can be templated).
+    pub url: String,
+    /// Headers to pass during the requests (e.g. HTTP Authorization)
+    pub headers: Option<Vec<RequestHeader>>,
+    /// JSON pointer to the array of records in the response body, e.g.
+    /// `/data`. The body itself is expected to be an array if not specified.
+    pub records_path: Option<String>,
+    /// Describes how the next page is requested.
+    pub pagination: HttpPagination,
+    /// Describes how to fetch only the records added since the previous poll.
+    pub high_water_mark: Option<HttpHighWaterMark>,
+}
+
+impl_enum_variant!(FetchStep::HttpPaginated(FetchStepHttpPaginated));
+
+/// Connects to an Ethereum node to stream blocks.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct FetchStepEthereumBlocks {
+    /// Identifier of the chain to scan blocks from. This parameter may be used
+    /// for RPC endpoint lookup as well as asserting that provided `nodeUrl`
+    /// corresponds to the expected chain.
+    pub chain_id: Option<u64>,
+    /// Url of the node.
+    pub node_url: Option<String>,
+    /// Number of the first block to scan. Defaults to the genesis block.
+    pub from_block: Option<u64>,
+    /// Number of the last block to scan. The chain is followed indefinitely if
+    /// not specified.
+    pub to_block: Option<u64>,
+}
+
+impl_enum_variant!(FetchStep::EthereumBlocks(FetchStepEthereumBlocks));
+
+/// Connects to an Ethereum node to stream transactions along with their
+/// receipts.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct FetchStepEthereumTransactions {
+    /// Identifier of the chain to scan transactions from. This parameter may
+    /// be used for RPC endpoint lookup as well as asserting that provided
+    /// `nodeUrl` corresponds to the expected chain.
+    pub chain_id: Option<u64>,
+    /// Url of the node.
+    pub node_url: Option<String>,
+    /// Number of the first block to scan. Defaults to the genesis block.
+    pub from_block: Option<u64>,
+    /// Number of the last block to scan. The chain is followed indefinitely if
+    /// not specified.
+    pub to_block: Option<u64>,
+    /// Address of the recipient (e.g. a contract) to limit the transactions
+    /// to.
+    pub to_address: Option<String>,
+    /// Solidity function signature to use for decoding the transaction input.
+    /// Using this field adds `input_decoded` to the output containing decoded
+    /// call arguments as JSON.
+    pub signature: Option<String>,
+}
+
+impl_enum_variant!(FetchStep::EthereumTransactions(
+    FetchStepEthereumTransactions
+));
+
 #[derive(Clone, Copy, PartialEq, Eq, Debug)]
 pub enum SourceOrdering {
     ByEventTime,
     ByName,
 }
 
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// HttpHighWaterMark
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Defines how records added since the previous poll are requested from an
+/// HTTP API.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct HttpHighWaterMark {
+    /// Name of the record field which greatest value seen so far is
+    /// remembered in the source state.
+    pub field: String,
+    /// Name of the query parameter of the first page request that will carry
+    /// the remembered value.
+    pub param: String,
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// HttpPagination
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub enum HttpPagination {
+    LinkHeader(HttpPaginationLinkHeader),
+    BodyCursor(HttpPaginationBodyCursor),
+    OffsetLimit(HttpPaginationOffsetLimit),
+}
+
+impl_enum_with_variants!(HttpPagination);
+
+/// Follows the `next` relation of the `Link` response header (RFC 8288).
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct HttpPaginationLinkHeader {}
+
+impl_enum_variant!(HttpPagination::LinkHeader(HttpPaginationLinkHeader));
+
+/// Passes the cursor found in the response body as a query parameter of the
+/// next page request. Pagination stops when the cursor is missing or null.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct HttpPaginationBodyCursor {
+    /// JSON pointer to the next page cursor in the response body, e.g.
+    /// `/meta/next_cursor`.
+    pub cursor_path: String,
+    /// Name of the query parameter to pass the cursor in.
+    pub cursor_param: String,
+}
+
+impl_enum_variant!(HttpPagination::BodyCursor(HttpPaginationBodyCursor));
+
+/// Requests pages of fixed size using offset and limit query parameters.
+/// Pagination stops when a page returns fewer records than requested.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct HttpPaginationOffsetLimit {
+    /// Name of the offset query parameter.
+    pub offset_param: String,
+    /// Name of the limit query parameter.
+    pub limit_param: String,
+    /// Number of records to request per page.
+    pub page_size: u64,
+}
+
+impl_enum_variant!(HttpPagination::OffsetLimit(HttpPaginationOffsetLimit));
+
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
 // MergeStrategy
 // https://github.com/kamu-data/open-data-fabric/blob/master/open-data-fabric.md#mergestrategy-schema
@@ -660,6 +807,11 @@ impl_enum_variant!(PrepStep::Pipe(PrepStepPipe));
 pub enum CompressionFormat {
     Gzip,
     Zip,
+    Zstd,
+    Xz,
+    Bzip2,
+    Tar,
+    Auto,
 }
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
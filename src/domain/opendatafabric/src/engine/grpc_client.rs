@@ -32,13 +32,33 @@ use crate::{
 
 pub struct EngineGrpcClient {
     client: EngineClientGRPC<tonic::transport::Channel>,
+    trace_parent: Option<tonic::metadata::AsciiMetadataValue>,
 }
 
 impl EngineGrpcClient {
     pub async fn connect(host: &str, port: u16) -> Result<Self, tonic::transport::Error> {
         let client = EngineClientGRPC::connect(format!("http://{host}:{port}")).await?;
 
-        Ok(Self { client })
+        Ok(Self {
+            client,
+            trace_parent: None,
+        })
+    }
+
+    /// Propagates the W3C `traceparent` to the engine with every request, so
+    /// that engine operations can be followed as part of the caller's trace
+    pub fn set_trace_parent(&mut self, trace_parent: Option<&str>) {
+        self.trace_parent = trace_parent.and_then(|v| v.parse().ok());
+    }
+
+    fn new_request<T>(&self, message: T) -> tonic::Request<T> {
+        let mut request = tonic::Request::new(message);
+        if let Some(trace_parent) = &self.trace_parent {
+            request
+                .metadata_mut()
+                .insert("traceparent", trace_parent.clone());
+        }
+        request
     }
 
     pub async fn execute_raw_query(
@@ -49,7 +69,7 @@ impl EngineGrpcClient {
             .write_raw_query_request(&request)
             .int_err()?;
 
-        let request_grpc = tonic::Request::new(RawQueryRequestGRPC {
+        let request_grpc = self.new_request(RawQueryRequestGRPC {
             flatbuffer: fb.collapse_vec(),
         });
 
@@ -88,7 +108,7 @@ impl EngineGrpcClient {
             .write_transform_request(&request)
             .int_err()?;
 
-        let request_grpc = tonic::Request::new(TransformRequestGRPC {
+        let request_grpc = self.new_request(TransformRequestGRPC {
             flatbuffer: fb.collapse_vec(),
         });
 
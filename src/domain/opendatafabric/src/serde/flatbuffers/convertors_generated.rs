@@ -670,6 +670,22 @@ impl<'fb> FlatbuffersEnumSerializable<'fb, fb::FetchStep> for odf::FetchStep {
                 fb::FetchStep::FetchStepEthereumLogs,
                 v.serialize(fb).as_union_value(),
             ),
+            odf::FetchStep::Sql(v) => (
+                fb::FetchStep::FetchStepSql,
+                v.serialize(fb).as_union_value(),
+            ),
+            odf::FetchStep::HttpPaginated(v) => (
+                fb::FetchStep::FetchStepHttpPaginated,
+                v.serialize(fb).as_union_value(),
+            ),
+            odf::FetchStep::EthereumBlocks(v) => (
+                fb::FetchStep::FetchStepEthereumBlocks,
+                v.serialize(fb).as_union_value(),
+            ),
+            odf::FetchStep::EthereumTransactions(v) => (
+                fb::FetchStep::FetchStepEthereumTransactions,
+                v.serialize(fb).as_union_value(),
+            ),
         }
     }
 }
@@ -702,6 +718,26 @@ impl<'fb> FlatbuffersEnumDeserializable<'fb, fb::FetchStep> for odf::FetchStep {
                     fb::FetchStepEthereumLogs::init_from_table(table)
                 }))
             }
+            fb::FetchStep::FetchStepSql => {
+                odf::FetchStep::Sql(odf::FetchStepSql::deserialize(unsafe {
+                    fb::FetchStepSql::init_from_table(table)
+                }))
+            }
+            fb::FetchStep::FetchStepHttpPaginated => {
+                odf::FetchStep::HttpPaginated(odf::FetchStepHttpPaginated::deserialize(unsafe {
+                    fb::FetchStepHttpPaginated::init_from_table(table)
+                }))
+            }
+            fb::FetchStep::FetchStepEthereumBlocks => {
+                odf::FetchStep::EthereumBlocks(odf::FetchStepEthereumBlocks::deserialize(unsafe {
+                    fb::FetchStepEthereumBlocks::init_from_table(table)
+                }))
+            }
+            fb::FetchStep::FetchStepEthereumTransactions => odf::FetchStep::EthereumTransactions(
+                odf::FetchStepEthereumTransactions::deserialize(unsafe {
+                    fb::FetchStepEthereumTransactions::init_from_table(table)
+                }),
+            ),
             _ => panic!("Invalid enum value: {}", t.0),
         }
     }
@@ -899,6 +935,137 @@ impl<'fb> FlatbuffersDeserializable<fb::FetchStepEthereumLogs<'fb>> for odf::Fet
     }
 }
 
+impl<'fb> FlatbuffersSerializable<'fb> for odf::FetchStepSql {
+    type OffsetT = WIPOffset<fb::FetchStepSql<'fb>>;
+
+    fn serialize(&self, fb: &mut FlatBufferBuilder<'fb>) -> Self::OffsetT {
+        let url_offset = { fb.create_string(&self.url) };
+        let query_offset = { fb.create_string(&self.query) };
+        let cursor_column_offset = self.cursor_column.as_ref().map(|v| fb.create_string(&v));
+        let mut builder = fb::FetchStepSqlBuilder::new(fb);
+        builder.add_url(url_offset);
+        builder.add_query(query_offset);
+        cursor_column_offset.map(|off| builder.add_cursor_column(off));
+        builder.finish()
+    }
+}
+
+impl<'fb> FlatbuffersDeserializable<fb::FetchStepSql<'fb>> for odf::FetchStepSql {
+    fn deserialize(proxy: fb::FetchStepSql<'fb>) -> Self {
+        odf::FetchStepSql {
+            url: proxy.url().map(|v| v.to_owned()).unwrap(),
+            query: proxy.query().map(|v| v.to_owned()).unwrap(),
+            cursor_column: proxy.cursor_column().map(|v| v.to_owned()),
+        }
+    }
+}
+
+impl<'fb> FlatbuffersSerializable<'fb> for odf::FetchStepHttpPaginated {
+    type OffsetT = WIPOffset<fb::FetchStepHttpPaginated<'fb>>;
+
+    fn serialize(&self, fb: &mut FlatBufferBuilder<'fb>) -> Self::OffsetT {
+        let url_offset = { fb.create_string(&self.url) };
+        let headers_offset = self.headers.as_ref().map(|v| {
+            let offsets: Vec<_> = v.iter().map(|i| i.serialize(fb)).collect();
+            fb.create_vector(&offsets)
+        });
+        let records_path_offset = self.records_path.as_ref().map(|v| fb.create_string(&v));
+        let pagination_offset = { self.pagination.serialize(fb) };
+        let high_water_mark_offset = self.high_water_mark.as_ref().map(|v| v.serialize(fb));
+        let mut builder = fb::FetchStepHttpPaginatedBuilder::new(fb);
+        builder.add_url(url_offset);
+        headers_offset.map(|off| builder.add_headers(off));
+        records_path_offset.map(|off| builder.add_records_path(off));
+        builder.add_pagination_type(pagination_offset.0);
+        builder.add_pagination(pagination_offset.1);
+        high_water_mark_offset.map(|off| builder.add_high_water_mark(off));
+        builder.finish()
+    }
+}
+
+impl<'fb> FlatbuffersDeserializable<fb::FetchStepHttpPaginated<'fb>>
+    for odf::FetchStepHttpPaginated
+{
+    fn deserialize(proxy: fb::FetchStepHttpPaginated<'fb>) -> Self {
+        odf::FetchStepHttpPaginated {
+            url: proxy.url().map(|v| v.to_owned()).unwrap(),
+            headers: proxy.headers().map(|v| {
+                v.iter()
+                    .map(|i| odf::RequestHeader::deserialize(i))
+                    .collect()
+            }),
+            records_path: proxy.records_path().map(|v| v.to_owned()),
+            pagination: proxy
+                .pagination()
+                .map(|v| odf::HttpPagination::deserialize(v, proxy.pagination_type()))
+                .unwrap(),
+            high_water_mark: proxy
+                .high_water_mark()
+                .map(|v| odf::HttpHighWaterMark::deserialize(v)),
+        }
+    }
+}
+
+impl<'fb> FlatbuffersSerializable<'fb> for odf::FetchStepEthereumBlocks {
+    type OffsetT = WIPOffset<fb::FetchStepEthereumBlocks<'fb>>;
+
+    fn serialize(&self, fb: &mut FlatBufferBuilder<'fb>) -> Self::OffsetT {
+        let node_url_offset = self.node_url.as_ref().map(|v| fb.create_string(&v));
+        let mut builder = fb::FetchStepEthereumBlocksBuilder::new(fb);
+        self.chain_id.map(|v| builder.add_chain_id(v));
+        node_url_offset.map(|off| builder.add_node_url(off));
+        self.from_block.map(|v| builder.add_from_block(v));
+        self.to_block.map(|v| builder.add_to_block(v));
+        builder.finish()
+    }
+}
+
+impl<'fb> FlatbuffersDeserializable<fb::FetchStepEthereumBlocks<'fb>>
+    for odf::FetchStepEthereumBlocks
+{
+    fn deserialize(proxy: fb::FetchStepEthereumBlocks<'fb>) -> Self {
+        odf::FetchStepEthereumBlocks {
+            chain_id: proxy.chain_id().map(|v| v),
+            node_url: proxy.node_url().map(|v| v.to_owned()),
+            from_block: proxy.from_block().map(|v| v),
+            to_block: proxy.to_block().map(|v| v),
+        }
+    }
+}
+
+impl<'fb> FlatbuffersSerializable<'fb> for odf::FetchStepEthereumTransactions {
+    type OffsetT = WIPOffset<fb::FetchStepEthereumTransactions<'fb>>;
+
+    fn serialize(&self, fb: &mut FlatBufferBuilder<'fb>) -> Self::OffsetT {
+        let node_url_offset = self.node_url.as_ref().map(|v| fb.create_string(&v));
+        let to_address_offset = self.to_address.as_ref().map(|v| fb.create_string(&v));
+        let signature_offset = self.signature.as_ref().map(|v| fb.create_string(&v));
+        let mut builder = fb::FetchStepEthereumTransactionsBuilder::new(fb);
+        self.chain_id.map(|v| builder.add_chain_id(v));
+        node_url_offset.map(|off| builder.add_node_url(off));
+        self.from_block.map(|v| builder.add_from_block(v));
+        self.to_block.map(|v| builder.add_to_block(v));
+        to_address_offset.map(|off| builder.add_to_address(off));
+        signature_offset.map(|off| builder.add_signature(off));
+        builder.finish()
+    }
+}
+
+impl<'fb> FlatbuffersDeserializable<fb::FetchStepEthereumTransactions<'fb>>
+    for odf::FetchStepEthereumTransactions
+{
+    fn deserialize(proxy: fb::FetchStepEthereumTransactions<'fb>) -> Self {
+        odf::FetchStepEthereumTransactions {
+            chain_id: proxy.chain_id().map(|v| v),
+            node_url: proxy.node_url().map(|v| v.to_owned()),
+            from_block: proxy.from_block().map(|v| v),
+            to_block: proxy.to_block().map(|v| v),
+            to_address: proxy.to_address().map(|v| v.to_owned()),
+            signature: proxy.signature().map(|v| v.to_owned()),
+        }
+    }
+}
+
 impl From<odf::SourceOrdering> for fb::SourceOrdering {
     fn from(v: odf::SourceOrdering) -> Self {
         match v {
@@ -918,6 +1085,148 @@ impl Into<odf::SourceOrdering> for fb::SourceOrdering {
     }
 }
 
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// HttpHighWaterMark
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+impl<'fb> FlatbuffersSerializable<'fb> for odf::HttpHighWaterMark {
+    type OffsetT = WIPOffset<fb::HttpHighWaterMark<'fb>>;
+
+    fn serialize(&self, fb: &mut FlatBufferBuilder<'fb>) -> Self::OffsetT {
+        let field_offset = { fb.create_string(&self.field) };
+        let param_offset = { fb.create_string(&self.param) };
+        let mut builder = fb::HttpHighWaterMarkBuilder::new(fb);
+        builder.add_field(field_offset);
+        builder.add_param(param_offset);
+        builder.finish()
+    }
+}
+
+impl<'fb> FlatbuffersDeserializable<fb::HttpHighWaterMark<'fb>> for odf::HttpHighWaterMark {
+    fn deserialize(proxy: fb::HttpHighWaterMark<'fb>) -> Self {
+        odf::HttpHighWaterMark {
+            field: proxy.field().map(|v| v.to_owned()).unwrap(),
+            param: proxy.param().map(|v| v.to_owned()).unwrap(),
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// HttpPagination
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+impl<'fb> FlatbuffersEnumSerializable<'fb, fb::HttpPagination> for odf::HttpPagination {
+    fn serialize(
+        &self,
+        fb: &mut FlatBufferBuilder<'fb>,
+    ) -> (fb::HttpPagination, WIPOffset<UnionWIPOffset>) {
+        match self {
+            odf::HttpPagination::LinkHeader(v) => (
+                fb::HttpPagination::HttpPaginationLinkHeader,
+                v.serialize(fb).as_union_value(),
+            ),
+            odf::HttpPagination::BodyCursor(v) => (
+                fb::HttpPagination::HttpPaginationBodyCursor,
+                v.serialize(fb).as_union_value(),
+            ),
+            odf::HttpPagination::OffsetLimit(v) => (
+                fb::HttpPagination::HttpPaginationOffsetLimit,
+                v.serialize(fb).as_union_value(),
+            ),
+        }
+    }
+}
+
+impl<'fb> FlatbuffersEnumDeserializable<'fb, fb::HttpPagination> for odf::HttpPagination {
+    fn deserialize(table: flatbuffers::Table<'fb>, t: fb::HttpPagination) -> Self {
+        match t {
+            fb::HttpPagination::HttpPaginationLinkHeader => {
+                odf::HttpPagination::LinkHeader(odf::HttpPaginationLinkHeader::deserialize(
+                    unsafe { fb::HttpPaginationLinkHeader::init_from_table(table) },
+                ))
+            }
+            fb::HttpPagination::HttpPaginationBodyCursor => {
+                odf::HttpPagination::BodyCursor(odf::HttpPaginationBodyCursor::deserialize(
+                    unsafe { fb::HttpPaginationBodyCursor::init_from_table(table) },
+                ))
+            }
+            fb::HttpPagination::HttpPaginationOffsetLimit => {
+                odf::HttpPagination::OffsetLimit(odf::HttpPaginationOffsetLimit::deserialize(
+                    unsafe { fb::HttpPaginationOffsetLimit::init_from_table(table) },
+                ))
+            }
+            _ => panic!("Invalid enum value: {}", t.0),
+        }
+    }
+}
+
+impl<'fb> FlatbuffersSerializable<'fb> for odf::HttpPaginationLinkHeader {
+    type OffsetT = WIPOffset<fb::HttpPaginationLinkHeader<'fb>>;
+
+    fn serialize(&self, fb: &mut FlatBufferBuilder<'fb>) -> Self::OffsetT {
+        let mut builder = fb::HttpPaginationLinkHeaderBuilder::new(fb);
+        builder.finish()
+    }
+}
+
+impl<'fb> FlatbuffersDeserializable<fb::HttpPaginationLinkHeader<'fb>>
+    for odf::HttpPaginationLinkHeader
+{
+    fn deserialize(proxy: fb::HttpPaginationLinkHeader<'fb>) -> Self {
+        odf::HttpPaginationLinkHeader {}
+    }
+}
+
+impl<'fb> FlatbuffersSerializable<'fb> for odf::HttpPaginationBodyCursor {
+    type OffsetT = WIPOffset<fb::HttpPaginationBodyCursor<'fb>>;
+
+    fn serialize(&self, fb: &mut FlatBufferBuilder<'fb>) -> Self::OffsetT {
+        let cursor_path_offset = { fb.create_string(&self.cursor_path) };
+        let cursor_param_offset = { fb.create_string(&self.cursor_param) };
+        let mut builder = fb::HttpPaginationBodyCursorBuilder::new(fb);
+        builder.add_cursor_path(cursor_path_offset);
+        builder.add_cursor_param(cursor_param_offset);
+        builder.finish()
+    }
+}
+
+impl<'fb> FlatbuffersDeserializable<fb::HttpPaginationBodyCursor<'fb>>
+    for odf::HttpPaginationBodyCursor
+{
+    fn deserialize(proxy: fb::HttpPaginationBodyCursor<'fb>) -> Self {
+        odf::HttpPaginationBodyCursor {
+            cursor_path: proxy.cursor_path().map(|v| v.to_owned()).unwrap(),
+            cursor_param: proxy.cursor_param().map(|v| v.to_owned()).unwrap(),
+        }
+    }
+}
+
+impl<'fb> FlatbuffersSerializable<'fb> for odf::HttpPaginationOffsetLimit {
+    type OffsetT = WIPOffset<fb::HttpPaginationOffsetLimit<'fb>>;
+
+    fn serialize(&self, fb: &mut FlatBufferBuilder<'fb>) -> Self::OffsetT {
+        let offset_param_offset = { fb.create_string(&self.offset_param) };
+        let limit_param_offset = { fb.create_string(&self.limit_param) };
+        let mut builder = fb::HttpPaginationOffsetLimitBuilder::new(fb);
+        builder.add_offset_param(offset_param_offset);
+        builder.add_limit_param(limit_param_offset);
+        builder.add_page_size(self.page_size);
+        builder.finish()
+    }
+}
+
+impl<'fb> FlatbuffersDeserializable<fb::HttpPaginationOffsetLimit<'fb>>
+    for odf::HttpPaginationOffsetLimit
+{
+    fn deserialize(proxy: fb::HttpPaginationOffsetLimit<'fb>) -> Self {
+        odf::HttpPaginationOffsetLimit {
+            offset_param: proxy.offset_param().map(|v| v.to_owned()).unwrap(),
+            limit_param: proxy.limit_param().map(|v| v.to_owned()).unwrap(),
+            page_size: proxy.page_size(),
+        }
+    }
+}
+
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
 // MergeStrategy
 // https://github.com/kamu-data/open-data-fabric/blob/master/open-data-fabric.md#mergestrategy-schema
@@ -1392,6 +1701,11 @@ impl From<odf::CompressionFormat> for fb::CompressionFormat {
         match v {
             odf::CompressionFormat::Gzip => fb::CompressionFormat::Gzip,
             odf::CompressionFormat::Zip => fb::CompressionFormat::Zip,
+            odf::CompressionFormat::Zstd => fb::CompressionFormat::Zstd,
+            odf::CompressionFormat::Xz => fb::CompressionFormat::Xz,
+            odf::CompressionFormat::Bzip2 => fb::CompressionFormat::Bzip2,
+            odf::CompressionFormat::Tar => fb::CompressionFormat::Tar,
+            odf::CompressionFormat::Auto => fb::CompressionFormat::Auto,
         }
     }
 }
@@ -1401,6 +1715,11 @@ impl Into<odf::CompressionFormat> for fb::CompressionFormat {
         match self {
             fb::CompressionFormat::Gzip => odf::CompressionFormat::Gzip,
             fb::CompressionFormat::Zip => odf::CompressionFormat::Zip,
+            fb::CompressionFormat::Zstd => odf::CompressionFormat::Zstd,
+            fb::CompressionFormat::Xz => odf::CompressionFormat::Xz,
+            fb::CompressionFormat::Bzip2 => odf::CompressionFormat::Bzip2,
+            fb::CompressionFormat::Tar => odf::CompressionFormat::Tar,
+            fb::CompressionFormat::Auto => odf::CompressionFormat::Auto,
             _ => panic!("Invalid enum value: {}", self.0),
         }
     }
@@ -816,6 +816,110 @@ impl flatbuffers::SimpleToVerifyInSlice for MqttQos {}
     since = "2.0.0",
     note = "Use associated constants instead. This will no longer be generated in 2021."
 )]
+pub const ENUM_MIN_HTTP_PAGINATION: u8 = 0;
+#[deprecated(
+    since = "2.0.0",
+    note = "Use associated constants instead. This will no longer be generated in 2021."
+)]
+pub const ENUM_MAX_HTTP_PAGINATION: u8 = 3;
+#[deprecated(
+    since = "2.0.0",
+    note = "Use associated constants instead. This will no longer be generated in 2021."
+)]
+#[allow(non_camel_case_types)]
+pub const ENUM_VALUES_HTTP_PAGINATION: [HttpPagination; 4] = [
+    HttpPagination::NONE,
+    HttpPagination::HttpPaginationLinkHeader,
+    HttpPagination::HttpPaginationBodyCursor,
+    HttpPagination::HttpPaginationOffsetLimit,
+];
+
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+#[repr(transparent)]
+pub struct HttpPagination(pub u8);
+#[allow(non_upper_case_globals)]
+impl HttpPagination {
+    pub const NONE: Self = Self(0);
+    pub const HttpPaginationLinkHeader: Self = Self(1);
+    pub const HttpPaginationBodyCursor: Self = Self(2);
+    pub const HttpPaginationOffsetLimit: Self = Self(3);
+
+    pub const ENUM_MIN: u8 = 0;
+    pub const ENUM_MAX: u8 = 3;
+    pub const ENUM_VALUES: &'static [Self] = &[
+        Self::NONE,
+        Self::HttpPaginationLinkHeader,
+        Self::HttpPaginationBodyCursor,
+        Self::HttpPaginationOffsetLimit,
+    ];
+    /// Returns the variant's name or "" if unknown.
+    pub fn variant_name(self) -> Option<&'static str> {
+        match self {
+            Self::NONE => Some("NONE"),
+            Self::HttpPaginationLinkHeader => Some("HttpPaginationLinkHeader"),
+            Self::HttpPaginationBodyCursor => Some("HttpPaginationBodyCursor"),
+            Self::HttpPaginationOffsetLimit => Some("HttpPaginationOffsetLimit"),
+            _ => None,
+        }
+    }
+}
+impl core::fmt::Debug for HttpPagination {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        if let Some(name) = self.variant_name() {
+            f.write_str(name)
+        } else {
+            f.write_fmt(format_args!("<UNKNOWN {:?}>", self.0))
+        }
+    }
+}
+impl<'a> flatbuffers::Follow<'a> for HttpPagination {
+    type Inner = Self;
+    #[inline]
+    unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+        let b = flatbuffers::read_scalar_at::<u8>(buf, loc);
+        Self(b)
+    }
+}
+
+impl flatbuffers::Push for HttpPagination {
+    type Output = HttpPagination;
+    #[inline]
+    unsafe fn push(&self, dst: &mut [u8], _written_len: usize) {
+        flatbuffers::emplace_scalar::<u8>(dst, self.0);
+    }
+}
+
+impl flatbuffers::EndianScalar for HttpPagination {
+    type Scalar = u8;
+    #[inline]
+    fn to_little_endian(self) -> u8 {
+        self.0.to_le()
+    }
+    #[inline]
+    #[allow(clippy::wrong_self_convention)]
+    fn from_little_endian(v: u8) -> Self {
+        let b = u8::from_le(v);
+        Self(b)
+    }
+}
+
+impl<'a> flatbuffers::Verifiable for HttpPagination {
+    #[inline]
+    fn run_verifier(
+        v: &mut flatbuffers::Verifier,
+        pos: usize,
+    ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+        use self::flatbuffers::Verifiable;
+        u8::run_verifier(v, pos)
+    }
+}
+
+impl flatbuffers::SimpleToVerifyInSlice for HttpPagination {}
+pub struct HttpPaginationUnionTableOffset {}
+#[deprecated(
+    since = "2.0.0",
+    note = "Use associated constants instead. This will no longer be generated in 2021."
+)]
 pub const ENUM_MIN_SOURCE_ORDERING: i32 = 0;
 #[deprecated(
     since = "2.0.0",
@@ -911,19 +1015,23 @@ pub const ENUM_MIN_FETCH_STEP: u8 = 0;
     since = "2.0.0",
     note = "Use associated constants instead. This will no longer be generated in 2021."
 )]
-pub const ENUM_MAX_FETCH_STEP: u8 = 5;
+pub const ENUM_MAX_FETCH_STEP: u8 = 9;
 #[deprecated(
     since = "2.0.0",
     note = "Use associated constants instead. This will no longer be generated in 2021."
 )]
 #[allow(non_camel_case_types)]
-pub const ENUM_VALUES_FETCH_STEP: [FetchStep; 6] = [
+pub const ENUM_VALUES_FETCH_STEP: [FetchStep; 10] = [
     FetchStep::NONE,
     FetchStep::FetchStepUrl,
     FetchStep::FetchStepFilesGlob,
     FetchStep::FetchStepContainer,
     FetchStep::FetchStepMqtt,
     FetchStep::FetchStepEthereumLogs,
+    FetchStep::FetchStepSql,
+    FetchStep::FetchStepHttpPaginated,
+    FetchStep::FetchStepEthereumBlocks,
+    FetchStep::FetchStepEthereumTransactions,
 ];
 
 #[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
@@ -937,9 +1045,13 @@ impl FetchStep {
     pub const FetchStepContainer: Self = Self(3);
     pub const FetchStepMqtt: Self = Self(4);
     pub const FetchStepEthereumLogs: Self = Self(5);
+    pub const FetchStepSql: Self = Self(6);
+    pub const FetchStepHttpPaginated: Self = Self(7);
+    pub const FetchStepEthereumBlocks: Self = Self(8);
+    pub const FetchStepEthereumTransactions: Self = Self(9);
 
     pub const ENUM_MIN: u8 = 0;
-    pub const ENUM_MAX: u8 = 5;
+    pub const ENUM_MAX: u8 = 9;
     pub const ENUM_VALUES: &'static [Self] = &[
         Self::NONE,
         Self::FetchStepUrl,
@@ -947,6 +1059,10 @@ impl FetchStep {
         Self::FetchStepContainer,
         Self::FetchStepMqtt,
         Self::FetchStepEthereumLogs,
+        Self::FetchStepSql,
+        Self::FetchStepHttpPaginated,
+        Self::FetchStepEthereumBlocks,
+        Self::FetchStepEthereumTransactions,
     ];
     /// Returns the variant's name or "" if unknown.
     pub fn variant_name(self) -> Option<&'static str> {
@@ -957,6 +1073,10 @@ impl FetchStep {
             Self::FetchStepContainer => Some("FetchStepContainer"),
             Self::FetchStepMqtt => Some("FetchStepMqtt"),
             Self::FetchStepEthereumLogs => Some("FetchStepEthereumLogs"),
+            Self::FetchStepSql => Some("FetchStepSql"),
+            Self::FetchStepHttpPaginated => Some("FetchStepHttpPaginated"),
+            Self::FetchStepEthereumBlocks => Some("FetchStepEthereumBlocks"),
+            Self::FetchStepEthereumTransactions => Some("FetchStepEthereumTransactions"),
             _ => None,
         }
     }
@@ -1024,14 +1144,21 @@ pub const ENUM_MIN_COMPRESSION_FORMAT: i32 = 0;
     since = "2.0.0",
     note = "Use associated constants instead. This will no longer be generated in 2021."
 )]
-pub const ENUM_MAX_COMPRESSION_FORMAT: i32 = 1;
+pub const ENUM_MAX_COMPRESSION_FORMAT: i32 = 6;
 #[deprecated(
     since = "2.0.0",
     note = "Use associated constants instead. This will no longer be generated in 2021."
 )]
 #[allow(non_camel_case_types)]
-pub const ENUM_VALUES_COMPRESSION_FORMAT: [CompressionFormat; 2] =
-    [CompressionFormat::Gzip, CompressionFormat::Zip];
+pub const ENUM_VALUES_COMPRESSION_FORMAT: [CompressionFormat; 7] = [
+    CompressionFormat::Gzip,
+    CompressionFormat::Zip,
+    CompressionFormat::Zstd,
+    CompressionFormat::Xz,
+    CompressionFormat::Bzip2,
+    CompressionFormat::Tar,
+    CompressionFormat::Auto,
+];
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
 #[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
@@ -1041,15 +1168,33 @@ pub struct CompressionFormat(pub i32);
 impl CompressionFormat {
     pub const Gzip: Self = Self(0);
     pub const Zip: Self = Self(1);
+    pub const Zstd: Self = Self(2);
+    pub const Xz: Self = Self(3);
+    pub const Bzip2: Self = Self(4);
+    pub const Tar: Self = Self(5);
+    pub const Auto: Self = Self(6);
 
     pub const ENUM_MIN: i32 = 0;
-    pub const ENUM_MAX: i32 = 1;
-    pub const ENUM_VALUES: &'static [Self] = &[Self::Gzip, Self::Zip];
+    pub const ENUM_MAX: i32 = 6;
+    pub const ENUM_VALUES: &'static [Self] = &[
+        Self::Gzip,
+        Self::Zip,
+        Self::Zstd,
+        Self::Xz,
+        Self::Bzip2,
+        Self::Tar,
+        Self::Auto,
+    ];
     /// Returns the variant's name or "" if unknown.
     pub fn variant_name(self) -> Option<&'static str> {
         match self {
             Self::Gzip => Some("Gzip"),
             Self::Zip => Some("Zip"),
+            Self::Zstd => Some("Zstd"),
+            Self::Xz => Some("Xz"),
+            Self::Bzip2 => Some("Bzip2"),
+            Self::Tar => Some("Tar"),
+            Self::Auto => Some("Auto"),
             _ => None,
         }
     }
@@ -6973,16 +7118,16 @@ impl core::fmt::Debug for MqttTopicSubscription<'_> {
         ds.finish()
     }
 }
-pub enum FetchStepUrlOffset {}
+pub enum HttpPaginationLinkHeaderOffset {}
 #[derive(Copy, Clone, PartialEq)]
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
-pub struct FetchStepUrl<'a> {
+pub struct HttpPaginationLinkHeader<'a> {
     pub _tab: flatbuffers::Table<'a>,
 }
 
-impl<'a> flatbuffers::Follow<'a> for FetchStepUrl<'a> {
-    type Inner = FetchStepUrl<'a>;
+impl<'a> flatbuffers::Follow<'a> for HttpPaginationLinkHeader<'a> {
+    type Inner = HttpPaginationLinkHeader<'a>;
     #[inline]
     unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
         Self {
@@ -6991,181 +7136,294 @@ impl<'a> flatbuffers::Follow<'a> for FetchStepUrl<'a> {
     }
 }
 
-impl<'a> FetchStepUrl<'a> {
-    pub const VT_URL: flatbuffers::VOffsetT = 4;
-    pub const VT_EVENT_TIME_TYPE: flatbuffers::VOffsetT = 6;
-    pub const VT_EVENT_TIME: flatbuffers::VOffsetT = 8;
-    pub const VT_CACHE_TYPE: flatbuffers::VOffsetT = 10;
-    pub const VT_CACHE: flatbuffers::VOffsetT = 12;
-    pub const VT_HEADERS: flatbuffers::VOffsetT = 14;
-
+impl<'a> HttpPaginationLinkHeader<'a> {
     #[inline]
     pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
-        FetchStepUrl { _tab: table }
+        HttpPaginationLinkHeader { _tab: table }
     }
     #[allow(unused_mut)]
     pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
         _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
-        args: &'args FetchStepUrlArgs<'args>,
-    ) -> flatbuffers::WIPOffset<FetchStepUrl<'bldr>> {
-        let mut builder = FetchStepUrlBuilder::new(_fbb);
-        if let Some(x) = args.headers {
-            builder.add_headers(x);
-        }
-        if let Some(x) = args.cache {
-            builder.add_cache(x);
-        }
-        if let Some(x) = args.event_time {
-            builder.add_event_time(x);
-        }
-        if let Some(x) = args.url {
-            builder.add_url(x);
-        }
-        builder.add_cache_type(args.cache_type);
-        builder.add_event_time_type(args.event_time_type);
+        _args: &'args HttpPaginationLinkHeaderArgs,
+    ) -> flatbuffers::WIPOffset<HttpPaginationLinkHeader<'bldr>> {
+        let mut builder = HttpPaginationLinkHeaderBuilder::new(_fbb);
         builder.finish()
     }
+}
 
+impl flatbuffers::Verifiable for HttpPaginationLinkHeader<'_> {
     #[inline]
-    pub fn url(&self) -> Option<&'a str> {
-        // Safety:
-        // Created from valid Table for this object
-        // which contains a valid value in this slot
-        unsafe {
-            self._tab
-                .get::<flatbuffers::ForwardsUOffset<&str>>(FetchStepUrl::VT_URL, None)
-        }
+    fn run_verifier(
+        v: &mut flatbuffers::Verifier,
+        pos: usize,
+    ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+        use self::flatbuffers::Verifiable;
+        v.visit_table(pos)?.finish();
+        Ok(())
     }
+}
+pub struct HttpPaginationLinkHeaderArgs {}
+impl<'a> Default for HttpPaginationLinkHeaderArgs {
     #[inline]
-    pub fn event_time_type(&self) -> EventTimeSource {
-        // Safety:
-        // Created from valid Table for this object
-        // which contains a valid value in this slot
-        unsafe {
-            self._tab
-                .get::<EventTimeSource>(
-                    FetchStepUrl::VT_EVENT_TIME_TYPE,
-                    Some(EventTimeSource::NONE),
-                )
-                .unwrap()
+    fn default() -> Self {
+        HttpPaginationLinkHeaderArgs {}
+    }
+}
+
+pub struct HttpPaginationLinkHeaderBuilder<'a: 'b, 'b> {
+    fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+    start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b> HttpPaginationLinkHeaderBuilder<'a, 'b> {
+    #[inline]
+    pub fn new(
+        _fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+    ) -> HttpPaginationLinkHeaderBuilder<'a, 'b> {
+        let start = _fbb.start_table();
+        HttpPaginationLinkHeaderBuilder {
+            fbb_: _fbb,
+            start_: start,
         }
     }
     #[inline]
-    pub fn event_time(&self) -> Option<flatbuffers::Table<'a>> {
-        // Safety:
-        // Created from valid Table for this object
-        // which contains a valid value in this slot
-        unsafe {
-            self._tab
-                .get::<flatbuffers::ForwardsUOffset<flatbuffers::Table<'a>>>(
-                    FetchStepUrl::VT_EVENT_TIME,
-                    None,
-                )
+    pub fn finish(self) -> flatbuffers::WIPOffset<HttpPaginationLinkHeader<'a>> {
+        let o = self.fbb_.end_table(self.start_);
+        flatbuffers::WIPOffset::new(o.value())
+    }
+}
+
+impl core::fmt::Debug for HttpPaginationLinkHeader<'_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let mut ds = f.debug_struct("HttpPaginationLinkHeader");
+        ds.finish()
+    }
+}
+pub enum HttpPaginationBodyCursorOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct HttpPaginationBodyCursor<'a> {
+    pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for HttpPaginationBodyCursor<'a> {
+    type Inner = HttpPaginationBodyCursor<'a>;
+    #[inline]
+    unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+        Self {
+            _tab: flatbuffers::Table::new(buf, loc),
         }
     }
+}
+
+impl<'a> HttpPaginationBodyCursor<'a> {
+    pub const VT_CURSOR_PATH: flatbuffers::VOffsetT = 4;
+    pub const VT_CURSOR_PARAM: flatbuffers::VOffsetT = 6;
+
     #[inline]
-    pub fn cache_type(&self) -> SourceCaching {
-        // Safety:
-        // Created from valid Table for this object
-        // which contains a valid value in this slot
-        unsafe {
-            self._tab
-                .get::<SourceCaching>(FetchStepUrl::VT_CACHE_TYPE, Some(SourceCaching::NONE))
-                .unwrap()
+    pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+        HttpPaginationBodyCursor { _tab: table }
+    }
+    #[allow(unused_mut)]
+    pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
+        _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
+        args: &'args HttpPaginationBodyCursorArgs<'args>,
+    ) -> flatbuffers::WIPOffset<HttpPaginationBodyCursor<'bldr>> {
+        let mut builder = HttpPaginationBodyCursorBuilder::new(_fbb);
+        if let Some(x) = args.cursor_param {
+            builder.add_cursor_param(x);
+        }
+        if let Some(x) = args.cursor_path {
+            builder.add_cursor_path(x);
         }
+        builder.finish()
     }
+
     #[inline]
-    pub fn cache(&self) -> Option<flatbuffers::Table<'a>> {
+    pub fn cursor_path(&self) -> Option<&'a str> {
         // Safety:
         // Created from valid Table for this object
         // which contains a valid value in this slot
         unsafe {
-            self._tab
-                .get::<flatbuffers::ForwardsUOffset<flatbuffers::Table<'a>>>(
-                    FetchStepUrl::VT_CACHE,
-                    None,
-                )
+            self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(
+                HttpPaginationBodyCursor::VT_CURSOR_PATH,
+                None,
+            )
         }
     }
     #[inline]
-    pub fn headers(
-        &self,
-    ) -> Option<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<RequestHeader<'a>>>> {
+    pub fn cursor_param(&self) -> Option<&'a str> {
         // Safety:
         // Created from valid Table for this object
         // which contains a valid value in this slot
         unsafe {
-            self._tab.get::<flatbuffers::ForwardsUOffset<
-                flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<RequestHeader>>,
-            >>(FetchStepUrl::VT_HEADERS, None)
+            self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(
+                HttpPaginationBodyCursor::VT_CURSOR_PARAM,
+                None,
+            )
         }
     }
+}
+
+impl flatbuffers::Verifiable for HttpPaginationBodyCursor<'_> {
     #[inline]
-    #[allow(non_snake_case)]
-    pub fn event_time_as_event_time_source_from_metadata(
-        &self,
-    ) -> Option<EventTimeSourceFromMetadata<'a>> {
-        if self.event_time_type() == EventTimeSource::EventTimeSourceFromMetadata {
-            self.event_time().map(|t| {
-                // Safety:
-                // Created from a valid Table for this object
-                // Which contains a valid union in this slot
-                unsafe { EventTimeSourceFromMetadata::init_from_table(t) }
-            })
-        } else {
-            None
+    fn run_verifier(
+        v: &mut flatbuffers::Verifier,
+        pos: usize,
+    ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+        use self::flatbuffers::Verifiable;
+        v.visit_table(pos)?
+            .visit_field::<flatbuffers::ForwardsUOffset<&str>>(
+                "cursor_path",
+                Self::VT_CURSOR_PATH,
+                false,
+            )?
+            .visit_field::<flatbuffers::ForwardsUOffset<&str>>(
+                "cursor_param",
+                Self::VT_CURSOR_PARAM,
+                false,
+            )?
+            .finish();
+        Ok(())
+    }
+}
+pub struct HttpPaginationBodyCursorArgs<'a> {
+    pub cursor_path: Option<flatbuffers::WIPOffset<&'a str>>,
+    pub cursor_param: Option<flatbuffers::WIPOffset<&'a str>>,
+}
+impl<'a> Default for HttpPaginationBodyCursorArgs<'a> {
+    #[inline]
+    fn default() -> Self {
+        HttpPaginationBodyCursorArgs {
+            cursor_path: None,
+            cursor_param: None,
         }
     }
+}
 
+pub struct HttpPaginationBodyCursorBuilder<'a: 'b, 'b> {
+    fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+    start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b> HttpPaginationBodyCursorBuilder<'a, 'b> {
     #[inline]
-    #[allow(non_snake_case)]
-    pub fn event_time_as_event_time_source_from_path(&self) -> Option<EventTimeSourceFromPath<'a>> {
-        if self.event_time_type() == EventTimeSource::EventTimeSourceFromPath {
-            self.event_time().map(|t| {
-                // Safety:
-                // Created from a valid Table for this object
-                // Which contains a valid union in this slot
-                unsafe { EventTimeSourceFromPath::init_from_table(t) }
-            })
-        } else {
-            None
+    pub fn add_cursor_path(&mut self, cursor_path: flatbuffers::WIPOffset<&'b str>) {
+        self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(
+            HttpPaginationBodyCursor::VT_CURSOR_PATH,
+            cursor_path,
+        );
+    }
+    #[inline]
+    pub fn add_cursor_param(&mut self, cursor_param: flatbuffers::WIPOffset<&'b str>) {
+        self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(
+            HttpPaginationBodyCursor::VT_CURSOR_PARAM,
+            cursor_param,
+        );
+    }
+    #[inline]
+    pub fn new(
+        _fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+    ) -> HttpPaginationBodyCursorBuilder<'a, 'b> {
+        let start = _fbb.start_table();
+        HttpPaginationBodyCursorBuilder {
+            fbb_: _fbb,
+            start_: start,
         }
     }
+    #[inline]
+    pub fn finish(self) -> flatbuffers::WIPOffset<HttpPaginationBodyCursor<'a>> {
+        let o = self.fbb_.end_table(self.start_);
+        flatbuffers::WIPOffset::new(o.value())
+    }
+}
+
+impl core::fmt::Debug for HttpPaginationBodyCursor<'_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let mut ds = f.debug_struct("HttpPaginationBodyCursor");
+        ds.field("cursor_path", &self.cursor_path());
+        ds.field("cursor_param", &self.cursor_param());
+        ds.finish()
+    }
+}
+pub enum HttpPaginationOffsetLimitOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct HttpPaginationOffsetLimit<'a> {
+    pub _tab: flatbuffers::Table<'a>,
+}
 
+impl<'a> flatbuffers::Follow<'a> for HttpPaginationOffsetLimit<'a> {
+    type Inner = HttpPaginationOffsetLimit<'a>;
     #[inline]
-    #[allow(non_snake_case)]
-    pub fn event_time_as_event_time_source_from_system_time(
-        &self,
-    ) -> Option<EventTimeSourceFromSystemTime<'a>> {
-        if self.event_time_type() == EventTimeSource::EventTimeSourceFromSystemTime {
-            self.event_time().map(|t| {
-                // Safety:
-                // Created from a valid Table for this object
-                // Which contains a valid union in this slot
-                unsafe { EventTimeSourceFromSystemTime::init_from_table(t) }
-            })
-        } else {
-            None
+    unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+        Self {
+            _tab: flatbuffers::Table::new(buf, loc),
         }
     }
+}
+
+impl<'a> HttpPaginationOffsetLimit<'a> {
+    pub const VT_OFFSET_PARAM: flatbuffers::VOffsetT = 4;
+    pub const VT_LIMIT_PARAM: flatbuffers::VOffsetT = 6;
+    pub const VT_PAGE_SIZE: flatbuffers::VOffsetT = 8;
 
     #[inline]
-    #[allow(non_snake_case)]
-    pub fn cache_as_source_caching_forever(&self) -> Option<SourceCachingForever<'a>> {
-        if self.cache_type() == SourceCaching::SourceCachingForever {
-            self.cache().map(|t| {
-                // Safety:
-                // Created from a valid Table for this object
-                // Which contains a valid union in this slot
-                unsafe { SourceCachingForever::init_from_table(t) }
-            })
-        } else {
-            None
+    pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+        HttpPaginationOffsetLimit { _tab: table }
+    }
+    #[allow(unused_mut)]
+    pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
+        _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
+        args: &'args HttpPaginationOffsetLimitArgs<'args>,
+    ) -> flatbuffers::WIPOffset<HttpPaginationOffsetLimit<'bldr>> {
+        let mut builder = HttpPaginationOffsetLimitBuilder::new(_fbb);
+        builder.add_page_size(args.page_size);
+        if let Some(x) = args.limit_param {
+            builder.add_limit_param(x);
+        }
+        if let Some(x) = args.offset_param {
+            builder.add_offset_param(x);
+        }
+        builder.finish()
+    }
+
+    #[inline]
+    pub fn offset_param(&self) -> Option<&'a str> {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(
+                HttpPaginationOffsetLimit::VT_OFFSET_PARAM,
+                None,
+            )
+        }
+    }
+    #[inline]
+    pub fn limit_param(&self) -> Option<&'a str> {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(
+                HttpPaginationOffsetLimit::VT_LIMIT_PARAM,
+                None,
+            )
+        }
+    }
+    #[inline]
+    pub fn page_size(&self) -> u64 {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab
+                .get::<u64>(HttpPaginationOffsetLimit::VT_PAGE_SIZE, Some(0))
+                .unwrap()
         }
     }
 }
 
-impl flatbuffers::Verifiable for FetchStepUrl<'_> {
+impl flatbuffers::Verifiable for HttpPaginationOffsetLimit<'_> {
     #[inline]
     fn run_verifier(
         v: &mut flatbuffers::Verifier,
@@ -7173,187 +7431,97 @@ impl flatbuffers::Verifiable for FetchStepUrl<'_> {
     ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
         use self::flatbuffers::Verifiable;
         v.visit_table(pos)?
-     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("url", Self::VT_URL, false)?
-     .visit_union::<EventTimeSource, _>("event_time_type", Self::VT_EVENT_TIME_TYPE, "event_time", Self::VT_EVENT_TIME, false, |key, v, pos| {
-        match key {
-          EventTimeSource::EventTimeSourceFromMetadata => v.verify_union_variant::<flatbuffers::ForwardsUOffset<EventTimeSourceFromMetadata>>("EventTimeSource::EventTimeSourceFromMetadata", pos),
-          EventTimeSource::EventTimeSourceFromPath => v.verify_union_variant::<flatbuffers::ForwardsUOffset<EventTimeSourceFromPath>>("EventTimeSource::EventTimeSourceFromPath", pos),
-          EventTimeSource::EventTimeSourceFromSystemTime => v.verify_union_variant::<flatbuffers::ForwardsUOffset<EventTimeSourceFromSystemTime>>("EventTimeSource::EventTimeSourceFromSystemTime", pos),
-          _ => Ok(()),
-        }
-     })?
-     .visit_union::<SourceCaching, _>("cache_type", Self::VT_CACHE_TYPE, "cache", Self::VT_CACHE, false, |key, v, pos| {
-        match key {
-          SourceCaching::SourceCachingForever => v.verify_union_variant::<flatbuffers::ForwardsUOffset<SourceCachingForever>>("SourceCaching::SourceCachingForever", pos),
-          _ => Ok(()),
-        }
-     })?
-     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<RequestHeader>>>>("headers", Self::VT_HEADERS, false)?
-     .finish();
+            .visit_field::<flatbuffers::ForwardsUOffset<&str>>(
+                "offset_param",
+                Self::VT_OFFSET_PARAM,
+                false,
+            )?
+            .visit_field::<flatbuffers::ForwardsUOffset<&str>>(
+                "limit_param",
+                Self::VT_LIMIT_PARAM,
+                false,
+            )?
+            .visit_field::<u64>("page_size", Self::VT_PAGE_SIZE, false)?
+            .finish();
         Ok(())
     }
 }
-pub struct FetchStepUrlArgs<'a> {
-    pub url: Option<flatbuffers::WIPOffset<&'a str>>,
-    pub event_time_type: EventTimeSource,
-    pub event_time: Option<flatbuffers::WIPOffset<flatbuffers::UnionWIPOffset>>,
-    pub cache_type: SourceCaching,
-    pub cache: Option<flatbuffers::WIPOffset<flatbuffers::UnionWIPOffset>>,
-    pub headers: Option<
-        flatbuffers::WIPOffset<
-            flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<RequestHeader<'a>>>,
-        >,
-    >,
+pub struct HttpPaginationOffsetLimitArgs<'a> {
+    pub offset_param: Option<flatbuffers::WIPOffset<&'a str>>,
+    pub limit_param: Option<flatbuffers::WIPOffset<&'a str>>,
+    pub page_size: u64,
 }
-impl<'a> Default for FetchStepUrlArgs<'a> {
+impl<'a> Default for HttpPaginationOffsetLimitArgs<'a> {
     #[inline]
     fn default() -> Self {
-        FetchStepUrlArgs {
-            url: None,
-            event_time_type: EventTimeSource::NONE,
-            event_time: None,
-            cache_type: SourceCaching::NONE,
-            cache: None,
-            headers: None,
+        HttpPaginationOffsetLimitArgs {
+            offset_param: None,
+            limit_param: None,
+            page_size: 0,
         }
     }
 }
 
-pub struct FetchStepUrlBuilder<'a: 'b, 'b> {
+pub struct HttpPaginationOffsetLimitBuilder<'a: 'b, 'b> {
     fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
     start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
 }
-impl<'a: 'b, 'b> FetchStepUrlBuilder<'a, 'b> {
-    #[inline]
-    pub fn add_url(&mut self, url: flatbuffers::WIPOffset<&'b str>) {
-        self.fbb_
-            .push_slot_always::<flatbuffers::WIPOffset<_>>(FetchStepUrl::VT_URL, url);
-    }
+impl<'a: 'b, 'b> HttpPaginationOffsetLimitBuilder<'a, 'b> {
     #[inline]
-    pub fn add_event_time_type(&mut self, event_time_type: EventTimeSource) {
-        self.fbb_.push_slot::<EventTimeSource>(
-            FetchStepUrl::VT_EVENT_TIME_TYPE,
-            event_time_type,
-            EventTimeSource::NONE,
+    pub fn add_offset_param(&mut self, offset_param: flatbuffers::WIPOffset<&'b str>) {
+        self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(
+            HttpPaginationOffsetLimit::VT_OFFSET_PARAM,
+            offset_param,
         );
     }
     #[inline]
-    pub fn add_event_time(
-        &mut self,
-        event_time: flatbuffers::WIPOffset<flatbuffers::UnionWIPOffset>,
-    ) {
-        self.fbb_
-            .push_slot_always::<flatbuffers::WIPOffset<_>>(FetchStepUrl::VT_EVENT_TIME, event_time);
-    }
-    #[inline]
-    pub fn add_cache_type(&mut self, cache_type: SourceCaching) {
-        self.fbb_.push_slot::<SourceCaching>(
-            FetchStepUrl::VT_CACHE_TYPE,
-            cache_type,
-            SourceCaching::NONE,
+    pub fn add_limit_param(&mut self, limit_param: flatbuffers::WIPOffset<&'b str>) {
+        self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(
+            HttpPaginationOffsetLimit::VT_LIMIT_PARAM,
+            limit_param,
         );
     }
     #[inline]
-    pub fn add_cache(&mut self, cache: flatbuffers::WIPOffset<flatbuffers::UnionWIPOffset>) {
-        self.fbb_
-            .push_slot_always::<flatbuffers::WIPOffset<_>>(FetchStepUrl::VT_CACHE, cache);
-    }
-    #[inline]
-    pub fn add_headers(
-        &mut self,
-        headers: flatbuffers::WIPOffset<
-            flatbuffers::Vector<'b, flatbuffers::ForwardsUOffset<RequestHeader<'b>>>,
-        >,
-    ) {
+    pub fn add_page_size(&mut self, page_size: u64) {
         self.fbb_
-            .push_slot_always::<flatbuffers::WIPOffset<_>>(FetchStepUrl::VT_HEADERS, headers);
+            .push_slot::<u64>(HttpPaginationOffsetLimit::VT_PAGE_SIZE, page_size, 0);
     }
     #[inline]
-    pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> FetchStepUrlBuilder<'a, 'b> {
+    pub fn new(
+        _fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+    ) -> HttpPaginationOffsetLimitBuilder<'a, 'b> {
         let start = _fbb.start_table();
-        FetchStepUrlBuilder {
+        HttpPaginationOffsetLimitBuilder {
             fbb_: _fbb,
             start_: start,
         }
     }
     #[inline]
-    pub fn finish(self) -> flatbuffers::WIPOffset<FetchStepUrl<'a>> {
+    pub fn finish(self) -> flatbuffers::WIPOffset<HttpPaginationOffsetLimit<'a>> {
         let o = self.fbb_.end_table(self.start_);
         flatbuffers::WIPOffset::new(o.value())
     }
 }
 
-impl core::fmt::Debug for FetchStepUrl<'_> {
+impl core::fmt::Debug for HttpPaginationOffsetLimit<'_> {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-        let mut ds = f.debug_struct("FetchStepUrl");
-        ds.field("url", &self.url());
-        ds.field("event_time_type", &self.event_time_type());
-        match self.event_time_type() {
-            EventTimeSource::EventTimeSourceFromMetadata => {
-                if let Some(x) = self.event_time_as_event_time_source_from_metadata() {
-                    ds.field("event_time", &x)
-                } else {
-                    ds.field(
-                        "event_time",
-                        &"InvalidFlatbuffer: Union discriminant does not match value.",
-                    )
-                }
-            }
-            EventTimeSource::EventTimeSourceFromPath => {
-                if let Some(x) = self.event_time_as_event_time_source_from_path() {
-                    ds.field("event_time", &x)
-                } else {
-                    ds.field(
-                        "event_time",
-                        &"InvalidFlatbuffer: Union discriminant does not match value.",
-                    )
-                }
-            }
-            EventTimeSource::EventTimeSourceFromSystemTime => {
-                if let Some(x) = self.event_time_as_event_time_source_from_system_time() {
-                    ds.field("event_time", &x)
-                } else {
-                    ds.field(
-                        "event_time",
-                        &"InvalidFlatbuffer: Union discriminant does not match value.",
-                    )
-                }
-            }
-            _ => {
-                let x: Option<()> = None;
-                ds.field("event_time", &x)
-            }
-        };
-        ds.field("cache_type", &self.cache_type());
-        match self.cache_type() {
-            SourceCaching::SourceCachingForever => {
-                if let Some(x) = self.cache_as_source_caching_forever() {
-                    ds.field("cache", &x)
-                } else {
-                    ds.field(
-                        "cache",
-                        &"InvalidFlatbuffer: Union discriminant does not match value.",
-                    )
-                }
-            }
-            _ => {
-                let x: Option<()> = None;
-                ds.field("cache", &x)
-            }
-        };
-        ds.field("headers", &self.headers());
+        let mut ds = f.debug_struct("HttpPaginationOffsetLimit");
+        ds.field("offset_param", &self.offset_param());
+        ds.field("limit_param", &self.limit_param());
+        ds.field("page_size", &self.page_size());
         ds.finish()
     }
 }
-pub enum FetchStepFilesGlobOffset {}
+pub enum HttpHighWaterMarkOffset {}
 #[derive(Copy, Clone, PartialEq)]
 
-pub struct FetchStepFilesGlob<'a> {
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+pub struct HttpHighWaterMark<'a> {
     pub _tab: flatbuffers::Table<'a>,
 }
 
-impl<'a> flatbuffers::Follow<'a> for FetchStepFilesGlob<'a> {
-    type Inner = FetchStepFilesGlob<'a>;
+impl<'a> flatbuffers::Follow<'a> for HttpHighWaterMark<'a> {
+    type Inner = HttpHighWaterMark<'a>;
     #[inline]
     unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
         Self {
@@ -7362,26 +7530,157 @@ impl<'a> flatbuffers::Follow<'a> for FetchStepFilesGlob<'a> {
     }
 }
 
-impl<'a> FetchStepFilesGlob<'a> {
-    pub const VT_PATH: flatbuffers::VOffsetT = 4;
+impl<'a> HttpHighWaterMark<'a> {
+    pub const VT_FIELD: flatbuffers::VOffsetT = 4;
+    pub const VT_PARAM: flatbuffers::VOffsetT = 6;
+
+    #[inline]
+    pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+        HttpHighWaterMark { _tab: table }
+    }
+    #[allow(unused_mut)]
+    pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
+        _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
+        args: &'args HttpHighWaterMarkArgs<'args>,
+    ) -> flatbuffers::WIPOffset<HttpHighWaterMark<'bldr>> {
+        let mut builder = HttpHighWaterMarkBuilder::new(_fbb);
+        if let Some(x) = args.param {
+            builder.add_param(x);
+        }
+        if let Some(x) = args.field {
+            builder.add_field(x);
+        }
+        builder.finish()
+    }
+
+    #[inline]
+    pub fn field(&self) -> Option<&'a str> {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab
+                .get::<flatbuffers::ForwardsUOffset<&str>>(HttpHighWaterMark::VT_FIELD, None)
+        }
+    }
+    #[inline]
+    pub fn param(&self) -> Option<&'a str> {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab
+                .get::<flatbuffers::ForwardsUOffset<&str>>(HttpHighWaterMark::VT_PARAM, None)
+        }
+    }
+}
+
+impl flatbuffers::Verifiable for HttpHighWaterMark<'_> {
+    #[inline]
+    fn run_verifier(
+        v: &mut flatbuffers::Verifier,
+        pos: usize,
+    ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+        use self::flatbuffers::Verifiable;
+        v.visit_table(pos)?
+            .visit_field::<flatbuffers::ForwardsUOffset<&str>>("field", Self::VT_FIELD, false)?
+            .visit_field::<flatbuffers::ForwardsUOffset<&str>>("param", Self::VT_PARAM, false)?
+            .finish();
+        Ok(())
+    }
+}
+pub struct HttpHighWaterMarkArgs<'a> {
+    pub field: Option<flatbuffers::WIPOffset<&'a str>>,
+    pub param: Option<flatbuffers::WIPOffset<&'a str>>,
+}
+impl<'a> Default for HttpHighWaterMarkArgs<'a> {
+    #[inline]
+    fn default() -> Self {
+        HttpHighWaterMarkArgs {
+            field: None,
+            param: None,
+        }
+    }
+}
+
+pub struct HttpHighWaterMarkBuilder<'a: 'b, 'b> {
+    fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+    start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b> HttpHighWaterMarkBuilder<'a, 'b> {
+    #[inline]
+    pub fn add_field(&mut self, field: flatbuffers::WIPOffset<&'b str>) {
+        self.fbb_
+            .push_slot_always::<flatbuffers::WIPOffset<_>>(HttpHighWaterMark::VT_FIELD, field);
+    }
+    #[inline]
+    pub fn add_param(&mut self, param: flatbuffers::WIPOffset<&'b str>) {
+        self.fbb_
+            .push_slot_always::<flatbuffers::WIPOffset<_>>(HttpHighWaterMark::VT_PARAM, param);
+    }
+    #[inline]
+    pub fn new(
+        _fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+    ) -> HttpHighWaterMarkBuilder<'a, 'b> {
+        let start = _fbb.start_table();
+        HttpHighWaterMarkBuilder {
+            fbb_: _fbb,
+            start_: start,
+        }
+    }
+    #[inline]
+    pub fn finish(self) -> flatbuffers::WIPOffset<HttpHighWaterMark<'a>> {
+        let o = self.fbb_.end_table(self.start_);
+        flatbuffers::WIPOffset::new(o.value())
+    }
+}
+
+impl core::fmt::Debug for HttpHighWaterMark<'_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let mut ds = f.debug_struct("HttpHighWaterMark");
+        ds.field("field", &self.field());
+        ds.field("param", &self.param());
+        ds.finish()
+    }
+}
+pub enum FetchStepUrlOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+pub struct FetchStepUrl<'a> {
+    pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for FetchStepUrl<'a> {
+    type Inner = FetchStepUrl<'a>;
+    #[inline]
+    unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+        Self {
+            _tab: flatbuffers::Table::new(buf, loc),
+        }
+    }
+}
+
+impl<'a> FetchStepUrl<'a> {
+    pub const VT_URL: flatbuffers::VOffsetT = 4;
     pub const VT_EVENT_TIME_TYPE: flatbuffers::VOffsetT = 6;
     pub const VT_EVENT_TIME: flatbuffers::VOffsetT = 8;
     pub const VT_CACHE_TYPE: flatbuffers::VOffsetT = 10;
     pub const VT_CACHE: flatbuffers::VOffsetT = 12;
-    pub const VT_ORDER: flatbuffers::VOffsetT = 14;
+    pub const VT_HEADERS: flatbuffers::VOffsetT = 14;
 
     #[inline]
     pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
-        FetchStepFilesGlob { _tab: table }
+        FetchStepUrl { _tab: table }
     }
     #[allow(unused_mut)]
     pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
         _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
-        args: &'args FetchStepFilesGlobArgs<'args>,
-    ) -> flatbuffers::WIPOffset<FetchStepFilesGlob<'bldr>> {
-        let mut builder = FetchStepFilesGlobBuilder::new(_fbb);
-        if let Some(x) = args.order {
-            builder.add_order(x);
+        args: &'args FetchStepUrlArgs<'args>,
+    ) -> flatbuffers::WIPOffset<FetchStepUrl<'bldr>> {
+        let mut builder = FetchStepUrlBuilder::new(_fbb);
+        if let Some(x) = args.headers {
+            builder.add_headers(x);
         }
         if let Some(x) = args.cache {
             builder.add_cache(x);
@@ -7389,8 +7688,8 @@ impl<'a> FetchStepFilesGlob<'a> {
         if let Some(x) = args.event_time {
             builder.add_event_time(x);
         }
-        if let Some(x) = args.path {
-            builder.add_path(x);
+        if let Some(x) = args.url {
+            builder.add_url(x);
         }
         builder.add_cache_type(args.cache_type);
         builder.add_event_time_type(args.event_time_type);
@@ -7398,13 +7697,13 @@ impl<'a> FetchStepFilesGlob<'a> {
     }
 
     #[inline]
-    pub fn path(&self) -> Option<&'a str> {
+    pub fn url(&self) -> Option<&'a str> {
         // Safety:
         // Created from valid Table for this object
         // which contains a valid value in this slot
         unsafe {
             self._tab
-                .get::<flatbuffers::ForwardsUOffset<&str>>(FetchStepFilesGlob::VT_PATH, None)
+                .get::<flatbuffers::ForwardsUOffset<&str>>(FetchStepUrl::VT_URL, None)
         }
     }
     #[inline]
@@ -7415,7 +7714,7 @@ impl<'a> FetchStepFilesGlob<'a> {
         unsafe {
             self._tab
                 .get::<EventTimeSource>(
-                    FetchStepFilesGlob::VT_EVENT_TIME_TYPE,
+                    FetchStepUrl::VT_EVENT_TIME_TYPE,
                     Some(EventTimeSource::NONE),
                 )
                 .unwrap()
@@ -7429,7 +7728,7 @@ impl<'a> FetchStepFilesGlob<'a> {
         unsafe {
             self._tab
                 .get::<flatbuffers::ForwardsUOffset<flatbuffers::Table<'a>>>(
-                    FetchStepFilesGlob::VT_EVENT_TIME,
+                    FetchStepUrl::VT_EVENT_TIME,
                     None,
                 )
         }
@@ -7441,7 +7740,7 @@ impl<'a> FetchStepFilesGlob<'a> {
         // which contains a valid value in this slot
         unsafe {
             self._tab
-                .get::<SourceCaching>(FetchStepFilesGlob::VT_CACHE_TYPE, Some(SourceCaching::NONE))
+                .get::<SourceCaching>(FetchStepUrl::VT_CACHE_TYPE, Some(SourceCaching::NONE))
                 .unwrap()
         }
     }
@@ -7453,19 +7752,22 @@ impl<'a> FetchStepFilesGlob<'a> {
         unsafe {
             self._tab
                 .get::<flatbuffers::ForwardsUOffset<flatbuffers::Table<'a>>>(
-                    FetchStepFilesGlob::VT_CACHE,
+                    FetchStepUrl::VT_CACHE,
                     None,
                 )
         }
     }
     #[inline]
-    pub fn order(&self) -> Option<SourceOrdering> {
+    pub fn headers(
+        &self,
+    ) -> Option<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<RequestHeader<'a>>>> {
         // Safety:
         // Created from valid Table for this object
         // which contains a valid value in this slot
         unsafe {
-            self._tab
-                .get::<SourceOrdering>(FetchStepFilesGlob::VT_ORDER, None)
+            self._tab.get::<flatbuffers::ForwardsUOffset<
+                flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<RequestHeader>>,
+            >>(FetchStepUrl::VT_HEADERS, None)
         }
     }
     #[inline]
@@ -7533,7 +7835,7 @@ impl<'a> FetchStepFilesGlob<'a> {
     }
 }
 
-impl flatbuffers::Verifiable for FetchStepFilesGlob<'_> {
+impl flatbuffers::Verifiable for FetchStepUrl<'_> {
     #[inline]
     fn run_verifier(
         v: &mut flatbuffers::Verifier,
@@ -7541,7 +7843,7 @@ impl flatbuffers::Verifiable for FetchStepFilesGlob<'_> {
     ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
         use self::flatbuffers::Verifiable;
         v.visit_table(pos)?
-     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("path", Self::VT_PATH, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("url", Self::VT_URL, false)?
      .visit_union::<EventTimeSource, _>("event_time_type", Self::VT_EVENT_TIME_TYPE, "event_time", Self::VT_EVENT_TIME, false, |key, v, pos| {
         match key {
           EventTimeSource::EventTimeSourceFromMetadata => v.verify_union_variant::<flatbuffers::ForwardsUOffset<EventTimeSourceFromMetadata>>("EventTimeSource::EventTimeSourceFromMetadata", pos),
@@ -7556,47 +7858,51 @@ impl flatbuffers::Verifiable for FetchStepFilesGlob<'_> {
           _ => Ok(()),
         }
      })?
-     .visit_field::<SourceOrdering>("order", Self::VT_ORDER, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<RequestHeader>>>>("headers", Self::VT_HEADERS, false)?
      .finish();
         Ok(())
     }
 }
-pub struct FetchStepFilesGlobArgs<'a> {
-    pub path: Option<flatbuffers::WIPOffset<&'a str>>,
+pub struct FetchStepUrlArgs<'a> {
+    pub url: Option<flatbuffers::WIPOffset<&'a str>>,
     pub event_time_type: EventTimeSource,
     pub event_time: Option<flatbuffers::WIPOffset<flatbuffers::UnionWIPOffset>>,
     pub cache_type: SourceCaching,
     pub cache: Option<flatbuffers::WIPOffset<flatbuffers::UnionWIPOffset>>,
-    pub order: Option<SourceOrdering>,
+    pub headers: Option<
+        flatbuffers::WIPOffset<
+            flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<RequestHeader<'a>>>,
+        >,
+    >,
 }
-impl<'a> Default for FetchStepFilesGlobArgs<'a> {
+impl<'a> Default for FetchStepUrlArgs<'a> {
     #[inline]
     fn default() -> Self {
-        FetchStepFilesGlobArgs {
-            path: None,
+        FetchStepUrlArgs {
+            url: None,
             event_time_type: EventTimeSource::NONE,
             event_time: None,
             cache_type: SourceCaching::NONE,
             cache: None,
-            order: None,
+            headers: None,
         }
     }
 }
 
-pub struct FetchStepFilesGlobBuilder<'a: 'b, 'b> {
+pub struct FetchStepUrlBuilder<'a: 'b, 'b> {
     fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
     start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
 }
-impl<'a: 'b, 'b> FetchStepFilesGlobBuilder<'a, 'b> {
+impl<'a: 'b, 'b> FetchStepUrlBuilder<'a, 'b> {
     #[inline]
-    pub fn add_path(&mut self, path: flatbuffers::WIPOffset<&'b str>) {
+    pub fn add_url(&mut self, url: flatbuffers::WIPOffset<&'b str>) {
         self.fbb_
-            .push_slot_always::<flatbuffers::WIPOffset<_>>(FetchStepFilesGlob::VT_PATH, path);
+            .push_slot_always::<flatbuffers::WIPOffset<_>>(FetchStepUrl::VT_URL, url);
     }
     #[inline]
     pub fn add_event_time_type(&mut self, event_time_type: EventTimeSource) {
         self.fbb_.push_slot::<EventTimeSource>(
-            FetchStepFilesGlob::VT_EVENT_TIME_TYPE,
+            FetchStepUrl::VT_EVENT_TIME_TYPE,
             event_time_type,
             EventTimeSource::NONE,
         );
@@ -7606,15 +7912,13 @@ impl<'a: 'b, 'b> FetchStepFilesGlobBuilder<'a, 'b> {
         &mut self,
         event_time: flatbuffers::WIPOffset<flatbuffers::UnionWIPOffset>,
     ) {
-        self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(
-            FetchStepFilesGlob::VT_EVENT_TIME,
-            event_time,
-        );
+        self.fbb_
+            .push_slot_always::<flatbuffers::WIPOffset<_>>(FetchStepUrl::VT_EVENT_TIME, event_time);
     }
     #[inline]
     pub fn add_cache_type(&mut self, cache_type: SourceCaching) {
         self.fbb_.push_slot::<SourceCaching>(
-            FetchStepFilesGlob::VT_CACHE_TYPE,
+            FetchStepUrl::VT_CACHE_TYPE,
             cache_type,
             SourceCaching::NONE,
         );
@@ -7622,34 +7926,37 @@ impl<'a: 'b, 'b> FetchStepFilesGlobBuilder<'a, 'b> {
     #[inline]
     pub fn add_cache(&mut self, cache: flatbuffers::WIPOffset<flatbuffers::UnionWIPOffset>) {
         self.fbb_
-            .push_slot_always::<flatbuffers::WIPOffset<_>>(FetchStepFilesGlob::VT_CACHE, cache);
+            .push_slot_always::<flatbuffers::WIPOffset<_>>(FetchStepUrl::VT_CACHE, cache);
     }
     #[inline]
-    pub fn add_order(&mut self, order: SourceOrdering) {
+    pub fn add_headers(
+        &mut self,
+        headers: flatbuffers::WIPOffset<
+            flatbuffers::Vector<'b, flatbuffers::ForwardsUOffset<RequestHeader<'b>>>,
+        >,
+    ) {
         self.fbb_
-            .push_slot_always::<SourceOrdering>(FetchStepFilesGlob::VT_ORDER, order);
+            .push_slot_always::<flatbuffers::WIPOffset<_>>(FetchStepUrl::VT_HEADERS, headers);
     }
     #[inline]
-    pub fn new(
-        _fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>,
-    ) -> FetchStepFilesGlobBuilder<'a, 'b> {
+    pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> FetchStepUrlBuilder<'a, 'b> {
         let start = _fbb.start_table();
-        FetchStepFilesGlobBuilder {
+        FetchStepUrlBuilder {
             fbb_: _fbb,
             start_: start,
         }
     }
     #[inline]
-    pub fn finish(self) -> flatbuffers::WIPOffset<FetchStepFilesGlob<'a>> {
+    pub fn finish(self) -> flatbuffers::WIPOffset<FetchStepUrl<'a>> {
         let o = self.fbb_.end_table(self.start_);
         flatbuffers::WIPOffset::new(o.value())
     }
 }
 
-impl core::fmt::Debug for FetchStepFilesGlob<'_> {
+impl core::fmt::Debug for FetchStepUrl<'_> {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-        let mut ds = f.debug_struct("FetchStepFilesGlob");
-        ds.field("path", &self.path());
+        let mut ds = f.debug_struct("FetchStepUrl");
+        ds.field("url", &self.url());
         ds.field("event_time_type", &self.event_time_type());
         match self.event_time_type() {
             EventTimeSource::EventTimeSourceFromMetadata => {
@@ -7704,19 +8011,1157 @@ impl core::fmt::Debug for FetchStepFilesGlob<'_> {
                 ds.field("cache", &x)
             }
         };
-        ds.field("order", &self.order());
+        ds.field("headers", &self.headers());
+        ds.finish()
+    }
+}
+pub enum FetchStepFilesGlobOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct FetchStepFilesGlob<'a> {
+    pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for FetchStepFilesGlob<'a> {
+    type Inner = FetchStepFilesGlob<'a>;
+    #[inline]
+    unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+        Self {
+            _tab: flatbuffers::Table::new(buf, loc),
+        }
+    }
+}
+
+impl<'a> FetchStepFilesGlob<'a> {
+    pub const VT_PATH: flatbuffers::VOffsetT = 4;
+    pub const VT_EVENT_TIME_TYPE: flatbuffers::VOffsetT = 6;
+    pub const VT_EVENT_TIME: flatbuffers::VOffsetT = 8;
+    pub const VT_CACHE_TYPE: flatbuffers::VOffsetT = 10;
+    pub const VT_CACHE: flatbuffers::VOffsetT = 12;
+    pub const VT_ORDER: flatbuffers::VOffsetT = 14;
+
+    #[inline]
+    pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+        FetchStepFilesGlob { _tab: table }
+    }
+    #[allow(unused_mut)]
+    pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
+        _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
+        args: &'args FetchStepFilesGlobArgs<'args>,
+    ) -> flatbuffers::WIPOffset<FetchStepFilesGlob<'bldr>> {
+        let mut builder = FetchStepFilesGlobBuilder::new(_fbb);
+        if let Some(x) = args.order {
+            builder.add_order(x);
+        }
+        if let Some(x) = args.cache {
+            builder.add_cache(x);
+        }
+        if let Some(x) = args.event_time {
+            builder.add_event_time(x);
+        }
+        if let Some(x) = args.path {
+            builder.add_path(x);
+        }
+        builder.add_cache_type(args.cache_type);
+        builder.add_event_time_type(args.event_time_type);
+        builder.finish()
+    }
+
+    #[inline]
+    pub fn path(&self) -> Option<&'a str> {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab
+                .get::<flatbuffers::ForwardsUOffset<&str>>(FetchStepFilesGlob::VT_PATH, None)
+        }
+    }
+    #[inline]
+    pub fn event_time_type(&self) -> EventTimeSource {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab
+                .get::<EventTimeSource>(
+                    FetchStepFilesGlob::VT_EVENT_TIME_TYPE,
+                    Some(EventTimeSource::NONE),
+                )
+                .unwrap()
+        }
+    }
+    #[inline]
+    pub fn event_time(&self) -> Option<flatbuffers::Table<'a>> {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab
+                .get::<flatbuffers::ForwardsUOffset<flatbuffers::Table<'a>>>(
+                    FetchStepFilesGlob::VT_EVENT_TIME,
+                    None,
+                )
+        }
+    }
+    #[inline]
+    pub fn cache_type(&self) -> SourceCaching {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab
+                .get::<SourceCaching>(FetchStepFilesGlob::VT_CACHE_TYPE, Some(SourceCaching::NONE))
+                .unwrap()
+        }
+    }
+    #[inline]
+    pub fn cache(&self) -> Option<flatbuffers::Table<'a>> {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab
+                .get::<flatbuffers::ForwardsUOffset<flatbuffers::Table<'a>>>(
+                    FetchStepFilesGlob::VT_CACHE,
+                    None,
+                )
+        }
+    }
+    #[inline]
+    pub fn order(&self) -> Option<SourceOrdering> {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab
+                .get::<SourceOrdering>(FetchStepFilesGlob::VT_ORDER, None)
+        }
+    }
+    #[inline]
+    #[allow(non_snake_case)]
+    pub fn event_time_as_event_time_source_from_metadata(
+        &self,
+    ) -> Option<EventTimeSourceFromMetadata<'a>> {
+        if self.event_time_type() == EventTimeSource::EventTimeSourceFromMetadata {
+            self.event_time().map(|t| {
+                // Safety:
+                // Created from a valid Table for this object
+                // Which contains a valid union in this slot
+                unsafe { EventTimeSourceFromMetadata::init_from_table(t) }
+            })
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    #[allow(non_snake_case)]
+    pub fn event_time_as_event_time_source_from_path(&self) -> Option<EventTimeSourceFromPath<'a>> {
+        if self.event_time_type() == EventTimeSource::EventTimeSourceFromPath {
+            self.event_time().map(|t| {
+                // Safety:
+                // Created from a valid Table for this object
+                // Which contains a valid union in this slot
+                unsafe { EventTimeSourceFromPath::init_from_table(t) }
+            })
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    #[allow(non_snake_case)]
+    pub fn event_time_as_event_time_source_from_system_time(
+        &self,
+    ) -> Option<EventTimeSourceFromSystemTime<'a>> {
+        if self.event_time_type() == EventTimeSource::EventTimeSourceFromSystemTime {
+            self.event_time().map(|t| {
+                // Safety:
+                // Created from a valid Table for this object
+                // Which contains a valid union in this slot
+                unsafe { EventTimeSourceFromSystemTime::init_from_table(t) }
+            })
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    #[allow(non_snake_case)]
+    pub fn cache_as_source_caching_forever(&self) -> Option<SourceCachingForever<'a>> {
+        if self.cache_type() == SourceCaching::SourceCachingForever {
+            self.cache().map(|t| {
+                // Safety:
+                // Created from a valid Table for this object
+                // Which contains a valid union in this slot
+                unsafe { SourceCachingForever::init_from_table(t) }
+            })
+        } else {
+            None
+        }
+    }
+}
+
+impl flatbuffers::Verifiable for FetchStepFilesGlob<'_> {
+    #[inline]
+    fn run_verifier(
+        v: &mut flatbuffers::Verifier,
+        pos: usize,
+    ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+        use self::flatbuffers::Verifiable;
+        v.visit_table(pos)?
+     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("path", Self::VT_PATH, false)?
+     .visit_union::<EventTimeSource, _>("event_time_type", Self::VT_EVENT_TIME_TYPE, "event_time", Self::VT_EVENT_TIME, false, |key, v, pos| {
+        match key {
+          EventTimeSource::EventTimeSourceFromMetadata => v.verify_union_variant::<flatbuffers::ForwardsUOffset<EventTimeSourceFromMetadata>>("EventTimeSource::EventTimeSourceFromMetadata", pos),
+          EventTimeSource::EventTimeSourceFromPath => v.verify_union_variant::<flatbuffers::ForwardsUOffset<EventTimeSourceFromPath>>("EventTimeSource::EventTimeSourceFromPath", pos),
+          EventTimeSource::EventTimeSourceFromSystemTime => v.verify_union_variant::<flatbuffers::ForwardsUOffset<EventTimeSourceFromSystemTime>>("EventTimeSource::EventTimeSourceFromSystemTime", pos),
+          _ => Ok(()),
+        }
+     })?
+     .visit_union::<SourceCaching, _>("cache_type", Self::VT_CACHE_TYPE, "cache", Self::VT_CACHE, false, |key, v, pos| {
+        match key {
+          SourceCaching::SourceCachingForever => v.verify_union_variant::<flatbuffers::ForwardsUOffset<SourceCachingForever>>("SourceCaching::SourceCachingForever", pos),
+          _ => Ok(()),
+        }
+     })?
+     .visit_field::<SourceOrdering>("order", Self::VT_ORDER, false)?
+     .finish();
+        Ok(())
+    }
+}
+pub struct FetchStepFilesGlobArgs<'a> {
+    pub path: Option<flatbuffers::WIPOffset<&'a str>>,
+    pub event_time_type: EventTimeSource,
+    pub event_time: Option<flatbuffers::WIPOffset<flatbuffers::UnionWIPOffset>>,
+    pub cache_type: SourceCaching,
+    pub cache: Option<flatbuffers::WIPOffset<flatbuffers::UnionWIPOffset>>,
+    pub order: Option<SourceOrdering>,
+}
+impl<'a> Default for FetchStepFilesGlobArgs<'a> {
+    #[inline]
+    fn default() -> Self {
+        FetchStepFilesGlobArgs {
+            path: None,
+            event_time_type: EventTimeSource::NONE,
+            event_time: None,
+            cache_type: SourceCaching::NONE,
+            cache: None,
+            order: None,
+        }
+    }
+}
+
+pub struct FetchStepFilesGlobBuilder<'a: 'b, 'b> {
+    fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+    start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b> FetchStepFilesGlobBuilder<'a, 'b> {
+    #[inline]
+    pub fn add_path(&mut self, path: flatbuffers::WIPOffset<&'b str>) {
+        self.fbb_
+            .push_slot_always::<flatbuffers::WIPOffset<_>>(FetchStepFilesGlob::VT_PATH, path);
+    }
+    #[inline]
+    pub fn add_event_time_type(&mut self, event_time_type: EventTimeSource) {
+        self.fbb_.push_slot::<EventTimeSource>(
+            FetchStepFilesGlob::VT_EVENT_TIME_TYPE,
+            event_time_type,
+            EventTimeSource::NONE,
+        );
+    }
+    #[inline]
+    pub fn add_event_time(
+        &mut self,
+        event_time: flatbuffers::WIPOffset<flatbuffers::UnionWIPOffset>,
+    ) {
+        self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(
+            FetchStepFilesGlob::VT_EVENT_TIME,
+            event_time,
+        );
+    }
+    #[inline]
+    pub fn add_cache_type(&mut self, cache_type: SourceCaching) {
+        self.fbb_.push_slot::<SourceCaching>(
+            FetchStepFilesGlob::VT_CACHE_TYPE,
+            cache_type,
+            SourceCaching::NONE,
+        );
+    }
+    #[inline]
+    pub fn add_cache(&mut self, cache: flatbuffers::WIPOffset<flatbuffers::UnionWIPOffset>) {
+        self.fbb_
+            .push_slot_always::<flatbuffers::WIPOffset<_>>(FetchStepFilesGlob::VT_CACHE, cache);
+    }
+    #[inline]
+    pub fn add_order(&mut self, order: SourceOrdering) {
+        self.fbb_
+            .push_slot_always::<SourceOrdering>(FetchStepFilesGlob::VT_ORDER, order);
+    }
+    #[inline]
+    pub fn new(
+        _fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+    ) -> FetchStepFilesGlobBuilder<'a, 'b> {
+        let start = _fbb.start_table();
+        FetchStepFilesGlobBuilder {
+            fbb_: _fbb,
+            start_: start,
+        }
+    }
+    #[inline]
+    pub fn finish(self) -> flatbuffers::WIPOffset<FetchStepFilesGlob<'a>> {
+        let o = self.fbb_.end_table(self.start_);
+        flatbuffers::WIPOffset::new(o.value())
+    }
+}
+
+impl core::fmt::Debug for FetchStepFilesGlob<'_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let mut ds = f.debug_struct("FetchStepFilesGlob");
+        ds.field("path", &self.path());
+        ds.field("event_time_type", &self.event_time_type());
+        match self.event_time_type() {
+            EventTimeSource::EventTimeSourceFromMetadata => {
+                if let Some(x) = self.event_time_as_event_time_source_from_metadata() {
+                    ds.field("event_time", &x)
+                } else {
+                    ds.field(
+                        "event_time",
+                        &"InvalidFlatbuffer: Union discriminant does not match value.",
+                    )
+                }
+            }
+            EventTimeSource::EventTimeSourceFromPath => {
+                if let Some(x) = self.event_time_as_event_time_source_from_path() {
+                    ds.field("event_time", &x)
+                } else {
+                    ds.field(
+                        "event_time",
+                        &"InvalidFlatbuffer: Union discriminant does not match value.",
+                    )
+                }
+            }
+            EventTimeSource::EventTimeSourceFromSystemTime => {
+                if let Some(x) = self.event_time_as_event_time_source_from_system_time() {
+                    ds.field("event_time", &x)
+                } else {
+                    ds.field(
+                        "event_time",
+                        &"InvalidFlatbuffer: Union discriminant does not match value.",
+                    )
+                }
+            }
+            _ => {
+                let x: Option<()> = None;
+                ds.field("event_time", &x)
+            }
+        };
+        ds.field("cache_type", &self.cache_type());
+        match self.cache_type() {
+            SourceCaching::SourceCachingForever => {
+                if let Some(x) = self.cache_as_source_caching_forever() {
+                    ds.field("cache", &x)
+                } else {
+                    ds.field(
+                        "cache",
+                        &"InvalidFlatbuffer: Union discriminant does not match value.",
+                    )
+                }
+            }
+            _ => {
+                let x: Option<()> = None;
+                ds.field("cache", &x)
+            }
+        };
+        ds.field("order", &self.order());
+        ds.finish()
+    }
+}
+pub enum FetchStepContainerOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct FetchStepContainer<'a> {
+    pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for FetchStepContainer<'a> {
+    type Inner = FetchStepContainer<'a>;
+    #[inline]
+    unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+        Self {
+            _tab: flatbuffers::Table::new(buf, loc),
+        }
+    }
+}
+
+impl<'a> FetchStepContainer<'a> {
+    pub const VT_IMAGE: flatbuffers::VOffsetT = 4;
+    pub const VT_COMMAND: flatbuffers::VOffsetT = 6;
+    pub const VT_ARGS: flatbuffers::VOffsetT = 8;
+    pub const VT_ENV: flatbuffers::VOffsetT = 10;
+
+    #[inline]
+    pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+        FetchStepContainer { _tab: table }
+    }
+    #[allow(unused_mut)]
+    pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
+        _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
+        args: &'args FetchStepContainerArgs<'args>,
+    ) -> flatbuffers::WIPOffset<FetchStepContainer<'bldr>> {
+        let mut builder = FetchStepContainerBuilder::new(_fbb);
+        if let Some(x) = args.env {
+            builder.add_env(x);
+        }
+        if let Some(x) = args.args {
+            builder.add_args(x);
+        }
+        if let Some(x) = args.command {
+            builder.add_command(x);
+        }
+        if let Some(x) = args.image {
+            builder.add_image(x);
+        }
+        builder.finish()
+    }
+
+    #[inline]
+    pub fn image(&self) -> Option<&'a str> {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab
+                .get::<flatbuffers::ForwardsUOffset<&str>>(FetchStepContainer::VT_IMAGE, None)
+        }
+    }
+    #[inline]
+    pub fn command(
+        &self,
+    ) -> Option<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<&'a str>>> {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab.get::<flatbuffers::ForwardsUOffset<
+                flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<&'a str>>,
+            >>(FetchStepContainer::VT_COMMAND, None)
+        }
+    }
+    #[inline]
+    pub fn args(&self) -> Option<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<&'a str>>> {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab.get::<flatbuffers::ForwardsUOffset<
+                flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<&'a str>>,
+            >>(FetchStepContainer::VT_ARGS, None)
+        }
+    }
+    #[inline]
+    pub fn env(&self) -> Option<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<EnvVar<'a>>>> {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab.get::<flatbuffers::ForwardsUOffset<
+                flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<EnvVar>>,
+            >>(FetchStepContainer::VT_ENV, None)
+        }
+    }
+}
+
+impl flatbuffers::Verifiable for FetchStepContainer<'_> {
+    #[inline]
+    fn run_verifier(
+        v: &mut flatbuffers::Verifier,
+        pos: usize,
+    ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+        use self::flatbuffers::Verifiable;
+        v.visit_table(pos)?
+            .visit_field::<flatbuffers::ForwardsUOffset<&str>>("image", Self::VT_IMAGE, false)?
+            .visit_field::<flatbuffers::ForwardsUOffset<
+                flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<&'_ str>>,
+            >>("command", Self::VT_COMMAND, false)?
+            .visit_field::<flatbuffers::ForwardsUOffset<
+                flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<&'_ str>>,
+            >>("args", Self::VT_ARGS, false)?
+            .visit_field::<flatbuffers::ForwardsUOffset<
+                flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<EnvVar>>,
+            >>("env", Self::VT_ENV, false)?
+            .finish();
+        Ok(())
+    }
+}
+pub struct FetchStepContainerArgs<'a> {
+    pub image: Option<flatbuffers::WIPOffset<&'a str>>,
+    pub command: Option<
+        flatbuffers::WIPOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<&'a str>>>,
+    >,
+    pub args: Option<
+        flatbuffers::WIPOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<&'a str>>>,
+    >,
+    pub env: Option<
+        flatbuffers::WIPOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<EnvVar<'a>>>>,
+    >,
+}
+impl<'a> Default for FetchStepContainerArgs<'a> {
+    #[inline]
+    fn default() -> Self {
+        FetchStepContainerArgs {
+            image: None,
+            command: None,
+            args: None,
+            env: None,
+        }
+    }
+}
+
+pub struct FetchStepContainerBuilder<'a: 'b, 'b> {
+    fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+    start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b> FetchStepContainerBuilder<'a, 'b> {
+    #[inline]
+    pub fn add_image(&mut self, image: flatbuffers::WIPOffset<&'b str>) {
+        self.fbb_
+            .push_slot_always::<flatbuffers::WIPOffset<_>>(FetchStepContainer::VT_IMAGE, image);
+    }
+    #[inline]
+    pub fn add_command(
+        &mut self,
+        command: flatbuffers::WIPOffset<
+            flatbuffers::Vector<'b, flatbuffers::ForwardsUOffset<&'b str>>,
+        >,
+    ) {
+        self.fbb_
+            .push_slot_always::<flatbuffers::WIPOffset<_>>(FetchStepContainer::VT_COMMAND, command);
+    }
+    #[inline]
+    pub fn add_args(
+        &mut self,
+        args: flatbuffers::WIPOffset<
+            flatbuffers::Vector<'b, flatbuffers::ForwardsUOffset<&'b str>>,
+        >,
+    ) {
+        self.fbb_
+            .push_slot_always::<flatbuffers::WIPOffset<_>>(FetchStepContainer::VT_ARGS, args);
+    }
+    #[inline]
+    pub fn add_env(
+        &mut self,
+        env: flatbuffers::WIPOffset<
+            flatbuffers::Vector<'b, flatbuffers::ForwardsUOffset<EnvVar<'b>>>,
+        >,
+    ) {
+        self.fbb_
+            .push_slot_always::<flatbuffers::WIPOffset<_>>(FetchStepContainer::VT_ENV, env);
+    }
+    #[inline]
+    pub fn new(
+        _fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+    ) -> FetchStepContainerBuilder<'a, 'b> {
+        let start = _fbb.start_table();
+        FetchStepContainerBuilder {
+            fbb_: _fbb,
+            start_: start,
+        }
+    }
+    #[inline]
+    pub fn finish(self) -> flatbuffers::WIPOffset<FetchStepContainer<'a>> {
+        let o = self.fbb_.end_table(self.start_);
+        flatbuffers::WIPOffset::new(o.value())
+    }
+}
+
+impl core::fmt::Debug for FetchStepContainer<'_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let mut ds = f.debug_struct("FetchStepContainer");
+        ds.field("image", &self.image());
+        ds.field("command", &self.command());
+        ds.field("args", &self.args());
+        ds.field("env", &self.env());
+        ds.finish()
+    }
+}
+pub enum FetchStepMqttOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct FetchStepMqtt<'a> {
+    pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for FetchStepMqtt<'a> {
+    type Inner = FetchStepMqtt<'a>;
+    #[inline]
+    unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+        Self {
+            _tab: flatbuffers::Table::new(buf, loc),
+        }
+    }
+}
+
+impl<'a> FetchStepMqtt<'a> {
+    pub const VT_HOST: flatbuffers::VOffsetT = 4;
+    pub const VT_PORT: flatbuffers::VOffsetT = 6;
+    pub const VT_USERNAME: flatbuffers::VOffsetT = 8;
+    pub const VT_PASSWORD: flatbuffers::VOffsetT = 10;
+    pub const VT_TOPICS: flatbuffers::VOffsetT = 12;
+
+    #[inline]
+    pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+        FetchStepMqtt { _tab: table }
+    }
+    #[allow(unused_mut)]
+    pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
+        _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
+        args: &'args FetchStepMqttArgs<'args>,
+    ) -> flatbuffers::WIPOffset<FetchStepMqtt<'bldr>> {
+        let mut builder = FetchStepMqttBuilder::new(_fbb);
+        if let Some(x) = args.topics {
+            builder.add_topics(x);
+        }
+        if let Some(x) = args.password {
+            builder.add_password(x);
+        }
+        if let Some(x) = args.username {
+            builder.add_username(x);
+        }
+        builder.add_port(args.port);
+        if let Some(x) = args.host {
+            builder.add_host(x);
+        }
+        builder.finish()
+    }
+
+    #[inline]
+    pub fn host(&self) -> Option<&'a str> {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab
+                .get::<flatbuffers::ForwardsUOffset<&str>>(FetchStepMqtt::VT_HOST, None)
+        }
+    }
+    #[inline]
+    pub fn port(&self) -> i32 {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab
+                .get::<i32>(FetchStepMqtt::VT_PORT, Some(0))
+                .unwrap()
+        }
+    }
+    #[inline]
+    pub fn username(&self) -> Option<&'a str> {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab
+                .get::<flatbuffers::ForwardsUOffset<&str>>(FetchStepMqtt::VT_USERNAME, None)
+        }
+    }
+    #[inline]
+    pub fn password(&self) -> Option<&'a str> {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab
+                .get::<flatbuffers::ForwardsUOffset<&str>>(FetchStepMqtt::VT_PASSWORD, None)
+        }
+    }
+    #[inline]
+    pub fn topics(
+        &self,
+    ) -> Option<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<MqttTopicSubscription<'a>>>>
+    {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab.get::<flatbuffers::ForwardsUOffset<
+                flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<MqttTopicSubscription>>,
+            >>(FetchStepMqtt::VT_TOPICS, None)
+        }
+    }
+}
+
+impl flatbuffers::Verifiable for FetchStepMqtt<'_> {
+    #[inline]
+    fn run_verifier(
+        v: &mut flatbuffers::Verifier,
+        pos: usize,
+    ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+        use self::flatbuffers::Verifiable;
+        v.visit_table(pos)?
+            .visit_field::<flatbuffers::ForwardsUOffset<&str>>("host", Self::VT_HOST, false)?
+            .visit_field::<i32>("port", Self::VT_PORT, false)?
+            .visit_field::<flatbuffers::ForwardsUOffset<&str>>(
+                "username",
+                Self::VT_USERNAME,
+                false,
+            )?
+            .visit_field::<flatbuffers::ForwardsUOffset<&str>>(
+                "password",
+                Self::VT_PASSWORD,
+                false,
+            )?
+            .visit_field::<flatbuffers::ForwardsUOffset<
+                flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<MqttTopicSubscription>>,
+            >>("topics", Self::VT_TOPICS, false)?
+            .finish();
+        Ok(())
+    }
+}
+pub struct FetchStepMqttArgs<'a> {
+    pub host: Option<flatbuffers::WIPOffset<&'a str>>,
+    pub port: i32,
+    pub username: Option<flatbuffers::WIPOffset<&'a str>>,
+    pub password: Option<flatbuffers::WIPOffset<&'a str>>,
+    pub topics: Option<
+        flatbuffers::WIPOffset<
+            flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<MqttTopicSubscription<'a>>>,
+        >,
+    >,
+}
+impl<'a> Default for FetchStepMqttArgs<'a> {
+    #[inline]
+    fn default() -> Self {
+        FetchStepMqttArgs {
+            host: None,
+            port: 0,
+            username: None,
+            password: None,
+            topics: None,
+        }
+    }
+}
+
+pub struct FetchStepMqttBuilder<'a: 'b, 'b> {
+    fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+    start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b> FetchStepMqttBuilder<'a, 'b> {
+    #[inline]
+    pub fn add_host(&mut self, host: flatbuffers::WIPOffset<&'b str>) {
+        self.fbb_
+            .push_slot_always::<flatbuffers::WIPOffset<_>>(FetchStepMqtt::VT_HOST, host);
+    }
+    #[inline]
+    pub fn add_port(&mut self, port: i32) {
+        self.fbb_.push_slot::<i32>(FetchStepMqtt::VT_PORT, port, 0);
+    }
+    #[inline]
+    pub fn add_username(&mut self, username: flatbuffers::WIPOffset<&'b str>) {
+        self.fbb_
+            .push_slot_always::<flatbuffers::WIPOffset<_>>(FetchStepMqtt::VT_USERNAME, username);
+    }
+    #[inline]
+    pub fn add_password(&mut self, password: flatbuffers::WIPOffset<&'b str>) {
+        self.fbb_
+            .push_slot_always::<flatbuffers::WIPOffset<_>>(FetchStepMqtt::VT_PASSWORD, password);
+    }
+    #[inline]
+    pub fn add_topics(
+        &mut self,
+        topics: flatbuffers::WIPOffset<
+            flatbuffers::Vector<'b, flatbuffers::ForwardsUOffset<MqttTopicSubscription<'b>>>,
+        >,
+    ) {
+        self.fbb_
+            .push_slot_always::<flatbuffers::WIPOffset<_>>(FetchStepMqtt::VT_TOPICS, topics);
+    }
+    #[inline]
+    pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> FetchStepMqttBuilder<'a, 'b> {
+        let start = _fbb.start_table();
+        FetchStepMqttBuilder {
+            fbb_: _fbb,
+            start_: start,
+        }
+    }
+    #[inline]
+    pub fn finish(self) -> flatbuffers::WIPOffset<FetchStepMqtt<'a>> {
+        let o = self.fbb_.end_table(self.start_);
+        flatbuffers::WIPOffset::new(o.value())
+    }
+}
+
+impl core::fmt::Debug for FetchStepMqtt<'_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let mut ds = f.debug_struct("FetchStepMqtt");
+        ds.field("host", &self.host());
+        ds.field("port", &self.port());
+        ds.field("username", &self.username());
+        ds.field("password", &self.password());
+        ds.field("topics", &self.topics());
+        ds.finish()
+    }
+}
+pub enum FetchStepEthereumLogsOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct FetchStepEthereumLogs<'a> {
+    pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for FetchStepEthereumLogs<'a> {
+    type Inner = FetchStepEthereumLogs<'a>;
+    #[inline]
+    unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+        Self {
+            _tab: flatbuffers::Table::new(buf, loc),
+        }
+    }
+}
+
+impl<'a> FetchStepEthereumLogs<'a> {
+    pub const VT_CHAIN_ID: flatbuffers::VOffsetT = 4;
+    pub const VT_NODE_URL: flatbuffers::VOffsetT = 6;
+    pub const VT_FILTER: flatbuffers::VOffsetT = 8;
+    pub const VT_SIGNATURE: flatbuffers::VOffsetT = 10;
+
+    #[inline]
+    pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+        FetchStepEthereumLogs { _tab: table }
+    }
+    #[allow(unused_mut)]
+    pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
+        _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
+        args: &'args FetchStepEthereumLogsArgs<'args>,
+    ) -> flatbuffers::WIPOffset<FetchStepEthereumLogs<'bldr>> {
+        let mut builder = FetchStepEthereumLogsBuilder::new(_fbb);
+        if let Some(x) = args.chain_id {
+            builder.add_chain_id(x);
+        }
+        if let Some(x) = args.signature {
+            builder.add_signature(x);
+        }
+        if let Some(x) = args.filter {
+            builder.add_filter(x);
+        }
+        if let Some(x) = args.node_url {
+            builder.add_node_url(x);
+        }
+        builder.finish()
+    }
+
+    #[inline]
+    pub fn chain_id(&self) -> Option<u64> {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab
+                .get::<u64>(FetchStepEthereumLogs::VT_CHAIN_ID, None)
+        }
+    }
+    #[inline]
+    pub fn node_url(&self) -> Option<&'a str> {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab
+                .get::<flatbuffers::ForwardsUOffset<&str>>(FetchStepEthereumLogs::VT_NODE_URL, None)
+        }
+    }
+    #[inline]
+    pub fn filter(&self) -> Option<&'a str> {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab
+                .get::<flatbuffers::ForwardsUOffset<&str>>(FetchStepEthereumLogs::VT_FILTER, None)
+        }
+    }
+    #[inline]
+    pub fn signature(&self) -> Option<&'a str> {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(
+                FetchStepEthereumLogs::VT_SIGNATURE,
+                None,
+            )
+        }
+    }
+}
+
+impl flatbuffers::Verifiable for FetchStepEthereumLogs<'_> {
+    #[inline]
+    fn run_verifier(
+        v: &mut flatbuffers::Verifier,
+        pos: usize,
+    ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+        use self::flatbuffers::Verifiable;
+        v.visit_table(pos)?
+            .visit_field::<u64>("chain_id", Self::VT_CHAIN_ID, false)?
+            .visit_field::<flatbuffers::ForwardsUOffset<&str>>(
+                "node_url",
+                Self::VT_NODE_URL,
+                false,
+            )?
+            .visit_field::<flatbuffers::ForwardsUOffset<&str>>("filter", Self::VT_FILTER, false)?
+            .visit_field::<flatbuffers::ForwardsUOffset<&str>>(
+                "signature",
+                Self::VT_SIGNATURE,
+                false,
+            )?
+            .finish();
+        Ok(())
+    }
+}
+pub struct FetchStepEthereumLogsArgs<'a> {
+    pub chain_id: Option<u64>,
+    pub node_url: Option<flatbuffers::WIPOffset<&'a str>>,
+    pub filter: Option<flatbuffers::WIPOffset<&'a str>>,
+    pub signature: Option<flatbuffers::WIPOffset<&'a str>>,
+}
+impl<'a> Default for FetchStepEthereumLogsArgs<'a> {
+    #[inline]
+    fn default() -> Self {
+        FetchStepEthereumLogsArgs {
+            chain_id: None,
+            node_url: None,
+            filter: None,
+            signature: None,
+        }
+    }
+}
+
+pub struct FetchStepEthereumLogsBuilder<'a: 'b, 'b> {
+    fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+    start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b> FetchStepEthereumLogsBuilder<'a, 'b> {
+    #[inline]
+    pub fn add_chain_id(&mut self, chain_id: u64) {
+        self.fbb_
+            .push_slot_always::<u64>(FetchStepEthereumLogs::VT_CHAIN_ID, chain_id);
+    }
+    #[inline]
+    pub fn add_node_url(&mut self, node_url: flatbuffers::WIPOffset<&'b str>) {
+        self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(
+            FetchStepEthereumLogs::VT_NODE_URL,
+            node_url,
+        );
+    }
+    #[inline]
+    pub fn add_filter(&mut self, filter: flatbuffers::WIPOffset<&'b str>) {
+        self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(
+            FetchStepEthereumLogs::VT_FILTER,
+            filter,
+        );
+    }
+    #[inline]
+    pub fn add_signature(&mut self, signature: flatbuffers::WIPOffset<&'b str>) {
+        self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(
+            FetchStepEthereumLogs::VT_SIGNATURE,
+            signature,
+        );
+    }
+    #[inline]
+    pub fn new(
+        _fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+    ) -> FetchStepEthereumLogsBuilder<'a, 'b> {
+        let start = _fbb.start_table();
+        FetchStepEthereumLogsBuilder {
+            fbb_: _fbb,
+            start_: start,
+        }
+    }
+    #[inline]
+    pub fn finish(self) -> flatbuffers::WIPOffset<FetchStepEthereumLogs<'a>> {
+        let o = self.fbb_.end_table(self.start_);
+        flatbuffers::WIPOffset::new(o.value())
+    }
+}
+
+impl core::fmt::Debug for FetchStepEthereumLogs<'_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let mut ds = f.debug_struct("FetchStepEthereumLogs");
+        ds.field("chain_id", &self.chain_id());
+        ds.field("node_url", &self.node_url());
+        ds.field("filter", &self.filter());
+        ds.field("signature", &self.signature());
+        ds.finish()
+    }
+}
+pub enum FetchStepSqlOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct FetchStepSql<'a> {
+    pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for FetchStepSql<'a> {
+    type Inner = FetchStepSql<'a>;
+    #[inline]
+    unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+        Self {
+            _tab: flatbuffers::Table::new(buf, loc),
+        }
+    }
+}
+
+impl<'a> FetchStepSql<'a> {
+    pub const VT_URL: flatbuffers::VOffsetT = 4;
+    pub const VT_QUERY: flatbuffers::VOffsetT = 6;
+    pub const VT_CURSOR_COLUMN: flatbuffers::VOffsetT = 8;
+
+    #[inline]
+    pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+        FetchStepSql { _tab: table }
+    }
+    #[allow(unused_mut)]
+    pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
+        _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
+        args: &'args FetchStepSqlArgs<'args>,
+    ) -> flatbuffers::WIPOffset<FetchStepSql<'bldr>> {
+        let mut builder = FetchStepSqlBuilder::new(_fbb);
+        if let Some(x) = args.cursor_column {
+            builder.add_cursor_column(x);
+        }
+        if let Some(x) = args.query {
+            builder.add_query(x);
+        }
+        if let Some(x) = args.url {
+            builder.add_url(x);
+        }
+        builder.finish()
+    }
+
+    #[inline]
+    pub fn url(&self) -> Option<&'a str> {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab
+                .get::<flatbuffers::ForwardsUOffset<&str>>(FetchStepSql::VT_URL, None)
+        }
+    }
+    #[inline]
+    pub fn query(&self) -> Option<&'a str> {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab
+                .get::<flatbuffers::ForwardsUOffset<&str>>(FetchStepSql::VT_QUERY, None)
+        }
+    }
+    #[inline]
+    pub fn cursor_column(&self) -> Option<&'a str> {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab
+                .get::<flatbuffers::ForwardsUOffset<&str>>(FetchStepSql::VT_CURSOR_COLUMN, None)
+        }
+    }
+}
+
+impl flatbuffers::Verifiable for FetchStepSql<'_> {
+    #[inline]
+    fn run_verifier(
+        v: &mut flatbuffers::Verifier,
+        pos: usize,
+    ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+        use self::flatbuffers::Verifiable;
+        v.visit_table(pos)?
+            .visit_field::<flatbuffers::ForwardsUOffset<&str>>("url", Self::VT_URL, false)?
+            .visit_field::<flatbuffers::ForwardsUOffset<&str>>("query", Self::VT_QUERY, false)?
+            .visit_field::<flatbuffers::ForwardsUOffset<&str>>(
+                "cursor_column",
+                Self::VT_CURSOR_COLUMN,
+                false,
+            )?
+            .finish();
+        Ok(())
+    }
+}
+pub struct FetchStepSqlArgs<'a> {
+    pub url: Option<flatbuffers::WIPOffset<&'a str>>,
+    pub query: Option<flatbuffers::WIPOffset<&'a str>>,
+    pub cursor_column: Option<flatbuffers::WIPOffset<&'a str>>,
+}
+impl<'a> Default for FetchStepSqlArgs<'a> {
+    #[inline]
+    fn default() -> Self {
+        FetchStepSqlArgs {
+            url: None,
+            query: None,
+            cursor_column: None,
+        }
+    }
+}
+
+pub struct FetchStepSqlBuilder<'a: 'b, 'b> {
+    fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+    start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b> FetchStepSqlBuilder<'a, 'b> {
+    #[inline]
+    pub fn add_url(&mut self, url: flatbuffers::WIPOffset<&'b str>) {
+        self.fbb_
+            .push_slot_always::<flatbuffers::WIPOffset<_>>(FetchStepSql::VT_URL, url);
+    }
+    #[inline]
+    pub fn add_query(&mut self, query: flatbuffers::WIPOffset<&'b str>) {
+        self.fbb_
+            .push_slot_always::<flatbuffers::WIPOffset<_>>(FetchStepSql::VT_QUERY, query);
+    }
+    #[inline]
+    pub fn add_cursor_column(&mut self, cursor_column: flatbuffers::WIPOffset<&'b str>) {
+        self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(
+            FetchStepSql::VT_CURSOR_COLUMN,
+            cursor_column,
+        );
+    }
+    #[inline]
+    pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> FetchStepSqlBuilder<'a, 'b> {
+        let start = _fbb.start_table();
+        FetchStepSqlBuilder {
+            fbb_: _fbb,
+            start_: start,
+        }
+    }
+    #[inline]
+    pub fn finish(self) -> flatbuffers::WIPOffset<FetchStepSql<'a>> {
+        let o = self.fbb_.end_table(self.start_);
+        flatbuffers::WIPOffset::new(o.value())
+    }
+}
+
+impl core::fmt::Debug for FetchStepSql<'_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let mut ds = f.debug_struct("FetchStepSql");
+        ds.field("url", &self.url());
+        ds.field("query", &self.query());
+        ds.field("cursor_column", &self.cursor_column());
         ds.finish()
     }
 }
-pub enum FetchStepContainerOffset {}
+pub enum FetchStepHttpPaginatedOffset {}
 #[derive(Copy, Clone, PartialEq)]
 
-pub struct FetchStepContainer<'a> {
+pub struct FetchStepHttpPaginated<'a> {
     pub _tab: flatbuffers::Table<'a>,
 }
 
-impl<'a> flatbuffers::Follow<'a> for FetchStepContainer<'a> {
-    type Inner = FetchStepContainer<'a>;
+impl<'a> flatbuffers::Follow<'a> for FetchStepHttpPaginated<'a> {
+    type Inner = FetchStepHttpPaginated<'a>;
     #[inline]
     unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
         Self {
@@ -7725,85 +9170,171 @@ impl<'a> flatbuffers::Follow<'a> for FetchStepContainer<'a> {
     }
 }
 
-impl<'a> FetchStepContainer<'a> {
-    pub const VT_IMAGE: flatbuffers::VOffsetT = 4;
-    pub const VT_COMMAND: flatbuffers::VOffsetT = 6;
-    pub const VT_ARGS: flatbuffers::VOffsetT = 8;
-    pub const VT_ENV: flatbuffers::VOffsetT = 10;
+impl<'a> FetchStepHttpPaginated<'a> {
+    pub const VT_URL: flatbuffers::VOffsetT = 4;
+    pub const VT_HEADERS: flatbuffers::VOffsetT = 6;
+    pub const VT_RECORDS_PATH: flatbuffers::VOffsetT = 8;
+    pub const VT_PAGINATION_TYPE: flatbuffers::VOffsetT = 10;
+    pub const VT_PAGINATION: flatbuffers::VOffsetT = 12;
+    pub const VT_HIGH_WATER_MARK: flatbuffers::VOffsetT = 14;
 
     #[inline]
     pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
-        FetchStepContainer { _tab: table }
+        FetchStepHttpPaginated { _tab: table }
     }
     #[allow(unused_mut)]
     pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
         _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
-        args: &'args FetchStepContainerArgs<'args>,
-    ) -> flatbuffers::WIPOffset<FetchStepContainer<'bldr>> {
-        let mut builder = FetchStepContainerBuilder::new(_fbb);
-        if let Some(x) = args.env {
-            builder.add_env(x);
+        args: &'args FetchStepHttpPaginatedArgs<'args>,
+    ) -> flatbuffers::WIPOffset<FetchStepHttpPaginated<'bldr>> {
+        let mut builder = FetchStepHttpPaginatedBuilder::new(_fbb);
+        if let Some(x) = args.high_water_mark {
+            builder.add_high_water_mark(x);
         }
-        if let Some(x) = args.args {
-            builder.add_args(x);
+        if let Some(x) = args.pagination {
+            builder.add_pagination(x);
         }
-        if let Some(x) = args.command {
-            builder.add_command(x);
+        if let Some(x) = args.records_path {
+            builder.add_records_path(x);
         }
-        if let Some(x) = args.image {
-            builder.add_image(x);
+        if let Some(x) = args.headers {
+            builder.add_headers(x);
+        }
+        if let Some(x) = args.url {
+            builder.add_url(x);
         }
+        builder.add_pagination_type(args.pagination_type);
         builder.finish()
     }
 
     #[inline]
-    pub fn image(&self) -> Option<&'a str> {
+    pub fn url(&self) -> Option<&'a str> {
         // Safety:
         // Created from valid Table for this object
         // which contains a valid value in this slot
         unsafe {
             self._tab
-                .get::<flatbuffers::ForwardsUOffset<&str>>(FetchStepContainer::VT_IMAGE, None)
+                .get::<flatbuffers::ForwardsUOffset<&str>>(FetchStepHttpPaginated::VT_URL, None)
         }
     }
     #[inline]
-    pub fn command(
+    pub fn headers(
         &self,
-    ) -> Option<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<&'a str>>> {
+    ) -> Option<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<RequestHeader<'a>>>> {
         // Safety:
         // Created from valid Table for this object
         // which contains a valid value in this slot
         unsafe {
             self._tab.get::<flatbuffers::ForwardsUOffset<
-                flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<&'a str>>,
-            >>(FetchStepContainer::VT_COMMAND, None)
+                flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<RequestHeader>>,
+            >>(FetchStepHttpPaginated::VT_HEADERS, None)
         }
     }
     #[inline]
-    pub fn args(&self) -> Option<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<&'a str>>> {
+    pub fn records_path(&self) -> Option<&'a str> {
         // Safety:
         // Created from valid Table for this object
         // which contains a valid value in this slot
         unsafe {
-            self._tab.get::<flatbuffers::ForwardsUOffset<
-                flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<&'a str>>,
-            >>(FetchStepContainer::VT_ARGS, None)
+            self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(
+                FetchStepHttpPaginated::VT_RECORDS_PATH,
+                None,
+            )
         }
     }
     #[inline]
-    pub fn env(&self) -> Option<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<EnvVar<'a>>>> {
+    pub fn pagination_type(&self) -> HttpPagination {
         // Safety:
         // Created from valid Table for this object
         // which contains a valid value in this slot
         unsafe {
-            self._tab.get::<flatbuffers::ForwardsUOffset<
-                flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<EnvVar>>,
-            >>(FetchStepContainer::VT_ENV, None)
+            self._tab
+                .get::<HttpPagination>(
+                    FetchStepHttpPaginated::VT_PAGINATION_TYPE,
+                    Some(HttpPagination::NONE),
+                )
+                .unwrap()
+        }
+    }
+    #[inline]
+    pub fn pagination(&self) -> Option<flatbuffers::Table<'a>> {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab
+                .get::<flatbuffers::ForwardsUOffset<flatbuffers::Table<'a>>>(
+                    FetchStepHttpPaginated::VT_PAGINATION,
+                    None,
+                )
+        }
+    }
+    #[inline]
+    pub fn high_water_mark(&self) -> Option<HttpHighWaterMark<'a>> {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab
+                .get::<flatbuffers::ForwardsUOffset<HttpHighWaterMark>>(
+                    FetchStepHttpPaginated::VT_HIGH_WATER_MARK,
+                    None,
+                )
+        }
+    }
+    #[inline]
+    #[allow(non_snake_case)]
+    pub fn pagination_as_http_pagination_link_header(
+        &self,
+    ) -> Option<HttpPaginationLinkHeader<'a>> {
+        if self.pagination_type() == HttpPagination::HttpPaginationLinkHeader {
+            self.pagination().map(|t| {
+                // Safety:
+                // Created from a valid Table for this object
+                // Which contains a valid union in this slot
+                unsafe { HttpPaginationLinkHeader::init_from_table(t) }
+            })
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    #[allow(non_snake_case)]
+    pub fn pagination_as_http_pagination_body_cursor(
+        &self,
+    ) -> Option<HttpPaginationBodyCursor<'a>> {
+        if self.pagination_type() == HttpPagination::HttpPaginationBodyCursor {
+            self.pagination().map(|t| {
+                // Safety:
+                // Created from a valid Table for this object
+                // Which contains a valid union in this slot
+                unsafe { HttpPaginationBodyCursor::init_from_table(t) }
+            })
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    #[allow(non_snake_case)]
+    pub fn pagination_as_http_pagination_offset_limit(
+        &self,
+    ) -> Option<HttpPaginationOffsetLimit<'a>> {
+        if self.pagination_type() == HttpPagination::HttpPaginationOffsetLimit {
+            self.pagination().map(|t| {
+                // Safety:
+                // Created from a valid Table for this object
+                // Which contains a valid union in this slot
+                unsafe { HttpPaginationOffsetLimit::init_from_table(t) }
+            })
+        } else {
+            None
         }
     }
 }
 
-impl flatbuffers::Verifiable for FetchStepContainer<'_> {
+impl flatbuffers::Verifiable for FetchStepHttpPaginated<'_> {
     #[inline]
     fn run_verifier(
         v: &mut flatbuffers::Verifier,
@@ -7811,120 +9342,179 @@ impl flatbuffers::Verifiable for FetchStepContainer<'_> {
     ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
         use self::flatbuffers::Verifiable;
         v.visit_table(pos)?
-            .visit_field::<flatbuffers::ForwardsUOffset<&str>>("image", Self::VT_IMAGE, false)?
-            .visit_field::<flatbuffers::ForwardsUOffset<
-                flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<&'_ str>>,
-            >>("command", Self::VT_COMMAND, false)?
-            .visit_field::<flatbuffers::ForwardsUOffset<
-                flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<&'_ str>>,
-            >>("args", Self::VT_ARGS, false)?
-            .visit_field::<flatbuffers::ForwardsUOffset<
-                flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<EnvVar>>,
-            >>("env", Self::VT_ENV, false)?
-            .finish();
+     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("url", Self::VT_URL, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<RequestHeader>>>>("headers", Self::VT_HEADERS, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("records_path", Self::VT_RECORDS_PATH, false)?
+     .visit_union::<HttpPagination, _>("pagination_type", Self::VT_PAGINATION_TYPE, "pagination", Self::VT_PAGINATION, false, |key, v, pos| {
+        match key {
+          HttpPagination::HttpPaginationLinkHeader => v.verify_union_variant::<flatbuffers::ForwardsUOffset<HttpPaginationLinkHeader>>("HttpPagination::HttpPaginationLinkHeader", pos),
+          HttpPagination::HttpPaginationBodyCursor => v.verify_union_variant::<flatbuffers::ForwardsUOffset<HttpPaginationBodyCursor>>("HttpPagination::HttpPaginationBodyCursor", pos),
+          HttpPagination::HttpPaginationOffsetLimit => v.verify_union_variant::<flatbuffers::ForwardsUOffset<HttpPaginationOffsetLimit>>("HttpPagination::HttpPaginationOffsetLimit", pos),
+          _ => Ok(()),
+        }
+     })?
+     .visit_field::<flatbuffers::ForwardsUOffset<HttpHighWaterMark>>("high_water_mark", Self::VT_HIGH_WATER_MARK, false)?
+     .finish();
         Ok(())
     }
 }
-pub struct FetchStepContainerArgs<'a> {
-    pub image: Option<flatbuffers::WIPOffset<&'a str>>,
-    pub command: Option<
-        flatbuffers::WIPOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<&'a str>>>,
-    >,
-    pub args: Option<
-        flatbuffers::WIPOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<&'a str>>>,
-    >,
-    pub env: Option<
-        flatbuffers::WIPOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<EnvVar<'a>>>>,
+pub struct FetchStepHttpPaginatedArgs<'a> {
+    pub url: Option<flatbuffers::WIPOffset<&'a str>>,
+    pub headers: Option<
+        flatbuffers::WIPOffset<
+            flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<RequestHeader<'a>>>,
+        >,
     >,
+    pub records_path: Option<flatbuffers::WIPOffset<&'a str>>,
+    pub pagination_type: HttpPagination,
+    pub pagination: Option<flatbuffers::WIPOffset<flatbuffers::UnionWIPOffset>>,
+    pub high_water_mark: Option<flatbuffers::WIPOffset<HttpHighWaterMark<'a>>>,
 }
-impl<'a> Default for FetchStepContainerArgs<'a> {
+impl<'a> Default for FetchStepHttpPaginatedArgs<'a> {
     #[inline]
     fn default() -> Self {
-        FetchStepContainerArgs {
-            image: None,
-            command: None,
-            args: None,
-            env: None,
+        FetchStepHttpPaginatedArgs {
+            url: None,
+            headers: None,
+            records_path: None,
+            pagination_type: HttpPagination::NONE,
+            pagination: None,
+            high_water_mark: None,
         }
     }
 }
 
-pub struct FetchStepContainerBuilder<'a: 'b, 'b> {
+pub struct FetchStepHttpPaginatedBuilder<'a: 'b, 'b> {
     fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
     start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
 }
-impl<'a: 'b, 'b> FetchStepContainerBuilder<'a, 'b> {
+impl<'a: 'b, 'b> FetchStepHttpPaginatedBuilder<'a, 'b> {
     #[inline]
-    pub fn add_image(&mut self, image: flatbuffers::WIPOffset<&'b str>) {
+    pub fn add_url(&mut self, url: flatbuffers::WIPOffset<&'b str>) {
         self.fbb_
-            .push_slot_always::<flatbuffers::WIPOffset<_>>(FetchStepContainer::VT_IMAGE, image);
+            .push_slot_always::<flatbuffers::WIPOffset<_>>(FetchStepHttpPaginated::VT_URL, url);
     }
     #[inline]
-    pub fn add_command(
+    pub fn add_headers(
         &mut self,
-        command: flatbuffers::WIPOffset<
-            flatbuffers::Vector<'b, flatbuffers::ForwardsUOffset<&'b str>>,
+        headers: flatbuffers::WIPOffset<
+            flatbuffers::Vector<'b, flatbuffers::ForwardsUOffset<RequestHeader<'b>>>,
         >,
     ) {
-        self.fbb_
-            .push_slot_always::<flatbuffers::WIPOffset<_>>(FetchStepContainer::VT_COMMAND, command);
+        self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(
+            FetchStepHttpPaginated::VT_HEADERS,
+            headers,
+        );
     }
     #[inline]
-    pub fn add_args(
+    pub fn add_records_path(&mut self, records_path: flatbuffers::WIPOffset<&'b str>) {
+        self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(
+            FetchStepHttpPaginated::VT_RECORDS_PATH,
+            records_path,
+        );
+    }
+    #[inline]
+    pub fn add_pagination_type(&mut self, pagination_type: HttpPagination) {
+        self.fbb_.push_slot::<HttpPagination>(
+            FetchStepHttpPaginated::VT_PAGINATION_TYPE,
+            pagination_type,
+            HttpPagination::NONE,
+        );
+    }
+    #[inline]
+    pub fn add_pagination(
         &mut self,
-        args: flatbuffers::WIPOffset<
-            flatbuffers::Vector<'b, flatbuffers::ForwardsUOffset<&'b str>>,
-        >,
+        pagination: flatbuffers::WIPOffset<flatbuffers::UnionWIPOffset>,
     ) {
-        self.fbb_
-            .push_slot_always::<flatbuffers::WIPOffset<_>>(FetchStepContainer::VT_ARGS, args);
+        self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(
+            FetchStepHttpPaginated::VT_PAGINATION,
+            pagination,
+        );
     }
     #[inline]
-    pub fn add_env(
+    pub fn add_high_water_mark(
         &mut self,
-        env: flatbuffers::WIPOffset<
-            flatbuffers::Vector<'b, flatbuffers::ForwardsUOffset<EnvVar<'b>>>,
-        >,
+        high_water_mark: flatbuffers::WIPOffset<HttpHighWaterMark<'b>>,
     ) {
         self.fbb_
-            .push_slot_always::<flatbuffers::WIPOffset<_>>(FetchStepContainer::VT_ENV, env);
+            .push_slot_always::<flatbuffers::WIPOffset<HttpHighWaterMark>>(
+                FetchStepHttpPaginated::VT_HIGH_WATER_MARK,
+                high_water_mark,
+            );
     }
     #[inline]
     pub fn new(
         _fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>,
-    ) -> FetchStepContainerBuilder<'a, 'b> {
+    ) -> FetchStepHttpPaginatedBuilder<'a, 'b> {
         let start = _fbb.start_table();
-        FetchStepContainerBuilder {
+        FetchStepHttpPaginatedBuilder {
             fbb_: _fbb,
             start_: start,
         }
     }
     #[inline]
-    pub fn finish(self) -> flatbuffers::WIPOffset<FetchStepContainer<'a>> {
+    pub fn finish(self) -> flatbuffers::WIPOffset<FetchStepHttpPaginated<'a>> {
         let o = self.fbb_.end_table(self.start_);
         flatbuffers::WIPOffset::new(o.value())
     }
 }
 
-impl core::fmt::Debug for FetchStepContainer<'_> {
+impl core::fmt::Debug for FetchStepHttpPaginated<'_> {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-        let mut ds = f.debug_struct("FetchStepContainer");
-        ds.field("image", &self.image());
-        ds.field("command", &self.command());
-        ds.field("args", &self.args());
-        ds.field("env", &self.env());
+        let mut ds = f.debug_struct("FetchStepHttpPaginated");
+        ds.field("url", &self.url());
+        ds.field("headers", &self.headers());
+        ds.field("records_path", &self.records_path());
+        ds.field("pagination_type", &self.pagination_type());
+        match self.pagination_type() {
+            HttpPagination::HttpPaginationLinkHeader => {
+                if let Some(x) = self.pagination_as_http_pagination_link_header() {
+                    ds.field("pagination", &x)
+                } else {
+                    ds.field(
+                        "pagination",
+                        &"InvalidFlatbuffer: Union discriminant does not match value.",
+                    )
+                }
+            }
+            HttpPagination::HttpPaginationBodyCursor => {
+                if let Some(x) = self.pagination_as_http_pagination_body_cursor() {
+                    ds.field("pagination", &x)
+                } else {
+                    ds.field(
+                        "pagination",
+                        &"InvalidFlatbuffer: Union discriminant does not match value.",
+                    )
+                }
+            }
+            HttpPagination::HttpPaginationOffsetLimit => {
+                if let Some(x) = self.pagination_as_http_pagination_offset_limit() {
+                    ds.field("pagination", &x)
+                } else {
+                    ds.field(
+                        "pagination",
+                        &"InvalidFlatbuffer: Union discriminant does not match value.",
+                    )
+                }
+            }
+            _ => {
+                let x: Option<()> = None;
+                ds.field("pagination", &x)
+            }
+        };
+        ds.field("high_water_mark", &self.high_water_mark());
         ds.finish()
     }
 }
-pub enum FetchStepMqttOffset {}
+pub enum FetchStepEthereumBlocksOffset {}
 #[derive(Copy, Clone, PartialEq)]
 
-pub struct FetchStepMqtt<'a> {
+pub struct FetchStepEthereumBlocks<'a> {
     pub _tab: flatbuffers::Table<'a>,
 }
 
-impl<'a> flatbuffers::Follow<'a> for FetchStepMqtt<'a> {
-    type Inner = FetchStepMqtt<'a>;
+impl<'a> flatbuffers::Follow<'a> for FetchStepEthereumBlocks<'a> {
+    type Inner = FetchStepEthereumBlocks<'a>;
     #[inline]
     unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
         Self {
@@ -7933,97 +9523,82 @@ impl<'a> flatbuffers::Follow<'a> for FetchStepMqtt<'a> {
     }
 }
 
-impl<'a> FetchStepMqtt<'a> {
-    pub const VT_HOST: flatbuffers::VOffsetT = 4;
-    pub const VT_PORT: flatbuffers::VOffsetT = 6;
-    pub const VT_USERNAME: flatbuffers::VOffsetT = 8;
-    pub const VT_PASSWORD: flatbuffers::VOffsetT = 10;
-    pub const VT_TOPICS: flatbuffers::VOffsetT = 12;
+impl<'a> FetchStepEthereumBlocks<'a> {
+    pub const VT_CHAIN_ID: flatbuffers::VOffsetT = 4;
+    pub const VT_NODE_URL: flatbuffers::VOffsetT = 6;
+    pub const VT_FROM_BLOCK: flatbuffers::VOffsetT = 8;
+    pub const VT_TO_BLOCK: flatbuffers::VOffsetT = 10;
 
     #[inline]
     pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
-        FetchStepMqtt { _tab: table }
+        FetchStepEthereumBlocks { _tab: table }
     }
     #[allow(unused_mut)]
     pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
         _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
-        args: &'args FetchStepMqttArgs<'args>,
-    ) -> flatbuffers::WIPOffset<FetchStepMqtt<'bldr>> {
-        let mut builder = FetchStepMqttBuilder::new(_fbb);
-        if let Some(x) = args.topics {
-            builder.add_topics(x);
+        args: &'args FetchStepEthereumBlocksArgs<'args>,
+    ) -> flatbuffers::WIPOffset<FetchStepEthereumBlocks<'bldr>> {
+        let mut builder = FetchStepEthereumBlocksBuilder::new(_fbb);
+        if let Some(x) = args.to_block {
+            builder.add_to_block(x);
         }
-        if let Some(x) = args.password {
-            builder.add_password(x);
+        if let Some(x) = args.from_block {
+            builder.add_from_block(x);
         }
-        if let Some(x) = args.username {
-            builder.add_username(x);
+        if let Some(x) = args.chain_id {
+            builder.add_chain_id(x);
         }
-        builder.add_port(args.port);
-        if let Some(x) = args.host {
-            builder.add_host(x);
+        if let Some(x) = args.node_url {
+            builder.add_node_url(x);
         }
         builder.finish()
     }
 
     #[inline]
-    pub fn host(&self) -> Option<&'a str> {
+    pub fn chain_id(&self) -> Option<u64> {
         // Safety:
         // Created from valid Table for this object
         // which contains a valid value in this slot
         unsafe {
             self._tab
-                .get::<flatbuffers::ForwardsUOffset<&str>>(FetchStepMqtt::VT_HOST, None)
+                .get::<u64>(FetchStepEthereumBlocks::VT_CHAIN_ID, None)
         }
     }
     #[inline]
-    pub fn port(&self) -> i32 {
+    pub fn node_url(&self) -> Option<&'a str> {
         // Safety:
         // Created from valid Table for this object
         // which contains a valid value in this slot
         unsafe {
-            self._tab
-                .get::<i32>(FetchStepMqtt::VT_PORT, Some(0))
-                .unwrap()
+            self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(
+                FetchStepEthereumBlocks::VT_NODE_URL,
+                None,
+            )
         }
     }
     #[inline]
-    pub fn username(&self) -> Option<&'a str> {
+    pub fn from_block(&self) -> Option<u64> {
         // Safety:
         // Created from valid Table for this object
         // which contains a valid value in this slot
         unsafe {
             self._tab
-                .get::<flatbuffers::ForwardsUOffset<&str>>(FetchStepMqtt::VT_USERNAME, None)
+                .get::<u64>(FetchStepEthereumBlocks::VT_FROM_BLOCK, None)
         }
     }
     #[inline]
-    pub fn password(&self) -> Option<&'a str> {
+    pub fn to_block(&self) -> Option<u64> {
         // Safety:
         // Created from valid Table for this object
         // which contains a valid value in this slot
         unsafe {
             self._tab
-                .get::<flatbuffers::ForwardsUOffset<&str>>(FetchStepMqtt::VT_PASSWORD, None)
-        }
-    }
-    #[inline]
-    pub fn topics(
-        &self,
-    ) -> Option<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<MqttTopicSubscription<'a>>>>
-    {
-        // Safety:
-        // Created from valid Table for this object
-        // which contains a valid value in this slot
-        unsafe {
-            self._tab.get::<flatbuffers::ForwardsUOffset<
-                flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<MqttTopicSubscription>>,
-            >>(FetchStepMqtt::VT_TOPICS, None)
+                .get::<u64>(FetchStepEthereumBlocks::VT_TO_BLOCK, None)
         }
     }
 }
 
-impl flatbuffers::Verifiable for FetchStepMqtt<'_> {
+impl flatbuffers::Verifiable for FetchStepEthereumBlocks<'_> {
     #[inline]
     fn run_verifier(
         v: &mut flatbuffers::Verifier,
@@ -8031,118 +9606,99 @@ impl flatbuffers::Verifiable for FetchStepMqtt<'_> {
     ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
         use self::flatbuffers::Verifiable;
         v.visit_table(pos)?
-            .visit_field::<flatbuffers::ForwardsUOffset<&str>>("host", Self::VT_HOST, false)?
-            .visit_field::<i32>("port", Self::VT_PORT, false)?
-            .visit_field::<flatbuffers::ForwardsUOffset<&str>>(
-                "username",
-                Self::VT_USERNAME,
-                false,
-            )?
+            .visit_field::<u64>("chain_id", Self::VT_CHAIN_ID, false)?
             .visit_field::<flatbuffers::ForwardsUOffset<&str>>(
-                "password",
-                Self::VT_PASSWORD,
+                "node_url",
+                Self::VT_NODE_URL,
                 false,
             )?
-            .visit_field::<flatbuffers::ForwardsUOffset<
-                flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<MqttTopicSubscription>>,
-            >>("topics", Self::VT_TOPICS, false)?
+            .visit_field::<u64>("from_block", Self::VT_FROM_BLOCK, false)?
+            .visit_field::<u64>("to_block", Self::VT_TO_BLOCK, false)?
             .finish();
         Ok(())
     }
 }
-pub struct FetchStepMqttArgs<'a> {
-    pub host: Option<flatbuffers::WIPOffset<&'a str>>,
-    pub port: i32,
-    pub username: Option<flatbuffers::WIPOffset<&'a str>>,
-    pub password: Option<flatbuffers::WIPOffset<&'a str>>,
-    pub topics: Option<
-        flatbuffers::WIPOffset<
-            flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<MqttTopicSubscription<'a>>>,
-        >,
-    >,
+pub struct FetchStepEthereumBlocksArgs<'a> {
+    pub chain_id: Option<u64>,
+    pub node_url: Option<flatbuffers::WIPOffset<&'a str>>,
+    pub from_block: Option<u64>,
+    pub to_block: Option<u64>,
 }
-impl<'a> Default for FetchStepMqttArgs<'a> {
+impl<'a> Default for FetchStepEthereumBlocksArgs<'a> {
     #[inline]
     fn default() -> Self {
-        FetchStepMqttArgs {
-            host: None,
-            port: 0,
-            username: None,
-            password: None,
-            topics: None,
+        FetchStepEthereumBlocksArgs {
+            chain_id: None,
+            node_url: None,
+            from_block: None,
+            to_block: None,
         }
     }
 }
 
-pub struct FetchStepMqttBuilder<'a: 'b, 'b> {
+pub struct FetchStepEthereumBlocksBuilder<'a: 'b, 'b> {
     fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
     start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
 }
-impl<'a: 'b, 'b> FetchStepMqttBuilder<'a, 'b> {
+impl<'a: 'b, 'b> FetchStepEthereumBlocksBuilder<'a, 'b> {
     #[inline]
-    pub fn add_host(&mut self, host: flatbuffers::WIPOffset<&'b str>) {
+    pub fn add_chain_id(&mut self, chain_id: u64) {
         self.fbb_
-            .push_slot_always::<flatbuffers::WIPOffset<_>>(FetchStepMqtt::VT_HOST, host);
-    }
-    #[inline]
-    pub fn add_port(&mut self, port: i32) {
-        self.fbb_.push_slot::<i32>(FetchStepMqtt::VT_PORT, port, 0);
+            .push_slot_always::<u64>(FetchStepEthereumBlocks::VT_CHAIN_ID, chain_id);
     }
     #[inline]
-    pub fn add_username(&mut self, username: flatbuffers::WIPOffset<&'b str>) {
-        self.fbb_
-            .push_slot_always::<flatbuffers::WIPOffset<_>>(FetchStepMqtt::VT_USERNAME, username);
+    pub fn add_node_url(&mut self, node_url: flatbuffers::WIPOffset<&'b str>) {
+        self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(
+            FetchStepEthereumBlocks::VT_NODE_URL,
+            node_url,
+        );
     }
     #[inline]
-    pub fn add_password(&mut self, password: flatbuffers::WIPOffset<&'b str>) {
+    pub fn add_from_block(&mut self, from_block: u64) {
         self.fbb_
-            .push_slot_always::<flatbuffers::WIPOffset<_>>(FetchStepMqtt::VT_PASSWORD, password);
+            .push_slot_always::<u64>(FetchStepEthereumBlocks::VT_FROM_BLOCK, from_block);
     }
     #[inline]
-    pub fn add_topics(
-        &mut self,
-        topics: flatbuffers::WIPOffset<
-            flatbuffers::Vector<'b, flatbuffers::ForwardsUOffset<MqttTopicSubscription<'b>>>,
-        >,
-    ) {
+    pub fn add_to_block(&mut self, to_block: u64) {
         self.fbb_
-            .push_slot_always::<flatbuffers::WIPOffset<_>>(FetchStepMqtt::VT_TOPICS, topics);
+            .push_slot_always::<u64>(FetchStepEthereumBlocks::VT_TO_BLOCK, to_block);
     }
     #[inline]
-    pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> FetchStepMqttBuilder<'a, 'b> {
+    pub fn new(
+        _fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+    ) -> FetchStepEthereumBlocksBuilder<'a, 'b> {
         let start = _fbb.start_table();
-        FetchStepMqttBuilder {
+        FetchStepEthereumBlocksBuilder {
             fbb_: _fbb,
             start_: start,
         }
     }
     #[inline]
-    pub fn finish(self) -> flatbuffers::WIPOffset<FetchStepMqtt<'a>> {
+    pub fn finish(self) -> flatbuffers::WIPOffset<FetchStepEthereumBlocks<'a>> {
         let o = self.fbb_.end_table(self.start_);
         flatbuffers::WIPOffset::new(o.value())
     }
 }
 
-impl core::fmt::Debug for FetchStepMqtt<'_> {
+impl core::fmt::Debug for FetchStepEthereumBlocks<'_> {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-        let mut ds = f.debug_struct("FetchStepMqtt");
-        ds.field("host", &self.host());
-        ds.field("port", &self.port());
-        ds.field("username", &self.username());
-        ds.field("password", &self.password());
-        ds.field("topics", &self.topics());
+        let mut ds = f.debug_struct("FetchStepEthereumBlocks");
+        ds.field("chain_id", &self.chain_id());
+        ds.field("node_url", &self.node_url());
+        ds.field("from_block", &self.from_block());
+        ds.field("to_block", &self.to_block());
         ds.finish()
     }
 }
-pub enum FetchStepEthereumLogsOffset {}
+pub enum FetchStepEthereumTransactionsOffset {}
 #[derive(Copy, Clone, PartialEq)]
 
-pub struct FetchStepEthereumLogs<'a> {
+pub struct FetchStepEthereumTransactions<'a> {
     pub _tab: flatbuffers::Table<'a>,
 }
 
-impl<'a> flatbuffers::Follow<'a> for FetchStepEthereumLogs<'a> {
-    type Inner = FetchStepEthereumLogs<'a>;
+impl<'a> flatbuffers::Follow<'a> for FetchStepEthereumTransactions<'a> {
+    type Inner = FetchStepEthereumTransactions<'a>;
     #[inline]
     unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
         Self {
@@ -8151,30 +9707,38 @@ impl<'a> flatbuffers::Follow<'a> for FetchStepEthereumLogs<'a> {
     }
 }
 
-impl<'a> FetchStepEthereumLogs<'a> {
+impl<'a> FetchStepEthereumTransactions<'a> {
     pub const VT_CHAIN_ID: flatbuffers::VOffsetT = 4;
     pub const VT_NODE_URL: flatbuffers::VOffsetT = 6;
-    pub const VT_FILTER: flatbuffers::VOffsetT = 8;
-    pub const VT_SIGNATURE: flatbuffers::VOffsetT = 10;
+    pub const VT_FROM_BLOCK: flatbuffers::VOffsetT = 8;
+    pub const VT_TO_BLOCK: flatbuffers::VOffsetT = 10;
+    pub const VT_TO_ADDRESS: flatbuffers::VOffsetT = 12;
+    pub const VT_SIGNATURE: flatbuffers::VOffsetT = 14;
 
     #[inline]
     pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
-        FetchStepEthereumLogs { _tab: table }
+        FetchStepEthereumTransactions { _tab: table }
     }
     #[allow(unused_mut)]
     pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
         _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
-        args: &'args FetchStepEthereumLogsArgs<'args>,
-    ) -> flatbuffers::WIPOffset<FetchStepEthereumLogs<'bldr>> {
-        let mut builder = FetchStepEthereumLogsBuilder::new(_fbb);
+        args: &'args FetchStepEthereumTransactionsArgs<'args>,
+    ) -> flatbuffers::WIPOffset<FetchStepEthereumTransactions<'bldr>> {
+        let mut builder = FetchStepEthereumTransactionsBuilder::new(_fbb);
+        if let Some(x) = args.to_block {
+            builder.add_to_block(x);
+        }
+        if let Some(x) = args.from_block {
+            builder.add_from_block(x);
+        }
         if let Some(x) = args.chain_id {
             builder.add_chain_id(x);
         }
         if let Some(x) = args.signature {
             builder.add_signature(x);
         }
-        if let Some(x) = args.filter {
-            builder.add_filter(x);
+        if let Some(x) = args.to_address {
+            builder.add_to_address(x);
         }
         if let Some(x) = args.node_url {
             builder.add_node_url(x);
@@ -8189,27 +9753,51 @@ impl<'a> FetchStepEthereumLogs<'a> {
         // which contains a valid value in this slot
         unsafe {
             self._tab
-                .get::<u64>(FetchStepEthereumLogs::VT_CHAIN_ID, None)
+                .get::<u64>(FetchStepEthereumTransactions::VT_CHAIN_ID, None)
         }
     }
     #[inline]
     pub fn node_url(&self) -> Option<&'a str> {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(
+                FetchStepEthereumTransactions::VT_NODE_URL,
+                None,
+            )
+        }
+    }
+    #[inline]
+    pub fn from_block(&self) -> Option<u64> {
         // Safety:
         // Created from valid Table for this object
         // which contains a valid value in this slot
         unsafe {
             self._tab
-                .get::<flatbuffers::ForwardsUOffset<&str>>(FetchStepEthereumLogs::VT_NODE_URL, None)
+                .get::<u64>(FetchStepEthereumTransactions::VT_FROM_BLOCK, None)
         }
     }
     #[inline]
-    pub fn filter(&self) -> Option<&'a str> {
+    pub fn to_block(&self) -> Option<u64> {
         // Safety:
         // Created from valid Table for this object
         // which contains a valid value in this slot
         unsafe {
             self._tab
-                .get::<flatbuffers::ForwardsUOffset<&str>>(FetchStepEthereumLogs::VT_FILTER, None)
+                .get::<u64>(FetchStepEthereumTransactions::VT_TO_BLOCK, None)
+        }
+    }
+    #[inline]
+    pub fn to_address(&self) -> Option<&'a str> {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(
+                FetchStepEthereumTransactions::VT_TO_ADDRESS,
+                None,
+            )
         }
     }
     #[inline]
@@ -8219,14 +9807,14 @@ impl<'a> FetchStepEthereumLogs<'a> {
         // which contains a valid value in this slot
         unsafe {
             self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(
-                FetchStepEthereumLogs::VT_SIGNATURE,
+                FetchStepEthereumTransactions::VT_SIGNATURE,
                 None,
             )
         }
     }
 }
 
-impl flatbuffers::Verifiable for FetchStepEthereumLogs<'_> {
+impl flatbuffers::Verifiable for FetchStepEthereumTransactions<'_> {
     #[inline]
     fn run_verifier(
         v: &mut flatbuffers::Verifier,
@@ -8240,7 +9828,13 @@ impl flatbuffers::Verifiable for FetchStepEthereumLogs<'_> {
                 Self::VT_NODE_URL,
                 false,
             )?
-            .visit_field::<flatbuffers::ForwardsUOffset<&str>>("filter", Self::VT_FILTER, false)?
+            .visit_field::<u64>("from_block", Self::VT_FROM_BLOCK, false)?
+            .visit_field::<u64>("to_block", Self::VT_TO_BLOCK, false)?
+            .visit_field::<flatbuffers::ForwardsUOffset<&str>>(
+                "to_address",
+                Self::VT_TO_ADDRESS,
+                false,
+            )?
             .visit_field::<flatbuffers::ForwardsUOffset<&str>>(
                 "signature",
                 Self::VT_SIGNATURE,
@@ -8250,78 +9844,94 @@ impl flatbuffers::Verifiable for FetchStepEthereumLogs<'_> {
         Ok(())
     }
 }
-pub struct FetchStepEthereumLogsArgs<'a> {
+pub struct FetchStepEthereumTransactionsArgs<'a> {
     pub chain_id: Option<u64>,
     pub node_url: Option<flatbuffers::WIPOffset<&'a str>>,
-    pub filter: Option<flatbuffers::WIPOffset<&'a str>>,
+    pub from_block: Option<u64>,
+    pub to_block: Option<u64>,
+    pub to_address: Option<flatbuffers::WIPOffset<&'a str>>,
     pub signature: Option<flatbuffers::WIPOffset<&'a str>>,
 }
-impl<'a> Default for FetchStepEthereumLogsArgs<'a> {
+impl<'a> Default for FetchStepEthereumTransactionsArgs<'a> {
     #[inline]
     fn default() -> Self {
-        FetchStepEthereumLogsArgs {
+        FetchStepEthereumTransactionsArgs {
             chain_id: None,
             node_url: None,
-            filter: None,
+            from_block: None,
+            to_block: None,
+            to_address: None,
             signature: None,
         }
     }
 }
 
-pub struct FetchStepEthereumLogsBuilder<'a: 'b, 'b> {
+pub struct FetchStepEthereumTransactionsBuilder<'a: 'b, 'b> {
     fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
     start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
 }
-impl<'a: 'b, 'b> FetchStepEthereumLogsBuilder<'a, 'b> {
+impl<'a: 'b, 'b> FetchStepEthereumTransactionsBuilder<'a, 'b> {
     #[inline]
     pub fn add_chain_id(&mut self, chain_id: u64) {
         self.fbb_
-            .push_slot_always::<u64>(FetchStepEthereumLogs::VT_CHAIN_ID, chain_id);
+            .push_slot_always::<u64>(FetchStepEthereumTransactions::VT_CHAIN_ID, chain_id);
     }
     #[inline]
     pub fn add_node_url(&mut self, node_url: flatbuffers::WIPOffset<&'b str>) {
         self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(
-            FetchStepEthereumLogs::VT_NODE_URL,
+            FetchStepEthereumTransactions::VT_NODE_URL,
             node_url,
         );
     }
     #[inline]
-    pub fn add_filter(&mut self, filter: flatbuffers::WIPOffset<&'b str>) {
+    pub fn add_from_block(&mut self, from_block: u64) {
+        self.fbb_
+            .push_slot_always::<u64>(FetchStepEthereumTransactions::VT_FROM_BLOCK, from_block);
+    }
+    #[inline]
+    pub fn add_to_block(&mut self, to_block: u64) {
+        self.fbb_
+            .push_slot_always::<u64>(FetchStepEthereumTransactions::VT_TO_BLOCK, to_block);
+    }
+    #[inline]
+    pub fn add_to_address(&mut self, to_address: flatbuffers::WIPOffset<&'b str>) {
         self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(
-            FetchStepEthereumLogs::VT_FILTER,
-            filter,
+            FetchStepEthereumTransactions::VT_TO_ADDRESS,
+            to_address,
         );
     }
     #[inline]
     pub fn add_signature(&mut self, signature: flatbuffers::WIPOffset<&'b str>) {
         self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(
-            FetchStepEthereumLogs::VT_SIGNATURE,
+            FetchStepEthereumTransactions::VT_SIGNATURE,
             signature,
         );
     }
     #[inline]
     pub fn new(
         _fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>,
-    ) -> FetchStepEthereumLogsBuilder<'a, 'b> {
+    ) -> FetchStepEthereumTransactionsBuilder<'a, 'b> {
         let start = _fbb.start_table();
-        FetchStepEthereumLogsBuilder {
+        FetchStepEthereumTransactionsBuilder {
             fbb_: _fbb,
             start_: start,
         }
     }
     #[inline]
-    pub fn finish(self) -> flatbuffers::WIPOffset<FetchStepEthereumLogs<'a>> {
+    pub fn finish(self) -> flatbuffers::WIPOffset<FetchStepEthereumTransactions<'a>> {
         let o = self.fbb_.end_table(self.start_);
         flatbuffers::WIPOffset::new(o.value())
     }
 }
 
-impl core::fmt::Debug for FetchStepEthereumLogs<'_> {
+impl core::fmt::Debug for FetchStepEthereumTransactions<'_> {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-        let mut ds = f.debug_struct("FetchStepEthereumLogs");
+        let mut ds = f.debug_struct("FetchStepEthereumTransactions");
         ds.field("chain_id", &self.chain_id());
         ds.field("node_url", &self.node_url());
-        ds.field("filter", &self.filter());
+        ds.field("from_block", &self.from_block());
+        ds.field("to_block", &self.to_block());
+        ds.field("to_address", &self.to_address());
         ds.field("signature", &self.signature());
         ds.finish()
     }
@@ -9029,6 +10639,68 @@ impl<'a> SetPollingSource<'a> {
         }
     }
 
+    #[inline]
+    #[allow(non_snake_case)]
+    pub fn fetch_as_fetch_step_sql(&self) -> Option<FetchStepSql<'a>> {
+        if self.fetch_type() == FetchStep::FetchStepSql {
+            self.fetch().map(|t| {
+                // Safety:
+                // Created from a valid Table for this object
+                // Which contains a valid union in this slot
+                unsafe { FetchStepSql::init_from_table(t) }
+            })
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    #[allow(non_snake_case)]
+    pub fn fetch_as_fetch_step_http_paginated(&self) -> Option<FetchStepHttpPaginated<'a>> {
+        if self.fetch_type() == FetchStep::FetchStepHttpPaginated {
+            self.fetch().map(|t| {
+                // Safety:
+                // Created from a valid Table for this object
+                // Which contains a valid union in this slot
+                unsafe { FetchStepHttpPaginated::init_from_table(t) }
+            })
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    #[allow(non_snake_case)]
+    pub fn fetch_as_fetch_step_ethereum_blocks(&self) -> Option<FetchStepEthereumBlocks<'a>> {
+        if self.fetch_type() == FetchStep::FetchStepEthereumBlocks {
+            self.fetch().map(|t| {
+                // Safety:
+                // Created from a valid Table for this object
+                // Which contains a valid union in this slot
+                unsafe { FetchStepEthereumBlocks::init_from_table(t) }
+            })
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    #[allow(non_snake_case)]
+    pub fn fetch_as_fetch_step_ethereum_transactions(
+        &self,
+    ) -> Option<FetchStepEthereumTransactions<'a>> {
+        if self.fetch_type() == FetchStep::FetchStepEthereumTransactions {
+            self.fetch().map(|t| {
+                // Safety:
+                // Created from a valid Table for this object
+                // Which contains a valid union in this slot
+                unsafe { FetchStepEthereumTransactions::init_from_table(t) }
+            })
+        } else {
+            None
+        }
+    }
+
     #[inline]
     #[allow(non_snake_case)]
     pub fn read_as_read_step_csv(&self) -> Option<ReadStepCsv<'a>> {
@@ -9210,6 +10882,10 @@ impl flatbuffers::Verifiable for SetPollingSource<'_> {
           FetchStep::FetchStepContainer => v.verify_union_variant::<flatbuffers::ForwardsUOffset<FetchStepContainer>>("FetchStep::FetchStepContainer", pos),
           FetchStep::FetchStepMqtt => v.verify_union_variant::<flatbuffers::ForwardsUOffset<FetchStepMqtt>>("FetchStep::FetchStepMqtt", pos),
           FetchStep::FetchStepEthereumLogs => v.verify_union_variant::<flatbuffers::ForwardsUOffset<FetchStepEthereumLogs>>("FetchStep::FetchStepEthereumLogs", pos),
+          FetchStep::FetchStepSql => v.verify_union_variant::<flatbuffers::ForwardsUOffset<FetchStepSql>>("FetchStep::FetchStepSql", pos),
+          FetchStep::FetchStepHttpPaginated => v.verify_union_variant::<flatbuffers::ForwardsUOffset<FetchStepHttpPaginated>>("FetchStep::FetchStepHttpPaginated", pos),
+          FetchStep::FetchStepEthereumBlocks => v.verify_union_variant::<flatbuffers::ForwardsUOffset<FetchStepEthereumBlocks>>("FetchStep::FetchStepEthereumBlocks", pos),
+          FetchStep::FetchStepEthereumTransactions => v.verify_union_variant::<flatbuffers::ForwardsUOffset<FetchStepEthereumTransactions>>("FetchStep::FetchStepEthereumTransactions", pos),
           _ => Ok(()),
         }
      })?
@@ -9417,6 +11093,46 @@ impl core::fmt::Debug for SetPollingSource<'_> {
                     )
                 }
             }
+            FetchStep::FetchStepSql => {
+                if let Some(x) = self.fetch_as_fetch_step_sql() {
+                    ds.field("fetch", &x)
+                } else {
+                    ds.field(
+                        "fetch",
+                        &"InvalidFlatbuffer: Union discriminant does not match value.",
+                    )
+                }
+            }
+            FetchStep::FetchStepHttpPaginated => {
+                if let Some(x) = self.fetch_as_fetch_step_http_paginated() {
+                    ds.field("fetch", &x)
+                } else {
+                    ds.field(
+                        "fetch",
+                        &"InvalidFlatbuffer: Union discriminant does not match value.",
+                    )
+                }
+            }
+            FetchStep::FetchStepEthereumBlocks => {
+                if let Some(x) = self.fetch_as_fetch_step_ethereum_blocks() {
+                    ds.field("fetch", &x)
+                } else {
+                    ds.field(
+                        "fetch",
+                        &"InvalidFlatbuffer: Union discriminant does not match value.",
+                    )
+                }
+            }
+            FetchStep::FetchStepEthereumTransactions => {
+                if let Some(x) = self.fetch_as_fetch_step_ethereum_transactions() {
+                    ds.field("fetch", &x)
+                } else {
+                    ds.field(
+                        "fetch",
+                        &"InvalidFlatbuffer: Union discriminant does not match value.",
+                    )
+                }
+            }
             _ => {
                 let x: Option<()> = None;
                 ds.field("fetch", &x)
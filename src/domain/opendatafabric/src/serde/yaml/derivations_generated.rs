@@ -435,6 +435,16 @@ pub enum FetchStepDef {
     Mqtt(#[serde_as(as = "FetchStepMqttDef")] FetchStepMqtt),
     #[serde(alias = "ethereumLogs", alias = "ethereumlogs")]
     EthereumLogs(#[serde_as(as = "FetchStepEthereumLogsDef")] FetchStepEthereumLogs),
+    #[serde(alias = "sql")]
+    Sql(#[serde_as(as = "FetchStepSqlDef")] FetchStepSql),
+    #[serde(alias = "httpPaginated", alias = "httppaginated")]
+    HttpPaginated(#[serde_as(as = "FetchStepHttpPaginatedDef")] FetchStepHttpPaginated),
+    #[serde(alias = "ethereumBlocks", alias = "ethereumblocks")]
+    EthereumBlocks(#[serde_as(as = "FetchStepEthereumBlocksDef")] FetchStepEthereumBlocks),
+    #[serde(alias = "ethereumTransactions", alias = "ethereumtransactions")]
+    EthereumTransactions(
+        #[serde_as(as = "FetchStepEthereumTransactionsDef")] FetchStepEthereumTransactions,
+    ),
 }
 
 implement_serde_as!(FetchStep, FetchStepDef, "FetchStepDef");
@@ -455,6 +465,22 @@ implement_serde_as!(
     FetchStepEthereumLogsDef,
     "FetchStepEthereumLogsDef"
 );
+implement_serde_as!(FetchStepSql, FetchStepSqlDef, "FetchStepSqlDef");
+implement_serde_as!(
+    FetchStepHttpPaginated,
+    FetchStepHttpPaginatedDef,
+    "FetchStepHttpPaginatedDef"
+);
+implement_serde_as!(
+    FetchStepEthereumBlocks,
+    FetchStepEthereumBlocksDef,
+    "FetchStepEthereumBlocksDef"
+);
+implement_serde_as!(
+    FetchStepEthereumTransactions,
+    FetchStepEthereumTransactionsDef,
+    "FetchStepEthereumTransactionsDef"
+);
 
 #[serde_as]
 #[skip_serializing_none]
@@ -532,6 +558,61 @@ pub struct FetchStepEthereumLogsDef {
     pub signature: Option<String>,
 }
 
+#[serde_as]
+#[skip_serializing_none]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(remote = "FetchStepSql")]
+#[serde(deny_unknown_fields, rename_all = "camelCase")]
+pub struct FetchStepSqlDef {
+    pub url: String,
+    pub query: String,
+    pub cursor_column: Option<String>,
+}
+
+#[serde_as]
+#[skip_serializing_none]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(remote = "FetchStepHttpPaginated")]
+#[serde(deny_unknown_fields, rename_all = "camelCase")]
+pub struct FetchStepHttpPaginatedDef {
+    pub url: String,
+    #[serde_as(as = "Option<Vec<RequestHeaderDef>>")]
+    #[serde(default)]
+    pub headers: Option<Vec<RequestHeader>>,
+    pub records_path: Option<String>,
+    #[serde_as(as = "HttpPaginationDef")]
+    pub pagination: HttpPagination,
+    #[serde_as(as = "Option<HttpHighWaterMarkDef>")]
+    #[serde(default)]
+    pub high_water_mark: Option<HttpHighWaterMark>,
+}
+
+#[serde_as]
+#[skip_serializing_none]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(remote = "FetchStepEthereumBlocks")]
+#[serde(deny_unknown_fields, rename_all = "camelCase")]
+pub struct FetchStepEthereumBlocksDef {
+    pub chain_id: Option<u64>,
+    pub node_url: Option<String>,
+    pub from_block: Option<u64>,
+    pub to_block: Option<u64>,
+}
+
+#[serde_as]
+#[skip_serializing_none]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(remote = "FetchStepEthereumTransactions")]
+#[serde(deny_unknown_fields, rename_all = "camelCase")]
+pub struct FetchStepEthereumTransactionsDef {
+    pub chain_id: Option<u64>,
+    pub node_url: Option<String>,
+    pub from_block: Option<u64>,
+    pub to_block: Option<u64>,
+    pub to_address: Option<String>,
+    pub signature: Option<String>,
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(remote = "SourceOrdering")]
 #[serde(deny_unknown_fields)]
@@ -544,6 +625,88 @@ pub enum SourceOrderingDef {
 
 implement_serde_as!(SourceOrdering, SourceOrderingDef, "SourceOrderingDef");
 
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// HttpHighWaterMark
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[serde_as]
+#[skip_serializing_none]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(remote = "HttpHighWaterMark")]
+#[serde(deny_unknown_fields, rename_all = "camelCase")]
+pub struct HttpHighWaterMarkDef {
+    pub field: String,
+    pub param: String,
+}
+
+implement_serde_as!(
+    HttpHighWaterMark,
+    HttpHighWaterMarkDef,
+    "HttpHighWaterMarkDef"
+);
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// HttpPagination
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[serde_as]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(remote = "HttpPagination")]
+#[serde(deny_unknown_fields, tag = "kind")]
+pub enum HttpPaginationDef {
+    #[serde(alias = "linkHeader", alias = "linkheader")]
+    LinkHeader(#[serde_as(as = "HttpPaginationLinkHeaderDef")] HttpPaginationLinkHeader),
+    #[serde(alias = "bodyCursor", alias = "bodycursor")]
+    BodyCursor(#[serde_as(as = "HttpPaginationBodyCursorDef")] HttpPaginationBodyCursor),
+    #[serde(alias = "offsetLimit", alias = "offsetlimit")]
+    OffsetLimit(#[serde_as(as = "HttpPaginationOffsetLimitDef")] HttpPaginationOffsetLimit),
+}
+
+implement_serde_as!(HttpPagination, HttpPaginationDef, "HttpPaginationDef");
+implement_serde_as!(
+    HttpPaginationLinkHeader,
+    HttpPaginationLinkHeaderDef,
+    "HttpPaginationLinkHeaderDef"
+);
+implement_serde_as!(
+    HttpPaginationBodyCursor,
+    HttpPaginationBodyCursorDef,
+    "HttpPaginationBodyCursorDef"
+);
+implement_serde_as!(
+    HttpPaginationOffsetLimit,
+    HttpPaginationOffsetLimitDef,
+    "HttpPaginationOffsetLimitDef"
+);
+
+#[serde_as]
+#[skip_serializing_none]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(remote = "HttpPaginationLinkHeader")]
+#[serde(deny_unknown_fields, rename_all = "camelCase")]
+pub struct HttpPaginationLinkHeaderDef {}
+
+#[serde_as]
+#[skip_serializing_none]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(remote = "HttpPaginationBodyCursor")]
+#[serde(deny_unknown_fields, rename_all = "camelCase")]
+pub struct HttpPaginationBodyCursorDef {
+    pub cursor_path: String,
+    pub cursor_param: String,
+}
+
+#[serde_as]
+#[skip_serializing_none]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(remote = "HttpPaginationOffsetLimit")]
+#[serde(deny_unknown_fields, rename_all = "camelCase")]
+pub struct HttpPaginationOffsetLimitDef {
+    pub offset_param: String,
+    pub limit_param: String,
+    pub page_size: u64,
+}
+
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
 // MergeStrategy
 // https://github.com/kamu-data/open-data-fabric/blob/master/open-data-fabric.md#mergestrategy-schema
@@ -777,6 +940,16 @@ pub enum CompressionFormatDef {
     Gzip,
     #[serde(alias = "zip")]
     Zip,
+    #[serde(alias = "zstd")]
+    Zstd,
+    #[serde(alias = "xz")]
+    Xz,
+    #[serde(alias = "bzip2")]
+    Bzip2,
+    #[serde(alias = "tar")]
+    Tar,
+    #[serde(alias = "auto")]
+    Auto,
 }
 
 implement_serde_as!(
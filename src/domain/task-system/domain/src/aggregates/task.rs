@@ -19,7 +19,12 @@ pub struct Task(Aggregate<TaskState, (dyn TaskSystemEventStore + 'static)>);
 
 impl Task {
     /// Creates a task with a pending `TaskCreated` event
-    pub fn new(now: DateTime<Utc>, task_id: TaskID, logical_plan: LogicalPlan) -> Self {
+    pub fn new(
+        now: DateTime<Utc>,
+        task_id: TaskID,
+        logical_plan: LogicalPlan,
+        trace_parent: Option<String>,
+    ) -> Self {
         Self(
             Aggregate::new(
                 task_id,
@@ -27,6 +32,7 @@ impl Task {
                     event_time: now,
                     task_id,
                     logical_plan,
+                    trace_parent,
                 },
             )
             .unwrap(),
@@ -8,6 +8,7 @@
 // by the Apache License, Version 2.0.
 
 use enum_variants::*;
+use kamu_core::CompactionDataLayout;
 use opendatafabric::{DatasetID, Multihash};
 use serde::{Deserialize, Serialize};
 
@@ -74,6 +75,8 @@ pub struct HardCompactionDataset {
     pub max_slice_size: Option<u64>,
     pub max_slice_records: Option<u64>,
     pub keep_metadata_only: bool,
+    #[serde(default)]
+    pub data_layout: CompactionDataLayout,
 }
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
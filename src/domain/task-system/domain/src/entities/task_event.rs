@@ -37,6 +37,9 @@ pub struct TaskEventCreated {
     pub event_time: DateTime<Utc>,
     pub task_id: TaskID,
     pub logical_plan: LogicalPlan,
+    /// W3C `traceparent` of the operation that created the task
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub trace_parent: Option<String>,
 }
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -25,6 +25,9 @@ pub struct TaskState {
     pub cancellation_requested: bool,
     /// Execution plan of the task
     pub logical_plan: LogicalPlan,
+    /// W3C `traceparent` of the operation that created the task, so that its
+    /// execution can be followed as part of the same trace
+    pub trace_parent: Option<String>,
 
     /// Time when task was originally created and placed in a queue
     pub created_at: DateTime<Utc>,
@@ -51,11 +54,13 @@ impl Projection for TaskState {
                     event_time,
                     task_id,
                     logical_plan,
+                    trace_parent,
                 }) => Ok(Self {
                     task_id,
                     status: TaskStatus::Queued,
                     cancellation_requested: false,
                     logical_plan,
+                    trace_parent,
                     created_at: event_time,
                     ran_at: None,
                     cancellation_requested_at: None,
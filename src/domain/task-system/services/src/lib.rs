@@ -11,7 +11,9 @@
 pub use kamu_task_system as domain;
 
 mod task_executor_impl;
+mod task_metrics;
 mod task_scheduler_impl;
 
 pub use task_executor_impl::*;
+pub use task_metrics::*;
 pub use task_scheduler_impl::*;
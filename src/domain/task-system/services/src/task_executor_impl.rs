@@ -16,6 +16,7 @@ use kamu_core::{
     CompactionOptions,
     CompactionService,
     DatasetRepository,
+    ExpectationsChecksCollector,
     PollingIngestOptions,
     PullError,
     PullOptions,
@@ -24,10 +25,13 @@ use kamu_core::{
     ResetService,
     TransformError,
 };
-use kamu_datasets::{DatasetEnvVar, DatasetEnvVarService};
+use kamu_datasets::{DatasetEnvVar, DatasetEnvVarService, DatasetExpectationService};
 use kamu_task_system::*;
 use messaging_outbox::{Outbox, OutboxExt};
 use time_source::SystemTimeSource;
+use tracing::Instrument as _;
+
+use crate::TaskMetrics;
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
 
@@ -35,6 +39,7 @@ pub struct TaskExecutorImpl {
     catalog: Catalog,
     task_sched: Arc<dyn TaskScheduler>,
     time_source: Arc<dyn SystemTimeSource>,
+    metrics: Option<Arc<TaskMetrics>>,
 }
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -47,11 +52,13 @@ impl TaskExecutorImpl {
         catalog: Catalog,
         task_sched: Arc<dyn TaskScheduler>,
         time_source: Arc<dyn SystemTimeSource>,
+        metrics: Option<Arc<TaskMetrics>>,
     ) -> Self {
         Self {
             catalog,
             task_sched,
             time_source,
+            metrics,
         }
     }
 
@@ -83,6 +90,8 @@ impl TaskExecutorImpl {
     }
 
     async fn execute_task(&self, task: &Task) -> Result<TaskOutcome, InternalError> {
+        let start = std::time::Instant::now();
+
         let task_outcome = match &task.logical_plan {
             LogicalPlan::UpdateDataset(upd) => self.update_dataset_logical_plan(upd).await?,
             LogicalPlan::Probe(Probe {
@@ -111,6 +120,10 @@ impl TaskExecutorImpl {
             "Task finished",
         );
 
+        if let Some(metrics) = &self.metrics {
+            metrics.observe_task_duration(&task.logical_plan, &task_outcome, start.elapsed());
+        }
+
         Ok(task_outcome)
     }
 
@@ -149,20 +162,24 @@ impl TaskExecutorImpl {
         &self,
         update_dataset_args: &UpdateDataset,
     ) -> Result<TaskOutcome, InternalError> {
-        let dataset_env_vars = DatabaseTransactionRunner::new(self.catalog.clone())
-            .transactional_with(
-                |dataset_env_vars_svc: Arc<dyn DatasetEnvVarService>| async move {
-                    let dataset_env_vars = dataset_env_vars_svc
-                        .get_all_dataset_env_vars_by_dataset_id(
-                            &update_dataset_args.dataset_id,
-                            None,
-                        )
-                        .await
-                        .int_err()?;
-                    Ok(dataset_env_vars.list)
-                },
-            )
-            .await?;
+        let (dataset_env_vars, dataset_expectations) = DatabaseTransactionRunner::new(
+            self.catalog.clone(),
+        )
+        .transactional_with2(
+            |dataset_env_vars_svc: Arc<dyn DatasetEnvVarService>,
+             dataset_expectation_svc: Arc<dyn DatasetExpectationService>| async move {
+                let dataset_env_vars = dataset_env_vars_svc
+                    .get_all_dataset_env_vars_by_dataset_id(&update_dataset_args.dataset_id, None)
+                    .await
+                    .int_err()?;
+                let dataset_expectations = dataset_expectation_svc
+                    .get_dataset_expectations(&update_dataset_args.dataset_id)
+                    .await
+                    .int_err()?;
+                Ok((dataset_env_vars.list, dataset_expectations))
+            },
+        )
+        .await?;
         let dataset_env_vars_hash_map = dataset_env_vars
             .into_iter()
             .map(|dataset_env_var| (dataset_env_var.key.clone(), dataset_env_var))
@@ -170,21 +187,45 @@ impl TaskExecutorImpl {
         let pull_options = PullOptions {
             ingest_options: PollingIngestOptions {
                 dataset_env_vars: dataset_env_vars_hash_map,
+                dataset_expectations: HashMap::from([(
+                    update_dataset_args.dataset_id.clone(),
+                    dataset_expectations,
+                )]),
                 fetch_uncacheable: update_dataset_args.fetch_uncacheable,
                 ..Default::default()
             },
             ..Default::default()
         };
 
+        let expectations_checks = Arc::new(ExpectationsChecksCollector::default());
+
         let pull_svc = self.catalog.get_one::<dyn PullService>().int_err()?;
         let maybe_pull_result = pull_svc
             .pull(
                 &update_dataset_args.dataset_id.as_any_ref(),
                 pull_options,
-                None,
+                Some(expectations_checks.clone()),
             )
             .await;
 
+        // Checks are recorded even when the ingest was rejected
+        let expectations_checks = expectations_checks.take();
+        if !expectations_checks.is_empty() {
+            DatabaseTransactionRunner::new(self.catalog.clone())
+                .transactional_with(
+                    |dataset_expectation_svc: Arc<dyn DatasetExpectationService>| async move {
+                        for (dataset_id, check) in expectations_checks {
+                            dataset_expectation_svc
+                                .record_expectations_check(&dataset_id, check)
+                                .await
+                                .int_err()?;
+                        }
+                        Ok(())
+                    },
+                )
+                .await?;
+        }
+
         match maybe_pull_result {
             Ok(pull_result) => Ok(TaskOutcome::Success(TaskResult::UpdateDatasetResult(
                 TaskUpdateDatasetResult { pull_result },
@@ -251,6 +292,7 @@ impl TaskExecutorImpl {
                     max_slice_size: hard_compaction_args.max_slice_size,
                     max_slice_records: hard_compaction_args.max_slice_records,
                     keep_metadata_only: hard_compaction_args.keep_metadata_only,
+                    data_layout: hard_compaction_args.data_layout.clone(),
                 },
                 None,
             )
@@ -273,8 +315,26 @@ impl TaskExecutor for TaskExecutorImpl {
     async fn run(&self) -> Result<(), InternalError> {
         loop {
             let task = self.take_task().await?;
-            let task_outcome = self.execute_task(&task).await?;
-            self.process_task_outcome(task, task_outcome).await?;
+
+            let task_span = tracing::info_span!(
+                "Running task",
+                task_id = %task.task_id,
+                otel.kind = "consumer",
+            );
+            if let Some(trace_parent) = task
+                .trace_parent
+                .as_deref()
+                .and_then(observability::TraceContext::from_traceparent)
+            {
+                observability::set_remote_parent(&task_span, trace_parent);
+            }
+
+            async {
+                let task_outcome = self.execute_task(&task).await?;
+                self.process_task_outcome(task, task_outcome).await
+            }
+            .instrument(task_span)
+            .await?;
         }
     }
 }
@@ -0,0 +1,80 @@
+// Copyright Kamu Data, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+use dill::*;
+use kamu_task_system::{LogicalPlan, TaskOutcome};
+use observability::*;
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+pub struct TaskMetrics {
+    pub queue_depth: Gauge,
+    pub task_duration_seconds: HistogramVec,
+}
+
+#[component(pub)]
+#[interface(dyn MetricsProvider)]
+#[scope(Singleton)]
+impl TaskMetrics {
+    pub fn new() -> Self {
+        Self {
+            queue_depth: Gauge::new(
+                "kamu_task_queue_depth",
+                "Number of tasks waiting to be picked up by the executor",
+            )
+            .unwrap(),
+            task_duration_seconds: HistogramVec::new(
+                HistogramOpts::new(
+                    "kamu_task_duration_seconds",
+                    "Duration of task execution by the kind of logical plan and outcome",
+                )
+                .buckets(DEFAULT_DURATION_BUCKETS.to_vec()),
+                &["logical_plan", "outcome"],
+            )
+            .unwrap(),
+        }
+    }
+
+    #[allow(clippy::cast_precision_loss)]
+    pub(crate) fn set_queue_depth(&self, queue_len: usize) {
+        self.queue_depth.set(queue_len as f64);
+    }
+
+    pub(crate) fn observe_task_duration(
+        &self,
+        logical_plan: &LogicalPlan,
+        outcome: &TaskOutcome,
+        duration: std::time::Duration,
+    ) {
+        let logical_plan = match logical_plan {
+            LogicalPlan::UpdateDataset(_) => "UpdateDataset",
+            LogicalPlan::Probe(_) => "Probe",
+            LogicalPlan::HardCompactionDataset(_) => "HardCompactionDataset",
+            LogicalPlan::Reset(_) => "Reset",
+        };
+        let outcome = match outcome {
+            TaskOutcome::Success(_) => "success",
+            TaskOutcome::Failed(_) => "failed",
+            TaskOutcome::Cancelled => "cancelled",
+        };
+
+        self.task_duration_seconds
+            .with_label_values(&[logical_plan, outcome])
+            .observe(duration.as_secs_f64());
+    }
+}
+
+impl MetricsProvider for TaskMetrics {
+    fn register(&self, registry: &Registry) -> Result<(), prometheus::Error> {
+        registry.register(Box::new(self.queue_depth.clone()))?;
+        registry.register(Box::new(self.task_duration_seconds.clone()))
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -15,6 +15,8 @@ use kamu_task_system::*;
 use opendatafabric::DatasetID;
 use time_source::SystemTimeSource;
 
+use crate::TaskMetrics;
+
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
 
 pub struct TaskSchedulerImpl {
@@ -22,6 +24,7 @@ pub struct TaskSchedulerImpl {
     // TODO: EventStore is transaction-dependent, it can't be instantiated in a singleton
     event_store: Arc<dyn TaskSystemEventStore>,
     time_source: Arc<dyn SystemTimeSource>,
+    metrics: Option<Arc<TaskMetrics>>,
 }
 
 #[derive(Default)]
@@ -39,11 +42,19 @@ impl TaskSchedulerImpl {
     pub fn new(
         event_store: Arc<dyn TaskSystemEventStore>,
         time_source: Arc<dyn SystemTimeSource>,
+        metrics: Option<Arc<TaskMetrics>>,
     ) -> Self {
         Self {
             state: Arc::new(Mutex::new(State::default())),
             event_store,
             time_source,
+            metrics,
+        }
+    }
+
+    fn on_queue_changed(&self, queue_len: usize) {
+        if let Some(metrics) = &self.metrics {
+            metrics.set_queue_depth(queue_len);
         }
     }
 }
@@ -54,10 +65,14 @@ impl TaskSchedulerImpl {
 impl TaskScheduler for TaskSchedulerImpl {
     #[tracing::instrument(level = "info", skip_all, fields(?logical_plan))]
     async fn create_task(&self, logical_plan: LogicalPlan) -> Result<TaskState, CreateTaskError> {
+        // Task execution will continue the trace of the operation that created it
+        let trace_parent = observability::current_trace_context().map(|ctx| ctx.to_traceparent());
+
         let mut task = Task::new(
             self.time_source.now(),
             self.event_store.new_task_id().await?,
             logical_plan,
+            trace_parent,
         );
         task.save(self.event_store.as_ref()).await.int_err()?;
 
@@ -66,6 +81,7 @@ impl TaskScheduler for TaskSchedulerImpl {
             state.task_queue.push_back(task.task_id);
             state.task_queue.len()
         };
+        self.on_queue_changed(queue_len);
 
         tracing::info!(
             task_id = %task.task_id,
@@ -90,8 +106,12 @@ impl TaskScheduler for TaskSchedulerImpl {
             task.cancel(self.time_source.now()).int_err()?;
             task.save(self.event_store.as_ref()).await.int_err()?;
 
-            let mut state = self.state.lock().unwrap();
-            state.task_queue.retain(|task_id| *task_id != task.task_id);
+            let queue_len = {
+                let mut state = self.state.lock().unwrap();
+                state.task_queue.retain(|task_id| *task_id != task.task_id);
+                state.task_queue.len()
+            };
+            self.on_queue_changed(queue_len);
         }
 
         Ok(task.into())
@@ -146,15 +166,17 @@ impl TaskScheduler for TaskSchedulerImpl {
 
     // TODO: How to prevent tasks from being lost if executor crashes
     async fn try_take(&self) -> Result<Option<TaskID>, TakeTaskError> {
-        let task_id = {
+        let (task_id, queue_len) = {
             let mut s = self.state.lock().unwrap();
-            s.task_queue.pop_front()
+            (s.task_queue.pop_front(), s.task_queue.len())
         };
 
         let Some(task_id) = task_id else {
             return Ok(None);
         };
 
+        self.on_queue_changed(queue_len);
+
         let mut task = Task::load(task_id, self.event_store.as_ref())
             .await
             .int_err()?;
@@ -23,6 +23,7 @@ async fn test_task_agg_create_new() {
         Utc::now(),
         event_store.new_task_id().await.unwrap(),
         Probe::default().into(),
+        None,
     );
 
     assert_eq!(event_store.len().await.unwrap(), 0);
@@ -45,7 +46,7 @@ async fn test_task_save_load_update() {
     let event_store = InMemoryTaskSystemEventStore::new();
     let task_id = event_store.new_task_id().await.unwrap();
 
-    let mut task = Task::new(Utc::now(), task_id, Probe::default().into());
+    let mut task = Task::new(Utc::now(), task_id, Probe::default().into(), None);
     task.save(&event_store).await.unwrap();
 
     task.run(Utc::now()).unwrap();
@@ -92,6 +93,7 @@ async fn test_task_agg_illegal_transition() {
         Utc::now(),
         event_store.new_task_id().await.unwrap(),
         Probe::default().into(),
+        None,
     );
     task.finish(Utc::now(), TaskOutcome::Cancelled).unwrap();
 
@@ -12,7 +12,7 @@ use std::sync::Arc;
 
 use kamu_task_system::{LogicalPlan, Probe, TaskScheduler, TaskState, TaskStatus};
 use kamu_task_system_inmem::InMemoryTaskSystemEventStore;
-use kamu_task_system_services::TaskSchedulerImpl;
+use kamu_task_system_services::{TaskMetrics, TaskSchedulerImpl};
 use time_source::SystemTimeSourceStub;
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -82,13 +82,45 @@ async fn test_task_cancellation() {
     assert_eq!(task_sched.try_take().await.unwrap(), None);
 }
 
+#[test_log::test(tokio::test)]
+async fn test_queue_depth_metric() {
+    let metrics = Arc::new(TaskMetrics::new());
+    let task_sched = TaskSchedulerImpl::new(
+        Arc::new(InMemoryTaskSystemEventStore::new()),
+        Arc::new(SystemTimeSourceStub::new()),
+        Some(metrics.clone()),
+    );
+    let queue_depth = || metrics.queue_depth.get();
+
+    let task_id_1 = task_sched
+        .create_task(Probe { ..Probe::default() }.into())
+        .await
+        .unwrap()
+        .task_id;
+    task_sched
+        .create_task(Probe { ..Probe::default() }.into())
+        .await
+        .unwrap();
+    task_sched
+        .create_task(Probe { ..Probe::default() }.into())
+        .await
+        .unwrap();
+    assert!((queue_depth() - 3.0).abs() < f64::EPSILON);
+
+    task_sched.cancel_task(task_id_1).await.unwrap();
+    assert!((queue_depth() - 2.0).abs() < f64::EPSILON);
+
+    task_sched.try_take().await.unwrap();
+    assert!((queue_depth() - 1.0).abs() < f64::EPSILON);
+}
+
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
 
 fn create_task_scheduler() -> impl TaskScheduler {
     let event_store = Arc::new(InMemoryTaskSystemEventStore::new());
     let time_source = Arc::new(SystemTimeSourceStub::new());
 
-    TaskSchedulerImpl::new(event_store, time_source)
+    TaskSchedulerImpl::new(event_store, time_source, None)
 }
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
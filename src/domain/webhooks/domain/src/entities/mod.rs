@@ -0,0 +1,20 @@
+// Copyright Kamu Data, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+mod webhook_delivery;
+mod webhook_event;
+mod webhook_secret;
+mod webhook_signature;
+mod webhook_subscription;
+
+pub use webhook_delivery::*;
+pub use webhook_event::*;
+pub use webhook_secret::*;
+pub use webhook_signature::*;
+pub use webhook_subscription::*;
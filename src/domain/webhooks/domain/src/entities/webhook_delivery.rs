@@ -0,0 +1,246 @@
+// Copyright Kamu Data, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+use chrono::{DateTime, Duration, Utc};
+use uuid::Uuid;
+
+use crate::{WebhookEvent, WebhookEventType, WebhookRetryPolicy};
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Delivery of a single event to a single subscription, which tracks all
+/// attempts made to send it
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WebhookDelivery {
+    pub id: Uuid,
+    pub subscription_id: Uuid,
+    pub event_id: Uuid,
+    pub event_type: WebhookEventType,
+    pub payload: serde_json::Value,
+    pub status: WebhookDeliveryStatus,
+    pub attempts: u32,
+    /// Only defined while the delivery is pending
+    pub next_attempt_at: Option<DateTime<Utc>>,
+    pub last_attempt_at: Option<DateTime<Utc>>,
+    pub last_response_code: Option<u16>,
+    pub last_error: Option<String>,
+    pub created_at: DateTime<Utc>,
+}
+
+impl WebhookDelivery {
+    pub fn new(subscription_id: Uuid, event: &WebhookEvent, created_at: DateTime<Utc>) -> Self {
+        Self {
+            id: Uuid::new_v4(),
+            subscription_id,
+            event_id: event.id,
+            event_type: event.event_type,
+            payload: event.to_payload(),
+            status: WebhookDeliveryStatus::Pending,
+            attempts: 0,
+            next_attempt_at: Some(created_at),
+            last_attempt_at: None,
+            last_response_code: None,
+            last_error: None,
+            created_at,
+        }
+    }
+
+    pub fn on_delivered(&mut self, attempted_at: DateTime<Utc>, response_code: u16) {
+        self.attempts += 1;
+        self.status = WebhookDeliveryStatus::Delivered;
+        self.next_attempt_at = None;
+        self.last_attempt_at = Some(attempted_at);
+        self.last_response_code = Some(response_code);
+        self.last_error = None;
+    }
+
+    /// Schedules a retry according to the policy, or moves the delivery to
+    /// the dead letter state when attempts are exhausted
+    pub fn on_failed(
+        &mut self,
+        attempted_at: DateTime<Utc>,
+        response_code: Option<u16>,
+        error: String,
+        retry_policy: &WebhookRetryPolicy,
+    ) {
+        self.attempts += 1;
+        self.last_attempt_at = Some(attempted_at);
+        self.last_response_code = response_code;
+        self.last_error = Some(error);
+
+        if self.attempts >= retry_policy.max_attempts {
+            self.status = WebhookDeliveryStatus::DeadLettered;
+            self.next_attempt_at = None;
+        } else {
+            self.next_attempt_at = Some(attempted_at + retry_policy.backoff(self.attempts));
+        }
+    }
+
+    /// Resets the attempt budget and schedules an immediate attempt
+    pub fn redeliver(&mut self, now: DateTime<Utc>) {
+        self.status = WebhookDeliveryStatus::Pending;
+        self.attempts = 0;
+        self.next_attempt_at = Some(now);
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "sqlx", derive(sqlx::Type))]
+#[cfg_attr(
+    feature = "sqlx",
+    sqlx(type_name = "webhook_delivery_status", rename_all = "snake_case")
+)]
+pub enum WebhookDeliveryStatus {
+    Pending,
+    Delivered,
+    DeadLettered,
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WebhookRetryPolicy {
+    pub max_attempts: u32,
+    pub min_backoff: Duration,
+    pub max_backoff: Duration,
+}
+
+impl WebhookRetryPolicy {
+    /// Exponential backoff: delay doubles after every failed attempt
+    pub fn backoff(&self, failed_attempts: u32) -> Duration {
+        let exponent = failed_attempts.saturating_sub(1).min(30);
+        self.min_backoff
+            .checked_mul(1 << exponent)
+            .unwrap_or(self.max_backoff)
+            .min(self.max_backoff)
+    }
+}
+
+impl Default for WebhookRetryPolicy {
+    fn default() -> Self {
+        Self {
+            max_attempts: 8,
+            min_backoff: Duration::try_seconds(10).unwrap(),
+            max_backoff: Duration::try_hours(1).unwrap(),
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(feature = "sqlx")]
+#[derive(Debug, Clone, sqlx::FromRow, PartialEq, Eq)]
+pub struct WebhookDeliveryRowModel {
+    pub id: Uuid,
+    pub subscription_id: Uuid,
+    pub event_id: Uuid,
+    pub event_type: String,
+    pub payload: String,
+    pub status: WebhookDeliveryStatus,
+    pub attempts: i32,
+    pub next_attempt_at: Option<DateTime<Utc>>,
+    pub last_attempt_at: Option<DateTime<Utc>>,
+    pub last_response_code: Option<i32>,
+    pub last_error: Option<String>,
+    pub created_at: DateTime<Utc>,
+}
+
+#[cfg(feature = "sqlx")]
+impl TryFrom<WebhookDeliveryRowModel> for WebhookDelivery {
+    type Error = internal_error::InternalError;
+
+    fn try_from(value: WebhookDeliveryRowModel) -> Result<Self, Self::Error> {
+        use internal_error::ResultIntoInternal;
+
+        Ok(WebhookDelivery {
+            id: value.id,
+            subscription_id: value.subscription_id,
+            event_id: value.event_id,
+            event_type: value.event_type.parse().int_err()?,
+            payload: serde_json::from_str(&value.payload).int_err()?,
+            status: value.status,
+            attempts: u32::try_from(value.attempts).int_err()?,
+            next_attempt_at: value.next_attempt_at,
+            last_attempt_at: value.last_attempt_at,
+            last_response_code: value
+                .last_response_code
+                .map(u16::try_from)
+                .transpose()
+                .int_err()?,
+            last_error: value.last_error,
+            created_at: value.created_at,
+        })
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use chrono::TimeZone;
+    use opendatafabric::DatasetID;
+
+    use super::*;
+
+    #[test]
+    fn test_retry_backoff_is_exponential_and_capped() {
+        let policy = WebhookRetryPolicy {
+            max_attempts: 10,
+            min_backoff: Duration::try_seconds(10).unwrap(),
+            max_backoff: Duration::try_minutes(1).unwrap(),
+        };
+
+        assert_eq!(policy.backoff(1), Duration::try_seconds(10).unwrap());
+        assert_eq!(policy.backoff(2), Duration::try_seconds(20).unwrap());
+        assert_eq!(policy.backoff(3), Duration::try_seconds(40).unwrap());
+        assert_eq!(policy.backoff(4), Duration::try_minutes(1).unwrap());
+        assert_eq!(policy.backoff(100), Duration::try_minutes(1).unwrap());
+    }
+
+    #[test]
+    fn test_delivery_dead_lettered_after_max_attempts() {
+        let policy = WebhookRetryPolicy {
+            max_attempts: 2,
+            ..WebhookRetryPolicy::default()
+        };
+        let now = Utc.with_ymd_and_hms(2050, 1, 1, 12, 0, 0).unwrap();
+        let event = WebhookEvent::new(
+            WebhookEventType::FlowFailed,
+            now,
+            DatasetID::new_seeded_ed25519(b"foo"),
+            serde_json::json!({}),
+        );
+
+        let mut delivery = WebhookDelivery::new(Uuid::new_v4(), &event, now);
+        assert_eq!(delivery.status, WebhookDeliveryStatus::Pending);
+        assert_eq!(delivery.next_attempt_at, Some(now));
+
+        delivery.on_failed(now, Some(500), "Server error".to_string(), &policy);
+        assert_eq!(delivery.status, WebhookDeliveryStatus::Pending);
+        assert_eq!(delivery.next_attempt_at, Some(now + policy.min_backoff));
+
+        delivery.on_failed(now, None, "Timeout".to_string(), &policy);
+        assert_eq!(delivery.status, WebhookDeliveryStatus::DeadLettered);
+        assert_eq!(delivery.next_attempt_at, None);
+        assert_eq!(delivery.attempts, 2);
+
+        delivery.redeliver(now);
+        assert_eq!(delivery.status, WebhookDeliveryStatus::Pending);
+        assert_eq!(delivery.attempts, 0);
+
+        delivery.on_delivered(now, 200);
+        assert_eq!(delivery.status, WebhookDeliveryStatus::Delivered);
+        assert_eq!(delivery.last_response_code, Some(200));
+        assert_eq!(delivery.last_error, None);
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -0,0 +1,112 @@
+// Copyright Kamu Data, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+use chrono::{DateTime, Utc};
+use opendatafabric::DatasetID;
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+use uuid::Uuid;
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+pub enum WebhookEventType {
+    #[serde(rename = "dataset.data_appended")]
+    DatasetDataAppended,
+    #[serde(rename = "dataset.deleted")]
+    DatasetDeleted,
+    #[serde(rename = "flow.succeeded")]
+    FlowSucceeded,
+    #[serde(rename = "flow.failed")]
+    FlowFailed,
+}
+
+impl WebhookEventType {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::DatasetDataAppended => "dataset.data_appended",
+            Self::DatasetDeleted => "dataset.deleted",
+            Self::FlowSucceeded => "flow.succeeded",
+            Self::FlowFailed => "flow.failed",
+        }
+    }
+}
+
+impl std::fmt::Display for WebhookEventType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl std::str::FromStr for WebhookEventType {
+    type Err = UnknownWebhookEventTypeError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "dataset.data_appended" => Ok(Self::DatasetDataAppended),
+            "dataset.deleted" => Ok(Self::DatasetDeleted),
+            "flow.succeeded" => Ok(Self::FlowSucceeded),
+            "flow.failed" => Ok(Self::FlowFailed),
+            _ => Err(UnknownWebhookEventTypeError {
+                event_type: s.to_string(),
+            }),
+        }
+    }
+}
+
+#[derive(Error, Debug)]
+#[error("Unknown webhook event type: '{event_type}'")]
+pub struct UnknownWebhookEventTypeError {
+    pub event_type: String,
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// A single occurrence of something subscribers may be notified about.
+/// Every subscription interested in the event receives its own delivery, but
+/// all deliveries share the event identifier, so receivers can deduplicate.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WebhookEvent {
+    pub id: Uuid,
+    pub event_type: WebhookEventType,
+    pub event_time: DateTime<Utc>,
+    pub dataset_id: DatasetID,
+    /// Event-specific attributes
+    pub data: serde_json::Value,
+}
+
+impl WebhookEvent {
+    pub fn new(
+        event_type: WebhookEventType,
+        event_time: DateTime<Utc>,
+        dataset_id: DatasetID,
+        data: serde_json::Value,
+    ) -> Self {
+        Self {
+            id: Uuid::new_v4(),
+            event_type,
+            event_time,
+            dataset_id,
+            data,
+        }
+    }
+
+    /// JSON document sent as a request body to the subscriber
+    pub fn to_payload(&self) -> serde_json::Value {
+        serde_json::json!({
+            "id": self.id.to_string(),
+            "type": self.event_type.as_str(),
+            "eventTime": self.event_time.to_rfc3339(),
+            "datasetId": self.dataset_id.to_string(),
+            "data": self.data,
+        })
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -0,0 +1,128 @@
+// Copyright Kamu Data, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+use aes_gcm::aead::{Aead, AeadCore, KeyInit, OsRng};
+use aes_gcm::{Aes256Gcm, Nonce};
+use thiserror::Error;
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+const NONCE_LENGTH: usize = 12;
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Encrypts the signing secret of a subscription with AES-256-GCM for storing
+/// it at rest. The result is the hex-encoded nonce followed by the ciphertext.
+///
+/// The key has the same format as the key of dataset secrets, which allows
+/// rotating both with `kamu system rotate-secrets-key`.
+pub fn encrypt_webhook_secret(
+    secret: &str,
+    encryption_key: &str,
+) -> Result<String, WebhookSecretEncryptionError> {
+    let cipher = cipher_from_key(encryption_key)?;
+    let nonce = Aes256Gcm::generate_nonce(&mut OsRng);
+
+    let ciphertext = cipher
+        .encrypt(&nonce, secret.as_bytes())
+        .map_err(|_| WebhookSecretEncryptionError::InvalidEncryptionKey)?;
+
+    let mut encrypted = nonce.to_vec();
+    encrypted.extend(ciphertext);
+    Ok(hex::encode(encrypted))
+}
+
+/// Tries the keys in order and returns the secret decrypted with the first one
+/// that fits, so that secrets encrypted before a key rotation remain readable
+pub fn decrypt_webhook_secret<'a>(
+    encrypted_secret: &str,
+    encryption_keys: impl IntoIterator<Item = &'a str>,
+) -> Result<String, WebhookSecretEncryptionError> {
+    let encrypted = hex::decode(encrypted_secret)
+        .ok()
+        .filter(|e| e.len() > NONCE_LENGTH)
+        .ok_or(WebhookSecretEncryptionError::MalformedSecret)?;
+    let (nonce, ciphertext) = encrypted.split_at(NONCE_LENGTH);
+
+    let mut has_keys = false;
+    for encryption_key in encryption_keys {
+        has_keys = true;
+
+        let Ok(cipher) = cipher_from_key(encryption_key) else {
+            continue;
+        };
+        if let Ok(secret) = cipher.decrypt(Nonce::from_slice(nonce), ciphertext) {
+            return String::from_utf8(secret)
+                .map_err(|_| WebhookSecretEncryptionError::MalformedSecret);
+        }
+    }
+
+    if has_keys {
+        Err(WebhookSecretEncryptionError::NoMatchingKey)
+    } else {
+        Err(WebhookSecretEncryptionError::KeyNotConfigured)
+    }
+}
+
+fn cipher_from_key(encryption_key: &str) -> Result<Aes256Gcm, WebhookSecretEncryptionError> {
+    Aes256Gcm::new_from_slice(encryption_key.as_bytes())
+        .map_err(|_| WebhookSecretEncryptionError::InvalidEncryptionKey)
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[derive(Error, Debug, PartialEq, Eq)]
+pub enum WebhookSecretEncryptionError {
+    #[error("Secrets encryption key is not configured")]
+    KeyNotConfigured,
+
+    #[error("Secrets encryption key must be 32 bytes long")]
+    InvalidEncryptionKey,
+
+    #[error("Webhook secret can't be decrypted with any of the configured keys")]
+    NoMatchingKey,
+
+    #[error("Stored webhook secret is malformed")]
+    MalformedSecret,
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const KEY: &str = "aBcDeFgHiJkLmNoPqRsTuVwXyZ012345";
+    const OTHER_KEY: &str = "012345aBcDeFgHiJkLmNoPqRsTuVwXyZ";
+
+    #[test]
+    fn test_secret_roundtrip_with_rotated_keys() {
+        let encrypted = encrypt_webhook_secret("my-secret", KEY).unwrap();
+        assert!(!encrypted.contains("my-secret"));
+
+        assert_eq!(
+            decrypt_webhook_secret(&encrypted, [OTHER_KEY, KEY]).unwrap(),
+            "my-secret"
+        );
+        assert_eq!(
+            decrypt_webhook_secret(&encrypted, [OTHER_KEY]),
+            Err(WebhookSecretEncryptionError::NoMatchingKey)
+        );
+        assert_eq!(
+            decrypt_webhook_secret(&encrypted, []),
+            Err(WebhookSecretEncryptionError::KeyNotConfigured)
+        );
+        assert_eq!(
+            encrypt_webhook_secret("my-secret", "short"),
+            Err(WebhookSecretEncryptionError::InvalidEncryptionKey)
+        );
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -0,0 +1,107 @@
+// Copyright Kamu Data, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+use hmac::{Hmac, Mac};
+use sha2::Sha256;
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+pub const HEADER_WEBHOOK_SIGNATURE: &str = "X-Kamu-Signature";
+pub const HEADER_WEBHOOK_TIMESTAMP: &str = "X-Kamu-Timestamp";
+pub const HEADER_WEBHOOK_EVENT: &str = "X-Kamu-Event";
+pub const HEADER_WEBHOOK_DELIVERY: &str = "X-Kamu-Delivery";
+
+const SIGNATURE_SCHEME_PREFIX: &str = "sha256=";
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Computes the value of the signature header.
+///
+/// The signature is an HMAC-SHA256 of `"{timestamp}.{body}"` keyed with the
+/// subscription secret. Including the timestamp allows receivers to reject
+/// replayed requests.
+pub fn sign_webhook_payload(secret: &str, timestamp: i64, body: &[u8]) -> String {
+    let mac = webhook_mac(secret, timestamp, body);
+
+    format!(
+        "{SIGNATURE_SCHEME_PREFIX}{}",
+        hex::encode(mac.finalize().into_bytes())
+    )
+}
+
+/// Receiver-side check of the signature header, in constant time
+pub fn verify_webhook_signature(
+    secret: &str,
+    timestamp: i64,
+    body: &[u8],
+    signature: &str,
+) -> bool {
+    let Some(signature_hex) = signature.strip_prefix(SIGNATURE_SCHEME_PREFIX) else {
+        return false;
+    };
+    let Ok(signature_bytes) = hex::decode(signature_hex) else {
+        return false;
+    };
+
+    webhook_mac(secret, timestamp, body)
+        .verify_slice(&signature_bytes)
+        .is_ok()
+}
+
+fn webhook_mac(secret: &str, timestamp: i64, body: &[u8]) -> Hmac<Sha256> {
+    let mut mac =
+        Hmac::<Sha256>::new_from_slice(secret.as_bytes()).expect("HMAC can take key of any size");
+    mac.update(timestamp.to_string().as_bytes());
+    mac.update(b".");
+    mac.update(body);
+    mac
+}
+
+////////
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sign_webhook_payload() {
+        let signature = sign_webhook_payload("secret", 1_700_000_000, br#"{"a":1}"#);
+        assert_eq!(
+            signature,
+            "sha256=49f24e537407743fa4a0242bb63b94b9a47ee99cbbe071ccd8a22550ae411686"
+        );
+
+        assert!(verify_webhook_signature(
+            "secret",
+            1_700_000_000,
+            br#"{"a":1}"#,
+            &signature
+        ));
+        assert!(!verify_webhook_signature(
+            "secret",
+            1_700_000_001,
+            br#"{"a":1}"#,
+            &signature
+        ));
+        assert!(!verify_webhook_signature(
+            "other-secret",
+            1_700_000_000,
+            br#"{"a":1}"#,
+            &signature
+        ));
+        assert!(!verify_webhook_signature(
+            "secret",
+            1_700_000_000,
+            br#"{"a":1}"#,
+            "md5=abc"
+        ));
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
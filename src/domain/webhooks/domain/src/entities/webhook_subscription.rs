@@ -0,0 +1,204 @@
+// Copyright Kamu Data, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+use chrono::{DateTime, Utc};
+use opendatafabric::{AccountID, DatasetID};
+use rand::RngCore;
+use thiserror::Error;
+use url::Url;
+use uuid::Uuid;
+
+use crate::{decrypt_webhook_secret, WebhookEventType, WebhookSecretEncryptionError};
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+const WEBHOOK_SECRET_LENGTH_BYTES: usize = 32;
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WebhookSubscription {
+    pub id: Uuid,
+    pub scope: WebhookSubscriptionScope,
+    pub target_url: Url,
+    pub label: String,
+    pub event_types: Vec<WebhookEventType>,
+    /// Shared secret used to sign payloads sent to the target, encrypted with
+    /// [`encrypt_webhook_secret`]
+    pub secret: String,
+    pub enabled: bool,
+    pub created_at: DateTime<Utc>,
+}
+
+impl WebhookSubscription {
+    pub fn new(
+        scope: WebhookSubscriptionScope,
+        target_url: Url,
+        label: String,
+        mut event_types: Vec<WebhookEventType>,
+        secret: String,
+        created_at: DateTime<Utc>,
+    ) -> Result<Self, InvalidWebhookSubscriptionError> {
+        event_types.sort();
+        event_types.dedup();
+
+        let subscription = Self {
+            id: Uuid::new_v4(),
+            scope,
+            target_url,
+            label,
+            event_types,
+            secret,
+            enabled: true,
+            created_at,
+        };
+        subscription.validate()?;
+
+        Ok(subscription)
+    }
+
+    pub fn validate(&self) -> Result<(), InvalidWebhookSubscriptionError> {
+        if !matches!(self.target_url.scheme(), "http" | "https") {
+            return Err(InvalidWebhookSubscriptionError {
+                reason: format!(
+                    "Unsupported target URL scheme '{}', expected http or https",
+                    self.target_url.scheme()
+                ),
+            });
+        }
+
+        if self.event_types.is_empty() {
+            return Err(InvalidWebhookSubscriptionError {
+                reason: "At least one event type must be specified".to_string(),
+            });
+        }
+
+        // Ownership of a dataset is forgotten as soon as it is deleted, so we
+        // cannot route deletion events to account-wide subscriptions
+        if let WebhookSubscriptionScope::Account(_) = &self.scope
+            && self.event_types.contains(&WebhookEventType::DatasetDeleted)
+        {
+            return Err(InvalidWebhookSubscriptionError {
+                reason: format!(
+                    "Event type '{}' can only be subscribed to per dataset",
+                    WebhookEventType::DatasetDeleted
+                ),
+            });
+        }
+
+        Ok(())
+    }
+
+    pub fn is_interested_in(&self, event_type: WebhookEventType) -> bool {
+        self.enabled && self.event_types.contains(&event_type)
+    }
+
+    /// Decrypts the signing secret with the first of the keys that fits
+    pub fn get_exposed_secret<'a>(
+        &self,
+        encryption_keys: impl IntoIterator<Item = &'a str>,
+    ) -> Result<String, WebhookSecretEncryptionError> {
+        decrypt_webhook_secret(&self.secret, encryption_keys)
+    }
+
+    /// Generates a random signing secret for a new subscription
+    pub fn generate_secret() -> String {
+        let mut secret = [0u8; WEBHOOK_SECRET_LENGTH_BYTES];
+        rand::thread_rng().fill_bytes(&mut secret);
+        hex::encode(secret)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum WebhookSubscriptionScope {
+    /// Events of a single dataset
+    Dataset(DatasetID),
+    /// Events of all datasets owned by the account
+    Account(AccountID),
+}
+
+impl WebhookSubscriptionScope {
+    pub fn dataset_id(&self) -> Option<&DatasetID> {
+        match self {
+            Self::Dataset(dataset_id) => Some(dataset_id),
+            Self::Account(_) => None,
+        }
+    }
+
+    pub fn account_id(&self) -> Option<&AccountID> {
+        match self {
+            Self::Dataset(_) => None,
+            Self::Account(account_id) => Some(account_id),
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(feature = "sqlx")]
+#[derive(Debug, Clone, sqlx::FromRow, PartialEq, Eq)]
+pub struct WebhookSubscriptionRowModel {
+    pub id: Uuid,
+    pub dataset_id: Option<String>,
+    pub account_id: Option<String>,
+    pub target_url: String,
+    pub label: String,
+    pub event_types: String,
+    pub secret: String,
+    pub enabled: bool,
+    pub created_at: DateTime<Utc>,
+}
+
+#[cfg(feature = "sqlx")]
+impl TryFrom<WebhookSubscriptionRowModel> for WebhookSubscription {
+    type Error = internal_error::InternalError;
+
+    fn try_from(value: WebhookSubscriptionRowModel) -> Result<Self, Self::Error> {
+        use internal_error::{ErrorIntoInternal, ResultIntoInternal};
+
+        let scope = match (value.dataset_id, value.account_id) {
+            (Some(dataset_id), None) => {
+                WebhookSubscriptionScope::Dataset(DatasetID::from_did_str(&dataset_id).int_err()?)
+            }
+            (None, Some(account_id)) => {
+                WebhookSubscriptionScope::Account(AccountID::from_did_str(&account_id).int_err()?)
+            }
+            _ => {
+                return Err(format!(
+                    "Webhook subscription {} must have exactly one of dataset or account scope",
+                    value.id
+                )
+                .int_err());
+            }
+        };
+
+        Ok(WebhookSubscription {
+            id: value.id,
+            scope,
+            target_url: Url::parse(&value.target_url).int_err()?,
+            label: value.label,
+            event_types: serde_json::from_str(&value.event_types).int_err()?,
+            secret: value.secret,
+            enabled: value.enabled,
+            created_at: value.created_at,
+        })
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[derive(Error, Debug)]
+#[error("Invalid webhook subscription: {reason}")]
+pub struct InvalidWebhookSubscriptionError {
+    pub reason: String,
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -0,0 +1,117 @@
+// Copyright Kamu Data, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+use chrono::{DateTime, Utc};
+use database_common::DatabasePaginationOpts;
+use internal_error::InternalError;
+use thiserror::Error;
+use uuid::Uuid;
+
+use crate::{WebhookDelivery, WebhookDeliveryStatus};
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[async_trait::async_trait]
+pub trait WebhookDeliveryRepository: Send + Sync {
+    async fn create_delivery(
+        &self,
+        delivery: &WebhookDelivery,
+    ) -> Result<(), CreateWebhookDeliveryError>;
+
+    async fn update_delivery(
+        &self,
+        delivery: &WebhookDelivery,
+    ) -> Result<(), UpdateWebhookDeliveryError>;
+
+    async fn get_delivery(
+        &self,
+        delivery_id: &Uuid,
+    ) -> Result<WebhookDelivery, GetWebhookDeliveryError>;
+
+    /// Returns pending deliveries scheduled at or before the specified time,
+    /// earliest first
+    async fn list_due_deliveries(
+        &self,
+        now: DateTime<Utc>,
+        limit: usize,
+    ) -> Result<Vec<WebhookDelivery>, ListWebhookDeliveriesError>;
+
+    async fn get_deliveries_count_by_subscription(
+        &self,
+        subscription_id: &Uuid,
+        maybe_status: Option<WebhookDeliveryStatus>,
+    ) -> Result<usize, ListWebhookDeliveriesError>;
+
+    /// Returns deliveries of the subscription, most recent first
+    async fn get_deliveries_by_subscription(
+        &self,
+        subscription_id: &Uuid,
+        maybe_status: Option<WebhookDeliveryStatus>,
+        pagination: &DatabasePaginationOpts,
+    ) -> Result<Vec<WebhookDelivery>, ListWebhookDeliveriesError>;
+
+    async fn delete_deliveries_by_subscription(
+        &self,
+        subscription_id: &Uuid,
+    ) -> Result<(), DeleteWebhookDeliveriesError>;
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[derive(Error, Debug)]
+pub enum CreateWebhookDeliveryError {
+    #[error(transparent)]
+    Internal(InternalError),
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[derive(Error, Debug)]
+pub enum UpdateWebhookDeliveryError {
+    #[error(transparent)]
+    NotFound(WebhookDeliveryNotFoundError),
+
+    #[error(transparent)]
+    Internal(InternalError),
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[derive(Error, Debug)]
+pub enum GetWebhookDeliveryError {
+    #[error(transparent)]
+    NotFound(WebhookDeliveryNotFoundError),
+
+    #[error(transparent)]
+    Internal(InternalError),
+}
+
+#[derive(Error, Debug)]
+#[error("Webhook delivery not found: '{delivery_id}'")]
+pub struct WebhookDeliveryNotFoundError {
+    pub delivery_id: Uuid,
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[derive(Error, Debug)]
+pub enum ListWebhookDeliveriesError {
+    #[error(transparent)]
+    Internal(InternalError),
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[derive(Error, Debug)]
+pub enum DeleteWebhookDeliveriesError {
+    #[error(transparent)]
+    Internal(InternalError),
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
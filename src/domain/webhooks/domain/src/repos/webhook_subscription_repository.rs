@@ -0,0 +1,112 @@
+// Copyright Kamu Data, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+use internal_error::InternalError;
+use thiserror::Error;
+use uuid::Uuid;
+
+use crate::{InvalidWebhookSubscriptionError, WebhookSubscription, WebhookSubscriptionScope};
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[async_trait::async_trait]
+pub trait WebhookSubscriptionRepository: Send + Sync {
+    async fn create_subscription(
+        &self,
+        subscription: &WebhookSubscription,
+    ) -> Result<(), CreateWebhookSubscriptionError>;
+
+    async fn get_subscription(
+        &self,
+        subscription_id: &Uuid,
+    ) -> Result<WebhookSubscription, GetWebhookSubscriptionError>;
+
+    /// Returns subscriptions ordered by creation time
+    async fn list_subscriptions_by_scope(
+        &self,
+        scope: &WebhookSubscriptionScope,
+    ) -> Result<Vec<WebhookSubscription>, ListWebhookSubscriptionsError>;
+
+    /// Returns subscriptions of all scopes ordered by creation time
+    async fn list_all_subscriptions(
+        &self,
+    ) -> Result<Vec<WebhookSubscription>, ListWebhookSubscriptionsError>;
+
+    /// Updates mutable attributes: target, label, event types, secret and
+    /// status
+    async fn update_subscription(
+        &self,
+        subscription: &WebhookSubscription,
+    ) -> Result<(), UpdateWebhookSubscriptionError>;
+
+    async fn delete_subscription(
+        &self,
+        subscription_id: &Uuid,
+    ) -> Result<(), DeleteWebhookSubscriptionError>;
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[derive(Error, Debug)]
+pub enum CreateWebhookSubscriptionError {
+    #[error(transparent)]
+    InvalidSubscription(InvalidWebhookSubscriptionError),
+
+    #[error(transparent)]
+    Internal(InternalError),
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[derive(Error, Debug)]
+pub enum GetWebhookSubscriptionError {
+    #[error(transparent)]
+    NotFound(WebhookSubscriptionNotFoundError),
+
+    #[error(transparent)]
+    Internal(InternalError),
+}
+
+#[derive(Error, Debug)]
+#[error("Webhook subscription not found: '{subscription_id}'")]
+pub struct WebhookSubscriptionNotFoundError {
+    pub subscription_id: Uuid,
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[derive(Error, Debug)]
+pub enum ListWebhookSubscriptionsError {
+    #[error(transparent)]
+    Internal(InternalError),
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[derive(Error, Debug)]
+pub enum UpdateWebhookSubscriptionError {
+    #[error(transparent)]
+    NotFound(WebhookSubscriptionNotFoundError),
+
+    #[error(transparent)]
+    Internal(InternalError),
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[derive(Error, Debug)]
+pub enum DeleteWebhookSubscriptionError {
+    #[error(transparent)]
+    NotFound(WebhookSubscriptionNotFoundError),
+
+    #[error(transparent)]
+    Internal(InternalError),
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
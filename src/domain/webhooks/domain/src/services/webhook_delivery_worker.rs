@@ -0,0 +1,24 @@
+// Copyright Kamu Data, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+use internal_error::InternalError;
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[async_trait::async_trait]
+pub trait WebhookDeliveryWorker: Sync + Send {
+    /// Runs the delivery main loop
+    async fn run(&self) -> Result<(), InternalError>;
+
+    /// Makes a single attempt for every delivery that is currently due,
+    /// returning the number of processed deliveries
+    async fn deliver_due(&self) -> Result<usize, InternalError>;
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
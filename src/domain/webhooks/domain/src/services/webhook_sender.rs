@@ -0,0 +1,54 @@
+// Copyright Kamu Data, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+use internal_error::InternalError;
+use thiserror::Error;
+use url::Url;
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Performs the HTTP request to the subscriber
+#[async_trait::async_trait]
+pub trait WebhookSender: Sync + Send {
+    async fn send(&self, request: WebhookRequest) -> Result<WebhookResponse, WebhookSendError>;
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WebhookRequest {
+    pub target_url: Url,
+    pub headers: Vec<(String, String)>,
+    pub body: Vec<u8>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WebhookResponse {
+    pub status_code: u16,
+}
+
+impl WebhookResponse {
+    pub fn is_success(&self) -> bool {
+        (200..300).contains(&self.status_code)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[derive(Error, Debug)]
+pub enum WebhookSendError {
+    /// Target was unreachable or did not respond in time
+    #[error("Failed to send webhook request: {reason}")]
+    Transport { reason: String },
+
+    #[error(transparent)]
+    Internal(InternalError),
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -0,0 +1,112 @@
+// Copyright Kamu Data, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+use database_common::DatabasePaginationOpts;
+use internal_error::InternalError;
+use thiserror::Error;
+use url::Url;
+use uuid::Uuid;
+
+use crate::{
+    CreateWebhookSubscriptionError,
+    DeleteWebhookSubscriptionError,
+    GetWebhookSubscriptionError,
+    ListWebhookDeliveriesError,
+    ListWebhookSubscriptionsError,
+    UpdateWebhookSubscriptionError,
+    WebhookDelivery,
+    WebhookDeliveryNotFoundError,
+    WebhookDeliveryStatus,
+    WebhookEventType,
+    WebhookSubscription,
+    WebhookSubscriptionScope,
+};
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[async_trait::async_trait]
+pub trait WebhookSubscriptionService: Sync + Send {
+    /// Validates and registers a new subscription with a freshly generated
+    /// signing secret, which is stored encrypted
+    async fn create_subscription(
+        &self,
+        scope: WebhookSubscriptionScope,
+        target_url: Url,
+        label: String,
+        event_types: Vec<WebhookEventType>,
+    ) -> Result<WebhookSubscription, CreateWebhookSubscriptionError>;
+
+    /// Re-encrypts the signing secrets of all subscriptions with the new key,
+    /// returning the number of affected subscriptions. Secrets are decrypted
+    /// with the current or any of the previous keys, secrets already encrypted
+    /// with the new key are left intact.
+    async fn rotate_secret_encryption_key(
+        &self,
+        new_encryption_key: &str,
+    ) -> Result<usize, InternalError>;
+
+    async fn get_subscription(
+        &self,
+        subscription_id: &Uuid,
+    ) -> Result<WebhookSubscription, GetWebhookSubscriptionError>;
+
+    async fn list_subscriptions(
+        &self,
+        scope: &WebhookSubscriptionScope,
+    ) -> Result<Vec<WebhookSubscription>, ListWebhookSubscriptionsError>;
+
+    /// Pauses or resumes notifications. Events occurring while the
+    /// subscription is disabled are not delivered later.
+    async fn set_subscription_enabled(
+        &self,
+        subscription_id: &Uuid,
+        enabled: bool,
+    ) -> Result<WebhookSubscription, UpdateWebhookSubscriptionError>;
+
+    /// Removes the subscription along with its delivery history
+    async fn delete_subscription(
+        &self,
+        subscription_id: &Uuid,
+    ) -> Result<(), DeleteWebhookSubscriptionError>;
+
+    async fn list_deliveries(
+        &self,
+        subscription_id: &Uuid,
+        maybe_status: Option<WebhookDeliveryStatus>,
+        pagination: Option<DatabasePaginationOpts>,
+    ) -> Result<WebhookDeliveryListing, ListWebhookDeliveriesError>;
+
+    /// Schedules another round of attempts for a delivery of the specified
+    /// subscription, typically one that was dead-lettered
+    async fn redeliver(
+        &self,
+        subscription_id: &Uuid,
+        delivery_id: &Uuid,
+    ) -> Result<WebhookDelivery, RedeliverWebhookDeliveryError>;
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+pub struct WebhookDeliveryListing {
+    pub list: Vec<WebhookDelivery>,
+    pub total_count: usize,
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[derive(Error, Debug)]
+pub enum RedeliverWebhookDeliveryError {
+    #[error(transparent)]
+    NotFound(WebhookDeliveryNotFoundError),
+
+    #[error(transparent)]
+    Internal(InternalError),
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
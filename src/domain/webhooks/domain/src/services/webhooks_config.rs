@@ -0,0 +1,61 @@
+// Copyright Kamu Data, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+use crate::WebhookRetryPolicy;
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[derive(Debug, Clone)]
+pub struct WebhooksConfig {
+    /// How often the delivery worker checks for due deliveries
+    pub delivery_polling_interval: chrono::Duration,
+    /// Maximal number of deliveries attempted in one iteration
+    pub delivery_batch_size: usize,
+    /// Maximal number of deliveries attempted at the same time
+    pub delivery_concurrency: usize,
+    /// Maximal time to wait for the subscriber to respond
+    pub request_timeout: chrono::Duration,
+    pub retry_policy: WebhookRetryPolicy,
+    /// Key used to encrypt signing secrets of subscriptions at rest
+    pub secret_encryption_key: Option<String>,
+    /// Keys replaced by `secret_encryption_key` during a rotation, secrets
+    /// encrypted with them remain readable
+    pub previous_secret_encryption_keys: Vec<String>,
+    /// Allows delivering to loopback, private and link-local addresses. Only
+    /// meant for local development, as it lets subscribers reach internal
+    /// services.
+    pub allow_private_targets: bool,
+}
+
+impl WebhooksConfig {
+    /// Keys to try when decrypting signing secrets, the current one first
+    pub fn secret_decryption_keys(&self) -> impl Iterator<Item = &str> {
+        self.secret_encryption_key
+            .iter()
+            .chain(&self.previous_secret_encryption_keys)
+            .map(String::as_str)
+    }
+}
+
+impl Default for WebhooksConfig {
+    fn default() -> Self {
+        Self {
+            delivery_polling_interval: chrono::Duration::try_seconds(5).unwrap(),
+            delivery_batch_size: 50,
+            delivery_concurrency: 10,
+            request_timeout: chrono::Duration::try_seconds(10).unwrap(),
+            retry_policy: WebhookRetryPolicy::default(),
+            secret_encryption_key: None,
+            previous_secret_encryption_keys: Vec::new(),
+            allow_private_targets: false,
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
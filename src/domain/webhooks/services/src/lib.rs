@@ -0,0 +1,25 @@
+// Copyright Kamu Data, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+#![feature(let_chains)]
+
+// Re-exports
+pub use kamu_webhooks as domain;
+
+mod messages;
+mod webhook_delivery_worker_impl;
+mod webhook_event_dispatcher;
+mod webhook_sender_http;
+mod webhook_subscription_service_impl;
+
+pub use messages::*;
+pub use webhook_delivery_worker_impl::*;
+pub use webhook_event_dispatcher::*;
+pub use webhook_sender_http::*;
+pub use webhook_subscription_service_impl::*;
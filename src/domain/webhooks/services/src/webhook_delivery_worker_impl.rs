@@ -0,0 +1,237 @@
+// Copyright Kamu Data, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+use std::sync::Arc;
+
+use database_common::DatabaseTransactionRunner;
+use dill::*;
+use futures::StreamExt;
+use internal_error::{InternalError, ResultIntoInternal};
+use kamu_webhooks::*;
+use time_source::SystemTimeSource;
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+pub struct WebhookDeliveryWorkerImpl {
+    catalog: Catalog,
+    sender: Arc<dyn WebhookSender>,
+    time_source: Arc<dyn SystemTimeSource>,
+    config: Arc<WebhooksConfig>,
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[component(pub)]
+#[interface(dyn WebhookDeliveryWorker)]
+#[scope(Singleton)]
+impl WebhookDeliveryWorkerImpl {
+    pub fn new(
+        catalog: Catalog,
+        sender: Arc<dyn WebhookSender>,
+        time_source: Arc<dyn SystemTimeSource>,
+        config: Arc<WebhooksConfig>,
+    ) -> Self {
+        Self {
+            catalog,
+            sender,
+            time_source,
+            config,
+        }
+    }
+
+    /// Loads due deliveries along with their subscriptions in a single short
+    /// transaction, so that no transaction is held while waiting on targets
+    async fn load_due_deliveries(
+        &self,
+    ) -> Result<Vec<(WebhookDelivery, WebhookSubscription)>, InternalError> {
+        let now = self.time_source.now();
+        let batch_size = self.config.delivery_batch_size;
+
+        DatabaseTransactionRunner::new(self.catalog.clone())
+            .transactional_with2(
+                |delivery_repo: Arc<dyn WebhookDeliveryRepository>,
+                 subscription_repo: Arc<dyn WebhookSubscriptionRepository>| async move {
+                    let deliveries = delivery_repo
+                        .list_due_deliveries(now, batch_size)
+                        .await
+                        .int_err()?;
+
+                    let mut result = Vec::with_capacity(deliveries.len());
+                    for delivery in deliveries {
+                        match subscription_repo
+                            .get_subscription(&delivery.subscription_id)
+                            .await
+                        {
+                            Ok(subscription) => result.push((delivery, subscription)),
+                            // Subscription was removed concurrently
+                            Err(GetWebhookSubscriptionError::NotFound(_)) => {}
+                            Err(GetWebhookSubscriptionError::Internal(e)) => return Err(e),
+                        }
+                    }
+
+                    Ok(result)
+                },
+            )
+            .await
+    }
+
+    async fn attempt_delivery(
+        &self,
+        delivery: &mut WebhookDelivery,
+        subscription: &WebhookSubscription,
+    ) -> Result<(), InternalError> {
+        let attempted_at = self.time_source.now();
+        let timestamp = attempted_at.timestamp();
+        let body = serde_json::to_vec(&delivery.payload).int_err()?;
+        let secret = subscription
+            .get_exposed_secret(self.config.secret_decryption_keys())
+            .int_err()?;
+
+        let request = WebhookRequest {
+            target_url: subscription.target_url.clone(),
+            headers: vec![
+                (
+                    HEADER_WEBHOOK_SIGNATURE.to_string(),
+                    sign_webhook_payload(&secret, timestamp, &body),
+                ),
+                (HEADER_WEBHOOK_TIMESTAMP.to_string(), timestamp.to_string()),
+                (
+                    HEADER_WEBHOOK_EVENT.to_string(),
+                    delivery.event_type.to_string(),
+                ),
+                (HEADER_WEBHOOK_DELIVERY.to_string(), delivery.id.to_string()),
+            ],
+            body,
+        };
+
+        let retry_policy = &self.config.retry_policy;
+        let request_timeout = self.config.request_timeout.to_std().int_err()?;
+
+        // The sender enforces the timeout too, but a stuck target must never hold
+        // up the whole batch
+        let send_result = tokio::time::timeout(request_timeout, self.sender.send(request))
+            .await
+            .unwrap_or_else(|_| {
+                Err(WebhookSendError::Transport {
+                    reason: format!("Target did not respond within {request_timeout:?}"),
+                })
+            });
+
+        match send_result {
+            Ok(response) if response.is_success() => {
+                delivery.on_delivered(attempted_at, response.status_code);
+            }
+            Ok(response) => {
+                delivery.on_failed(
+                    attempted_at,
+                    Some(response.status_code),
+                    format!("Target responded with status {}", response.status_code),
+                    retry_policy,
+                );
+            }
+            Err(WebhookSendError::Transport { reason }) => {
+                delivery.on_failed(attempted_at, None, reason, retry_policy);
+            }
+            Err(WebhookSendError::Internal(e)) => return Err(e),
+        }
+
+        match delivery.status {
+            WebhookDeliveryStatus::Delivered => {
+                tracing::debug!(delivery_id = %delivery.id, "Webhook delivered");
+            }
+            WebhookDeliveryStatus::Pending => {
+                tracing::warn!(
+                    delivery_id = %delivery.id,
+                    attempts = delivery.attempts,
+                    error = ?delivery.last_error,
+                    "Webhook delivery failed, will retry",
+                );
+            }
+            WebhookDeliveryStatus::DeadLettered => {
+                tracing::error!(
+                    delivery_id = %delivery.id,
+                    attempts = delivery.attempts,
+                    error = ?delivery.last_error,
+                    "Webhook delivery failed, giving up",
+                );
+            }
+        }
+
+        Ok(())
+    }
+
+    async fn save_delivery(&self, delivery: &WebhookDelivery) -> Result<(), InternalError> {
+        DatabaseTransactionRunner::new(self.catalog.clone())
+            .transactional_with(
+                |delivery_repo: Arc<dyn WebhookDeliveryRepository>| async move {
+                    match delivery_repo.update_delivery(delivery).await {
+                        // Subscription was removed concurrently
+                        Ok(()) | Err(UpdateWebhookDeliveryError::NotFound(_)) => Ok(()),
+                        Err(UpdateWebhookDeliveryError::Internal(e)) => Err(e),
+                    }
+                },
+            )
+            .await
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[async_trait::async_trait]
+impl WebhookDeliveryWorker for WebhookDeliveryWorkerImpl {
+    async fn run(&self) -> Result<(), InternalError> {
+        let polling_interval = self.config.delivery_polling_interval.to_std().int_err()?;
+
+        loop {
+            match self.deliver_due().await {
+                // Keep draining the backlog without pausing while batches are full
+                Ok(num_processed) if num_processed >= self.config.delivery_batch_size => {}
+                Ok(_) => tokio::time::sleep(polling_interval).await,
+                // Errors are transient, e.g. the database being unavailable: the
+                // deliveries remain due and are picked up by the next iteration
+                Err(e) => {
+                    tracing::error!(error = ?e, error_msg = %e, "Failed to deliver webhooks");
+                    tokio::time::sleep(polling_interval).await;
+                }
+            }
+        }
+    }
+
+    #[tracing::instrument(level = "debug", skip_all)]
+    async fn deliver_due(&self) -> Result<usize, InternalError> {
+        let due_deliveries = self.load_due_deliveries().await?;
+        let num_processed = due_deliveries.len();
+
+        futures::stream::iter(due_deliveries)
+            .for_each_concurrent(
+                self.config.delivery_concurrency,
+                |(mut delivery, subscription)| async move {
+                    // A failure of one delivery must not affect the others, the
+                    // failed one remains due and is retried later
+                    let res = match self.attempt_delivery(&mut delivery, &subscription).await {
+                        Ok(()) => self.save_delivery(&delivery).await,
+                        Err(e) => Err(e),
+                    };
+                    if let Err(e) = res {
+                        tracing::error!(
+                            delivery_id = %delivery.id,
+                            error = ?e,
+                            error_msg = %e,
+                            "Failed to process webhook delivery",
+                        );
+                    }
+                },
+            )
+            .await;
+
+        Ok(num_processed)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -0,0 +1,227 @@
+// Copyright Kamu Data, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+use std::sync::Arc;
+
+use dill::{component, interface, meta, Catalog};
+use internal_error::{InternalError, ResultIntoInternal};
+use kamu_core::{
+    DatasetLifecycleMessage,
+    DatasetOwnershipService,
+    MESSAGE_PRODUCER_KAMU_CORE_DATASET_SERVICE,
+};
+use kamu_flow_system::{FlowKey, FlowOutcome, FlowProgressMessage, FlowProgressMessageFinished};
+use kamu_flow_system_services::MESSAGE_PRODUCER_KAMU_FLOW_PROGRESS_SERVICE;
+use kamu_webhooks::*;
+use messaging_outbox::{
+    MessageConsumer,
+    MessageConsumerMeta,
+    MessageConsumerT,
+    MessageConsumptionDurability,
+};
+use time_source::SystemTimeSource;
+
+use crate::MESSAGE_CONSUMER_KAMU_WEBHOOK_EVENT_DISPATCHER;
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Translates domain messages into webhook events and schedules a delivery
+/// for every interested subscription. Actual sending happens asynchronously
+/// in [`crate::WebhookDeliveryWorkerImpl`].
+pub struct WebhookEventDispatcher {
+    subscription_repo: Arc<dyn WebhookSubscriptionRepository>,
+    delivery_repo: Arc<dyn WebhookDeliveryRepository>,
+    dataset_ownership_service: Arc<dyn DatasetOwnershipService>,
+    time_source: Arc<dyn SystemTimeSource>,
+}
+
+#[component(pub)]
+#[interface(dyn MessageConsumer)]
+#[interface(dyn MessageConsumerT<DatasetLifecycleMessage>)]
+#[interface(dyn MessageConsumerT<FlowProgressMessage>)]
+#[meta(MessageConsumerMeta {
+    consumer_name: MESSAGE_CONSUMER_KAMU_WEBHOOK_EVENT_DISPATCHER,
+    feeding_producers: &[
+        MESSAGE_PRODUCER_KAMU_CORE_DATASET_SERVICE,
+        MESSAGE_PRODUCER_KAMU_FLOW_PROGRESS_SERVICE,
+    ],
+    durability: MessageConsumptionDurability::Durable,
+})]
+impl WebhookEventDispatcher {
+    pub fn new(
+        subscription_repo: Arc<dyn WebhookSubscriptionRepository>,
+        delivery_repo: Arc<dyn WebhookDeliveryRepository>,
+        dataset_ownership_service: Arc<dyn DatasetOwnershipService>,
+        time_source: Arc<dyn SystemTimeSource>,
+    ) -> Self {
+        Self {
+            subscription_repo,
+            delivery_repo,
+            dataset_ownership_service,
+            time_source,
+        }
+    }
+
+    async fn dispatch_event(&self, event: &WebhookEvent) -> Result<(), InternalError> {
+        let mut scopes = vec![WebhookSubscriptionScope::Dataset(event.dataset_id.clone())];
+
+        // Owners are already forgotten by the time deletion is processed
+        if event.event_type != WebhookEventType::DatasetDeleted {
+            let owner_ids = self
+                .dataset_ownership_service
+                .get_dataset_owners(&event.dataset_id)
+                .await?;
+            scopes.extend(owner_ids.into_iter().map(WebhookSubscriptionScope::Account));
+        }
+
+        let now = self.time_source.now();
+
+        for scope in scopes {
+            let subscriptions = self
+                .subscription_repo
+                .list_subscriptions_by_scope(&scope)
+                .await
+                .int_err()?;
+
+            for subscription in subscriptions
+                .iter()
+                .filter(|s| s.is_interested_in(event.event_type))
+            {
+                let delivery = WebhookDelivery::new(subscription.id, event, now);
+
+                tracing::debug!(
+                    subscription_id = %subscription.id,
+                    delivery_id = %delivery.id,
+                    event_type = %event.event_type,
+                    "Scheduling webhook delivery",
+                );
+
+                self.delivery_repo
+                    .create_delivery(&delivery)
+                    .await
+                    .int_err()?;
+            }
+        }
+
+        Ok(())
+    }
+
+    fn flow_finished_events(message: &FlowProgressMessageFinished) -> Vec<WebhookEvent> {
+        // Only dataset flows are of interest to subscribers
+        let FlowKey::Dataset(flow_key) = &message.flow_key else {
+            return vec![];
+        };
+
+        let flow_attributes = serde_json::json!({
+            "flowId": message.flow_id.to_string(),
+            "flowType": flow_key.flow_type,
+        });
+
+        match &message.outcome {
+            // New data is reported via dataset lifecycle messages, as it may also be appended
+            // outside of flows
+            FlowOutcome::Success(_) => vec![WebhookEvent::new(
+                WebhookEventType::FlowSucceeded,
+                message.event_time,
+                flow_key.dataset_id.clone(),
+                flow_attributes,
+            )],
+            FlowOutcome::Failed(flow_error) => {
+                let mut flow_attributes = flow_attributes;
+                flow_attributes["error"] = serde_json::json!(flow_error);
+
+                vec![WebhookEvent::new(
+                    WebhookEventType::FlowFailed,
+                    message.event_time,
+                    flow_key.dataset_id.clone(),
+                    flow_attributes,
+                )]
+            }
+            FlowOutcome::Aborted => vec![],
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+impl MessageConsumer for WebhookEventDispatcher {}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[async_trait::async_trait]
+impl MessageConsumerT<DatasetLifecycleMessage> for WebhookEventDispatcher {
+    #[tracing::instrument(level = "debug", skip_all, fields(?message))]
+    async fn consume_message(
+        &self,
+        _: &Catalog,
+        message: &DatasetLifecycleMessage,
+    ) -> Result<(), InternalError> {
+        match message {
+            DatasetLifecycleMessage::Deleted(message) => {
+                let event = WebhookEvent::new(
+                    WebhookEventType::DatasetDeleted,
+                    self.time_source.now(),
+                    message.dataset_id.clone(),
+                    serde_json::json!({}),
+                );
+                self.dispatch_event(&event).await
+            }
+
+            DatasetLifecycleMessage::DataUpdated(message) => {
+                let event = WebhookEvent::new(
+                    WebhookEventType::DatasetDataAppended,
+                    self.time_source.now(),
+                    message.dataset_id.clone(),
+                    serde_json::json!({
+                        "oldHead": message.old_head.as_ref().map(ToString::to_string),
+                        "newHead": message.new_head.to_string(),
+                        "newOffsetInterval": message.new_offset_interval.as_ref().map(|i| {
+                            serde_json::json!({
+                                "start": i.start,
+                                "end": i.end,
+                            })
+                        }),
+                        "newWatermark": message.new_watermark.map(|w| w.to_rfc3339()),
+                    }),
+                );
+                self.dispatch_event(&event).await
+            }
+
+            DatasetLifecycleMessage::Created(_)
+            | DatasetLifecycleMessage::DependenciesUpdated(_)
+            | DatasetLifecycleMessage::Renamed(_) => {
+                // No action required
+                Ok(())
+            }
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[async_trait::async_trait]
+impl MessageConsumerT<FlowProgressMessage> for WebhookEventDispatcher {
+    #[tracing::instrument(level = "debug", skip_all, fields(?message))]
+    async fn consume_message(
+        &self,
+        _: &Catalog,
+        message: &FlowProgressMessage,
+    ) -> Result<(), InternalError> {
+        match message {
+            FlowProgressMessage::Finished(message) => {
+                for event in Self::flow_finished_events(message) {
+                    self.dispatch_event(&event).await?;
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
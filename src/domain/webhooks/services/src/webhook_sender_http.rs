@@ -0,0 +1,180 @@
+// Copyright Kamu Data, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr, SocketAddr};
+use std::sync::Arc;
+
+use dill::*;
+use internal_error::ResultIntoInternal;
+use kamu_webhooks::*;
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+pub struct WebhookSenderHttp {
+    client: reqwest::Client,
+    allow_private_targets: bool,
+}
+
+#[component(pub)]
+#[interface(dyn WebhookSender)]
+#[scope(Singleton)]
+impl WebhookSenderHttp {
+    pub fn new(config: Arc<WebhooksConfig>) -> Self {
+        let mut client_builder = reqwest::Client::builder()
+            .timeout(
+                config
+                    .request_timeout
+                    .to_std()
+                    .expect("Request timeout must be positive"),
+            )
+            // Targets are user-provided, don't let them bounce us elsewhere
+            .redirect(reqwest::redirect::Policy::none());
+
+        // Targets are resolved when connecting rather than on subscription, so
+        // that a name can't be re-pointed at internal services after validation
+        if !config.allow_private_targets {
+            client_builder = client_builder.dns_resolver(Arc::new(PublicAddressResolver));
+        }
+
+        Self {
+            client: client_builder.build().unwrap(),
+            allow_private_targets: config.allow_private_targets,
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl WebhookSender for WebhookSenderHttp {
+    #[tracing::instrument(level = "debug", skip_all, fields(target_url = %request.target_url))]
+    async fn send(&self, request: WebhookRequest) -> Result<WebhookResponse, WebhookSendError> {
+        // Literal addresses bypass the resolver
+        let literal_ip = match request.target_url.host() {
+            Some(url::Host::Ipv4(ip)) => Some(IpAddr::V4(ip)),
+            Some(url::Host::Ipv6(ip)) => Some(IpAddr::V6(ip)),
+            Some(url::Host::Domain(_)) | None => None,
+        };
+        if !self.allow_private_targets
+            && let Some(ip) = literal_ip
+            && !is_public_ip(ip)
+        {
+            return Err(WebhookSendError::Transport {
+                reason: format!("Target address {ip} is not public"),
+            });
+        }
+
+        let mut request_builder = self
+            .client
+            .post(request.target_url)
+            .header(reqwest::header::CONTENT_TYPE, "application/json");
+        for (name, value) in request.headers {
+            request_builder = request_builder.header(name, value);
+        }
+
+        let request = request_builder
+            .body(request.body)
+            .build()
+            .int_err()
+            .map_err(WebhookSendError::Internal)?;
+
+        let response =
+            self.client
+                .execute(request)
+                .await
+                .map_err(|e| WebhookSendError::Transport {
+                    reason: error_chain_message(&e),
+                })?;
+
+        Ok(WebhookResponse {
+            status_code: response.status().as_u16(),
+        })
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Resolves target names, refusing to connect if any of their addresses is
+/// loopback, private, link-local or otherwise not publicly routable
+struct PublicAddressResolver;
+
+impl reqwest::dns::Resolve for PublicAddressResolver {
+    fn resolve(&self, name: reqwest::dns::Name) -> reqwest::dns::Resolving {
+        Box::pin(async move {
+            let addrs: Vec<SocketAddr> =
+                tokio::net::lookup_host((name.as_str(), 0)).await?.collect();
+
+            if let Some(addr) = addrs.iter().find(|addr| !is_public_ip(addr.ip())) {
+                return Err(format!(
+                    "Target host '{}' resolves to address {} which is not public",
+                    name.as_str(),
+                    addr.ip()
+                )
+                .into());
+            }
+
+            Ok(Box::new(addrs.into_iter()) as reqwest::dns::Addrs)
+        })
+    }
+}
+
+/// Includes the causes, which tell why the connection failed
+fn error_chain_message(e: &dyn std::error::Error) -> String {
+    let mut message = e.to_string();
+    let mut source = e.source();
+    while let Some(cause) = source {
+        let cause_message = cause.to_string();
+        if !message.contains(&cause_message) {
+            message = format!("{message}: {cause_message}");
+        }
+        source = cause.source();
+    }
+    message
+}
+
+fn is_public_ip(ip: IpAddr) -> bool {
+    match ip {
+        IpAddr::V4(ip) => is_public_ipv4(ip),
+        IpAddr::V6(ip) => {
+            if let Some(ip) = ip.to_ipv4_mapped() {
+                return is_public_ipv4(ip);
+            }
+            is_public_ipv6(ip)
+        }
+    }
+}
+
+fn is_public_ipv4(ip: Ipv4Addr) -> bool {
+    let [a, b, ..] = ip.octets();
+
+    // "This network" 0.0.0.0/8, shared address space 100.64.0.0/10 and reserved
+    // 240.0.0.0/4 ranges have no std helpers
+    let is_reserved = a == 0 || (a == 100 && (b & 0b1100_0000) == 64) || a >= 240;
+
+    !(is_reserved
+        || ip.is_unspecified()
+        || ip.is_loopback()
+        || ip.is_private()
+        || ip.is_link_local()
+        || ip.is_broadcast()
+        || ip.is_documentation()
+        || ip.is_multicast())
+}
+
+fn is_public_ipv6(ip: Ipv6Addr) -> bool {
+    let segments = ip.segments();
+
+    // Unique local fc00::/7, link-local fe80::/10 and documentation
+    // 2001:db8::/32 ranges have no stable std helpers
+    let is_reserved = (segments[0] & 0xfe00) == 0xfc00
+        || (segments[0] & 0xffc0) == 0xfe80
+        || (segments[0] == 0x2001 && segments[1] == 0x0db8);
+
+    !(is_reserved || ip.is_unspecified() || ip.is_loopback() || ip.is_multicast())
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -0,0 +1,261 @@
+// Copyright Kamu Data, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+use std::sync::Arc;
+
+use database_common::DatabasePaginationOpts;
+use dill::*;
+use internal_error::{InternalError, ResultIntoInternal};
+use kamu_webhooks::*;
+use time_source::SystemTimeSource;
+use url::Url;
+use uuid::Uuid;
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+pub struct WebhookSubscriptionServiceImpl {
+    subscription_repo: Arc<dyn WebhookSubscriptionRepository>,
+    delivery_repo: Arc<dyn WebhookDeliveryRepository>,
+    time_source: Arc<dyn SystemTimeSource>,
+    config: Arc<WebhooksConfig>,
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[component(pub)]
+#[interface(dyn WebhookSubscriptionService)]
+impl WebhookSubscriptionServiceImpl {
+    pub fn new(
+        subscription_repo: Arc<dyn WebhookSubscriptionRepository>,
+        delivery_repo: Arc<dyn WebhookDeliveryRepository>,
+        time_source: Arc<dyn SystemTimeSource>,
+        config: Arc<WebhooksConfig>,
+    ) -> Self {
+        Self {
+            subscription_repo,
+            delivery_repo,
+            time_source,
+            config,
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[async_trait::async_trait]
+impl WebhookSubscriptionService for WebhookSubscriptionServiceImpl {
+    async fn create_subscription(
+        &self,
+        scope: WebhookSubscriptionScope,
+        target_url: Url,
+        label: String,
+        event_types: Vec<WebhookEventType>,
+    ) -> Result<WebhookSubscription, CreateWebhookSubscriptionError> {
+        let encrypted_secret = encrypt_webhook_secret(
+            &WebhookSubscription::generate_secret(),
+            self.config
+                .secret_encryption_key
+                .as_deref()
+                .ok_or(WebhookSecretEncryptionError::KeyNotConfigured)
+                .int_err()
+                .map_err(CreateWebhookSubscriptionError::Internal)?,
+        )
+        .int_err()
+        .map_err(CreateWebhookSubscriptionError::Internal)?;
+
+        let subscription = WebhookSubscription::new(
+            scope,
+            target_url,
+            label,
+            event_types,
+            encrypted_secret,
+            self.time_source.now(),
+        )
+        .map_err(CreateWebhookSubscriptionError::InvalidSubscription)?;
+
+        self.subscription_repo
+            .create_subscription(&subscription)
+            .await?;
+
+        Ok(subscription)
+    }
+
+    async fn rotate_secret_encryption_key(
+        &self,
+        new_encryption_key: &str,
+    ) -> Result<usize, InternalError> {
+        let subscriptions = self
+            .subscription_repo
+            .list_all_subscriptions()
+            .await
+            .int_err()?;
+
+        let mut num_rotated = 0;
+        for mut subscription in subscriptions {
+            // Already encrypted with the new key
+            if decrypt_webhook_secret(&subscription.secret, [new_encryption_key]).is_ok() {
+                continue;
+            }
+
+            let secret = subscription
+                .get_exposed_secret(self.config.secret_decryption_keys())
+                .int_err()?;
+            subscription.secret = encrypt_webhook_secret(&secret, new_encryption_key).int_err()?;
+
+            self.subscription_repo
+                .update_subscription(&subscription)
+                .await
+                .int_err()?;
+
+            num_rotated += 1;
+        }
+
+        tracing::info!(
+            num_rotated,
+            "Re-encrypted webhook subscription secrets with the new key"
+        );
+
+        Ok(num_rotated)
+    }
+
+    async fn get_subscription(
+        &self,
+        subscription_id: &Uuid,
+    ) -> Result<WebhookSubscription, GetWebhookSubscriptionError> {
+        self.subscription_repo
+            .get_subscription(subscription_id)
+            .await
+    }
+
+    async fn list_subscriptions(
+        &self,
+        scope: &WebhookSubscriptionScope,
+    ) -> Result<Vec<WebhookSubscription>, ListWebhookSubscriptionsError> {
+        self.subscription_repo
+            .list_subscriptions_by_scope(scope)
+            .await
+    }
+
+    async fn set_subscription_enabled(
+        &self,
+        subscription_id: &Uuid,
+        enabled: bool,
+    ) -> Result<WebhookSubscription, UpdateWebhookSubscriptionError> {
+        let mut subscription = self
+            .subscription_repo
+            .get_subscription(subscription_id)
+            .await
+            .map_err(|e| match e {
+                GetWebhookSubscriptionError::NotFound(e) => {
+                    UpdateWebhookSubscriptionError::NotFound(e)
+                }
+                GetWebhookSubscriptionError::Internal(e) => {
+                    UpdateWebhookSubscriptionError::Internal(e)
+                }
+            })?;
+
+        if subscription.enabled != enabled {
+            subscription.enabled = enabled;
+            self.subscription_repo
+                .update_subscription(&subscription)
+                .await?;
+        }
+
+        Ok(subscription)
+    }
+
+    async fn delete_subscription(
+        &self,
+        subscription_id: &Uuid,
+    ) -> Result<(), DeleteWebhookSubscriptionError> {
+        self.subscription_repo
+            .delete_subscription(subscription_id)
+            .await?;
+
+        self.delivery_repo
+            .delete_deliveries_by_subscription(subscription_id)
+            .await
+            .int_err()
+            .map_err(DeleteWebhookSubscriptionError::Internal)
+    }
+
+    async fn list_deliveries(
+        &self,
+        subscription_id: &Uuid,
+        maybe_status: Option<WebhookDeliveryStatus>,
+        pagination: Option<DatabasePaginationOpts>,
+    ) -> Result<WebhookDeliveryListing, ListWebhookDeliveriesError> {
+        let total_count = self
+            .delivery_repo
+            .get_deliveries_count_by_subscription(subscription_id, maybe_status)
+            .await?;
+        if total_count == 0 {
+            return Ok(WebhookDeliveryListing {
+                total_count,
+                list: vec![],
+            });
+        }
+        let database_pagination = pagination.unwrap_or(DatabasePaginationOpts {
+            #[allow(clippy::cast_possible_wrap)]
+            limit: total_count as i64,
+            offset: 0,
+        });
+
+        let list = self
+            .delivery_repo
+            .get_deliveries_by_subscription(subscription_id, maybe_status, &database_pagination)
+            .await?;
+        Ok(WebhookDeliveryListing { list, total_count })
+    }
+
+    async fn redeliver(
+        &self,
+        subscription_id: &Uuid,
+        delivery_id: &Uuid,
+    ) -> Result<WebhookDelivery, RedeliverWebhookDeliveryError> {
+        let mut delivery =
+            self.delivery_repo
+                .get_delivery(delivery_id)
+                .await
+                .map_err(|e| match e {
+                    GetWebhookDeliveryError::NotFound(e) => {
+                        RedeliverWebhookDeliveryError::NotFound(e)
+                    }
+                    GetWebhookDeliveryError::Internal(e) => {
+                        RedeliverWebhookDeliveryError::Internal(e)
+                    }
+                })?;
+
+        if delivery.subscription_id != *subscription_id {
+            return Err(RedeliverWebhookDeliveryError::NotFound(
+                WebhookDeliveryNotFoundError {
+                    delivery_id: *delivery_id,
+                },
+            ));
+        }
+
+        delivery.redeliver(self.time_source.now());
+
+        self.delivery_repo
+            .update_delivery(&delivery)
+            .await
+            .map_err(|e| match e {
+                UpdateWebhookDeliveryError::NotFound(e) => {
+                    RedeliverWebhookDeliveryError::NotFound(e)
+                }
+                UpdateWebhookDeliveryError::Internal(e) => {
+                    RedeliverWebhookDeliveryError::Internal(e)
+                }
+            })?;
+
+        Ok(delivery)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
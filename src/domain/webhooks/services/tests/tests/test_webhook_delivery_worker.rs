@@ -0,0 +1,334 @@
+// Copyright Kamu Data, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+use std::collections::HashMap;
+
+use chrono::Duration;
+use kamu_core::DatasetLifecycleMessage;
+use kamu_webhooks::*;
+use messaging_outbox::MessageConsumerT;
+use opendatafabric::DatasetID;
+
+use crate::{
+    WebhooksHarness,
+    TEST_MAX_ATTEMPTS,
+    TEST_MIN_BACKOFF_SECS,
+    TEST_SECRET_ENCRYPTION_KEY,
+};
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[test_log::test(tokio::test)]
+async fn test_delivery_is_signed_and_completed() {
+    let harness = WebhooksHarness::new(HashMap::new());
+    let (subscription, delivery) = harness.schedule_deletion_delivery().await;
+
+    assert_eq!(harness.worker.deliver_due().await.unwrap(), 1);
+
+    let requests = harness.sender.take_requests();
+    assert_eq!(requests.len(), 1);
+    let request = &requests[0];
+    assert_eq!(request.target_url, subscription.target_url);
+    assert_eq!(
+        serde_json::from_slice::<serde_json::Value>(&request.body).unwrap(),
+        delivery.payload
+    );
+
+    let header = |name: &str| {
+        request
+            .headers
+            .iter()
+            .find(|(k, _)| k == name)
+            .map(|(_, v)| v.as_str())
+            .unwrap()
+    };
+    let timestamp = WebhooksHarness::start_time().timestamp();
+    assert_eq!(header(HEADER_WEBHOOK_TIMESTAMP), timestamp.to_string());
+    assert_eq!(header(HEADER_WEBHOOK_EVENT), "dataset.deleted");
+    assert_eq!(header(HEADER_WEBHOOK_DELIVERY), delivery.id.to_string());
+    // The secret is stored encrypted, while requests are signed with the plain one
+    let secret = subscription
+        .get_exposed_secret([TEST_SECRET_ENCRYPTION_KEY])
+        .unwrap();
+    assert_ne!(secret, subscription.secret);
+    assert!(verify_webhook_signature(
+        &secret,
+        timestamp,
+        &request.body,
+        header(HEADER_WEBHOOK_SIGNATURE),
+    ));
+
+    let delivery = harness
+        .delivery_repo
+        .get_delivery(&delivery.id)
+        .await
+        .unwrap();
+    assert_eq!(delivery.status, WebhookDeliveryStatus::Delivered);
+    assert_eq!(delivery.attempts, 1);
+    assert_eq!(delivery.last_response_code, Some(200));
+    assert_eq!(delivery.next_attempt_at, None);
+
+    // Nothing is left to deliver
+    assert_eq!(harness.worker.deliver_due().await.unwrap(), 0);
+    assert!(harness.sender.take_requests().is_empty());
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[test_log::test(tokio::test)]
+async fn test_failed_delivery_retried_until_dead_lettered() {
+    let harness = WebhooksHarness::new(HashMap::new());
+    let (_, delivery) = harness.schedule_deletion_delivery().await;
+    let min_backoff = Duration::try_seconds(TEST_MIN_BACKOFF_SECS).unwrap();
+
+    harness.sender.enqueue_response(Ok(500));
+    harness
+        .sender
+        .enqueue_response(Err("connection refused".to_string()));
+    harness.sender.enqueue_response(Ok(503));
+
+    // First attempt fails and is postponed by the minimal backoff
+    assert_eq!(harness.worker.deliver_due().await.unwrap(), 1);
+    let stored = harness
+        .delivery_repo
+        .get_delivery(&delivery.id)
+        .await
+        .unwrap();
+    assert_eq!(stored.status, WebhookDeliveryStatus::Pending);
+    assert_eq!(stored.attempts, 1);
+    assert_eq!(stored.last_response_code, Some(500));
+    assert_eq!(
+        stored.next_attempt_at,
+        Some(WebhooksHarness::start_time() + min_backoff)
+    );
+
+    // Not due yet
+    assert_eq!(harness.worker.deliver_due().await.unwrap(), 0);
+
+    // Second attempt fails on transport level
+    harness.advance_time(min_backoff);
+    assert_eq!(harness.worker.deliver_due().await.unwrap(), 1);
+    let stored = harness
+        .delivery_repo
+        .get_delivery(&delivery.id)
+        .await
+        .unwrap();
+    assert_eq!(stored.status, WebhookDeliveryStatus::Pending);
+    assert_eq!(stored.attempts, 2);
+    assert_eq!(stored.last_response_code, None);
+    assert_eq!(stored.last_error.as_deref(), Some("connection refused"));
+
+    // Last attempt exhausts the retry budget
+    harness.advance_time(min_backoff * 2);
+    assert_eq!(harness.worker.deliver_due().await.unwrap(), 1);
+    let stored = harness
+        .delivery_repo
+        .get_delivery(&delivery.id)
+        .await
+        .unwrap();
+    assert_eq!(stored.status, WebhookDeliveryStatus::DeadLettered);
+    assert_eq!(stored.attempts, TEST_MAX_ATTEMPTS);
+    assert_eq!(stored.next_attempt_at, None);
+
+    harness.advance_time(Duration::try_hours(1).unwrap());
+    assert_eq!(harness.worker.deliver_due().await.unwrap(), 0);
+    assert_eq!(harness.sender.take_requests().len(), 3);
+
+    // Manual redelivery starts over
+    let redelivered = harness
+        .subscription_service
+        .redeliver(&delivery.subscription_id, &delivery.id)
+        .await
+        .unwrap();
+    assert_eq!(redelivered.status, WebhookDeliveryStatus::Pending);
+    assert_eq!(redelivered.attempts, 0);
+
+    assert_eq!(harness.worker.deliver_due().await.unwrap(), 1);
+    let stored = harness
+        .delivery_repo
+        .get_delivery(&delivery.id)
+        .await
+        .unwrap();
+    assert_eq!(stored.status, WebhookDeliveryStatus::Delivered);
+    assert_eq!(stored.attempts, 1);
+
+    let dead_lettered = harness
+        .subscription_service
+        .list_deliveries(
+            &delivery.subscription_id,
+            Some(WebhookDeliveryStatus::DeadLettered),
+            None,
+        )
+        .await
+        .unwrap();
+    assert_eq!(dead_lettered.total_count, 0);
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[test_log::test(tokio::test)]
+async fn test_deleted_subscription_stops_deliveries() {
+    let harness = WebhooksHarness::new(HashMap::new());
+    let (subscription, _) = harness.schedule_deletion_delivery().await;
+
+    harness
+        .subscription_service
+        .delete_subscription(&subscription.id)
+        .await
+        .unwrap();
+
+    assert_eq!(harness.worker.deliver_due().await.unwrap(), 0);
+    assert!(harness.sender.take_requests().is_empty());
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[test_log::test(tokio::test(start_paused = true))]
+async fn test_delivery_times_out_on_unresponsive_target() {
+    let harness = WebhooksHarness::new(HashMap::new());
+    let (_, delivery) = harness.schedule_deletion_delivery().await;
+
+    harness
+        .sender
+        .set_response_delay(std::time::Duration::from_secs(3600));
+
+    assert_eq!(harness.worker.deliver_due().await.unwrap(), 1);
+
+    let stored = harness
+        .delivery_repo
+        .get_delivery(&delivery.id)
+        .await
+        .unwrap();
+    assert_eq!(stored.status, WebhookDeliveryStatus::Pending);
+    assert_eq!(stored.attempts, 1);
+    assert_eq!(stored.last_response_code, None);
+    assert!(stored
+        .last_error
+        .as_deref()
+        .unwrap()
+        .starts_with("Target did not respond within"));
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[test_log::test(tokio::test)]
+async fn test_failing_delivery_does_not_affect_others() {
+    let harness = WebhooksHarness::new(HashMap::new());
+    let dataset_id = DatasetID::new_seeded_ed25519(b"foo");
+    let scope = WebhookSubscriptionScope::Dataset(dataset_id.clone());
+
+    let mut broken_subscription = harness
+        .create_subscription(scope.clone(), vec![WebhookEventType::DatasetDeleted])
+        .await;
+    let subscription = harness
+        .create_subscription(scope, vec![WebhookEventType::DatasetDeleted])
+        .await;
+
+    // Secret can't be decrypted, so the delivery can't be signed
+    broken_subscription.secret = "garbage".to_string();
+    harness
+        .subscription_repo
+        .update_subscription(&broken_subscription)
+        .await
+        .unwrap();
+
+    harness
+        .dispatcher
+        .consume_message(
+            &harness.catalog,
+            &DatasetLifecycleMessage::deleted(dataset_id),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(harness.worker.deliver_due().await.unwrap(), 2);
+    assert_eq!(harness.sender.take_requests().len(), 1);
+
+    let broken_deliveries = harness.deliveries(&broken_subscription.id).await;
+    assert_eq!(broken_deliveries[0].status, WebhookDeliveryStatus::Pending);
+    assert_eq!(broken_deliveries[0].attempts, 0);
+
+    let deliveries = harness.deliveries(&subscription.id).await;
+    assert_eq!(deliveries[0].status, WebhookDeliveryStatus::Delivered);
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[test_log::test(tokio::test)]
+async fn test_rotate_secret_encryption_key() {
+    const NEW_KEY: &str = "4pQnOqXJGzHkbTyv0MxJlQHJBWcvUy3S";
+
+    let harness = WebhooksHarness::new(HashMap::new());
+    let subscription = harness
+        .create_subscription(
+            WebhookSubscriptionScope::Dataset(DatasetID::new_seeded_ed25519(b"foo")),
+            vec![WebhookEventType::DatasetDeleted],
+        )
+        .await;
+    let secret = subscription
+        .get_exposed_secret([TEST_SECRET_ENCRYPTION_KEY])
+        .unwrap();
+
+    assert_eq!(
+        harness
+            .subscription_service
+            .rotate_secret_encryption_key(NEW_KEY)
+            .await
+            .unwrap(),
+        1
+    );
+
+    let rotated = harness
+        .subscription_service
+        .get_subscription(&subscription.id)
+        .await
+        .unwrap();
+    assert_eq!(rotated.get_exposed_secret([NEW_KEY]).unwrap(), secret);
+    assert_eq!(
+        rotated.get_exposed_secret([TEST_SECRET_ENCRYPTION_KEY]),
+        Err(WebhookSecretEncryptionError::NoMatchingKey)
+    );
+
+    // Already rotated
+    assert_eq!(
+        harness
+            .subscription_service
+            .rotate_secret_encryption_key(NEW_KEY)
+            .await
+            .unwrap(),
+        0
+    );
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+impl WebhooksHarness {
+    async fn schedule_deletion_delivery(&self) -> (WebhookSubscription, WebhookDelivery) {
+        let dataset_id = DatasetID::new_seeded_ed25519(b"foo");
+
+        let subscription = self
+            .create_subscription(
+                WebhookSubscriptionScope::Dataset(dataset_id.clone()),
+                vec![WebhookEventType::DatasetDeleted],
+            )
+            .await;
+
+        self.dispatcher
+            .consume_message(&self.catalog, &DatasetLifecycleMessage::deleted(dataset_id))
+            .await
+            .unwrap();
+
+        let mut deliveries = self.deliveries(&subscription.id).await;
+        assert_eq!(deliveries.len(), 1);
+
+        (subscription, deliveries.remove(0))
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
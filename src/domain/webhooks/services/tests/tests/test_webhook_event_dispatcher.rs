@@ -0,0 +1,301 @@
+// Copyright Kamu Data, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+use std::assert_matches::assert_matches;
+use std::collections::HashMap;
+
+use kamu_core::DatasetLifecycleMessage;
+use kamu_flow_system::{
+    DatasetFlowType,
+    FlowError,
+    FlowID,
+    FlowKeyDataset,
+    FlowOutcome,
+    FlowProgressMessage,
+    FlowResult,
+    FlowResultDatasetUpdate,
+    FlowResultDatasetUpdateChanged,
+};
+use kamu_webhooks::*;
+use messaging_outbox::MessageConsumerT;
+use opendatafabric::{AccountID, DatasetID, Multihash, OffsetInterval};
+use url::Url;
+
+use crate::WebhooksHarness;
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[test_log::test(tokio::test)]
+async fn test_flow_success_dispatched_to_dataset_and_owner_subscriptions() {
+    let dataset_id = DatasetID::new_seeded_ed25519(b"foo");
+    let owner_id = AccountID::new_seeded_ed25519(b"owner");
+    let harness = WebhooksHarness::new(HashMap::from([(
+        dataset_id.clone(),
+        vec![owner_id.clone()],
+    )]));
+
+    let dataset_subscription = harness
+        .create_subscription(
+            WebhookSubscriptionScope::Dataset(dataset_id.clone()),
+            vec![
+                WebhookEventType::DatasetDataAppended,
+                WebhookEventType::FlowSucceeded,
+            ],
+        )
+        .await;
+    let account_subscription = harness
+        .create_subscription(
+            WebhookSubscriptionScope::Account(owner_id.clone()),
+            vec![WebhookEventType::FlowSucceeded],
+        )
+        .await;
+    let failures_subscription = harness
+        .create_subscription(
+            WebhookSubscriptionScope::Dataset(dataset_id.clone()),
+            vec![WebhookEventType::FlowFailed],
+        )
+        .await;
+
+    harness
+        .dispatch_flow_finished(
+            &dataset_id,
+            FlowOutcome::Success(FlowResult::DatasetUpdate(FlowResultDatasetUpdate::Changed(
+                FlowResultDatasetUpdateChanged {
+                    old_head: None,
+                    new_head: Multihash::from_digest_sha3_256(b"new-head"),
+                },
+            ))),
+        )
+        .await;
+
+    // Appended data is reported by dataset lifecycle messages instead
+    let deliveries = harness.deliveries(&dataset_subscription.id).await;
+    assert_eq!(
+        deliveries.iter().map(|d| d.event_type).collect::<Vec<_>>(),
+        vec![WebhookEventType::FlowSucceeded]
+    );
+    assert_eq!(deliveries[0].status, WebhookDeliveryStatus::Pending);
+    assert_eq!(
+        deliveries[0].next_attempt_at,
+        Some(WebhooksHarness::start_time())
+    );
+    assert_eq!(deliveries[0].payload["type"], "flow.succeeded");
+    assert_eq!(deliveries[0].payload["datasetId"], dataset_id.to_string());
+    assert_eq!(deliveries[0].payload["data"]["flowId"], "1");
+    assert_eq!(deliveries[0].payload["data"]["flowType"], "Ingest");
+
+    let deliveries = harness.deliveries(&account_subscription.id).await;
+    assert_eq!(
+        deliveries.iter().map(|d| d.event_type).collect::<Vec<_>>(),
+        vec![WebhookEventType::FlowSucceeded]
+    );
+
+    assert!(harness
+        .deliveries(&failures_subscription.id)
+        .await
+        .is_empty());
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[test_log::test(tokio::test)]
+async fn test_data_updated_dispatched_to_dataset_and_owner_subscriptions() {
+    let dataset_id = DatasetID::new_seeded_ed25519(b"foo");
+    let owner_id = AccountID::new_seeded_ed25519(b"owner");
+    let harness = WebhooksHarness::new(HashMap::from([(
+        dataset_id.clone(),
+        vec![owner_id.clone()],
+    )]));
+
+    let dataset_subscription = harness
+        .create_subscription(
+            WebhookSubscriptionScope::Dataset(dataset_id.clone()),
+            vec![WebhookEventType::DatasetDataAppended],
+        )
+        .await;
+    let account_subscription = harness
+        .create_subscription(
+            WebhookSubscriptionScope::Account(owner_id.clone()),
+            vec![WebhookEventType::DatasetDataAppended],
+        )
+        .await;
+
+    let old_head = Multihash::from_digest_sha3_256(b"old-head");
+    let new_head = Multihash::from_digest_sha3_256(b"new-head");
+    harness
+        .dispatcher
+        .consume_message(
+            &harness.catalog,
+            &DatasetLifecycleMessage::data_updated(
+                dataset_id.clone(),
+                Some(old_head.clone()),
+                new_head.clone(),
+                Some(OffsetInterval { start: 10, end: 19 }),
+                None,
+            ),
+        )
+        .await
+        .unwrap();
+
+    for subscription in [&dataset_subscription, &account_subscription] {
+        let deliveries = harness.deliveries(&subscription.id).await;
+        assert_eq!(deliveries.len(), 1);
+        assert_eq!(
+            deliveries[0].event_type,
+            WebhookEventType::DatasetDataAppended
+        );
+        assert_eq!(deliveries[0].payload["type"], "dataset.data_appended");
+        assert_eq!(deliveries[0].payload["datasetId"], dataset_id.to_string());
+        assert_eq!(
+            deliveries[0].payload["data"],
+            serde_json::json!({
+                "oldHead": old_head.to_string(),
+                "newHead": new_head.to_string(),
+                "newOffsetInterval": {
+                    "start": 10,
+                    "end": 19,
+                },
+                "newWatermark": null,
+            })
+        );
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[test_log::test(tokio::test)]
+async fn test_flow_failure_skips_disabled_subscriptions() {
+    let dataset_id = DatasetID::new_seeded_ed25519(b"foo");
+    let harness = WebhooksHarness::new(HashMap::new());
+
+    let enabled_subscription = harness
+        .create_subscription(
+            WebhookSubscriptionScope::Dataset(dataset_id.clone()),
+            vec![WebhookEventType::FlowFailed],
+        )
+        .await;
+    let disabled_subscription = harness
+        .create_subscription(
+            WebhookSubscriptionScope::Dataset(dataset_id.clone()),
+            vec![WebhookEventType::FlowFailed],
+        )
+        .await;
+    harness
+        .subscription_service
+        .set_subscription_enabled(&disabled_subscription.id, false)
+        .await
+        .unwrap();
+
+    harness
+        .dispatch_flow_finished(&dataset_id, FlowOutcome::Failed(FlowError::Failed))
+        .await;
+
+    let deliveries = harness.deliveries(&enabled_subscription.id).await;
+    assert_eq!(deliveries.len(), 1);
+    assert_eq!(deliveries[0].event_type, WebhookEventType::FlowFailed);
+    assert_eq!(deliveries[0].payload["data"]["error"], "Failed");
+
+    assert!(harness
+        .deliveries(&disabled_subscription.id)
+        .await
+        .is_empty());
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[test_log::test(tokio::test)]
+async fn test_dataset_deleted_dispatched_to_dataset_subscriptions() {
+    let dataset_id = DatasetID::new_seeded_ed25519(b"foo");
+    let other_dataset_id = DatasetID::new_seeded_ed25519(b"bar");
+    let harness = WebhooksHarness::new(HashMap::new());
+
+    let subscription = harness
+        .create_subscription(
+            WebhookSubscriptionScope::Dataset(dataset_id.clone()),
+            vec![WebhookEventType::DatasetDeleted],
+        )
+        .await;
+    let other_subscription = harness
+        .create_subscription(
+            WebhookSubscriptionScope::Dataset(other_dataset_id),
+            vec![WebhookEventType::DatasetDeleted],
+        )
+        .await;
+
+    harness
+        .dispatcher
+        .consume_message(
+            &harness.catalog,
+            &DatasetLifecycleMessage::deleted(dataset_id.clone()),
+        )
+        .await
+        .unwrap();
+
+    let deliveries = harness.deliveries(&subscription.id).await;
+    assert_eq!(deliveries.len(), 1);
+    assert_eq!(deliveries[0].event_type, WebhookEventType::DatasetDeleted);
+    assert_eq!(deliveries[0].payload["datasetId"], dataset_id.to_string());
+
+    assert!(harness.deliveries(&other_subscription.id).await.is_empty());
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[test_log::test(tokio::test)]
+async fn test_invalid_subscriptions_rejected() {
+    let harness = WebhooksHarness::new(HashMap::new());
+
+    let res = harness
+        .subscription_service
+        .create_subscription(
+            WebhookSubscriptionScope::Account(AccountID::new_seeded_ed25519(b"owner")),
+            Url::parse("https://example.com/hooks").unwrap(),
+            "test".to_string(),
+            vec![WebhookEventType::DatasetDeleted],
+        )
+        .await;
+    assert_matches!(
+        res,
+        Err(CreateWebhookSubscriptionError::InvalidSubscription(_))
+    );
+
+    let res = harness
+        .subscription_service
+        .create_subscription(
+            WebhookSubscriptionScope::Dataset(DatasetID::new_seeded_ed25519(b"foo")),
+            Url::parse("ftp://example.com/hooks").unwrap(),
+            "test".to_string(),
+            vec![WebhookEventType::FlowFailed],
+        )
+        .await;
+    assert_matches!(
+        res,
+        Err(CreateWebhookSubscriptionError::InvalidSubscription(_))
+    );
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+impl WebhooksHarness {
+    async fn dispatch_flow_finished(&self, dataset_id: &DatasetID, outcome: FlowOutcome) {
+        let message = FlowProgressMessage::finished(
+            WebhooksHarness::start_time(),
+            FlowID::new(1),
+            FlowKeyDataset::new(dataset_id.clone(), DatasetFlowType::Ingest).into(),
+            outcome,
+        );
+
+        self.dispatcher
+            .consume_message(&self.catalog, &message)
+            .await
+            .unwrap();
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
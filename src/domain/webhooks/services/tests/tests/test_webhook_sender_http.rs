@@ -0,0 +1,89 @@
+// Copyright Kamu Data, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+use std::assert_matches::assert_matches;
+use std::sync::Arc;
+
+use kamu_webhooks::*;
+use kamu_webhooks_services::WebhookSenderHttp;
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use url::Url;
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[test_log::test(tokio::test)]
+async fn test_private_targets_rejected() {
+    let port = start_target().await;
+    let sender = WebhookSenderHttp::new(Arc::new(WebhooksConfig::default()));
+
+    for target_url in [
+        format!("http://127.0.0.1:{port}/hooks"),
+        format!("http://[::ffff:127.0.0.1]:{port}/hooks"),
+    ] {
+        let res = sender.send(request(&target_url)).await;
+        assert_matches!(
+            res,
+            Err(WebhookSendError::Transport { reason }) if reason.contains("is not public")
+        );
+    }
+
+    // Names are checked once resolved
+    let res = sender
+        .send(request(&format!("http://localhost:{port}/hooks")))
+        .await;
+    assert_matches!(
+        res,
+        Err(WebhookSendError::Transport { reason }) if reason.contains("is not public")
+    );
+}
+
+#[test_log::test(tokio::test)]
+async fn test_private_targets_allowed_explicitly() {
+    let port = start_target().await;
+    let sender = WebhookSenderHttp::new(Arc::new(WebhooksConfig {
+        allow_private_targets: true,
+        ..WebhooksConfig::default()
+    }));
+
+    let response = sender
+        .send(request(&format!("http://localhost:{port}/hooks")))
+        .await
+        .unwrap();
+    assert_eq!(response.status_code, 204);
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+fn request(target_url: &str) -> WebhookRequest {
+    WebhookRequest {
+        target_url: Url::parse(target_url).unwrap(),
+        headers: vec![],
+        body: b"{}".to_vec(),
+    }
+}
+
+/// Starts a local target responding with `204 No Content` to every request
+async fn start_target() -> u16 {
+    let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let port = listener.local_addr().unwrap().port();
+
+    tokio::spawn(async move {
+        while let Ok((mut stream, _)) = listener.accept().await {
+            let mut buf = [0u8; 4096];
+            let _ = stream.read(&mut buf).await;
+            let _ = stream
+                .write_all(b"HTTP/1.1 204 No Content\r\nConnection: close\r\n\r\n")
+                .await;
+        }
+    });
+
+    port
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
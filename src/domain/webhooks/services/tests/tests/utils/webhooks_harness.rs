@@ -0,0 +1,228 @@
+// Copyright Kamu Data, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+use std::collections::{HashMap, VecDeque};
+use std::sync::{Arc, Mutex};
+
+use chrono::{DateTime, Duration, TimeZone, Utc};
+use database_common::NoOpDatabasePlugin;
+use dill::{Catalog, CatalogBuilder};
+use internal_error::InternalError;
+use kamu_core::DatasetOwnershipService;
+use kamu_webhooks::*;
+use kamu_webhooks_inmem::{
+    InMemoryWebhookDeliveryRepository,
+    InMemoryWebhookSubscriptionRepository,
+};
+use kamu_webhooks_services::{
+    WebhookDeliveryWorkerImpl,
+    WebhookEventDispatcher,
+    WebhookSubscriptionServiceImpl,
+};
+use opendatafabric::{AccountID, DatasetID};
+use time_source::{SystemTimeSource, SystemTimeSourceStub};
+use url::Url;
+use uuid::Uuid;
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+pub(crate) const TEST_MAX_ATTEMPTS: u32 = 3;
+pub(crate) const TEST_MIN_BACKOFF_SECS: i64 = 10;
+pub(crate) const TEST_SECRET_ENCRYPTION_KEY: &str = "QfnEDcnUtGSW2pwVXaFPvZOwxyFm2BOC";
+
+pub(crate) struct WebhooksHarness {
+    pub catalog: Catalog,
+    pub subscription_service: Arc<dyn WebhookSubscriptionService>,
+    pub subscription_repo: Arc<dyn WebhookSubscriptionRepository>,
+    pub delivery_repo: Arc<dyn WebhookDeliveryRepository>,
+    pub dispatcher: Arc<WebhookEventDispatcher>,
+    pub worker: Arc<dyn WebhookDeliveryWorker>,
+    pub sender: FakeWebhookSender,
+    pub time_source: SystemTimeSourceStub,
+}
+
+impl WebhooksHarness {
+    pub fn new(dataset_owners: HashMap<DatasetID, Vec<AccountID>>) -> Self {
+        let time_source = SystemTimeSourceStub::new_set(Self::start_time());
+        let sender = FakeWebhookSender::default();
+
+        let catalog = {
+            let mut b = CatalogBuilder::new();
+
+            b.add::<InMemoryWebhookSubscriptionRepository>()
+                .add::<InMemoryWebhookDeliveryRepository>()
+                .add::<WebhookSubscriptionServiceImpl>()
+                .add::<WebhookEventDispatcher>()
+                .add::<WebhookDeliveryWorkerImpl>()
+                .add_value(WebhooksConfig {
+                    delivery_batch_size: 10,
+                    retry_policy: WebhookRetryPolicy {
+                        max_attempts: TEST_MAX_ATTEMPTS,
+                        min_backoff: Duration::try_seconds(TEST_MIN_BACKOFF_SECS).unwrap(),
+                        max_backoff: Duration::try_hours(1).unwrap(),
+                    },
+                    secret_encryption_key: Some(TEST_SECRET_ENCRYPTION_KEY.to_string()),
+                    ..WebhooksConfig::default()
+                })
+                .add_value(sender.clone())
+                .bind::<dyn WebhookSender, FakeWebhookSender>()
+                .add_value(FakeDatasetOwnershipService {
+                    owners: dataset_owners,
+                })
+                .bind::<dyn DatasetOwnershipService, FakeDatasetOwnershipService>()
+                .add_value(time_source.clone())
+                .bind::<dyn SystemTimeSource, SystemTimeSourceStub>();
+
+            NoOpDatabasePlugin::init_database_components(&mut b);
+
+            b.build()
+        };
+
+        Self {
+            subscription_service: catalog.get_one().unwrap(),
+            subscription_repo: catalog.get_one().unwrap(),
+            delivery_repo: catalog.get_one().unwrap(),
+            dispatcher: catalog.get_one().unwrap(),
+            worker: catalog.get_one().unwrap(),
+            catalog,
+            sender,
+            time_source,
+        }
+    }
+
+    pub fn start_time() -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(2050, 1, 1, 12, 0, 0).unwrap()
+    }
+
+    pub fn advance_time(&self, duration: Duration) {
+        self.time_source.set(self.time_source.now() + duration);
+    }
+
+    pub async fn create_subscription(
+        &self,
+        scope: WebhookSubscriptionScope,
+        event_types: Vec<WebhookEventType>,
+    ) -> WebhookSubscription {
+        self.subscription_service
+            .create_subscription(
+                scope,
+                Url::parse("https://example.com/hooks").unwrap(),
+                "test".to_string(),
+                event_types,
+            )
+            .await
+            .unwrap()
+    }
+
+    pub async fn deliveries(&self, subscription_id: &Uuid) -> Vec<WebhookDelivery> {
+        let mut deliveries = self
+            .subscription_service
+            .list_deliveries(subscription_id, None, None)
+            .await
+            .unwrap()
+            .list;
+        deliveries.sort_by_key(|d| d.event_type);
+        deliveries
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Records requests and replies with queued responses, succeeding once the
+/// queue is exhausted. Responses can be delayed to simulate slow targets.
+#[derive(Clone, Default)]
+pub(crate) struct FakeWebhookSender {
+    state: Arc<Mutex<FakeWebhookSenderState>>,
+}
+
+#[derive(Default)]
+struct FakeWebhookSenderState {
+    requests: Vec<WebhookRequest>,
+    responses: VecDeque<Result<u16, String>>,
+    response_delay: Option<std::time::Duration>,
+}
+
+impl FakeWebhookSender {
+    pub fn enqueue_response(&self, response: Result<u16, String>) {
+        self.state.lock().unwrap().responses.push_back(response);
+    }
+
+    pub fn set_response_delay(&self, delay: std::time::Duration) {
+        self.state.lock().unwrap().response_delay = Some(delay);
+    }
+
+    pub fn take_requests(&self) -> Vec<WebhookRequest> {
+        std::mem::take(&mut self.state.lock().unwrap().requests)
+    }
+}
+
+#[async_trait::async_trait]
+impl WebhookSender for FakeWebhookSender {
+    async fn send(&self, request: WebhookRequest) -> Result<WebhookResponse, WebhookSendError> {
+        let (response, response_delay) = {
+            let mut state = self.state.lock().unwrap();
+            state.requests.push(request);
+            (
+                state.responses.pop_front().unwrap_or(Ok(200)),
+                state.response_delay,
+            )
+        };
+
+        if let Some(delay) = response_delay {
+            tokio::time::sleep(delay).await;
+        }
+
+        match response {
+            Ok(status_code) => Ok(WebhookResponse { status_code }),
+            Err(reason) => Err(WebhookSendError::Transport { reason }),
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[derive(Clone)]
+struct FakeDatasetOwnershipService {
+    owners: HashMap<DatasetID, Vec<AccountID>>,
+}
+
+#[async_trait::async_trait]
+impl DatasetOwnershipService for FakeDatasetOwnershipService {
+    async fn get_dataset_owners(
+        &self,
+        dataset_id: &DatasetID,
+    ) -> Result<Vec<AccountID>, InternalError> {
+        Ok(self.owners.get(dataset_id).cloned().unwrap_or_default())
+    }
+
+    async fn get_owned_datasets(
+        &self,
+        account_id: &AccountID,
+    ) -> Result<Vec<DatasetID>, InternalError> {
+        Ok(self
+            .owners
+            .iter()
+            .filter(|(_, owners)| owners.contains(account_id))
+            .map(|(dataset_id, _)| dataset_id.clone())
+            .collect())
+    }
+
+    async fn is_dataset_owned_by(
+        &self,
+        dataset_id: &DatasetID,
+        account_id: &AccountID,
+    ) -> Result<bool, InternalError> {
+        Ok(self
+            .owners
+            .get(dataset_id)
+            .is_some_and(|owners| owners.contains(account_id)))
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -7,8 +7,11 @@
 // the Business Source License, use of this software will be governed
 // by the Apache License, Version 2.0.
 
+use chrono::{TimeZone, Utc};
 use indoc::indoc;
+use kamu_cli_puppet::extensions::KamuCliPuppetExt;
 use kamu_cli_puppet::KamuCliPuppet;
+use opendatafabric::*;
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
 
@@ -49,3 +52,82 @@ pub async fn test_datafusion_cli_not_launched_in_root_ws(kamu: KamuCliPuppet) {
 }
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+pub async fn test_datafusion_cli_time_travel(mut kamu: KamuCliPuppet) {
+    kamu.add_dataset(DatasetSnapshot {
+        name: "population".try_into().unwrap(),
+        kind: DatasetKind::Root,
+        metadata: vec![AddPushSource {
+            source_name: SourceState::DEFAULT_SOURCE_NAME.to_string(),
+            read: ReadStepCsv {
+                schema: Some(vec![
+                    "event_time TIMESTAMP".to_owned(),
+                    "city STRING".to_owned(),
+                    "population BIGINT".to_owned(),
+                ]),
+                ..Default::default()
+            }
+            .into(),
+            preprocess: None,
+            merge: MergeStrategyLedger {
+                primary_key: vec!["event_time".to_owned(), "city".to_owned()],
+            }
+            .into(),
+        }
+        .into()],
+    })
+    .await;
+
+    for (day, data) in [
+        (1, "2020-01-01,A,1000\n2020-01-01,B,2000\n"),
+        (2, "2020-01-02,A,1100\n2020-01-02,B,2100\n"),
+    ] {
+        kamu.set_system_time(Some(Utc.with_ymd_and_hms(2000, 1, day, 0, 0, 0).unwrap()));
+
+        let data_path = kamu.workspace_path().join(format!("data-{day}.csv"));
+        std::fs::write(&data_path, data).unwrap();
+
+        kamu.execute([
+            "ingest",
+            "population",
+            data_path.as_os_str().to_str().unwrap(),
+        ])
+        .await
+        .success();
+    }
+
+    let assert = kamu
+        .execute_with_input(
+            ["sql"],
+            indoc!(
+                "
+                select city, population
+                from dataset_as_of('population', TIMESTAMP '2000-01-01T12:00:00Z')
+                order by city;
+                "
+            ),
+        )
+        .await
+        .success();
+
+    let stdout = std::str::from_utf8(&assert.get_output().stdout).unwrap();
+
+    assert!(
+        stdout.contains(
+            indoc!(
+                r#"
+                +------+------------+
+                | city | population |
+                +------+------------+
+                | A    | 1000       |
+                | B    | 2000       |
+                +------+------------+
+                "#
+            )
+            .trim()
+        ),
+        "Unexpected output:\n{stdout}",
+    );
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
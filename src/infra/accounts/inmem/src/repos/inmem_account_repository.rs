@@ -105,6 +105,28 @@ impl AccountRepository for InMemoryAccountRepository {
         Ok(())
     }
 
+    async fn set_account_is_admin(
+        &self,
+        account_id: &AccountID,
+        is_admin: bool,
+    ) -> Result<(), SetAccountIsAdminError> {
+        let mut guard = self.state.lock().unwrap();
+
+        let Some(account) = guard.accounts_by_id.get_mut(account_id) else {
+            return Err(SetAccountIsAdminError::NotFound(AccountNotFoundByIdError {
+                account_id: account_id.clone(),
+            }));
+        };
+        account.is_admin = is_admin;
+
+        let account_name = account.account_name.clone();
+        if let Some(account) = guard.accounts_by_name.get_mut(&account_name) {
+            account.is_admin = is_admin;
+        }
+
+        Ok(())
+    }
+
     async fn get_account_by_id(
         &self,
         account_id: &AccountID,
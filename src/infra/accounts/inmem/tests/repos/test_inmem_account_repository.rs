@@ -101,6 +101,14 @@ database_transactional_test!(
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
 
+database_transactional_test!(
+    storage = inmem,
+    fixture = kamu_accounts_repo_tests::test_set_account_is_admin,
+    harness = InMemoryAccountRepositoryHarness
+);
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
 struct InMemoryAccountRepositoryHarness {
     catalog: Catalog,
 }
@@ -298,6 +298,40 @@ impl AccountRepository for PostgresAccountRepository {
 
         Ok(maybe_account_row.map(|account_row| account_row.id))
     }
+
+    async fn set_account_is_admin(
+        &self,
+        account_id: &AccountID,
+        is_admin: bool,
+    ) -> Result<(), SetAccountIsAdminError> {
+        let mut tr = self.transaction.lock().await;
+
+        let connection_mut = tr
+            .connection_mut()
+            .await
+            .map_err(SetAccountIsAdminError::Internal)?;
+
+        let update_result = sqlx::query!(
+            r#"
+            UPDATE accounts SET is_admin = $1
+              WHERE id = $2
+            "#,
+            is_admin,
+            account_id.to_string(),
+        )
+        .execute(connection_mut)
+        .await
+        .int_err()
+        .map_err(SetAccountIsAdminError::Internal)?;
+
+        if update_result.rows_affected() == 0 {
+            return Err(SetAccountIsAdminError::NotFound(AccountNotFoundByIdError {
+                account_id: account_id.clone(),
+            }));
+        }
+
+        Ok(())
+    }
 }
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
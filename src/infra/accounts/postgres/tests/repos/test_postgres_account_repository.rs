@@ -103,6 +103,14 @@ database_transactional_test!(
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
 
+database_transactional_test!(
+    storage = postgres,
+    fixture = kamu_accounts_repo_tests::test_set_account_is_admin,
+    harness = PostgresAccountRepositoryHarness
+);
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
 struct PostgresAccountRepositoryHarness {
     catalog: Catalog,
 }
@@ -374,3 +374,34 @@ pub async fn test_duplicate_github_account_email(catalog: &Catalog) {
 }
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+pub async fn test_set_account_is_admin(catalog: &Catalog) {
+    let account = make_test_account("wasya", PROVIDER_PASSWORD, "wasya");
+
+    let account_repo = catalog.get_one::<dyn AccountRepository>().unwrap();
+    account_repo.create_account(&account).await.unwrap();
+    assert!(!account.is_admin);
+
+    account_repo
+        .set_account_is_admin(&account.id, true)
+        .await
+        .unwrap();
+
+    let db_account = account_repo.get_account_by_id(&account.id).await.unwrap();
+    assert!(db_account.is_admin);
+
+    let db_account = account_repo
+        .get_account_by_name(&account.account_name)
+        .await
+        .unwrap();
+    assert!(db_account.is_admin);
+
+    assert_matches!(
+        account_repo
+            .set_account_is_admin(&AccountID::new_seeded_ed25519(b"wrong"), true)
+            .await,
+        Err(SetAccountIsAdminError::NotFound(_))
+    );
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -13,9 +13,14 @@ use std::path::{Path, PathBuf};
 use std::sync::Arc;
 
 use chrono::{DateTime, Utc};
+use datafusion::common::config::{ParquetColumnOptions, TableParquetOptions};
+use datafusion::logical_expr::window_function::row_number;
+use datafusion::logical_expr::ExprFunctionExt;
 use datafusion::prelude::*;
 use dill::{component, interface};
 use domain::{
+    CompactionCompression,
+    CompactionDataLayout,
     CompactionError,
     CompactionListener,
     CompactionMultiListener,
@@ -23,6 +28,7 @@ use domain::{
     CompactionPhase,
     CompactionResult,
     CompactionService,
+    InvalidDataLayoutError,
     InvalidDatasetKindError,
     NullCompactionListener,
     DEFAULT_MAX_SLICE_RECORDS,
@@ -41,6 +47,7 @@ use opendatafabric::{
     MetadataEvent,
     Multihash,
     OffsetInterval,
+    OperationType,
     SetVocab,
     SourceState,
 };
@@ -95,7 +102,7 @@ struct DataSliceBatchInfo {
 struct ChainFilesInfo {
     old_head: Multihash,
     old_num_blocks: usize,
-    offset_column: String,
+    vocab: DatasetVocabulary,
     data_slice_batches: Vec<DataSliceBatch>,
 }
 
@@ -235,7 +242,7 @@ impl CompactionServiceImpl {
 
         Ok(ChainFilesInfo {
             data_slice_batches,
-            offset_column: vocab.offset_column,
+            vocab,
             old_head: old_head.unwrap(),
             old_num_blocks,
         })
@@ -264,28 +271,57 @@ impl CompactionServiceImpl {
     async fn merge_files(
         &self,
         data_slice_batches: &mut [DataSliceBatch],
-        offset_column: &str,
+        vocab: &DatasetVocabulary,
+        data_layout: &CompactionDataLayout,
         compaction_dir_path: &Path,
     ) -> Result<(), CompactionError> {
         let ctx = new_session_context(self.object_store_registry.clone());
+        let offset_order = col(Column::from_name(&vocab.offset_column)).sort(true, false);
 
         for (index, data_slice_batch) in data_slice_batches.iter_mut().enumerate() {
             if let DataSliceBatch::CompactedBatch(data_slice_batch_info) = data_slice_batch {
+                // Slices are collected from the head of the chain, so we read them back
+                // in the order they were written
+                let data_slice_urls: Vec<_> = data_slice_batch_info
+                    .data_slices_batch
+                    .iter()
+                    .rev()
+                    .cloned()
+                    .collect();
+
                 let data_frame = ctx
                     .read_parquet(
-                        data_slice_batch_info.data_slices_batch.clone(),
+                        data_slice_urls.clone(),
                         datafusion::execution::options::ParquetReadOptions {
                             file_extension: "",
                             ..Default::default()
                         },
                     )
                     .await
-                    .int_err()?
-                    // TODO: PERF: Consider passing sort order hint to `read_parquet` to let DF now
-                    // that the data is already pre-sorted
-                    .sort(vec![col(Column::from_name(offset_column)).sort(true, false)])
                     .int_err()?;
 
+                Self::validate_layout_columns(&data_frame, data_layout)?;
+
+                let data_frame = if !data_layout.sort_by.is_empty()
+                    && Self::is_append_only(&ctx, &data_slice_urls, vocab).await?
+                {
+                    Self::sort_and_reassign_offsets(
+                        data_frame,
+                        vocab,
+                        &data_layout.sort_by,
+                        data_slice_batch_info.lower_bound.start_offset,
+                    )?
+                } else {
+                    if !data_layout.sort_by.is_empty() {
+                        tracing::info!(
+                            index,
+                            "Slice may contain retractions or corrections - keeping the offset \
+                             order"
+                        );
+                    }
+                    data_frame.sort(vec![offset_order.clone()]).int_err()?
+                };
+
                 let new_file_path =
                     compaction_dir_path.join(format!("merge-slice-{index}").as_str());
 
@@ -294,7 +330,7 @@ impl CompactionServiceImpl {
                         new_file_path.to_str().unwrap(),
                         datafusion::dataframe::DataFrameWriteOptions::new()
                             .with_single_file_output(true),
-                        None,
+                        Some(Self::get_write_properties(data_layout)?),
                     )
                     .await
                     .int_err()?;
@@ -305,6 +341,167 @@ impl CompactionServiceImpl {
         Ok(())
     }
 
+    fn validate_layout_columns(
+        data_frame: &DataFrame,
+        data_layout: &CompactionDataLayout,
+    ) -> Result<(), CompactionError> {
+        let schema = data_frame.schema();
+        for column in data_layout
+            .sort_by
+            .iter()
+            .chain(data_layout.bloom_filter_columns.iter())
+        {
+            if !schema.has_column_with_unqualified_name(column) {
+                return Err(InvalidDataLayoutError::new(format!(
+                    "Column '{column}' does not exist in the dataset"
+                ))
+                .into());
+            }
+        }
+        Ok(())
+    }
+
+    /// Checks that slices contain only appends using the statistics of the
+    /// operation type column in the Parquet footers, without scanning the data.
+    /// Slices without statistics are treated as containing other operations.
+    async fn is_append_only(
+        ctx: &SessionContext,
+        data_slice_urls: &[Url],
+        vocab: &DatasetVocabulary,
+    ) -> Result<bool, CompactionError> {
+        use datafusion::parquet::arrow::async_reader::{AsyncFileReader, ParquetObjectReader};
+        use datafusion::parquet::file::statistics::Statistics;
+
+        let append = OperationType::Append as i32;
+
+        for url in data_slice_urls {
+            let object_store = ctx.runtime_env().object_store(url).int_err()?;
+            let path = object_store::path::Path::from_url_path(url.path()).int_err()?;
+            let object_meta = object_store.head(&path).await.int_err()?;
+
+            let metadata = ParquetObjectReader::new(object_store, object_meta)
+                .get_metadata()
+                .await
+                .int_err()?;
+
+            let schema = metadata.file_metadata().schema_descr();
+            let Some(column_index) = (0..schema.num_columns())
+                .find(|i| schema.column(*i).path().string() == vocab.operation_type_column)
+            else {
+                return Ok(false);
+            };
+
+            for row_group in metadata.row_groups() {
+                match row_group.column(column_index).statistics() {
+                    Some(Statistics::Int32(stats))
+                        if stats.has_min_max_set()
+                            && *stats.min() == append
+                            && *stats.max() == append => {}
+                    _ => return Ok(false),
+                }
+            }
+        }
+
+        Ok(true)
+    }
+
+    // ODF requires records of a slice to be ordered by offset, so offsets are
+    // re-assigned sequentially in the new order starting from the beginning of
+    // the batch interval. The resulting slice covers exactly the same offset
+    // interval. This is only done for append-only slices, where offsets are
+    // not referenced by retractions or corrections.
+    fn sort_and_reassign_offsets(
+        data_frame: DataFrame,
+        vocab: &DatasetVocabulary,
+        sort_by: &[String],
+        start_offset: u64,
+    ) -> Result<DataFrame, CompactionError> {
+        let offset_column = Column::from_name(&vocab.offset_column);
+
+        let columns: Vec<_> = data_frame
+            .schema()
+            .fields()
+            .iter()
+            .map(|f| f.name().clone())
+            .collect();
+        let offset_type = data_frame
+            .schema()
+            .field_with_unqualified_name(&vocab.offset_column)
+            .int_err()?
+            .data_type()
+            .clone();
+
+        let order_by: Vec<_> = sort_by
+            .iter()
+            .map(|c| col(Column::from_name(c)).sort(true, false))
+            // Original offsets keep the order of records with equal keys stable
+            .chain(std::iter::once(
+                col(offset_column.clone()).sort(true, false),
+            ))
+            .collect();
+
+        let data_frame = data_frame
+            .with_column(
+                &vocab.offset_column,
+                row_number().order_by(order_by).build().int_err()?,
+            )
+            .int_err()?
+            .with_column(
+                &vocab.offset_column,
+                cast(
+                    col(offset_column.clone()) + lit(i64::try_from(start_offset).int_err()? - 1),
+                    offset_type,
+                ),
+            )
+            .int_err()?
+            .select_columns(&columns.iter().map(String::as_str).collect::<Vec<_>>())
+            .int_err()?
+            .sort(vec![col(offset_column).sort(true, false)])
+            .int_err()?;
+
+        Ok(data_frame)
+    }
+
+    fn get_write_properties(
+        data_layout: &CompactionDataLayout,
+    ) -> Result<TableParquetOptions, CompactionError> {
+        let mut options = TableParquetOptions::default();
+
+        if let Some(max_row_group_size) = data_layout.max_row_group_size {
+            options.global.max_row_group_size =
+                usize::try_from(max_row_group_size).map_err(|_| {
+                    InvalidDataLayoutError::new(format!(
+                        "Maximum row group size {max_row_group_size} is too large"
+                    ))
+                })?;
+        }
+
+        if let Some(page_index) = data_layout.page_index {
+            options.global.statistics_enabled =
+                Some(if page_index { "page" } else { "chunk" }.to_string());
+        }
+
+        if let Some(compression) = data_layout.compression {
+            options.global.compression = Some(match compression {
+                CompactionCompression::Uncompressed => "uncompressed".to_string(),
+                CompactionCompression::Snappy => "snappy".to_string(),
+                CompactionCompression::Zstd { level } => format!("zstd({level})"),
+            });
+        }
+
+        for column in &data_layout.bloom_filter_columns {
+            options.column_specific_options.insert(
+                column.clone(),
+                ParquetColumnOptions {
+                    bloom_filter_enabled: Some(true),
+                    ..Default::default()
+                },
+            );
+        }
+
+        Ok(options)
+    }
+
     fn create_run_compaction_dir(&self) -> Result<PathBuf, CompactionError> {
         let compaction_dir_path = self
             .run_info_dir
@@ -401,6 +598,7 @@ impl CompactionServiceImpl {
         max_slice_size: u64,
         max_slice_records: u64,
         keep_metadata_only: bool,
+        data_layout: &CompactionDataLayout,
         listener: Arc<dyn CompactionListener>,
     ) -> Result<CompactionResult, CompactionError> {
         let compaction_dir_path = self.create_run_compaction_dir()?;
@@ -424,7 +622,8 @@ impl CompactionServiceImpl {
         listener.begin_phase(CompactionPhase::MergeDataslices);
         self.merge_files(
             &mut chain_files_info.data_slice_batches,
-            chain_files_info.offset_column.as_str(),
+            &chain_files_info.vocab,
+            data_layout,
             &compaction_dir_path,
         )
         .await?;
@@ -488,6 +687,8 @@ impl CompactionService for CompactionServiceImpl {
             ));
         }
 
+        options.data_layout.validate()?;
+
         let listener = maybe_listener.unwrap_or(Arc::new(NullCompactionListener {}));
 
         let max_slice_size = options.max_slice_size.unwrap_or(DEFAULT_MAX_SLICE_SIZE);
@@ -501,6 +702,7 @@ impl CompactionService for CompactionServiceImpl {
                 max_slice_size,
                 max_slice_records,
                 options.keep_metadata_only,
+                &options.data_layout,
                 listener.clone(),
             )
             .await
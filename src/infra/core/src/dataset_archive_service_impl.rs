@@ -0,0 +1,467 @@
+// Copyright Kamu Data, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+use std::collections::{HashMap, HashSet};
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+use dill::*;
+use futures::StreamExt;
+use internal_error::{ErrorIntoInternal, InternalError, ResultIntoInternal};
+use kamu_core::*;
+use opendatafabric::*;
+use serde::{Deserialize, Serialize};
+use url::Url;
+
+use crate::{DatasetFactoryImpl, DatasetLayout};
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+const ARCHIVE_MANIFEST_FILE: &str = "manifest.json";
+const ARCHIVE_DATASETS_DIR: &str = "datasets";
+const ARCHIVE_FORMAT_VERSION: u32 = 1;
+const ARCHIVE_COMPRESSION_LEVEL: i32 = 3;
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Archive is produced by syncing datasets into a temporary directory using
+/// regular local FS layout and then packing that directory into a
+/// zstd-compressed tarball. Import reverses the process and syncs the unpacked
+/// datasets into the workspace as untrusted sources, which enables full
+/// validation of metadata blocks and hashes of all data and checkpoint files.
+pub struct DatasetArchiveServiceImpl {
+    dataset_repo: Arc<dyn DatasetRepository>,
+    dataset_action_authorizer: Arc<dyn auth::DatasetActionAuthorizer>,
+    dependency_graph_service: Arc<dyn DependencyGraphService>,
+    sync_svc: Arc<dyn SyncService>,
+    run_info_dir: Arc<RunInfoDir>,
+}
+
+#[component(pub)]
+#[interface(dyn DatasetArchiveService)]
+impl DatasetArchiveServiceImpl {
+    pub fn new(
+        dataset_repo: Arc<dyn DatasetRepository>,
+        dataset_action_authorizer: Arc<dyn auth::DatasetActionAuthorizer>,
+        dependency_graph_service: Arc<dyn DependencyGraphService>,
+        sync_svc: Arc<dyn SyncService>,
+        run_info_dir: Arc<RunInfoDir>,
+    ) -> Self {
+        Self {
+            dataset_repo,
+            dataset_action_authorizer,
+            dependency_graph_service,
+            sync_svc,
+            run_info_dir,
+        }
+    }
+
+    async fn resolve_export_closure(
+        &self,
+        dataset_refs: Vec<DatasetRef>,
+        recursive: bool,
+    ) -> Result<Vec<DatasetHandle>, ExportError> {
+        let mut handles = Vec::new();
+        for dataset_ref in dataset_refs {
+            let hdl = self.dataset_repo.resolve_dataset_ref(&dataset_ref).await?;
+            if !handles.contains(&hdl) {
+                handles.push(hdl);
+            }
+        }
+
+        if !recursive {
+            return Ok(handles);
+        }
+
+        let dataset_ids = handles.into_iter().map(|hdl| hdl.id).collect();
+        let closure_ids: Vec<_> = self
+            .dependency_graph_service
+            .get_recursive_upstream_dependencies(dataset_ids)
+            .await
+            .int_err()?
+            .collect()
+            .await;
+
+        // Upstream datasets must come first, so that import can be performed in order
+        let mut upstream = HashMap::with_capacity(closure_ids.len());
+        for dataset_id in &closure_ids {
+            let upstream_ids: Vec<_> = self
+                .dependency_graph_service
+                .get_upstream_dependencies(dataset_id)
+                .await
+                .int_err()?
+                .collect()
+                .await;
+            upstream.insert(dataset_id.clone(), upstream_ids);
+        }
+
+        let mut handles = Vec::with_capacity(closure_ids.len());
+        for dataset_id in Self::sort_upstream_first(&closure_ids, &upstream) {
+            handles.push(
+                self.dataset_repo
+                    .resolve_dataset_ref(&dataset_id.into_local_ref())
+                    .await?,
+            );
+        }
+
+        Ok(handles)
+    }
+
+    /// Orders datasets topologically so that every dataset follows all of its
+    /// upstream dependencies, otherwise preserving the original order
+    fn sort_upstream_first(
+        dataset_ids: &[DatasetID],
+        upstream: &HashMap<DatasetID, Vec<DatasetID>>,
+    ) -> Vec<DatasetID> {
+        fn visit(
+            dataset_id: &DatasetID,
+            upstream: &HashMap<DatasetID, Vec<DatasetID>>,
+            visited: &mut HashSet<DatasetID>,
+            ordered: &mut Vec<DatasetID>,
+        ) {
+            if !visited.insert(dataset_id.clone()) {
+                return;
+            }
+            for upstream_id in upstream.get(dataset_id).into_iter().flatten() {
+                if upstream.contains_key(upstream_id) {
+                    visit(upstream_id, upstream, visited, ordered);
+                }
+            }
+            ordered.push(dataset_id.clone());
+        }
+
+        let mut visited = HashSet::with_capacity(dataset_ids.len());
+        let mut ordered = Vec::with_capacity(dataset_ids.len());
+        for dataset_id in dataset_ids {
+            visit(dataset_id, upstream, &mut visited, &mut ordered);
+        }
+        ordered
+    }
+
+    fn archived_dataset_dir(staging_dir: &Path, dataset_id: &DatasetID) -> PathBuf {
+        staging_dir
+            .join(ARCHIVE_DATASETS_DIR)
+            .join(dataset_id.as_multibase().to_stack_string())
+    }
+
+    fn archived_dataset_url(staging_dir: &Path, dataset_id: &DatasetID) -> Url {
+        let mut url =
+            Url::from_directory_path(Self::archived_dataset_dir(staging_dir, dataset_id)).unwrap();
+        if !url.path().ends_with('/') {
+            url.set_path(&format!("{}/", url.path()));
+        }
+        url
+    }
+
+    /// Reads the dataset ID from the seed block of the unpacked metadata chain.
+    /// Hashes of blocks are validated later during the sync, so a tampered seed
+    /// will not make it into the workspace.
+    async fn read_archived_dataset_id(
+        staging_dir: &Path,
+        archived: &ArchivedDataset,
+    ) -> Result<DatasetID, ImportError> {
+        let dataset = DatasetFactoryImpl::get_local_fs(DatasetLayout::new(
+            Self::archived_dataset_dir(staging_dir, &archived.id),
+        ));
+
+        let seed = dataset
+            .as_metadata_chain()
+            .accept_one(SearchSeedVisitor::new())
+            .await
+            .map_err(|e| ArchiveInvalidError {
+                message: format!(
+                    "Failed to read metadata chain of dataset {}: {e}",
+                    archived.alias
+                ),
+            })?
+            .into_event()
+            .ok_or_else(|| ArchiveInvalidError {
+                message: format!("Metadata chain of dataset {} has no seed", archived.alias),
+            })?;
+
+        Ok(seed.dataset_id)
+    }
+
+    fn pack(staging_dir: &Path, archive_path: &Path) -> Result<(), InternalError> {
+        let file = std::fs::File::create(archive_path).int_err()?;
+        let encoder = zstd::Encoder::new(file, ARCHIVE_COMPRESSION_LEVEL)
+            .int_err()?
+            .auto_finish();
+
+        let mut builder = tar::Builder::new(encoder);
+        builder
+            .append_path_with_name(
+                staging_dir.join(ARCHIVE_MANIFEST_FILE),
+                ARCHIVE_MANIFEST_FILE,
+            )
+            .int_err()?;
+        builder
+            .append_dir_all(ARCHIVE_DATASETS_DIR, staging_dir.join(ARCHIVE_DATASETS_DIR))
+            .int_err()?;
+        builder.into_inner().int_err()?;
+
+        Ok(())
+    }
+
+    fn unpack(archive_path: &Path, staging_dir: &Path) -> Result<(), ImportError> {
+        let file = std::fs::File::open(archive_path).int_err()?;
+        let decoder = zstd::Decoder::new(file).int_err()?;
+
+        // Note: `unpack` refuses to write entries outside of the target directory
+        tar::Archive::new(decoder)
+            .unpack(staging_dir)
+            .map_err(|e| ArchiveInvalidError {
+                message: format!("Failed to unpack the archive: {e}"),
+            })?;
+
+        Ok(())
+    }
+
+    fn read_manifest(staging_dir: &Path) -> Result<Vec<ArchivedDataset>, ImportError> {
+        let manifest_path = staging_dir.join(ARCHIVE_MANIFEST_FILE);
+        if !manifest_path.is_file() {
+            return Err(ArchiveInvalidError {
+                message: "Archive does not contain a manifest".to_string(),
+            }
+            .into());
+        }
+
+        let manifest: ArchiveManifest =
+            serde_json::from_slice(&std::fs::read(&manifest_path).int_err()?).map_err(|e| {
+                ArchiveInvalidError {
+                    message: format!("Malformed manifest: {e}"),
+                }
+            })?;
+
+        if manifest.version != ARCHIVE_FORMAT_VERSION {
+            return Err(ArchiveInvalidError {
+                message: format!(
+                    "Unsupported archive version {}, expected {ARCHIVE_FORMAT_VERSION}",
+                    manifest.version
+                ),
+            }
+            .into());
+        }
+
+        manifest
+            .datasets
+            .into_iter()
+            .map(|entry| entry.try_into())
+            .collect()
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[async_trait::async_trait]
+impl DatasetArchiveService for DatasetArchiveServiceImpl {
+    #[tracing::instrument(level = "info", skip_all, fields(?dataset_refs, ?archive_path, ?options))]
+    async fn export_datasets(
+        &self,
+        dataset_refs: Vec<DatasetRef>,
+        archive_path: &Path,
+        options: ExportOptions,
+    ) -> Result<ExportResult, ExportError> {
+        let handles = self
+            .resolve_export_closure(dataset_refs, options.recursive)
+            .await?;
+
+        let staging_dir = tempfile::tempdir_in(self.run_info_dir.as_path()).int_err()?;
+
+        let mut datasets = Vec::with_capacity(handles.len());
+        for hdl in handles {
+            self.dataset_action_authorizer
+                .check_action_allowed(&hdl, auth::DatasetAction::Read)
+                .await?;
+
+            let head = self
+                .dataset_repo
+                .get_dataset_by_handle(&hdl)
+                .as_metadata_chain()
+                .resolve_ref(&BlockRef::Head)
+                .await
+                .int_err()?;
+
+            tracing::info!(dataset = %hdl, %head, "Writing dataset into the archive");
+
+            self.sync_svc
+                .sync(
+                    &hdl.as_any_ref(),
+                    &Self::archived_dataset_url(staging_dir.path(), &hdl.id).into(),
+                    SyncOptions {
+                        trust_source: Some(true),
+                        create_if_not_exists: true,
+                        force: false,
+                    },
+                    None,
+                )
+                .await
+                .map_err(|e| match e {
+                    SyncError::Access(e) => ExportError::Access(e),
+                    e => ExportError::Internal(e.int_err()),
+                })?;
+
+            datasets.push(ArchivedDataset {
+                id: hdl.id,
+                alias: hdl.alias,
+                head,
+            });
+        }
+
+        let manifest = ArchiveManifest {
+            version: ARCHIVE_FORMAT_VERSION,
+            datasets: datasets.iter().map(Into::into).collect(),
+        };
+        std::fs::write(
+            staging_dir.path().join(ARCHIVE_MANIFEST_FILE),
+            serde_json::to_vec_pretty(&manifest).int_err()?,
+        )
+        .int_err()?;
+
+        let staging_path = staging_dir.path().to_path_buf();
+        let target_path = archive_path.to_path_buf();
+        tokio::task::spawn_blocking(move || Self::pack(&staging_path, &target_path))
+            .await
+            .int_err()??;
+
+        Ok(ExportResult {
+            archive_path: archive_path.to_path_buf(),
+            datasets,
+        })
+    }
+
+    #[tracing::instrument(level = "info", skip_all, fields(?archive_path, ?options))]
+    async fn import_datasets(
+        &self,
+        archive_path: &Path,
+        options: ImportOptions,
+    ) -> Result<ImportResult, ImportError> {
+        let staging_dir = tempfile::tempdir_in(self.run_info_dir.as_path()).int_err()?;
+
+        let staging_path = staging_dir.path().to_path_buf();
+        let source_path = archive_path.to_path_buf();
+        tokio::task::spawn_blocking(move || Self::unpack(&source_path, &staging_path))
+            .await
+            .int_err()??;
+
+        let archived_datasets = Self::read_manifest(staging_dir.path())?;
+
+        let mut results = Vec::with_capacity(archived_datasets.len());
+        for archived in archived_datasets {
+            let alias = if self.dataset_repo.is_multi_tenant() {
+                archived.alias.clone()
+            } else {
+                DatasetAlias::new(None, archived.alias.dataset_name.clone())
+            };
+
+            tracing::info!(%alias, id = %archived.id, head = %archived.head, "Importing dataset");
+
+            let dataset_id = Self::read_archived_dataset_id(staging_dir.path(), &archived).await?;
+            if dataset_id != archived.id {
+                return Err(ArchiveInvalidError {
+                    message: format!(
+                        "ID of imported dataset {alias} is {dataset_id} while manifest specifies \
+                         {}",
+                        archived.id
+                    ),
+                }
+                .into());
+            }
+
+            // Source is treated as untrusted to validate the chain and all object hashes
+            let sync_result = self
+                .sync_svc
+                .sync(
+                    &Self::archived_dataset_url(staging_dir.path(), &archived.id).into(),
+                    &alias.as_any_ref(),
+                    SyncOptions {
+                        trust_source: Some(false),
+                        create_if_not_exists: true,
+                        force: options.force,
+                    },
+                    None,
+                )
+                .await
+                .map_err(|e| ImportError::SyncFailed {
+                    alias: alias.clone(),
+                    source: e,
+                })?;
+
+            let new_head = self
+                .dataset_repo
+                .find_dataset_by_ref(&alias.as_local_ref())
+                .await
+                .int_err()?
+                .as_metadata_chain()
+                .resolve_ref(&BlockRef::Head)
+                .await
+                .int_err()?;
+
+            if new_head != archived.head {
+                return Err(ArchiveInvalidError {
+                    message: format!(
+                        "Head of imported dataset {alias} is {new_head} while manifest specifies \
+                         {}",
+                        archived.head
+                    ),
+                }
+                .into());
+            }
+
+            results.push((archived, sync_result));
+        }
+
+        Ok(ImportResult { datasets: results })
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// Manifest
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct ArchiveManifest {
+    version: u32,
+    datasets: Vec<ArchiveManifestEntry>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct ArchiveManifestEntry {
+    id: String,
+    alias: String,
+    head: String,
+}
+
+impl From<&ArchivedDataset> for ArchiveManifestEntry {
+    fn from(value: &ArchivedDataset) -> Self {
+        Self {
+            id: value.id.as_did_str().to_string(),
+            alias: value.alias.to_string(),
+            head: value.head.as_multibase().to_string(),
+        }
+    }
+}
+
+impl TryFrom<ArchiveManifestEntry> for ArchivedDataset {
+    type Error = ImportError;
+
+    fn try_from(value: ArchiveManifestEntry) -> Result<Self, Self::Error> {
+        let invalid = |e: &dyn std::fmt::Display| ArchiveInvalidError {
+            message: format!("Malformed manifest entry: {e}"),
+        };
+
+        Ok(Self {
+            id: DatasetID::from_did_str(&value.id).map_err(|e| invalid(&e))?,
+            alias: DatasetAlias::try_from(value.alias.as_str()).map_err(|e| invalid(&e))?,
+            head: Multihash::from_multibase(&value.head).map_err(|e| invalid(&e))?,
+        })
+    }
+}
@@ -0,0 +1,176 @@
+// Copyright Kamu Data, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+use std::collections::{BTreeMap, BTreeSet};
+use std::sync::Arc;
+
+use dill::*;
+use futures::TryStreamExt;
+use internal_error::{InternalError, ResultIntoInternal};
+use kamu_core::*;
+use kamu_datasets::{DatasetDependencyEntry, DatasetDependencyRepository};
+use opendatafabric::DatasetID;
+
+use crate::DependencyGraphRepositoryInMemory;
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Compares persisted dependency graph with the one rebuilt from metadata
+/// chains of all datasets
+pub struct DependencyGraphConsistencyChecker {
+    dataset_repo: Arc<dyn DatasetRepository>,
+    dataset_dependency_repo: Arc<dyn DatasetDependencyRepository>,
+}
+
+#[component(pub)]
+impl DependencyGraphConsistencyChecker {
+    pub fn new(
+        dataset_repo: Arc<dyn DatasetRepository>,
+        dataset_dependency_repo: Arc<dyn DatasetDependencyRepository>,
+    ) -> Self {
+        Self {
+            dataset_repo,
+            dataset_dependency_repo,
+        }
+    }
+
+    /// Rebuilds the graph from metadata chains and reports the differences.
+    /// When `repair` is set the persisted graph is overwritten to match
+    /// metadata chains.
+    #[tracing::instrument(level = "info", skip_all, fields(repair))]
+    pub async fn check(
+        &self,
+        repair: bool,
+    ) -> Result<DependencyGraphConsistencyReport, InternalError> {
+        let expected = self.rebuild_from_metadata_chains().await?;
+        let persisted = self.load_persisted().await?;
+
+        let mut report = DependencyGraphConsistencyReport::default();
+
+        let downstream_dataset_ids: BTreeSet<_> =
+            expected.keys().chain(persisted.keys()).cloned().collect();
+
+        for downstream_dataset_id in downstream_dataset_ids {
+            let empty = BTreeSet::new();
+            let expected_upstream_ids = expected.get(&downstream_dataset_id).unwrap_or(&empty);
+            let persisted_upstream_ids = persisted.get(&downstream_dataset_id).unwrap_or(&empty);
+
+            if expected_upstream_ids == persisted_upstream_ids {
+                continue;
+            }
+
+            report.missing_dependencies.extend(
+                expected_upstream_ids
+                    .difference(persisted_upstream_ids)
+                    .map(|id| {
+                        DatasetDependencyEntry::new(downstream_dataset_id.clone(), id.clone())
+                    }),
+            );
+            report.unexpected_dependencies.extend(
+                persisted_upstream_ids
+                    .difference(expected_upstream_ids)
+                    .map(|id| {
+                        DatasetDependencyEntry::new(downstream_dataset_id.clone(), id.clone())
+                    }),
+            );
+
+            if repair {
+                let upstream_ids: Vec<_> = expected_upstream_ids.iter().cloned().collect();
+                self.dataset_dependency_repo
+                    .set_upstream_dependencies(&downstream_dataset_id, &upstream_ids)
+                    .await
+                    .int_err()?;
+            }
+        }
+
+        report.repaired = repair && !report.is_consistent();
+
+        // A repaired table fully reflects the metadata chains, no bootstrap is needed
+        if repair {
+            self.dataset_dependency_repo
+                .mark_bootstrapped()
+                .await
+                .int_err()?;
+        }
+
+        tracing::info!(
+            num_missing = report.missing_dependencies.len(),
+            num_unexpected = report.unexpected_dependencies.len(),
+            repaired = report.repaired,
+            "Dependency graph consistency check finished",
+        );
+
+        Ok(report)
+    }
+
+    async fn rebuild_from_metadata_chains(
+        &self,
+    ) -> Result<BTreeMap<DatasetID, BTreeSet<DatasetID>>, InternalError> {
+        let repository = DependencyGraphRepositoryInMemory::new(self.dataset_repo.clone());
+
+        let mut dependencies_stream = repository.list_dependencies_of_all_datasets();
+        let mut result = BTreeMap::new();
+
+        while let Some(DatasetDependencies {
+            downstream_dataset_id,
+            upstream_dataset_ids,
+        }) = dependencies_stream.try_next().await?
+        {
+            if !upstream_dataset_ids.is_empty() {
+                result.insert(
+                    downstream_dataset_id,
+                    upstream_dataset_ids.into_iter().collect(),
+                );
+            }
+        }
+
+        Ok(result)
+    }
+
+    async fn load_persisted(
+        &self,
+    ) -> Result<BTreeMap<DatasetID, BTreeSet<DatasetID>>, InternalError> {
+        let mut entries_stream = self.dataset_dependency_repo.list_all_dependencies();
+        let mut result: BTreeMap<_, BTreeSet<_>> = BTreeMap::new();
+
+        while let Some(DatasetDependencyEntry {
+            downstream_dataset_id,
+            upstream_dataset_id,
+        }) = entries_stream.try_next().await?
+        {
+            result
+                .entry(downstream_dataset_id)
+                .or_default()
+                .insert(upstream_dataset_id);
+        }
+
+        Ok(result)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct DependencyGraphConsistencyReport {
+    /// Dependencies declared in metadata chains but absent in the persisted
+    /// graph
+    pub missing_dependencies: Vec<DatasetDependencyEntry>,
+    /// Dependencies present in the persisted graph but not declared in
+    /// metadata chains
+    pub unexpected_dependencies: Vec<DatasetDependencyEntry>,
+    pub repaired: bool,
+}
+
+impl DependencyGraphConsistencyReport {
+    pub fn is_consistent(&self) -> bool {
+        self.missing_dependencies.is_empty() && self.unexpected_dependencies.is_empty()
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
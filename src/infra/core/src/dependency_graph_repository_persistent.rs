@@ -0,0 +1,219 @@
+// Copyright Kamu Data, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+use std::collections::BTreeMap;
+use std::sync::Arc;
+
+use database_common::DatabaseTransactionRunner;
+use dill::*;
+use internal_error::{InternalError, ResultIntoInternal};
+use kamu_core::*;
+use kamu_datasets::{DatasetDependencyEntry, DatasetDependencyRepository};
+use messaging_outbox::{
+    MessageConsumer,
+    MessageConsumerMeta,
+    MessageConsumerT,
+    MessageConsumptionDurability,
+};
+use opendatafabric::DatasetID;
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Reads the dependency graph from [`DatasetDependencyRepository`] instead of
+/// scanning metadata chains of all datasets.
+///
+/// Only datasets that have at least one dependency are stored, datasets
+/// without any are discovered by the graph service on first access.
+///
+/// Until the repository is marked as bootstrapped (e.g. the database was just
+/// created for an existing workspace), the graph is bootstrapped by scanning
+/// the metadata chains once and the found dependencies are persisted. Rows
+/// written by lifecycle events before that do not prevent the bootstrap.
+pub struct DependencyGraphRepositoryPersistent {
+    catalog: Catalog,
+    metadata_chains_repository: Arc<dyn DependencyGraphRepository>,
+}
+
+impl DependencyGraphRepositoryPersistent {
+    /// The catalog must not be transactional, as the repository opens a new
+    /// transaction every time the graph is loaded
+    pub fn new(
+        catalog: Catalog,
+        metadata_chains_repository: Arc<dyn DependencyGraphRepository>,
+    ) -> Self {
+        Self {
+            catalog,
+            metadata_chains_repository,
+        }
+    }
+
+    async fn load_entries(&self) -> Result<Vec<DatasetDependencyEntry>, InternalError> {
+        DatabaseTransactionRunner::new(self.catalog.clone())
+            .transactional_with(
+                |dataset_dependency_repo: Arc<dyn DatasetDependencyRepository>| async move {
+                    use futures::TryStreamExt;
+
+                    dataset_dependency_repo
+                        .list_all_dependencies()
+                        .try_collect::<Vec<_>>()
+                        .await
+                },
+            )
+            .await
+    }
+
+    async fn is_bootstrapped(&self) -> Result<bool, InternalError> {
+        DatabaseTransactionRunner::new(self.catalog.clone())
+            .transactional_with(
+                |dataset_dependency_repo: Arc<dyn DatasetDependencyRepository>| async move {
+                    dataset_dependency_repo.is_bootstrapped().await.int_err()
+                },
+            )
+            .await
+    }
+
+    #[tracing::instrument(level = "info", skip_all)]
+    async fn bootstrap_from_metadata_chains(&self) -> Result<(), InternalError> {
+        use futures::TryStreamExt;
+
+        let dependencies: Vec<_> = self
+            .metadata_chains_repository
+            .list_dependencies_of_all_datasets()
+            .try_filter(|d| futures::future::ready(!d.upstream_dataset_ids.is_empty()))
+            .try_collect()
+            .await?;
+
+        DatabaseTransactionRunner::new(self.catalog.clone())
+            .transactional_with(
+                |dataset_dependency_repo: Arc<dyn DatasetDependencyRepository>| {
+                    let dependencies = &dependencies;
+                    async move {
+                        // Metadata chains are the source of truth, so entries written by
+                        // lifecycle events in the meantime are simply overwritten
+                        for d in dependencies {
+                            dataset_dependency_repo
+                                .set_upstream_dependencies(
+                                    &d.downstream_dataset_id,
+                                    &d.upstream_dataset_ids,
+                                )
+                                .await
+                                .int_err()?;
+                        }
+                        dataset_dependency_repo.mark_bootstrapped().await.int_err()
+                    }
+                },
+            )
+            .await?;
+
+        tracing::info!(
+            num_datasets = dependencies.len(),
+            "Bootstrapped persisted dependencies from metadata chains"
+        );
+
+        Ok(())
+    }
+}
+
+impl DependencyGraphRepository for DependencyGraphRepositoryPersistent {
+    #[tracing::instrument(level = "debug", skip_all)]
+    fn list_dependencies_of_all_datasets(&self) -> DatasetDependenciesIDStream {
+        Box::pin(async_stream::try_stream! {
+            if !self.is_bootstrapped().await? {
+                self.bootstrap_from_metadata_chains().await?;
+            }
+
+            let entries = self.load_entries().await?;
+
+            tracing::debug!(num_edges = entries.len(), "Loaded persisted dependencies");
+
+            let mut upstream_ids_by_downstream_id: BTreeMap<DatasetID, Vec<DatasetID>> =
+                BTreeMap::new();
+            for DatasetDependencyEntry {
+                downstream_dataset_id,
+                upstream_dataset_id,
+            } in entries
+            {
+                upstream_ids_by_downstream_id
+                    .entry(downstream_dataset_id)
+                    .or_default()
+                    .push(upstream_dataset_id);
+            }
+
+            for (downstream_dataset_id, upstream_dataset_ids) in upstream_ids_by_downstream_id {
+                yield DatasetDependencies {
+                    downstream_dataset_id,
+                    upstream_dataset_ids,
+                };
+            }
+        })
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Keeps [`DatasetDependencyRepository`] in sync with dataset lifecycle
+/// events. Runs in the same transaction as the operation that changed the
+/// dependencies.
+pub struct DependencyGraphPersistenceMessageConsumer {
+    dataset_dependency_repo: Arc<dyn DatasetDependencyRepository>,
+}
+
+#[component(pub)]
+#[interface(dyn MessageConsumer)]
+#[interface(dyn MessageConsumerT<DatasetLifecycleMessage>)]
+#[meta(MessageConsumerMeta {
+    consumer_name: MESSAGE_CONSUMER_KAMU_CORE_DEPENDENCY_GRAPH_REPOSITORY,
+    feeding_producers: &[MESSAGE_PRODUCER_KAMU_CORE_DATASET_SERVICE],
+    durability: MessageConsumptionDurability::BestEffort,
+})]
+impl DependencyGraphPersistenceMessageConsumer {
+    pub fn new(dataset_dependency_repo: Arc<dyn DatasetDependencyRepository>) -> Self {
+        Self {
+            dataset_dependency_repo,
+        }
+    }
+}
+
+impl MessageConsumer for DependencyGraphPersistenceMessageConsumer {}
+
+#[async_trait::async_trait]
+impl MessageConsumerT<DatasetLifecycleMessage> for DependencyGraphPersistenceMessageConsumer {
+    #[tracing::instrument(level = "debug", skip_all, fields(?message))]
+    async fn consume_message(
+        &self,
+        _: &Catalog,
+        message: &DatasetLifecycleMessage,
+    ) -> Result<(), InternalError> {
+        match message {
+            DatasetLifecycleMessage::Created(_) => {
+                // No action required: datasets without dependencies are not stored
+                Ok(())
+            }
+
+            DatasetLifecycleMessage::DataUpdated(_) | DatasetLifecycleMessage::Renamed(_) => {
+                // No action required: dependencies are tracked by dataset IDs
+                Ok(())
+            }
+
+            DatasetLifecycleMessage::DependenciesUpdated(message) => self
+                .dataset_dependency_repo
+                .set_upstream_dependencies(&message.dataset_id, &message.new_upstream_ids)
+                .await
+                .int_err(),
+
+            DatasetLifecycleMessage::Deleted(message) => self
+                .dataset_dependency_repo
+                .delete_dependencies_of_dataset(&message.dataset_id)
+                .await
+                .int_err(),
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -138,6 +138,26 @@ impl DependencyGraphServiceInMemory {
         Ok(())
     }
 
+    /// Datasets without any dependencies are not necessarily known to the
+    /// repository (e.g. the persistent one only stores edges), so any dataset
+    /// that is not in the graph yet is treated as an isolated node
+    async fn ensure_dataset_nodes(&self, dataset_ids: &[DatasetID]) {
+        {
+            let state = self.state.read().await;
+            if dataset_ids
+                .iter()
+                .all(|dataset_id| state.dataset_node_indices.contains_key(dataset_id))
+            {
+                return;
+            }
+        }
+
+        let mut state = self.state.write().await;
+        for dataset_id in dataset_ids {
+            state.get_or_create_dataset_node(dataset_id);
+        }
+    }
+
     /// Tracks a dependency between upstream and downstream dataset
     #[tracing::instrument(level = "trace", skip_all, fields(%dataset_upstream_id, %dataset_downstream_id))]
     fn add_dependency(
@@ -284,6 +304,8 @@ impl DependencyGraphService for DependencyGraphServiceInMemory {
             .map_err(GetDependenciesError::Internal)
             .unwrap();
 
+        self.ensure_dataset_nodes(&dataset_ids).await;
+
         let result = self
             .run_recursive_reversed_breadth_first_search(dataset_ids)
             .await?;
@@ -301,6 +323,8 @@ impl DependencyGraphService for DependencyGraphServiceInMemory {
             .map_err(GetDependenciesError::Internal)
             .unwrap();
 
+        self.ensure_dataset_nodes(&dataset_ids).await;
+
         let result = self.run_recursive_depth_first_search(dataset_ids).await?;
 
         Ok(Box::pin(tokio_stream::iter(result)))
@@ -317,6 +341,9 @@ impl DependencyGraphService for DependencyGraphServiceInMemory {
             .int_err()
             .map_err(GetDependenciesError::Internal)?;
 
+        self.ensure_dataset_nodes(std::slice::from_ref(dataset_id))
+            .await;
+
         let downstream_node_datasets: Vec<_> = {
             let state = self.state.read().await;
 
@@ -351,6 +378,9 @@ impl DependencyGraphService for DependencyGraphServiceInMemory {
             .int_err()
             .map_err(GetDependenciesError::Internal)?;
 
+        self.ensure_dataset_nodes(std::slice::from_ref(dataset_id))
+            .await;
+
         let upstream_node_datasets: Vec<_> = {
             let state = self.state.read().await;
 
@@ -397,14 +427,13 @@ impl MessageConsumerT<DatasetLifecycleMessage> for DependencyGraphServiceInMemor
             }
 
             DatasetLifecycleMessage::Deleted(message) => {
-                let node_index = state.get_dataset_node(&message.dataset_id).int_err()?;
-
-                state.datasets_graph.remove_node(node_index);
-                state.dataset_node_indices.remove(&message.dataset_id);
+                if let Some(node_index) = state.dataset_node_indices.remove(&message.dataset_id) {
+                    state.datasets_graph.remove_node(node_index);
+                }
             }
 
             DatasetLifecycleMessage::DependenciesUpdated(message) => {
-                let node_index = state.get_dataset_node(&message.dataset_id).int_err()?;
+                let node_index = state.get_or_create_dataset_node(&message.dataset_id);
 
                 let existing_upstream_ids: HashSet<_> = state
                     .datasets_graph
@@ -429,6 +458,10 @@ impl MessageConsumerT<DatasetLifecycleMessage> for DependencyGraphServiceInMemor
                     self.add_dependency(&mut state, added_id, &message.dataset_id);
                 }
             }
+
+            DatasetLifecycleMessage::DataUpdated(_) | DatasetLifecycleMessage::Renamed(_) => {
+                // No action required
+            }
         }
 
         Ok(())
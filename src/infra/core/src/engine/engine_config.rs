@@ -9,8 +9,14 @@
 
 use std::time::Duration;
 
+use super::EngineResources;
+
 #[derive(Debug, Clone)]
 pub struct ODFEngineConfig {
     pub start_timeout: Duration,
     pub shutdown_timeout: Duration,
+    /// Arguments passed to the engine container
+    pub args: Vec<String>,
+    /// Resource limits of the engine container
+    pub resources: EngineResources,
 }
@@ -42,14 +42,24 @@ impl EngineContainer {
         let stdout_file = std::fs::File::create(&logs_config.stdout_path)?;
         let stderr_file = std::fs::File::create(&logs_config.stderr_path)?;
 
-        let container = container_runtime
+        let mut run_cmd = container_runtime
             .run_attached(image)
             .container_name(format!("kamu-engine-{operation_id}"))
             .volumes(volumes)
             .expose_port(Self::ADAPTER_PORT)
+            .args(engine_config.args)
             .stdout(stdout_file)
             .stderr(stderr_file)
-            .terminate_timeout(engine_config.shutdown_timeout)
+            .terminate_timeout(engine_config.shutdown_timeout);
+
+        if let Some(memory) = engine_config.resources.memory {
+            run_cmd = run_cmd.memory(memory);
+        }
+        if let Some(cpus) = engine_config.resources.cpus {
+            run_cmd = run_cmd.cpus(cpus);
+        }
+
+        let container = run_cmd
             .spawn()
             .map_err(|e| EngineError::internal(e, logs_config.log_files()))?;
 
@@ -0,0 +1,44 @@
+// Copyright Kamu Data, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+use observability::*;
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+pub struct EngineMetrics {
+    pub provisioning_wait_seconds: HistogramVec,
+}
+
+#[dill::component(pub)]
+#[dill::interface(dyn MetricsProvider)]
+#[dill::scope(dill::Singleton)]
+impl EngineMetrics {
+    pub fn new() -> Self {
+        Self {
+            provisioning_wait_seconds: HistogramVec::new(
+                HistogramOpts::new(
+                    "kamu_engine_provisioning_wait_seconds",
+                    "Time spent waiting for an engine to be provisioned, including pulling the \
+                     image and waiting for a free concurrency slot",
+                )
+                .buckets(DEFAULT_DURATION_BUCKETS.to_vec()),
+                &["engine"],
+            )
+            .unwrap(),
+        }
+    }
+}
+
+impl MetricsProvider for EngineMetrics {
+    fn register(&self, registry: &Registry) -> Result<(), prometheus::Error> {
+        registry.register(Box::new(self.provisioning_wait_seconds.clone()))
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -64,7 +64,9 @@ impl ODFEngine {
             }
             ObjectRepositoryProtocol::Memory
             | ObjectRepositoryProtocol::Http
-            | ObjectRepositoryProtocol::S3 => {
+            | ObjectRepositoryProtocol::S3
+            | ObjectRepositoryProtocol::Gcs
+            | ObjectRepositoryProtocol::Azure => {
                 Arc::new(EngineIoStrategyRemoteProxy::new(self.dataset_repo.clone()))
             }
         }
@@ -81,6 +83,9 @@ impl ODFEngine {
 
         let output_data_path = request.output_data_path.clone();
 
+        let trace_parent = observability::current_trace_context().map(|ctx| ctx.to_traceparent());
+        engine_client.set_trace_parent(trace_parent.as_deref());
+
         let response = engine_client.execute_raw_query(request).await;
 
         tracing::info!(?response, "Operation response");
@@ -133,6 +138,9 @@ impl ODFEngine {
         let new_checkpoint_path = request.new_checkpoint_path.clone();
         let new_data_path = request.new_data_path.clone();
 
+        let trace_parent = observability::current_trace_context().map(|ctx| ctx.to_traceparent());
+        engine_client.set_trace_parent(trace_parent.as_deref());
+
         let response = engine_client.execute_transform(request).await;
 
         tracing::info!(?response, "Operation response");
@@ -7,28 +7,25 @@
 // the Business Source License, use of this software will be governed
 // by the Apache License, Version 2.0.
 
-use std::collections::HashSet;
+use std::collections::{HashMap, HashSet};
 use std::sync::{Arc, Mutex};
 use std::time::Duration;
 
 use container_runtime::*;
-use internal_error::{ErrorIntoInternal, ResultIntoInternal};
+use internal_error::ResultIntoInternal;
 use kamu_core::engine::*;
 use kamu_core::*;
 
 use super::engine_odf::*;
-use crate::utils::docker_images;
 use crate::*;
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
 
 pub struct EngineProvisionerLocal {
     config: EngineProvisionerLocalConfig,
-    spark_engine: Arc<dyn Engine>,
-    flink_engine: Arc<dyn Engine>,
-    datafusion_engine: Arc<dyn Engine>,
-    risingwave_engine: Arc<dyn Engine>,
+    engines: HashMap<String, (EngineSpec, Arc<dyn Engine>)>,
     container_runtime: Arc<ContainerRuntime>,
+    metrics: Option<Arc<EngineMetrics>>,
     inner: Arc<Inner>,
 }
 
@@ -45,48 +42,48 @@ struct State {
 #[dill::component(pub)]
 #[dill::interface(dyn EngineProvisioner)]
 impl EngineProvisionerLocal {
+    /// # Arguments
+    ///
+    /// * `engine_registry` - engines available for provisioning, defaults to
+    ///   the built-in engines when not present in the catalog
     #[allow(clippy::needless_pass_by_value)]
     pub fn new(
         config: EngineProvisionerLocalConfig,
+        engine_registry: Option<Arc<EngineRegistry>>,
         container_runtime: Arc<ContainerRuntime>,
         dataset_repo: Arc<dyn DatasetRepository>,
         run_info_dir: Arc<RunInfoDir>,
+        metrics: Option<Arc<EngineMetrics>>,
     ) -> Self {
-        let engine_config = ODFEngineConfig {
-            start_timeout: config.start_timeout,
-            shutdown_timeout: config.shutdown_timeout,
-        };
+        let engine_registry = engine_registry.unwrap_or_default();
+
+        let engines = engine_registry
+            .engines()
+            .iter()
+            .map(|spec| {
+                let engine_config = ODFEngineConfig {
+                    start_timeout: config.start_timeout,
+                    shutdown_timeout: config.shutdown_timeout,
+                    args: spec.args.clone(),
+                    resources: spec.resources.clone(),
+                };
+
+                let engine: Arc<dyn Engine> = Arc::new(ODFEngine::new(
+                    container_runtime.clone(),
+                    engine_config,
+                    &spec.image,
+                    run_info_dir.clone(),
+                    dataset_repo.clone(),
+                ));
+
+                (spec.id.clone(), (spec.clone(), engine))
+            })
+            .collect();
 
         Self {
-            spark_engine: Arc::new(ODFEngine::new(
-                container_runtime.clone(),
-                engine_config.clone(),
-                &config.spark_image,
-                run_info_dir.clone(),
-                dataset_repo.clone(),
-            )),
-            flink_engine: Arc::new(ODFEngine::new(
-                container_runtime.clone(),
-                engine_config.clone(),
-                &config.flink_image,
-                run_info_dir.clone(),
-                dataset_repo.clone(),
-            )),
-            datafusion_engine: Arc::new(ODFEngine::new(
-                container_runtime.clone(),
-                engine_config.clone(),
-                &config.datafusion_image,
-                run_info_dir.clone(),
-                dataset_repo.clone(),
-            )),
-            risingwave_engine: Arc::new(ODFEngine::new(
-                container_runtime.clone(),
-                engine_config.clone(),
-                &config.risingwave_image,
-                run_info_dir.clone(),
-                dataset_repo.clone(),
-            )),
+            engines,
             container_runtime,
+            metrics,
             inner: Arc::new(Inner {
                 state: Mutex::new(State {
                     outstanding_handles: 0,
@@ -199,27 +196,32 @@ impl EngineProvisioner for EngineProvisionerLocal {
     ) -> Result<Arc<dyn Engine>, EngineProvisioningError> {
         let listener = maybe_listener.unwrap_or_else(|| Arc::new(NullEngineProvisioningListener));
 
-        let (engine, image) = match engine_id {
-            "spark" => Ok((self.spark_engine.clone(), &self.config.spark_image)),
-            "flink" => Ok((self.flink_engine.clone(), &self.config.flink_image)),
-            "datafusion" => Ok((
-                self.datafusion_engine.clone(),
-                &self.config.datafusion_image,
-            )),
-            "risingwave" => Ok((
-                self.risingwave_engine.clone(),
-                &self.config.risingwave_image,
-            )),
-            _ => Err(format!("Unsupported engine {engine_id}").int_err()),
-        }?;
-
-        self.ensure_image(image, listener.clone()).await?;
+        let Some((spec, engine)) = self.engines.get(engine_id) else {
+            return Err(UnknownEngineError {
+                engine_id: engine_id.to_string(),
+            }
+            .into());
+        };
+
+        let start = std::time::Instant::now();
+
+        self.ensure_image(&spec.image, listener.clone()).await?;
 
         listener.begin(engine_id);
         self.wait_for_max_concurrency().await;
         listener.success();
 
-        Ok(Arc::new(EngineHandle::new(self.inner.clone(), engine)))
+        if let Some(metrics) = &self.metrics {
+            metrics
+                .provisioning_wait_seconds
+                .with_label_values(&[engine_id])
+                .observe(start.elapsed().as_secs_f64());
+        }
+
+        Ok(Arc::new(EngineHandle::new(
+            self.inner.clone(),
+            engine.clone(),
+        )))
     }
 }
 
@@ -235,12 +237,6 @@ pub struct EngineProvisionerLocalConfig {
     pub start_timeout: Duration,
     /// Timeout for waiting for engine container to shutdown cleanly
     pub shutdown_timeout: Duration,
-
-    // TODO: Remove in favor of explicit images in ODF protocol
-    pub spark_image: String,
-    pub flink_image: String,
-    pub datafusion_image: String,
-    pub risingwave_image: String,
 }
 
 // This is for tests only
@@ -250,10 +246,6 @@ impl Default for EngineProvisionerLocalConfig {
             max_concurrency: None,
             start_timeout: Duration::from_secs(30),
             shutdown_timeout: Duration::from_secs(5),
-            spark_image: docker_images::SPARK.to_owned(),
-            flink_image: docker_images::FLINK.to_owned(),
-            datafusion_image: docker_images::DATAFUSION.to_owned(),
-            risingwave_image: docker_images::RISINGWAVE.to_owned(),
         }
     }
 }
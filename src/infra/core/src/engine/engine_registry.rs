@@ -0,0 +1,157 @@
+// Copyright Kamu Data, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+use kamu_core::{EngineDesc, QueryDialect, UnknownEngineError};
+use opendatafabric::Transform;
+
+use crate::utils::docker_images;
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Describes an ODF-compliant engine that can be used in transformations
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EngineSpec {
+    /// Identifier of the engine as used in transformations, e.g. "datafusion"
+    pub id: String,
+    /// A short human-readable name of the engine, e.g. "DataFusion"
+    pub name: String,
+    /// Language and dialect this engine is using for queries
+    pub dialect: QueryDialect,
+    /// OCI image repository and a tag of the engine image
+    pub image: String,
+    /// Arguments passed to the container, overriding the default command of
+    /// the image when specified
+    pub args: Vec<String>,
+    /// Resource limits of the engine container
+    pub resources: EngineResources,
+}
+
+impl EngineSpec {
+    pub fn new(
+        id: impl Into<String>,
+        name: impl Into<String>,
+        dialect: QueryDialect,
+        image: impl Into<String>,
+    ) -> Self {
+        Self {
+            id: id.into(),
+            name: name.into(),
+            dialect,
+            image: image.into(),
+            args: Vec::new(),
+            resources: EngineResources::default(),
+        }
+    }
+
+    pub fn desc(&self) -> EngineDesc {
+        EngineDesc {
+            id: self.id.clone(),
+            name: self.name.clone(),
+            dialect: self.dialect.clone(),
+            latest_image: self.image.clone(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct EngineResources {
+    /// Memory limit in the container runtime format, e.g. "512m" or "4g"
+    pub memory: Option<String>,
+    /// Number of CPUs the container can use, e.g. "1.5"
+    pub cpus: Option<String>,
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Engines that are available in the workspace or on the server.
+///
+/// Starts with the built-in engines which can be overridden or extended by
+/// engines declared in the configuration.
+#[derive(Debug, Clone)]
+pub struct EngineRegistry {
+    engines: Vec<EngineSpec>,
+}
+
+impl EngineRegistry {
+    pub fn new(engines: impl IntoIterator<Item = EngineSpec>) -> Self {
+        let mut this = Self {
+            engines: Vec::new(),
+        };
+        for engine in engines {
+            this = this.with_engine(engine);
+        }
+        this
+    }
+
+    pub fn builtin() -> Self {
+        Self::new([
+            EngineSpec::new(
+                "spark",
+                "Spark",
+                QueryDialect::SqlSpark,
+                docker_images::SPARK,
+            ),
+            EngineSpec::new(
+                "flink",
+                "Flink",
+                QueryDialect::SqlFlink,
+                docker_images::FLINK,
+            ),
+            EngineSpec::new(
+                "datafusion",
+                "DataFusion",
+                QueryDialect::SqlDataFusion,
+                docker_images::DATAFUSION,
+            ),
+            EngineSpec::new(
+                "risingwave",
+                "RisingWave",
+                QueryDialect::SqlRisingWave,
+                docker_images::RISINGWAVE,
+            ),
+        ])
+    }
+
+    /// Adds an engine, replacing the existing one with the same identifier
+    pub fn with_engine(mut self, engine: EngineSpec) -> Self {
+        if let Some(i) = self.engines.iter().position(|e| e.id == engine.id) {
+            self.engines[i] = engine;
+        } else {
+            self.engines.push(engine);
+        }
+        self
+    }
+
+    pub fn get(&self, engine_id: &str) -> Option<&EngineSpec> {
+        self.engines.iter().find(|e| e.id == engine_id)
+    }
+
+    pub fn engines(&self) -> &[EngineSpec] {
+        &self.engines
+    }
+
+    /// Ensures that the transformation uses one of the registered engines
+    pub fn validate_transform(&self, transform: &Transform) -> Result<(), UnknownEngineError> {
+        let Transform::Sql(sql) = transform;
+        if self.get(&sql.engine).is_none() {
+            return Err(UnknownEngineError {
+                engine_id: sql.engine.clone(),
+            });
+        }
+        Ok(())
+    }
+}
+
+impl Default for EngineRegistry {
+    fn default() -> Self {
+        Self::builtin()
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -11,10 +11,14 @@ mod engine_config;
 mod engine_container;
 mod engine_datafusion_inproc;
 mod engine_io_strategy;
+mod engine_metrics;
 mod engine_odf;
 mod engine_provisioner_local;
+mod engine_registry;
 
 pub use engine_config::*;
 pub use engine_datafusion_inproc::*;
 pub use engine_io_strategy::*;
+pub use engine_metrics::*;
 pub use engine_provisioner_local::*;
+pub use engine_registry::*;
@@ -7,6 +7,8 @@
 // the Business Source License, use of this software will be governed
 // by the Apache License, Version 2.0.
 
+use std::path::PathBuf;
+
 use url::Url;
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -35,8 +37,24 @@ pub struct HttpSourceConfig {
     pub user_agent: String,
     /// Timeout for the connect phase of the HTTP client
     pub connect_timeout: std::time::Duration,
+    /// Timeout for the entire request, including reading of the response
+    /// body. No timeout is applied when not set.
+    pub request_timeout: Option<std::time::Duration>,
     /// Maximum number of redirects to follow
     pub max_redirects: usize,
+    /// Maximum number of times a request will be retried after a connection
+    /// error, a timeout, `429 Too Many Requests` or a `5xx` server error
+    pub max_retries: u32,
+    /// Initial delay between retries that doubles with every attempt. Used
+    /// when server does not specify the delay via `Retry-After` header.
+    pub retry_backoff: std::time::Duration,
+    /// Maximum time to wait before retrying a request. Requests asking to
+    /// wait longer via `Retry-After` header will fail instead.
+    pub max_retry_after: std::time::Duration,
+    /// Proxy server to route all HTTP(S) requests through
+    pub proxy: Option<Url>,
+    /// Paths to additional PEM-encoded root certificates to trust
+    pub ca_certificates: Vec<PathBuf>,
 }
 
 impl Default for HttpSourceConfig {
@@ -44,7 +62,13 @@ impl Default for HttpSourceConfig {
         Self {
             user_agent: concat!("kamu/", env!("CARGO_PKG_VERSION")).to_string(),
             connect_timeout: std::time::Duration::from_secs(30),
+            request_timeout: None,
             max_redirects: 10,
+            max_retries: 5,
+            retry_backoff: std::time::Duration::from_secs(1),
+            max_retry_after: std::time::Duration::from_secs(60),
+            proxy: None,
+            ca_certificates: Vec::new(),
         }
     }
 }
@@ -57,12 +81,16 @@ pub struct MqttSourceConfig {
     /// which we will consider that we have "caught up" and end the polling
     /// loop.
     pub broker_idle_timeout_ms: u64,
+    /// Time in milliseconds to wait for the broker to receive acknowledgements
+    /// of the messages after they were committed.
+    pub ack_timeout_ms: u64,
 }
 
 impl Default for MqttSourceConfig {
     fn default() -> Self {
         Self {
             broker_idle_timeout_ms: 1_000,
+            ack_timeout_ms: 10_000,
         }
     }
 }
@@ -82,6 +110,11 @@ pub struct EthereumSourceConfig {
     /// scanned even if we didn't reach the target record number. This is useful
     /// to not lose a lot of scanning progress in case of an RPC error.
     pub commit_after_blocks_scanned: u64,
+    /// Number of most recent blocks that are considered subject to chain
+    /// reorganizations. Hashes of such blocks are recorded in the source state
+    /// and verified upon the next poll to retract records of the orphaned
+    /// blocks.
+    pub confirmation_depth: u64,
 }
 
 impl Default for EthereumSourceConfig {
@@ -90,6 +123,7 @@ impl Default for EthereumSourceConfig {
             rpc_endpoints: Vec::new(),
             get_logs_block_stride: 100_000,
             commit_after_blocks_scanned: 1_000_000,
+            confirmation_depth: 12,
         }
     }
 }
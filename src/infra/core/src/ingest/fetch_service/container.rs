@@ -88,11 +88,11 @@ impl FetchService {
         }
 
         if let Some(args) = &fetch.args {
-            container_builder = container_builder.args(
-                args.iter()
-                    .map(|arg| self.template_string(arg, dataset_env_vars))
-                    .collect::<Result<Vec<_>, _>>()?,
-            );
+            let mut templated_args = Vec::with_capacity(args.len());
+            for arg in args {
+                templated_args.push(self.template_string(arg, dataset_env_vars).await?);
+            }
+            container_builder = container_builder.args(templated_args);
         }
 
         let mut batch_size = self.source_config.target_records_per_slice;
@@ -100,11 +100,12 @@ impl FetchService {
         if let Some(env_vars) = &fetch.env {
             for EnvVar { name, value } in env_vars {
                 let value = if let Some(value) = value {
-                    self.template_string(value, dataset_env_vars)?
+                    self.template_string(value, dataset_env_vars).await?
                 } else {
                     let value = self
                         .dataset_key_value_svc
-                        .find_dataset_env_var_value_by_key(name, dataset_env_vars)?;
+                        .find_dataset_env_var_value_by_key(name, dataset_env_vars)
+                        .await?;
 
                     Cow::from(value.into_exposed_value())
                 };
@@ -229,6 +230,8 @@ impl FetchService {
                 source_event_time: None,
                 has_more,
                 zero_copy_path: None,
+                retraction: None,
+                acknowledgement: None,
             }))
         }
     }
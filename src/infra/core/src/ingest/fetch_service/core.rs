@@ -14,10 +14,12 @@ use std::sync::Arc;
 
 use chrono::{DateTime, Utc};
 use container_runtime::*;
-use internal_error::{ErrorIntoInternal, ResultIntoInternal};
+use datafusion::arrow::datatypes::SchemaRef;
+use internal_error::{ErrorIntoInternal, InternalError, ResultIntoInternal};
 use kamu_core::*;
 use kamu_datasets::{DatasetEnvVar, DatasetKeyValueService};
 use opendatafabric::*;
+use serde::{Deserialize, Serialize};
 use url::Url;
 
 use super::*;
@@ -75,6 +77,7 @@ impl FetchService {
         dataset_handle: &DatasetHandle,
         operation_id: &str,
         fetch_step: &FetchStep,
+        read_step: Option<&ReadStep>,
         prev_source_state: Option<&PollingSourceState>,
         target_path: &Path,
         system_time: &DateTime<Utc>,
@@ -85,8 +88,10 @@ impl FetchService {
 
         match fetch_step {
             FetchStep::Url(furl) => {
-                let url = self.template_url(&furl.url, dataset_env_vars)?;
-                let headers = self.template_headers(&furl.headers, dataset_env_vars)?;
+                let url = self.template_url(&furl.url, dataset_env_vars).await?;
+                let headers = self
+                    .template_headers(&furl.headers, dataset_env_vars)
+                    .await?;
 
                 match url.scheme() {
                     "file" => Self::fetch_file(
@@ -147,6 +152,7 @@ impl FetchService {
                     self.fetch_mqtt(
                         dataset_handle,
                         fetch,
+                        read_step,
                         target_path,
                         dataset_env_vars,
                         &listener,
@@ -173,19 +179,111 @@ impl FetchService {
                     }
                 }
             }
+            FetchStep::EthereumBlocks(fetch) => {
+                cfg_if::cfg_if! {
+                    if #[cfg(feature = "ingest-evm")] {
+                        self.fetch_ethereum_blocks(
+                            fetch,
+                            prev_source_state,
+                            target_path,
+                            dataset_env_vars,
+                            &listener,
+                        )
+                        .await
+                    } else {
+                        unimplemented!("Kamu was compiled without Ethereum support")
+                    }
+                }
+            }
+            FetchStep::EthereumTransactions(fetch) => {
+                cfg_if::cfg_if! {
+                    if #[cfg(feature = "ingest-evm")] {
+                        self.fetch_ethereum_transactions(
+                            fetch,
+                            prev_source_state,
+                            target_path,
+                            dataset_env_vars,
+                            &listener,
+                        )
+                        .await
+                    } else {
+                        unimplemented!("Kamu was compiled without Ethereum support")
+                    }
+                }
+            }
+            FetchStep::Sql(fetch) => {
+                cfg_if::cfg_if! {
+                    if #[cfg(feature = "ingest-sql")] {
+                        self.fetch_sql(
+                            fetch,
+                            prev_source_state,
+                            target_path,
+                            dataset_env_vars,
+                            &listener,
+                        )
+                        .await
+                    } else {
+                        unimplemented!("Kamu was compiled without SQL database support")
+                    }
+                }
+            }
+            FetchStep::HttpPaginated(fetch) => {
+                self.fetch_http_paginated(
+                    fetch,
+                    prev_source_state,
+                    target_path,
+                    dataset_env_vars,
+                    &listener,
+                )
+                .await
+            }
+        }
+    }
+
+    /// Recreates the acknowledgement of the records that were fetched by a
+    /// previous ingest attempt and cached in a savepoint
+    #[allow(unused_variables)]
+    pub async fn resume_acknowledgement(
+        &self,
+        dataset_handle: &DatasetHandle,
+        fetch_step: &FetchStep,
+        state: &FetchAcknowledgementState,
+        dataset_env_vars: &HashMap<String, DatasetEnvVar>,
+    ) -> Result<FetchAcknowledgement, PollingIngestError> {
+        match (fetch_step, state) {
+            (FetchStep::Mqtt(fetch), FetchAcknowledgementState::Mqtt { packet_ids }) => {
+                cfg_if::cfg_if! {
+                    if #[cfg(feature = "ingest-mqtt")] {
+                        self.resume_mqtt_acknowledgement(
+                            dataset_handle,
+                            fetch,
+                            packet_ids,
+                            dataset_env_vars,
+                        )
+                        .await
+                    } else {
+                        unimplemented!("Kamu was compiled without MQTT support")
+                    }
+                }
+            }
+            _ => Err(format!(
+                "Acknowledgement state {state:?} does not match the fetch step {fetch_step:?}"
+            )
+            .int_err()
+            .into()),
         }
     }
 
-    pub(super) fn template_url(
+    pub(super) async fn template_url(
         &self,
         url_tpl: &str,
         dataset_env_vars: &HashMap<String, DatasetEnvVar>,
     ) -> Result<Url, PollingIngestError> {
-        let url = self.template_string(url_tpl, dataset_env_vars)?;
+        let url = self.template_string(url_tpl, dataset_env_vars).await?;
         Ok(Url::parse(&url).int_err()?)
     }
 
-    pub(super) fn template_headers(
+    pub(super) async fn template_headers(
         &self,
         headers_tpl: &Option<Vec<RequestHeader>>,
         dataset_env_vars: &HashMap<String, DatasetEnvVar>,
@@ -196,7 +294,8 @@ impl FetchService {
             let hdr = RequestHeader {
                 name: htpl.name.clone(),
                 value: self
-                    .template_string(&htpl.value, dataset_env_vars)?
+                    .template_string(&htpl.value, dataset_env_vars)
+                    .await?
                     .into_owned(),
             };
             res.push(hdr);
@@ -204,7 +303,7 @@ impl FetchService {
         Ok(res)
     }
 
-    pub(super) fn template_string<'a>(
+    pub(super) async fn template_string<'a>(
         &self,
         s: &'a str,
         dataset_env_vars: &'a HashMap<String, DatasetEnvVar>,
@@ -222,7 +321,8 @@ impl FetchService {
 
                     let dataset_env_var_secret_value = self
                         .dataset_key_value_svc
-                        .find_dataset_env_var_value_by_key(env_name, dataset_env_vars)?;
+                        .find_dataset_env_var_value_by_key(env_name, dataset_env_vars)
+                        .await?;
 
                     s.to_mut()
                         .replace_range(tpl_range, dataset_env_var_secret_value.get_exposed_value());
@@ -259,6 +359,156 @@ pub struct FetchResultUpdated {
     pub source_event_time: Option<DateTime<Utc>>,
     pub has_more: bool,
     pub zero_copy_path: Option<PathBuf>,
+    /// Previously fetched records that the source reports as no longer valid
+    pub retraction: Option<FetchRetraction>,
+    /// Confirms the delivery of fetched records to sources that redeliver
+    /// them until they are acknowledged
+    pub acknowledgement: Option<FetchAcknowledgement>,
+}
+
+/// Describes previously ingested records that should be retracted along with
+/// committing the newly fetched data
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields, rename_all = "camelCase", tag = "kind")]
+pub enum FetchRetraction {
+    /// Records that belong to blocks orphaned by a chain reorganization,
+    /// identified by hex-encoded hashes in the `block_hash` column. All of the
+    /// blocks are at or above `from_block_number`.
+    BlockHashes {
+        block_hashes: Vec<String>,
+        #[serde(default)]
+        from_block_number: u64,
+    },
+}
+
+impl FetchRetraction {
+    const BLOCK_HASH_COLUMN: &'static str = "block_hash";
+    const BLOCK_NUMBER_COLUMN: &'static str = "block_number";
+
+    /// Returns a filter that selects the retracted records from the dataset
+    /// with the specified schema, or `None` if the dataset has no data yet
+    pub fn to_filter_expr(
+        &self,
+        prev_schema: Option<&SchemaRef>,
+    ) -> Result<Option<datafusion::prelude::Expr>, PollingIngestError> {
+        use datafusion::arrow::datatypes::DataType;
+        use datafusion::prelude::*;
+        use datafusion::scalar::ScalarValue;
+
+        let Some(prev_schema) = prev_schema else {
+            return Ok(None);
+        };
+
+        match self {
+            Self::BlockHashes {
+                block_hashes,
+                from_block_number,
+            } => {
+                // Fail early with a clear message instead of a planning error deep in the
+                // writer when the query dropped or transformed the column
+                let has_block_hash = prev_schema
+                    .field_with_name(Self::BLOCK_HASH_COLUMN)
+                    .is_ok_and(|f| {
+                        matches!(
+                            f.data_type(),
+                            DataType::Binary | DataType::LargeBinary | DataType::FixedSizeBinary(_)
+                        )
+                    });
+                if !has_block_hash {
+                    return Err(BadInputSchemaError::new(
+                        format!(
+                            "Source reported a chain reorganization, but the dataset has no \
+                             binary '{}' column to retract records of the orphaned blocks by. \
+                             Make sure the preprocessing query keeps this column as is.",
+                            Self::BLOCK_HASH_COLUMN
+                        ),
+                        prev_schema.clone(),
+                    )
+                    .into());
+                }
+
+                let hashes = block_hashes
+                    .iter()
+                    .map(|h| {
+                        let bytes = hex::decode(h.strip_prefix("0x").unwrap_or(h)).int_err()?;
+                        Ok(lit(ScalarValue::Binary(Some(bytes))))
+                    })
+                    .collect::<Result<Vec<_>, InternalError>>()?;
+
+                let filter = col(Self::BLOCK_HASH_COLUMN).in_list(hashes, false);
+
+                // Hashes are random, so Parquet statistics can't be used to skip data by
+                // them, unlike the monotonic block numbers
+                let has_block_number = prev_schema
+                    .field_with_name(Self::BLOCK_NUMBER_COLUMN)
+                    .is_ok_and(|f| f.data_type().is_integer());
+                let filter = if has_block_number {
+                    col(Self::BLOCK_NUMBER_COLUMN)
+                        .gt_eq(lit(*from_block_number))
+                        .and(filter)
+                } else {
+                    filter
+                };
+
+                Ok(Some(filter))
+            }
+        }
+    }
+}
+
+/// Source-specific operation that confirms the delivery of fetched records
+#[async_trait::async_trait]
+pub trait FetchAcknowledger: Send + Sync {
+    async fn acknowledge(&self) -> Result<(), InternalError>;
+
+    /// Returns the state that allows to acknowledge the same records after
+    /// they were cached in a savepoint, see
+    /// [`FetchService::resume_acknowledgement`]
+    fn state(&self) -> FetchAcknowledgementState;
+}
+
+/// Handle that has to be acknowledged only after the fetched records were
+/// committed. Dropping it without acknowledging lets the source deliver the
+/// same records again during the next fetch.
+#[derive(Clone)]
+pub struct FetchAcknowledgement(Arc<dyn FetchAcknowledger>);
+
+impl FetchAcknowledgement {
+    pub fn new(acknowledger: impl FetchAcknowledger + 'static) -> Self {
+        Self(Arc::new(acknowledger))
+    }
+
+    pub async fn acknowledge(&self) -> Result<(), InternalError> {
+        self.0.acknowledge().await
+    }
+
+    pub fn state(&self) -> FetchAcknowledgementState {
+        self.0.state()
+    }
+}
+
+impl std::fmt::Debug for FetchAcknowledgement {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("FetchAcknowledgement")
+            .finish_non_exhaustive()
+    }
+}
+
+impl PartialEq for FetchAcknowledgement {
+    fn eq(&self, other: &Self) -> bool {
+        std::ptr::addr_eq(Arc::as_ptr(&self.0), Arc::as_ptr(&other.0))
+    }
+}
+
+impl Eq for FetchAcknowledgement {}
+
+/// Identifies the fetched records that are yet to be acknowledged
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields, rename_all = "camelCase", tag = "kind")]
+pub enum FetchAcknowledgementState {
+    /// Packet IDs of the MQTT messages with QoS 1 and 2 that the broker
+    /// delivers again with the same IDs until they are acknowledged
+    Mqtt { packet_ids: Vec<u16> },
 }
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
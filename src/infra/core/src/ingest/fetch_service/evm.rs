@@ -11,6 +11,7 @@ use std::collections::HashMap;
 use std::path::Path;
 use std::sync::Arc;
 
+use alloy::primitives::B256;
 use futures::TryStreamExt;
 use internal_error::{ErrorIntoInternal, ResultIntoInternal};
 use kamu_core::*;
@@ -22,6 +23,19 @@ use crate::PollingSourceState;
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
 
+pub(super) type EthereumRpcClient = alloy::providers::RootProvider<alloy::transports::BoxTransport>;
+
+/// Result of checking previously scanned blocks against the canonical chain
+pub(super) struct EthereumChainCheck {
+    /// Blocks that are no longer part of the canonical chain, newest first
+    pub orphaned_blocks: Vec<(u64, B256)>,
+    /// Blocks that are still part of the canonical chain, the last one being
+    /// the block to resume the scan after
+    pub valid_blocks: Vec<(u64, B256)>,
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
 impl FetchService {
     // TODO: FIXME: This implementation is overly complex due to DataFusion's poor
     // support of streaming / unbounded sources.
@@ -35,7 +49,10 @@ impl FetchService {
     // convert the WHERE clause into a filter, and then calls ETH RPC directly
     // to scan through block ranges.
     //
-    // TODO: Account for re-orgs
+    // Chain reorganizations are handled by recording hashes of the recent blocks
+    // that are not yet considered final in the source state. Upon the next poll
+    // we verify these blocks are still part of the canonical chain, retract logs
+    // of the orphaned blocks, and re-scan the chain starting from the fork point.
     pub(crate) async fn fetch_ethereum_logs(
         &self,
         fetch: &FetchStepEthereumLogs,
@@ -44,8 +61,6 @@ impl FetchService {
         dataset_env_vars: &HashMap<String, DatasetEnvVar>,
         listener: &Arc<dyn FetchProgressListener>,
     ) -> Result<FetchResult, PollingIngestError> {
-        use alloy::providers::{Provider, ProviderBuilder};
-        use alloy::rpc::types::eth::BlockTransactionsKind;
         use datafusion::prelude::*;
         use datafusion_ethers::convert::*;
         use datafusion_ethers::stream::*;
@@ -63,51 +78,23 @@ impl FetchService {
         };
 
         // Get last state
-        let resume_from_state = match prev_source_state {
-            None => None,
-            Some(PollingSourceState::ETag(s)) => {
-                let Some((num, _hash)) = s.split_once('@') else {
-                    panic!("Malformed ETag: {s}");
-                };
-                Some(StreamState {
-                    last_seen_block: num.parse().unwrap(),
-                })
-            }
-            _ => panic!("EthereumLogs should only use ETag state"),
-        };
+        let prev_state = EthereumScanState::from_source_state(prev_source_state, "EthereumLogs")?;
 
-        // Setup node RPC client
-        let node_url = if let Some(url) = &fetch.node_url {
-            self.template_url(url, dataset_env_vars)?
-        } else if let Some(ep) = self
-            .eth_source_config
-            .get_endpoint_by_chain_id(fetch.chain_id.unwrap())
-        {
-            ep.node_url.clone()
-        } else {
-            Err(EthereumRpcError::new(format!(
-                "Ethereum node RPC URL is not provided in the source manifest and no default node \
-                 configured for chain ID {}",
-                fetch.chain_id.unwrap()
-            ))
-            .int_err())?
-        };
+        let rpc_client = self
+            .connect_ethereum_node(fetch.chain_id, fetch.node_url.as_ref(), dataset_env_vars)
+            .await?;
 
-        let rpc_client = ProviderBuilder::new()
-            .on_builtin(node_url.as_str())
-            .await
-            .int_err()?;
+        // Check that previously scanned recent blocks were not orphaned by a reorg
+        let EthereumChainCheck {
+            orphaned_blocks,
+            valid_blocks,
+        } = self
+            .check_ethereum_chain(&rpc_client, prev_state.as_ref())
+            .await?;
 
-        let chain_id = rpc_client.get_chain_id().await.int_err()?;
-        tracing::info!(%node_url, %chain_id, "Connected to ETH node");
-        if let Some(expected_chain_id) = fetch.chain_id
-            && expected_chain_id != chain_id
-        {
-            Err(EthereumRpcError::new(format!(
-                "Expected to connect to chain ID {expected_chain_id} but got {chain_id} instead"
-            ))
-            .int_err())?;
-        }
+        let resume_from_state = valid_blocks.last().map(|(number, _)| StreamState {
+            last_seen_block: *number,
+        });
 
         // Setup Datafusion context
         let mut cfg = SessionConfig::new()
@@ -230,22 +217,18 @@ impl FetchService {
         }
 
         // Have we made any progress?
-        if resume_from_state == state {
+        if resume_from_state == state && orphaned_blocks.is_empty() {
             return Ok(FetchResult::UpToDate);
         }
 
         let state = state.unwrap();
 
-        // Record scanning state
-        // TODO: Reorg tolerance
-        let last_seen_block = rpc_client
-            .get_block(state.last_seen_block.into(), BlockTransactionsKind::Hashes)
-            .await
-            .int_err()?
-            .unwrap();
+        let new_state = self
+            .record_unconfirmed_blocks(&rpc_client, state.last_seen_block, &valid_blocks)
+            .await?;
 
         tracing::info!(
-            blocks_scanned = state.last_seen_block - block_range_unprocessed.0 + 1,
+            blocks_scanned = state.last_seen_block + 1 - block_range_unprocessed.0,
             block_range_scanned = ?(block_range_unprocessed.0, state.last_seen_block),
             block_range_ramaining = ?(state.last_seen_block + 1, block_range_unprocessed.1),
             num_logs = coder.len(),
@@ -255,30 +238,194 @@ impl FetchService {
         // Did we exhaust the source? (not accounting for new transactions)
         let has_more = state.last_seen_block < block_range_unprocessed.1;
 
-        // Write data, if any, to parquet file
-        if coder.len() > 0 {
-            let batch = coder.finish();
-            {
-                let mut writer = datafusion::parquet::arrow::ArrowWriter::try_new(
-                    std::fs::File::create_new(target_path).int_err()?,
-                    batch.schema(),
-                    None,
-                )
-                .int_err()?;
-                writer.write(&batch).int_err()?;
-                writer.finish().int_err()?;
+        let batch = coder.finish();
+        Self::write_ethereum_batch(&batch, new_state, &orphaned_blocks, has_more, target_path)
+    }
+
+    pub(super) async fn connect_ethereum_node(
+        &self,
+        chain_id: Option<u64>,
+        node_url: Option<&String>,
+        dataset_env_vars: &HashMap<String, DatasetEnvVar>,
+    ) -> Result<EthereumRpcClient, PollingIngestError> {
+        use alloy::providers::{Provider, ProviderBuilder};
+
+        let node_url = if let Some(url) = node_url {
+            self.template_url(url, dataset_env_vars).await?
+        } else if let Some(ep) = self
+            .eth_source_config
+            .get_endpoint_by_chain_id(chain_id.unwrap())
+        {
+            ep.node_url.clone()
+        } else {
+            Err(EthereumRpcError::new(format!(
+                "Ethereum node RPC URL is not provided in the source manifest and no default node \
+                 configured for chain ID {}",
+                chain_id.unwrap()
+            ))
+            .int_err())?
+        };
+
+        let rpc_client = ProviderBuilder::new()
+            .on_builtin(node_url.as_str())
+            .await
+            .int_err()?;
+
+        let actual_chain_id = rpc_client.get_chain_id().await.int_err()?;
+        tracing::info!(%node_url, chain_id = %actual_chain_id, "Connected to ETH node");
+        if let Some(expected_chain_id) = chain_id
+            && expected_chain_id != actual_chain_id
+        {
+            Err(EthereumRpcError::new(format!(
+                "Expected to connect to chain ID {expected_chain_id} but got {actual_chain_id} \
+                 instead"
+            ))
+            .int_err())?;
+        }
+
+        Ok(rpc_client)
+    }
+
+    /// Checks that previously scanned recent blocks were not orphaned by a
+    /// chain reorganization
+    pub(super) async fn check_ethereum_chain(
+        &self,
+        rpc_client: &EthereumRpcClient,
+        prev_state: Option<&EthereumScanState>,
+    ) -> Result<EthereumChainCheck, PollingIngestError> {
+        let mut orphaned_blocks = Vec::new();
+
+        let Some(prev_state) = prev_state else {
+            return Ok(EthereumChainCheck {
+                orphaned_blocks,
+                valid_blocks: Vec::new(),
+            });
+        };
+
+        for (number, hash) in prev_state.blocks.iter().rev() {
+            if Self::get_ethereum_block_hash(rpc_client, *number).await? == Some(*hash) {
+                break;
             }
+            orphaned_blocks.push((*number, *hash));
+        }
+
+        let valid_blocks =
+            prev_state.blocks[..prev_state.blocks.len() - orphaned_blocks.len()].to_vec();
+
+        if valid_blocks.is_empty() {
+            Err(EthereumReorgTooDeepError {
+                block_number: prev_state.blocks[0].0,
+                confirmation_depth: self.eth_source_config.confirmation_depth,
+            }
+            .int_err())?;
+        }
+
+        if !orphaned_blocks.is_empty() {
+            tracing::warn!(
+                fork_block = valid_blocks.last().unwrap().0,
+                orphaned_blocks = ?orphaned_blocks,
+                "Detected chain reorganization, retracting records of orphaned blocks",
+            );
+        }
+
+        Ok(EthereumChainCheck {
+            orphaned_blocks,
+            valid_blocks,
+        })
+    }
+
+    /// Records hashes of the scanned blocks that can still be reorganized, i.e.
+    /// the ones that did not yet reach the confirmation depth. The last scanned
+    /// block is always recorded to be able to detect reorgs upon resuming.
+    pub(super) async fn record_unconfirmed_blocks(
+        &self,
+        rpc_client: &EthereumRpcClient,
+        last_seen_block: u64,
+        valid_blocks: &[(u64, B256)],
+    ) -> Result<EthereumScanState, PollingIngestError> {
+        use alloy::providers::Provider;
+
+        let head_block = rpc_client.get_block_number().await.int_err()?;
+        let confirmation_depth = self.eth_source_config.confirmation_depth.max(1);
+        let first_unconfirmed_block = std::cmp::max(
+            (last_seen_block + 1).saturating_sub(confirmation_depth),
+            (head_block + 1).saturating_sub(confirmation_depth),
+        )
+        .min(last_seen_block);
+
+        let mut state = EthereumScanState { blocks: Vec::new() };
+        for number in first_unconfirmed_block..=last_seen_block {
+            let hash = if let Some((_, hash)) = valid_blocks.iter().find(|(n, _)| *n == number) {
+                *hash
+            } else {
+                Self::get_ethereum_block_hash(rpc_client, number)
+                    .await?
+                    .ok_or_else(|| {
+                        EthereumRpcError::new(format!("Node did not return block {number}"))
+                            .int_err()
+                    })?
+            };
+            state.blocks.push((number, hash));
+        }
+
+        Ok(state)
+    }
+
+    async fn get_ethereum_block_hash(
+        rpc_client: &EthereumRpcClient,
+        number: u64,
+    ) -> Result<Option<B256>, PollingIngestError> {
+        use alloy::providers::Provider;
+        use alloy::rpc::types::eth::BlockTransactionsKind;
+
+        Ok(rpc_client
+            .get_block(number.into(), BlockTransactionsKind::Hashes)
+            .await
+            .int_err()?
+            .and_then(|b| b.header.hash))
+    }
+
+    /// Writes data, if any, to parquet file. Upon reorg the file is written
+    /// even if empty to let ingest commit the retractions.
+    pub(super) fn write_ethereum_batch(
+        batch: &datafusion::arrow::record_batch::RecordBatch,
+        new_state: EthereumScanState,
+        orphaned_blocks: &[(u64, B256)],
+        has_more: bool,
+        target_path: &Path,
+    ) -> Result<FetchResult, PollingIngestError> {
+        if batch.num_rows() > 0 || !orphaned_blocks.is_empty() {
+            let mut writer = datafusion::parquet::arrow::ArrowWriter::try_new(
+                std::fs::File::create_new(target_path).int_err()?,
+                batch.schema(),
+                None,
+            )
+            .int_err()?;
+            writer.write(batch).int_err()?;
+            writer.finish().int_err()?;
         }
 
         Ok(FetchResult::Updated(FetchResultUpdated {
-            source_state: Some(PollingSourceState::ETag(format!(
-                "{}@{:x}",
-                last_seen_block.header.number.unwrap(),
-                last_seen_block.header.hash.unwrap(),
-            ))),
+            source_state: Some(PollingSourceState::ETag(new_state.to_etag())),
             source_event_time: None,
             has_more,
             zero_copy_path: None,
+            retraction: if orphaned_blocks.is_empty() {
+                None
+            } else {
+                Some(FetchRetraction::BlockHashes {
+                    block_hashes: orphaned_blocks
+                        .iter()
+                        .map(|(_, hash)| format!("{hash:x}"))
+                        .collect(),
+                    from_block_number: orphaned_blocks
+                        .iter()
+                        .map(|(number, _)| *number)
+                        .min()
+                        .unwrap(),
+                })
+            },
+            acknowledgement: None,
         }))
     }
 }
@@ -287,11 +434,78 @@ impl FetchService {
 
 #[derive(thiserror::Error, Debug)]
 #[error("Ethereum RPC error: {message}")]
-struct EthereumRpcError {
+pub(super) struct EthereumRpcError {
     pub message: String,
 }
 
 impl EthereumRpcError {
+    pub(super) fn new(message: impl Into<String>) -> Self {
+        Self {
+            message: message.into(),
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// State of the Ethereum sources stored as an ETag in the form of
+/// comma-separated `<number>@<hash>` entries of the recently scanned blocks in
+/// ascending order, where the last entry is the last scanned block
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(super) struct EthereumScanState {
+    blocks: Vec<(u64, B256)>,
+}
+
+impl EthereumScanState {
+    pub(super) fn from_source_state(
+        source_state: Option<&PollingSourceState>,
+        source_name: &str,
+    ) -> Result<Option<Self>, PollingIngestError> {
+        match source_state {
+            None => Ok(None),
+            Some(PollingSourceState::ETag(s)) => Ok(Some(Self::parse(s).int_err()?)),
+            Some(state) => Err(MalformedSourceStateError::new(format!(
+                "{source_name} source expects ETag state but got: {state:?}"
+            ))
+            .int_err()
+            .into()),
+        }
+    }
+
+    fn parse(s: &str) -> Result<Self, MalformedSourceStateError> {
+        let blocks = s
+            .split(',')
+            .map(|entry| {
+                let (number, hash) = entry.split_once('@')?;
+                Some((number.parse().ok()?, hash.parse().ok()?))
+            })
+            .collect::<Option<Vec<_>>>()
+            .filter(|blocks| blocks.windows(2).all(|w| w[0].0 < w[1].0))
+            .ok_or_else(|| {
+                MalformedSourceStateError::new(format!("Malformed Ethereum source state: {s}"))
+            })?;
+
+        Ok(Self { blocks })
+    }
+
+    pub(super) fn to_etag(&self) -> String {
+        self.blocks
+            .iter()
+            .map(|(number, hash)| format!("{number}@{hash:x}"))
+            .collect::<Vec<_>>()
+            .join(",")
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[derive(thiserror::Error, Debug)]
+#[error("{message}")]
+struct MalformedSourceStateError {
+    pub message: String,
+}
+
+impl MalformedSourceStateError {
     fn new(message: impl Into<String>) -> Self {
         Self {
             message: message.into(),
@@ -300,3 +514,15 @@ impl EthereumRpcError {
 }
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[derive(thiserror::Error, Debug)]
+#[error(
+    "Chain reorganization affects block {block_number} or earlier, which is deeper than the \
+     confirmation depth of {confirmation_depth} blocks"
+)]
+struct EthereumReorgTooDeepError {
+    pub block_number: u64,
+    pub confirmation_depth: u64,
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -0,0 +1,562 @@
+// Copyright Kamu Data, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+use std::collections::HashMap;
+use std::path::Path;
+use std::sync::Arc;
+
+use alloy::dyn_abi::{DynSolValue, JsonAbiExt};
+use alloy::json_abi::Function;
+use alloy::primitives::{Address, B256};
+use alloy::rpc::types::eth::{Block, BlockTransactions, BlockTransactionsKind, TransactionReceipt};
+use datafusion::arrow::array::*;
+use datafusion::arrow::datatypes::{DataType, Field, Schema, TimeUnit};
+use datafusion::arrow::record_batch::RecordBatch;
+use internal_error::{ErrorIntoInternal, InternalError, ResultIntoInternal};
+use kamu_core::*;
+use kamu_datasets::DatasetEnvVar;
+use opendatafabric::*;
+
+use super::evm::*;
+use super::*;
+use crate::PollingSourceState;
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+impl FetchService {
+    pub(crate) async fn fetch_ethereum_blocks(
+        &self,
+        fetch: &FetchStepEthereumBlocks,
+        prev_source_state: Option<&PollingSourceState>,
+        target_path: &Path,
+        dataset_env_vars: &HashMap<String, DatasetEnvVar>,
+        listener: &Arc<dyn FetchProgressListener>,
+    ) -> Result<FetchResult, PollingIngestError> {
+        let rpc_client = self
+            .connect_ethereum_node(fetch.chain_id, fetch.node_url.as_ref(), dataset_env_vars)
+            .await?;
+
+        self.scan_ethereum_blocks(
+            &rpc_client,
+            EthereumBlockRange {
+                source_name: "EthereumBlocks",
+                from_block: fetch.from_block,
+                to_block: fetch.to_block,
+            },
+            &mut EthBlocksToArrow::default(),
+            prev_source_state,
+            target_path,
+            listener,
+        )
+        .await
+    }
+
+    pub(crate) async fn fetch_ethereum_transactions(
+        &self,
+        fetch: &FetchStepEthereumTransactions,
+        prev_source_state: Option<&PollingSourceState>,
+        target_path: &Path,
+        dataset_env_vars: &HashMap<String, DatasetEnvVar>,
+        listener: &Arc<dyn FetchProgressListener>,
+    ) -> Result<FetchResult, PollingIngestError> {
+        let to_address = fetch
+            .to_address
+            .as_ref()
+            .map(|a| a.parse::<Address>())
+            .transpose()
+            .int_err()?;
+
+        // Alloy does not support newlines in signatures, but it's nice for formatting
+        let function = fetch
+            .signature
+            .as_ref()
+            .map(|s| Function::parse(&s.replace('\n', " ")))
+            .transpose()
+            .int_err()?;
+
+        let rpc_client = self
+            .connect_ethereum_node(fetch.chain_id, fetch.node_url.as_ref(), dataset_env_vars)
+            .await?;
+
+        self.scan_ethereum_blocks(
+            &rpc_client,
+            EthereumBlockRange {
+                source_name: "EthereumTransactions",
+                from_block: fetch.from_block,
+                to_block: fetch.to_block,
+            },
+            &mut EthTransactionsToArrow::new(to_address, function),
+            prev_source_state,
+            target_path,
+            listener,
+        )
+        .await
+    }
+
+    // Unlike logs that are fetched in strides of blocks via `eth_getLogs`, blocks
+    // and transactions have to be fetched one block at a time, so the scan is
+    // interrupted to commit progress in the same way, but the records are
+    // transcoded as soon as each block is received.
+    //
+    // Chain reorganizations are handled exactly like in the `EthereumLogs` source.
+    async fn scan_ethereum_blocks(
+        &self,
+        rpc_client: &EthereumRpcClient,
+        range: EthereumBlockRange,
+        coder: &mut dyn EthBlockTranscoder,
+        prev_source_state: Option<&PollingSourceState>,
+        target_path: &Path,
+        listener: &Arc<dyn FetchProgressListener>,
+    ) -> Result<FetchResult, PollingIngestError> {
+        use alloy::providers::Provider;
+
+        let prev_state =
+            EthereumScanState::from_source_state(prev_source_state, range.source_name)?;
+
+        let EthereumChainCheck {
+            orphaned_blocks,
+            mut valid_blocks,
+        } = self
+            .check_ethereum_chain(rpc_client, prev_state.as_ref())
+            .await?;
+
+        let resume_after_block = valid_blocks.last().map(|(number, _)| *number);
+
+        // Block interval that will be considered during this iteration
+        let head_block = rpc_client.get_block_number().await.int_err()?;
+        let block_range_unprocessed = (
+            resume_after_block.map_or(range.from_block.unwrap_or(0), |n| n + 1),
+            range.to_block.map_or(head_block, |n| n.min(head_block)),
+        );
+
+        let mut last_seen_block = resume_after_block;
+
+        for number in block_range_unprocessed.0..=block_range_unprocessed.1 {
+            let transactions_kind = if coder.include_transactions() {
+                BlockTransactionsKind::Full
+            } else {
+                BlockTransactionsKind::Hashes
+            };
+
+            let block = rpc_client
+                .get_block(number.into(), transactions_kind)
+                .await
+                .int_err()?
+                .ok_or_else(|| {
+                    EthereumRpcError::new(format!("Node did not return block {number}")).int_err()
+                })?;
+
+            let receipts = if coder.include_transactions() {
+                rpc_client
+                    .get_block_receipts(number.into())
+                    .await
+                    .int_err()?
+                    .ok_or_else(|| {
+                        EthereumRpcError::new(format!(
+                            "Node did not return receipts of block {number}"
+                        ))
+                        .int_err()
+                    })?
+            } else {
+                Vec::new()
+            };
+
+            let hash = block.header.hash.ok_or_else(|| {
+                EthereumRpcError::new(format!("Node returned block {number} without a hash"))
+                    .int_err()
+            })?;
+
+            coder.append(number, hash, &block, &receipts)?;
+
+            // Remembering the hashes of scanned blocks avoids a race with a reorg that
+            // could happen before the unconfirmed blocks are recorded
+            valid_blocks.push((number, hash));
+            last_seen_block = Some(number);
+
+            let blocks_processed = number + 1 - block_range_unprocessed.0;
+
+            listener.on_progress(&FetchProgress {
+                fetched_bytes: blocks_processed,
+                total_bytes: TotalBytes::Exact(
+                    block_range_unprocessed.1 + 1 - block_range_unprocessed.0,
+                ),
+            });
+
+            if coder.len() as u64 >= self.source_config.target_records_per_slice {
+                tracing::info!(
+                    target_records_per_slice = self.source_config.target_records_per_slice,
+                    num_records = coder.len(),
+                    "Interrupting the scan after reaching the target batch size",
+                );
+                break;
+            }
+            if blocks_processed >= self.eth_source_config.commit_after_blocks_scanned {
+                tracing::info!(
+                    commit_after_blocks_scanned =
+                        self.eth_source_config.commit_after_blocks_scanned,
+                    blocks_processed,
+                    "Interrupting the scan to commit progress",
+                );
+                break;
+            }
+        }
+
+        // Have we made any progress?
+        if resume_after_block == last_seen_block && orphaned_blocks.is_empty() {
+            return Ok(FetchResult::UpToDate);
+        }
+
+        // Resuming is only possible from a block of the canonical chain, so there is
+        // always one in case of a reorg
+        let last_seen_block = last_seen_block.unwrap();
+
+        let new_state = self
+            .record_unconfirmed_blocks(rpc_client, last_seen_block, &valid_blocks)
+            .await?;
+
+        tracing::info!(
+            blocks_scanned = (last_seen_block + 1).saturating_sub(block_range_unprocessed.0),
+            block_range_scanned = ?(block_range_unprocessed.0, last_seen_block),
+            block_range_ramaining = ?(last_seen_block + 1, block_range_unprocessed.1),
+            num_records = coder.len(),
+            "Finished block scan cycle",
+        );
+
+        // Did we exhaust the source? (not accounting for new blocks)
+        let has_more = last_seen_block < block_range_unprocessed.1;
+
+        let batch = coder.finish()?;
+        Self::write_ethereum_batch(&batch, new_state, &orphaned_blocks, has_more, target_path)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+struct EthereumBlockRange {
+    source_name: &'static str,
+    from_block: Option<u64>,
+    to_block: Option<u64>,
+}
+
+/// Accumulates records extracted from the scanned blocks in Arrow format
+trait EthBlockTranscoder: Send {
+    /// Whether blocks have to be fetched with full transactions and receipts
+    fn include_transactions(&self) -> bool;
+
+    fn append(
+        &mut self,
+        number: u64,
+        hash: B256,
+        block: &Block,
+        receipts: &[TransactionReceipt],
+    ) -> Result<(), InternalError>;
+
+    fn len(&self) -> usize;
+
+    fn finish(&mut self) -> Result<RecordBatch, InternalError>;
+}
+
+fn block_timestamp_type() -> DataType {
+    DataType::Timestamp(TimeUnit::Second, Some("UTC".into()))
+}
+
+fn to_u64(value: u128) -> Result<u64, InternalError> {
+    u64::try_from(value).int_err()
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[derive(Default)]
+struct EthBlocksToArrow {
+    block_number: UInt64Builder,
+    block_hash: BinaryBuilder,
+    block_timestamp: TimestampSecondBuilder,
+    parent_hash: BinaryBuilder,
+    miner: BinaryBuilder,
+    gas_limit: UInt64Builder,
+    gas_used: UInt64Builder,
+    base_fee_per_gas: UInt64Builder,
+    transaction_count: UInt64Builder,
+}
+
+impl EthBlockTranscoder for EthBlocksToArrow {
+    fn include_transactions(&self) -> bool {
+        false
+    }
+
+    fn append(
+        &mut self,
+        number: u64,
+        hash: B256,
+        block: &Block,
+        _receipts: &[TransactionReceipt],
+    ) -> Result<(), InternalError> {
+        let header = &block.header;
+
+        self.block_number.append_value(number);
+        self.block_hash.append_value(hash.as_slice());
+        self.block_timestamp
+            .append_value(i64::try_from(header.timestamp).int_err()?);
+        self.parent_hash.append_value(header.parent_hash.as_slice());
+        self.miner.append_value(header.miner.as_slice());
+        self.gas_limit.append_value(to_u64(header.gas_limit)?);
+        self.gas_used.append_value(to_u64(header.gas_used)?);
+        self.base_fee_per_gas
+            .append_option(header.base_fee_per_gas.map(to_u64).transpose()?);
+        self.transaction_count
+            .append_value(block.transactions.len() as u64);
+
+        Ok(())
+    }
+
+    fn len(&self) -> usize {
+        self.block_number.len()
+    }
+
+    fn finish(&mut self) -> Result<RecordBatch, InternalError> {
+        let schema = Schema::new(vec![
+            Field::new("block_number", DataType::UInt64, false),
+            Field::new("block_hash", DataType::Binary, false),
+            Field::new("block_timestamp", block_timestamp_type(), false),
+            Field::new("parent_hash", DataType::Binary, false),
+            Field::new("miner", DataType::Binary, false),
+            Field::new("gas_limit", DataType::UInt64, false),
+            Field::new("gas_used", DataType::UInt64, false),
+            Field::new("base_fee_per_gas", DataType::UInt64, true),
+            Field::new("transaction_count", DataType::UInt64, false),
+        ]);
+
+        RecordBatch::try_new(
+            Arc::new(schema),
+            vec![
+                Arc::new(self.block_number.finish()),
+                Arc::new(self.block_hash.finish()),
+                Arc::new(self.block_timestamp.finish().with_timezone("UTC")),
+                Arc::new(self.parent_hash.finish()),
+                Arc::new(self.miner.finish()),
+                Arc::new(self.gas_limit.finish()),
+                Arc::new(self.gas_used.finish()),
+                Arc::new(self.base_fee_per_gas.finish()),
+                Arc::new(self.transaction_count.finish()),
+            ],
+        )
+        .int_err()
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+struct EthTransactionsToArrow {
+    to_address: Option<Address>,
+    function: Option<Function>,
+
+    block_number: UInt64Builder,
+    block_hash: BinaryBuilder,
+    block_timestamp: TimestampSecondBuilder,
+    transaction_index: UInt64Builder,
+    transaction_hash: BinaryBuilder,
+    from: BinaryBuilder,
+    to: BinaryBuilder,
+    value: StringBuilder,
+    nonce: UInt64Builder,
+    gas: UInt64Builder,
+    gas_price: UInt64Builder,
+    gas_used: UInt64Builder,
+    status: BooleanBuilder,
+    contract_address: BinaryBuilder,
+    input: BinaryBuilder,
+    input_decoded: StringBuilder,
+}
+
+impl EthTransactionsToArrow {
+    fn new(to_address: Option<Address>, function: Option<Function>) -> Self {
+        Self {
+            to_address,
+            function,
+            block_number: UInt64Builder::new(),
+            block_hash: BinaryBuilder::new(),
+            block_timestamp: TimestampSecondBuilder::new(),
+            transaction_index: UInt64Builder::new(),
+            transaction_hash: BinaryBuilder::new(),
+            from: BinaryBuilder::new(),
+            to: BinaryBuilder::new(),
+            value: StringBuilder::new(),
+            nonce: UInt64Builder::new(),
+            gas: UInt64Builder::new(),
+            gas_price: UInt64Builder::new(),
+            gas_used: UInt64Builder::new(),
+            status: BooleanBuilder::new(),
+            contract_address: BinaryBuilder::new(),
+            input: BinaryBuilder::new(),
+            input_decoded: StringBuilder::new(),
+        }
+    }
+
+    /// Decodes call arguments into a JSON object, returning `None` when the
+    /// input does not correspond to the function
+    fn decode_input(function: &Function, input: &[u8]) -> Option<String> {
+        let args = input.strip_prefix(function.selector().as_slice())?;
+        let values = function.abi_decode_input(args, true).ok()?;
+
+        let decoded: serde_json::Map<_, _> = function
+            .inputs
+            .iter()
+            .zip(&values)
+            .enumerate()
+            .map(|(i, (param, value))| {
+                let name = if param.name.is_empty() {
+                    i.to_string()
+                } else {
+                    param.name.clone()
+                };
+                (name, sol_value_to_json(value))
+            })
+            .collect();
+
+        Some(serde_json::Value::Object(decoded).to_string())
+    }
+}
+
+impl EthBlockTranscoder for EthTransactionsToArrow {
+    fn include_transactions(&self) -> bool {
+        true
+    }
+
+    fn append(
+        &mut self,
+        number: u64,
+        hash: B256,
+        block: &Block,
+        receipts: &[TransactionReceipt],
+    ) -> Result<(), InternalError> {
+        let BlockTransactions::Full(transactions) = &block.transactions else {
+            return Err(EthereumRpcError::new(format!(
+                "Node returned block {number} without transaction details"
+            ))
+            .int_err());
+        };
+        if transactions.len() != receipts.len() {
+            return Err(EthereumRpcError::new(format!(
+                "Node returned {} receipts for {} transactions of block {number}",
+                receipts.len(),
+                transactions.len()
+            ))
+            .int_err());
+        }
+
+        let block_timestamp = i64::try_from(block.header.timestamp).int_err()?;
+
+        for (tx, receipt) in transactions.iter().zip(receipts) {
+            if tx.hash != receipt.transaction_hash {
+                return Err(EthereumRpcError::new(format!(
+                    "Receipts of block {number} do not match its transactions"
+                ))
+                .int_err());
+            }
+            if self.to_address.is_some() && tx.to != self.to_address {
+                continue;
+            }
+
+            self.block_number.append_value(number);
+            self.block_hash.append_value(hash.as_slice());
+            self.block_timestamp.append_value(block_timestamp);
+            self.transaction_index.append_option(tx.transaction_index);
+            self.transaction_hash.append_value(tx.hash.as_slice());
+            self.from.append_value(tx.from.as_slice());
+            self.to.append_option(tx.to.as_ref().map(|a| a.as_slice()));
+            self.value.append_value(tx.value.to_string());
+            self.nonce.append_value(tx.nonce);
+            self.gas.append_value(to_u64(tx.gas)?);
+            self.gas_price
+                .append_value(to_u64(receipt.effective_gas_price)?);
+            self.gas_used.append_value(to_u64(receipt.gas_used)?);
+            self.status.append_value(receipt.status());
+            self.contract_address
+                .append_option(receipt.contract_address.as_ref().map(|a| a.as_slice()));
+            self.input.append_value(&tx.input);
+
+            if let Some(function) = &self.function {
+                self.input_decoded
+                    .append_option(Self::decode_input(function, &tx.input));
+            }
+        }
+
+        Ok(())
+    }
+
+    fn len(&self) -> usize {
+        self.block_number.len()
+    }
+
+    fn finish(&mut self) -> Result<RecordBatch, InternalError> {
+        let mut fields = vec![
+            Field::new("block_number", DataType::UInt64, false),
+            Field::new("block_hash", DataType::Binary, false),
+            Field::new("block_timestamp", block_timestamp_type(), false),
+            Field::new("transaction_index", DataType::UInt64, true),
+            Field::new("transaction_hash", DataType::Binary, false),
+            Field::new("from", DataType::Binary, false),
+            Field::new("to", DataType::Binary, true),
+            Field::new("value", DataType::Utf8, false),
+            Field::new("nonce", DataType::UInt64, false),
+            Field::new("gas", DataType::UInt64, false),
+            Field::new("gas_price", DataType::UInt64, false),
+            Field::new("gas_used", DataType::UInt64, false),
+            Field::new("status", DataType::Boolean, false),
+            Field::new("contract_address", DataType::Binary, true),
+            Field::new("input", DataType::Binary, false),
+        ];
+
+        let mut columns: Vec<ArrayRef> = vec![
+            Arc::new(self.block_number.finish()),
+            Arc::new(self.block_hash.finish()),
+            Arc::new(self.block_timestamp.finish().with_timezone("UTC")),
+            Arc::new(self.transaction_index.finish()),
+            Arc::new(self.transaction_hash.finish()),
+            Arc::new(self.from.finish()),
+            Arc::new(self.to.finish()),
+            Arc::new(self.value.finish()),
+            Arc::new(self.nonce.finish()),
+            Arc::new(self.gas.finish()),
+            Arc::new(self.gas_price.finish()),
+            Arc::new(self.gas_used.finish()),
+            Arc::new(self.status.finish()),
+            Arc::new(self.contract_address.finish()),
+            Arc::new(self.input.finish()),
+        ];
+
+        if self.function.is_some() {
+            fields.push(Field::new("input_decoded", DataType::Utf8, true));
+            columns.push(Arc::new(self.input_decoded.finish()));
+        }
+
+        RecordBatch::try_new(Arc::new(Schema::new(fields)), columns).int_err()
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Big integers are represented as strings to avoid losing precision
+fn sol_value_to_json(value: &DynSolValue) -> serde_json::Value {
+    match value {
+        DynSolValue::Bool(v) => (*v).into(),
+        DynSolValue::Int(v, _) => v.to_string().into(),
+        DynSolValue::Uint(v, _) => v.to_string().into(),
+        DynSolValue::Address(v) => v.to_string().into(),
+        DynSolValue::FixedBytes(v, size) => format!("0x{}", hex::encode(&v[..*size])).into(),
+        DynSolValue::Bytes(v) => format!("0x{}", hex::encode(v)).into(),
+        DynSolValue::String(v) => v.clone().into(),
+        DynSolValue::Array(v) | DynSolValue::FixedArray(v) | DynSolValue::Tuple(v) => {
+            v.iter().map(sol_value_to_json).collect()
+        }
+        other => format!("{other:?}").into(),
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -100,6 +100,8 @@ impl FetchService {
             source_event_time,
             has_more: !matched_files.is_empty(),
             zero_copy_path: fetch_res.zero_copy_path,
+            retraction: None,
+            acknowledgement: None,
         }))
     }
 
@@ -151,6 +153,8 @@ impl FetchService {
             source_event_time,
             has_more: false,
             zero_copy_path: Some(path.to_path_buf()),
+            retraction: None,
+            acknowledgement: None,
         }))
     }
 
@@ -102,6 +102,8 @@ impl FetchService {
             source_event_time: Some(system_time),
             has_more: false,
             zero_copy_path: None,
+            retraction: None,
+            acknowledgement: None,
         }))
     }
 }
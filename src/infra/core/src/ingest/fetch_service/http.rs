@@ -9,10 +9,12 @@
 
 use std::path::Path;
 use std::sync::Arc;
+use std::time::Duration;
 
 use ::http::{header, HeaderMap, HeaderName, HeaderValue, StatusCode};
 use chrono::{DateTime, Utc};
-use internal_error::{ErrorIntoInternal, ResultIntoInternal};
+use futures::TryStreamExt;
+use internal_error::{ErrorIntoInternal, InternalError, ResultIntoInternal};
 use kamu_core::*;
 use opendatafabric::*;
 use url::Url;
@@ -23,7 +25,6 @@ use crate::PollingSourceState;
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
 
 impl FetchService {
-    // TODO: PERF: Consider compression
     pub(super) async fn fetch_http(
         &self,
         url: Url,
@@ -34,21 +35,7 @@ impl FetchService {
         system_time: &DateTime<Utc>,
         listener: &Arc<dyn FetchProgressListener>,
     ) -> Result<FetchResult, PollingIngestError> {
-        use tokio::io::AsyncWriteExt;
-
-        let mut client_builder = reqwest::Client::builder();
-
-        if !self.http_source_config.user_agent.is_empty() {
-            client_builder = client_builder.user_agent(&self.http_source_config.user_agent);
-        }
-
-        let client = client_builder
-            .connect_timeout(self.http_source_config.connect_timeout)
-            .redirect(reqwest::redirect::Policy::limited(
-                self.http_source_config.max_redirects,
-            ))
-            .build()
-            .int_err()?;
+        let client = self.http_client()?;
 
         let mut headers: HeaderMap = headers
             .into_iter()
@@ -72,64 +59,94 @@ impl FetchService {
             }
         }
 
-        let mut response = match client.get(url.clone()).headers(headers).send().await {
-            Ok(r) => Ok(r),
-            Err(err) if err.is_connect() || err.is_timeout() => Err(
-                PollingIngestError::unreachable(url.as_str(), Some(err.into())),
-            ),
-            Err(err) => Err(err.int_err().into()),
-        }?;
-
-        match response.status() {
-            StatusCode::OK => (),
-            StatusCode::NOT_MODIFIED => {
-                return Ok(FetchResult::UpToDate);
-            }
-            StatusCode::NOT_FOUND => {
-                return Err(PollingIngestError::not_found(url.as_str(), None));
-            }
-            code => {
-                let body = response.text().await.ok();
-
-                return Err(PollingIngestError::unreachable(
-                    url.as_str(),
-                    Some(HttpStatusError::new(u32::from(code.as_u16()), body).into()),
-                ));
-            }
+        // Reqwest transparently decodes gzip, brotli and deflate, but not zstd, so we
+        // advertise it explicitly and decode it ourselves below
+        if !headers.contains_key(header::ACCEPT_ENCODING) {
+            headers.insert(
+                header::ACCEPT_ENCODING,
+                HeaderValue::from_static("gzip, deflate, br, zstd"),
+            );
         }
 
-        let mut last_modified_time = None;
-        let source_state = if let Some(etag) = response.headers().get(reqwest::header::ETAG) {
-            Some(PollingSourceState::ETag(
-                etag.to_str().int_err()?.to_string(),
-            ))
-        } else if let Some(last_modified) = response.headers().get(reqwest::header::LAST_MODIFIED) {
-            let last_modified = Self::parse_http_date_time(last_modified.to_str().int_err()?);
-            last_modified_time = Some(last_modified);
-            Some(PollingSourceState::LastModified(last_modified))
-        } else {
-            None
-        };
+        // Errors while reading the body restart the request, sharing the retry budget
+        // with the request itself
+        let mut attempt = 0;
+        let (source_state, last_modified_time) = loop {
+            let response = self.http_get_with_retries(&client, &url, &headers).await?;
 
-        let total_bytes = response
-            .content_length()
-            .map_or(TotalBytes::Unknown, TotalBytes::Exact);
-        let mut fetched_bytes = 0;
-        let mut file = tokio::fs::File::create(target_path).await.int_err()?;
+            match response.status() {
+                StatusCode::OK => (),
+                StatusCode::NOT_MODIFIED => {
+                    return Ok(FetchResult::UpToDate);
+                }
+                StatusCode::NOT_FOUND => {
+                    return Err(PollingIngestError::not_found(url.as_str(), None));
+                }
+                code => {
+                    let body = response.text().await.ok();
 
-        while let Some(chunk) = response.chunk().await.int_err()? {
-            file.write_all(&chunk).await.int_err()?;
+                    return Err(PollingIngestError::unreachable(
+                        url.as_str(),
+                        Some(HttpStatusError::new(u32::from(code.as_u16()), body).into()),
+                    ));
+                }
+            }
 
-            fetched_bytes += chunk.len() as u64;
+            let mut last_modified_time = None;
+            let source_state = if let Some(etag) = response.headers().get(reqwest::header::ETAG) {
+                Some(PollingSourceState::ETag(
+                    etag.to_str().int_err()?.to_string(),
+                ))
+            } else if let Some(last_modified) =
+                response.headers().get(reqwest::header::LAST_MODIFIED)
+            {
+                match Self::parse_http_date_time(last_modified.to_str().int_err()?) {
+                    Ok(last_modified) => {
+                        last_modified_time = Some(last_modified);
+                        Some(PollingSourceState::LastModified(last_modified))
+                    }
+                    Err(err) => {
+                        tracing::warn!(%url, error = %err, "Ignoring invalid Last-Modified header");
+                        None
+                    }
+                }
+            } else {
+                None
+            };
 
-            listener.on_progress(&FetchProgress {
-                fetched_bytes,
-                total_bytes,
-            });
-        }
+            match Self::download_http_body(response, target_path, listener).await {
+                Ok(()) => break (source_state, last_modified_time),
+                Err(err) => {
+                    // Only errors of the transfer itself are retried, not the local IO or
+                    // decoding errors
+                    let Some(transfer_err) = err
+                        .get_ref()
+                        .and_then(|e| e.downcast_ref::<reqwest::Error>())
+                    else {
+                        return Err(err.int_err().into());
+                    };
 
-        // Important: Ensures file is closed immediately when dropped
-        file.flush().await.int_err()?;
+                    if attempt >= self.http_source_config.max_retries {
+                        return Err(PollingIngestError::unreachable(
+                            url.as_str(),
+                            Some(err.into()),
+                        ));
+                    }
+
+                    let delay = self.http_retry_backoff(attempt);
+                    tracing::warn!(
+                        %url,
+                        attempt,
+                        ?delay,
+                        error = %transfer_err,
+                        "Reading HTTP response body failed, retrying",
+                    );
+
+                    attempt += 1;
+                    tokio::time::sleep(delay).await;
+                }
+            }
+        };
 
         let source_event_time = match event_time_source {
             None | Some(EventTimeSource::FromMetadata(_)) => last_modified_time,
@@ -147,26 +164,207 @@ impl FetchService {
             source_event_time,
             has_more: false,
             zero_copy_path: None,
+            retraction: None,
+            acknowledgement: None,
         }))
     }
 
-    fn parse_http_date_time(val: &str) -> DateTime<Utc> {
+    /// Writes the response body into the file, decoding it if necessary.
+    /// Errors of the transfer are returned as IO errors wrapping
+    /// [`reqwest::Error`].
+    async fn download_http_body(
+        response: reqwest::Response,
+        target_path: &Path,
+        listener: &Arc<dyn FetchProgressListener>,
+    ) -> Result<(), std::io::Error> {
+        use tokio::io::AsyncWriteExt;
+
+        let is_zstd = response
+            .headers()
+            .get(header::CONTENT_ENCODING)
+            .is_some_and(|v| v.as_bytes().eq_ignore_ascii_case(b"zstd"));
+
+        let total_bytes = response
+            .content_length()
+            .map_or(TotalBytes::Unknown, TotalBytes::Exact);
+        let mut fetched_bytes = 0;
+        let mut file = tokio::fs::File::create(target_path).await?;
+
+        let stream = response.bytes_stream().map_ok(|chunk| {
+            fetched_bytes += chunk.len() as u64;
+
+            listener.on_progress(&FetchProgress {
+                fetched_bytes,
+                total_bytes,
+            });
+
+            chunk
+        });
+        let mut reader =
+            tokio_util::io::StreamReader::new(Box::pin(stream.map_err(std::io::Error::other)));
+
+        if is_zstd {
+            let mut decoder = async_compression::tokio::bufread::ZstdDecoder::new(reader);
+            tokio::io::copy(&mut decoder, &mut file).await?;
+        } else {
+            tokio::io::copy(&mut reader, &mut file).await?;
+        }
+
+        // Important: Ensures file is closed immediately when dropped
+        file.flush().await
+    }
+
+    pub(super) fn http_client(&self) -> Result<reqwest::Client, InternalError> {
+        let cfg = &self.http_source_config;
+        let mut client_builder = reqwest::Client::builder();
+
+        if !cfg.user_agent.is_empty() {
+            client_builder = client_builder.user_agent(&cfg.user_agent);
+        }
+
+        if let Some(request_timeout) = cfg.request_timeout {
+            client_builder = client_builder.timeout(request_timeout);
+        }
+
+        if let Some(proxy) = &cfg.proxy {
+            client_builder = client_builder.proxy(reqwest::Proxy::all(proxy.as_str()).int_err()?);
+        }
+
+        for path in &cfg.ca_certificates {
+            let pem = std::fs::read(path).int_err()?;
+            client_builder = client_builder
+                .add_root_certificate(reqwest::Certificate::from_pem(&pem).int_err()?);
+        }
+
+        client_builder
+            .connect_timeout(cfg.connect_timeout)
+            .redirect(reqwest::redirect::Policy::limited(cfg.max_redirects))
+            .build()
+            .int_err()
+    }
+
+    /// Performs a GET request retrying it upon connection errors, timeouts,
+    /// `429 Too Many Requests` and `5xx` server errors with exponential backoff
+    /// or the delay specified by the server via `Retry-After` header. An
+    /// invalid `Retry-After` header is ignored in favor of the backoff.
+    ///
+    /// Responses with any other status are returned to the caller as is.
+    pub(super) async fn http_get_with_retries(
+        &self,
+        client: &reqwest::Client,
+        url: &Url,
+        headers: &HeaderMap,
+    ) -> Result<reqwest::Response, PollingIngestError> {
+        let cfg = &self.http_source_config;
+        let mut attempt = 0;
+
+        loop {
+            let backoff = self.http_retry_backoff(attempt);
+
+            let delay = match client
+                .get(url.clone())
+                .headers(headers.clone())
+                .send()
+                .await
+            {
+                Ok(response) => {
+                    let status = response.status();
+                    if !(status == StatusCode::TOO_MANY_REQUESTS || status.is_server_error())
+                        || attempt >= cfg.max_retries
+                    {
+                        return Ok(response);
+                    }
+
+                    let retry_after = response.headers().get(header::RETRY_AFTER).and_then(
+                        |value| {
+                            let delay = value.to_str().ok().and_then(parse_retry_after);
+                            if delay.is_none() {
+                                tracing::warn!(%url, ?value, "Ignoring invalid Retry-After header");
+                            }
+                            delay
+                        },
+                    );
+
+                    match retry_after {
+                        None => backoff,
+                        Some(delay) if delay > cfg.max_retry_after => {
+                            return Err(PollingIngestError::unreachable(
+                                url.as_str(),
+                                Some(
+                                    HttpRetryError::new(format!(
+                                        "Server asked to retry after {delay:?} which exceeds the \
+                                         maximum of {:?}",
+                                        cfg.max_retry_after
+                                    ))
+                                    .into(),
+                                ),
+                            ));
+                        }
+                        Some(delay) => delay,
+                    }
+                }
+                Err(err) if err.is_connect() || err.is_timeout() => {
+                    if attempt >= cfg.max_retries {
+                        return Err(PollingIngestError::unreachable(
+                            url.as_str(),
+                            Some(err.into()),
+                        ));
+                    }
+                    backoff
+                }
+                Err(err) => return Err(err.int_err().into()),
+            };
+
+            tracing::warn!(%url, attempt, ?delay, "HTTP request failed, retrying");
+
+            attempt += 1;
+            tokio::time::sleep(delay).await;
+        }
+    }
+
+    /// Exponential backoff before the retry that follows the specified attempt
+    fn http_retry_backoff(&self, attempt: u32) -> Duration {
+        let cfg = &self.http_source_config;
+        cfg.retry_backoff
+            .saturating_mul(1 << attempt.min(16))
+            .min(cfg.max_retry_after)
+    }
+
+    fn parse_http_date_time(val: &str) -> Result<DateTime<Utc>, HttpDateTimeParseError> {
         DateTime::parse_from_rfc2822(val)
-            .unwrap_or_else(|e| panic!("Failed to parse Last-Modified header {val}: {e}"))
-            .into()
+            .map(Into::into)
+            .map_err(|source| HttpDateTimeParseError {
+                value: val.to_string(),
+                source,
+            })
     }
 }
 
+/// Parses `Retry-After` header that contains either a number of seconds or an
+/// HTTP date
+fn parse_retry_after(value: &str) -> Option<Duration> {
+    if let Ok(seconds) = value.trim().parse::<u64>() {
+        return Some(Duration::from_secs(seconds));
+    }
+
+    let at = DateTime::parse_from_rfc2822(value.trim()).ok()?;
+    Some(
+        (at.with_timezone(&Utc) - Utc::now())
+            .to_std()
+            .unwrap_or(Duration::ZERO),
+    )
+}
+
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
 
 #[derive(thiserror::Error, Debug)]
-struct HttpStatusError {
+pub(super) struct HttpStatusError {
     pub code: u32,
     pub body: Option<String>,
 }
 
 impl HttpStatusError {
-    fn new(code: u32, body: Option<String>) -> Self {
+    pub(super) fn new(code: u32, body: Option<String>) -> Self {
         Self { code, body }
     }
 }
@@ -182,3 +380,29 @@ impl std::fmt::Display for HttpStatusError {
 }
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[derive(thiserror::Error, Debug)]
+#[error("HTTP request retry error: {message}")]
+struct HttpRetryError {
+    pub message: String,
+}
+
+impl HttpRetryError {
+    fn new(message: impl Into<String>) -> Self {
+        Self {
+            message: message.into(),
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[derive(thiserror::Error, Debug)]
+#[error("Failed to parse HTTP date time {value:?}")]
+struct HttpDateTimeParseError {
+    pub value: String,
+    #[source]
+    pub source: chrono::ParseError,
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
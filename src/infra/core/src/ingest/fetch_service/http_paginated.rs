@@ -0,0 +1,401 @@
+// Copyright Kamu Data, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+use std::cmp::Ordering;
+use std::collections::HashMap;
+use std::path::Path;
+use std::sync::Arc;
+
+use ::http::{header, HeaderMap, HeaderName, HeaderValue, StatusCode};
+use internal_error::{ErrorIntoInternal, InternalError, ResultIntoInternal};
+use kamu_core::*;
+use kamu_datasets::DatasetEnvVar;
+use opendatafabric::*;
+use url::Url;
+
+use super::http::HttpStatusError;
+use super::*;
+use crate::PollingSourceState;
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+impl FetchService {
+    // Pages are requested one by one and records found in them are written
+    // into the target file as NDJSON.
+    //
+    // With the high-water mark configured the greatest value of its field is
+    // stored in the source state and passed to the first page request of the
+    // next poll. Otherwise body cursor pagination stores the last next page
+    // cursor returned by the API to resume from it, while the other pagination
+    // kinds fetch all pages on every poll. When the last page has no next
+    // cursor, its own cursor is stored along with the number of records in it,
+    // so that the page is requested again but only the records appended to it
+    // since are fetched.
+    pub(super) async fn fetch_http_paginated(
+        &self,
+        fetch: &FetchStepHttpPaginated,
+        prev_source_state: Option<&PollingSourceState>,
+        target_path: &Path,
+        dataset_env_vars: &HashMap<String, DatasetEnvVar>,
+        listener: &Arc<dyn FetchProgressListener>,
+    ) -> Result<FetchResult, PollingIngestError> {
+        use tokio::io::AsyncWriteExt;
+
+        let prev_state = match prev_source_state {
+            None => None,
+            Some(PollingSourceState::ETag(value)) => Some(value.as_str()),
+            Some(state) => Err(PaginationError::new(format!(
+                "Paginated HTTP source expects ETag state but got: {state:?}"
+            ))
+            .int_err())?,
+        };
+
+        let first_page_url = self.template_url(&fetch.url, dataset_env_vars).await?;
+        let headers: HeaderMap = self
+            .template_headers(&fetch.headers, dataset_env_vars)
+            .await?
+            .into_iter()
+            .map(|h| {
+                let name = HeaderName::try_from(h.name).int_err()?;
+                let value = HeaderValue::try_from(h.value).int_err()?;
+                Ok((name, value))
+            })
+            .collect::<Result<_, InternalError>>()?;
+
+        let mut position = match (&fetch.high_water_mark, &fetch.pagination, prev_state) {
+            (None, HttpPagination::BodyCursor(_), Some(state)) => serde_json::from_str(state)
+                .map_err(|e| {
+                    PaginationError::new(format!("Invalid body cursor state {state}: {e}"))
+                        .int_err()
+                })?,
+            _ => BodyCursorPosition::default(),
+        };
+
+        let mut page_url = first_page_url.clone();
+        match &fetch.pagination {
+            HttpPagination::LinkHeader(_) => {}
+            HttpPagination::BodyCursor(p) => {
+                if let Some(cursor) = &position.cursor {
+                    set_query_param(&mut page_url, &p.cursor_param, cursor);
+                }
+            }
+            HttpPagination::OffsetLimit(p) => {
+                set_query_param(&mut page_url, &p.offset_param, "0");
+                set_query_param(&mut page_url, &p.limit_param, &p.page_size.to_string());
+            }
+        }
+        if let (Some(hwm), Some(value)) = (&fetch.high_water_mark, prev_state) {
+            set_query_param(&mut page_url, &hwm.param, value);
+        }
+
+        let client = self.http_client()?;
+
+        let mut file = tokio::fs::File::create(target_path).await.int_err()?;
+        let mut fetched_bytes = 0;
+        let mut num_pages = 0;
+        let mut num_records = 0;
+        let mut high_water_mark: Option<serde_json::Value> = None;
+        let mut skip_records = position.skip;
+        let mut has_more = false;
+        let mut offset = 0;
+
+        loop {
+            tracing::debug!(%page_url, "Fetching page");
+
+            let response = self.http_get_page(&client, &page_url, &headers).await?;
+
+            let next_link = match &fetch.pagination {
+                HttpPagination::LinkHeader(_) => response
+                    .headers()
+                    .get_all(header::LINK)
+                    .iter()
+                    .filter_map(|v| v.to_str().ok())
+                    .find_map(parse_link_header_next),
+                _ => None,
+            };
+
+            let body = response.bytes().await.int_err()?;
+            fetched_bytes += body.len() as u64;
+            num_pages += 1;
+
+            let body: serde_json::Value = serde_json::from_slice(&body).map_err(|e| {
+                PaginationError::new(format!("Page {page_url} is not a valid JSON: {e}")).int_err()
+            })?;
+
+            let records = match &fetch.records_path {
+                None => Some(&body),
+                Some(path) => body.pointer(path),
+            };
+            let page_records = match records {
+                None | Some(serde_json::Value::Null) => &[][..],
+                Some(serde_json::Value::Array(records)) => records.as_slice(),
+                Some(_) => Err(PaginationError::new(format!(
+                    "Expected an array of records in page {page_url}"
+                ))
+                .int_err())?,
+            };
+
+            // Records of a page requested again were partially fetched by the previous poll
+            let records = &page_records[skip_records.min(page_records.len())..];
+            skip_records = 0;
+
+            for record in records {
+                if let Some(hwm) = &fetch.high_water_mark {
+                    if let Some(value) = record.get(&hwm.field).filter(|v| !v.is_null()) {
+                        let is_greater = match &high_water_mark {
+                            None => true,
+                            Some(max) => compare_json_values(value, max)?.is_gt(),
+                        };
+                        if is_greater {
+                            high_water_mark = Some(value.clone());
+                        }
+                    }
+                }
+
+                let mut line = serde_json::to_vec(record).int_err()?;
+                line.push(b'\n');
+                file.write_all(&line).await.int_err()?;
+            }
+            num_records += records.len() as u64;
+
+            listener.on_progress(&FetchProgress {
+                fetched_bytes,
+                total_bytes: TotalBytes::Unknown,
+            });
+
+            // Stop on empty pages to guard against APIs that keep returning the
+            // same cursor or link
+            if page_records.is_empty() {
+                break;
+            }
+
+            let next_page_url = match &fetch.pagination {
+                HttpPagination::LinkHeader(_) => match next_link {
+                    None => None,
+                    Some(link) => Some(page_url.join(&link).int_err()?),
+                },
+                HttpPagination::BodyCursor(p) => {
+                    match body.pointer(&p.cursor_path).and_then(json_value_to_param) {
+                        None => {
+                            position.skip = page_records.len();
+                            None
+                        }
+                        Some(cursor) => {
+                            let mut url = first_page_url.clone();
+                            set_query_param(&mut url, &p.cursor_param, &cursor);
+                            if let (Some(hwm), Some(value)) = (&fetch.high_water_mark, prev_state) {
+                                set_query_param(&mut url, &hwm.param, value);
+                            }
+                            position = BodyCursorPosition {
+                                cursor: Some(cursor),
+                                skip: 0,
+                            };
+                            Some(url)
+                        }
+                    }
+                }
+                HttpPagination::OffsetLimit(p) => {
+                    if (records.len() as u64) < p.page_size {
+                        None
+                    } else {
+                        offset += p.page_size;
+                        let mut url = page_url.clone();
+                        set_query_param(&mut url, &p.offset_param, &offset.to_string());
+                        Some(url)
+                    }
+                }
+            };
+
+            let Some(next_page_url) = next_page_url else {
+                break;
+            };
+
+            // Only the cursor state allows resuming from the middle of the result set
+            if fetch.high_water_mark.is_none()
+                && matches!(fetch.pagination, HttpPagination::BodyCursor(_))
+                && num_records >= self.source_config.target_records_per_slice
+            {
+                has_more = true;
+                break;
+            }
+
+            page_url = next_page_url;
+        }
+
+        // Important: Ensures file is closed immediately when dropped
+        file.flush().await.int_err()?;
+
+        tracing::info!(num_pages, num_records, has_more, "Finished fetching pages");
+
+        let source_state = match (&fetch.high_water_mark, &fetch.pagination) {
+            (Some(_), _) => high_water_mark
+                .as_ref()
+                .and_then(json_value_to_param)
+                .or_else(|| prev_state.map(str::to_string)),
+            (None, HttpPagination::BodyCursor(_)) => {
+                if position == BodyCursorPosition::default() {
+                    None
+                } else {
+                    Some(serde_json::to_string(&position).int_err()?)
+                }
+            }
+            (None, _) => None,
+        }
+        .map(PollingSourceState::ETag);
+
+        if num_records == 0 && source_state.as_ref() == prev_source_state {
+            return Ok(FetchResult::UpToDate);
+        }
+
+        Ok(FetchResult::Updated(FetchResultUpdated {
+            source_state,
+            source_event_time: None,
+            has_more,
+            zero_copy_path: None,
+            retraction: None,
+            acknowledgement: None,
+        }))
+    }
+
+    /// Performs GET request, waiting and retrying it when the server responds
+    /// with `429 Too Many Requests`
+    async fn http_get_page(
+        &self,
+        client: &reqwest::Client,
+        url: &Url,
+        headers: &HeaderMap,
+    ) -> Result<reqwest::Response, PollingIngestError> {
+        let response = self.http_get_with_retries(client, url, headers).await?;
+
+        match response.status() {
+            StatusCode::OK => Ok(response),
+            StatusCode::NOT_FOUND => Err(PollingIngestError::not_found(url.as_str(), None)),
+            code => {
+                let body = response.text().await.ok();
+
+                Err(PollingIngestError::unreachable(
+                    url.as_str(),
+                    Some(HttpStatusError::new(u32::from(code.as_u16()), body).into()),
+                ))
+            }
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Position in the results of body cursor pagination that is stored in the
+/// source state as JSON
+#[derive(Debug, Default, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+struct BodyCursorPosition {
+    /// Cursor of the page to request, `None` for the first page
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    cursor: Option<String>,
+
+    /// Number of records at the start of the page that were already fetched
+    #[serde(default)]
+    skip: usize,
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+fn set_query_param(url: &mut Url, name: &str, value: &str) {
+    let pairs: Vec<(String, String)> = url
+        .query_pairs()
+        .filter(|(k, _)| k != name)
+        .map(|(k, v)| (k.into_owned(), v.into_owned()))
+        .collect();
+
+    url.query_pairs_mut()
+        .clear()
+        .extend_pairs(pairs)
+        .append_pair(name, value);
+}
+
+fn json_value_to_param(value: &serde_json::Value) -> Option<String> {
+    match value {
+        serde_json::Value::String(s) if !s.is_empty() => Some(s.clone()),
+        serde_json::Value::Number(n) => Some(n.to_string()),
+        _ => None,
+    }
+}
+
+fn compare_json_values(
+    a: &serde_json::Value,
+    b: &serde_json::Value,
+) -> Result<Ordering, InternalError> {
+    match (a, b) {
+        (serde_json::Value::String(a), serde_json::Value::String(b)) => Ok(a.cmp(b)),
+        (serde_json::Value::Number(a), serde_json::Value::Number(b)) => {
+            if let (Some(a), Some(b)) = (a.as_i64(), b.as_i64()) {
+                Ok(a.cmp(&b))
+            } else if let (Some(a), Some(b)) = (a.as_u64(), b.as_u64()) {
+                Ok(a.cmp(&b))
+            } else {
+                Ok(a.as_f64()
+                    .partial_cmp(&b.as_f64())
+                    .unwrap_or(Ordering::Equal))
+            }
+        }
+        _ => Err(PaginationError::new(format!(
+            "High-water mark field has values of different types: {a} and {b}"
+        ))
+        .int_err()),
+    }
+}
+
+/// Finds the target of the `rel="next"` link in the value of `Link` header
+/// (RFC 8288)
+fn parse_link_header_next(value: &str) -> Option<String> {
+    let mut rest = value;
+
+    loop {
+        let start = rest.find('<')?;
+        let end = start + rest[start..].find('>')?;
+        let target = &rest[start + 1..end];
+
+        rest = &rest[end + 1..];
+        let params_end = rest.find('<').unwrap_or(rest.len());
+        let params = &rest[..params_end];
+
+        let is_next = params.split(';').any(|param| {
+            let Some((name, value)) = param.split_once('=') else {
+                return false;
+            };
+            name.trim().eq_ignore_ascii_case("rel")
+                && value
+                    .trim()
+                    .trim_matches('"')
+                    .split_ascii_whitespace()
+                    .any(|rel| rel.eq_ignore_ascii_case("next"))
+        });
+
+        if is_next {
+            return Some(target.to_string());
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[derive(thiserror::Error, Debug)]
+#[error("Paginated HTTP source error: {message}")]
+struct PaginationError {
+    pub message: String,
+}
+
+impl PaginationError {
+    fn new(message: impl Into<String>) -> Self {
+        Self {
+            message: message.into(),
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
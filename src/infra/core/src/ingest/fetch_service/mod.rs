@@ -17,9 +17,14 @@ pub use configs::*;
 mod container;
 #[cfg(feature = "ingest-evm")]
 mod evm;
+#[cfg(feature = "ingest-evm")]
+mod evm_blocks;
 mod file;
 #[cfg(feature = "ingest-ftp")]
 mod ftp;
 mod http;
+mod http_paginated;
 #[cfg(feature = "ingest-mqtt")]
 mod mqtt;
+#[cfg(feature = "ingest-sql")]
+mod sql;
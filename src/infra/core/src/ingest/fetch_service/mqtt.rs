@@ -7,48 +7,50 @@
 // the Business Source License, use of this software will be governed
 // by the Apache License, Version 2.0.
 
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
+use std::io::Write as _;
 use std::path::Path;
-use std::sync::Arc;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
 
-use internal_error::ResultIntoInternal;
+use internal_error::{ErrorIntoInternal, InternalError, ResultIntoInternal};
 use kamu_core::*;
 use kamu_datasets::DatasetEnvVar;
 use opendatafabric::*;
+use rumqttc::{AsyncClient, ConnectionError, Event, EventLoop, MqttOptions, Outgoing, Publish};
+use tokio::sync::mpsc;
 
 use super::*;
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
 
 impl FetchService {
+    // Messages are acknowledged manually and only after the fetched data is
+    // committed. The broker session is persistent and identified by the dataset
+    // ID, so messages with QoS 1 and 2 that were not acknowledged because the
+    // ingest failed are delivered again during the next fetch.
+    //
+    // The event loop of the connection is polled in a background task until the
+    // messages are acknowledged, so that keep-alive pings are sent while the
+    // data is being committed.
     pub(crate) async fn fetch_mqtt(
         &self,
         dataset_handle: &DatasetHandle,
         fetch: &FetchStepMqtt,
+        read_step: Option<&ReadStep>,
         target_path: &Path,
         dataset_env_vars: &HashMap<String, DatasetEnvVar>,
         listener: &Arc<dyn FetchProgressListener>,
     ) -> Result<FetchResult, PollingIngestError> {
-        use std::io::Write as _;
+        use rumqttc::{Packet, QoS};
 
-        use rumqttc::{AsyncClient, Event, MqttOptions, Packet, QoS};
-
-        // TODO: Reconsider assignment of client identity on which QoS session data
-        // rentention is based
-        let client_id = format!("kamu-ingest-{}", dataset_handle.id.as_multibase());
-
-        let mut opts = MqttOptions::new(client_id, &fetch.host, u16::try_from(fetch.port).unwrap());
-        opts.set_clean_session(false);
-
-        // TODO: Reconsider password propagation
-        if let (Some(username), Some(password)) = (&fetch.username, &fetch.password) {
-            let password = self.template_string(password, dataset_env_vars)?;
-            opts.set_credentials(username, password);
-        }
+        let opts = self
+            .mqtt_options(dataset_handle, fetch, dataset_env_vars)
+            .await?;
 
         tracing::debug!("Connecting to the MQTT broker and subscribing to the topic");
 
-        let (client, mut event_loop) = AsyncClient::new(opts, 1000);
+        let (client, event_loop) = AsyncClient::new(opts, 1000);
         client
             .subscribe_many(fetch.topics.clone().into_iter().map(|s| {
                 rumqttc::SubscribeFilter::new(
@@ -63,64 +65,471 @@ impl FetchService {
             .await
             .int_err()?;
 
+        let mut session = MqttSession::start(client, event_loop);
+
+        let format = MqttPayloadFormat::from_read_step(read_step);
+        let mut writer = MqttPayloadWriter::new(&format, target_path)?;
         let mut fetched_bytes = 0;
-        let mut fetched_records = 0;
-        let mut file = std::fs::File::create(target_path).int_err()?;
 
-        let max_records = self.source_config.target_records_per_slice;
-        let poll_timeout =
-            std::time::Duration::from_millis(self.mqtt_source_config.broker_idle_timeout_ms);
+        // Files in binary formats can't be concatenated
+        let max_messages = match format {
+            MqttPayloadFormat::Raw => 1,
+            _ => self.source_config.target_records_per_slice,
+        };
+        let poll_timeout = Duration::from_millis(self.mqtt_source_config.broker_idle_timeout_ms);
 
         loop {
             // Limit number of records read if they keep flowing faster that we timeout
-            // TODO: Manual ACKs to prevent lost records
-            if fetched_records >= max_records {
+            if session.publishes.len() as u64 >= max_messages {
                 break;
             }
 
-            if let Ok(poll) = tokio::time::timeout(poll_timeout, event_loop.poll()).await {
-                match poll.int_err()? {
-                    Event::Incoming(Packet::Publish(publish)) => {
-                        // TODO: Assuming that payload is JSON and formatting it as line-delimited
-                        if fetched_bytes != 0 {
-                            file.write_all(b" ").int_err()?;
-                        }
-                        let json = std::str::from_utf8(&publish.payload).int_err()?.trim();
-                        file.write_all(json.as_bytes()).int_err()?;
-
-                        fetched_bytes += publish.payload.len() as u64 + 1;
-                        fetched_records += 1;
-
-                        listener.on_progress(&FetchProgress {
-                            fetched_bytes,
-                            total_bytes: TotalBytes::Unknown,
-                        });
-                    }
-                    event => tracing::debug!(?event, "Received"),
-                }
-            } else {
+            let Ok(event) = tokio::time::timeout(poll_timeout, session.events.recv()).await else {
                 break;
             };
+
+            match event.transpose().int_err()? {
+                Some(Event::Incoming(Packet::Publish(mut publish))) => {
+                    let payload = std::mem::take(&mut publish.payload);
+                    writer.write(&publish.topic, &payload)?;
+
+                    fetched_bytes += payload.len() as u64;
+                    session.publishes.push(publish);
+
+                    listener.on_progress(&FetchProgress {
+                        fetched_bytes,
+                        total_bytes: TotalBytes::Unknown,
+                    });
+                }
+                Some(event) => tracing::debug!(?event, "Received"),
+                None => break,
+            }
         }
 
+        writer.finish()?;
+
         tracing::debug!(
             fetched_bytes,
-            fetched_records,
-            "Disconnecting from the MQTT broker"
+            fetched_messages = session.publishes.len(),
+            "Finished reading messages from the MQTT broker"
         );
 
-        file.flush().int_err()?;
-
-        if fetched_bytes == 0 {
-            Ok(FetchResult::UpToDate)
-        } else {
-            // TODO: Store last packet ID / client ID in the source state?
-            Ok(FetchResult::Updated(FetchResultUpdated {
-                source_state: None,
-                source_event_time: None,
-                has_more: false,
-                zero_copy_path: None,
-            }))
+        if session.publishes.is_empty() {
+            return Ok(FetchResult::UpToDate);
+        }
+
+        // Broker limits the number of unacknowledged messages in flight, so more
+        // messages may be waiting for these ones to be acknowledged
+        let has_more = session.publishes.len() as u64 >= max_messages
+            || session.publishes.iter().any(|p| p.qos != QoS::AtMostOnce);
+
+        Ok(FetchResult::Updated(FetchResultUpdated {
+            source_state: None,
+            source_event_time: None,
+            has_more,
+            zero_copy_path: None,
+            retraction: None,
+            acknowledgement: Some(FetchAcknowledgement::new(MqttAcknowledger::new(
+                session,
+                Vec::new(),
+                Duration::from_millis(self.mqtt_source_config.ack_timeout_ms),
+            ))),
+        }))
+    }
+
+    // Reconnects to the persistent session, where the broker delivers the messages
+    // that were not acknowledged again, and acknowledges the ones with the packet
+    // IDs of the messages cached in the savepoint
+    pub(super) async fn resume_mqtt_acknowledgement(
+        &self,
+        dataset_handle: &DatasetHandle,
+        fetch: &FetchStepMqtt,
+        packet_ids: &[u16],
+        dataset_env_vars: &HashMap<String, DatasetEnvVar>,
+    ) -> Result<FetchAcknowledgement, PollingIngestError> {
+        let opts = self
+            .mqtt_options(dataset_handle, fetch, dataset_env_vars)
+            .await?;
+
+        let (client, event_loop) = AsyncClient::new(opts, 1000);
+        let session = MqttSession::start(client, event_loop);
+
+        Ok(FetchAcknowledgement::new(MqttAcknowledger::new(
+            session,
+            packet_ids.to_vec(),
+            Duration::from_millis(self.mqtt_source_config.ack_timeout_ms),
+        )))
+    }
+
+    async fn mqtt_options(
+        &self,
+        dataset_handle: &DatasetHandle,
+        fetch: &FetchStepMqtt,
+        dataset_env_vars: &HashMap<String, DatasetEnvVar>,
+    ) -> Result<MqttOptions, PollingIngestError> {
+        let client_id = format!("kamu-ingest-{}", dataset_handle.id.as_multibase());
+
+        let mut opts = MqttOptions::new(client_id, &fetch.host, u16::try_from(fetch.port).unwrap());
+        opts.set_clean_session(false);
+        opts.set_manual_acks(true);
+
+        // TODO: Reconsider password propagation
+        if let (Some(username), Some(password)) = (&fetch.username, &fetch.password) {
+            let password = self.template_string(password, dataset_env_vars).await?;
+            opts.set_credentials(username, password);
+        }
+
+        Ok(opts)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Connection to the broker with the event loop polled in a background task
+struct MqttSession {
+    client: AsyncClient,
+    events: mpsc::UnboundedReceiver<Result<Event, ConnectionError>>,
+    /// Messages received in this session
+    publishes: Vec<Publish>,
+    _poller: AbortOnDrop,
+}
+
+impl MqttSession {
+    fn start(client: AsyncClient, mut event_loop: EventLoop) -> Self {
+        let (tx, events) = mpsc::unbounded_channel();
+
+        // Stops on errors instead of reconnecting, as the packet IDs of
+        // received messages are only valid within the connection
+        let poller = tokio::spawn(async move {
+            loop {
+                let event = event_loop.poll().await;
+                let stop = matches!(event, Err(_) | Ok(Event::Outgoing(Outgoing::Disconnect)));
+                if tx.send(event).is_err() || stop {
+                    break;
+                }
+            }
+        });
+
+        Self {
+            client,
+            events,
+            publishes: Vec::new(),
+            _poller: AbortOnDrop(poller),
+        }
+    }
+}
+
+/// Closes the connection when the session is dropped without acknowledging
+struct AbortOnDrop(tokio::task::JoinHandle<()>);
+
+impl Drop for AbortOnDrop {
+    fn drop(&mut self) {
+        self.0.abort();
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Keeps the broker connection open until the received messages are
+/// acknowledged
+struct MqttAcknowledger {
+    session: Mutex<Option<MqttSession>>,
+    /// Messages with QoS 1 and 2 that are expected to be delivered again
+    /// before they can be acknowledged
+    redelivered: Vec<u16>,
+    timeout: Duration,
+}
+
+impl MqttAcknowledger {
+    fn new(session: MqttSession, redelivered: Vec<u16>, timeout: Duration) -> Self {
+        Self {
+            session: Mutex::new(Some(session)),
+            redelivered,
+            timeout,
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl FetchAcknowledger for MqttAcknowledger {
+    async fn acknowledge(&self) -> Result<(), InternalError> {
+        use rumqttc::{Packet, QoS};
+
+        let Some(MqttSession {
+            client,
+            mut events,
+            publishes,
+            _poller,
+        }) = self.session.lock().unwrap().take()
+        else {
+            return Ok(());
+        };
+
+        // Messages with QoS 0 don't have acknowledgements
+        let mut pending: HashSet<u16> = publishes
+            .iter()
+            .filter(|p| p.qos != QoS::AtMostOnce)
+            .map(|p| p.pkid)
+            .chain(self.redelivered.iter().copied())
+            .collect();
+        let mut redelivered: HashSet<u16> = self.redelivered.iter().copied().collect();
+
+        tracing::debug!(
+            num_messages = pending.len(),
+            "Acknowledging messages received from the MQTT broker"
+        );
+
+        // Messages with QoS 2 are complete only when we respond to the release of
+        // the message that the broker sends after our ack, so we disconnect only
+        // after that
+        let acknowledge = async {
+            for publish in &publishes {
+                client.ack(publish).await.int_err()?;
+            }
+
+            let mut disconnecting = false;
+            loop {
+                if pending.is_empty() && !disconnecting {
+                    client.disconnect().await.int_err()?;
+                    disconnecting = true;
+                }
+
+                let Some(event) = events.recv().await else {
+                    return Err(
+                        "MQTT connection closed before messages were acknowledged".int_err()
+                    );
+                };
+
+                match event.int_err()? {
+                    // Packet IDs may be reused for new messages once the previous ones are
+                    // acknowledged, so only the redeliveries are matched
+                    Event::Incoming(Packet::Publish(publish))
+                        if publish.dup && redelivered.remove(&publish.pkid) =>
+                    {
+                        client.ack(&publish).await.int_err()?;
+                    }
+                    Event::Outgoing(Outgoing::PubAck(pkid) | Outgoing::PubComp(pkid)) => {
+                        pending.remove(&pkid);
+                    }
+                    Event::Outgoing(Outgoing::Disconnect) => return Ok(()),
+                    event => tracing::debug!(?event, "Received"),
+                }
+            }
+        };
+
+        tokio::time::timeout(self.timeout, acknowledge)
+            .await
+            .int_err()??;
+
+        Ok(())
+    }
+
+    fn state(&self) -> FetchAcknowledgementState {
+        use rumqttc::QoS;
+
+        let session = self.session.lock().unwrap();
+
+        let packet_ids = session
+            .iter()
+            .flat_map(|s| &s.publishes)
+            .filter(|p| p.qos != QoS::AtMostOnce)
+            .map(|p| p.pkid)
+            .chain(self.redelivered.iter().copied())
+            .collect();
+
+        FetchAcknowledgementState::Mqtt { packet_ids }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Defines how message payloads are combined into the fetched file depending
+/// on the format that the read step expects
+enum MqttPayloadFormat<'a> {
+    /// Every payload is a JSON document that is written as a separate line
+    NdJson,
+    /// Records found in every payload are combined into a single JSON document
+    Json { sub_path: Option<&'a str> },
+    /// Features found in every payload are combined into a single feature
+    /// collection
+    GeoJson,
+    /// Every payload contains one or more CSV rows, preceded by the header row
+    /// if the read step expects it
+    Csv { header: bool },
+    /// Every payload is a complete file in a binary format
+    Raw,
+}
+
+impl<'a> MqttPayloadFormat<'a> {
+    fn from_read_step(read_step: Option<&'a ReadStep>) -> Self {
+        match read_step {
+            None | Some(ReadStep::NdJson(_) | ReadStep::NdGeoJson(_)) => Self::NdJson,
+            Some(ReadStep::Json(read)) => Self::Json {
+                sub_path: read.sub_path.as_deref(),
+            },
+            Some(ReadStep::GeoJson(_)) => Self::GeoJson,
+            Some(ReadStep::Csv(read)) => Self::Csv {
+                header: read.header.unwrap_or(false),
+            },
+            Some(ReadStep::Parquet(_) | ReadStep::EsriShapefile(_)) => Self::Raw,
+        }
+    }
+}
+
+struct MqttPayloadWriter<'a> {
+    format: &'a MqttPayloadFormat<'a>,
+    file: std::io::BufWriter<std::fs::File>,
+    records: Vec<serde_json::Value>,
+    csv_header_written: bool,
+}
+
+impl<'a> MqttPayloadWriter<'a> {
+    fn new(format: &'a MqttPayloadFormat<'a>, target_path: &Path) -> Result<Self, InternalError> {
+        Ok(Self {
+            format,
+            file: std::io::BufWriter::new(std::fs::File::create(target_path).int_err()?),
+            records: Vec::new(),
+            csv_header_written: false,
+        })
+    }
+
+    // Payloads that don't match the expected format are skipped, as they would
+    // otherwise be redelivered and fail the ingest forever
+    fn write(&mut self, topic: &str, payload: &[u8]) -> Result<(), InternalError> {
+        use serde_json::Value;
+
+        let parse_json = || match serde_json::from_slice::<Value>(payload) {
+            Ok(value) => Some(value),
+            Err(err) => {
+                tracing::warn!(topic, error = %err, "Skipping message that is not a valid JSON");
+                None
+            }
+        };
+
+        // Avro payloads are decoded into JSON records when the read step expects JSON
+        if payload.starts_with(AVRO_MAGIC)
+            && let MqttPayloadFormat::NdJson | MqttPayloadFormat::Json { .. } = self.format
+        {
+            let Some(records) = decode_avro(topic, payload) else {
+                return Ok(());
+            };
+
+            if let MqttPayloadFormat::NdJson = self.format {
+                for record in records {
+                    serde_json::to_writer(&mut self.file, &record).int_err()?;
+                    self.file.write_all(b"\n").int_err()?;
+                }
+            } else {
+                self.records.extend(records);
+            }
+
+            return Ok(());
+        }
+
+        match self.format {
+            MqttPayloadFormat::NdJson => {
+                if let Some(value) = parse_json() {
+                    serde_json::to_writer(&mut self.file, &value).int_err()?;
+                    self.file.write_all(b"\n").int_err()?;
+                }
+            }
+            MqttPayloadFormat::Json { sub_path } => {
+                let records = parse_json().and_then(|value| {
+                    sub_path
+                        .into_iter()
+                        .flat_map(|p| p.split('.'))
+                        .try_fold(value, |mut v, key| v.get_mut(key).map(Value::take))
+                });
+                match records {
+                    Some(Value::Array(records)) => self.records.extend(records),
+                    Some(record @ Value::Object(_)) => self.records.push(record),
+                    _ => tracing::warn!(topic, "Skipping message that does not contain records"),
+                }
+            }
+            MqttPayloadFormat::GeoJson => match parse_json() {
+                Some(mut value) if value["type"] == "FeatureCollection" => {
+                    if let Value::Array(features) = value["features"].take() {
+                        self.records.extend(features);
+                    }
+                }
+                Some(value) if value["type"] == "Feature" => self.records.push(value),
+                _ => tracing::warn!(topic, "Skipping message that does not contain features"),
+            },
+            MqttPayloadFormat::Csv { header } => {
+                // Only the header row of the first payload is kept
+                let rows = if *header && self.csv_header_written {
+                    payload
+                        .iter()
+                        .position(|b| *b == b'\n')
+                        .map_or(&[][..], |i| &payload[i + 1..])
+                } else {
+                    payload
+                };
+                if *header && !payload.is_empty() {
+                    self.csv_header_written = true;
+                }
+
+                self.file.write_all(rows).int_err()?;
+                if !rows.is_empty() && !rows.ends_with(b"\n") {
+                    self.file.write_all(b"\n").int_err()?;
+                }
+            }
+            MqttPayloadFormat::Raw => {
+                self.file.write_all(payload).int_err()?;
+            }
+        }
+
+        Ok(())
+    }
+
+    fn finish(mut self) -> Result<(), InternalError> {
+        use serde_json::{json, Value};
+
+        let records = Value::Array(self.records);
+
+        let document = match self.format {
+            MqttPayloadFormat::Json { sub_path } => Some(
+                sub_path
+                    .into_iter()
+                    .flat_map(|p| p.rsplit('.'))
+                    .fold(records, |v, key| json!({ key: v })),
+            ),
+            MqttPayloadFormat::GeoJson => Some(json!({
+                "type": "FeatureCollection",
+                "features": records,
+            })),
+            MqttPayloadFormat::NdJson | MqttPayloadFormat::Csv { .. } | MqttPayloadFormat::Raw => {
+                None
+            }
+        };
+
+        if let Some(document) = document {
+            serde_json::to_writer(&mut self.file, &document).int_err()?;
+        }
+
+        self.file.flush().int_err()?;
+        Ok(())
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Avro payloads are expected to be object container files, which embed the
+/// schema of the records
+const AVRO_MAGIC: &[u8] = b"Obj\x01";
+
+fn decode_avro(topic: &str, payload: &[u8]) -> Option<Vec<serde_json::Value>> {
+    let records = apache_avro::Reader::new(payload).and_then(|reader| {
+        reader
+            .map(|value| value.and_then(serde_json::Value::try_from))
+            .collect::<Result<Vec<_>, _>>()
+    });
+
+    match records {
+        Ok(records) => Some(records),
+        Err(err) => {
+            tracing::warn!(topic, error = %err, "Skipping message that is not a valid Avro file");
+            None
         }
     }
 }
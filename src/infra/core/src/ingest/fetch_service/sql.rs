@@ -0,0 +1,724 @@
+// Copyright Kamu Data, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+use std::collections::HashMap;
+use std::path::Path;
+use std::sync::Arc;
+
+use chrono::{DateTime, NaiveDate, NaiveDateTime, Utc};
+use datafusion::arrow::array::*;
+use datafusion::arrow::datatypes::{DataType, Date32Type, Field, Schema, TimeUnit};
+use futures::{Stream, StreamExt};
+use internal_error::{ErrorIntoInternal, InternalError, ResultIntoInternal};
+use kamu_core::*;
+use kamu_datasets::DatasetEnvVar;
+use opendatafabric::*;
+use sqlx::types::Decimal;
+use sqlx::{Column, Connection, Row, TypeInfo};
+
+use super::*;
+use crate::PollingSourceState;
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+const SQL_BATCH_SIZE: usize = 10_000;
+
+// Numeric columns are read with a fixed precision and scale, as the precision
+// of the values is not known from the result metadata
+const DECIMAL_PRECISION: u8 = 38;
+const DECIMAL_SCALE: i8 = 18;
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+impl FetchService {
+    // Query results are streamed from the database row by row and transcoded
+    // into Arrow batches that are written into a Parquet file.
+    //
+    // When the cursor column is specified the query is wrapped to only return
+    // the rows with cursor value greater than the one stored in the source
+    // state, ordered by the cursor and limited to about the target number of
+    // records per slice. The cursor does not have to be unique: all rows that
+    // share the value of the last row in a slice are fetched along with it.
+    pub(crate) async fn fetch_sql(
+        &self,
+        fetch: &FetchStepSql,
+        prev_source_state: Option<&PollingSourceState>,
+        target_path: &Path,
+        dataset_env_vars: &HashMap<String, DatasetEnvVar>,
+        listener: &Arc<dyn FetchProgressListener>,
+    ) -> Result<FetchResult, PollingIngestError> {
+        let prev_cursor = match prev_source_state {
+            None => None,
+            Some(PollingSourceState::ETag(cursor)) => Some(cursor.as_str()),
+            Some(state) => Err(SqlSourceError::new(format!(
+                "Sql source expects ETag state but got: {state:?}"
+            ))
+            .int_err())?,
+        };
+
+        if fetch.cursor_column.is_none() && prev_cursor.is_some() {
+            Err(SqlSourceError::new(
+                "Sql source has a stored cursor but does not specify the cursor column",
+            )
+            .int_err())?;
+        }
+
+        let url = self.template_url(&fetch.url, dataset_env_vars).await?;
+
+        let limit = self.source_config.target_records_per_slice;
+
+        let (num_rows, last_cursor) = match url.scheme() {
+            "postgres" | "postgresql" => {
+                let mut conn = sqlx::PgConnection::connect(url.as_str()).await.int_err()?;
+
+                // The stored cursor is bound as a string, so it has to be cast to the type of
+                // the column explicitly
+                let cursor_type = match (&fetch.cursor_column, prev_cursor) {
+                    (Some(cursor_column), Some(_)) => {
+                        Some(describe_column_type(&mut conn, &fetch.query, cursor_column).await?)
+                    }
+                    _ => None,
+                };
+
+                let query = build_query::<PostgresDialect>(
+                    fetch,
+                    prev_cursor,
+                    cursor_type.as_deref(),
+                    limit,
+                );
+                tracing::info!(host = ?url.host_str(), query, "Querying Postgres database");
+
+                let rows = bind_cursor(sqlx::query(&query), prev_cursor).fetch(&mut conn);
+
+                write_rows::<PostgresDialect>(rows, fetch, target_path, listener).await?
+            }
+            "mysql" | "mariadb" => {
+                let mut url = url;
+                url.set_scheme("mysql").unwrap();
+
+                // MySQL converts the string parameter to the type of the column on its own
+                let query = build_query::<MySqlDialect>(fetch, prev_cursor, None, limit);
+                tracing::info!(host = ?url.host_str(), query, "Querying MySQL database");
+
+                let mut conn = sqlx::MySqlConnection::connect(url.as_str())
+                    .await
+                    .int_err()?;
+                let rows = bind_cursor(sqlx::query(&query), prev_cursor).fetch(&mut conn);
+
+                write_rows::<MySqlDialect>(rows, fetch, target_path, listener).await?
+            }
+            scheme => Err(SqlSourceError::new(format!(
+                "Unsupported database URL scheme: {scheme}"
+            ))
+            .int_err())?,
+        };
+
+        tracing::info!(num_rows, ?last_cursor, "Finished reading query results");
+
+        if fetch.cursor_column.is_none() {
+            return Ok(FetchResult::Updated(FetchResultUpdated {
+                source_state: None,
+                source_event_time: None,
+                has_more: false,
+                zero_copy_path: None,
+                retraction: None,
+                acknowledgement: None,
+            }));
+        }
+
+        let Some(last_cursor) = last_cursor else {
+            return Ok(FetchResult::UpToDate);
+        };
+
+        Ok(FetchResult::Updated(FetchResultUpdated {
+            source_state: Some(PollingSourceState::ETag(last_cursor)),
+            source_event_time: None,
+            has_more: num_rows as u64 >= limit,
+            zero_copy_path: None,
+            retraction: None,
+            acknowledgement: None,
+        }))
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Wraps the query to fetch the next slice of rows after the previous cursor.
+/// The slice ends at the cursor value of the row at the target position and
+/// includes all rows with this value, so that rows sharing the value of the
+/// cursor are never split between the slices.
+///
+/// The previous cursor is referenced by two parameters, see [`bind_cursor`].
+fn build_query<D: SqlDialect>(
+    fetch: &FetchStepSql,
+    prev_cursor: Option<&str>,
+    cursor_type: Option<&str>,
+    limit: u64,
+) -> String {
+    let query = fetch.query.trim().trim_end_matches(';');
+
+    let Some(cursor_column) = &fetch.cursor_column else {
+        return query.to_string();
+    };
+
+    let cursor_column = D::quote_identifier(cursor_column);
+
+    let after_cursor = |index| {
+        if prev_cursor.is_some() {
+            format!(
+                "{cursor_column} > {}",
+                D::cursor_placeholder(index, cursor_type)
+            )
+        } else {
+            "TRUE".to_string()
+        }
+    };
+
+    let slice_end = format!(
+        "SELECT {cursor_column} FROM ({query}) AS page WHERE {} ORDER BY {cursor_column} LIMIT 1 \
+         OFFSET {}",
+        after_cursor(2),
+        limit.saturating_sub(1),
+    );
+
+    format!(
+        "SELECT * FROM ({query}) AS src WHERE {} AND COALESCE({cursor_column} <= ({slice_end}), \
+         TRUE) ORDER BY {cursor_column}",
+        after_cursor(1),
+    )
+}
+
+/// Binds the previous cursor as a string to the parameters of the query
+/// produced by [`build_query`]
+fn bind_cursor<'q, DB>(
+    query: sqlx::query::Query<'q, DB, <DB as sqlx::Database>::Arguments<'q>>,
+    prev_cursor: Option<&'q str>,
+) -> sqlx::query::Query<'q, DB, <DB as sqlx::Database>::Arguments<'q>>
+where
+    DB: sqlx::Database,
+    &'q str: sqlx::Encode<'q, DB> + sqlx::Type<DB>,
+{
+    match prev_cursor {
+        Some(prev_cursor) => query.bind(prev_cursor).bind(prev_cursor),
+        None => query,
+    }
+}
+
+/// Determines the database type of the column in the query results without
+/// running the query
+async fn describe_column_type(
+    conn: &mut sqlx::PgConnection,
+    query: &str,
+    column_name: &str,
+) -> Result<String, InternalError> {
+    use sqlx::Executor;
+
+    let query = query.trim().trim_end_matches(';');
+    let describe = conn.describe(query).await.int_err()?;
+
+    describe
+        .columns()
+        .iter()
+        .find(|c| c.name() == column_name)
+        .map(|c| c.type_info().name().to_string())
+        .ok_or_else(|| {
+            SqlSourceError::new(format!(
+                "Query results do not contain the cursor column {column_name}"
+            ))
+            .int_err()
+        })
+}
+
+/// Transcodes the rows into a Parquet file, returning the number of rows and
+/// the cursor column value of the last row
+async fn write_rows<D: SqlDialect>(
+    rows: impl Stream<Item = Result<D::Row, sqlx::Error>>,
+    fetch: &FetchStepSql,
+    target_path: &Path,
+    listener: &Arc<dyn FetchProgressListener>,
+) -> Result<(usize, Option<String>), InternalError> {
+    futures::pin_mut!(rows);
+
+    let mut num_rows = 0;
+    let mut last_cursor = None;
+    let mut cursor_index = None;
+    let mut builders = Vec::new();
+    let mut schema = None;
+    let mut writer = None;
+
+    while let Some(row) = rows.next().await {
+        let row = row.int_err()?;
+
+        // Infer the schema from the first row
+        if schema.is_none() {
+            let mut fields = Vec::new();
+            for column in row.columns() {
+                let builder = D::column_builder(column.type_info().name()).ok_or_else(|| {
+                    SqlSourceError::new(format!(
+                        "Column {} has unsupported type {}, consider casting it in the query",
+                        column.name(),
+                        column.type_info().name()
+                    ))
+                    .int_err()
+                })?;
+                fields.push(Field::new(column.name(), builder.data_type(), true));
+                builders.push(builder);
+            }
+
+            if let Some(cursor_column) = &fetch.cursor_column {
+                cursor_index = Some(
+                    row.columns()
+                        .iter()
+                        .position(|c| c.name() == cursor_column)
+                        .ok_or_else(|| {
+                            SqlSourceError::new(format!(
+                                "Query results do not contain the cursor column {cursor_column}"
+                            ))
+                            .int_err()
+                        })?,
+                );
+            }
+
+            let s = Arc::new(Schema::new(fields));
+            writer = Some(
+                datafusion::parquet::arrow::ArrowWriter::try_new(
+                    std::fs::File::create_new(target_path).int_err()?,
+                    s.clone(),
+                    None,
+                )
+                .int_err()?,
+            );
+            schema = Some(s);
+        }
+
+        for (index, builder) in builders.iter_mut().enumerate() {
+            D::append_value(builder, &row, index).int_err()?;
+        }
+
+        if let Some(cursor_index) = cursor_index {
+            last_cursor = Some(
+                D::value_literal(&builders[cursor_index], &row, cursor_index)
+                    .int_err()?
+                    .ok_or_else(|| {
+                        SqlSourceError::new("Cursor column contains null values").int_err()
+                    })?,
+            );
+        }
+
+        num_rows += 1;
+
+        if num_rows % SQL_BATCH_SIZE == 0 {
+            let batch = finish_batch(schema.as_ref().unwrap(), &mut builders)?;
+            writer.as_mut().unwrap().write(&batch).int_err()?;
+
+            listener.on_progress(&FetchProgress {
+                fetched_bytes: num_rows as u64,
+                total_bytes: TotalBytes::Unknown,
+            });
+        }
+    }
+
+    if let Some(mut writer) = writer {
+        if num_rows % SQL_BATCH_SIZE != 0 {
+            let batch = finish_batch(schema.as_ref().unwrap(), &mut builders)?;
+            writer.write(&batch).int_err()?;
+        }
+        writer.close().int_err()?;
+    }
+
+    Ok((num_rows, last_cursor))
+}
+
+fn finish_batch(
+    schema: &Arc<Schema>,
+    builders: &mut [ColumnBuilder],
+) -> Result<RecordBatch, InternalError> {
+    RecordBatch::try_new(
+        schema.clone(),
+        builders.iter_mut().map(ColumnBuilder::finish).collect(),
+    )
+    .int_err()
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Arrow array builder matching the type of a result column
+enum ColumnBuilder {
+    Boolean(BooleanBuilder),
+    Int16(Int16Builder),
+    Int32(Int32Builder),
+    Int64(Int64Builder),
+    UInt64(UInt64Builder),
+    Float32(Float32Builder),
+    Float64(Float64Builder),
+    Decimal(Decimal128Builder),
+    Utf8(StringBuilder),
+    Binary(BinaryBuilder),
+    Date32(Date32Builder),
+    Timestamp(TimestampMicrosecondBuilder),
+    TimestampUtc(TimestampMicrosecondBuilder),
+}
+
+impl ColumnBuilder {
+    fn decimal() -> Self {
+        Self::Decimal(
+            Decimal128Builder::new()
+                .with_precision_and_scale(DECIMAL_PRECISION, DECIMAL_SCALE)
+                .unwrap(),
+        )
+    }
+
+    fn timestamp_utc() -> Self {
+        Self::TimestampUtc(TimestampMicrosecondBuilder::new().with_timezone("UTC"))
+    }
+
+    fn data_type(&self) -> DataType {
+        match self {
+            Self::Boolean(_) => DataType::Boolean,
+            Self::Int16(_) => DataType::Int16,
+            Self::Int32(_) => DataType::Int32,
+            Self::Int64(_) => DataType::Int64,
+            Self::UInt64(_) => DataType::UInt64,
+            Self::Float32(_) => DataType::Float32,
+            Self::Float64(_) => DataType::Float64,
+            Self::Decimal(_) => DataType::Decimal128(DECIMAL_PRECISION, DECIMAL_SCALE),
+            Self::Utf8(_) => DataType::Utf8,
+            Self::Binary(_) => DataType::Binary,
+            Self::Date32(_) => DataType::Date32,
+            Self::Timestamp(_) => DataType::Timestamp(TimeUnit::Microsecond, None),
+            Self::TimestampUtc(_) => DataType::Timestamp(TimeUnit::Microsecond, Some("UTC".into())),
+        }
+    }
+
+    fn finish(&mut self) -> ArrayRef {
+        match self {
+            Self::Boolean(b) => Arc::new(b.finish()),
+            Self::Int16(b) => Arc::new(b.finish()),
+            Self::Int32(b) => Arc::new(b.finish()),
+            Self::Int64(b) => Arc::new(b.finish()),
+            Self::UInt64(b) => Arc::new(b.finish()),
+            Self::Float32(b) => Arc::new(b.finish()),
+            Self::Float64(b) => Arc::new(b.finish()),
+            Self::Decimal(b) => Arc::new(b.finish()),
+            Self::Utf8(b) => Arc::new(b.finish()),
+            Self::Binary(b) => Arc::new(b.finish()),
+            Self::Date32(b) => Arc::new(b.finish()),
+            Self::Timestamp(b) | Self::TimestampUtc(b) => Arc::new(b.finish()),
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+trait SqlDialect {
+    type Row: Row;
+
+    fn quote_identifier(name: &str) -> String;
+
+    /// Returns the placeholder of a query parameter with specified index,
+    /// converted to the database type of the cursor column if it is known
+    fn cursor_placeholder(index: usize, type_name: Option<&str>) -> String;
+
+    /// Returns the builder for a column of specified database type or `None`
+    /// if the type is not supported
+    fn column_builder(type_name: &str) -> Option<ColumnBuilder>;
+
+    fn append_value(
+        builder: &mut ColumnBuilder,
+        row: &Self::Row,
+        index: usize,
+    ) -> Result<(), sqlx::Error>;
+
+    /// Returns the value as a string that can be bound as a parameter and
+    /// compared against the column in a query
+    fn value_literal(
+        builder: &ColumnBuilder,
+        row: &Self::Row,
+        index: usize,
+    ) -> Result<Option<String>, sqlx::Error>;
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+struct PostgresDialect;
+
+impl SqlDialect for PostgresDialect {
+    type Row = sqlx::postgres::PgRow;
+
+    fn quote_identifier(name: &str) -> String {
+        format!("\"{}\"", name.replace('"', "\"\""))
+    }
+
+    fn cursor_placeholder(index: usize, type_name: Option<&str>) -> String {
+        match type_name {
+            Some(type_name) => format!("CAST(${index} AS {type_name})"),
+            None => format!("${index}"),
+        }
+    }
+
+    fn column_builder(type_name: &str) -> Option<ColumnBuilder> {
+        match type_name {
+            "BOOL" => Some(ColumnBuilder::Boolean(BooleanBuilder::new())),
+            "INT2" => Some(ColumnBuilder::Int16(Int16Builder::new())),
+            "INT4" => Some(ColumnBuilder::Int32(Int32Builder::new())),
+            "INT8" => Some(ColumnBuilder::Int64(Int64Builder::new())),
+            "FLOAT4" => Some(ColumnBuilder::Float32(Float32Builder::new())),
+            "FLOAT8" => Some(ColumnBuilder::Float64(Float64Builder::new())),
+            "NUMERIC" => Some(ColumnBuilder::decimal()),
+            "TEXT" | "VARCHAR" | "BPCHAR" | "NAME" => {
+                Some(ColumnBuilder::Utf8(StringBuilder::new()))
+            }
+            "BYTEA" => Some(ColumnBuilder::Binary(BinaryBuilder::new())),
+            "DATE" => Some(ColumnBuilder::Date32(Date32Builder::new())),
+            "TIMESTAMP" => Some(ColumnBuilder::Timestamp(TimestampMicrosecondBuilder::new())),
+            "TIMESTAMPTZ" => Some(ColumnBuilder::timestamp_utc()),
+            _ => None,
+        }
+    }
+
+    fn append_value(
+        builder: &mut ColumnBuilder,
+        row: &Self::Row,
+        index: usize,
+    ) -> Result<(), sqlx::Error> {
+        append_value_common(builder, row, index)
+    }
+
+    fn value_literal(
+        builder: &ColumnBuilder,
+        row: &Self::Row,
+        index: usize,
+    ) -> Result<Option<String>, sqlx::Error> {
+        value_literal_common(builder, row, index, "+00")
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+struct MySqlDialect;
+
+impl SqlDialect for MySqlDialect {
+    type Row = sqlx::mysql::MySqlRow;
+
+    fn quote_identifier(name: &str) -> String {
+        format!("`{}`", name.replace('`', "``"))
+    }
+
+    fn cursor_placeholder(_index: usize, _type_name: Option<&str>) -> String {
+        "?".to_string()
+    }
+
+    fn column_builder(type_name: &str) -> Option<ColumnBuilder> {
+        match type_name {
+            "BOOLEAN" => Some(ColumnBuilder::Boolean(BooleanBuilder::new())),
+            "TINYINT" | "SMALLINT" | "MEDIUMINT" | "INT" | "BIGINT" => {
+                Some(ColumnBuilder::Int64(Int64Builder::new()))
+            }
+            "TINYINT UNSIGNED" | "SMALLINT UNSIGNED" | "MEDIUMINT UNSIGNED" | "INT UNSIGNED"
+            | "BIGINT UNSIGNED" => Some(ColumnBuilder::UInt64(UInt64Builder::new())),
+            "FLOAT" => Some(ColumnBuilder::Float32(Float32Builder::new())),
+            "DOUBLE" => Some(ColumnBuilder::Float64(Float64Builder::new())),
+            "DECIMAL" => Some(ColumnBuilder::decimal()),
+            "CHAR" | "VARCHAR" | "TINYTEXT" | "TEXT" | "MEDIUMTEXT" | "LONGTEXT" | "ENUM" => {
+                Some(ColumnBuilder::Utf8(StringBuilder::new()))
+            }
+            "BINARY" | "VARBINARY" | "TINYBLOB" | "BLOB" | "MEDIUMBLOB" | "LONGBLOB" => {
+                Some(ColumnBuilder::Binary(BinaryBuilder::new()))
+            }
+            "DATE" => Some(ColumnBuilder::Date32(Date32Builder::new())),
+            "DATETIME" => Some(ColumnBuilder::Timestamp(TimestampMicrosecondBuilder::new())),
+            "TIMESTAMP" => Some(ColumnBuilder::timestamp_utc()),
+            _ => None,
+        }
+    }
+
+    fn append_value(
+        builder: &mut ColumnBuilder,
+        row: &Self::Row,
+        index: usize,
+    ) -> Result<(), sqlx::Error> {
+        if let ColumnBuilder::UInt64(b) = builder {
+            b.append_option(row.try_get::<Option<u64>, _>(index)?);
+            Ok(())
+        } else {
+            append_value_common(builder, row, index)
+        }
+    }
+
+    // Note: sqlx sets the session time zone to UTC, so timestamps are compared
+    // without an offset
+    fn value_literal(
+        builder: &ColumnBuilder,
+        row: &Self::Row,
+        index: usize,
+    ) -> Result<Option<String>, sqlx::Error> {
+        if let ColumnBuilder::UInt64(_) = builder {
+            Ok(row.try_get::<Option<u64>, _>(index)?.map(|v| v.to_string()))
+        } else {
+            value_literal_common(builder, row, index, "")
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+fn append_value_common<'r, R>(
+    builder: &mut ColumnBuilder,
+    row: &'r R,
+    index: usize,
+) -> Result<(), sqlx::Error>
+where
+    R: Row,
+    usize: sqlx::ColumnIndex<R>,
+    bool: sqlx::Decode<'r, R::Database> + sqlx::Type<R::Database>,
+    i16: sqlx::Decode<'r, R::Database> + sqlx::Type<R::Database>,
+    i32: sqlx::Decode<'r, R::Database> + sqlx::Type<R::Database>,
+    i64: sqlx::Decode<'r, R::Database> + sqlx::Type<R::Database>,
+    f32: sqlx::Decode<'r, R::Database> + sqlx::Type<R::Database>,
+    f64: sqlx::Decode<'r, R::Database> + sqlx::Type<R::Database>,
+    Decimal: sqlx::Decode<'r, R::Database> + sqlx::Type<R::Database>,
+    String: sqlx::Decode<'r, R::Database> + sqlx::Type<R::Database>,
+    Vec<u8>: sqlx::Decode<'r, R::Database> + sqlx::Type<R::Database>,
+    NaiveDate: sqlx::Decode<'r, R::Database> + sqlx::Type<R::Database>,
+    NaiveDateTime: sqlx::Decode<'r, R::Database> + sqlx::Type<R::Database>,
+    DateTime<Utc>: sqlx::Decode<'r, R::Database> + sqlx::Type<R::Database>,
+{
+    match builder {
+        ColumnBuilder::Boolean(b) => b.append_option(row.try_get::<Option<bool>, _>(index)?),
+        ColumnBuilder::Int16(b) => b.append_option(row.try_get::<Option<i16>, _>(index)?),
+        ColumnBuilder::Int32(b) => b.append_option(row.try_get::<Option<i32>, _>(index)?),
+        ColumnBuilder::Int64(b) => b.append_option(row.try_get::<Option<i64>, _>(index)?),
+        ColumnBuilder::Float32(b) => b.append_option(row.try_get::<Option<f32>, _>(index)?),
+        ColumnBuilder::Float64(b) => b.append_option(row.try_get::<Option<f64>, _>(index)?),
+        ColumnBuilder::Decimal(b) => b.append_option(
+            row.try_get::<Option<Decimal>, _>(index)?
+                .map(decimal_to_i128)
+                .transpose()?,
+        ),
+        ColumnBuilder::Utf8(b) => b.append_option(row.try_get::<Option<String>, _>(index)?),
+        ColumnBuilder::Binary(b) => b.append_option(row.try_get::<Option<Vec<u8>>, _>(index)?),
+        ColumnBuilder::Date32(b) => b.append_option(
+            row.try_get::<Option<NaiveDate>, _>(index)?
+                .map(Date32Type::from_naive_date),
+        ),
+        ColumnBuilder::Timestamp(b) => b.append_option(
+            row.try_get::<Option<NaiveDateTime>, _>(index)?
+                .map(|v| v.and_utc().timestamp_micros()),
+        ),
+        ColumnBuilder::TimestampUtc(b) => b.append_option(
+            row.try_get::<Option<DateTime<Utc>>, _>(index)?
+                .map(|v| v.timestamp_micros()),
+        ),
+        ColumnBuilder::UInt64(_) => unreachable!(),
+    }
+    Ok(())
+}
+
+fn value_literal_common<'r, R>(
+    builder: &ColumnBuilder,
+    row: &'r R,
+    index: usize,
+    utc_offset_suffix: &str,
+) -> Result<Option<String>, sqlx::Error>
+where
+    R: Row,
+    usize: sqlx::ColumnIndex<R>,
+    bool: sqlx::Decode<'r, R::Database> + sqlx::Type<R::Database>,
+    i16: sqlx::Decode<'r, R::Database> + sqlx::Type<R::Database>,
+    i32: sqlx::Decode<'r, R::Database> + sqlx::Type<R::Database>,
+    i64: sqlx::Decode<'r, R::Database> + sqlx::Type<R::Database>,
+    f32: sqlx::Decode<'r, R::Database> + sqlx::Type<R::Database>,
+    f64: sqlx::Decode<'r, R::Database> + sqlx::Type<R::Database>,
+    Decimal: sqlx::Decode<'r, R::Database> + sqlx::Type<R::Database>,
+    String: sqlx::Decode<'r, R::Database> + sqlx::Type<R::Database>,
+    NaiveDate: sqlx::Decode<'r, R::Database> + sqlx::Type<R::Database>,
+    NaiveDateTime: sqlx::Decode<'r, R::Database> + sqlx::Type<R::Database>,
+    DateTime<Utc>: sqlx::Decode<'r, R::Database> + sqlx::Type<R::Database>,
+{
+    const TIMESTAMP_FORMAT: &str = "%Y-%m-%d %H:%M:%S%.6f";
+
+    let literal = match builder {
+        ColumnBuilder::Boolean(_) => row
+            .try_get::<Option<bool>, _>(index)?
+            .map(|v| v.to_string()),
+        ColumnBuilder::Int16(_) => row.try_get::<Option<i16>, _>(index)?.map(|v| v.to_string()),
+        ColumnBuilder::Int32(_) => row.try_get::<Option<i32>, _>(index)?.map(|v| v.to_string()),
+        ColumnBuilder::Int64(_) => row.try_get::<Option<i64>, _>(index)?.map(|v| v.to_string()),
+        ColumnBuilder::Float32(_) => row.try_get::<Option<f32>, _>(index)?.map(|v| v.to_string()),
+        ColumnBuilder::Float64(_) => row.try_get::<Option<f64>, _>(index)?.map(|v| v.to_string()),
+        ColumnBuilder::Decimal(_) => row
+            .try_get::<Option<Decimal>, _>(index)?
+            .map(|v| v.to_string()),
+        ColumnBuilder::Utf8(_) => row.try_get::<Option<String>, _>(index)?,
+        ColumnBuilder::Date32(_) => row
+            .try_get::<Option<NaiveDate>, _>(index)?
+            .map(|v| v.format("%Y-%m-%d").to_string()),
+        ColumnBuilder::Timestamp(_) => row
+            .try_get::<Option<NaiveDateTime>, _>(index)?
+            .map(|v| v.format(TIMESTAMP_FORMAT).to_string()),
+        ColumnBuilder::TimestampUtc(_) => row
+            .try_get::<Option<DateTime<Utc>>, _>(index)?
+            .map(|v| format!("{}{utc_offset_suffix}", v.format(TIMESTAMP_FORMAT))),
+        ColumnBuilder::Binary(_) | ColumnBuilder::UInt64(_) => {
+            return Err(sqlx::Error::Decode(
+                "Column type is not supported as a cursor".into(),
+            ))
+        }
+    };
+
+    Ok(literal)
+}
+
+/// Converts the value into the integer representation of a decimal with
+/// [`DECIMAL_SCALE`]
+fn decimal_to_i128(value: Decimal) -> Result<i128, sqlx::Error> {
+    let out_of_range = || {
+        sqlx::Error::Decode(
+            format!(
+                "Decimal value {value} does not fit into precision {DECIMAL_PRECISION} and scale \
+                 {DECIMAL_SCALE}, consider casting it in the query"
+            )
+            .into(),
+        )
+    };
+
+    let scale = u32::try_from(DECIMAL_SCALE).unwrap();
+    if value.scale() > scale {
+        return Err(out_of_range());
+    }
+
+    let v = value
+        .mantissa()
+        .checked_mul(10i128.pow(scale - value.scale()))
+        .ok_or_else(out_of_range)?;
+
+    if v.unsigned_abs() >= 10u128.pow(u32::from(DECIMAL_PRECISION)) {
+        return Err(out_of_range());
+    }
+
+    Ok(v)
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[derive(thiserror::Error, Debug)]
+#[error("Sql source error: {message}")]
+struct SqlSourceError {
+    pub message: String,
+}
+
+impl SqlSourceError {
+    fn new(message: impl Into<String>) -> Self {
+        Self {
+            message: message.into(),
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
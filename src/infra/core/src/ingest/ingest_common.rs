@@ -14,6 +14,7 @@ use datafusion::prelude::*;
 use internal_error::*;
 use kamu_core::engine::*;
 use kamu_core::{ObjectStoreRegistry, *};
+use messaging_outbox::{Outbox, OutboxExt};
 use opendatafabric::*;
 
 use crate::engine::*;
@@ -174,6 +175,37 @@ pub fn preprocess_default(
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
 
+/// Notifies consumers about the data appended by an ingest iteration. Writes
+/// that only updated the schema are not reported.
+pub(crate) async fn post_data_updated_message(
+    outbox: &dyn Outbox,
+    dataset_id: &DatasetID,
+    write_result: &WriteDataResult,
+) -> Result<(), InternalError> {
+    let Some(add_data_block) = &write_result.add_data_block else {
+        return Ok(());
+    };
+
+    outbox
+        .post_message(
+            MESSAGE_PRODUCER_KAMU_CORE_DATASET_SERVICE,
+            DatasetLifecycleMessage::data_updated(
+                dataset_id.clone(),
+                Some(write_result.old_head.clone()),
+                write_result.new_head.clone(),
+                add_data_block
+                    .event
+                    .new_data
+                    .as_ref()
+                    .map(|new_data| new_data.offset_interval.clone()),
+                add_data_block.event.new_watermark,
+            ),
+        )
+        .await
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
 pub fn new_session_context(object_store_registry: Arc<dyn ObjectStoreRegistry>) -> SessionContext {
     use datafusion::execution::runtime_env::{RuntimeConfig, RuntimeEnv};
     use datafusion::prelude::*;
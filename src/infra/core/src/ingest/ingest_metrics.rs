@@ -0,0 +1,75 @@
+// Copyright Kamu Data, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+use kamu_core::WriteDataResult;
+use observability::*;
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+pub struct IngestMetrics {
+    pub records_total: CounterVec,
+    pub bytes_total: CounterVec,
+}
+
+#[dill::component(pub)]
+#[dill::interface(dyn MetricsProvider)]
+#[dill::scope(dill::Singleton)]
+impl IngestMetrics {
+    pub fn new() -> Self {
+        Self {
+            // Not partitioned by dataset, as the endpoint is not authenticated and would
+            // otherwise reveal the identifiers of private datasets
+            records_total: CounterVec::new(
+                Opts::new(
+                    "kamu_ingest_records_total",
+                    "Number of records committed by polling and push ingest",
+                ),
+                &["ingest"],
+            )
+            .unwrap(),
+            bytes_total: CounterVec::new(
+                Opts::new(
+                    "kamu_ingest_bytes_total",
+                    "Size of data slices committed by polling and push ingest",
+                ),
+                &["ingest"],
+            )
+            .unwrap(),
+        }
+    }
+
+    /// Accounts for the data committed by a `polling` or `push` ingest
+    #[allow(clippy::cast_precision_loss)]
+    pub(crate) fn on_data_written(&self, ingest: &str, write_result: &WriteDataResult) {
+        let Some(new_data) = write_result
+            .add_data_block
+            .as_ref()
+            .and_then(|b| b.event.new_data.as_ref())
+        else {
+            return;
+        };
+
+        let num_records = new_data.offset_interval.end - new_data.offset_interval.start + 1;
+        self.records_total
+            .with_label_values(&[ingest])
+            .inc_by(num_records as f64);
+        self.bytes_total
+            .with_label_values(&[ingest])
+            .inc_by(new_data.size as f64);
+    }
+}
+
+impl MetricsProvider for IngestMetrics {
+    fn register(&self, registry: &Registry) -> Result<(), prometheus::Error> {
+        registry.register(Box::new(self.records_total.clone()))?;
+        registry.register(Box::new(self.bytes_total.clone()))
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
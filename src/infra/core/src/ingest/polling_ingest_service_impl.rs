@@ -17,6 +17,7 @@ use internal_error::{InternalError, ResultIntoInternal};
 use kamu_core::ingest::*;
 use kamu_core::*;
 use kamu_ingest_datafusion::DataWriterDataFusion;
+use messaging_outbox::Outbox;
 use opendatafabric::serde::yaml::Manifest;
 use opendatafabric::*;
 use random_names::get_random_name;
@@ -36,6 +37,8 @@ pub struct PollingIngestServiceImpl {
     run_info_dir: Arc<RunInfoDir>,
     cache_dir: Arc<CacheDir>,
     time_source: Arc<dyn SystemTimeSource>,
+    outbox: Arc<dyn Outbox>,
+    metrics: Option<Arc<IngestMetrics>>,
 }
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -53,6 +56,8 @@ impl PollingIngestServiceImpl {
         run_info_dir: Arc<RunInfoDir>,
         cache_dir: Arc<CacheDir>,
         time_source: Arc<dyn SystemTimeSource>,
+        outbox: Arc<dyn Outbox>,
+        metrics: Option<Arc<IngestMetrics>>,
     ) -> Self {
         Self {
             dataset_repo,
@@ -64,6 +69,8 @@ impl PollingIngestServiceImpl {
             run_info_dir,
             cache_dir,
             time_source,
+            outbox,
+            metrics,
         }
     }
 
@@ -109,6 +116,13 @@ impl PollingIngestServiceImpl {
             .with_metadata_state_scanned(None)
             .await
             .int_err()?
+            .with_expectations(
+                args.options
+                    .dataset_expectations
+                    .get(&args.dataset_handle.id)
+                    .cloned()
+                    .unwrap_or_default(),
+            )
             .build();
 
         let Some(MetadataEvent::SetPollingSource(polling_source)) =
@@ -225,8 +239,15 @@ impl PollingIngestServiceImpl {
             });
         }
 
-        let savepoint = match self.fetch(&args).await? {
-            FetchStepResult::Updated(savepoint) => savepoint,
+        // Source state changes with the commit, so the savepoint path is determined
+        // beforehand
+        let savepoint_path = self.get_savepoint_path(
+            &args.polling_source.fetch,
+            Self::prev_source_state(&args).as_ref(),
+        );
+
+        let (savepoint, acknowledgement) = match self.fetch(&args).await? {
+            FetchStepResult::Updated(savepoint, acknowledgement) => (savepoint, acknowledgement),
             FetchStepResult::UpToDate => {
                 return Ok(PollingIngestResult::UpToDate {
                     no_source_defined: false,
@@ -282,6 +303,11 @@ impl PollingIngestServiceImpl {
 
         let new_source_state = savepoint.source_state.map(|ss| ss.to_source_state());
 
+        let retract_previous = match &savepoint.retraction {
+            Some(retraction) => retraction.to_filter_expr(args.data_writer.prev_schema())?,
+            None => None,
+        };
+
         let out_dir = args.operation_dir.join("out");
         let data_staging_path = out_dir.join("data");
         std::fs::create_dir(&out_dir).int_err()?;
@@ -295,6 +321,7 @@ impl PollingIngestServiceImpl {
                     source_event_time: savepoint.source_event_time.unwrap_or(args.system_time),
                     new_watermark: None,
                     new_source_state,
+                    retract_previous,
                     data_staging_path,
                 },
             )
@@ -320,6 +347,27 @@ impl PollingIngestServiceImpl {
 
                 let res = args.data_writer.commit(staged).await?;
 
+                if let Some(acknowledgement) = acknowledgement {
+                    self.acknowledge_fetch(&acknowledgement, &savepoint_path, &savepoint.data)
+                        .await;
+                }
+
+                if let Some(metrics) = &self.metrics {
+                    metrics.on_data_written("polling", &res);
+                }
+
+                ingest_common::post_data_updated_message(
+                    self.outbox.as_ref(),
+                    &args.dataset_handle.id,
+                    &res,
+                )
+                .await?;
+
+                if let Some(mut check) = res.expectations_check {
+                    check.new_head = Some(res.new_head.clone());
+                    args.listener.on_expectations_checked(&check);
+                }
+
                 Ok(PollingIngestResult::Updated {
                     old_head: res.old_head,
                     new_head: res.new_head,
@@ -330,24 +378,58 @@ impl PollingIngestServiceImpl {
             Err(StageDataError::BadInputSchema(e)) => Err(e.into()),
             Err(StageDataError::IncompatibleSchema(e)) => Err(e.into()),
             Err(StageDataError::MergeError(e)) => Err(e.into()),
-            Err(StageDataError::EmptyCommit(_)) => Ok(PollingIngestResult::UpToDate {
-                no_source_defined: false,
-                uncacheable,
-            }),
+            Err(StageDataError::ExpectationsFailed(e)) => {
+                args.listener.on_expectations_checked(&e.check);
+                Err(e.into())
+            }
+            Err(StageDataError::EmptyCommit(_)) => {
+                // Nothing new to commit, so the fetched records are safe to acknowledge
+                if let Some(acknowledgement) = acknowledgement {
+                    self.acknowledge_fetch(&acknowledgement, &savepoint_path, &savepoint.data)
+                        .await;
+                }
+
+                Ok(PollingIngestResult::UpToDate {
+                    no_source_defined: false,
+                    uncacheable,
+                })
+            }
             Err(StageDataError::Internal(e)) => Err(e.into()),
         }
     }
 
+    // Failing to acknowledge is not an error of the ingest as the data is already
+    // committed - the source will only deliver the same records again.
+    //
+    // The savepoint is removed, as the source state does not change with the
+    // acknowledged records and the savepoint would otherwise be resumed from again.
+    async fn acknowledge_fetch(
+        &self,
+        acknowledgement: &FetchAcknowledgement,
+        savepoint_path: &Path,
+        savepoint_data: &SavepointData,
+    ) {
+        if let Err(err) = std::fs::remove_file(savepoint_path)
+            .and_then(|()| savepoint_data.remove_owned(&self.cache_dir))
+        {
+            tracing::warn!(error = ?err, ?savepoint_path, "Failed to remove fetch savepoint");
+        }
+
+        if let Err(err) = acknowledgement.acknowledge().await {
+            tracing::warn!(
+                error = ?err,
+                "Failed to acknowledge fetched records, they may be ingested again"
+            );
+        }
+    }
+
     #[tracing::instrument(level = "info", skip_all)]
     async fn fetch(
         &self,
         args: &IngestIterationArgs<'_>,
     ) -> Result<FetchStepResult, PollingIngestError> {
         let fetch_step = &args.polling_source.fetch;
-        let prev_source_state = args
-            .data_writer
-            .prev_source_state()
-            .and_then(PollingSourceState::try_from_source_state);
+        let prev_source_state = Self::prev_source_state(args);
 
         let savepoint_path = self.get_savepoint_path(fetch_step, prev_source_state.as_ref());
         let savepoint = self.read_fetch_savepoint(&savepoint_path)?;
@@ -358,11 +440,33 @@ impl PollingIngestServiceImpl {
                     ?savepoint_path,
                     "Ignoring savepoint due to --fetch-uncacheable"
                 );
-            } else if let FetchStep::Mqtt(_) = fetch_step {
+            } else if let (FetchStep::Mqtt(_), None) = (fetch_step, &savepoint.acknowledgement) {
+                // Savepoints of older versions can't tell whether the messages were committed
+                tracing::info!(
+                    ?savepoint_path,
+                    "Ignoring savepoint without acknowledgement"
+                );
             } else {
                 tracing::info!(?savepoint_path, "Resuming from savepoint");
                 args.listener.on_cache_hit(&savepoint.created_at);
-                return Ok(FetchStepResult::Updated(savepoint));
+
+                // Records cached from sources that deliver them until acknowledged still have
+                // to be acknowledged once committed
+                let acknowledgement = match &savepoint.acknowledgement {
+                    None => None,
+                    Some(state) => Some(
+                        self.fetch_service
+                            .resume_acknowledgement(
+                                &args.dataset_handle,
+                                fetch_step,
+                                state,
+                                &args.options.dataset_env_vars,
+                            )
+                            .await?,
+                    ),
+                };
+
+                return Ok(FetchStepResult::Updated(savepoint, acknowledgement));
             }
         }
 
@@ -380,6 +484,7 @@ impl PollingIngestServiceImpl {
                 &args.dataset_handle,
                 &args.operation_id,
                 fetch_step,
+                Some(&args.polling_source.read),
                 prev_source_state.as_ref(),
                 &target_path,
                 &args.system_time,
@@ -407,13 +512,24 @@ impl PollingIngestServiceImpl {
                     source_event_time: upd.source_event_time,
                     data,
                     has_more: upd.has_more,
+                    retraction: upd.retraction,
+                    acknowledgement: upd
+                        .acknowledgement
+                        .as_ref()
+                        .map(FetchAcknowledgement::state),
                 };
                 self.write_fetch_savepoint(&savepoint_path, &savepoint)?;
-                Ok(FetchStepResult::Updated(savepoint))
+                Ok(FetchStepResult::Updated(savepoint, upd.acknowledgement))
             }
         }
     }
 
+    fn prev_source_state(args: &IngestIterationArgs<'_>) -> Option<PollingSourceState> {
+        args.data_writer
+            .prev_source_state()
+            .and_then(PollingSourceState::try_from_source_state)
+    }
+
     /// Savepoint is considered valid only when it corresponds to the identical
     /// fetch step and the source state of the previous commit - this way
     /// savepoint is always based on next state increment after the previous
@@ -671,7 +787,7 @@ impl PollingIngestService for PollingIngestServiceImpl {
 
 pub(crate) enum FetchStepResult {
     UpToDate,
-    Updated(FetchSavepoint),
+    Updated(FetchSavepoint, Option<FetchAcknowledgement>),
 }
 
 pub(crate) struct PrepStepResult {
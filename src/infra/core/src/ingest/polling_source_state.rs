@@ -16,6 +16,8 @@ use opendatafabric::SourceState;
 use serde::{Deserialize, Deserializer, Serialize, Serializer};
 use serde_with::skip_serializing_none;
 
+use crate::{FetchAcknowledgementState, FetchRetraction};
+
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
 
 #[derive(Debug, Clone, Eq, PartialEq)]
@@ -105,6 +107,10 @@ pub(crate) struct FetchSavepoint {
     pub source_event_time: Option<DateTime<Utc>>,
     pub data: SavepointData,
     pub has_more: bool,
+    #[serde(default)]
+    pub retraction: Option<FetchRetraction>,
+    #[serde(default)]
+    pub acknowledgement: Option<FetchAcknowledgementState>,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
@@ -9,19 +9,23 @@
 
 use std::fs::File;
 use std::io::prelude::*;
-use std::io::Error as IOError;
+use std::io::{Error as IOError, SeekFrom};
 use std::path::{Path, PathBuf};
 use std::process;
 use std::process::{Command, Stdio};
 use std::sync::Arc;
 
-use internal_error::ResultIntoInternal;
+use internal_error::{ErrorIntoInternal, ResultIntoInternal};
 use kamu_core::*;
 use opendatafabric::*;
 use thiserror::Error;
 
 const BUFFER_SIZE: usize = 8096;
 
+/// Number of leading bytes to inspect when detecting the compression format
+/// (tar header magic is located at offset 257)
+const MAGIC_BYTES_PEEK_SIZE: usize = 262;
+
 pub struct PrepService {}
 
 impl PrepService {
@@ -50,12 +54,13 @@ impl PrepService {
                         },
                     )?,
                 ),
-                PrepStep::Decompress(dc) => match dc.format {
-                    CompressionFormat::Zip => {
-                        Box::new(DecompressZipStream::new(stream, dc.sub_path.clone()))
-                    }
-                    CompressionFormat::Gzip => Box::new(DecompressGzipStream::new(stream)),
-                },
+                PrepStep::Decompress(dc) => {
+                    let (format, stream) = match dc.format {
+                        CompressionFormat::Auto => detect_compression_format(stream)?,
+                        format => (format, stream),
+                    };
+                    Self::decompress(format, stream, dc.sub_path.clone())?
+                }
             };
         }
 
@@ -64,6 +69,139 @@ impl PrepService {
 
         Ok(())
     }
+
+    fn decompress(
+        format: CompressionFormat,
+        input: Box<dyn Stream>,
+        sub_path: Option<String>,
+    ) -> Result<Box<dyn Stream>, PollingIngestError> {
+        let stream: Box<dyn Stream> = match format {
+            CompressionFormat::Zip => Box::new(DecompressZipStream::new(input, sub_path)),
+            CompressionFormat::Tar => Box::new(DecompressTarStream::new(input, sub_path)),
+            CompressionFormat::Gzip => Box::new(DecoderStream::new(
+                flate2::read::GzDecoder::new(input),
+                flate2::read::GzDecoder::into_inner,
+            )),
+            CompressionFormat::Zstd => Box::new(DecoderStream::new(
+                zstd::stream::read::Decoder::new(input).int_err()?,
+                |d| d.finish().into_inner(),
+            )),
+            CompressionFormat::Xz => Box::new(DecoderStream::new(
+                xz2::read::XzDecoder::new_multi_decoder(input),
+                xz2::read::XzDecoder::into_inner,
+            )),
+            CompressionFormat::Bzip2 => Box::new(DecoderStream::new(
+                bzip2::read::MultiBzDecoder::new(input),
+                bzip2::read::MultiBzDecoder::into_inner,
+            )),
+            CompressionFormat::Auto => unreachable!(),
+        };
+        Ok(stream)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// Format detection
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Determines the compression format from the magic bytes at the beginning of
+/// the stream, returning the stream positioned back at its start
+fn detect_compression_format(
+    mut input: Box<dyn Stream>,
+) -> Result<(CompressionFormat, Box<dyn Stream>), PollingIngestError> {
+    let start_pos = match input.as_seekable_read() {
+        Some(seekable) => Some(seekable.stream_position().int_err()?),
+        None => None,
+    };
+
+    let mut head = Vec::with_capacity(MAGIC_BYTES_PEEK_SIZE);
+    input
+        .by_ref()
+        .take(MAGIC_BYTES_PEEK_SIZE as u64)
+        .read_to_end(&mut head)
+        .int_err()?;
+
+    if head.starts_with(&[0x37, 0x7a, 0xbc, 0xaf, 0x27, 0x1c]) {
+        return Err(UnsupportedCompressionFormatError {
+            format: "7z".to_owned(),
+        }
+        .int_err()
+        .into());
+    }
+
+    let format = compression_format_from_magic_bytes(&head)
+        .ok_or_else(|| UnknownCompressionFormatError {}.int_err())?;
+
+    let input: Box<dyn Stream> = if let Some(start_pos) = start_pos {
+        input
+            .as_seekable_read()
+            .unwrap()
+            .seek(SeekFrom::Start(start_pos))
+            .int_err()?;
+        input
+    } else {
+        Box::new(PeekedStream {
+            head: std::io::Cursor::new(head),
+            input,
+        })
+    };
+
+    Ok((format, input))
+}
+
+fn compression_format_from_magic_bytes(head: &[u8]) -> Option<CompressionFormat> {
+    if head.starts_with(&[0x1f, 0x8b]) {
+        Some(CompressionFormat::Gzip)
+    } else if head.starts_with(b"PK\x03\x04") || head.starts_with(b"PK\x05\x06") {
+        Some(CompressionFormat::Zip)
+    } else if head.starts_with(&[0x28, 0xb5, 0x2f, 0xfd]) {
+        Some(CompressionFormat::Zstd)
+    } else if head.starts_with(&[0xfd, b'7', b'z', b'X', b'Z', 0x00]) {
+        Some(CompressionFormat::Xz)
+    } else if head.starts_with(b"BZh") {
+        Some(CompressionFormat::Bzip2)
+    } else if head.get(257..262) == Some(b"ustar".as_slice()) {
+        Some(CompressionFormat::Tar)
+    } else {
+        None
+    }
+}
+
+#[derive(Debug, Error)]
+#[error("Unable to detect compression format of the data")]
+struct UnknownCompressionFormatError {}
+
+#[derive(Debug, Error)]
+#[error("Compression format {format} is not supported, use a pipe step to extract the data")]
+struct UnsupportedCompressionFormatError {
+    format: String,
+}
+
+/// Replays the bytes consumed during format detection before continuing to
+/// read from the underlying stream
+struct PeekedStream {
+    head: std::io::Cursor<Vec<u8>>,
+    input: Box<dyn Stream>,
+}
+
+impl Read for PeekedStream {
+    fn read(&mut self, buf: &mut [u8]) -> Result<usize, IOError> {
+        let read = self.head.read(buf)?;
+        if read != 0 {
+            return Ok(read);
+        }
+        self.input.read(buf)
+    }
+}
+
+impl Stream for PeekedStream {
+    fn as_seekable_read(&mut self) -> Option<&mut dyn ReadAndSeek> {
+        None
+    }
+
+    fn join(self: Box<Self>) -> Result<(), PollingIngestError> {
+        self.input.join()
+    }
 }
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -289,33 +427,156 @@ impl Stream for DecompressZipStream {
 }
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
-// DecompressGzipStream
+// DecompressTarStream
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+struct DecompressTarStream {
+    ingress: std::thread::JoinHandle<Result<(), PollingIngestError>>,
+    chunks: std::sync::mpsc::Receiver<Vec<u8>>,
+    chunk: std::io::Cursor<Vec<u8>>,
+}
+
+impl DecompressTarStream {
+    fn new(input: Box<dyn Stream>, sub_path: Option<String>) -> Self {
+        // Bounded channel provides the backpressure: the ingress thread blocks
+        // until the reader consumes a chunk instead of polling for free space
+        let (tx, rx) = std::sync::mpsc::sync_channel::<Vec<u8>>(1);
+
+        // Entries borrow the archive, so same as with zip we read them in a separate
+        // thread that owns the input
+        let ingress = std::thread::Builder::new()
+            .name("decompress_tar_stream".to_owned())
+            .spawn(move || {
+                let pattern = sub_path
+                    .as_deref()
+                    .map(glob::Pattern::new)
+                    .transpose()
+                    .int_err()?;
+
+                let mut archive = tar::Archive::new(input);
+
+                {
+                    let mut entry = None;
+                    for e in archive.entries().int_err()? {
+                        let e = e.int_err()?;
+                        if !e.header().entry_type().is_file() {
+                            continue;
+                        }
+                        let matches = match &pattern {
+                            None => true,
+                            Some(pattern) => pattern.matches_path(&e.path().int_err()?),
+                        };
+                        if matches {
+                            entry = Some(e);
+                            break;
+                        }
+                    }
+
+                    let mut entry = entry.ok_or_else(|| {
+                        ArchiveEntryNotFoundError {
+                            sub_path: sub_path.clone(),
+                        }
+                        .int_err()
+                    })?;
+
+                    loop {
+                        let mut chunk = vec![0; BUFFER_SIZE];
+                        let read = entry.read(&mut chunk).int_err()?;
+                        if read == 0 {
+                            break;
+                        }
+                        chunk.truncate(read);
+
+                        // Reader was dropped - no one is interested in the rest of the entry
+                        if tx.send(chunk).is_err() {
+                            break;
+                        }
+                    }
+                }
+
+                // Signal the end of data before draining the archive
+                drop(tx);
+
+                // Drain the rest of the archive to let the upstream steps complete
+                let mut input = archive.into_inner();
+                std::io::copy(&mut input, &mut std::io::sink()).int_err()?;
+                input.join()
+            })
+            .unwrap();
+
+        Self {
+            ingress,
+            chunks: rx,
+            chunk: std::io::Cursor::new(Vec::new()),
+        }
+    }
+}
+
+impl Read for DecompressTarStream {
+    fn read(&mut self, buf: &mut [u8]) -> Result<usize, IOError> {
+        loop {
+            let read = self.chunk.read(buf)?;
+            if read != 0 || buf.is_empty() {
+                return Ok(read);
+            }
+            match self.chunks.recv() {
+                Ok(chunk) => self.chunk = std::io::Cursor::new(chunk),
+                // Sender is dropped once the entry is fully read or ingress failed
+                Err(_) => return Ok(0),
+            }
+        }
+    }
+}
+
+impl Stream for DecompressTarStream {
+    fn as_seekable_read(&mut self) -> Option<&mut dyn ReadAndSeek> {
+        None
+    }
+
+    fn join(self: Box<Self>) -> Result<(), PollingIngestError> {
+        self.ingress.join().unwrap()
+    }
+}
+
+#[derive(Debug, Error)]
+#[error("Archive does not contain a file matching {sub_path:?}")]
+struct ArchiveEntryNotFoundError {
+    sub_path: Option<String>,
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// DecoderStream
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
 
-struct DecompressGzipStream {
-    decoder: flate2::read::GzDecoder<Box<dyn Stream>>,
+/// Wraps streaming decoders of single-file compression formats (gzip, zstd,
+/// xz, bzip2) that own the input stream
+struct DecoderStream<D> {
+    decoder: D,
+    into_inner: fn(D) -> Box<dyn Stream>,
 }
 
-impl DecompressGzipStream {
-    fn new(input: Box<dyn Stream>) -> Self {
-        let decoder = flate2::read::GzDecoder::new(input);
-        Self { decoder }
+impl<D: Read + Send> DecoderStream<D> {
+    fn new(decoder: D, into_inner: fn(D) -> Box<dyn Stream>) -> Self {
+        Self {
+            decoder,
+            into_inner,
+        }
     }
 }
 
-impl Read for DecompressGzipStream {
+impl<D: Read + Send> Read for DecoderStream<D> {
     fn read(&mut self, buf: &mut [u8]) -> Result<usize, IOError> {
         self.decoder.read(buf)
     }
 }
 
-impl Stream for DecompressGzipStream {
+impl<D: Read + Send> Stream for DecoderStream<D> {
     fn as_seekable_read(&mut self) -> Option<&mut dyn ReadAndSeek> {
         None
     }
 
     fn join(self: Box<Self>) -> Result<(), PollingIngestError> {
-        self.decoder.into_inner().join()
+        (self.into_inner)(self.decoder).join()
     }
 }
 
@@ -17,12 +17,13 @@ use internal_error::{ErrorIntoInternal, InternalError, ResultIntoInternal};
 use kamu_core::ingest::*;
 use kamu_core::*;
 use kamu_ingest_datafusion::*;
+use messaging_outbox::Outbox;
 use opendatafabric::*;
 use random_names::get_random_name;
 use time_source::SystemTimeSource;
 use tokio::io::AsyncRead;
 
-use super::ingest_common;
+use super::{ingest_common, IngestMetrics};
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
 
@@ -34,6 +35,8 @@ pub struct PushIngestServiceImpl {
     time_source: Arc<dyn SystemTimeSource>,
     engine_provisioner: Arc<dyn EngineProvisioner>,
     run_info_dir: Arc<RunInfoDir>,
+    outbox: Arc<dyn Outbox>,
+    metrics: Option<Arc<IngestMetrics>>,
 }
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -49,6 +52,8 @@ impl PushIngestServiceImpl {
         time_source: Arc<dyn SystemTimeSource>,
         engine_provisioner: Arc<dyn EngineProvisioner>,
         run_info_dir: Arc<RunInfoDir>,
+        outbox: Arc<dyn Outbox>,
+        metrics: Option<Arc<IngestMetrics>>,
     ) -> Self {
         Self {
             dataset_repo,
@@ -58,6 +63,8 @@ impl PushIngestServiceImpl {
             time_source,
             engine_provisioner,
             run_info_dir,
+            outbox,
+            metrics,
         }
     }
 
@@ -85,7 +92,7 @@ impl PushIngestServiceImpl {
             ingest_common::new_session_context(self.object_store_registry.clone());
 
         let mut data_writer = self
-            .make_data_writer(dataset.clone(), source_name, ctx.clone())
+            .make_data_writer(dataset.clone(), source_name, &opts, ctx.clone())
             .await?;
 
         let push_source = match (data_writer.source_event(), opts.auto_create_push_source) {
@@ -97,7 +104,7 @@ impl PushIngestServiceImpl {
 
                 // Update data writer, as we've modified the dataset
                 data_writer = self
-                    .make_data_writer(dataset.clone(), source_name, ctx.clone())
+                    .make_data_writer(dataset.clone(), source_name, &opts, ctx.clone())
                     .await?;
                 Ok(add_push_source_event)
             }
@@ -112,6 +119,7 @@ impl PushIngestServiceImpl {
         }?;
 
         let args = PushIngestArgs {
+            dataset_id: dataset_handle.id,
             operation_id,
             operation_dir,
             system_time: self.time_source.now(),
@@ -143,13 +151,16 @@ impl PushIngestServiceImpl {
         &self,
         dataset: Arc<dyn Dataset>,
         source_name: Option<&str>,
+        opts: &PushIngestOpts,
         ctx: SessionContext,
     ) -> Result<DataWriterDataFusion, PushIngestError> {
         match DataWriterDataFusion::builder(dataset, ctx)
             .with_metadata_state_scanned(source_name)
             .await
         {
-            Ok(b) => Ok(b.build()),
+            Ok(b) => Ok(b
+                .with_expectations(opts.dataset_expectations.clone())
+                .build()),
             Err(ScanMetadataError::SourceNotFound(err)) => {
                 Err(PushIngestError::SourceNotFound(err.into()))
             }
@@ -263,6 +274,7 @@ impl PushIngestServiceImpl {
                     source_event_time: args.opts.source_event_time.unwrap_or(args.system_time),
                     new_watermark: None,
                     new_source_state: None, // TODO: Support storing ingest source state
+                    retract_previous: None,
                     data_staging_path,
                 },
             )
@@ -277,6 +289,22 @@ impl PushIngestServiceImpl {
 
                 let res = args.data_writer.commit(staged).await?;
 
+                if let Some(metrics) = &self.metrics {
+                    metrics.on_data_written("push", &res);
+                }
+
+                ingest_common::post_data_updated_message(
+                    self.outbox.as_ref(),
+                    &args.dataset_id,
+                    &res,
+                )
+                .await?;
+
+                if let Some(mut check) = res.expectations_check {
+                    check.new_head = Some(res.new_head.clone());
+                    args.listener.on_expectations_checked(&check);
+                }
+
                 Ok(PushIngestResult::Updated {
                     old_head: res.old_head,
                     new_head: res.new_head,
@@ -286,6 +314,10 @@ impl PushIngestServiceImpl {
             Err(StageDataError::BadInputSchema(e)) => Err(e.into()),
             Err(StageDataError::IncompatibleSchema(e)) => Err(e.into()),
             Err(StageDataError::MergeError(e)) => Err(e.into()),
+            Err(StageDataError::ExpectationsFailed(e)) => {
+                args.listener.on_expectations_checked(&e.check);
+                Err(e.into())
+            }
             Err(StageDataError::EmptyCommit(_)) => Ok(PushIngestResult::UpToDate),
             Err(StageDataError::Internal(e)) => Err(e.into()),
         }
@@ -493,6 +525,7 @@ impl PushIngestService for PushIngestServiceImpl {
 }
 
 struct PushIngestArgs {
+    dataset_id: DatasetID,
     operation_id: String,
     operation_dir: PathBuf,
     system_time: DateTime<Utc>,
@@ -27,11 +27,14 @@ mod use_cases;
 pub mod utils;
 
 mod compaction_service_impl;
+mod dataset_archive_service_impl;
 mod dataset_changes_service_impl;
 mod dataset_config;
 mod dataset_layout;
 mod dataset_ownership_service_inmem;
+mod dependency_graph_consistency_checker;
 mod dependency_graph_repository_inmem;
+mod dependency_graph_repository_persistent;
 mod dependency_graph_service_inmem;
 mod provenance_service_impl;
 mod pull_service_impl;
@@ -47,11 +50,14 @@ mod transform_service_impl;
 mod verification_service_impl;
 
 pub use compaction_service_impl::*;
+pub use dataset_archive_service_impl::*;
 pub use dataset_changes_service_impl::*;
 pub use dataset_config::*;
 pub use dataset_layout::*;
 pub use dataset_ownership_service_inmem::*;
+pub use dependency_graph_consistency_checker::*;
 pub use dependency_graph_repository_inmem::*;
+pub use dependency_graph_repository_persistent::*;
 pub use dependency_graph_service_inmem::*;
 pub use engine::*;
 pub use ingest::*;
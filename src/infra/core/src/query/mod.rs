@@ -7,20 +7,24 @@
 // the Business Source License, use of this software will be governed
 // by the Apache License, Version 2.0.
 
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 use std::sync::{Arc, Mutex};
 
+use chrono::{DateTime, Utc};
 use datafusion::arrow::datatypes::{Schema, SchemaRef};
 use datafusion::catalog::{CatalogProvider, SchemaProvider, Session};
-use datafusion::common::{Constraints, Statistics};
+use datafusion::common::stats::Precision;
+use datafusion::common::{Constraints, ScalarValue, Statistics};
 use datafusion::config::TableOptions;
 use datafusion::datasource::empty::EmptyTable;
+use datafusion::datasource::function::TableFunctionImpl;
 use datafusion::datasource::listing::{ListingTable, ListingTableConfig};
 use datafusion::datasource::{TableProvider, TableType};
 use datafusion::error::DataFusionError;
-use datafusion::execution::context::DataFilePaths;
+use datafusion::execution::context::{DataFilePaths, ExecutionProps};
 use datafusion::execution::options::ReadOptions;
-use datafusion::logical_expr::LogicalPlan;
+use datafusion::logical_expr::{LogicalPlan, TableProviderFilterPushDown};
+use datafusion::optimizer::simplify_expressions::{ExprSimplifier, SimplifyContext};
 use datafusion::physical_plan::ExecutionPlan;
 use datafusion::prelude::*;
 use futures::stream::{self, StreamExt, TryStreamExt};
@@ -28,6 +32,9 @@ use internal_error::{InternalError, ResultIntoInternal};
 use kamu_core::*;
 use opendatafabric::*;
 
+mod slices;
+use slices::*;
+
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
 // Catalog
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -84,8 +91,13 @@ struct KamuSchemaImpl {
     dataset_action_authorizer: Arc<dyn auth::DatasetActionAuthorizer>,
     options: QueryOptions,
     cache: Mutex<SchemaCache>,
+    time_travel_inputs: Mutex<BTreeMap<TimeTravelInput, Multihash>>,
+    time_travel_tables: Mutex<BTreeMap<TimeTravelCall, Arc<KamuTable>>>,
 }
 
+/// Arguments of a time-travel table function call as they appear in the query
+type TimeTravelCall = (String, TimeTravelSelector);
+
 #[derive(Default)]
 struct SchemaCache {
     tables: Option<HashMap<String, Arc<KamuTable>>>,
@@ -108,6 +120,8 @@ impl KamuSchema {
                 dataset_action_authorizer,
                 options,
                 cache: Mutex::new(SchemaCache::default()),
+                time_travel_inputs: Mutex::new(BTreeMap::new()),
+                time_travel_tables: Mutex::new(BTreeMap::new()),
             }),
         }
     }
@@ -241,6 +255,220 @@ impl KamuSchema {
         let cache = self.ensure_cache().await?;
         Ok(cache.tables.as_ref().unwrap().contains_key(name))
     }
+
+    /// Returns the blocks that time-travel table functions were resolved to
+    /// so far
+    pub fn time_travel_inputs(&self) -> BTreeMap<TimeTravelInput, Multihash> {
+        self.inner.time_travel_inputs.lock().unwrap().clone()
+    }
+
+    /// Resolves the dataset state requested by a time-travel table function
+    /// call and prepares the table provider for it. Table functions cannot
+    /// perform async operations, so when possible this happens before the
+    /// query is planned.
+    #[tracing::instrument(level = "info", skip_all, fields(%dataset_ref, ?selector))]
+    pub async fn prepare_time_travel_table(
+        &self,
+        dataset_ref: String,
+        selector: TimeTravelSelector,
+    ) -> Result<Arc<KamuTable>, DataFusionError> {
+        let call = (dataset_ref, selector);
+        if let Some(table) = self.inner.time_travel_tables.lock().unwrap().get(&call) {
+            return Ok(table.clone());
+        }
+
+        let hdl = self.resolve_time_travel_dataset(&call.0).await?;
+        let dataset = self.inner.dataset_repo.get_dataset_by_handle(&hdl);
+
+        let input = TimeTravelInput {
+            dataset_id: hdl.id.clone(),
+            selector: call.1.clone(),
+        };
+
+        // Previously resolved state takes precedence to keep the query reproducible
+        let pinned_hash = self
+            .inner
+            .options
+            .as_of_state
+            .as_ref()
+            .and_then(|s| s.time_travel_inputs.get(&input))
+            .cloned();
+
+        let hash = if let Some(hash) = pinned_hash {
+            hash
+        } else {
+            resolve_time_travel_block(dataset.as_ref(), &input.selector).await?
+        };
+
+        tracing::debug!(%hash, "Resolved time-travel input");
+
+        let table = Arc::new(KamuTable::new(
+            self.inner.session_config.clone(),
+            self.inner.table_options.clone(),
+            hdl,
+            dataset,
+            Some(hash.clone()),
+            None,
+        ));
+
+        // Pre-initialize the schema as `TableProvider::schema()` is not async
+        table
+            .get_table_schema()
+            .await
+            .map_err(|e| DataFusionError::External(e.into()))?;
+
+        self.inner
+            .time_travel_inputs
+            .lock()
+            .unwrap()
+            .insert(input, hash);
+
+        self.inner
+            .time_travel_tables
+            .lock()
+            .unwrap()
+            .insert(call, table.clone());
+
+        Ok(table)
+    }
+
+    /// Returns the table provider previously prepared via
+    /// [`Self::prepare_time_travel_table()`], or prepares it in place.
+    ///
+    /// The latter is the case for queries planned directly by the
+    /// [`SessionContext`] (e.g. in the SQL shell or Flight SQL sessions), where
+    /// nothing can be prepared in advance. Table functions are not async, so
+    /// the worker thread is blocked while the dataset state is resolved.
+    fn time_travel_table(
+        &self,
+        dataset_ref: String,
+        selector: TimeTravelSelector,
+    ) -> Result<Arc<KamuTable>, DataFusionError> {
+        use tokio::runtime::{Handle, RuntimeFlavor};
+
+        let call = (dataset_ref, selector);
+        if let Some(table) = self.inner.time_travel_tables.lock().unwrap().get(&call) {
+            return Ok(table.clone());
+        }
+
+        let handle = Handle::try_current().map_err(|e| DataFusionError::External(e.into()))?;
+        if handle.runtime_flavor() != RuntimeFlavor::MultiThread {
+            return Err(DataFusionError::Plan(
+                "Time-travel table functions require a multi-threaded runtime when used outside \
+                 of the query service"
+                    .to_string(),
+            ));
+        }
+
+        let (dataset_ref, selector) = call;
+        tokio::task::block_in_place(|| {
+            handle.block_on(self.prepare_time_travel_table(dataset_ref, selector))
+        })
+    }
+
+    async fn resolve_time_travel_dataset(
+        &self,
+        dataset_ref: &str,
+    ) -> Result<DatasetHandle, DataFusionError> {
+        let not_found = || DataFusionError::Plan(format!("Dataset not found: {dataset_ref}"));
+
+        // When aliases are specified the name resolution is disabled
+        let resolved_ref = match &self.inner.options.aliases {
+            Some(aliases) => aliases
+                .get(dataset_ref)
+                .ok_or_else(not_found)?
+                .as_local_ref(),
+            None => dataset_ref
+                .parse::<DatasetRef>()
+                .map_err(|e| DataFusionError::Plan(e.to_string()))?,
+        };
+
+        let hdl = match self
+            .inner
+            .dataset_repo
+            .resolve_dataset_ref(&resolved_ref)
+            .await
+        {
+            Ok(hdl) => hdl,
+            Err(GetDatasetError::NotFound(_)) => return Err(not_found()),
+            Err(GetDatasetError::Internal(e)) => return Err(DataFusionError::External(e.into())),
+        };
+
+        // Same as with regular tables - datasets that cannot be read appear as
+        // non-existing
+        if self
+            .inner
+            .dataset_action_authorizer
+            .check_action_allowed(&hdl, auth::DatasetAction::Read)
+            .await
+            .is_err()
+        {
+            return Err(not_found());
+        }
+
+        Ok(hdl)
+    }
+}
+
+async fn resolve_time_travel_block(
+    dataset: &dyn Dataset,
+    selector: &TimeTravelSelector,
+) -> Result<Multihash, DataFusionError> {
+    let chain = dataset.as_metadata_chain();
+
+    match selector {
+        TimeTravelSelector::Block(block) => {
+            if let Ok(hash) = Multihash::from_multibase(block) {
+                if chain
+                    .contains_block(&hash)
+                    .await
+                    .int_err()
+                    .map_err(|e| DataFusionError::External(e.into()))?
+                {
+                    Ok(hash)
+                } else {
+                    Err(DataFusionError::Plan(format!("Block not found: {block}")))
+                }
+            } else {
+                let block_ref: BlockRef = block.parse().map_err(|_| {
+                    DataFusionError::Plan(format!("Invalid block reference: {block}"))
+                })?;
+
+                chain
+                    .resolve_ref(&block_ref)
+                    .await
+                    .int_err()
+                    .map_err(|e| DataFusionError::External(e.into()))
+            }
+        }
+        TimeTravelSelector::SystemTime(system_time) => {
+            // System time is monotonically non-decreasing along the chain, so the
+            // first block we meet walking back from the head is the one we need
+            let found = chain
+                .reduce(
+                    None,
+                    MetadataVisitorDecision::Next,
+                    |state: &mut Option<Multihash>, hash, block| {
+                        if block.system_time <= *system_time {
+                            *state = Some(hash.clone());
+                            MetadataVisitorDecision::Stop
+                        } else {
+                            MetadataVisitorDecision::Next
+                        }
+                    },
+                )
+                .await
+                .int_err()
+                .map_err(|e| DataFusionError::External(e.into()))?;
+
+            found.ok_or_else(|| {
+                DataFusionError::Plan(format!(
+                    "Dataset did not exist as of {}",
+                    system_time.to_rfc3339()
+                ))
+            })
+        }
+    }
 }
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -296,10 +524,10 @@ impl SchemaProvider for KamuSchema {
         };
 
         if let Some(table) = table {
-            // HACK: We pre-initialize the schema here because `TableProvider::schema()` is
-            // not async
+            // HACK: We pre-initialize the schema and slice information here because
+            // `TableProvider::schema()` and `TableProvider::statistics()` are not async
             table
-                .get_table_schema()
+                .get_table_metadata()
                 .await
                 .map_err(|e| DataFusionError::External(e.into()))?;
 
@@ -326,8 +554,17 @@ pub(crate) struct KamuTable {
 
 #[derive(Default)]
 struct TableCache {
-    schema: Option<SchemaRef>,
-    table: Option<Arc<dyn TableProvider>>,
+    metadata: Option<Arc<TableMetadata>>,
+    /// Column statistics read from the footers of data files, by data hash
+    time_statistics: HashMap<Multihash, Arc<SliceTimeStatistics>>,
+}
+
+/// Everything we know about the table from the metadata chain
+struct TableMetadata {
+    schema: SchemaRef,
+    vocab: DatasetVocabulary,
+    /// Slices in the reverse chronological order
+    slices: Vec<DataSliceInfo>,
 }
 
 impl KamuTable {
@@ -350,37 +587,192 @@ impl KamuTable {
         }
     }
 
+    /// Scans the metadata chain once to collect the schema, vocabulary and the
+    /// information about all data slices
     #[tracing::instrument(level="info", skip_all, fields(dataset_handle = ?self.dataset_handle))]
-    async fn init_table_schema(&self) -> Result<SchemaRef, InternalError> {
-        let maybe_set_data_schema = self
-            .dataset
-            .as_metadata_chain()
-            .accept_one(SearchSetDataSchemaVisitor::new())
+    async fn init_table_metadata(&self) -> Result<TableMetadata, InternalError> {
+        let chain = self.dataset.as_metadata_chain();
+
+        let hash = if let Some(hash) = &self.as_of {
+            hash.clone()
+        } else {
+            chain.resolve_ref(&BlockRef::Head).await.int_err()?
+        };
+
+        tracing::debug!(as_of = ?self.as_of, "Collecting data slices");
+
+        let last_records_to_consider = self.hints.as_ref().and_then(|o| o.last_records_to_consider);
+
+        type Flag = MetadataEventTypeFlags;
+        type Decision = MetadataVisitorDecision;
+
+        struct DataSliceCollectorVisitorState {
+            slices: Vec<DataSliceInfo>,
+            num_records: u64,
+            last_records_to_consider: Option<u64>,
+        }
+
+        let mut set_data_schema_visitor = SearchSetDataSchemaVisitor::new();
+        let mut set_vocab_visitor = SearchSetVocabVisitor::new();
+        let mut data_slice_visitor = GenericCallbackVisitor::new(
+            DataSliceCollectorVisitorState {
+                slices: Vec::new(),
+                num_records: 0,
+                last_records_to_consider,
+            },
+            Decision::NextOfType(Flag::DATA_BLOCK),
+            |state, _hash, block| {
+                let new_data = match &block.event {
+                    MetadataEvent::AddData(e) => e.new_data.as_ref(),
+                    MetadataEvent::ExecuteTransform(e) => e.new_data.as_ref(),
+                    _ => unreachable!(),
+                };
+                let Some(slice) = new_data else {
+                    return Decision::NextOfType(Flag::DATA_BLOCK);
+                };
+
+                state.num_records += slice.num_records();
+                state.slices.push(DataSliceInfo {
+                    physical_hash: slice.physical_hash.clone(),
+                    offset_interval: slice.offset_interval.clone(),
+                });
+
+                if let Some(last_records_to_consider) = &state.last_records_to_consider
+                    && *last_records_to_consider <= state.num_records
+                {
+                    return Decision::Stop;
+                }
+
+                Decision::NextOfType(Flag::DATA_BLOCK)
+            },
+        );
+
+        chain
+            .accept_by_hash(
+                &mut [
+                    &mut set_data_schema_visitor,
+                    &mut set_vocab_visitor,
+                    &mut data_slice_visitor,
+                ],
+                &hash,
+            )
             .await
-            .int_err()?
-            .into_event();
+            .int_err()?;
 
-        if let Some(set_data_schema) = maybe_set_data_schema {
-            set_data_schema.schema_as_arrow().int_err()
+        let schema = if let Some(set_data_schema) = set_data_schema_visitor.into_event() {
+            set_data_schema.schema_as_arrow().int_err()?
         } else {
-            Ok(Arc::new(Schema::empty()))
+            Arc::new(Schema::empty())
+        };
+
+        let vocab = set_vocab_visitor.into_event().unwrap_or_default().into();
+
+        let slices = data_slice_visitor.into_state().slices;
+
+        tracing::debug!(num_slices = slices.len(), "Slices collected");
+
+        Ok(TableMetadata {
+            schema,
+            vocab,
+            slices,
+        })
+    }
+
+    async fn get_table_metadata(&self) -> Result<Arc<TableMetadata>, InternalError> {
+        {
+            let cache = self.cache.lock().unwrap();
+            if let Some(metadata) = &cache.metadata {
+                return Ok(Arc::clone(metadata));
+            }
+        }
+
+        let metadata = Arc::new(self.init_table_metadata().await?);
+
+        {
+            let mut cache = self.cache.lock().unwrap();
+            cache.metadata = Some(Arc::clone(&metadata));
+            Ok(metadata)
+        }
+    }
+
+    /// Skips slices that can't contain records matching the filters. Offset
+    /// ranges are taken from the metadata chain, while footers of data files
+    /// are read only when filters refer to time columns.
+    async fn prune_slices(
+        &self,
+        state: &dyn Session,
+        metadata: &TableMetadata,
+        pruner: &SlicePruner<'_>,
+    ) -> Result<Vec<Multihash>, InternalError> {
+        const READ_CONCURRENCY: usize = 16;
+
+        let slices: Vec<&DataSliceInfo> = metadata
+            .slices
+            .iter()
+            .filter(|s| pruner.may_match(&SliceColumnBounds::new(s, None)))
+            .collect();
+
+        if !pruner.needs_time_statistics() {
+            return Ok(slices
+                .into_iter()
+                .map(|s| s.physical_hash.clone())
+                .collect());
         }
+
+        let statistics: Vec<Arc<SliceTimeStatistics>> = stream::iter(&slices)
+            .map(|slice| self.get_time_statistics(state, &metadata.vocab, slice))
+            .buffered(READ_CONCURRENCY)
+            .try_collect()
+            .await?;
+
+        Ok(slices
+            .into_iter()
+            .zip(statistics)
+            .filter(|(s, stats)| pruner.may_match(&SliceColumnBounds::new(s, Some(stats.as_ref()))))
+            .map(|(s, _)| s.physical_hash.clone())
+            .collect())
     }
 
-    async fn get_table_schema(&self) -> Result<SchemaRef, InternalError> {
+    async fn get_time_statistics(
+        &self,
+        state: &dyn Session,
+        vocab: &DatasetVocabulary,
+        slice: &DataSliceInfo,
+    ) -> Result<Arc<SliceTimeStatistics>, InternalError> {
+        use datafusion::parquet::arrow::async_reader::{AsyncFileReader, ParquetObjectReader};
+
         {
             let cache = self.cache.lock().unwrap();
-            if let Some(schema) = &cache.schema {
-                return Ok(Arc::clone(schema));
+            if let Some(statistics) = cache.time_statistics.get(&slice.physical_hash) {
+                return Ok(Arc::clone(statistics));
             }
         }
 
-        let schema = self.init_table_schema().await?;
+        let url = self
+            .dataset
+            .as_data_repo()
+            .get_internal_url(&slice.physical_hash)
+            .await;
+        let object_store = state.runtime_env().object_store(&url).int_err()?;
+        let path = object_store::path::Path::from_url_path(url.path()).int_err()?;
+        let object_meta = object_store.head(&path).await.int_err()?;
+
+        let parquet_metadata = ParquetObjectReader::new(object_store, object_meta)
+            .get_metadata()
+            .await
+            .int_err()?;
+
+        let statistics = Arc::new(SliceTimeStatistics::from_parquet_metadata(
+            &parquet_metadata,
+            vocab,
+        ));
 
         {
             let mut cache = self.cache.lock().unwrap();
-            cache.schema = Some(Arc::clone(&schema));
-            Ok(schema)
+            cache
+                .time_statistics
+                .insert(slice.physical_hash.clone(), Arc::clone(&statistics));
+            Ok(statistics)
         }
     }
 
@@ -390,9 +782,8 @@ impl KamuTable {
     async fn init_table_provider(
         &self,
         schema: SchemaRef,
+        files: Vec<Multihash>,
     ) -> Result<Arc<dyn TableProvider>, InternalError> {
-        let files = self.collect_data_file_hashes(self.as_of.as_ref()).await?;
-
         if files.is_empty() {
             return Ok(Arc::new(EmptyTable::new(schema)));
         }
@@ -426,91 +817,6 @@ impl KamuTable {
 
         Ok(Arc::new(provider))
     }
-
-    async fn get_table_provider(&self) -> Result<Arc<dyn TableProvider>, InternalError> {
-        {
-            let cache = self.cache.lock().unwrap();
-            if let Some(table) = &cache.table {
-                return Ok(Arc::clone(table));
-            }
-        }
-
-        let schema = self.get_table_schema().await?;
-        let table = self.init_table_provider(schema).await?;
-
-        {
-            let mut cache = self.cache.lock().unwrap();
-            cache.table = Some(Arc::clone(&table));
-            Ok(table)
-        }
-    }
-
-    async fn collect_data_file_hashes(
-        &self,
-        as_of: Option<&Multihash>,
-    ) -> Result<Vec<Multihash>, InternalError> {
-        let hash = if let Some(hash) = as_of {
-            hash.clone()
-        } else {
-            self.dataset
-                .as_metadata_chain()
-                .resolve_ref(&BlockRef::Head)
-                .await
-                .int_err()?
-        };
-
-        tracing::debug!(?as_of, "Collecting data slices");
-
-        let last_records_to_consider = self.hints.as_ref().and_then(|o| o.last_records_to_consider);
-
-        type Flag = MetadataEventTypeFlags;
-        type Decision = MetadataVisitorDecision;
-
-        struct DataSliceCollectorVisitorState {
-            files: Vec<Multihash>,
-            num_records: u64,
-            last_records_to_consider: Option<u64>,
-        }
-
-        let final_state = self
-            .dataset
-            .as_metadata_chain()
-            .reduce_by_hash(
-                &hash,
-                DataSliceCollectorVisitorState {
-                    files: Vec::new(),
-                    num_records: 0,
-                    last_records_to_consider,
-                },
-                Decision::NextOfType(Flag::DATA_BLOCK),
-                |state, _hash, block| {
-                    let new_data = match &block.event {
-                        MetadataEvent::AddData(e) => e.new_data.as_ref(),
-                        MetadataEvent::ExecuteTransform(e) => e.new_data.as_ref(),
-                        _ => unreachable!(),
-                    };
-                    let Some(slice) = new_data else {
-                        return Decision::NextOfType(Flag::DATA_BLOCK);
-                    };
-
-                    state.num_records += slice.num_records();
-                    state.files.push(slice.physical_hash.clone());
-
-                    if let Some(last_records_to_consider) = &state.last_records_to_consider
-                        && *last_records_to_consider <= state.num_records
-                    {
-                        return Decision::Stop;
-                    }
-
-                    Decision::NextOfType(Flag::DATA_BLOCK)
-                },
-            )
-            .await
-            .int_err()?;
-
-        tracing::debug!(num_slices = final_state.files.len(), "Slices collected");
-        Ok(final_state.files)
-    }
 }
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -522,9 +828,16 @@ impl TableProvider for KamuTable {
     }
 
     fn schema(&self) -> SchemaRef {
-        // SAFETY: We rely on `KamuScheme` to pre-initialize schemas in async context
+        // SAFETY: We rely on `KamuScheme` to pre-initialize metadata in async context
         // before handing down references to `KamuTable`
-        self.cache.lock().unwrap().schema.clone().unwrap()
+        self.cache
+            .lock()
+            .unwrap()
+            .metadata
+            .as_ref()
+            .unwrap()
+            .schema
+            .clone()
     }
 
     fn constraints(&self) -> Option<&Constraints> {
@@ -547,6 +860,15 @@ impl TableProvider for KamuTable {
         None
     }
 
+    fn supports_filters_pushdown(
+        &self,
+        filters: &[&Expr],
+    ) -> datafusion::error::Result<Vec<TableProviderFilterPushDown>> {
+        // Filters are used to prune slices and row groups, but the data still has to
+        // be filtered afterwards
+        Ok(vec![TableProviderFilterPushDown::Inexact; filters.len()])
+    }
+
     async fn scan(
         &self,
         state: &dyn Session,
@@ -554,8 +876,33 @@ impl TableProvider for KamuTable {
         filters: &[Expr],
         limit: Option<usize>,
     ) -> datafusion::error::Result<Arc<dyn ExecutionPlan>> {
+        let metadata = self
+            .get_table_metadata()
+            .await
+            .map_err(|e| datafusion::error::DataFusionError::External(e.into()))?;
+
+        let pruner = SlicePruner::new(&metadata.vocab, filters);
+        let files: Vec<_> = if pruner.can_prune() {
+            self.prune_slices(state, &metadata, &pruner)
+                .await
+                .map_err(|e| datafusion::error::DataFusionError::External(e.into()))?
+        } else {
+            metadata
+                .slices
+                .iter()
+                .map(|s| s.physical_hash.clone())
+                .collect()
+        };
+
+        tracing::debug!(
+            dataset_handle = ?self.dataset_handle,
+            num_slices = metadata.slices.len(),
+            num_slices_after_pruning = files.len(),
+            "Pruned data slices",
+        );
+
         let p = self
-            .get_table_provider()
+            .init_table_provider(metadata.schema.clone(), files)
             .await
             .map_err(|e| datafusion::error::DataFusionError::External(e.into()))?;
 
@@ -563,7 +910,131 @@ impl TableProvider for KamuTable {
     }
 
     fn statistics(&self) -> Option<Statistics> {
-        None
+        let cache = self.cache.lock().unwrap();
+        let metadata = cache.metadata.as_ref()?;
+
+        let num_rows: u64 = metadata.slices.iter().map(DataSliceInfo::num_records).sum();
+
+        // Row counts come from the metadata chain and are not verified against the
+        // data files, so they can't be used for answering queries
+        let mut statistics = Statistics::new_unknown(&metadata.schema);
+        statistics.num_rows = Precision::Inexact(usize::try_from(num_rows).ok()?);
+        Some(statistics)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// Time travel
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Table functions that pin a dataset to a certain point in its history:
+///
+/// - `dataset_at('my.dataset', '<block hash or reference>')`
+/// - `dataset_as_of('my.dataset', TIMESTAMP '<system time>')`
+///
+/// Blocks they resolve to are recorded by the [`KamuSchema`] and reported as
+/// part of the [`QueryState`].
+pub(crate) struct KamuTimeTravelFunction {
+    schema: KamuSchema,
+    kind: TimeTravelFunctionKind,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub(crate) enum TimeTravelFunctionKind {
+    At,
+    AsOf,
+}
+
+impl TimeTravelFunctionKind {
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::At => "dataset_at",
+            Self::AsOf => "dataset_as_of",
+        }
+    }
+
+    pub fn from_name(name: &str) -> Option<Self> {
+        [Self::At, Self::AsOf]
+            .into_iter()
+            .find(|kind| kind.name().eq_ignore_ascii_case(name))
+    }
+}
+
+impl KamuTimeTravelFunction {
+    pub fn new(schema: KamuSchema, kind: TimeTravelFunctionKind) -> Self {
+        Self { schema, kind }
+    }
+
+    /// Parses the arguments of a call into the dataset reference and the state
+    /// selector
+    pub fn parse_args(
+        kind: TimeTravelFunctionKind,
+        args: &[Expr],
+    ) -> Result<(String, TimeTravelSelector), DataFusionError> {
+        let name = kind.name();
+        let usage = || {
+            DataFusionError::Plan(match kind {
+                TimeTravelFunctionKind::At => {
+                    format!("Usage: {name}('<dataset>', '<block hash or reference>')")
+                }
+                TimeTravelFunctionKind::AsOf => {
+                    format!("Usage: {name}('<dataset>', TIMESTAMP '<system time>')")
+                }
+            })
+        };
+
+        let [dataset_ref, selector] = args else {
+            return Err(usage());
+        };
+
+        // Fold casts like `TIMESTAMP '...'` into literals
+        let props = ExecutionProps::new();
+        let simplifier = ExprSimplifier::new(SimplifyContext::new(&props));
+        let literal = |expr: &Expr| match simplifier.simplify(expr.clone()) {
+            Ok(Expr::Literal(value)) => Some(value),
+            _ => None,
+        };
+
+        let Some(ScalarValue::Utf8(Some(dataset_ref))) = literal(dataset_ref) else {
+            return Err(usage());
+        };
+
+        let selector = match (kind, literal(selector)) {
+            (TimeTravelFunctionKind::At, Some(ScalarValue::Utf8(Some(block)))) => {
+                TimeTravelSelector::Block(block)
+            }
+            (TimeTravelFunctionKind::AsOf, Some(value)) => {
+                // Timestamps without a time zone are interpreted as UTC
+                let system_time = match value {
+                    ScalarValue::TimestampSecond(Some(v), _) => DateTime::from_timestamp(v, 0),
+                    ScalarValue::TimestampMillisecond(Some(v), _) => {
+                        DateTime::from_timestamp_millis(v)
+                    }
+                    ScalarValue::TimestampMicrosecond(Some(v), _) => {
+                        DateTime::from_timestamp_micros(v)
+                    }
+                    ScalarValue::TimestampNanosecond(Some(v), _) => {
+                        Some(DateTime::from_timestamp_nanos(v))
+                    }
+                    ScalarValue::Utf8(Some(v)) => DateTime::parse_from_rfc3339(&v)
+                        .ok()
+                        .map(|dt| dt.with_timezone(&Utc)),
+                    _ => None,
+                };
+                TimeTravelSelector::SystemTime(system_time.ok_or_else(usage)?)
+            }
+            _ => return Err(usage()),
+        };
+
+        Ok((dataset_ref, selector))
+    }
+}
+
+impl TableFunctionImpl for KamuTimeTravelFunction {
+    fn call(&self, args: &[Expr]) -> datafusion::error::Result<Arc<dyn TableProvider>> {
+        let (dataset_ref, selector) = Self::parse_args(self.kind, args)?;
+        let table = self.schema.time_travel_table(dataset_ref, selector)?;
+        Ok(table)
     }
 }
 
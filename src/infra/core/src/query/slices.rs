@@ -0,0 +1,339 @@
+// Copyright Kamu Data, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+use datafusion::arrow::datatypes::{DataType, TimeUnit};
+use datafusion::common::ScalarValue;
+use datafusion::logical_expr::{Between, BinaryExpr, Cast, InList, Operator, TryCast};
+use datafusion::parquet::basic::{ConvertedType, LogicalType, TimeUnit as ParquetTimeUnit};
+use datafusion::parquet::file::metadata::ParquetMetaData;
+use datafusion::parquet::file::statistics::Statistics as ParquetStatistics;
+use datafusion::parquet::schema::types::ColumnDescriptor;
+use datafusion::prelude::*;
+use opendatafabric::*;
+
+const NANOS_PER_DAY: i128 = 86_400 * 1_000_000_000;
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Information about a data slice that is known from the metadata chain
+/// without opening the data file
+#[derive(Debug, Clone)]
+pub(crate) struct DataSliceInfo {
+    pub physical_hash: Multihash,
+    pub offset_interval: OffsetInterval,
+}
+
+impl DataSliceInfo {
+    pub fn num_records(&self) -> u64 {
+        self.offset_interval.end - self.offset_interval.start + 1
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Ranges of values that system columns take within a slice.
+///
+/// Offsets are known exactly from the metadata chain, as even the slices
+/// rewritten by compactions declare the full interval of offsets they carry.
+/// Time columns are not bounded by the metadata (watermarks don't limit the
+/// event times), so their ranges come from the column statistics of the
+/// parquet file and are only available when the footer was read.
+#[derive(Debug, Clone)]
+pub(crate) struct SliceColumnBounds {
+    offset: Bounds,
+    system_time: Option<Bounds>,
+    event_time: Option<Bounds>,
+}
+
+impl SliceColumnBounds {
+    pub fn new(slice: &DataSliceInfo, statistics: Option<&SliceTimeStatistics>) -> Self {
+        Self {
+            offset: Bounds {
+                min: i128::from(slice.offset_interval.start),
+                max: i128::from(slice.offset_interval.end),
+            },
+            system_time: statistics.and_then(|s| s.system_time),
+            event_time: statistics.and_then(|s| s.event_time),
+        }
+    }
+}
+
+/// Ranges of values that time columns take within a slice, as recorded in the
+/// column statistics of its parquet file
+#[derive(Debug, Clone, Default)]
+pub(crate) struct SliceTimeStatistics {
+    system_time: Option<Bounds>,
+    event_time: Option<Bounds>,
+}
+
+impl SliceTimeStatistics {
+    pub fn from_parquet_metadata(metadata: &ParquetMetaData, vocab: &DatasetVocabulary) -> Self {
+        Self {
+            system_time: column_bounds_from_parquet(metadata, &vocab.system_time_column),
+            event_time: column_bounds_from_parquet(metadata, &vocab.event_time_column),
+        }
+    }
+}
+
+/// Combines min/max statistics of a column across all row groups. Returns
+/// `None` if any row group lacks statistics or values can't be interpreted.
+fn column_bounds_from_parquet(metadata: &ParquetMetaData, column: &str) -> Option<Bounds> {
+    let schema = metadata.file_metadata().schema_descr();
+    let index = (0..schema.num_columns()).find(|i| schema.column(*i).path().string() == column)?;
+    let descr = schema.column(index);
+
+    let mut bounds: Option<Bounds> = None;
+    for row_group in metadata.row_groups() {
+        let stats = row_group.column(index).statistics()?;
+        if !stats.has_min_max_set() {
+            return None;
+        }
+
+        let (min, max) = match stats {
+            ParquetStatistics::Int64(s) => (i128::from(*s.min()), i128::from(*s.max())),
+            ParquetStatistics::Int32(s) => (i128::from(*s.min()), i128::from(*s.max())),
+            _ => return None,
+        };
+
+        let scale = time_column_scale(&descr)?;
+        let (min, max) = (min * scale, max * scale);
+
+        bounds = Some(match bounds {
+            None => Bounds { min, max },
+            Some(b) => Bounds {
+                min: b.min.min(min),
+                max: b.max.max(max),
+            },
+        });
+    }
+    bounds
+}
+
+/// Number of nanoseconds in a unit of the stored time value
+fn time_column_scale(descr: &ColumnDescriptor) -> Option<i128> {
+    match descr.logical_type() {
+        Some(LogicalType::Timestamp { unit, .. }) => Some(match unit {
+            ParquetTimeUnit::MILLIS(_) => 1_000_000,
+            ParquetTimeUnit::MICROS(_) => 1_000,
+            ParquetTimeUnit::NANOS(_) => 1,
+        }),
+        Some(LogicalType::Date) => Some(NANOS_PER_DAY),
+        Some(_) => None,
+        None => match descr.converted_type() {
+            ConvertedType::TIMESTAMP_MILLIS => Some(1_000_000),
+            ConvertedType::TIMESTAMP_MICROS => Some(1_000),
+            ConvertedType::DATE => Some(NANOS_PER_DAY),
+            _ => None,
+        },
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Decides which slices can be skipped entirely given the filters pushed down
+/// into a scan.
+///
+/// Only simple comparisons of `offset`, `system_time` and `event_time` columns
+/// with literals are considered. Any other expression is assumed to possibly
+/// match, so the pruning is always conservative and filters still need to be
+/// applied to the data.
+pub(crate) struct SlicePruner<'a> {
+    vocab: &'a DatasetVocabulary,
+    filters: &'a [Expr],
+}
+
+/// Closed interval of values a column takes within a slice
+#[derive(Debug, Clone, Copy)]
+struct Bounds {
+    min: i128,
+    max: i128,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum ColumnKind {
+    Offset,
+    Time,
+}
+
+impl<'a> SlicePruner<'a> {
+    pub fn new(vocab: &'a DatasetVocabulary, filters: &'a [Expr]) -> Self {
+        Self { vocab, filters }
+    }
+
+    /// Whether any of the filters refers to the system columns, i.e. whether
+    /// any slices can be pruned at all
+    pub fn can_prune(&self) -> bool {
+        self.refers_to(&[
+            &self.vocab.offset_column,
+            &self.vocab.system_time_column,
+            &self.vocab.event_time_column,
+        ])
+    }
+
+    /// Whether any of the filters refers to the time columns, i.e. whether
+    /// reading the slice statistics can lead to pruning
+    pub fn needs_time_statistics(&self) -> bool {
+        self.refers_to(&[
+            &self.vocab.system_time_column,
+            &self.vocab.event_time_column,
+        ])
+    }
+
+    fn refers_to(&self, columns: &[&String]) -> bool {
+        self.filters.iter().any(|f| {
+            f.column_refs()
+                .iter()
+                .any(|c| columns.iter().any(|name| c.name == **name))
+        })
+    }
+
+    pub fn may_match(&self, bounds: &SliceColumnBounds) -> bool {
+        self.filters.iter().all(|f| self.expr_may_match(bounds, f))
+    }
+
+    fn expr_may_match(&self, bounds: &SliceColumnBounds, expr: &Expr) -> bool {
+        match expr {
+            Expr::BinaryExpr(BinaryExpr { left, op, right }) => match op {
+                Operator::And => {
+                    self.expr_may_match(bounds, left) && self.expr_may_match(bounds, right)
+                }
+                Operator::Or => {
+                    self.expr_may_match(bounds, left) || self.expr_may_match(bounds, right)
+                }
+                _ => {
+                    if let (Some(c), Expr::Literal(v)) = (as_column(left), right.as_ref()) {
+                        self.comparison_may_match(bounds, &c.name, *op, v)
+                    } else if let (Expr::Literal(v), Some(c), Some(op)) =
+                        (left.as_ref(), as_column(right), op.swap())
+                    {
+                        self.comparison_may_match(bounds, &c.name, op, v)
+                    } else {
+                        true
+                    }
+                }
+            },
+            Expr::Between(Between {
+                expr,
+                negated: false,
+                low,
+                high,
+            }) => match (as_column(expr), low.as_ref(), high.as_ref()) {
+                (Some(c), Expr::Literal(low), Expr::Literal(high)) => {
+                    self.comparison_may_match(bounds, &c.name, Operator::GtEq, low)
+                        && self.comparison_may_match(bounds, &c.name, Operator::LtEq, high)
+                }
+                _ => true,
+            },
+            Expr::InList(InList {
+                expr,
+                list,
+                negated: false,
+            }) => match as_column(expr) {
+                Some(c) => list.iter().any(|v| match v {
+                    Expr::Literal(v) => self.comparison_may_match(bounds, &c.name, Operator::Eq, v),
+                    _ => true,
+                }),
+                None => true,
+            },
+            _ => true,
+        }
+    }
+
+    fn comparison_may_match(
+        &self,
+        bounds: &SliceColumnBounds,
+        column: &str,
+        op: Operator,
+        value: &ScalarValue,
+    ) -> bool {
+        let Some((kind, Bounds { min, max })) = self.column_bounds(bounds, column) else {
+            return true;
+        };
+        let Some(value) = scalar_to_i128(kind, value) else {
+            return true;
+        };
+
+        match op {
+            Operator::Eq => min <= value && max >= value,
+            Operator::Lt => min < value,
+            Operator::LtEq => min <= value,
+            Operator::Gt => max > value,
+            Operator::GtEq => max >= value,
+            _ => true,
+        }
+    }
+
+    fn column_bounds(
+        &self,
+        bounds: &SliceColumnBounds,
+        column: &str,
+    ) -> Option<(ColumnKind, Bounds)> {
+        if column == self.vocab.offset_column {
+            Some((ColumnKind::Offset, bounds.offset))
+        } else if column == self.vocab.system_time_column {
+            Some((ColumnKind::Time, bounds.system_time?))
+        } else if column == self.vocab.event_time_column {
+            Some((ColumnKind::Time, bounds.event_time?))
+        } else {
+            None
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Unwraps the column from casts that type coercion may add around it. Only
+/// casts that preserve the values exactly are considered.
+fn as_column(expr: &Expr) -> Option<&Column> {
+    match expr {
+        Expr::Column(c) => Some(c),
+        Expr::Cast(Cast { expr, data_type }) | Expr::TryCast(TryCast { expr, data_type }) => {
+            match data_type {
+                DataType::Int64
+                | DataType::UInt64
+                | DataType::Timestamp(
+                    TimeUnit::Millisecond | TimeUnit::Microsecond | TimeUnit::Nanosecond,
+                    _,
+                ) => as_column(expr),
+                _ => None,
+            }
+        }
+        _ => None,
+    }
+}
+
+/// Converts a literal into a value comparable with the column bounds. Times are
+/// represented as nanoseconds since the epoch.
+fn scalar_to_i128(kind: ColumnKind, value: &ScalarValue) -> Option<i128> {
+    match (kind, value) {
+        (ColumnKind::Offset, ScalarValue::Int8(Some(v))) => Some(i128::from(*v)),
+        (ColumnKind::Offset, ScalarValue::Int16(Some(v))) => Some(i128::from(*v)),
+        (ColumnKind::Offset, ScalarValue::Int32(Some(v))) => Some(i128::from(*v)),
+        (ColumnKind::Offset, ScalarValue::Int64(Some(v))) => Some(i128::from(*v)),
+        (ColumnKind::Offset, ScalarValue::UInt8(Some(v))) => Some(i128::from(*v)),
+        (ColumnKind::Offset, ScalarValue::UInt16(Some(v))) => Some(i128::from(*v)),
+        (ColumnKind::Offset, ScalarValue::UInt32(Some(v))) => Some(i128::from(*v)),
+        (ColumnKind::Offset, ScalarValue::UInt64(Some(v))) => Some(i128::from(*v)),
+        (ColumnKind::Time, ScalarValue::TimestampSecond(Some(v), _)) => {
+            Some(i128::from(*v) * 1_000_000_000)
+        }
+        (ColumnKind::Time, ScalarValue::TimestampMillisecond(Some(v), _)) => {
+            Some(i128::from(*v) * 1_000_000)
+        }
+        (ColumnKind::Time, ScalarValue::TimestampMicrosecond(Some(v), _)) => {
+            Some(i128::from(*v) * 1_000)
+        }
+        (ColumnKind::Time, ScalarValue::TimestampNanosecond(Some(v), _)) => Some(i128::from(*v)),
+        (ColumnKind::Time, ScalarValue::Date32(Some(v))) => Some(i128::from(*v) * NANOS_PER_DAY),
+        (ColumnKind::Time, ScalarValue::Date64(Some(v))) => Some(i128::from(*v) * 1_000_000),
+        _ => None,
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
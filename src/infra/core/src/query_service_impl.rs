@@ -26,7 +26,7 @@ use kamu_core::*;
 use opendatafabric::*;
 
 use crate::query::*;
-use crate::utils::docker_images;
+use crate::{EngineRegistry, EngineSpec};
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
 
@@ -34,24 +34,35 @@ pub struct QueryServiceImpl {
     dataset_repo: Arc<dyn DatasetRepository>,
     object_store_registry: Arc<dyn ObjectStoreRegistry>,
     dataset_action_authorizer: Arc<dyn DatasetActionAuthorizer>,
+    engine_registry: Option<Arc<EngineRegistry>>,
 }
 
 #[component(pub)]
 #[interface(dyn QueryService)]
 impl QueryServiceImpl {
+    /// # Arguments
+    ///
+    /// * `engine_registry` - engines reported as known, defaults to the
+    ///   built-in engines when not present in the catalog
     pub fn new(
         dataset_repo: Arc<dyn DatasetRepository>,
         object_store_registry: Arc<dyn ObjectStoreRegistry>,
         dataset_action_authorizer: Arc<dyn DatasetActionAuthorizer>,
+        engine_registry: Option<Arc<EngineRegistry>>,
     ) -> Self {
         Self {
             dataset_repo,
             object_store_registry,
             dataset_action_authorizer,
+            engine_registry,
         }
     }
 
     fn session_context(&self, options: QueryOptions) -> SessionContext {
+        self.session_context_with_schema(options).0
+    }
+
+    fn session_context_with_schema(&self, options: QueryOptions) -> (SessionContext, KamuSchema) {
         let mut cfg = SessionConfig::new()
             .with_information_schema(true)
             .with_default_catalog_and_schema("kamu", "kamu");
@@ -72,16 +83,24 @@ impl QueryServiceImpl {
         let runtime = Arc::new(RuntimeEnv::new(runtime_config).unwrap());
         let session_context = SessionContext::new_with_config_rt(cfg, runtime);
 
-        session_context.register_catalog(
-            "kamu",
-            Arc::new(KamuCatalog::new(Arc::new(KamuSchema::new(
-                &session_context,
-                self.dataset_repo.clone(),
-                self.dataset_action_authorizer.clone(),
-                options,
-            )))),
+        let schema = KamuSchema::new(
+            &session_context,
+            self.dataset_repo.clone(),
+            self.dataset_action_authorizer.clone(),
+            options,
         );
+
+        for kind in [TimeTravelFunctionKind::At, TimeTravelFunctionKind::AsOf] {
+            session_context.register_udtf(
+                kind.name(),
+                Arc::new(KamuTimeTravelFunction::new(schema.clone(), kind)),
+            );
+        }
+
         session_context
+            .register_catalog("kamu", Arc::new(KamuCatalog::new(Arc::new(schema.clone()))));
+
+        (session_context, schema)
     }
 
     /// Unless state is already provided in the options this will attempt to
@@ -103,6 +122,7 @@ impl QueryServiceImpl {
         if let Some(aliases) = &options.aliases {
             let mut as_of_state = QueryState {
                 inputs: BTreeMap::new(),
+                time_travel_inputs: BTreeMap::new(),
             };
             for id in aliases.values() {
                 let dataset = self.dataset_repo.find_dataset_by_ref(&id.into()).await?;
@@ -133,7 +153,7 @@ impl QueryServiceImpl {
         for stmt in statements {
             match stmt {
                 Statement::Statement(stmt) => {
-                    table_refs.append(&mut extract_table_refs(&stmt)?);
+                    table_refs.append(&mut extract_table_refs(&stmt)?.tables);
                 }
                 Statement::CreateExternalTable(_)
                 | Statement::CopyTo(_)
@@ -146,6 +166,7 @@ impl QueryServiceImpl {
         let mut aliases = BTreeMap::new();
         let mut as_of_state = QueryState {
             inputs: BTreeMap::new(),
+            time_travel_inputs: BTreeMap::new(),
         };
         for mut table in table_refs {
             // Strip possible `kamu.kamu.` prefix
@@ -180,6 +201,62 @@ impl QueryServiceImpl {
         })
     }
 
+    /// Table functions cannot perform async operations, so dataset states
+    /// requested via time-travel functions are resolved before the query is
+    /// planned to avoid blocking in the middle of planning
+    async fn prepare_time_travel_tables(
+        &self,
+        ctx: &SessionContext,
+        schema: &KamuSchema,
+        sql: &str,
+    ) -> Result<(), QueryError> {
+        use datafusion::sql::parser::Statement;
+        use datafusion::sql::sqlparser::ast::{FunctionArg, FunctionArgExpr};
+
+        let statements = datafusion::sql::parser::DFParser::parse_sql(sql)
+            .map_err(|e| DataFusionError::SQL(e, None))?;
+
+        let state = ctx.state();
+        let empty_schema = datafusion::common::DFSchema::empty();
+
+        for stmt in statements {
+            let Statement::Statement(stmt) = stmt else {
+                continue;
+            };
+
+            for (name, args) in extract_table_refs(&stmt)?.functions {
+                let Some(kind) = name
+                    .0
+                    .last()
+                    .and_then(|ident| TimeTravelFunctionKind::from_name(&ident.value))
+                else {
+                    continue;
+                };
+
+                // Arguments are planned the same way DataFusion does it for the
+                // table function call, so that they can be matched later
+                let mut arg_exprs = Vec::new();
+                for arg in args {
+                    let FunctionArg::Unnamed(FunctionArgExpr::Expr(expr)) = arg else {
+                        return Err(DataFusionError::Plan(format!(
+                            "{}() does not support named arguments",
+                            kind.name()
+                        ))
+                        .into());
+                    };
+                    arg_exprs.push(state.create_logical_expr(&expr.to_string(), &empty_schema)?);
+                }
+
+                let (dataset_ref, selector) = KamuTimeTravelFunction::parse_args(kind, &arg_exprs)?;
+                schema
+                    .prepare_time_travel_table(dataset_ref, selector)
+                    .await?;
+            }
+        }
+
+        Ok(())
+    }
+
     async fn single_dataset(
         &self,
         dataset_ref: &DatasetRef,
@@ -366,10 +443,16 @@ impl QueryService for QueryServiceImpl {
             "Resolved SQL query",
         );
 
-        let state = resolved_options.as_of_state.clone().unwrap();
-        let ctx = self.session_context(options);
+        let mut state = resolved_options.as_of_state.clone().unwrap();
+        let (ctx, schema) = self.session_context_with_schema(options);
+
+        self.prepare_time_travel_tables(&ctx, &schema, statement)
+            .await?;
+
         let df = ctx.sql(statement).await?;
 
+        state.time_travel_inputs = schema.time_travel_inputs();
+
         Ok(QueryResponse { df, state })
     }
 
@@ -398,28 +481,12 @@ impl QueryService for QueryServiceImpl {
     }
 
     async fn get_known_engines(&self) -> Result<Vec<EngineDesc>, InternalError> {
-        Ok(vec![
-            EngineDesc {
-                name: "Spark".to_string(),
-                dialect: QueryDialect::SqlSpark,
-                latest_image: docker_images::SPARK.to_string(),
-            },
-            EngineDesc {
-                name: "Flink".to_string(),
-                dialect: QueryDialect::SqlFlink,
-                latest_image: docker_images::FLINK.to_string(),
-            },
-            EngineDesc {
-                name: "DataFusion".to_string(),
-                dialect: QueryDialect::SqlDataFusion,
-                latest_image: docker_images::DATAFUSION.to_string(),
-            },
-            EngineDesc {
-                name: "RisingWave".to_string(),
-                dialect: QueryDialect::SqlRisingWave,
-                latest_image: docker_images::RISINGWAVE.to_string(),
-            },
-        ])
+        let engine_registry = self.engine_registry.clone().unwrap_or_default();
+        Ok(engine_registry
+            .engines()
+            .iter()
+            .map(EngineSpec::desc)
+            .collect())
     }
 }
 
@@ -464,12 +531,22 @@ async fn read_data_slice_metadata(
 
 // TODO: This is too complex - we should explore better ways to associate a
 // query with a certain state
+#[derive(Default)]
+struct TableRefs {
+    tables: Vec<datafusion::sql::sqlparser::ast::ObjectName>,
+    /// Table function calls, like `dataset_at()`, along with their arguments
+    functions: Vec<(
+        datafusion::sql::sqlparser::ast::ObjectName,
+        Vec<datafusion::sql::sqlparser::ast::FunctionArg>,
+    )>,
+}
+
 fn extract_table_refs(
     stmt: &datafusion::sql::sqlparser::ast::Statement,
-) -> Result<Vec<datafusion::sql::sqlparser::ast::ObjectName>, QueryError> {
+) -> Result<TableRefs, QueryError> {
     use datafusion::sql::sqlparser::ast::Statement;
 
-    let mut tables = Vec::new();
+    let mut tables = TableRefs::default();
     if let Statement::Query(query) = stmt {
         extract_table_refs_rec(query, &mut tables)?;
     }
@@ -479,7 +556,7 @@ fn extract_table_refs(
 
 fn extract_table_refs_rec(
     query: &datafusion::sql::sqlparser::ast::Query,
-    tables: &mut Vec<datafusion::sql::sqlparser::ast::ObjectName>,
+    tables: &mut TableRefs,
 ) -> Result<(), QueryError> {
     if let Some(with) = &query.with {
         for cte in &with.cte_tables {
@@ -492,7 +569,9 @@ fn extract_table_refs_rec(
     // TODO: This may fail in some tricky cases like nested CTEs
     if let Some(with) = &query.with {
         for cte in &with.cte_tables {
-            tables.retain(|tn| tn.0.len() != 1 || tn.0[0] != cte.alias.name);
+            tables
+                .tables
+                .retain(|tn| tn.0.len() != 1 || tn.0[0] != cte.alias.name);
         }
     }
 
@@ -501,7 +580,7 @@ fn extract_table_refs_rec(
 
 fn extract_table_refs_rec_set_expr(
     expr: &datafusion::sql::sqlparser::ast::SetExpr,
-    tables: &mut Vec<datafusion::sql::sqlparser::ast::ObjectName>,
+    tables: &mut TableRefs,
 ) -> Result<(), QueryError> {
     use datafusion::sql::sqlparser::ast::SetExpr;
 
@@ -527,13 +606,21 @@ fn extract_table_refs_rec_set_expr(
 
 fn extract_table_refs_rec_table_factor(
     expr: &datafusion::sql::sqlparser::ast::TableFactor,
-    tables: &mut Vec<datafusion::sql::sqlparser::ast::ObjectName>,
+    tables: &mut TableRefs,
 ) -> Result<(), QueryError> {
     use datafusion::sql::sqlparser::ast::TableFactor;
 
     match expr {
+        TableFactor::Table {
+            name,
+            args: Some(args),
+            ..
+        } => {
+            tables.functions.push((name.clone(), args.clone()));
+            Ok(())
+        }
         TableFactor::Table { name, .. } => {
-            tables.push(name.clone());
+            tables.tables.push(name.clone());
             Ok(())
         }
         TableFactor::Derived { subquery, .. } => extract_table_refs_rec(subquery, tables),
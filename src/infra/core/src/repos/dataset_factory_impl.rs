@@ -14,6 +14,7 @@ use internal_error::{ErrorIntoInternal, InternalError, ResultIntoInternal};
 use kamu_core::*;
 use url::Url;
 
+use crate::utils::object_store_context::{ObjectStoreConfig, ObjectStoreContext};
 use crate::utils::s3_context::S3Context;
 use crate::*;
 
@@ -22,6 +23,7 @@ use crate::*;
 pub struct DatasetFactoryImpl {
     ipfs_gateway: IpfsGateway,
     access_token_resolver: Arc<dyn kamu_core::auth::OdfServerAccessTokenResolver>,
+    object_store_config: Arc<ObjectStoreConfig>,
 }
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -47,10 +49,12 @@ impl DatasetFactoryImpl {
     pub fn new(
         ipfs_gateway: IpfsGateway,
         access_token_resolver: Arc<dyn kamu_core::auth::OdfServerAccessTokenResolver>,
+        object_store_config: Option<Arc<ObjectStoreConfig>>,
     ) -> Self {
         Self {
             ipfs_gateway,
             access_token_resolver,
+            object_store_config: object_store_config.unwrap_or_default(),
         }
     }
 
@@ -161,6 +165,32 @@ impl DatasetFactoryImpl {
         ))
     }
 
+    /// Creates new dataset proxy for a Google Cloud Storage (`gs://`) or Azure
+    /// Blob Storage (`az://`) URL
+    pub fn get_object_store_from_url(
+        base_url: &Url,
+        config: &ObjectStoreConfig,
+    ) -> Result<impl Dataset, InternalError> {
+        let context = ObjectStoreContext::from_url_with_config(base_url, config)?;
+        Ok(Self::get_object_store_from_context(&context))
+    }
+
+    pub fn get_object_store_from_context(context: &ObjectStoreContext) -> impl Dataset {
+        DatasetImpl::new(
+            MetadataChainImpl::new(
+                MetadataBlockRepositoryCachingInMem::new(MetadataBlockRepositoryImpl::new(
+                    ObjectRepositoryObjectStoreSha3::new(context.sub_context("blocks")),
+                )),
+                ReferenceRepositoryImpl::new(NamedObjectRepositoryObjectStore::new(
+                    context.sub_context("refs"),
+                )),
+            ),
+            ObjectRepositoryObjectStoreSha3::new(context.sub_context("data")),
+            ObjectRepositoryObjectStoreSha3::new(context.sub_context("checkpoints")),
+            NamedObjectRepositoryObjectStore::new(context.sub_context("info")),
+        )
+    }
+
     async fn get_ipfs_http(&self, base_url: Url) -> Result<impl Dataset, InternalError> {
         // Resolve IPNS DNSLink names if configured
         let dataset_url = match base_url.scheme() {
@@ -325,6 +355,10 @@ impl DatasetFactory for DatasetFactoryImpl {
                 let ds = Self::get_s3_from_url(url.clone()).await?;
                 Ok(Arc::new(ds))
             }
+            "gs" | "az" => {
+                let ds = Self::get_object_store_from_url(url, &self.object_store_config)?;
+                Ok(Arc::new(ds))
+            }
             _ => Err(UnsupportedProtocolError {
                 message: None,
                 url: url.clone(),
@@ -13,7 +13,7 @@ use kamu_core::*;
 use opendatafabric::*;
 use random_names::get_random_name;
 
-use crate::DatasetRepositoryWriter;
+use crate::{DatasetRepositoryWriter, EngineRegistry};
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
 
@@ -30,6 +30,7 @@ pub(crate) async fn create_dataset_from_snapshot_impl<
     dataset_repo: &TRepository,
     mut snapshot: DatasetSnapshot,
     system_time: DateTime<Utc>,
+    engine_registry: Option<&EngineRegistry>,
 ) -> Result<CreateDatasetFromSnapshotResult, CreateDatasetFromSnapshotError> {
     // Validate / resolve events
     for event in &mut snapshot.metadata {
@@ -75,6 +76,11 @@ pub(crate) async fn create_dataset_from_snapshot_impl<
                 } else {
                     resolve_transform_inputs(&mut e.inputs, dataset_repo, &snapshot.name).await?;
                     normalize_transform(&mut e.transform)?;
+                    if let Some(engine_registry) = engine_registry {
+                        engine_registry
+                            .validate_transform(&e.transform)
+                            .map_err(|e| InvalidSnapshotError::new(e.to_string()))?;
+                    }
                     Ok(())
                 }
             }
@@ -27,17 +27,23 @@ pub struct DatasetRepositoryLocalFs {
     storage_strategy: Box<dyn DatasetStorageStrategy>,
     thrash_lock: tokio::sync::Mutex<()>,
     system_time_source: Arc<dyn SystemTimeSource>,
+    engine_registry: Option<Arc<EngineRegistry>>,
 }
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
 
 #[component(pub)]
 impl DatasetRepositoryLocalFs {
+    /// # Arguments
+    ///
+    /// * `engine_registry` - when present in the catalog transformations of the
+    ///   created datasets are validated to only use the registered engines
     pub fn new(
         root: PathBuf,
         current_account_subject: Arc<CurrentAccountSubject>,
         multi_tenant: bool,
         system_time_source: Arc<dyn SystemTimeSource>,
+        engine_registry: Option<Arc<EngineRegistry>>,
     ) -> Self {
         Self {
             storage_strategy: if multi_tenant {
@@ -50,6 +56,7 @@ impl DatasetRepositoryLocalFs {
             },
             thrash_lock: tokio::sync::Mutex::new(()),
             system_time_source,
+            engine_registry,
         }
     }
 
@@ -286,7 +293,13 @@ impl DatasetRepositoryWriter for DatasetRepositoryLocalFs {
         &self,
         snapshot: DatasetSnapshot,
     ) -> Result<CreateDatasetFromSnapshotResult, CreateDatasetFromSnapshotError> {
-        create_dataset_from_snapshot_impl(self, snapshot, self.system_time_source.now()).await
+        create_dataset_from_snapshot_impl(
+            self,
+            snapshot,
+            self.system_time_source.now(),
+            self.engine_registry.as_deref(),
+        )
+        .await
     }
 
     async fn rename_dataset(
@@ -0,0 +1,532 @@
+// Copyright Kamu Data, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+use std::path::PathBuf;
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use dill::*;
+use internal_error::{ErrorIntoInternal, InternalError, ResultIntoInternal};
+use kamu_accounts::{CurrentAccountSubject, DEFAULT_ACCOUNT_NAME_STR};
+use kamu_core::*;
+use opendatafabric::*;
+use time_source::SystemTimeSource;
+use tokio::sync::Mutex;
+use url::Url;
+
+use crate::utils::object_store_context::ObjectStoreContext;
+use crate::*;
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Dataset repository backed by cloud storages that are accessed via the
+/// generic [`object_store`] crate, like Google Cloud Storage and Azure Blob
+/// Storage
+pub struct DatasetRepositoryObjectStore {
+    context: ObjectStoreContext,
+    current_account_subject: Arc<CurrentAccountSubject>,
+    multi_tenant: bool,
+    registry_cache: Option<Arc<ObjectStoreRegistryCache>>,
+    metadata_cache_local_fs_path: Option<Arc<PathBuf>>,
+    system_time_source: Arc<dyn SystemTimeSource>,
+    engine_registry: Option<Arc<EngineRegistry>>,
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[component(pub)]
+impl DatasetRepositoryObjectStore {
+    /// # Arguments
+    ///
+    /// * `registry_cache` - when present in the catalog enables in-memory cache
+    ///   of the dataset IDs and aliases present in the repository, allowing to
+    ///   avoid expensive bucket scanning
+    ///
+    /// * `metadata_cache_local_fs_path` - when specified enables the local FS
+    ///   cache of metadata blocks, allowing to dramatically reduce the number
+    ///   of requests to the storage
+    ///
+    /// * `engine_registry` - when present in the catalog transformations of the
+    ///   created datasets are validated to only use the registered engines
+    pub fn new(
+        context: ObjectStoreContext,
+        current_account_subject: Arc<CurrentAccountSubject>,
+        multi_tenant: bool,
+        registry_cache: Option<Arc<ObjectStoreRegistryCache>>,
+        metadata_cache_local_fs_path: Option<Arc<PathBuf>>,
+        system_time_source: Arc<dyn SystemTimeSource>,
+        engine_registry: Option<Arc<EngineRegistry>>,
+    ) -> Self {
+        Self {
+            context,
+            current_account_subject,
+            multi_tenant,
+            registry_cache,
+            metadata_cache_local_fs_path,
+            system_time_source,
+            engine_registry,
+        }
+    }
+
+    fn get_dataset_impl(&self, dataset_id: &DatasetID) -> Arc<dyn Dataset> {
+        let context = self
+            .context
+            .sub_context(&dataset_id.as_multibase().to_stack_string());
+
+        // TODO: Consider switching DatasetImpl to dynamic dispatch to simplify
+        // configurability
+        if let Some(metadata_cache_local_fs_path) = &self.metadata_cache_local_fs_path {
+            Arc::new(DatasetImpl::new(
+                MetadataChainImpl::new(
+                    MetadataBlockRepositoryCachingInMem::new(MetadataBlockRepositoryImpl::new(
+                        ObjectRepositoryCachingLocalFs::new(
+                            ObjectRepositoryObjectStoreSha3::new(context.sub_context("blocks")),
+                            metadata_cache_local_fs_path.clone(),
+                        ),
+                    )),
+                    ReferenceRepositoryImpl::new(NamedObjectRepositoryObjectStore::new(
+                        context.sub_context("refs"),
+                    )),
+                ),
+                ObjectRepositoryObjectStoreSha3::new(context.sub_context("data")),
+                ObjectRepositoryObjectStoreSha3::new(context.sub_context("checkpoints")),
+                NamedObjectRepositoryObjectStore::new(context.sub_context("info")),
+            ))
+        } else {
+            Arc::new(DatasetImpl::new(
+                MetadataChainImpl::new(
+                    MetadataBlockRepositoryCachingInMem::new(MetadataBlockRepositoryImpl::new(
+                        ObjectRepositoryObjectStoreSha3::new(context.sub_context("blocks")),
+                    )),
+                    ReferenceRepositoryImpl::new(NamedObjectRepositoryObjectStore::new(
+                        context.sub_context("refs"),
+                    )),
+                ),
+                ObjectRepositoryObjectStoreSha3::new(context.sub_context("data")),
+                ObjectRepositoryObjectStoreSha3::new(context.sub_context("checkpoints")),
+                NamedObjectRepositoryObjectStore::new(context.sub_context("info")),
+            ))
+        }
+    }
+
+    async fn delete_dataset_objects(&self, dataset_id: &DatasetID) -> Result<(), InternalError> {
+        let dataset_key_prefix = self
+            .context
+            .get_key(&format!("{}/", dataset_id.as_multibase()));
+        self.context.recursive_delete(dataset_key_prefix).await
+    }
+
+    async fn resolve_dataset_alias(
+        &self,
+        dataset: &dyn Dataset,
+    ) -> Result<DatasetAlias, GetNamedError> {
+        let bytes = dataset.as_info_repo().get("alias").await?;
+        let dataset_alias_str = std::str::from_utf8(&bytes[..]).int_err()?.trim();
+        let dataset_alias = DatasetAlias::try_from(dataset_alias_str).int_err()?;
+        Ok(dataset_alias)
+    }
+
+    async fn save_dataset_alias(
+        &self,
+        dataset: &dyn Dataset,
+        dataset_alias: &DatasetAlias,
+    ) -> Result<(), InternalError> {
+        dataset
+            .as_info_repo()
+            .set("alias", dataset_alias.to_string().as_bytes())
+            .await
+            .int_err()?;
+
+        Ok(())
+    }
+
+    #[tracing::instrument(level = "info", skip_all)]
+    async fn list_datasets_in_storage(&self) -> Result<Vec<DatasetHandle>, InternalError> {
+        let mut res = Vec::new();
+
+        let folders_common_prefixes = self.context.bucket_list_folders().await?;
+
+        for prefix in folders_common_prefixes {
+            if let Ok(id) = DatasetID::from_multibase_string(&prefix) {
+                let dataset = self.get_dataset_impl(&id);
+                let dataset_alias = match self.resolve_dataset_alias(dataset.as_ref()).await {
+                    Ok(alias) => Ok(alias),
+                    Err(GetNamedError::NotFound(_)) => {
+                        tracing::warn!(
+                            prefix,
+                            "Found dataset entry without a valid alias - this is likely a result \
+                             of interrupted creation or a push - ignoring this entry and leaving \
+                             it to be cleaned up by GC."
+                        );
+                        continue;
+                    }
+                    Err(err) => Err(err),
+                }
+                .int_err()?;
+
+                res.push(DatasetHandle::new(id, dataset_alias));
+            }
+        }
+
+        Ok(res)
+    }
+
+    async fn list_datasets_maybe_cached(&self) -> Result<Vec<DatasetHandle>, InternalError> {
+        if let Some(cache) = &self.registry_cache {
+            let mut cache = cache.state.lock().await;
+
+            // Init cache
+            if cache.last_updated == DateTime::UNIX_EPOCH {
+                tracing::debug!("Initializing dataset registry cache");
+                cache.datasets = self.list_datasets_in_storage().await?;
+                cache.last_updated = self.system_time_source.now();
+            }
+
+            Ok(cache.datasets.clone())
+        } else {
+            self.list_datasets_in_storage().await
+        }
+    }
+
+    fn stream_datasets_if<'s>(
+        &'s self,
+        alias_filter: impl Fn(&DatasetAlias) -> bool + Send + 's,
+    ) -> DatasetHandleStream<'s> {
+        Box::pin(async_stream::try_stream! {
+            for hdl in self.list_datasets_maybe_cached().await? {
+                if alias_filter(&hdl.alias) {
+                    yield hdl;
+                }
+            }
+        })
+    }
+
+    fn normalize_alias(&self, alias: &DatasetAlias) -> DatasetAlias {
+        if alias.is_multi_tenant() {
+            alias.clone()
+        } else if self.is_multi_tenant() {
+            match self.current_account_subject.as_ref() {
+                CurrentAccountSubject::Anonymous(_) => {
+                    panic!("Anonymous account misused, use multi-tenant alias");
+                }
+                CurrentAccountSubject::Logged(l) => {
+                    DatasetAlias::new(Some(l.account_name.clone()), alias.dataset_name.clone())
+                }
+            }
+        } else {
+            DatasetAlias::new(None, alias.dataset_name.clone())
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[async_trait]
+impl DatasetRegistry for DatasetRepositoryObjectStore {
+    async fn get_dataset_url(&self, dataset_ref: &DatasetRef) -> Result<Url, GetDatasetUrlError> {
+        let dataset_handle = self.resolve_dataset_ref(dataset_ref).await?;
+        let dataset_key = self
+            .context
+            .get_key(&format!("{}/", dataset_handle.id.as_multibase()));
+        Ok(self.context.bucket_url().join(&dataset_key).unwrap())
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[async_trait]
+impl DatasetRepository for DatasetRepositoryObjectStore {
+    fn is_multi_tenant(&self) -> bool {
+        self.multi_tenant
+    }
+
+    async fn resolve_dataset_ref(
+        &self,
+        dataset_ref: &DatasetRef,
+    ) -> Result<DatasetHandle, GetDatasetError> {
+        match dataset_ref {
+            DatasetRef::Handle(h) => Ok(h.clone()),
+            DatasetRef::Alias(alias) => {
+                // TODO: this is really really slow and expensive!
+                let normalized_alias = self.normalize_alias(alias);
+                use futures::StreamExt;
+                let mut datasets = self.get_all_datasets();
+                while let Some(hdl) = datasets.next().await {
+                    let hdl = hdl?;
+                    if hdl.alias == normalized_alias {
+                        return Ok(hdl);
+                    }
+                }
+                Err(GetDatasetError::NotFound(DatasetNotFoundError {
+                    dataset_ref: dataset_ref.clone(),
+                }))
+            }
+            DatasetRef::ID(id) => {
+                if self
+                    .context
+                    .bucket_path_exists(id.as_multibase().to_stack_string().as_str())
+                    .await?
+                {
+                    let dataset = self.get_dataset_impl(id);
+                    let dataset_alias = self
+                        .resolve_dataset_alias(dataset.as_ref())
+                        .await
+                        .int_err()?;
+                    Ok(DatasetHandle::new(id.clone(), dataset_alias))
+                } else {
+                    Err(GetDatasetError::NotFound(DatasetNotFoundError {
+                        dataset_ref: dataset_ref.clone(),
+                    }))
+                }
+            }
+        }
+    }
+
+    fn get_all_datasets(&self) -> DatasetHandleStream<'_> {
+        self.stream_datasets_if(|_| true)
+    }
+
+    fn get_datasets_by_owner(&self, account_name: &AccountName) -> DatasetHandleStream<'_> {
+        if !self.is_multi_tenant() && *account_name != DEFAULT_ACCOUNT_NAME_STR {
+            return Box::pin(futures::stream::empty());
+        }
+
+        let account_name = account_name.clone();
+        self.stream_datasets_if(move |dataset_alias| {
+            if let Some(dataset_account_name) = &dataset_alias.account_name {
+                *dataset_account_name == account_name
+            } else {
+                true
+            }
+        })
+    }
+
+    async fn find_dataset_by_ref(
+        &self,
+        dataset_ref: &DatasetRef,
+    ) -> Result<Arc<dyn Dataset>, GetDatasetError> {
+        let dataset_handle = self.resolve_dataset_ref(dataset_ref).await?;
+        let dataset = self.get_dataset_impl(&dataset_handle.id);
+        Ok(dataset)
+    }
+
+    fn get_dataset_by_handle(&self, dataset_handle: &DatasetHandle) -> Arc<dyn Dataset> {
+        self.get_dataset_impl(&dataset_handle.id)
+    }
+}
+
+#[async_trait]
+impl DatasetRepositoryWriter for DatasetRepositoryObjectStore {
+    async fn create_dataset(
+        &self,
+        dataset_alias: &DatasetAlias,
+        seed_block: MetadataBlockTyped<Seed>,
+    ) -> Result<CreateDatasetResult, CreateDatasetError> {
+        // TODO: AUTH: Introduce AccountActionAuthorizer to check whether the current
+        // subject has permissions to create dataset under the account specified in the
+        // dataset_alias.
+        let dataset_alias = self.normalize_alias(dataset_alias);
+
+        // Check if a dataset with the same alias can be resolved successfully
+        let maybe_existing_dataset_handle = match self
+            .resolve_dataset_ref(&dataset_alias.as_local_ref())
+            .await
+        {
+            Ok(existing_handle) => Ok(Some(existing_handle)),
+            Err(GetDatasetError::NotFound(_)) => Ok(None),
+            Err(GetDatasetError::Internal(e)) => Err(e),
+        }?;
+
+        // If so, there are 2 possibilities:
+        // - Dataset was partially created before (no head yet) and was not GC'd - so we
+        //   assume ownership
+        // - Dataset existed before (has valid head) - we should error out with name
+        //   collision
+        if let Some(existing_dataset_handle) = maybe_existing_dataset_handle {
+            let existing_dataset = self.get_dataset_by_handle(&existing_dataset_handle);
+
+            match existing_dataset
+                .as_metadata_chain()
+                .resolve_ref(&BlockRef::Head)
+                .await
+            {
+                // Existing head
+                Ok(_) => {
+                    return Err(CreateDatasetError::NameCollision(NameCollisionError {
+                        alias: dataset_alias.clone(),
+                    }));
+                }
+
+                // No head, so continue creating
+                Err(GetRefError::NotFound(_)) => {}
+
+                // Errors...
+                Err(GetRefError::Access(e)) => {
+                    return Err(CreateDatasetError::Internal(e.int_err()))
+                }
+                Err(GetRefError::Internal(e)) => return Err(CreateDatasetError::Internal(e)),
+            }
+        }
+
+        // It's okay to create a new dataset by this point
+
+        let dataset_handle =
+            DatasetHandle::new(seed_block.event.dataset_id.clone(), dataset_alias.clone());
+        let dataset = self.get_dataset_impl(&dataset_handle.id);
+
+        // There are three possibilities at this point:
+        // - Dataset did not exist before - continue normally
+        // - Dataset was partially created before (no head yet) and was not GC'd - so we
+        //   assume ownership
+        // - Dataset existed before (has valid head) - we should error out with name
+        //   collision
+        let head = match dataset
+            .as_metadata_chain()
+            .append(
+                seed_block.into(),
+                AppendOpts {
+                    // We are using head ref CAS to detect previous existence of a dataset
+                    // as atomically as possible
+                    check_ref_is: Some(None),
+                    ..AppendOpts::default()
+                },
+            )
+            .await
+        {
+            Ok(head) => head,
+            Err(err) => {
+                return Err(match err {
+                    AppendError::RefCASFailed(_) => {
+                        CreateDatasetError::RefCollision(RefCollisionError {
+                            id: dataset_handle.id,
+                        })
+                    }
+                    _ => err.int_err().into(),
+                })
+            }
+        };
+
+        self.save_dataset_alias(dataset.as_ref(), &dataset_alias)
+            .await?;
+
+        // Update cache if enabled
+        if let Some(cache) = &self.registry_cache {
+            let mut cache = cache.state.lock().await;
+            cache.datasets.push(dataset_handle.clone());
+        }
+
+        tracing::info!(
+            id = %dataset_handle.id,
+            alias = %dataset_handle.alias,
+            %head,
+            "Created new dataset",
+        );
+
+        Ok(CreateDatasetResult {
+            dataset_handle,
+            dataset,
+            head,
+        })
+    }
+
+    async fn create_dataset_from_snapshot(
+        &self,
+        snapshot: DatasetSnapshot,
+    ) -> Result<CreateDatasetFromSnapshotResult, CreateDatasetFromSnapshotError> {
+        create_dataset_from_snapshot_impl(
+            self,
+            snapshot,
+            self.system_time_source.now(),
+            self.engine_registry.as_deref(),
+        )
+        .await
+    }
+
+    async fn rename_dataset(
+        &self,
+        dataset_handle: &DatasetHandle,
+        new_name: &DatasetName,
+    ) -> Result<(), RenameDatasetError> {
+        let dataset = self.get_dataset_impl(&dataset_handle.id);
+
+        let new_alias =
+            DatasetAlias::new(dataset_handle.alias.account_name.clone(), new_name.clone());
+
+        // Note: should collision check be moved to use case level?
+        match self.resolve_dataset_ref(&new_alias.as_local_ref()).await {
+            Ok(_) => Err(RenameDatasetError::NameCollision(NameCollisionError {
+                alias: DatasetAlias::new(
+                    dataset_handle.alias.account_name.clone(),
+                    new_name.clone(),
+                ),
+            })),
+            Err(GetDatasetError::Internal(e)) => Err(RenameDatasetError::Internal(e)),
+            Err(GetDatasetError::NotFound(_)) => Ok(()),
+        }?;
+
+        // It's safe to rename dataset
+        self.save_dataset_alias(dataset.as_ref(), &new_alias)
+            .await?;
+
+        // Update cache if enabled
+        if let Some(cache) = &self.registry_cache {
+            let mut cache = cache.state.lock().await;
+            cache.datasets.retain(|h| h.id != dataset_handle.id);
+            cache
+                .datasets
+                .push(DatasetHandle::new(dataset_handle.id.clone(), new_alias));
+        }
+
+        Ok(())
+    }
+
+    async fn delete_dataset(
+        &self,
+        dataset_handle: &DatasetHandle,
+    ) -> Result<(), DeleteDatasetError> {
+        self.delete_dataset_objects(&dataset_handle.id)
+            .await
+            .map_err(DeleteDatasetError::Internal)?;
+
+        // Update cache if enabled
+        if let Some(cache) = &self.registry_cache {
+            let mut cache = cache.state.lock().await;
+            cache.datasets.retain(|h| h.id != dataset_handle.id);
+        }
+
+        Ok(())
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+pub struct ObjectStoreRegistryCache {
+    state: Arc<Mutex<State>>,
+}
+
+struct State {
+    datasets: Vec<DatasetHandle>,
+    last_updated: DateTime<Utc>,
+}
+
+#[component(pub)]
+#[dill::scope(Singleton)]
+impl ObjectStoreRegistryCache {
+    pub fn new() -> Self {
+        Self {
+            state: Arc::new(Mutex::new(State {
+                datasets: Vec::new(),
+                last_updated: DateTime::UNIX_EPOCH,
+            })),
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -33,6 +33,7 @@ pub struct DatasetRepositoryS3 {
     registry_cache: Option<Arc<S3RegistryCache>>,
     metadata_cache_local_fs_path: Option<Arc<PathBuf>>,
     system_time_source: Arc<dyn SystemTimeSource>,
+    engine_registry: Option<Arc<EngineRegistry>>,
 }
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -48,6 +49,9 @@ impl DatasetRepositoryS3 {
     /// * `metadata_cache_local_fs_path` - when specified enables the local FS
     ///   cache of metadata blocks, allowing to dramatically reduce the number
     ///   of requests to S3
+    ///
+    /// * `engine_registry` - when present in the catalog transformations of the
+    ///   created datasets are validated to only use the registered engines
     pub fn new(
         s3_context: S3Context,
         current_account_subject: Arc<CurrentAccountSubject>,
@@ -55,6 +59,7 @@ impl DatasetRepositoryS3 {
         registry_cache: Option<Arc<S3RegistryCache>>,
         metadata_cache_local_fs_path: Option<Arc<PathBuf>>,
         system_time_source: Arc<dyn SystemTimeSource>,
+        engine_registry: Option<Arc<EngineRegistry>>,
     ) -> Self {
         Self {
             s3_context,
@@ -63,6 +68,7 @@ impl DatasetRepositoryS3 {
             registry_cache,
             metadata_cache_local_fs_path,
             system_time_source,
+            engine_registry,
         }
     }
 
@@ -483,7 +489,13 @@ impl DatasetRepositoryWriter for DatasetRepositoryS3 {
         &self,
         snapshot: DatasetSnapshot,
     ) -> Result<CreateDatasetFromSnapshotResult, CreateDatasetFromSnapshotError> {
-        create_dataset_from_snapshot_impl(self, snapshot, self.system_time_source.now()).await
+        create_dataset_from_snapshot_impl(
+            self,
+            snapshot,
+            self.system_time_source.now(),
+            self.engine_registry.as_deref(),
+        )
+        .await
     }
 
     async fn rename_dataset(
@@ -400,10 +400,14 @@ impl ValidateSetPollingSourceVisitor {
                 }
 
                 // Eth source must identify the chain
-                if let FetchStep::EthereumLogs(f) = &e.fetch {
-                    if f.chain_id.is_none() && f.node_url.is_none() {
-                        invalid_event!(e.clone(), "Eth source must specify chainId or nodeUrl")
-                    }
+                let eth_chain = match &e.fetch {
+                    FetchStep::EthereumLogs(f) => Some((f.chain_id, &f.node_url)),
+                    FetchStep::EthereumBlocks(f) => Some((f.chain_id, &f.node_url)),
+                    FetchStep::EthereumTransactions(f) => Some((f.chain_id, &f.node_url)),
+                    _ => None,
+                };
+                if let Some((None, None)) = eth_chain {
+                    invalid_event!(e.clone(), "Eth source must specify chainId or nodeUrl")
                 }
 
                 true
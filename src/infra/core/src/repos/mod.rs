@@ -11,6 +11,7 @@ mod dataset_factory_impl;
 mod dataset_impl;
 mod dataset_repository_helpers;
 mod dataset_repository_local_fs;
+mod dataset_repository_object_store;
 mod dataset_repository_s3;
 mod dataset_repository_writer;
 mod metadata_block_repository_caching_inmem;
@@ -22,12 +23,15 @@ mod named_object_repository_http;
 mod named_object_repository_in_memory;
 mod named_object_repository_ipfs_http;
 mod named_object_repository_local_fs;
+mod named_object_repository_object_store;
 mod named_object_repository_s3;
 mod object_repository_caching_local_fs;
 mod object_repository_http;
 mod object_repository_in_memory;
 mod object_repository_local_fs;
+mod object_repository_object_store;
 mod object_repository_s3;
+mod object_store_builder_cloud_storage;
 mod object_store_builder_local_fs;
 mod object_store_builder_s3;
 mod object_store_registry_impl;
@@ -37,6 +41,7 @@ pub use dataset_factory_impl::*;
 pub use dataset_impl::*;
 pub use dataset_repository_helpers::*;
 pub use dataset_repository_local_fs::*;
+pub use dataset_repository_object_store::*;
 pub use dataset_repository_s3::*;
 pub use dataset_repository_writer::*;
 pub use metadata_block_repository_caching_inmem::*;
@@ -47,12 +52,15 @@ pub use named_object_repository_http::*;
 pub use named_object_repository_in_memory::*;
 pub use named_object_repository_ipfs_http::*;
 pub use named_object_repository_local_fs::*;
+pub use named_object_repository_object_store::*;
 pub use named_object_repository_s3::*;
 pub use object_repository_caching_local_fs::*;
 pub use object_repository_http::*;
 pub use object_repository_in_memory::*;
 pub use object_repository_local_fs::*;
+pub use object_repository_object_store::*;
 pub use object_repository_s3::*;
+pub use object_store_builder_cloud_storage::*;
 pub use object_store_builder_local_fs::*;
 pub use object_store_builder_s3::*;
 pub use object_store_registry_impl::*;
@@ -0,0 +1,73 @@
+// Copyright Kamu Data, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+use async_trait::async_trait;
+use bytes::Bytes;
+use kamu_core::*;
+
+use crate::utils::object_store_context::ObjectStoreContext;
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+pub struct NamedObjectRepositoryObjectStore {
+    context: ObjectStoreContext,
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+impl NamedObjectRepositoryObjectStore {
+    pub fn new(context: ObjectStoreContext) -> Self {
+        Self { context }
+    }
+
+    fn get_key(&self, name: &str) -> String {
+        self.context.get_key(name)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[async_trait]
+impl NamedObjectRepository for NamedObjectRepositoryObjectStore {
+    async fn get(&self, name: &str) -> Result<Bytes, GetNamedError> {
+        let key = self.get_key(name);
+
+        tracing::debug!(?key, "Reading object");
+
+        // TODO: Detect credentials error
+        match self.context.get_object_bytes(&key).await? {
+            Some(bytes) => Ok(bytes),
+            None => Err(GetNamedError::NotFound(NotFoundError {
+                name: name.to_owned(),
+            })),
+        }
+    }
+
+    async fn set(&self, name: &str, data: &[u8]) -> Result<(), SetNamedError> {
+        let key = self.get_key(name);
+
+        tracing::debug!(?key, "Inserting object");
+
+        // TODO: Detect credentials error
+        self.context.put_object(&key, data).await?;
+
+        Ok(())
+    }
+
+    async fn delete(&self, name: &str) -> Result<(), DeleteNamedError> {
+        let key = self.get_key(name);
+
+        tracing::debug!(?key, "Deleting object");
+
+        // TODO: Detect credentials error
+        self.context.delete_object(&key).await?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,216 @@
+// Copyright Kamu Data, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+use std::convert::TryFrom;
+use std::marker::PhantomData;
+use std::path::Path;
+
+use async_trait::async_trait;
+use bytes::Bytes;
+use internal_error::ResultIntoInternal;
+use kamu_core::*;
+use opendatafabric::{Multicodec, Multihash};
+use url::Url;
+
+use crate::utils::object_store_context::{AsyncReadObj, ObjectStoreContext};
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+pub type ObjectRepositoryObjectStoreSha3 =
+    ObjectRepositoryObjectStore<sha3::Sha3_256, { Multicodec::Sha3_256 as u32 }>;
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Object repository backed by any storage supported by the generic
+/// [`object_store`] crate (e.g. GCS and Azure Blob Storage)
+// TODO: Pass a single type that configures digest algo, multicodec, and hash
+//       base
+pub struct ObjectRepositoryObjectStore<D, const C: u32> {
+    context: ObjectStoreContext,
+    _phantom: PhantomData<D>,
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+impl<D, const C: u32> ObjectRepositoryObjectStore<D, C>
+where
+    D: Send + Sync,
+    D: digest::Digest,
+{
+    pub fn new(context: ObjectStoreContext) -> Self {
+        Self {
+            context,
+            _phantom: PhantomData,
+        }
+    }
+
+    fn get_key(&self, hash: &Multihash) -> String {
+        self.context.get_key(&hash.as_multibase().to_stack_string())
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[async_trait]
+impl<D, const C: u32> ObjectRepository for ObjectRepositoryObjectStore<D, C>
+where
+    D: Send + Sync,
+    D: digest::Digest,
+{
+    fn protocol(&self) -> ObjectRepositoryProtocol {
+        match self.context.scheme.as_str() {
+            "gs" => ObjectRepositoryProtocol::Gcs,
+            "az" => ObjectRepositoryProtocol::Azure,
+            scheme => unreachable!("Unexpected object store scheme: {scheme}"),
+        }
+    }
+
+    async fn contains(&self, hash: &Multihash) -> Result<bool, ContainsError> {
+        let key = self.get_key(hash);
+
+        tracing::debug!(?key, "Checking for object");
+
+        // TODO: Detect credentials error
+        Ok(self.context.head_object(&key).await?.is_some())
+    }
+
+    async fn get_size(&self, hash: &Multihash) -> Result<u64, GetError> {
+        let key = self.get_key(hash);
+
+        tracing::debug!(?key, "Checking for object");
+
+        match self.context.head_object(&key).await? {
+            Some(meta) => u64::try_from(meta.size).int_err().map_err(Into::into),
+            None => Err(GetError::NotFound(ObjectNotFoundError {
+                hash: hash.clone(),
+            })),
+        }
+    }
+
+    async fn get_bytes(&self, hash: &Multihash) -> Result<Bytes, GetError> {
+        let key = self.get_key(hash);
+
+        tracing::debug!(?key, "Reading object");
+
+        match self.context.get_object_bytes(&key).await? {
+            Some(bytes) => Ok(bytes),
+            None => Err(GetError::NotFound(ObjectNotFoundError {
+                hash: hash.clone(),
+            })),
+        }
+    }
+
+    async fn get_stream(&self, hash: &Multihash) -> Result<Box<AsyncReadObj>, GetError> {
+        let key = self.get_key(hash);
+
+        tracing::debug!(?key, "Reading object stream");
+
+        match self.context.get_object(&key).await? {
+            Some(stream) => Ok(stream),
+            None => Err(GetError::NotFound(ObjectNotFoundError {
+                hash: hash.clone(),
+            })),
+        }
+    }
+
+    async fn get_internal_url(&self, hash: &Multihash) -> Url {
+        self.context.bucket_url().join(&self.get_key(hash)).unwrap()
+    }
+
+    async fn get_external_download_url(
+        &self,
+        _hash: &Multihash,
+        _opts: ExternalTransferOpts,
+    ) -> Result<GetExternalUrlResult, GetExternalUrlError> {
+        // TODO: Support signed URLs for GCS and SAS tokens for Azure
+        Err(GetExternalUrlError::NotSupported)
+    }
+
+    async fn get_external_upload_url(
+        &self,
+        _hash: &Multihash,
+        _opts: ExternalTransferOpts,
+    ) -> Result<GetExternalUrlResult, GetExternalUrlError> {
+        // TODO: Support signed URLs for GCS and SAS tokens for Azure
+        Err(GetExternalUrlError::NotSupported)
+    }
+
+    async fn insert_bytes<'a>(
+        &'a self,
+        data: &'a [u8],
+        options: InsertOpts<'a>,
+    ) -> Result<InsertResult, InsertError> {
+        let hash = if let Some(hash) = options.precomputed_hash {
+            hash.clone()
+        } else {
+            Multihash::from_digest::<D>(Multicodec::try_from(C).unwrap(), data)
+        };
+
+        if let Some(expected_hash) = options.expected_hash {
+            if *expected_hash != hash {
+                return Err(InsertError::HashMismatch(HashMismatchError {
+                    expected: expected_hash.clone(),
+                    actual: hash,
+                }));
+            }
+        }
+
+        let key = self.get_key(&hash);
+
+        tracing::debug!(?key, "Inserting object");
+
+        // TODO: Detect credentials error
+        self.context.put_object(&key, data).await?;
+
+        Ok(InsertResult { hash })
+    }
+
+    async fn insert_stream<'a>(
+        &'a self,
+        src: Box<AsyncReadObj>,
+        options: InsertOpts<'a>,
+    ) -> Result<InsertResult, InsertError> {
+        let hash = if let Some(hash) = options.precomputed_hash {
+            hash.clone()
+        } else {
+            panic!("Writing steam into object store only supports pre-computed hashes")
+        };
+
+        let key = self.get_key(&hash);
+
+        tracing::debug!(?key, size = ?options.size_hint, "Inserting object stream");
+
+        // TODO: Detect credentials error
+        self.context.put_object_stream(&key, src).await?;
+
+        Ok(InsertResult { hash })
+    }
+
+    async fn insert_file_move<'a>(
+        &'a self,
+        src: &Path,
+        options: InsertOpts<'a>,
+    ) -> Result<InsertResult, InsertError> {
+        let file = tokio::fs::File::open(src).await.int_err()?;
+        let insert_result = self.insert_stream(Box::new(file), options).await?;
+        tokio::fs::remove_file(src).await.int_err()?;
+        Ok(insert_result)
+    }
+
+    async fn delete(&self, hash: &Multihash) -> Result<(), DeleteError> {
+        let key = self.get_key(hash);
+
+        tracing::debug!(?key, "Deleting object");
+
+        // TODO: Detect credentials error
+        self.context.delete_object(&key).await?;
+
+        Ok(())
+    }
+}
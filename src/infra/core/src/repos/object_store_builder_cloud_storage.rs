@@ -0,0 +1,55 @@
+// Copyright Kamu Data, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+use std::sync::Arc;
+
+use dill::*;
+use internal_error::InternalError;
+use kamu_core::*;
+use url::Url;
+
+use crate::utils::object_store_context::ObjectStoreContext;
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Registers the GCS or Azure Blob Storage bucket of the
+/// [`ObjectStoreContext`] with DataFusion. Unlike S3, the store instance is
+/// shared with the dataset repository, so the credentials are resolved only
+/// once.
+#[component(pub)]
+pub struct ObjectStoreBuilderCloudStorage {
+    context: ObjectStoreContext,
+}
+
+impl ObjectStoreBuilderCloudStorage {
+    pub fn new(context: ObjectStoreContext) -> Self {
+        Self { context }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+impl ObjectStoreBuilder for ObjectStoreBuilderCloudStorage {
+    fn object_store_url(&self) -> Url {
+        self.context.bucket_url()
+    }
+
+    #[tracing::instrument(
+        level = "info", skip_all,
+        fields(
+            scheme=self.context.scheme,
+            bucket=self.context.bucket,
+        ),
+    )]
+    fn build_object_store(&self) -> Result<Arc<dyn object_store::ObjectStore>, InternalError> {
+        Ok(self.context.store.clone())
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -16,17 +16,25 @@ use opendatafabric::*;
 use serde_json::json;
 use url::Url;
 
+use crate::utils::object_store_context::{ObjectStoreConfig, ObjectStoreContext};
 use crate::utils::s3_context::S3Context;
 
 pub struct SearchServiceImpl {
     remote_repo_reg: Arc<dyn RemoteRepositoryRegistry>,
+    object_store_config: Arc<ObjectStoreConfig>,
 }
 
 #[component(pub)]
 #[interface(dyn SearchService)]
 impl SearchServiceImpl {
-    pub fn new(remote_repo_reg: Arc<dyn RemoteRepositoryRegistry>) -> Self {
-        Self { remote_repo_reg }
+    pub fn new(
+        remote_repo_reg: Arc<dyn RemoteRepositoryRegistry>,
+        object_store_config: Option<Arc<ObjectStoreConfig>>,
+    ) -> Self {
+        Self {
+            remote_repo_reg,
+            object_store_config: object_store_config.unwrap_or_default(),
+        }
     }
 
     fn search_in_repo_localfs(
@@ -100,6 +108,37 @@ impl SearchServiceImpl {
         Ok(datasets)
     }
 
+    async fn search_in_repo_object_store(
+        &self,
+        url: &Url,
+        query: Option<&str>,
+        repo_name: &RepoName,
+    ) -> Result<Vec<SearchResultDataset>, SearchError> {
+        let mut datasets = Vec::new();
+
+        let context = ObjectStoreContext::from_url_with_config(url, &self.object_store_config)?;
+        let folders = context.bucket_list_folders().await?;
+
+        let query = query.unwrap_or_default();
+
+        for folder in folders {
+            let name = DatasetName::try_from(folder).int_err()?;
+
+            if query.is_empty() || name.contains(query) {
+                datasets.push(SearchResultDataset {
+                    id: None,
+                    alias: DatasetAliasRemote::new(repo_name.clone(), None, name),
+                    kind: None,
+                    num_blocks: None,
+                    num_records: None,
+                    estimated_size: None,
+                });
+            }
+        }
+
+        Ok(datasets)
+    }
+
     // TODO: This is a quick and dirty implementation that will soon be replaced
     async fn search_in_repo_odf(
         &self,
@@ -192,6 +231,10 @@ impl SearchServiceImpl {
         match url.scheme() {
             "file" => self.search_in_repo_localfs(url, query, repo_name),
             "s3" | "s3+http" | "s3+https" => self.search_in_repo_s3(url, query, repo_name).await,
+            "gs" | "az" => {
+                self.search_in_repo_object_store(url, query, repo_name)
+                    .await
+            }
             "odf+http" | "odf+https" => self.search_in_repo_odf(url, query, repo_name).await,
             _ => Err(UnsupportedProtocolError {
                 message: None,
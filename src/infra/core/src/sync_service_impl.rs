@@ -14,6 +14,7 @@ use internal_error::{ErrorIntoInternal, InternalError, ResultIntoInternal};
 use kamu_core::services::sync_service::DatasetNotFoundError;
 use kamu_core::utils::metadata_chain_comparator::*;
 use kamu_core::*;
+use messaging_outbox::Outbox;
 use opendatafabric::*;
 use url::Url;
 
@@ -33,6 +34,7 @@ pub struct SyncServiceImpl {
     dataset_factory: Arc<dyn DatasetFactory>,
     smart_transfer_protocol: Arc<dyn SmartTransferProtocolClient>,
     ipfs_client: Arc<IpfsClient>,
+    outbox: Arc<dyn Outbox>,
 }
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -48,6 +50,7 @@ impl SyncServiceImpl {
         dataset_factory: Arc<dyn DatasetFactory>,
         smart_transfer_protocol: Arc<dyn SmartTransferProtocolClient>,
         ipfs_client: Arc<IpfsClient>,
+        outbox: Arc<dyn Outbox>,
     ) -> Self {
         Self {
             remote_repo_reg,
@@ -57,6 +60,7 @@ impl SyncServiceImpl {
             dataset_factory,
             smart_transfer_protocol,
             ipfs_client,
+            outbox,
         }
     }
 
@@ -210,7 +214,7 @@ impl SyncServiceImpl {
 
         tracing::info!("Starting sync using Simple Transfer Protocol");
 
-        SimpleTransferProtocol
+        SimpleTransferProtocol::new(self.outbox.clone())
             .sync(
                 &src_ref.as_any_ref(),
                 src_dataset,
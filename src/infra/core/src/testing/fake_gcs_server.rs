@@ -0,0 +1,120 @@
+// Copyright Kamu Data, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+use std::path::Path;
+use std::time::Duration;
+
+use container_runtime::{ContainerProcess, ContainerRuntime};
+use url::Url;
+
+use super::TEST_BUCKET_NAME;
+use crate::utils::docker_images;
+use crate::utils::object_store_context::ObjectStoreConfig;
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+pub struct FakeGcsServer {
+    pub container_name: String,
+    pub address: String,
+    pub host_port: u16,
+    #[allow(dead_code)]
+    container: ContainerProcess,
+}
+
+impl FakeGcsServer {
+    pub const IMAGE: &'static str = docker_images::FAKE_GCS_SERVER;
+
+    pub async fn new(server_dir: &Path) -> Self {
+        let container_runtime = ContainerRuntime::default();
+
+        container_runtime
+            .ensure_image(Self::IMAGE, None)
+            .await
+            .unwrap();
+
+        let server_port = 4443;
+
+        if !server_dir.exists() {
+            std::fs::create_dir(server_dir).unwrap();
+        }
+
+        // Sub-directories of the data dir are loaded as buckets on startup
+        let container = container_runtime
+            .run_attached(Self::IMAGE)
+            .random_container_name_with_prefix("kamu-test-fake-gcs-")
+            .args(["-scheme", "http", "-data", "/data"])
+            .expose_port(server_port)
+            .volume((server_dir, "/data"))
+            .stdout(std::process::Stdio::null())
+            .stderr(std::process::Stdio::null())
+            .spawn()
+            .unwrap();
+
+        let host_port = container
+            .wait_for_host_socket(server_port, Duration::from_secs(20))
+            .await
+            .unwrap();
+
+        let address = container_runtime.get_runtime_host_addr();
+
+        Self {
+            container_name: container.container_name().to_string(),
+            container,
+            address,
+            host_port,
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+pub struct LocalGcsServer {
+    pub tmp_dir: tempfile::TempDir,
+    pub server: FakeGcsServer,
+    pub bucket: String,
+    pub url: Url,
+    /// Points the GCS client to the emulator and disables OAuth
+    pub object_store_config: ObjectStoreConfig,
+}
+
+impl LocalGcsServer {
+    pub async fn new() -> Self {
+        let tmp_dir = tempfile::tempdir().unwrap();
+        let bucket = TEST_BUCKET_NAME.to_string();
+        std::fs::create_dir(tmp_dir.path().join(&bucket)).unwrap();
+
+        let server = FakeGcsServer::new(tmp_dir.path()).await;
+
+        let service_account_key = serde_json::json!({
+            "gcs_base_url": format!("http://{}:{}", server.address, server.host_port),
+            "disable_oauth": true,
+            "client_email": "",
+            "private_key": "",
+            "private_key_id": "",
+        });
+
+        let object_store_config = ObjectStoreConfig {
+            gcs: vec![(
+                "google_service_account_key".to_string(),
+                service_account_key.to_string(),
+            )],
+            azure: Vec::new(),
+        };
+
+        let url = Url::parse(&format!("gs://{bucket}/")).unwrap();
+
+        Self {
+            tmp_dir,
+            server,
+            bucket,
+            url,
+            object_store_config,
+        }
+    }
+}
@@ -10,6 +10,7 @@
 mod dataset_data_helper;
 mod dataset_test_helper;
 mod dummy_smart_transfer_protocol_client;
+mod fake_gcs_server;
 mod http_file_server;
 mod id_factory;
 mod metadata_factory;
@@ -26,6 +27,7 @@ mod parquet_writer_helper;
 pub use dataset_data_helper::*;
 pub use dataset_test_helper::*;
 pub use dummy_smart_transfer_protocol_client::*;
+pub use fake_gcs_server::*;
 pub use http_file_server::*;
 pub use id_factory::*;
 pub use metadata_factory::*;
@@ -18,6 +18,7 @@ use itertools::Itertools;
 use kamu_core::engine::*;
 use kamu_core::*;
 use kamu_ingest_datafusion::DataWriterDataFusion;
+use messaging_outbox::{Outbox, OutboxExt};
 use opendatafabric::*;
 use random_names::get_random_name;
 use time_source::SystemTimeSource;
@@ -28,6 +29,7 @@ pub struct TransformServiceImpl {
     engine_provisioner: Arc<dyn EngineProvisioner>,
     time_source: Arc<dyn SystemTimeSource>,
     compaction_svc: Arc<dyn CompactionService>,
+    outbox: Arc<dyn Outbox>,
 }
 
 #[component(pub)]
@@ -39,6 +41,7 @@ impl TransformServiceImpl {
         engine_provisioner: Arc<dyn EngineProvisioner>,
         time_source: Arc<dyn SystemTimeSource>,
         compaction_svc: Arc<dyn CompactionService>,
+        outbox: Arc<dyn Outbox>,
     ) -> Self {
         Self {
             dataset_repo,
@@ -46,6 +49,7 @@ impl TransformServiceImpl {
             engine_provisioner,
             time_source,
             compaction_svc,
+            outbox,
         }
     }
 
@@ -112,6 +116,7 @@ impl TransformServiceImpl {
 
     async fn commit_execute_transform(
         dataset_repo: Arc<dyn DatasetRepository>,
+        outbox: Arc<dyn Outbox>,
         request: TransformRequestExt,
         response: TransformResponseExt,
     ) -> Result<TransformResult, TransformError> {
@@ -155,7 +160,7 @@ impl TransformServiceImpl {
             query_inputs: request.inputs.iter().map(|i| i.clone().into()).collect(),
             prev_checkpoint: request.prev_checkpoint,
             prev_offset: request.prev_offset,
-            new_offset_interval: response.new_offset_interval,
+            new_offset_interval: response.new_offset_interval.clone(),
             new_watermark: response.new_watermark,
         };
 
@@ -175,6 +180,19 @@ impl TransformServiceImpl {
             .await
         {
             Ok(res) => {
+                outbox
+                    .post_message(
+                        MESSAGE_PRODUCER_KAMU_CORE_DATASET_SERVICE,
+                        DatasetLifecycleMessage::data_updated(
+                            request.dataset_handle.id.clone(),
+                            Some(old_head.clone()),
+                            res.new_head.clone(),
+                            response.new_offset_interval,
+                            response.new_watermark,
+                        ),
+                    )
+                    .await?;
+
                 new_head = res.new_head;
                 Ok(())
             }
@@ -678,11 +696,13 @@ impl TransformServiceImpl {
         {
             Ok(Some(operation)) => {
                 let dataset_repo = self.dataset_repo.clone();
+                let outbox = self.outbox.clone();
                 Self::do_transform(
                     self.engine_provisioner.clone(),
                     operation,
                     |request, response| async move {
-                        Self::commit_execute_transform(dataset_repo, request, response).await
+                        Self::commit_execute_transform(dataset_repo, outbox, request, response)
+                            .await
                     },
                     listener,
                 )
@@ -25,6 +25,7 @@ use kamu_core::{
     MESSAGE_PRODUCER_KAMU_CORE_DATASET_SERVICE,
 };
 use messaging_outbox::{Outbox, OutboxExt};
+use opendatafabric::{MetadataEvent, OffsetInterval};
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
 
@@ -59,6 +60,11 @@ impl AppendDatasetMetadataBatchUseCase for AppendDatasetMetadataBatchUseCaseImpl
 
         let mut new_upstream_ids: Vec<opendatafabric::DatasetID> = vec![];
 
+        // Accumulated effect of data-bearing events of the batch
+        let mut has_new_data_events = false;
+        let mut new_offset_interval: Option<OffsetInterval> = None;
+        let mut new_watermark = None;
+
         for (hash, block) in new_blocks {
             tracing::debug!(sequence_numer = %block.sequence_number, hash = %hash, "Appending block");
 
@@ -76,6 +82,26 @@ impl AppendDatasetMetadataBatchUseCase for AppendDatasetMetadataBatchUseCaseImpl
                 }
             }
 
+            let maybe_new_data = match &block.event {
+                MetadataEvent::AddData(e) => Some((e.new_data.as_ref(), e.new_watermark)),
+                MetadataEvent::ExecuteTransform(e) => Some((e.new_data.as_ref(), e.new_watermark)),
+                _ => None,
+            };
+            if let Some((new_data, watermark)) = maybe_new_data {
+                has_new_data_events = true;
+                if let Some(new_data) = new_data {
+                    new_offset_interval = Some(OffsetInterval {
+                        start: new_offset_interval
+                            .as_ref()
+                            .map_or(new_data.offset_interval.start, |i| i.start),
+                        end: new_data.offset_interval.end,
+                    });
+                }
+                if watermark.is_some() {
+                    new_watermark = watermark;
+                }
+            }
+
             metadata_chain
                 .append(
                     block,
@@ -103,12 +129,16 @@ impl AppendDatasetMetadataBatchUseCase for AppendDatasetMetadataBatchUseCaseImpl
             )
             .await?;
 
-        if !new_upstream_ids.is_empty() {
-            let summary = dataset
-                .get_summary(GetSummaryOpts::default())
-                .await
-                .int_err()?;
+        if new_upstream_ids.is_empty() && !has_new_data_events {
+            return Ok(());
+        }
+
+        let summary = dataset
+            .get_summary(GetSummaryOpts::default())
+            .await
+            .int_err()?;
 
+        if !new_upstream_ids.is_empty() {
             self.outbox
                 .post_message(
                     MESSAGE_PRODUCER_KAMU_CORE_DATASET_SERVICE,
@@ -120,6 +150,21 @@ impl AppendDatasetMetadataBatchUseCase for AppendDatasetMetadataBatchUseCaseImpl
                 .await?;
         }
 
+        if has_new_data_events {
+            self.outbox
+                .post_message(
+                    MESSAGE_PRODUCER_KAMU_CORE_DATASET_SERVICE,
+                    DatasetLifecycleMessage::data_updated(
+                        summary.id,
+                        old_head,
+                        new_head,
+                        new_offset_interval,
+                        new_watermark,
+                    ),
+                )
+                .await?;
+        }
+
         Ok(())
     }
 }
@@ -12,37 +12,49 @@ use std::sync::Arc;
 use dill::{component, interface};
 use kamu_core::auth::{DatasetAction, DatasetActionAuthorizer};
 use kamu_core::{
+    AppendError,
+    AppendValidationError,
     CommitDatasetEventUseCase,
     CommitError,
     CommitOpts,
     CommitResult,
     DatasetLifecycleMessage,
     DatasetRepository,
+    InvalidEventError,
     MESSAGE_PRODUCER_KAMU_CORE_DATASET_SERVICE,
 };
 use messaging_outbox::{Outbox, OutboxExt};
 use opendatafabric::{DatasetHandle, MetadataEvent};
 
+use crate::EngineRegistry;
+
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
 
-#[component(pub)]
-#[interface(dyn CommitDatasetEventUseCase)]
 pub struct CommitDatasetEventUseCaseImpl {
     dataset_repo: Arc<dyn DatasetRepository>,
     dataset_action_authorizer: Arc<dyn DatasetActionAuthorizer>,
     outbox: Arc<dyn Outbox>,
+    engine_registry: Option<Arc<EngineRegistry>>,
 }
 
+#[component(pub)]
+#[interface(dyn CommitDatasetEventUseCase)]
 impl CommitDatasetEventUseCaseImpl {
+    /// # Arguments
+    ///
+    /// * `engine_registry` - when present in the catalog committed
+    ///   transformations are validated to only use the registered engines
     pub fn new(
         dataset_repo: Arc<dyn DatasetRepository>,
         dataset_action_authorizer: Arc<dyn DatasetActionAuthorizer>,
         outbox: Arc<dyn Outbox>,
+        engine_registry: Option<Arc<EngineRegistry>>,
     ) -> Self {
         Self {
             dataset_repo,
             dataset_action_authorizer,
             outbox,
+            engine_registry,
         }
     }
 }
@@ -61,7 +73,19 @@ impl CommitDatasetEventUseCase for CommitDatasetEventUseCaseImpl {
 
         let dataset = self.dataset_repo.get_dataset_by_handle(dataset_handle);
 
-        let commit_result = dataset.commit_event(event, opts).await?;
+        if let Some(engine_registry) = &self.engine_registry
+            && let MetadataEvent::SetTransform(e) = &event
+        {
+            engine_registry
+                .validate_transform(&e.transform)
+                .map_err(|err| {
+                    AppendError::InvalidBlock(AppendValidationError::InvalidEvent(
+                        InvalidEventError::new(e.clone(), err.to_string()),
+                    ))
+                })?;
+        }
+
+        let commit_result = dataset.commit_event(event.clone(), opts).await?;
 
         if !commit_result.new_upstream_ids.is_empty() {
             self.outbox
@@ -75,6 +99,17 @@ impl CommitDatasetEventUseCase for CommitDatasetEventUseCaseImpl {
                 .await?;
         }
 
+        if let Some(message) = DatasetLifecycleMessage::data_updated_by_event(
+            dataset_handle.id.clone(),
+            commit_result.old_head.clone(),
+            commit_result.new_head.clone(),
+            &event,
+        ) {
+            self.outbox
+                .post_message(MESSAGE_PRODUCER_KAMU_CORE_DATASET_SERVICE, message)
+                .await?;
+        }
+
         Ok(commit_result)
     }
 }
@@ -18,10 +18,20 @@ pub const JUPYTER: &str = "ghcr.io/kamu-data/jupyter:0.6.2";
 // Test Images
 pub const HTTPD: &str = "docker.io/httpd:2.4";
 pub const MINIO: &str = "docker.io/minio/minio:RELEASE.2021-08-31T05-46-54Z";
+pub const FAKE_GCS_SERVER: &str = "docker.io/fsouza/fake-gcs-server:1.49.3";
+pub const AZURITE: &str = "mcr.microsoft.com/azure-storage/azurite:3.31.0";
 pub const BUSYBOX: &str = "docker.io/busybox:latest";
 
 #[cfg(feature = "ingest-mqtt")]
-pub const RUMQTTD: &str = "docker.io/bytebeamio/rumqttd:0.19.0";
+pub const MOSQUITTO: &str = "docker.io/eclipse-mosquitto:2.0";
 
 #[cfg(feature = "ingest-ftp")]
 pub const FTP: &str = "docker.io/bogem/ftp";
+
+#[cfg(feature = "ingest-evm")]
+pub const ANVIL: &str = "ghcr.io/foundry-rs/foundry:latest";
+
+#[cfg(feature = "ingest-sql")]
+pub const POSTGRES: &str = "docker.io/postgres:16";
+#[cfg(feature = "ingest-sql")]
+pub const MARIADB: &str = "docker.io/mariadb:11";
@@ -0,0 +1,275 @@
+// Copyright Kamu Data, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+use std::str::FromStr;
+use std::sync::Arc;
+
+use bytes::Bytes;
+use futures::{StreamExt, TryStreamExt};
+use internal_error::{ErrorIntoInternal, InternalError, ResultIntoInternal};
+use object_store::azure::{AzureConfigKey, MicrosoftAzureBuilder};
+use object_store::gcp::{GoogleCloudStorageBuilder, GoogleConfigKey};
+use object_store::path::Path as ObjectPath;
+use object_store::{ObjectMeta, ObjectStore, PutPayload};
+use tokio::io::AsyncRead;
+use url::Url;
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Context for storages that are accessed via the generic [`ObjectStore`]
+/// interface, like Google Cloud Storage (`gs://`) and Azure Blob Storage
+/// (`az://`).
+///
+/// Credentials and endpoints are resolved from the standard environment
+/// variables of the respective provider (e.g. `GOOGLE_SERVICE_ACCOUNT`,
+/// `AZURE_STORAGE_ACCOUNT_NAME`), and can be overridden via
+/// [`ObjectStoreConfig`], which also allows pointing them to local emulators
+/// like `fake-gcs-server` and `Azurite`.
+#[derive(Clone)]
+pub struct ObjectStoreContext {
+    pub store: Arc<dyn ObjectStore>,
+    pub scheme: String,
+    pub bucket: String,
+    pub key_prefix: String,
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+pub type AsyncReadObj = dyn AsyncRead + Send + Unpin;
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Options applied on top of the environment when building object store
+/// clients
+#[derive(Debug, Clone, Default)]
+pub struct ObjectStoreConfig {
+    /// Google Cloud Storage options, keyed by [`GoogleConfigKey`] names (e.g.
+    /// `google_service_account_key`)
+    pub gcs: Vec<(String, String)>,
+    /// Azure Blob Storage options, keyed by [`AzureConfigKey`] names (e.g.
+    /// `azure_storage_account_name`, `azure_storage_endpoint`, `allow_http`)
+    pub azure: Vec<(String, String)>,
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+impl ObjectStoreContext {
+    pub const SUPPORTED_SCHEMES: [&'static str; 2] = ["gs", "az"];
+
+    pub fn new<S1, S2, S3>(
+        store: Arc<dyn ObjectStore>,
+        scheme: S1,
+        bucket: S2,
+        key_prefix: S3,
+    ) -> Self
+    where
+        S1: Into<String>,
+        S2: Into<String>,
+        S3: Into<String>,
+    {
+        Self {
+            store,
+            scheme: scheme.into(),
+            bucket: bucket.into(),
+            key_prefix: key_prefix.into(),
+        }
+    }
+
+    pub fn is_supported_url(url: &Url) -> bool {
+        Self::SUPPORTED_SCHEMES.contains(&url.scheme())
+    }
+
+    /// Creates a context for a sub-key while reusing the same object store
+    /// client and its credential cache
+    pub fn sub_context(&self, sub_key: &str) -> Self {
+        let mut key_prefix = self.get_key(sub_key);
+        if !key_prefix.ends_with('/') {
+            key_prefix.push('/');
+        }
+        Self {
+            store: self.store.clone(),
+            scheme: self.scheme.clone(),
+            bucket: self.bucket.clone(),
+            key_prefix,
+        }
+    }
+
+    pub fn from_url(url: &Url) -> Result<Self, InternalError> {
+        Self::from_url_with_config(url, &ObjectStoreConfig::default())
+    }
+
+    #[tracing::instrument(level = "info", name = "init_object_store_context", skip(config))]
+    pub fn from_url_with_config(
+        url: &Url,
+        config: &ObjectStoreConfig,
+    ) -> Result<Self, InternalError> {
+        let bucket = match url.host_str() {
+            Some(bucket) if !bucket.is_empty() => bucket.to_owned(),
+            _ => return Err(format!("Bucket is not specified in URL: {url}").int_err()),
+        };
+
+        let key_prefix = url.path().trim_start_matches('/').to_owned();
+        if !key_prefix.is_empty() && !key_prefix.ends_with('/') {
+            return Err(format!("Base URL does not contain a trailing slash: {url}").int_err());
+        }
+
+        let store: Arc<dyn ObjectStore> = match url.scheme() {
+            "gs" => {
+                let mut builder = GoogleCloudStorageBuilder::from_env();
+                for (key, value) in &config.gcs {
+                    builder = builder.with_config(GoogleConfigKey::from_str(key).int_err()?, value);
+                }
+                Arc::new(builder.with_bucket_name(&bucket).build().int_err()?)
+            }
+            "az" => {
+                let mut builder = MicrosoftAzureBuilder::from_env();
+                for (key, value) in &config.azure {
+                    builder = builder.with_config(AzureConfigKey::from_str(key).int_err()?, value);
+                }
+                Arc::new(builder.with_container_name(&bucket).build().int_err()?)
+            }
+            _ => return Err(format!("Unsupported object store URL: {url}").int_err()),
+        };
+
+        Ok(Self::new(store, url.scheme(), bucket, key_prefix))
+    }
+
+    /// Returns the URL of the bucket this context is operating in, which is
+    /// also used as a key when registering the store in DataFusion
+    pub fn bucket_url(&self) -> Url {
+        Url::parse(&format!("{}://{}/", self.scheme, self.bucket)).unwrap()
+    }
+
+    pub fn get_key(&self, sub_key: &str) -> String {
+        if self.key_prefix.is_empty() {
+            String::from(sub_key)
+        } else {
+            format!("{}{}", self.key_prefix, sub_key)
+        }
+    }
+
+    pub fn get_path(&self, sub_key: &str) -> ObjectPath {
+        ObjectPath::from(self.get_key(sub_key))
+    }
+
+    pub async fn head_object(&self, key: &str) -> Result<Option<ObjectMeta>, InternalError> {
+        match self.store.head(&ObjectPath::from(key)).await {
+            Ok(meta) => Ok(Some(meta)),
+            Err(object_store::Error::NotFound { .. }) => Ok(None),
+            Err(e) => Err(e.int_err()),
+        }
+    }
+
+    pub async fn get_object(&self, key: &str) -> Result<Option<Box<AsyncReadObj>>, InternalError> {
+        let res = match self.store.get(&ObjectPath::from(key)).await {
+            Ok(res) => res,
+            Err(object_store::Error::NotFound { .. }) => return Ok(None),
+            Err(e) => return Err(e.int_err()),
+        };
+
+        let stream = res
+            .into_stream()
+            .map_err(|e| std::io::Error::new(std::io::ErrorKind::Other, e));
+
+        Ok(Some(Box::new(tokio_util::io::StreamReader::new(stream))))
+    }
+
+    pub async fn get_object_bytes(&self, key: &str) -> Result<Option<Bytes>, InternalError> {
+        match self.store.get(&ObjectPath::from(key)).await {
+            Ok(res) => Ok(Some(res.bytes().await.int_err()?)),
+            Err(object_store::Error::NotFound { .. }) => Ok(None),
+            Err(e) => Err(e.int_err()),
+        }
+    }
+
+    pub async fn put_object(&self, key: &str, data: &[u8]) -> Result<(), InternalError> {
+        self.store
+            .put(
+                &ObjectPath::from(key),
+                PutPayload::from(Bytes::copy_from_slice(data)),
+            )
+            .await
+            .int_err()?;
+        Ok(())
+    }
+
+    /// Uploads the stream using multi-part upload when data exceeds a single
+    /// part size
+    pub async fn put_object_stream(
+        &self,
+        key: &str,
+        mut stream: Box<AsyncReadObj>,
+    ) -> Result<(), InternalError> {
+        use tokio::io::AsyncWriteExt;
+
+        let mut writer =
+            object_store::buffered::BufWriter::new(self.store.clone(), ObjectPath::from(key));
+
+        tokio::io::copy(&mut stream, &mut writer).await.int_err()?;
+        writer.shutdown().await.int_err()?;
+
+        Ok(())
+    }
+
+    pub async fn delete_object(&self, key: &str) -> Result<(), InternalError> {
+        match self.store.delete(&ObjectPath::from(key)).await {
+            Ok(()) | Err(object_store::Error::NotFound { .. }) => Ok(()),
+            Err(e) => Err(e.int_err()),
+        }
+    }
+
+    pub async fn bucket_path_exists(&self, key_prefix: &str) -> Result<bool, InternalError> {
+        let prefix = ObjectPath::from(self.get_key(key_prefix));
+        let mut listing = self.store.list(Some(&prefix));
+        match listing.next().await {
+            Some(Ok(_)) => Ok(true),
+            Some(Err(e)) => Err(e.int_err()),
+            None => Ok(false),
+        }
+    }
+
+    /// Lists the names of immediate "sub-folders" under the key prefix
+    pub async fn bucket_list_folders(&self) -> Result<Vec<String>, InternalError> {
+        let prefix = if self.key_prefix.is_empty() {
+            None
+        } else {
+            Some(ObjectPath::from(self.key_prefix.as_str()))
+        };
+
+        let listing = self
+            .store
+            .list_with_delimiter(prefix.as_ref())
+            .await
+            .int_err()?;
+
+        Ok(listing
+            .common_prefixes
+            .into_iter()
+            .filter_map(|p| p.filename().map(ToString::to_string))
+            .collect())
+    }
+
+    pub async fn recursive_delete(&self, key_prefix: String) -> Result<(), InternalError> {
+        let prefix = ObjectPath::from(key_prefix);
+
+        let locations = self
+            .store
+            .list(Some(&prefix))
+            .map_ok(|meta| meta.location)
+            .boxed();
+
+        self.store
+            .delete_stream(locations)
+            .try_collect::<Vec<_>>()
+            .await
+            .int_err()?;
+
+        Ok(())
+    }
+}
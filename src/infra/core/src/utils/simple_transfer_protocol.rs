@@ -11,10 +11,11 @@ use std::pin::Pin;
 use std::sync::{Arc, Mutex};
 
 use futures::{stream, Future, StreamExt, TryStreamExt};
-use internal_error::ErrorIntoInternal;
+use internal_error::{ErrorIntoInternal, ResultIntoInternal};
 use kamu_core::sync_service::DatasetNotFoundError;
 use kamu_core::utils::metadata_chain_comparator::*;
 use kamu_core::*;
+use messaging_outbox::{Outbox, OutboxExt};
 use opendatafabric::*;
 
 use crate::*;
@@ -57,11 +58,17 @@ impl Default for SimpleProtocolTransferOptions {
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
 
 /// Implements "Simple Transfer Protocol" as described in ODF spec
-pub struct SimpleTransferProtocol;
+pub struct SimpleTransferProtocol {
+    outbox: Arc<dyn Outbox>,
+}
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
 
 impl SimpleTransferProtocol {
+    pub fn new(outbox: Arc<dyn Outbox>) -> Self {
+        Self { outbox }
+    }
+
     pub async fn sync(
         &self,
         src_ref: &DatasetRefAny,
@@ -460,11 +467,48 @@ impl SimpleTransferProtocol {
             )
             .await?;
 
+        let mut new_upstream_ids: Vec<DatasetID> = vec![];
+
+        // Accumulated effect of data-bearing events of the transferred blocks
+        let mut has_new_data_events = false;
+        let mut new_offset_interval: Option<OffsetInterval> = None;
+        let mut new_watermark = None;
+
         // Commit blocks
         for (hash, block) in blocks.into_iter().rev() {
             tracing::debug!(?hash, "Appending block");
             let sequence_number = block.sequence_number;
 
+            if let MetadataEvent::SetTransform(transform) = &block.event {
+                // Collect only the latest upstream dataset IDs
+                new_upstream_ids.clear();
+                for new_input in &transform.inputs {
+                    if let Some(id) = new_input.dataset_ref.id() {
+                        new_upstream_ids.push(id.clone());
+                    }
+                }
+            }
+
+            let maybe_new_data = match &block.event {
+                MetadataEvent::AddData(e) => Some((e.new_data.as_ref(), e.new_watermark)),
+                MetadataEvent::ExecuteTransform(e) => Some((e.new_data.as_ref(), e.new_watermark)),
+                _ => None,
+            };
+            if let Some((new_data, watermark)) = maybe_new_data {
+                has_new_data_events = true;
+                if let Some(new_data) = new_data {
+                    new_offset_interval = Some(OffsetInterval {
+                        start: new_offset_interval
+                            .as_ref()
+                            .map_or(new_data.offset_interval.start, |i| i.start),
+                        end: new_data.offset_interval.end,
+                    });
+                }
+                if watermark.is_some() {
+                    new_watermark = watermark;
+                }
+            }
+
             match dst
                 .as_metadata_chain()
                 .append(
@@ -537,6 +581,41 @@ impl SimpleTransferProtocol {
             Err(SetRefError::BlockNotFound(e)) => Err(SyncError::Internal(e.int_err())),
         }?;
 
+        // Notify consumers the same way as appending a metadata batch does, as
+        // otherwise dependency graph and flows would not learn about the new blocks
+        if new_upstream_ids.is_empty() && !has_new_data_events {
+            return Ok(());
+        }
+
+        let summary = dst.get_summary(GetSummaryOpts::default()).await.int_err()?;
+
+        if !new_upstream_ids.is_empty() {
+            self.outbox
+                .post_message(
+                    MESSAGE_PRODUCER_KAMU_CORE_DATASET_SERVICE,
+                    DatasetLifecycleMessage::dependencies_updated(
+                        summary.id.clone(),
+                        new_upstream_ids,
+                    ),
+                )
+                .await?;
+        }
+
+        if has_new_data_events {
+            self.outbox
+                .post_message(
+                    MESSAGE_PRODUCER_KAMU_CORE_DATASET_SERVICE,
+                    DatasetLifecycleMessage::data_updated(
+                        summary.id,
+                        dst_head.cloned(),
+                        src_head.clone(),
+                        new_offset_interval,
+                        new_watermark,
+                    ),
+                )
+                .await?;
+        }
+
         Ok(())
     }
 }
@@ -0,0 +1,219 @@
+// Copyright Kamu Data, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+use std::path::Path;
+use std::sync::Arc;
+
+use criterion::{criterion_group, criterion_main, BatchSize, Criterion};
+use datafusion::arrow::array::{Int32Array, Int64Array, StringArray, TimestampMillisecondArray};
+use datafusion::arrow::datatypes::{DataType, Field, Schema, TimeUnit};
+use datafusion::arrow::record_batch::RecordBatch;
+use kamu::domain::*;
+use kamu::testing::{MetadataFactory, ParquetWriterHelper};
+use kamu::{
+    CompactionServiceImpl,
+    DatasetRepositoryLocalFs,
+    ObjectStoreBuilderLocalFs,
+    ObjectStoreRegistryImpl,
+};
+use kamu_accounts::CurrentAccountSubject;
+use opendatafabric::*;
+use tempfile::TempDir;
+use time_source::{SystemTimeSource, SystemTimeSourceDefault};
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+const AMOUNT_OF_SLICES: usize = 50;
+const RECORDS_PER_SLICE: usize = 10_000;
+const AMOUNT_OF_CITIES: usize = 100;
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+struct CompactionBenchSetup {
+    _temp_dir: TempDir,
+    compaction_svc: Arc<dyn CompactionService>,
+    dataset_handle: DatasetHandle,
+}
+
+async fn setup_dataset() -> CompactionBenchSetup {
+    let temp_dir = tempfile::tempdir().unwrap();
+    let run_info_dir = temp_dir.path().join("run");
+    let datasets_dir = temp_dir.path().join("datasets");
+    std::fs::create_dir(&run_info_dir).unwrap();
+    std::fs::create_dir(&datasets_dir).unwrap();
+
+    let catalog = dill::CatalogBuilder::new()
+        .add_value(RunInfoDir::new(run_info_dir))
+        .add_value(CurrentAccountSubject::new_test())
+        .add_builder(
+            DatasetRepositoryLocalFs::builder()
+                .with_root(datasets_dir)
+                .with_multi_tenant(false),
+        )
+        .bind::<dyn DatasetRepository, DatasetRepositoryLocalFs>()
+        .add::<auth::AlwaysHappyDatasetActionAuthorizer>()
+        .add::<SystemTimeSourceDefault>()
+        .bind::<dyn SystemTimeSource, SystemTimeSourceDefault>()
+        .add::<ObjectStoreRegistryImpl>()
+        .add::<ObjectStoreBuilderLocalFs>()
+        .add::<CompactionServiceImpl>()
+        .build();
+
+    let dataset_repo = catalog.get_one::<DatasetRepositoryLocalFs>().unwrap();
+
+    let snapshot = MetadataFactory::dataset_snapshot()
+        .name("foo")
+        .kind(DatasetKind::Root)
+        .push_event(MetadataFactory::set_data_schema().build())
+        .build();
+
+    let created = dataset_repo
+        .create_dataset_from_snapshot(snapshot)
+        .await
+        .unwrap()
+        .create_dataset_result;
+
+    for slice_index in 0..AMOUNT_OF_SLICES {
+        append_slice(
+            created.dataset.as_ref(),
+            temp_dir.path(),
+            (slice_index * RECORDS_PER_SLICE) as u64,
+        )
+        .await;
+    }
+
+    CompactionBenchSetup {
+        _temp_dir: temp_dir,
+        compaction_svc: catalog.get_one::<dyn CompactionService>().unwrap(),
+        dataset_handle: created.dataset_handle,
+    }
+}
+
+async fn append_slice(dataset: &dyn Dataset, tmp_dir: &Path, start_offset: u64) {
+    let schema = Arc::new(Schema::new(vec![
+        Field::new("offset", DataType::Int64, false),
+        Field::new("op", DataType::Int32, false),
+        Field::new(
+            "system_time",
+            DataType::Timestamp(TimeUnit::Millisecond, Some("UTC".into())),
+            false,
+        ),
+        Field::new(
+            "event_time",
+            DataType::Timestamp(TimeUnit::Millisecond, Some("UTC".into())),
+            false,
+        ),
+        Field::new("city", DataType::Utf8, false),
+        Field::new("population", DataType::Int64, false),
+    ]));
+
+    let offsets: Vec<i64> = (0..RECORDS_PER_SLICE)
+        .map(|i| i64::try_from(start_offset).unwrap() + i as i64)
+        .collect();
+
+    let record_batch = RecordBatch::try_new(
+        schema,
+        vec![
+            Arc::new(Int64Array::from(offsets.clone())),
+            Arc::new(Int32Array::from(vec![
+                OperationType::Append as i32;
+                RECORDS_PER_SLICE
+            ])),
+            Arc::new(TimestampMillisecondArray::from(offsets.clone()).with_timezone("UTC")),
+            Arc::new(TimestampMillisecondArray::from(offsets.clone()).with_timezone("UTC")),
+            Arc::new(StringArray::from_iter_values(offsets.iter().map(|o| {
+                format!("city-{}", (o * 7919) % AMOUNT_OF_CITIES as i64)
+            }))),
+            Arc::new(Int64Array::from(offsets)),
+        ],
+    )
+    .unwrap();
+
+    let data_path = tmp_dir.join("data");
+    ParquetWriterHelper::from_record_batch(&data_path, &record_batch).unwrap();
+
+    let prev_offset = start_offset.checked_sub(1);
+
+    dataset
+        .commit_add_data(
+            AddDataParams {
+                prev_checkpoint: None,
+                prev_offset,
+                new_offset_interval: Some(OffsetInterval {
+                    start: start_offset,
+                    end: start_offset + RECORDS_PER_SLICE as u64 - 1,
+                }),
+                new_watermark: None,
+                new_source_state: None,
+            },
+            Some(OwnedFile::new(data_path)),
+            None,
+            CommitOpts::default(),
+        )
+        .await
+        .unwrap();
+}
+
+async fn do_compact(setup: CompactionBenchSetup, data_layout: CompactionDataLayout) {
+    let result = setup
+        .compaction_svc
+        .compact_dataset(
+            &setup.dataset_handle,
+            CompactionOptions {
+                data_layout,
+                ..CompactionOptions::default()
+            },
+            None,
+        )
+        .await
+        .unwrap();
+
+    assert!(matches!(result, CompactionResult::Success { .. }));
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+fn bench_compaction(c: &mut Criterion) {
+    let rt = tokio::runtime::Runtime::new().unwrap();
+
+    let mut group = c.benchmark_group("compaction");
+    // Min size of iterations is 10
+    group.sample_size(10);
+
+    group.bench_function("offset_order", |b| {
+        b.iter_batched(
+            || rt.block_on(setup_dataset()),
+            |setup| rt.block_on(do_compact(setup, CompactionDataLayout::default())),
+            BatchSize::PerIteration,
+        );
+    });
+
+    group.bench_function("sort_by", |b| {
+        b.iter_batched(
+            || rt.block_on(setup_dataset()),
+            |setup| {
+                rt.block_on(do_compact(
+                    setup,
+                    CompactionDataLayout {
+                        sort_by: vec!["city".to_string()],
+                        ..CompactionDataLayout::default()
+                    },
+                ));
+            },
+            BatchSize::PerIteration,
+        );
+    });
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+criterion_group!(benches, bench_compaction);
+criterion_main!(benches);
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
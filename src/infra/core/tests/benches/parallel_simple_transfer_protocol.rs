@@ -33,6 +33,7 @@ use kamu::{
     SyncServiceImpl,
 };
 use kamu_accounts::CurrentAccountSubject;
+use messaging_outbox::DummyOutboxImpl;
 use opendatafabric::*;
 use url::Url;
 
@@ -66,6 +67,7 @@ async fn setup_dataset(
         .add::<auth::DummyOdfServerAccessTokenResolver>()
         .add::<DatasetFactoryImpl>()
         .add::<SyncServiceImpl>()
+        .add::<DummyOutboxImpl>()
         .add::<DummySmartTransferProtocolClient>()
         .add::<auth::AlwaysHappyDatasetActionAuthorizer>()
         .build();
@@ -19,6 +19,7 @@ use kamu::testing::*;
 use kamu::*;
 use kamu_accounts::CurrentAccountSubject;
 use kamu_datasets_services::DatasetKeyValueServiceSysEnv;
+use messaging_outbox::{DummyOutboxImpl, Outbox};
 use opendatafabric::*;
 use time_source::SystemTimeSourceDefault;
 
@@ -36,15 +37,18 @@ async fn test_engine_io_common<
 
     let engine_provisioner = Arc::new(EngineProvisionerLocal::new(
         EngineProvisionerLocalConfig::default(),
+        None,
         Arc::new(ContainerRuntime::default()),
         dataset_repo.clone(),
         run_info_dir.clone(),
+        None,
     ));
 
     let dataset_action_authorizer = Arc::new(auth::AlwaysHappyDatasetActionAuthorizer::new());
     let object_store_registry = Arc::new(ObjectStoreRegistryImpl::new(object_stores));
     let time_source = Arc::new(SystemTimeSourceDefault);
-    let dataset_env_var_sys_env = Arc::new(DatasetKeyValueServiceSysEnv::new());
+    let dataset_env_var_sys_env = Arc::new(DatasetKeyValueServiceSysEnv::new(None));
+    let outbox: Arc<dyn Outbox> = Arc::new(DummyOutboxImpl {});
 
     let ingest_svc = PollingIngestServiceImpl::new(
         dataset_repo.clone(),
@@ -64,6 +68,8 @@ async fn test_engine_io_common<
         run_info_dir.clone(),
         cache_dir,
         time_source.clone(),
+        outbox.clone(),
+        None,
     );
 
     let transform_svc = TransformServiceImpl::new(
@@ -78,6 +84,7 @@ async fn test_engine_io_common<
             time_source.clone(),
             run_info_dir.clone(),
         )),
+        outbox,
     );
 
     ///////////////////////////////////////////////////////////////////////////
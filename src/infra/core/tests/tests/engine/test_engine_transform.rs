@@ -23,6 +23,7 @@ use kamu::testing::*;
 use kamu::*;
 use kamu_accounts::CurrentAccountSubject;
 use kamu_datasets_services::DatasetKeyValueServiceSysEnv;
+use messaging_outbox::DummyOutboxImpl;
 use opendatafabric::*;
 use time_source::{SystemTimeSource, SystemTimeSourceStub};
 
@@ -256,6 +257,7 @@ impl TestHarness {
             .add::<PollingIngestServiceImpl>()
             .add::<PushIngestServiceImpl>()
             .add::<TransformServiceImpl>()
+            .add::<DummyOutboxImpl>()
             .add::<CompactionServiceImpl>()
             .add::<DatasetKeyValueServiceSysEnv>()
             .add_value(SystemTimeSourceStub::new_set(
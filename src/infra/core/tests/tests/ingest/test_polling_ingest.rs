@@ -20,6 +20,7 @@ use kamu::testing::*;
 use kamu::*;
 use kamu_accounts::CurrentAccountSubject;
 use kamu_datasets_services::DatasetKeyValueServiceSysEnv;
+use messaging_outbox::DummyOutboxImpl;
 use opendatafabric::*;
 use tempfile::TempDir;
 use time_source::{SystemTimeSource, SystemTimeSourceStub};
@@ -1118,6 +1119,7 @@ impl IngestTestHarness {
             .add::<DataFormatRegistryImpl>()
             .add::<FetchService>()
             .add::<PollingIngestServiceImpl>()
+            .add::<DummyOutboxImpl>()
             .add::<DatasetKeyValueServiceSysEnv>()
             .build();
 
@@ -175,3 +175,292 @@ fn test_prep_decompress_gzip() {
     assert!(target_path.exists());
     assert_eq!(std::fs::read_to_string(&target_path).unwrap(), content);
 }
+
+#[test]
+fn test_prep_decompress_zstd() {
+    let tempdir = tempfile::tempdir().unwrap();
+
+    let src_path = tempdir.path().join("data.zst");
+    let target_path = tempdir.path().join("prepared.bin");
+    let run_info_dir = tempdir.path();
+
+    let prep_steps = vec![PrepStep::Decompress(PrepStepDecompress {
+        format: CompressionFormat::Zstd,
+        sub_path: None,
+    })];
+
+    std::fs::write(
+        &src_path,
+        zstd::encode_all(CSV_CONTENT.as_bytes(), 0).unwrap(),
+    )
+    .unwrap();
+
+    let prep_svc = PrepService::new();
+
+    prep_svc
+        .prepare(&prep_steps, &src_path, &target_path, run_info_dir)
+        .unwrap();
+
+    assert_eq!(std::fs::read_to_string(&target_path).unwrap(), CSV_CONTENT);
+}
+
+#[test]
+fn test_prep_decompress_xz() {
+    let tempdir = tempfile::tempdir().unwrap();
+
+    let src_path = tempdir.path().join("data.xz");
+    let target_path = tempdir.path().join("prepared.bin");
+    let run_info_dir = tempdir.path();
+
+    let prep_steps = vec![PrepStep::Decompress(PrepStepDecompress {
+        format: CompressionFormat::Xz,
+        sub_path: None,
+    })];
+
+    {
+        let mut xz = xz2::write::XzEncoder::new(std::fs::File::create(&src_path).unwrap(), 6);
+        xz.write_all(CSV_CONTENT.as_bytes()).unwrap();
+        xz.finish().unwrap();
+    }
+
+    let prep_svc = PrepService::new();
+
+    prep_svc
+        .prepare(&prep_steps, &src_path, &target_path, run_info_dir)
+        .unwrap();
+
+    assert_eq!(std::fs::read_to_string(&target_path).unwrap(), CSV_CONTENT);
+}
+
+#[test]
+fn test_prep_decompress_bzip2() {
+    let tempdir = tempfile::tempdir().unwrap();
+
+    let src_path = tempdir.path().join("data.bz2");
+    let target_path = tempdir.path().join("prepared.bin");
+    let run_info_dir = tempdir.path();
+
+    let prep_steps = vec![PrepStep::Decompress(PrepStepDecompress {
+        format: CompressionFormat::Bzip2,
+        sub_path: None,
+    })];
+
+    {
+        let mut bz = bzip2::write::BzEncoder::new(
+            std::fs::File::create(&src_path).unwrap(),
+            bzip2::Compression::fast(),
+        );
+        bz.write_all(CSV_CONTENT.as_bytes()).unwrap();
+        bz.finish().unwrap();
+    }
+
+    let prep_svc = PrepService::new();
+
+    prep_svc
+        .prepare(&prep_steps, &src_path, &target_path, run_info_dir)
+        .unwrap();
+
+    assert_eq!(std::fs::read_to_string(&target_path).unwrap(), CSV_CONTENT);
+}
+
+#[test]
+fn test_prep_decompress_tar_sub_path() {
+    let tempdir = tempfile::tempdir().unwrap();
+
+    let src_path = tempdir.path().join("data.tar");
+    let target_path = tempdir.path().join("prepared.bin");
+    let run_info_dir = tempdir.path();
+
+    let prep_steps = vec![PrepStep::Decompress(PrepStepDecompress {
+        format: CompressionFormat::Tar,
+        sub_path: Some("data/*.csv".to_string()),
+    })];
+
+    std::fs::write(
+        &src_path,
+        make_tar(&[("README.md", "# Read me"), ("data/data.csv", CSV_CONTENT)]),
+    )
+    .unwrap();
+
+    let prep_svc = PrepService::new();
+
+    prep_svc
+        .prepare(&prep_steps, &src_path, &target_path, run_info_dir)
+        .unwrap();
+
+    assert_eq!(std::fs::read_to_string(&target_path).unwrap(), CSV_CONTENT);
+}
+
+#[test]
+fn test_prep_decompress_tar_sub_path_not_found() {
+    let tempdir = tempfile::tempdir().unwrap();
+
+    let src_path = tempdir.path().join("data.tar");
+    let target_path = tempdir.path().join("prepared.bin");
+    let run_info_dir = tempdir.path();
+
+    let prep_steps = vec![PrepStep::Decompress(PrepStepDecompress {
+        format: CompressionFormat::Tar,
+        sub_path: Some("*.json".to_string()),
+    })];
+
+    std::fs::write(&src_path, make_tar(&[("data.csv", CSV_CONTENT)])).unwrap();
+
+    let prep_svc = PrepService::new();
+
+    let res = prep_svc.prepare(&prep_steps, &src_path, &target_path, run_info_dir);
+    assert_matches!(res, Err(PollingIngestError::Internal(_)));
+}
+
+#[test]
+fn test_prep_decompress_tar_gz() {
+    let tempdir = tempfile::tempdir().unwrap();
+
+    let src_path = tempdir.path().join("data.tar.gz");
+    let target_path = tempdir.path().join("prepared.bin");
+    let run_info_dir = tempdir.path();
+
+    let prep_steps = vec![
+        PrepStep::Decompress(PrepStepDecompress {
+            format: CompressionFormat::Gzip,
+            sub_path: None,
+        }),
+        PrepStep::Decompress(PrepStepDecompress {
+            format: CompressionFormat::Tar,
+            sub_path: Some("data.csv".to_string()),
+        }),
+    ];
+
+    {
+        use flate2::write::GzEncoder;
+        use flate2::Compression;
+        let mut gzip = GzEncoder::new(
+            std::fs::File::create(&src_path).unwrap(),
+            Compression::fast(),
+        );
+        gzip.write_all(&make_tar(&[("data.csv", CSV_CONTENT)]))
+            .unwrap();
+    }
+
+    let prep_svc = PrepService::new();
+
+    prep_svc
+        .prepare(&prep_steps, &src_path, &target_path, run_info_dir)
+        .unwrap();
+
+    assert_eq!(std::fs::read_to_string(&target_path).unwrap(), CSV_CONTENT);
+}
+
+#[test]
+fn test_prep_decompress_auto() {
+    let tempdir = tempfile::tempdir().unwrap();
+
+    let src_path = tempdir.path().join("data.bin");
+    let target_path = tempdir.path().join("prepared.bin");
+    let run_info_dir = tempdir.path();
+
+    // Detection on a seekable file followed by detection on a decoded stream
+    let prep_steps = vec![
+        PrepStep::Decompress(PrepStepDecompress {
+            format: CompressionFormat::Auto,
+            sub_path: None,
+        }),
+        PrepStep::Decompress(PrepStepDecompress {
+            format: CompressionFormat::Auto,
+            sub_path: None,
+        }),
+    ];
+
+    std::fs::write(
+        &src_path,
+        zstd::encode_all(make_tar(&[("data.csv", CSV_CONTENT)]).as_slice(), 0).unwrap(),
+    )
+    .unwrap();
+
+    let prep_svc = PrepService::new();
+
+    prep_svc
+        .prepare(&prep_steps, &src_path, &target_path, run_info_dir)
+        .unwrap();
+
+    assert_eq!(std::fs::read_to_string(&target_path).unwrap(), CSV_CONTENT);
+}
+
+#[test]
+fn test_prep_decompress_auto_unknown_format() {
+    let tempdir = tempfile::tempdir().unwrap();
+
+    let src_path = tempdir.path().join("data.bin");
+    let target_path = tempdir.path().join("prepared.bin");
+    let run_info_dir = tempdir.path();
+
+    let prep_steps = vec![PrepStep::Decompress(PrepStepDecompress {
+        format: CompressionFormat::Auto,
+        sub_path: None,
+    })];
+
+    std::fs::write(&src_path, CSV_CONTENT).unwrap();
+
+    let prep_svc = PrepService::new();
+
+    let res = prep_svc.prepare(&prep_steps, &src_path, &target_path, run_info_dir);
+    assert_matches!(res, Err(PollingIngestError::Internal(_)));
+}
+
+#[test]
+fn test_prep_decompress_auto_7z_unsupported() {
+    let tempdir = tempfile::tempdir().unwrap();
+
+    let src_path = tempdir.path().join("data.7z");
+    let target_path = tempdir.path().join("prepared.bin");
+    let run_info_dir = tempdir.path();
+
+    let prep_steps = vec![PrepStep::Decompress(PrepStepDecompress {
+        format: CompressionFormat::Auto,
+        sub_path: None,
+    })];
+
+    let mut data = vec![0x37, 0x7a, 0xbc, 0xaf, 0x27, 0x1c, 0x00, 0x04];
+    data.extend_from_slice(CSV_CONTENT.as_bytes());
+    std::fs::write(&src_path, data).unwrap();
+
+    let prep_svc = PrepService::new();
+
+    let res = prep_svc.prepare(&prep_steps, &src_path, &target_path, run_info_dir);
+    assert_matches!(
+        res,
+        Err(PollingIngestError::Internal(e))
+            if std::error::Error::source(&e)
+                .unwrap()
+                .to_string()
+                .contains("7z is not supported")
+    );
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// Utils
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+const CSV_CONTENT: &str = indoc!(
+    "
+    city,population
+    A,1000
+    B,2000
+    C,3000
+    "
+);
+
+fn make_tar(files: &[(&str, &str)]) -> Vec<u8> {
+    let mut builder = tar::Builder::new(Vec::new());
+
+    for (path, content) in files {
+        let mut header = tar::Header::new_gnu();
+        header.set_size(content.len() as u64);
+        header.set_mode(0o644);
+        builder
+            .append_data(&mut header, path, content.as_bytes())
+            .unwrap();
+    }
+
+    builder.into_inner().unwrap()
+}
@@ -17,6 +17,7 @@ use kamu::domain::*;
 use kamu::testing::*;
 use kamu::*;
 use kamu_accounts::CurrentAccountSubject;
+use messaging_outbox::DummyOutboxImpl;
 use opendatafabric::*;
 use tempfile::TempDir;
 use time_source::{SystemTimeSource, SystemTimeSourceStub};
@@ -168,6 +169,12 @@ async fn test_ingest_push_url_stream() {
             .map(|dt| dt.to_rfc3339()),
         Some("2021-01-01T00:00:00+00:00".to_string())
     );
+
+    // Both rounds are accounted in metrics
+    let records_total = harness.metrics.records_total.with_label_values(&["push"]);
+    let bytes_total = harness.metrics.bytes_total.with_label_values(&["push"]);
+    assert_eq!(records_total.get().to_string(), "4");
+    assert!(bytes_total.get() > 0.0);
 }
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -814,6 +821,7 @@ struct IngestTestHarness {
     temp_dir: TempDir,
     dataset_repo: Arc<DatasetRepositoryLocalFs>,
     push_ingest_svc: Arc<dyn PushIngestService>,
+    metrics: Arc<IngestMetrics>,
     ctx: SessionContext,
 }
 
@@ -847,12 +855,15 @@ impl IngestTestHarness {
             .add::<ObjectStoreBuilderLocalFs>()
             .add::<DataFormatRegistryImpl>()
             .add::<PushIngestServiceImpl>()
+            .add::<IngestMetrics>()
+            .add::<DummyOutboxImpl>()
             .build();
 
         Self {
             temp_dir,
             dataset_repo: catalog.get_one().unwrap(),
             push_ingest_svc: catalog.get_one().unwrap(),
+            metrics: catalog.get_one().unwrap(),
             ctx: SessionContext::new_with_config(SessionConfig::new().with_target_partitions(1)),
         }
     }
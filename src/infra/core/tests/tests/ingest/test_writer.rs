@@ -21,6 +21,7 @@ use kamu::{DatasetRepositoryLocalFs, DatasetRepositoryWriter};
 use kamu_accounts::CurrentAccountSubject;
 use kamu_core::*;
 use kamu_data_utils::testing::{assert_data_eq, assert_schema_eq};
+use kamu_datasets::{DatasetExpectation, ExpectationOutcome, ExpectationPolicy, ExpectationRule};
 use kamu_ingest_datafusion::*;
 use odf::{AsTypedBlock, DatasetAlias};
 use opendatafabric as odf;
@@ -216,6 +217,7 @@ async fn test_data_writer_happy_path() {
             ),
             "city STRING, population BIGINT",
             Some(source_state.clone()),
+            None,
         )
         .await
         .unwrap();
@@ -490,6 +492,209 @@ async fn test_data_writer_ledger_orders_by_event_time() {
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
 
+#[test_group::group(engine, ingest, datafusion)]
+#[test_log::test(tokio::test)]
+async fn test_data_writer_retract_previous() {
+    let mut harness = Harness::new(vec![MetadataFactory::set_polling_source()
+        .merge(odf::MergeStrategyAppend {})
+        .build()
+        .into()])
+    .await;
+
+    harness
+        .write(
+            indoc!(
+                r#"
+                event_time,block,value
+                2021-01-01,a,1
+                2021-01-02,b,2
+                2021-01-03,c,3
+                "#
+            ),
+            "event_time DATE, block STRING, value BIGINT",
+        )
+        .await
+        .unwrap();
+
+    // Retracts records of orphaned blocks along with appending the new ones
+    harness.set_system_time(Utc.with_ymd_and_hms(2010, 1, 2, 12, 0, 0).unwrap());
+
+    harness
+        .write_opts(
+            indoc!(
+                r#"
+                event_time,block,value
+                2021-01-04,d,4
+                "#
+            ),
+            "event_time DATE, block STRING, value BIGINT",
+            None,
+            Some(col("block").in_list(vec![lit("b"), lit("c")], false)),
+        )
+        .await
+        .unwrap();
+
+    assert_data_eq(
+        harness.get_last_data().await,
+        indoc!(
+            r#"
+            +--------+----+----------------------+------------+-------+-------+
+            | offset | op | system_time          | event_time | block | value |
+            +--------+----+----------------------+------------+-------+-------+
+            | 3      | 1  | 2010-01-02T12:00:00Z | 2021-01-02 | b     | 2     |
+            | 4      | 1  | 2010-01-02T12:00:00Z | 2021-01-03 | c     | 3     |
+            | 5      | 0  | 2010-01-02T12:00:00Z | 2021-01-04 | d     | 4     |
+            +--------+----+----------------------+------------+-------+-------+
+            "#
+        ),
+    )
+    .await;
+
+    // Records that were already retracted are not retracted again
+    harness.set_system_time(Utc.with_ymd_and_hms(2010, 1, 3, 12, 0, 0).unwrap());
+
+    harness
+        .write_opts(
+            indoc!(
+                r#"
+                event_time,block,value
+                2021-01-05,e,5
+                "#
+            ),
+            "event_time DATE, block STRING, value BIGINT",
+            None,
+            Some(col("block").in_list(vec![lit("c"), lit("d")], false)),
+        )
+        .await
+        .unwrap();
+
+    assert_data_eq(
+        harness.get_last_data().await,
+        indoc!(
+            r#"
+            +--------+----+----------------------+------------+-------+-------+
+            | offset | op | system_time          | event_time | block | value |
+            +--------+----+----------------------+------------+-------+-------+
+            | 6      | 1  | 2010-01-03T12:00:00Z | 2021-01-04 | d     | 4     |
+            | 7      | 0  | 2010-01-03T12:00:00Z | 2021-01-05 | e     | 5     |
+            +--------+----+----------------------+------------+-------+-------+
+            "#
+        ),
+    )
+    .await;
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[test_group::group(engine, ingest, datafusion)]
+#[test_log::test(tokio::test)]
+async fn test_data_writer_retract_previous_duplicates() {
+    let mut harness = Harness::new(vec![MetadataFactory::set_polling_source()
+        .merge(odf::MergeStrategyAppend {})
+        .build()
+        .into()])
+    .await;
+
+    harness
+        .write(
+            indoc!(
+                r#"
+                event_time,block,value
+                2021-01-01,a,1
+                2021-01-01,a,1
+                "#
+            ),
+            "event_time DATE, block STRING, value BIGINT",
+        )
+        .await
+        .unwrap();
+
+    // Both copies of the record are retracted
+    harness.set_system_time(Utc.with_ymd_and_hms(2010, 1, 2, 12, 0, 0).unwrap());
+
+    harness
+        .write_opts(
+            indoc!(
+                r#"
+                event_time,block,value
+                2021-01-02,b,2
+                "#
+            ),
+            "event_time DATE, block STRING, value BIGINT",
+            None,
+            Some(col("block").eq(lit("a"))),
+        )
+        .await
+        .unwrap();
+
+    assert_data_eq(
+        harness.get_last_data().await,
+        indoc!(
+            r#"
+            +--------+----+----------------------+------------+-------+-------+
+            | offset | op | system_time          | event_time | block | value |
+            +--------+----+----------------------+------------+-------+-------+
+            | 2      | 1  | 2010-01-02T12:00:00Z | 2021-01-01 | a     | 1     |
+            | 3      | 1  | 2010-01-02T12:00:00Z | 2021-01-01 | a     | 1     |
+            | 4      | 0  | 2010-01-02T12:00:00Z | 2021-01-02 | b     | 2     |
+            +--------+----+----------------------+------------+-------+-------+
+            "#
+        ),
+    )
+    .await;
+
+    // The same record appended again is retracted exactly once, despite the
+    // equal records that were retracted earlier
+    harness.set_system_time(Utc.with_ymd_and_hms(2010, 1, 3, 12, 0, 0).unwrap());
+
+    harness
+        .write(
+            indoc!(
+                r#"
+                event_time,block,value
+                2021-01-01,a,1
+                "#
+            ),
+            "event_time DATE, block STRING, value BIGINT",
+        )
+        .await
+        .unwrap();
+
+    harness.set_system_time(Utc.with_ymd_and_hms(2010, 1, 4, 12, 0, 0).unwrap());
+
+    harness
+        .write_opts(
+            indoc!(
+                r#"
+                event_time,block,value
+                2021-01-03,c,3
+                "#
+            ),
+            "event_time DATE, block STRING, value BIGINT",
+            None,
+            Some(col("block").eq(lit("a"))),
+        )
+        .await
+        .unwrap();
+
+    assert_data_eq(
+        harness.get_last_data().await,
+        indoc!(
+            r#"
+            +--------+----+----------------------+------------+-------+-------+
+            | offset | op | system_time          | event_time | block | value |
+            +--------+----+----------------------+------------+-------+-------+
+            | 6      | 1  | 2010-01-04T12:00:00Z | 2021-01-01 | a     | 1     |
+            | 7      | 0  | 2010-01-04T12:00:00Z | 2021-01-03 | c     | 3     |
+            +--------+----+----------------------+------------+-------+-------+
+            "#
+        ),
+    )
+    .await;
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
 #[test_group::group(engine, ingest, datafusion)]
 #[test_log::test(tokio::test)]
 async fn test_data_writer_snapshot_orders_by_pk_and_operation_type() {
@@ -631,6 +836,138 @@ async fn test_data_writer_snapshot_orders_by_pk_and_operation_type() {
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
 
+#[test_group::group(engine, ingest, datafusion)]
+#[test_log::test(tokio::test)]
+async fn test_data_writer_snapshot_uses_merge_state_checkpoint() {
+    let mut harness = Harness::new(vec![MetadataFactory::set_polling_source()
+        .merge(odf::MergeStrategySnapshot {
+            primary_key: vec!["city".to_string()],
+            compare_columns: None,
+        })
+        .build()
+        .into()])
+    .await;
+
+    // Round 1
+    harness
+        .write(
+            indoc!(
+                r#"
+                city,population
+                A,1000
+                B,2000
+                C,3000
+                "#
+            ),
+            "city STRING, population BIGINT",
+        )
+        .await
+        .unwrap();
+
+    assert_data_eq(
+        harness.get_last_checkpoint().await,
+        indoc!(
+            r#"
+            +--------+----+----------------------+----------------------+------+------------+
+            | offset | op | system_time          | event_time           | city | population |
+            +--------+----+----------------------+----------------------+------+------------+
+            | 0      | 0  | 2010-01-01T12:00:00Z | 2000-01-01T12:00:00Z | A    | 1000       |
+            | 1      | 0  | 2010-01-01T12:00:00Z | 2000-01-01T12:00:00Z | B    | 2000       |
+            | 2      | 0  | 2010-01-01T12:00:00Z | 2000-01-01T12:00:00Z | C    | 3000       |
+            +--------+----+----------------------+----------------------+------+------------+
+            "#
+        ),
+    )
+    .await;
+
+    // Remove previous data to ensure the merge relies only on the checkpoint
+    std::fs::remove_file(harness.get_last_data_file().await).unwrap();
+
+    // Round 2
+    harness.set_system_time(Utc.with_ymd_and_hms(2010, 1, 2, 12, 0, 0).unwrap());
+    harness.set_source_event_time(Utc.with_ymd_and_hms(2000, 1, 2, 12, 0, 0).unwrap());
+
+    let res = harness
+        .write(
+            indoc!(
+                r#"
+                city,population
+                A,1000
+                B,2500
+                D,4000
+                "#
+            ),
+            "city STRING, population BIGINT",
+        )
+        .await
+        .unwrap();
+
+    assert_data_eq(
+        harness.get_last_data().await,
+        indoc!(
+            r#"
+            +--------+----+----------------------+----------------------+------+------------+
+            | offset | op | system_time          | event_time           | city | population |
+            +--------+----+----------------------+----------------------+------+------------+
+            | 3      | 2  | 2010-01-02T12:00:00Z | 2000-01-01T12:00:00Z | B    | 2000       |
+            | 4      | 3  | 2010-01-02T12:00:00Z | 2000-01-02T12:00:00Z | B    | 2500       |
+            | 5      | 1  | 2010-01-02T12:00:00Z | 2000-01-01T12:00:00Z | C    | 3000       |
+            | 6      | 0  | 2010-01-02T12:00:00Z | 2000-01-02T12:00:00Z | D    | 4000       |
+            +--------+----+----------------------+----------------------+------+------------+
+            "#
+        ),
+    )
+    .await;
+
+    assert_data_eq(
+        harness.get_last_checkpoint().await,
+        indoc!(
+            r#"
+            +--------+----+----------------------+----------------------+------+------------+
+            | offset | op | system_time          | event_time           | city | population |
+            +--------+----+----------------------+----------------------+------+------------+
+            | 0      | 0  | 2010-01-01T12:00:00Z | 2000-01-01T12:00:00Z | A    | 1000       |
+            | 4      | 3  | 2010-01-02T12:00:00Z | 2000-01-02T12:00:00Z | B    | 2500       |
+            | 6      | 0  | 2010-01-02T12:00:00Z | 2000-01-02T12:00:00Z | D    | 4000       |
+            +--------+----+----------------------+----------------------+------+------------+
+            "#
+        ),
+    )
+    .await;
+
+    // Round 3 (only source state changed) carries the checkpoint over
+    let prev_checkpoint = res.add_data_block.unwrap().event.new_checkpoint.unwrap();
+
+    harness.set_system_time(Utc.with_ymd_and_hms(2010, 1, 3, 12, 0, 0).unwrap());
+
+    let res = harness
+        .write_opts(
+            indoc!(
+                r#"
+                city,population
+                A,1000
+                B,2500
+                D,4000
+                "#
+            ),
+            "city STRING, population BIGINT",
+            Some(odf::SourceState {
+                source_name: odf::SourceState::DEFAULT_SOURCE_NAME.to_string(),
+                kind: "odf/etag".to_string(),
+                value: "123".to_string(),
+            }),
+            None,
+        )
+        .await
+        .unwrap();
+
+    let new_block = res.add_data_block.unwrap();
+    assert_eq!(new_block.event.new_data, None);
+    assert_eq!(new_block.event.new_checkpoint, Some(prev_checkpoint));
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
 #[test_group::group(engine, ingest, datafusion)]
 #[test_log::test(tokio::test)]
 async fn test_data_writer_normalizes_timestamps_to_utc_millis() {
@@ -931,6 +1268,187 @@ async fn test_data_writer_builder_scan_push_source_with_extra_events() {
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
 
+#[test_group::group(engine, ingest, datafusion)]
+#[test_log::test(tokio::test)]
+async fn test_data_writer_expectations_fail() {
+    let mut harness = Harness::new(vec![MetadataFactory::set_polling_source()
+        .merge(odf::MergeStrategyLedger {
+            primary_key: vec!["city".to_string()],
+        })
+        .build()
+        .into()])
+    .await;
+
+    harness
+        .set_expectations(vec![
+            DatasetExpectation {
+                name: "population-known".to_string(),
+                rule: ExpectationRule::NotNull {
+                    column: "population".to_string(),
+                },
+                policy: ExpectationPolicy::Fail,
+            },
+            DatasetExpectation {
+                name: "city-unique".to_string(),
+                rule: ExpectationRule::Unique {
+                    columns: vec!["city".to_string()],
+                },
+                policy: ExpectationPolicy::Warn,
+            },
+        ])
+        .await;
+
+    let head_before = harness.get_head().await;
+
+    let res = harness
+        .write(
+            indoc!(
+                r#"
+                city,population
+                A,1000
+                A,
+                C,3000
+                "#
+            ),
+            "city STRING, population BIGINT",
+        )
+        .await;
+
+    assert_matches!(
+        res,
+        Err(WriteDataError::ExpectationsFailed(ExpectationsFailedError { check }))
+            if check.num_records == 3
+                && check.outcomes == [
+                    ExpectationOutcome {
+                        expectation_name: "population-known".to_string(),
+                        policy: ExpectationPolicy::Fail,
+                        num_violations: 1,
+                    },
+                    ExpectationOutcome {
+                        expectation_name: "city-unique".to_string(),
+                        policy: ExpectationPolicy::Warn,
+                        num_violations: 2,
+                    },
+                ]
+    );
+
+    // Nothing was committed
+    assert_eq!(harness.get_head().await, head_before);
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[test_group::group(engine, ingest, datafusion)]
+#[test_log::test(tokio::test)]
+async fn test_data_writer_expectations_quarantine() {
+    let mut harness = Harness::new(vec![MetadataFactory::set_polling_source()
+        .merge(odf::MergeStrategyLedger {
+            primary_key: vec!["city".to_string()],
+        })
+        .build()
+        .into()])
+    .await;
+
+    harness
+        .set_expectations(vec![
+            DatasetExpectation {
+                name: "population-positive".to_string(),
+                rule: ExpectationRule::Range {
+                    column: "population".to_string(),
+                    min: Some(0.0),
+                    max: None,
+                },
+                policy: ExpectationPolicy::Quarantine,
+            },
+            DatasetExpectation {
+                name: "city-code".to_string(),
+                rule: ExpectationRule::Regex {
+                    column: "city".to_string(),
+                    pattern: "^[A-Z]$".to_string(),
+                },
+                policy: ExpectationPolicy::Quarantine,
+            },
+            DatasetExpectation {
+                name: "not-empty".to_string(),
+                rule: ExpectationRule::RowCount {
+                    min: Some(1),
+                    max: None,
+                },
+                policy: ExpectationPolicy::Fail,
+            },
+        ])
+        .await;
+
+    let res = harness
+        .write(
+            indoc!(
+                r#"
+                city,population
+                A,1000
+                B,-2000
+                cc,3000
+                D,4000
+                "#
+            ),
+            "city STRING, population BIGINT",
+        )
+        .await
+        .unwrap();
+
+    let check = res.expectations_check.unwrap();
+    assert_eq!(check.num_records, 4);
+    assert_eq!(check.num_quarantined, 2);
+    assert_eq!(
+        check
+            .outcomes
+            .iter()
+            .map(|o| o.num_violations)
+            .collect::<Vec<_>>(),
+        [1, 1, 0]
+    );
+
+    assert_data_eq(
+        harness.get_last_data().await,
+        indoc!(
+            r#"
+            +--------+----+----------------------+----------------------+------+------------+
+            | offset | op | system_time          | event_time           | city | population |
+            +--------+----+----------------------+----------------------+------+------------+
+            | 0      | 0  | 2010-01-01T12:00:00Z | 2000-01-01T12:00:00Z | A    | 1000       |
+            | 1      | 0  | 2010-01-01T12:00:00Z | 2000-01-01T12:00:00Z | D    | 4000       |
+            +--------+----+----------------------+----------------------+------+------------+
+            "#
+        ),
+    )
+    .await;
+
+    // Quarantined records are kept along with the dataset
+    let quarantined = harness
+        .dataset
+        .as_info_repo()
+        .get("quarantine-20100101T120000.000Z.parquet")
+        .await
+        .unwrap();
+
+    let quarantine_path = harness.temp_dir.path().join("quarantined.parquet");
+    std::fs::write(&quarantine_path, &quarantined).unwrap();
+
+    let num_quarantined = harness
+        .ctx
+        .read_parquet(
+            quarantine_path.to_str().unwrap(),
+            ParquetReadOptions::default(),
+        )
+        .await
+        .unwrap()
+        .count()
+        .await
+        .unwrap();
+    assert_eq!(num_quarantined, 2);
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
 struct Harness {
     temp_dir: tempfile::TempDir,
     dataset: Arc<dyn Dataset>,
@@ -1014,6 +1532,23 @@ impl Harness {
         self.source_event_time = t;
     }
 
+    async fn set_expectations(&mut self, expectations: Vec<DatasetExpectation>) {
+        self.writer = DataWriterDataFusion::builder(self.dataset.clone(), self.ctx.clone())
+            .with_expectations(expectations)
+            .with_metadata_state_scanned(None)
+            .await
+            .unwrap()
+            .build();
+    }
+
+    async fn get_head(&self) -> odf::Multihash {
+        self.dataset
+            .as_metadata_chain()
+            .resolve_ref(&BlockRef::Head)
+            .await
+            .unwrap()
+    }
+
     async fn reset_writer(&mut self) {
         self.writer = DataWriterDataFusion::builder(self.dataset.clone(), self.ctx.clone())
             .with_metadata_state_scanned(None)
@@ -1027,6 +1562,7 @@ impl Harness {
         data: &str,
         schema: &str,
         new_source_state: Option<odf::SourceState>,
+        retract_previous: Option<Expr>,
     ) -> Result<WriteDataResult, WriteDataError> {
         let df = if data.is_empty() {
             None
@@ -1059,6 +1595,7 @@ impl Harness {
                     source_event_time: self.source_event_time,
                     new_watermark: None,
                     new_source_state,
+                    retract_previous,
                     data_staging_path: self.temp_dir.path().join("write.tmp"),
                 },
             )
@@ -1066,7 +1603,7 @@ impl Harness {
     }
 
     async fn write(&mut self, data: &str, schema: &str) -> Result<WriteDataResult, WriteDataError> {
-        self.write_opts(data, schema, None).await
+        self.write_opts(data, schema, None, None).await
     }
 
     async fn get_last_schema_block(
@@ -1113,6 +1650,29 @@ impl Harness {
         .unwrap()
     }
 
+    async fn get_last_checkpoint(&self) -> DataFrame {
+        let block = self.get_last_data_block().await;
+
+        let checkpoint_file = kamu_data_utils::data::local_url::into_local_path(
+            self.dataset
+                .as_checkpoint_repo()
+                .get_internal_url(&block.event.new_checkpoint.unwrap().physical_hash)
+                .await,
+        )
+        .unwrap();
+
+        self.ctx
+            .read_parquet(
+                checkpoint_file.to_string_lossy().as_ref(),
+                ParquetReadOptions {
+                    file_extension: "",
+                    ..Default::default()
+                },
+            )
+            .await
+            .unwrap()
+    }
+
     async fn get_last_data(&self) -> DataFrame {
         let part_file = self.get_last_data_file().await;
         self.ctx
@@ -11,10 +11,12 @@ mod engine;
 mod ingest;
 mod repos;
 mod test_compact_service_impl;
+mod test_dataset_archive_service_impl;
 mod test_dataset_changes_service_impl;
 mod test_dataset_ownership_service_inmem;
 mod test_datasets_filtering;
 mod test_dependency_graph_inmem;
+mod test_dependency_graph_persistent;
 mod test_metadata_chain_comparator;
 mod test_pull_service_impl;
 mod test_query_service_impl;
@@ -9,6 +9,7 @@
 
 mod test_dataset_impl;
 mod test_dataset_repository_local_fs;
+mod test_dataset_repository_object_store;
 mod test_dataset_repository_s3;
 mod test_dataset_repository_shared;
 mod test_metadata_block_repository_caching_inmem;
@@ -0,0 +1,223 @@
+// Copyright Kamu Data, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+use std::sync::Arc;
+
+use dill::*;
+use kamu::testing::LocalGcsServer;
+use kamu::utils::object_store_context::{ObjectStoreConfig, ObjectStoreContext};
+use kamu::{
+    CreateDatasetFromSnapshotUseCaseImpl,
+    DatasetRepositoryObjectStore,
+    DatasetRepositoryWriter,
+    ObjectStoreRegistryCache,
+};
+use kamu_accounts::{CurrentAccountSubject, DEFAULT_ACCOUNT_NAME};
+use kamu_core::{CreateDatasetFromSnapshotUseCase, DatasetRepository};
+use messaging_outbox::{Outbox, OutboxImmediateImpl};
+use time_source::SystemTimeSourceDefault;
+
+use super::test_dataset_repository_shared;
+use crate::utils::LocalAzureServer;
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+struct ObjectStoreRepoHarness {
+    _catalog: dill::Catalog,
+    dataset_repo: Arc<DatasetRepositoryObjectStore>,
+    create_dataset_from_snapshot: Arc<dyn CreateDatasetFromSnapshotUseCase>,
+}
+
+impl ObjectStoreRepoHarness {
+    pub fn create_gcs(gcs: &LocalGcsServer, multi_tenant: bool, registry_caching: bool) -> Self {
+        Self::create(
+            &gcs.url,
+            &gcs.object_store_config,
+            multi_tenant,
+            registry_caching,
+        )
+    }
+
+    pub fn create_azure(
+        azure: &LocalAzureServer,
+        multi_tenant: bool,
+        registry_caching: bool,
+    ) -> Self {
+        Self::create(
+            &azure.url,
+            &azure.object_store_config,
+            multi_tenant,
+            registry_caching,
+        )
+    }
+
+    fn create(
+        url: &url::Url,
+        config: &ObjectStoreConfig,
+        multi_tenant: bool,
+        registry_caching: bool,
+    ) -> Self {
+        let context = ObjectStoreContext::from_url_with_config(url, config).unwrap();
+
+        let mut b = dill::CatalogBuilder::new();
+
+        b.add::<SystemTimeSourceDefault>()
+            .add_builder(
+                messaging_outbox::OutboxImmediateImpl::builder()
+                    .with_consumer_filter(messaging_outbox::ConsumerFilter::AllConsumers),
+            )
+            .bind::<dyn Outbox, OutboxImmediateImpl>()
+            .add_value(CurrentAccountSubject::new_test())
+            .add_builder(
+                DatasetRepositoryObjectStore::builder()
+                    .with_context(context)
+                    .with_multi_tenant(multi_tenant),
+            )
+            .bind::<dyn DatasetRepository, DatasetRepositoryObjectStore>()
+            .bind::<dyn DatasetRepositoryWriter, DatasetRepositoryObjectStore>()
+            .add::<CreateDatasetFromSnapshotUseCaseImpl>();
+
+        if registry_caching {
+            b.add::<ObjectStoreRegistryCache>();
+        }
+
+        let catalog = b.build();
+
+        let dataset_repo = catalog.get_one().unwrap();
+
+        let create_dataset_from_snapshot = catalog.get_one().unwrap();
+
+        Self {
+            _catalog: catalog,
+            dataset_repo,
+            create_dataset_from_snapshot,
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[test_group::group(containerized)]
+#[tokio::test]
+async fn test_create_dataset() {
+    let gcs = LocalGcsServer::new().await;
+    let harness = ObjectStoreRepoHarness::create_gcs(&gcs, false, false);
+
+    test_dataset_repository_shared::test_create_dataset(harness.dataset_repo.as_ref(), None).await;
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[test_group::group(containerized)]
+#[tokio::test]
+async fn test_create_dataset_multi_tenant_with_caching() {
+    let gcs = LocalGcsServer::new().await;
+    let harness = ObjectStoreRepoHarness::create_gcs(&gcs, true, true);
+
+    test_dataset_repository_shared::test_create_dataset(
+        harness.dataset_repo.as_ref(),
+        Some(DEFAULT_ACCOUNT_NAME.clone()),
+    )
+    .await;
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[test_group::group(containerized)]
+#[tokio::test]
+async fn test_rename_dataset() {
+    let gcs = LocalGcsServer::new().await;
+    let harness = ObjectStoreRepoHarness::create_gcs(&gcs, false, false);
+
+    test_dataset_repository_shared::test_rename_dataset(harness.dataset_repo.as_ref(), None).await;
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[test_group::group(containerized)]
+#[tokio::test]
+async fn test_delete_dataset() {
+    let gcs = LocalGcsServer::new().await;
+    let harness = ObjectStoreRepoHarness::create_gcs(&gcs, false, false);
+
+    test_dataset_repository_shared::test_delete_dataset(
+        harness.dataset_repo.as_ref(),
+        harness.create_dataset_from_snapshot.as_ref(),
+        None,
+    )
+    .await;
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[test_group::group(containerized)]
+#[tokio::test]
+async fn test_iterate_datasets_multi_tenant() {
+    let gcs = LocalGcsServer::new().await;
+    let harness = ObjectStoreRepoHarness::create_gcs(&gcs, true, false);
+
+    test_dataset_repository_shared::test_iterate_datasets_multi_tenant(
+        harness.dataset_repo.as_ref(),
+    )
+    .await;
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[test_group::group(containerized)]
+#[tokio::test]
+async fn test_create_dataset_azure() {
+    let azure = LocalAzureServer::new().await;
+    let harness = ObjectStoreRepoHarness::create_azure(&azure, false, false);
+
+    test_dataset_repository_shared::test_create_dataset(harness.dataset_repo.as_ref(), None).await;
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[test_group::group(containerized)]
+#[tokio::test]
+async fn test_rename_dataset_azure() {
+    let azure = LocalAzureServer::new().await;
+    let harness = ObjectStoreRepoHarness::create_azure(&azure, false, false);
+
+    test_dataset_repository_shared::test_rename_dataset(harness.dataset_repo.as_ref(), None).await;
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[test_group::group(containerized)]
+#[tokio::test]
+async fn test_delete_dataset_azure() {
+    let azure = LocalAzureServer::new().await;
+    let harness = ObjectStoreRepoHarness::create_azure(&azure, false, false);
+
+    test_dataset_repository_shared::test_delete_dataset(
+        harness.dataset_repo.as_ref(),
+        harness.create_dataset_from_snapshot.as_ref(),
+        None,
+    )
+    .await;
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[test_group::group(containerized)]
+#[tokio::test]
+async fn test_iterate_datasets_multi_tenant_azure() {
+    let azure = LocalAzureServer::new().await;
+    let harness = ObjectStoreRepoHarness::create_azure(&azure, true, false);
+
+    test_dataset_repository_shared::test_iterate_datasets_multi_tenant(
+        harness.dataset_repo.as_ref(),
+    )
+    .await;
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
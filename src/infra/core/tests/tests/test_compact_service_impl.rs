@@ -14,7 +14,14 @@ use chrono::{DateTime, NaiveDate, TimeDelta, TimeZone, Utc};
 use datafusion::execution::config::SessionConfig;
 use datafusion::execution::context::SessionContext;
 use dill::Component;
-use domain::{CompactionError, CompactionOptions, CompactionResult, CompactionService};
+use domain::{
+    CompactionCompression,
+    CompactionDataLayout,
+    CompactionError,
+    CompactionOptions,
+    CompactionResult,
+    CompactionService,
+};
 use futures::TryStreamExt;
 use indoc::{formatdoc, indoc};
 use kamu::domain::*;
@@ -23,6 +30,7 @@ use kamu::utils::s3_context::S3Context;
 use kamu::*;
 use kamu_accounts::CurrentAccountSubject;
 use kamu_core::auth;
+use messaging_outbox::DummyOutboxImpl;
 use opendatafabric::*;
 use time_source::{SystemTimeSource, SystemTimeSourceStub};
 
@@ -624,6 +632,144 @@ async fn test_dataset_compaction_limits() {
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
 
+#[test_group::group(ingest, datafusion, compact)]
+#[tokio::test]
+async fn test_dataset_compaction_data_layout() {
+    use datafusion::parquet::basic::Compression;
+    use datafusion::parquet::file::reader::{FileReader, SerializedFileReader};
+
+    let harness = CompactTestHarness::new();
+
+    let created = harness.create_test_root_dataset().await;
+    let dataset_ref = created.dataset_handle.as_local_ref();
+
+    let data_helper = harness.dataset_data_helper(&dataset_ref).await;
+
+    harness
+        .ingest_data(
+            indoc!(
+                "
+                date,city,population
+                2020-01-01,C,1000
+                2020-01-02,A,2000
+                2020-01-03,B,3000
+                "
+            )
+            .to_string(),
+            &dataset_ref,
+        )
+        .await;
+    harness
+        .ingest_data(
+            indoc!(
+                "
+                date,city,population
+                2020-01-04,B,4000
+                2020-01-05,C,5000
+                2020-01-06,A,6000
+                "
+            )
+            .to_string(),
+            &dataset_ref,
+        )
+        .await;
+
+    let data_layout = CompactionDataLayout {
+        sort_by: vec!["city".to_string()],
+        max_row_group_size: Some(2),
+        page_index: Some(true),
+        bloom_filter_columns: vec!["city".to_string()],
+        compression: Some(CompactionCompression::Zstd { level: 9 }),
+    };
+
+    // Unknown columns are rejected
+    assert_matches!(
+        harness
+            .compaction_svc
+            .compact_dataset(
+                &created.dataset_handle,
+                CompactionOptions {
+                    data_layout: CompactionDataLayout {
+                        bloom_filter_columns: vec!["town".to_string()],
+                        ..data_layout.clone()
+                    },
+                    ..CompactionOptions::default()
+                },
+                Some(Arc::new(NullCompactionListener {}))
+            )
+            .await,
+        Err(CompactionError::InvalidDataLayout(_)),
+    );
+
+    assert_matches!(
+        harness
+            .compaction_svc
+            .compact_dataset(
+                &created.dataset_handle,
+                CompactionOptions {
+                    data_layout,
+                    ..CompactionOptions::default()
+                },
+                Some(Arc::new(NullCompactionListener {}))
+            )
+            .await,
+        Ok(CompactionResult::Success {
+            new_num_blocks: 5,
+            old_num_blocks: 6,
+            ..
+        }),
+    );
+    assert!(harness.verify_dataset(&dataset_ref).await);
+
+    // Records are sorted by city with ties resolved by the original order, and
+    // offsets are re-assigned to stay monotonic
+    data_helper
+        .assert_last_data_records_eq(indoc!(
+            r#"
+            +--------+----+----------------------+----------------------+------+------------+
+            | offset | op | system_time          | date                 | city | population |
+            +--------+----+----------------------+----------------------+------+------------+
+            | 0      | 0  | 2050-01-01T12:00:00Z | 2020-01-02T00:00:00Z | A    | 2000       |
+            | 1      | 0  | 2050-01-01T12:00:00Z | 2020-01-06T00:00:00Z | A    | 6000       |
+            | 2      | 0  | 2050-01-01T12:00:00Z | 2020-01-03T00:00:00Z | B    | 3000       |
+            | 3      | 0  | 2050-01-01T12:00:00Z | 2020-01-04T00:00:00Z | B    | 4000       |
+            | 4      | 0  | 2050-01-01T12:00:00Z | 2020-01-01T00:00:00Z | C    | 1000       |
+            | 5      | 0  | 2050-01-01T12:00:00Z | 2020-01-05T00:00:00Z | C    | 5000       |
+            +--------+----+----------------------+----------------------+------+------------+
+            "#
+        ))
+        .await;
+
+    let new_add_data = data_helper.get_last_block_typed::<AddData>().await;
+    CompactTestHarness::assert_offset_interval_eq(
+        &new_add_data.event,
+        &OffsetInterval { start: 0, end: 5 },
+    );
+
+    // Check physical layout of the file
+    let data_path = data_helper.get_last_data_file().await;
+    let reader = SerializedFileReader::new(std::fs::File::open(data_path).unwrap()).unwrap();
+    let metadata = reader.metadata();
+
+    assert_eq!(metadata.num_row_groups(), 3);
+
+    let city_column_index = metadata
+        .file_metadata()
+        .schema_descr()
+        .columns()
+        .iter()
+        .position(|c| c.name() == "city")
+        .unwrap();
+
+    for row_group in metadata.row_groups() {
+        let city_column = row_group.column(city_column_index);
+        assert_matches!(city_column.compression(), Compression::ZSTD(_));
+        assert!(city_column.bloom_filter_offset().is_some());
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
 #[test_group::group(ingest, datafusion, compact)]
 #[tokio::test]
 async fn test_dataset_compaction_keep_all_non_data_blocks() {
@@ -1103,6 +1249,7 @@ impl CompactTestHarness {
             .bind::<dyn EngineProvisioner, mock_engine_provisioner::MockEngineProvisioner>()
             .add::<TransformServiceImpl>()
             .add::<VerificationServiceImpl>()
+            .add::<DummyOutboxImpl>()
             .build();
 
         let dataset_repo = catalog.get_one::<dyn DatasetRepository>().unwrap();
@@ -1155,6 +1302,7 @@ impl CompactTestHarness {
             .add::<PushIngestServiceImpl>()
             .add::<DataFormatRegistryImpl>()
             .add::<CompactionServiceImpl>()
+            .add::<DummyOutboxImpl>()
             .add_value(CurrentAccountSubject::new_test())
             .build();
 
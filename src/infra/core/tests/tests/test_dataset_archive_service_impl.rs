@@ -0,0 +1,415 @@
+// Copyright Kamu Data, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+use std::assert_matches::assert_matches;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+use dill::Component;
+use kamu::domain::*;
+use kamu::testing::*;
+use kamu::*;
+use kamu_accounts::CurrentAccountSubject;
+use messaging_outbox::DummyOutboxImpl;
+use opendatafabric::*;
+use tempfile::TempDir;
+use time_source::SystemTimeSourceDefault;
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[test_log::test(tokio::test)]
+async fn test_export_import_roundtrip() {
+    let tmp_dir = tempfile::tempdir().unwrap();
+    let src = ArchiveTestHarness::new(tmp_dir.path().join("src"));
+    let dst = ArchiveTestHarness::new(tmp_dir.path().join("dst"));
+
+    let foo = src.create_root_dataset("foo").await;
+    DatasetTestHelper::append_random_data(src.dataset_repo.as_ref(), &foo.alias, 32).await;
+    let head =
+        DatasetTestHelper::append_random_data(src.dataset_repo.as_ref(), &foo.alias, 32).await;
+
+    let archive_path = tmp_dir.path().join("bundle.tar.zst");
+    let export_result = src
+        .dataset_archive_svc
+        .export_datasets(
+            vec![foo.as_local_ref()],
+            &archive_path,
+            ExportOptions::default(),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(
+        export_result.datasets,
+        vec![ArchivedDataset {
+            id: foo.id.clone(),
+            alias: foo.alias.clone(),
+            head: head.clone(),
+        }]
+    );
+    assert!(archive_path.is_file());
+
+    let import_result = dst
+        .dataset_archive_svc
+        .import_datasets(&archive_path, ImportOptions::default())
+        .await
+        .unwrap();
+
+    assert_eq!(import_result.datasets.len(), 1);
+    assert_matches!(
+        &import_result.datasets[0].1,
+        SyncResult::Updated { old_head: None, new_head, num_blocks: 4 } if *new_head == head
+    );
+
+    let imported = dst
+        .dataset_repo
+        .resolve_dataset_ref(&foo.alias.as_local_ref())
+        .await
+        .unwrap();
+    assert_eq!(imported.id, foo.id);
+
+    DatasetTestHelper::assert_datasets_in_sync(
+        &src.dataset_layout(&foo).await,
+        &dst.dataset_layout(&imported).await,
+    );
+
+    // Importing the same archive again is a no-op
+    let import_result = dst
+        .dataset_archive_svc
+        .import_datasets(&archive_path, ImportOptions::default())
+        .await
+        .unwrap();
+    assert_matches!(&import_result.datasets[0].1, SyncResult::UpToDate);
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[test_log::test(tokio::test)]
+async fn test_export_recursive_includes_upstream_datasets() {
+    let tmp_dir = tempfile::tempdir().unwrap();
+    let src = ArchiveTestHarness::new(tmp_dir.path().join("src"));
+    let dst = ArchiveTestHarness::new(tmp_dir.path().join("dst"));
+
+    let foo = src.create_root_dataset("foo").await;
+    let bar = src
+        .create_derived_dataset("bar", vec![foo.alias.clone()])
+        .await;
+    src.init_dependency_graph().await;
+
+    let archive_path = tmp_dir.path().join("bundle.tar.zst");
+    let export_result = src
+        .dataset_archive_svc
+        .export_datasets(
+            vec![bar.as_local_ref()],
+            &archive_path,
+            ExportOptions { recursive: true },
+        )
+        .await
+        .unwrap();
+
+    let exported: Vec<_> = export_result
+        .datasets
+        .iter()
+        .map(|d| d.alias.to_string())
+        .collect();
+    assert_eq!(exported, vec!["foo", "bar"]);
+
+    dst.dataset_archive_svc
+        .import_datasets(&archive_path, ImportOptions::default())
+        .await
+        .unwrap();
+
+    for hdl in [&foo, &bar] {
+        let imported = dst
+            .dataset_repo
+            .resolve_dataset_ref(&hdl.alias.as_local_ref())
+            .await
+            .unwrap();
+        assert_eq!(imported.id, hdl.id);
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[test_log::test(tokio::test)]
+async fn test_import_rejects_invalid_archive() {
+    let tmp_dir = tempfile::tempdir().unwrap();
+    let dst = ArchiveTestHarness::new(tmp_dir.path().join("dst"));
+
+    let archive_path = tmp_dir.path().join("bundle.tar.zst");
+    std::fs::write(&archive_path, b"definitely not an archive").unwrap();
+
+    assert_matches!(
+        dst.dataset_archive_svc
+            .import_datasets(&archive_path, ImportOptions::default())
+            .await,
+        Err(ImportError::ArchiveInvalid(_))
+    );
+}
+
+#[test_log::test(tokio::test)]
+async fn test_import_rejects_tampered_data_file() {
+    let tmp_dir = tempfile::tempdir().unwrap();
+    let src = ArchiveTestHarness::new(tmp_dir.path().join("src"));
+    let dst = ArchiveTestHarness::new(tmp_dir.path().join("dst"));
+
+    let foo = src.create_root_dataset("foo").await;
+    DatasetTestHelper::append_random_data(src.dataset_repo.as_ref(), &foo.alias, 32).await;
+
+    let archive_path = tmp_dir.path().join("bundle.tar.zst");
+    src.dataset_archive_svc
+        .export_datasets(
+            vec![foo.as_local_ref()],
+            &archive_path,
+            ExportOptions::default(),
+        )
+        .await
+        .unwrap();
+
+    // Replace the data file with other content that does not match its hash
+    tamper_archive(&archive_path, |staging_dir| {
+        let data_file = list_files(&staging_dir.join("datasets"), "data")
+            .pop()
+            .unwrap();
+        std::fs::write(data_file, b"tampered").unwrap();
+    });
+
+    assert_matches!(
+        dst.dataset_archive_svc
+            .import_datasets(&archive_path, ImportOptions::default())
+            .await,
+        Err(ImportError::SyncFailed { .. })
+    );
+}
+
+#[test_log::test(tokio::test)]
+async fn test_import_rejects_tampered_block() {
+    let tmp_dir = tempfile::tempdir().unwrap();
+    let src = ArchiveTestHarness::new(tmp_dir.path().join("src"));
+    let dst = ArchiveTestHarness::new(tmp_dir.path().join("dst"));
+
+    let foo = src.create_root_dataset("foo").await;
+    DatasetTestHelper::append_random_data(src.dataset_repo.as_ref(), &foo.alias, 32).await;
+
+    let archive_path = tmp_dir.path().join("bundle.tar.zst");
+    src.dataset_archive_svc
+        .export_datasets(
+            vec![foo.as_local_ref()],
+            &archive_path,
+            ExportOptions::default(),
+        )
+        .await
+        .unwrap();
+
+    // Swap contents of two blocks so that both are well-formed but no longer match
+    // the hashes they are referenced by
+    tamper_archive(&archive_path, |staging_dir| {
+        let blocks = list_files(&staging_dir.join("datasets"), "blocks");
+        let (a, b) = (&blocks[0], &blocks[1]);
+        let (content_a, content_b) = (std::fs::read(a).unwrap(), std::fs::read(b).unwrap());
+        std::fs::write(a, content_b).unwrap();
+        std::fs::write(b, content_a).unwrap();
+    });
+
+    assert_matches!(
+        dst.dataset_archive_svc
+            .import_datasets(&archive_path, ImportOptions::default())
+            .await,
+        Err(ImportError::SyncFailed { .. })
+    );
+}
+
+#[test_log::test(tokio::test)]
+async fn test_import_rejects_dataset_id_mismatch() {
+    let tmp_dir = tempfile::tempdir().unwrap();
+    let src = ArchiveTestHarness::new(tmp_dir.path().join("src"));
+    let dst = ArchiveTestHarness::new(tmp_dir.path().join("dst"));
+
+    let foo = src.create_root_dataset("foo").await;
+    let bar = src.create_root_dataset("bar").await;
+
+    let archive_path = tmp_dir.path().join("bundle.tar.zst");
+    src.dataset_archive_svc
+        .export_datasets(
+            vec![foo.as_local_ref(), bar.as_local_ref()],
+            &archive_path,
+            ExportOptions::default(),
+        )
+        .await
+        .unwrap();
+
+    // Swap the chains, so that both are valid but don't belong to the datasets
+    // the manifest lists them under
+    tamper_archive(&archive_path, |staging_dir| {
+        let datasets_dir = staging_dir.join("datasets");
+        let foo_dir = datasets_dir.join(foo.id.as_multibase().to_stack_string());
+        let bar_dir = datasets_dir.join(bar.id.as_multibase().to_stack_string());
+        let tmp_dir = datasets_dir.join("tmp");
+        std::fs::rename(&foo_dir, &tmp_dir).unwrap();
+        std::fs::rename(&bar_dir, &foo_dir).unwrap();
+        std::fs::rename(&tmp_dir, &bar_dir).unwrap();
+    });
+
+    assert_matches!(
+        dst.dataset_archive_svc
+            .import_datasets(&archive_path, ImportOptions::default())
+            .await,
+        Err(ImportError::ArchiveInvalid(_))
+    );
+
+    // Nothing was imported under a foreign identity
+    assert_matches!(
+        dst.dataset_repo
+            .find_dataset_by_ref(&foo.alias.as_local_ref())
+            .await,
+        Err(GetDatasetError::NotFound(_))
+    );
+    assert_matches!(
+        dst.dataset_repo
+            .find_dataset_by_ref(&bar.alias.as_local_ref())
+            .await,
+        Err(GetDatasetError::NotFound(_))
+    );
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Unpacks the archive, lets the callback modify its contents and packs it back
+fn tamper_archive(archive_path: &Path, tamper: impl FnOnce(&Path)) {
+    let staging_dir = tempfile::tempdir().unwrap();
+
+    tar::Archive::new(zstd::Decoder::new(std::fs::File::open(archive_path).unwrap()).unwrap())
+        .unpack(staging_dir.path())
+        .unwrap();
+
+    tamper(staging_dir.path());
+
+    let encoder = zstd::Encoder::new(std::fs::File::create(archive_path).unwrap(), 3)
+        .unwrap()
+        .auto_finish();
+    let mut builder = tar::Builder::new(encoder);
+    builder.append_dir_all(".", staging_dir.path()).unwrap();
+    builder.into_inner().unwrap();
+}
+
+/// Lists files located in directories with the specified name, in stable order
+fn list_files(root: &Path, parent_dir_name: &str) -> Vec<PathBuf> {
+    let mut files: Vec<_> = walkdir::WalkDir::new(root)
+        .into_iter()
+        .map(Result::unwrap)
+        .filter(|e| e.file_type().is_file())
+        .filter(|e| {
+            e.path()
+                .parent()
+                .and_then(Path::file_name)
+                .is_some_and(|n| n == parent_dir_name)
+        })
+        .map(walkdir::DirEntry::into_path)
+        .collect();
+    files.sort();
+    files
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+struct ArchiveTestHarness {
+    _workdir: TempDir,
+    dataset_repo: Arc<DatasetRepositoryLocalFs>,
+    dependency_graph_service: Arc<dyn DependencyGraphService>,
+    dataset_archive_svc: Arc<dyn DatasetArchiveService>,
+}
+
+impl ArchiveTestHarness {
+    fn new(workspace_dir: PathBuf) -> Self {
+        let workdir = tempfile::tempdir().unwrap();
+        let datasets_dir = workspace_dir.join("datasets");
+        std::fs::create_dir_all(&datasets_dir).unwrap();
+
+        let catalog = dill::CatalogBuilder::new()
+            .add::<SystemTimeSourceDefault>()
+            .add_value(RunInfoDir::new(workdir.path()))
+            .add_value(RemoteReposDir::new(workspace_dir.join("repos")))
+            .add_value(IpfsGateway::default())
+            .add_value(kamu::utils::ipfs_wrapper::IpfsClient::default())
+            .add_value(CurrentAccountSubject::new_test())
+            .add::<auth::AlwaysHappyDatasetActionAuthorizer>()
+            .add_builder(
+                DatasetRepositoryLocalFs::builder()
+                    .with_root(datasets_dir)
+                    .with_multi_tenant(false),
+            )
+            .bind::<dyn DatasetRepository, DatasetRepositoryLocalFs>()
+            .bind::<dyn DatasetRepositoryWriter, DatasetRepositoryLocalFs>()
+            .add::<RemoteRepositoryRegistryImpl>()
+            .add::<auth::DummyOdfServerAccessTokenResolver>()
+            .add::<DatasetFactoryImpl>()
+            .add::<SyncServiceImpl>()
+            .add::<DummyOutboxImpl>()
+            .add::<DummySmartTransferProtocolClient>()
+            .add::<DependencyGraphServiceInMemory>()
+            .add::<DatasetArchiveServiceImpl>()
+            .build();
+
+        Self {
+            _workdir: workdir,
+            dataset_repo: catalog.get_one().unwrap(),
+            dependency_graph_service: catalog.get_one().unwrap(),
+            dataset_archive_svc: catalog.get_one().unwrap(),
+        }
+    }
+
+    async fn create_root_dataset(&self, name: &str) -> DatasetHandle {
+        self.dataset_repo
+            .create_dataset_from_snapshot(
+                MetadataFactory::dataset_snapshot()
+                    .name(name)
+                    .kind(DatasetKind::Root)
+                    .push_event(MetadataFactory::set_data_schema().build())
+                    .build(),
+            )
+            .await
+            .unwrap()
+            .create_dataset_result
+            .dataset_handle
+    }
+
+    async fn create_derived_dataset(&self, name: &str, inputs: Vec<DatasetAlias>) -> DatasetHandle {
+        self.dataset_repo
+            .create_dataset_from_snapshot(
+                MetadataFactory::dataset_snapshot()
+                    .name(name)
+                    .kind(DatasetKind::Derivative)
+                    .push_event(
+                        MetadataFactory::set_transform()
+                            .inputs_from_refs(inputs)
+                            .build(),
+                    )
+                    .build(),
+            )
+            .await
+            .unwrap()
+            .create_dataset_result
+            .dataset_handle
+    }
+
+    async fn init_dependency_graph(&self) {
+        let repository = DependencyGraphRepositoryInMemory::new(self.dataset_repo.clone());
+        self.dependency_graph_service
+            .eager_initialization(&repository)
+            .await
+            .unwrap();
+    }
+
+    async fn dataset_layout(&self, hdl: &DatasetHandle) -> DatasetLayout {
+        self.dataset_repo
+            .get_dataset_layout(&hdl.as_local_ref())
+            .await
+            .unwrap()
+    }
+}
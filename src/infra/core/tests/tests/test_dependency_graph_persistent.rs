@@ -0,0 +1,471 @@
+// Copyright Kamu Data, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+use std::collections::BTreeSet;
+use std::sync::Arc;
+
+use database_common::NoOpDatabasePlugin;
+use dill::Component;
+use futures::{StreamExt, TryStreamExt};
+use kamu::testing::MetadataFactory;
+use kamu::*;
+use kamu_accounts::CurrentAccountSubject;
+use kamu_core::*;
+use kamu_datasets::{DatasetDependencyEntry, DatasetDependencyRepository};
+use kamu_datasets_inmem::InMemoryDatasetDependencyRepository;
+use messaging_outbox::{register_message_dispatcher, Outbox, OutboxImmediateImpl};
+use opendatafabric::*;
+use tempfile::TempDir;
+use time_source::SystemTimeSourceDefault;
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[test_log::test(tokio::test)]
+async fn test_dependencies_persisted_on_lifecycle_events() {
+    let harness = PersistentDependencyGraphHarness::new();
+
+    let foo_id = harness.create_root_dataset("foo").await;
+    let bar_id = harness.create_root_dataset("bar").await;
+    let baz_id = harness.create_derived_dataset("baz", &["foo", "bar"]).await;
+
+    assert_eq!(
+        harness.list_persisted_entries().await,
+        BTreeSet::from([
+            DatasetDependencyEntry::new(baz_id.clone(), bar_id.clone()),
+            DatasetDependencyEntry::new(baz_id.clone(), foo_id.clone()),
+        ])
+    );
+
+    harness.modify_derived_dataset("baz", &["foo"]).await;
+
+    assert_eq!(
+        harness.list_persisted_entries().await,
+        BTreeSet::from([DatasetDependencyEntry::new(baz_id.clone(), foo_id.clone())])
+    );
+
+    harness.delete_dataset("baz").await;
+
+    assert_eq!(harness.list_persisted_entries().await, BTreeSet::new());
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[test_log::test(tokio::test)]
+async fn test_persistent_repository_groups_dependencies() {
+    let harness = PersistentDependencyGraphHarness::new();
+
+    let foo_id = harness.create_root_dataset("foo").await;
+    let bar_id = harness.create_root_dataset("bar").await;
+    let baz_id = harness.create_derived_dataset("baz", &["foo", "bar"]).await;
+    let qux_id = harness.create_derived_dataset("qux", &["baz"]).await;
+
+    let repository = harness.persistent_repository();
+
+    let mut dependencies: Vec<_> = repository
+        .list_dependencies_of_all_datasets()
+        .map_ok(|mut d| {
+            d.upstream_dataset_ids.sort();
+            (d.downstream_dataset_id, d.upstream_dataset_ids)
+        })
+        .try_collect()
+        .await
+        .unwrap();
+    dependencies.sort();
+
+    let mut expected_baz_upstream = vec![foo_id, bar_id];
+    expected_baz_upstream.sort();
+
+    let mut expected = vec![
+        (baz_id.clone(), expected_baz_upstream),
+        (qux_id, vec![baz_id]),
+    ];
+    expected.sort();
+
+    assert_eq!(dependencies, expected);
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[test_log::test(tokio::test)]
+async fn test_persistent_repository_bootstraps_from_metadata_chains() {
+    let harness = PersistentDependencyGraphHarness::new();
+
+    let foo_id = harness.create_root_dataset("foo").await;
+    let bar_id = harness.create_derived_dataset("bar", &["foo"]).await;
+
+    // Simulate a workspace that existed before the database was configured
+    harness
+        .dataset_dependency_repo
+        .delete_dependencies_of_dataset(&bar_id)
+        .await
+        .unwrap();
+    assert_eq!(harness.list_persisted_entries().await, BTreeSet::new());
+
+    let dependencies: Vec<_> = harness
+        .persistent_repository()
+        .list_dependencies_of_all_datasets()
+        .map_ok(|d| (d.downstream_dataset_id, d.upstream_dataset_ids))
+        .try_collect()
+        .await
+        .unwrap();
+    assert_eq!(dependencies, vec![(bar_id.clone(), vec![foo_id.clone()])]);
+
+    assert_eq!(
+        harness.list_persisted_entries().await,
+        BTreeSet::from([DatasetDependencyEntry::new(bar_id, foo_id)])
+    );
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[test_log::test(tokio::test)]
+async fn test_persistent_repository_bootstraps_when_rows_written_before_first_load() {
+    let harness = PersistentDependencyGraphHarness::new();
+
+    let foo_id = harness.create_root_dataset("foo").await;
+    let bar_id = harness.create_derived_dataset("bar", &["foo"]).await;
+
+    // Simulate a workspace that existed before the database was configured
+    harness
+        .dataset_dependency_repo
+        .delete_dependencies_of_dataset(&bar_id)
+        .await
+        .unwrap();
+
+    // A derived dataset is created before the graph is loaded for the first time
+    let baz_id = harness.create_derived_dataset("baz", &["foo"]).await;
+    assert_eq!(
+        harness.list_persisted_entries().await,
+        BTreeSet::from([DatasetDependencyEntry::new(baz_id.clone(), foo_id.clone())])
+    );
+
+    let repository = harness.persistent_repository();
+
+    let mut dependencies: Vec<_> = repository
+        .list_dependencies_of_all_datasets()
+        .map_ok(|d| (d.downstream_dataset_id, d.upstream_dataset_ids))
+        .try_collect()
+        .await
+        .unwrap();
+    dependencies.sort();
+
+    let mut expected = vec![
+        (bar_id.clone(), vec![foo_id.clone()]),
+        (baz_id.clone(), vec![foo_id.clone()]),
+    ];
+    expected.sort();
+
+    assert_eq!(dependencies, expected);
+    assert!(harness
+        .dataset_dependency_repo
+        .is_bootstrapped()
+        .await
+        .unwrap());
+
+    // Metadata chains are not scanned again once bootstrapped
+    harness
+        .dataset_dependency_repo
+        .delete_dependencies_of_dataset(&bar_id)
+        .await
+        .unwrap();
+
+    let dependencies: Vec<_> = repository
+        .list_dependencies_of_all_datasets()
+        .map_ok(|d| (d.downstream_dataset_id, d.upstream_dataset_ids))
+        .try_collect()
+        .await
+        .unwrap();
+    assert_eq!(dependencies, vec![(baz_id, vec![foo_id])]);
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[test_log::test(tokio::test)]
+async fn test_graph_service_with_persistent_repository() {
+    let harness = PersistentDependencyGraphHarness::new();
+
+    let foo_id = harness.create_root_dataset("foo").await;
+    let bar_id = harness.create_derived_dataset("bar", &["foo"]).await;
+    let lonely_id = harness.create_root_dataset("lonely").await;
+
+    // Fresh service that was never notified about created datasets
+    let dependency_graph_service = DependencyGraphServiceInMemory::new(None);
+    let repository = harness.persistent_repository();
+    dependency_graph_service
+        .eager_initialization(&repository)
+        .await
+        .unwrap();
+
+    let downstream: Vec<_> = dependency_graph_service
+        .get_downstream_dependencies(&foo_id)
+        .await
+        .unwrap()
+        .collect()
+        .await;
+    assert_eq!(downstream, vec![bar_id.clone()]);
+
+    let upstream: Vec<_> = dependency_graph_service
+        .get_upstream_dependencies(&bar_id)
+        .await
+        .unwrap()
+        .collect()
+        .await;
+    assert_eq!(upstream, vec![foo_id]);
+
+    // Datasets without dependencies are not persisted, but still resolvable
+    let downstream: Vec<_> = dependency_graph_service
+        .get_downstream_dependencies(&lonely_id)
+        .await
+        .unwrap()
+        .collect()
+        .await;
+    assert_eq!(downstream, Vec::<DatasetID>::new());
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[test_log::test(tokio::test)]
+async fn test_consistency_checker_detects_and_repairs() {
+    let harness = PersistentDependencyGraphHarness::new();
+
+    let foo_id = harness.create_root_dataset("foo").await;
+    let bar_id = harness.create_root_dataset("bar").await;
+    let baz_id = harness.create_derived_dataset("baz", &["foo"]).await;
+
+    let checker = harness
+        .catalog
+        .get_one::<DependencyGraphConsistencyChecker>()
+        .unwrap();
+
+    let report = checker.check(false).await.unwrap();
+    assert!(report.is_consistent());
+    assert!(!report.repaired);
+
+    // Corrupt the persisted graph
+    harness
+        .dataset_dependency_repo
+        .set_upstream_dependencies(&baz_id, &[bar_id.clone()])
+        .await
+        .unwrap();
+
+    let report = checker.check(false).await.unwrap();
+    assert_eq!(
+        report,
+        DependencyGraphConsistencyReport {
+            missing_dependencies: vec![DatasetDependencyEntry::new(baz_id.clone(), foo_id.clone())],
+            unexpected_dependencies: vec![DatasetDependencyEntry::new(
+                baz_id.clone(),
+                bar_id.clone()
+            )],
+            repaired: false,
+        }
+    );
+
+    let report = checker.check(true).await.unwrap();
+    assert!(!report.is_consistent());
+    assert!(report.repaired);
+
+    let report = checker.check(false).await.unwrap();
+    assert!(report.is_consistent());
+
+    assert_eq!(
+        harness.list_persisted_entries().await,
+        BTreeSet::from([DatasetDependencyEntry::new(baz_id, foo_id)])
+    );
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+struct PersistentDependencyGraphHarness {
+    _workdir: TempDir,
+    catalog: dill::Catalog,
+    dataset_repo: Arc<dyn DatasetRepository>,
+    dataset_dependency_repo: Arc<dyn DatasetDependencyRepository>,
+}
+
+impl PersistentDependencyGraphHarness {
+    fn new() -> Self {
+        let workdir = tempfile::tempdir().unwrap();
+        let datasets_dir = workdir.path().join("datasets");
+        std::fs::create_dir(&datasets_dir).unwrap();
+
+        let mut b = dill::CatalogBuilder::new();
+        b.add::<SystemTimeSourceDefault>()
+            .add_builder(
+                messaging_outbox::OutboxImmediateImpl::builder()
+                    .with_consumer_filter(messaging_outbox::ConsumerFilter::AllConsumers),
+            )
+            .bind::<dyn Outbox, OutboxImmediateImpl>()
+            .add_builder(
+                DatasetRepositoryLocalFs::builder()
+                    .with_root(datasets_dir)
+                    .with_multi_tenant(false),
+            )
+            .bind::<dyn DatasetRepository, DatasetRepositoryLocalFs>()
+            .bind::<dyn DatasetRepositoryWriter, DatasetRepositoryLocalFs>()
+            .add_value(CurrentAccountSubject::new_test())
+            .add::<auth::AlwaysHappyDatasetActionAuthorizer>()
+            .add::<DependencyGraphServiceInMemory>()
+            .add::<InMemoryDatasetDependencyRepository>()
+            .add::<DependencyGraphPersistenceMessageConsumer>()
+            .add::<DependencyGraphConsistencyChecker>()
+            .add::<CreateDatasetFromSnapshotUseCaseImpl>()
+            .add::<CommitDatasetEventUseCaseImpl>()
+            .add::<DeleteDatasetUseCaseImpl>();
+
+        NoOpDatabasePlugin::init_database_components(&mut b);
+
+        register_message_dispatcher::<DatasetLifecycleMessage>(
+            &mut b,
+            MESSAGE_PRODUCER_KAMU_CORE_DATASET_SERVICE,
+        );
+
+        let catalog = b.build();
+
+        let dataset_repo = catalog.get_one::<dyn DatasetRepository>().unwrap();
+        let dataset_dependency_repo = catalog
+            .get_one::<dyn DatasetDependencyRepository>()
+            .unwrap();
+
+        Self {
+            _workdir: workdir,
+            catalog,
+            dataset_repo,
+            dataset_dependency_repo,
+        }
+    }
+
+    fn persistent_repository(&self) -> DependencyGraphRepositoryPersistent {
+        DependencyGraphRepositoryPersistent::new(
+            self.catalog.clone(),
+            Arc::new(DependencyGraphRepositoryInMemory::new(
+                self.dataset_repo.clone(),
+            )),
+        )
+    }
+
+    async fn list_persisted_entries(&self) -> BTreeSet<DatasetDependencyEntry> {
+        self.dataset_dependency_repo
+            .list_all_dependencies()
+            .try_collect()
+            .await
+            .unwrap()
+    }
+
+    async fn dataset_id_by_name(&self, dataset_name: &str) -> DatasetID {
+        self.dataset_repo
+            .resolve_dataset_ref(
+                &DatasetAlias::new(None, DatasetName::new_unchecked(dataset_name)).as_local_ref(),
+            )
+            .await
+            .unwrap()
+            .id
+    }
+
+    async fn create_root_dataset(&self, dataset_name: &str) -> DatasetID {
+        let create_dataset_from_snapshot = self
+            .catalog
+            .get_one::<dyn CreateDatasetFromSnapshotUseCase>()
+            .unwrap();
+
+        create_dataset_from_snapshot
+            .execute(
+                MetadataFactory::dataset_snapshot()
+                    .name(DatasetAlias::new(
+                        None,
+                        DatasetName::new_unchecked(dataset_name),
+                    ))
+                    .kind(DatasetKind::Root)
+                    .push_event(MetadataFactory::set_polling_source().build())
+                    .build(),
+                Default::default(),
+            )
+            .await
+            .unwrap()
+            .dataset_handle
+            .id
+    }
+
+    async fn create_derived_dataset(&self, dataset_name: &str, input_names: &[&str]) -> DatasetID {
+        let create_dataset_from_snapshot = self
+            .catalog
+            .get_one::<dyn CreateDatasetFromSnapshotUseCase>()
+            .unwrap();
+
+        create_dataset_from_snapshot
+            .execute(
+                MetadataFactory::dataset_snapshot()
+                    .name(DatasetAlias::new(
+                        None,
+                        DatasetName::new_unchecked(dataset_name),
+                    ))
+                    .kind(DatasetKind::Derivative)
+                    .push_event(
+                        MetadataFactory::set_transform()
+                            .inputs_from_refs(input_names.iter().map(|name| {
+                                DatasetAlias::new(None, DatasetName::new_unchecked(*name))
+                            }))
+                            .build(),
+                    )
+                    .build(),
+                Default::default(),
+            )
+            .await
+            .unwrap()
+            .dataset_handle
+            .id
+    }
+
+    async fn modify_derived_dataset(&self, dataset_name: &str, input_names: &[&str]) {
+        let dataset_handle = self
+            .dataset_repo
+            .resolve_dataset_ref(
+                &DatasetAlias::new(None, DatasetName::new_unchecked(dataset_name)).as_local_ref(),
+            )
+            .await
+            .unwrap();
+
+        let mut id_aliases = Vec::new();
+        for input_name in input_names {
+            id_aliases.push((
+                self.dataset_id_by_name(input_name).await,
+                (*input_name).to_string(),
+            ));
+        }
+
+        let commit_dataset_event = self
+            .catalog
+            .get_one::<dyn CommitDatasetEventUseCase>()
+            .unwrap();
+        commit_dataset_event
+            .execute(
+                &dataset_handle,
+                MetadataEvent::SetTransform(
+                    MetadataFactory::set_transform()
+                        .inputs_from_refs_and_aliases(id_aliases)
+                        .build(),
+                ),
+                Default::default(),
+            )
+            .await
+            .unwrap();
+    }
+
+    async fn delete_dataset(&self, dataset_name: &str) {
+        let delete_dataset = self.catalog.get_one::<dyn DeleteDatasetUseCase>().unwrap();
+        delete_dataset
+            .execute_via_ref(
+                &DatasetAlias::new(None, DatasetName::new_unchecked(dataset_name)).as_local_ref(),
+            )
+            .await
+            .unwrap();
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -19,6 +19,7 @@ use kamu::domain::*;
 use kamu::testing::*;
 use kamu::*;
 use kamu_accounts::{CurrentAccountSubject, DEFAULT_ACCOUNT_NAME_STR};
+use messaging_outbox::DummyOutboxImpl;
 use opendatafabric::*;
 use time_source::SystemTimeSourceDefault;
 
@@ -153,6 +154,7 @@ async fn create_graph_remote(
         Arc::new(CurrentAccountSubject::new_test()),
         false,
         Arc::new(SystemTimeSourceDefault),
+        None,
     );
 
     create_graph(&remote_dataset_repo, datasets).await;
@@ -173,9 +175,11 @@ async fn create_graph_remote(
         Arc::new(DatasetFactoryImpl::new(
             IpfsGateway::default(),
             Arc::new(auth::DummyOdfServerAccessTokenResolver::new()),
+            None,
         )),
         Arc::new(DummySmartTransferProtocolClient::new()),
         Arc::new(kamu::utils::ipfs_wrapper::IpfsClient::default()),
+        Arc::new(DummyOutboxImpl {}),
     );
 
     for import_alias in to_import {
@@ -12,7 +12,7 @@ use std::collections::BTreeMap;
 use std::path::Path;
 use std::sync::Arc;
 
-use chrono::Utc;
+use chrono::{DateTime, Utc};
 use datafusion::arrow::array::*;
 use datafusion::arrow::datatypes::{DataType, Field, Schema};
 use datafusion::arrow::record_batch::RecordBatch;
@@ -729,6 +729,7 @@ async fn test_sql_statement_with_state_simple() {
                 source_event_time: Utc::now(),
                 new_watermark: None,
                 new_source_state: None,
+                retract_previous: None,
                 data_staging_path: tempdir.path().join(".temp-data"),
             },
         )
@@ -805,6 +806,7 @@ async fn test_sql_statement_with_state_simple() {
                 source_event_time: Utc::now(),
                 new_watermark: None,
                 new_source_state: None,
+                retract_previous: None,
                 data_staging_path: tempdir.path().join(".temp-data"),
             },
         )
@@ -941,6 +943,7 @@ async fn test_sql_statement_with_state_cte() {
                 source_event_time: Utc::now(),
                 new_watermark: None,
                 new_source_state: None,
+                retract_previous: None,
                 data_staging_path: tempdir.path().join(".temp-data"),
             },
         )
@@ -991,6 +994,7 @@ async fn test_sql_statement_with_state_cte() {
                 source_event_time: Utc::now(),
                 new_watermark: None,
                 new_source_state: None,
+                retract_previous: None,
                 data_staging_path: tempdir.path().join(".temp-data"),
             },
         )
@@ -1083,6 +1087,7 @@ async fn test_sql_statement_with_state_cte() {
                 source_event_time: Utc::now(),
                 new_watermark: None,
                 new_source_state: None,
+                retract_previous: None,
                 data_staging_path: tempdir.path().join(".temp-data"),
             },
         )
@@ -1112,6 +1117,7 @@ async fn test_sql_statement_with_state_cte() {
                 source_event_time: Utc::now(),
                 new_watermark: None,
                 new_source_state: None,
+                retract_previous: None,
                 data_staging_path: tempdir.path().join(".temp-data"),
             },
         )
@@ -1248,3 +1254,603 @@ async fn test_sql_statement_with_state_cte() {
 }
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[test_group::group(engine, datafusion)]
+#[test_log::test(tokio::test)]
+async fn test_sql_statement_time_travel() {
+    let tempdir = tempfile::tempdir().unwrap();
+    let catalog = create_catalog_with_local_workspace(
+        tempdir.path(),
+        MockDatasetActionAuthorizer::allowing(),
+    );
+
+    let TimeTravelTestDataset {
+        alias: foo_alias,
+        id: foo_id,
+        head,
+        first_block_hash,
+        t1,
+    } = create_time_travel_test_dataset(&catalog, tempdir.path()).await;
+
+    let query_svc = catalog.get_one::<dyn QueryService>().unwrap();
+    let expected_first = indoc::indoc!(
+        r#"
+        +-----+-----+
+        | cat | sum |
+        +-----+-----+
+        | a   | 1   |
+        | b   | 2   |
+        +-----+-----+
+        "#
+    );
+
+    // Query: at block
+    let res = query_svc
+        .sql_statement(
+            &format!(
+                r#"
+                select
+                    cat,
+                    sum(num) as sum
+                from dataset_at('{foo_alias}', '{first_block_hash}')
+                group by cat
+                order by 1
+                "#
+            ),
+            QueryOptions::default(),
+        )
+        .await
+        .unwrap();
+
+    kamu_data_utils::testing::assert_data_eq(res.df, expected_first).await;
+
+    assert_eq!(res.state.inputs, BTreeMap::new());
+    assert_eq!(
+        res.state.time_travel_inputs,
+        BTreeMap::from([(
+            TimeTravelInput {
+                dataset_id: foo_id.clone(),
+                selector: TimeTravelSelector::Block(first_block_hash.to_string()),
+            },
+            first_block_hash.clone(),
+        )])
+    );
+
+    // Query: as of system time, compared to the current state
+    let as_of = t1 + chrono::Duration::minutes(30);
+    let res = query_svc
+        .sql_statement(
+            &format!(
+                r#"
+                select
+                    cat,
+                    sum(num) as sum
+                from (
+                    select cat, num from dataset_as_of('{foo_alias}', TIMESTAMP '{}')
+                    union all
+                    select cat, num from {foo_alias}
+                )
+                group by cat
+                order by 1
+                "#,
+                as_of.to_rfc3339(),
+            ),
+            QueryOptions::default(),
+        )
+        .await
+        .unwrap();
+
+    kamu_data_utils::testing::assert_data_eq(
+        res.df,
+        indoc::indoc!(
+            r#"
+            +-----+-----+
+            | cat | sum |
+            +-----+-----+
+            | a   | 4   |
+            | b   | 8   |
+            +-----+-----+
+            "#
+        ),
+    )
+    .await;
+
+    assert_eq!(res.state.inputs, BTreeMap::from([(foo_id.clone(), head)]));
+    assert_eq!(
+        res.state.time_travel_inputs,
+        BTreeMap::from([(
+            TimeTravelInput {
+                dataset_id: foo_id.clone(),
+                selector: TimeTravelSelector::SystemTime(as_of),
+            },
+            first_block_hash.clone(),
+        )])
+    );
+
+    // Query: named reference is pinned by the previous state
+    let res = query_svc
+        .sql_statement(
+            &format!(
+                r#"
+                select
+                    cat,
+                    sum(num) as sum
+                from dataset_at('{foo_alias}', 'head')
+                group by cat
+                order by 1
+                "#
+            ),
+            QueryOptions {
+                as_of_state: Some(QueryState {
+                    inputs: BTreeMap::new(),
+                    time_travel_inputs: BTreeMap::from([(
+                        TimeTravelInput {
+                            dataset_id: foo_id.clone(),
+                            selector: TimeTravelSelector::Block("head".to_string()),
+                        },
+                        first_block_hash.clone(),
+                    )]),
+                }),
+                ..Default::default()
+            },
+        )
+        .await
+        .unwrap();
+
+    kamu_data_utils::testing::assert_data_eq(res.df, expected_first).await;
+
+    // Query: before the dataset existed
+    let res = query_svc
+        .sql_statement(
+            &format!(
+                "select * from dataset_as_of('{foo_alias}', TIMESTAMP '2000-01-01T00:00:00Z')"
+            ),
+            QueryOptions::default(),
+        )
+        .await;
+
+    assert_matches!(
+        res,
+        Err(QueryError::DataFusionError(DataFusionError {
+            source: ::datafusion::common::DataFusionError::Plan(s),
+            ..
+        })) if s.contains("Dataset did not exist as of")
+    );
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+struct TimeTravelTestDataset {
+    alias: DatasetAlias,
+    id: DatasetID,
+    head: Multihash,
+    first_block_hash: Multihash,
+    t1: DateTime<Utc>,
+}
+
+/// Creates the `foo` dataset with two data slices written an hour apart,
+/// starting at `t1`
+async fn create_time_travel_test_dataset(
+    catalog: &Catalog,
+    workdir: &Path,
+) -> TimeTravelTestDataset {
+    use ::datafusion::prelude::*;
+
+    let dataset_repo_writer = catalog.get_one::<dyn DatasetRepositoryWriter>().unwrap();
+    let ctx = SessionContext::new();
+
+    // Dataset init
+    let foo_alias = DatasetAlias::new(None, DatasetName::new_unchecked("foo"));
+    let foo_create = dataset_repo_writer
+        .create_dataset(
+            &foo_alias,
+            MetadataFactory::metadata_block(MetadataFactory::seed(DatasetKind::Root).build())
+                .build_typed(),
+        )
+        .await
+        .unwrap();
+    let foo_id = foo_create.dataset_handle.id;
+    let foo_dataset = foo_create.dataset;
+
+    let mut writer = DataWriterDataFusion::builder(foo_dataset.clone(), ctx.clone())
+        .with_metadata_state_scanned(None)
+        .await
+        .unwrap()
+        .build();
+
+    let t1 = Utc::now() + chrono::Duration::hours(1);
+    let t2 = t1 + chrono::Duration::hours(1);
+
+    for (system_time, num) in [(t1, [1, 2]), (t2, [2, 4])] {
+        writer
+            .write(
+                Some(
+                    ctx.read_batch(
+                        RecordBatch::try_new(
+                            Arc::new(Schema::new(vec![
+                                Field::new("cat", DataType::Utf8, false),
+                                Field::new("num", DataType::UInt64, false),
+                            ])),
+                            vec![
+                                Arc::new(StringArray::from(vec!["a", "b"])),
+                                Arc::new(UInt64Array::from(num.to_vec())),
+                            ],
+                        )
+                        .unwrap(),
+                    )
+                    .unwrap(),
+                ),
+                WriteDataOpts {
+                    system_time,
+                    source_event_time: system_time,
+                    new_watermark: None,
+                    new_source_state: None,
+                    retract_previous: None,
+                    data_staging_path: workdir.join(".temp-data"),
+                },
+            )
+            .await
+            .unwrap();
+    }
+
+    // Block of the first write
+    let head = foo_dataset
+        .as_metadata_chain()
+        .resolve_ref(&BlockRef::Head)
+        .await
+        .unwrap();
+    let first_block_hash = foo_dataset
+        .as_metadata_chain()
+        .get_block(&head)
+        .await
+        .unwrap()
+        .prev_block_hash
+        .unwrap();
+
+    TimeTravelTestDataset {
+        alias: foo_alias,
+        id: foo_id,
+        head,
+        first_block_hash,
+        t1,
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[test_group::group(engine, datafusion)]
+#[test_log::test(tokio::test(flavor = "multi_thread"))]
+async fn test_session_time_travel() {
+    let tempdir = tempfile::tempdir().unwrap();
+    let catalog = create_catalog_with_local_workspace(
+        tempdir.path(),
+        MockDatasetActionAuthorizer::allowing(),
+    );
+
+    let TimeTravelTestDataset {
+        alias: foo_alias,
+        first_block_hash,
+        t1,
+        ..
+    } = create_time_travel_test_dataset(&catalog, tempdir.path()).await;
+
+    // Sessions are used by the SQL shell and Flight SQL to plan queries directly
+    let query_svc = catalog.get_one::<dyn QueryService>().unwrap();
+    let ctx = query_svc.create_session().await.unwrap();
+
+    let expected_first = indoc::indoc!(
+        r#"
+        +-----+-----+
+        | cat | sum |
+        +-----+-----+
+        | a   | 1   |
+        | b   | 2   |
+        +-----+-----+
+        "#
+    );
+
+    let df = ctx
+        .sql(&format!(
+            r#"
+            select
+                cat,
+                sum(num) as sum
+            from dataset_at('{foo_alias}', '{first_block_hash}')
+            group by cat
+            order by 1
+            "#
+        ))
+        .await
+        .unwrap();
+
+    kamu_data_utils::testing::assert_data_eq(df, expected_first).await;
+
+    let as_of = t1 + chrono::Duration::minutes(30);
+    let df = ctx
+        .sql(&format!(
+            r#"
+            select
+                cat,
+                sum(num) as sum
+            from dataset_as_of('{foo_alias}', TIMESTAMP '{}')
+            group by cat
+            order by 1
+            "#,
+            as_of.to_rfc3339(),
+        ))
+        .await
+        .unwrap();
+
+    kamu_data_utils::testing::assert_data_eq(df, expected_first).await;
+
+    // Errors of the lazy resolution are reported as planning errors
+    let res = ctx
+        .sql(&format!(
+            "select * from dataset_as_of('{foo_alias}', TIMESTAMP '2000-01-01T00:00:00Z')"
+        ))
+        .await;
+
+    assert_matches!(
+        res,
+        Err(::datafusion::common::DataFusionError::Plan(s))
+            if s.contains("Dataset did not exist as of")
+    );
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+struct QueryServiceSessionFactory {
+    query_svc: Arc<dyn QueryService>,
+}
+
+#[async_trait::async_trait]
+impl kamu_adapter_flight_sql::SessionFactory for QueryServiceSessionFactory {
+    async fn authenticate(
+        &self,
+        _username: &str,
+        _password: &str,
+    ) -> Result<kamu_adapter_flight_sql::Token, tonic::Status> {
+        Ok(String::new())
+    }
+
+    async fn get_context(
+        &self,
+        _token: &kamu_adapter_flight_sql::Token,
+    ) -> Result<Arc<::datafusion::prelude::SessionContext>, tonic::Status> {
+        Ok(Arc::new(self.query_svc.create_session().await.unwrap()))
+    }
+}
+
+#[test_group::group(engine, datafusion)]
+#[test_log::test(tokio::test(flavor = "multi_thread"))]
+async fn test_flight_sql_time_travel() {
+    use arrow_flight::flight_service_server::FlightServiceServer;
+    use arrow_flight::sql::client::FlightSqlServiceClient;
+    use futures::TryStreamExt;
+
+    let tempdir = tempfile::tempdir().unwrap();
+    let catalog = create_catalog_with_local_workspace(
+        tempdir.path(),
+        MockDatasetActionAuthorizer::allowing(),
+    );
+
+    let TimeTravelTestDataset {
+        alias: foo_alias,
+        first_block_hash,
+        ..
+    } = create_time_travel_test_dataset(&catalog, tempdir.path()).await;
+
+    // Same setup as in `kamu sql server --flight-sql`
+    let service = kamu_adapter_flight_sql::KamuFlightSqlService::builder()
+        .with_session_factory(Arc::new(QueryServiceSessionFactory {
+            query_svc: catalog.get_one::<dyn QueryService>().unwrap(),
+        }))
+        .build();
+
+    let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let addr = listener.local_addr().unwrap();
+    let server = tokio::task::spawn(
+        tonic::transport::Server::builder()
+            .add_service(FlightServiceServer::new(service))
+            .serve_with_incoming(tokio_stream::wrappers::TcpListenerStream::new(listener)),
+    );
+
+    let channel = tonic::transport::Channel::from_shared(format!("http://{addr}"))
+        .unwrap()
+        .connect()
+        .await
+        .unwrap();
+    let mut client = FlightSqlServiceClient::new(channel);
+    client.handshake("kamu", "kamu").await.unwrap();
+
+    let fi = client
+        .execute(
+            format!(
+                r#"
+                select
+                    cat,
+                    sum(num) as sum
+                from dataset_at('{foo_alias}', '{first_block_hash}')
+                group by cat
+                order by 1
+                "#
+            ),
+            None,
+        )
+        .await
+        .unwrap();
+
+    let record_batches: Vec<_> = client
+        .do_get(fi.endpoint[0].ticket.clone().unwrap())
+        .await
+        .unwrap()
+        .try_collect()
+        .await
+        .unwrap();
+
+    server.abort();
+
+    let ctx = ::datafusion::prelude::SessionContext::new();
+    let df = ctx.read_batches(record_batches).unwrap();
+
+    kamu_data_utils::testing::assert_data_eq(
+        df,
+        indoc::indoc!(
+            r#"
+            +-----+-----+
+            | cat | sum |
+            +-----+-----+
+            | a   | 1   |
+            | b   | 2   |
+            +-----+-----+
+            "#
+        ),
+    )
+    .await;
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[test_group::group(engine, datafusion)]
+#[test_log::test(tokio::test)]
+async fn test_sql_statement_prunes_slices() {
+    use ::datafusion::physical_plan::displayable;
+    use ::datafusion::prelude::*;
+
+    let tempdir = tempfile::tempdir().unwrap();
+    let catalog = create_catalog_with_local_workspace(
+        tempdir.path(),
+        MockDatasetActionAuthorizer::allowing(),
+    );
+
+    let dataset_repo_writer = catalog.get_one::<dyn DatasetRepositoryWriter>().unwrap();
+    let ctx = SessionContext::new();
+
+    // Dataset init
+    let foo_alias = DatasetAlias::new(None, DatasetName::new_unchecked("foo"));
+    let foo_create = dataset_repo_writer
+        .create_dataset(
+            &foo_alias,
+            MetadataFactory::metadata_block(MetadataFactory::seed(DatasetKind::Root).build())
+                .build_typed(),
+        )
+        .await
+        .unwrap();
+
+    let mut writer = DataWriterDataFusion::builder(foo_create.dataset.clone(), ctx.clone())
+        .with_metadata_state_scanned(None)
+        .await
+        .unwrap()
+        .build();
+
+    // Three slices with one record each
+    let mut slice_hashes = Vec::new();
+    for (i, time) in [
+        "2050-01-01T00:00:00Z",
+        "2050-01-01T01:00:00Z",
+        "2050-01-01T02:00:00Z",
+    ]
+    .into_iter()
+    .enumerate()
+    {
+        let time = DateTime::parse_from_rfc3339(time).unwrap().into();
+
+        let res = writer
+            .write(
+                Some(
+                    ctx.read_batch(
+                        RecordBatch::try_new(
+                            Arc::new(Schema::new(vec![Field::new(
+                                "num",
+                                DataType::UInt64,
+                                false,
+                            )])),
+                            vec![Arc::new(UInt64Array::from(vec![u64::try_from(i).unwrap()]))],
+                        )
+                        .unwrap(),
+                    )
+                    .unwrap(),
+                ),
+                WriteDataOpts {
+                    system_time: time,
+                    source_event_time: time,
+                    new_watermark: None,
+                    new_source_state: None,
+                    retract_previous: None,
+                    data_staging_path: tempdir.path().join(".temp-data"),
+                },
+            )
+            .await
+            .unwrap();
+
+        slice_hashes.push(
+            res.add_data_block
+                .unwrap()
+                .event
+                .new_data
+                .unwrap()
+                .physical_hash,
+        );
+    }
+
+    let query_svc = catalog.get_one::<dyn QueryService>().unwrap();
+
+    for (filter, expected_slices) in [
+        ("\"offset\" >= 2", vec![2]),
+        ("\"offset\" = 1 or \"offset\" = 2", vec![1, 2]),
+        ("system_time < TIMESTAMP '2050-01-01T01:00:00Z'", vec![0]),
+        (
+            "system_time between TIMESTAMP '2050-01-01T00:30:00Z' and TIMESTAMP \
+             '2050-01-01T01:30:00Z'",
+            vec![1],
+        ),
+        ("event_time >= TIMESTAMP '2050-01-01T01:00:00Z'", vec![1, 2]),
+        (
+            "\"offset\" >= 1 and system_time < TIMESTAMP '2050-01-01T01:00:00Z'",
+            vec![],
+        ),
+        ("num >= 0", vec![0, 1, 2]),
+    ] {
+        let res = query_svc
+            .sql_statement(
+                &format!("select num from {foo_alias} where {filter} order by num"),
+                QueryOptions::default(),
+            )
+            .await
+            .unwrap();
+
+        let plan = res.df.clone().create_physical_plan().await.unwrap();
+        let plan = displayable(plan.as_ref()).indent(true).to_string();
+
+        for (i, hash) in slice_hashes.iter().enumerate() {
+            assert_eq!(
+                plan.contains(&hash.to_string()),
+                expected_slices.contains(&i),
+                "Unexpected slice {i} pruning for filter: {filter}\n{plan}"
+            );
+        }
+
+        let batches = res.df.collect().await.unwrap();
+        let nums: Vec<u64> = batches
+            .iter()
+            .flat_map(|b| {
+                b.column(0)
+                    .as_any()
+                    .downcast_ref::<UInt64Array>()
+                    .unwrap()
+                    .values()
+                    .to_vec()
+            })
+            .collect();
+        assert_eq!(
+            nums,
+            expected_slices
+                .iter()
+                .map(|i| u64::try_from(*i).unwrap())
+                .collect::<Vec<_>>(),
+            "Unexpected result for filter: {filter}"
+        );
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
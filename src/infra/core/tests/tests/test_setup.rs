@@ -44,11 +44,15 @@ async fn test_setup_pull_images() {
         .ensure_image(docker_images::MINIO, None)
         .await
         .unwrap();
+    container_runtime
+        .ensure_image(docker_images::FAKE_GCS_SERVER, None)
+        .await
+        .unwrap();
 
     cfg_if::cfg_if! {
         if #[cfg(feature = "ingest-mqtt")] {
             container_runtime
-                .ensure_image(docker_images::RUMQTTD, None)
+                .ensure_image(docker_images::MOSQUITTO, None)
                 .await
                 .unwrap();
         }
@@ -59,4 +63,17 @@ async fn test_setup_pull_images() {
             container_runtime.ensure_image(docker_images::FTP, None).await.unwrap();
         }
     }
+
+    cfg_if::cfg_if! {
+        if #[cfg(feature = "ingest-evm")] {
+            container_runtime.ensure_image(docker_images::ANVIL, None).await.unwrap();
+        }
+    }
+
+    cfg_if::cfg_if! {
+        if #[cfg(feature = "ingest-sql")] {
+            container_runtime.ensure_image(docker_images::POSTGRES, None).await.unwrap();
+            container_runtime.ensure_image(docker_images::MARIADB, None).await.unwrap();
+        }
+    }
 }
@@ -10,18 +10,22 @@
 use std::assert_matches::assert_matches;
 use std::path::Path;
 use std::str::FromStr;
+use std::sync::{Arc, Mutex};
 
 use dill::Component;
 use kamu::domain::*;
 use kamu::testing::*;
 use kamu::utils::ipfs_wrapper::IpfsClient;
+use kamu::utils::object_store_context::ObjectStoreConfig;
 use kamu::*;
 use kamu_accounts::CurrentAccountSubject;
+use messaging_outbox::{DummyOutboxImpl, MockOutbox, Outbox};
+use mockall::predicate::{always, eq};
 use opendatafabric::*;
 use time_source::SystemTimeSourceDefault;
 use url::Url;
 
-use crate::utils::IpfsDaemon;
+use crate::utils::{IpfsDaemon, LocalAzureServer};
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
 
@@ -78,6 +82,7 @@ async fn do_test_sync(
     push_ref: &DatasetRefRemote,
     pull_ref: &DatasetRefRemote,
     ipfs: Option<(IpfsGateway, IpfsClient)>,
+    object_store_config: ObjectStoreConfig,
     auth_expectations: AuthorizationExpectations,
 ) {
     // Tests sync between "foo" -> remote -> "bar"
@@ -97,6 +102,7 @@ async fn do_test_sync(
         .add::<SystemTimeSourceDefault>()
         .add_value(ipfs_gateway)
         .add_value(ipfs_client)
+        .add_value(object_store_config)
         .add_value(CurrentAccountSubject::new_test())
         .add_value(dataset_authorizer)
         .bind::<dyn auth::DatasetActionAuthorizer, MockDatasetActionAuthorizer>()
@@ -112,6 +118,7 @@ async fn do_test_sync(
         .add::<auth::DummyOdfServerAccessTokenResolver>()
         .add::<DatasetFactoryImpl>()
         .add::<SyncServiceImpl>()
+        .add::<DummyOutboxImpl>()
         .add::<DummySmartTransferProtocolClient>()
         .build();
 
@@ -437,6 +444,7 @@ async fn test_sync_to_from_local_fs() {
         &DatasetRefRemote::from(&repo_url),
         &DatasetRefRemote::from(&repo_url),
         None,
+        ObjectStoreConfig::default(),
         AuthorizationExpectations::default(),
     )
     .await;
@@ -444,6 +452,132 @@ async fn test_sync_to_from_local_fs() {
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
 
+#[test_log::test(tokio::test)]
+async fn test_sync_posts_data_updated_message() {
+    let tmp_workspace_dir = tempfile::tempdir().unwrap();
+    let tmp_repo_dir = tempfile::tempdir().unwrap();
+    let repo_url = Url::from_directory_path(tmp_repo_dir.path()).unwrap();
+    let remote_ref = DatasetRefRemote::from(&repo_url);
+
+    let foo_alias = DatasetAlias::new(None, DatasetName::new_unchecked("foo"));
+    let bar_alias = DatasetAlias::new(None, DatasetName::new_unchecked("bar"));
+    let baz_alias = DatasetAlias::new(None, DatasetName::new_unchecked("baz"));
+    let baz_copy_alias = DatasetAlias::new(None, DatasetName::new_unchecked("baz-copy"));
+
+    let datasets_dir = tmp_workspace_dir.path().join("datasets");
+    std::fs::create_dir(&datasets_dir).unwrap();
+
+    let posted_messages = Arc::new(Mutex::new(Vec::new()));
+
+    let mut mock_outbox = MockOutbox::new();
+    mock_outbox
+        .expect_post_message_as_json()
+        .with(eq(MESSAGE_PRODUCER_KAMU_CORE_DATASET_SERVICE), always())
+        .returning({
+            let posted_messages = posted_messages.clone();
+            move |_, message_as_json| {
+                posted_messages.lock().unwrap().push(
+                    serde_json::from_value::<DatasetLifecycleMessage>(message_as_json.clone())
+                        .unwrap(),
+                );
+                Ok(())
+            }
+        });
+
+    let catalog = dill::CatalogBuilder::new()
+        .add::<SystemTimeSourceDefault>()
+        .add_value(IpfsGateway::default())
+        .add_value(IpfsClient::default())
+        .add_value(ObjectStoreConfig::default())
+        .add_value(CurrentAccountSubject::new_test())
+        .add::<auth::AlwaysHappyDatasetActionAuthorizer>()
+        .add_builder(
+            DatasetRepositoryLocalFs::builder()
+                .with_root(datasets_dir)
+                .with_multi_tenant(false),
+        )
+        .bind::<dyn DatasetRepository, DatasetRepositoryLocalFs>()
+        .bind::<dyn DatasetRepositoryWriter, DatasetRepositoryLocalFs>()
+        .add_value(RemoteReposDir::new(tmp_workspace_dir.path().join("repos")))
+        .add::<RemoteRepositoryRegistryImpl>()
+        .add::<auth::DummyOdfServerAccessTokenResolver>()
+        .add::<DatasetFactoryImpl>()
+        .add::<SyncServiceImpl>()
+        .add::<DummySmartTransferProtocolClient>()
+        .add_value(mock_outbox)
+        .bind::<dyn Outbox, MockOutbox>()
+        .build();
+
+    let sync_svc = catalog.get_one::<dyn SyncService>().unwrap();
+    let dataset_repo = catalog.get_one::<DatasetRepositoryLocalFs>().unwrap();
+
+    for alias in [&foo_alias, &baz_alias] {
+        dataset_repo
+            .create_dataset_from_snapshot(
+                MetadataFactory::dataset_snapshot()
+                    .name(alias.clone())
+                    .kind(DatasetKind::Root)
+                    .push_event(MetadataFactory::set_data_schema().build())
+                    .build(),
+            )
+            .await
+            .unwrap();
+    }
+
+    let foo_id = dataset_repo
+        .resolve_dataset_ref(&foo_alias.as_local_ref())
+        .await
+        .unwrap()
+        .id;
+
+    DatasetTestHelper::append_random_data(dataset_repo.as_ref(), &foo_alias, FILE_DATA_ARRAY_SIZE)
+        .await;
+    let foo_head = DatasetTestHelper::append_random_data(
+        dataset_repo.as_ref(),
+        &foo_alias,
+        FILE_DATA_ARRAY_SIZE,
+    )
+    .await;
+
+    // Push into a repository and pull back under another name
+    assert_matches!(
+        sync_svc.sync(&foo_alias.as_any_ref(), &remote_ref.as_any_ref(), SyncOptions::default(), None).await,
+        Ok(SyncResult::Updated { new_head, .. }) if new_head == foo_head
+    );
+    assert_matches!(
+        sync_svc.sync(&remote_ref.as_any_ref(), &bar_alias.as_any_ref(), SyncOptions::default(), None).await,
+        Ok(SyncResult::Updated { new_head, .. }) if new_head == foo_head
+    );
+
+    // Metadata-only transfers don't announce any new data
+    assert_matches!(
+        sync_svc
+            .sync(
+                &baz_alias.as_any_ref(),
+                &baz_copy_alias.as_any_ref(),
+                SyncOptions::default(),
+                None
+            )
+            .await,
+        Ok(SyncResult::Updated { num_blocks: 2, .. })
+    );
+
+    let posted_messages = posted_messages.lock().unwrap();
+    assert_eq!(posted_messages.len(), 2);
+    for message in posted_messages.iter() {
+        assert_matches!(
+            message,
+            DatasetLifecycleMessage::DataUpdated(msg)
+                if msg.dataset_id == foo_id
+                    && msg.old_head.is_some()
+                    && msg.new_head == foo_head
+                    && msg.new_offset_interval == Some(OffsetInterval { start: 0, end: 19 })
+        );
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
 #[test_group::group(containerized)]
 #[test_log::test(tokio::test)]
 async fn test_sync_to_from_s3() {
@@ -455,6 +589,45 @@ async fn test_sync_to_from_s3() {
         &DatasetRefRemote::from(&s3.url),
         &DatasetRefRemote::from(&s3.url),
         None,
+        ObjectStoreConfig::default(),
+        AuthorizationExpectations::default(),
+    )
+    .await;
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[test_group::group(containerized)]
+#[test_log::test(tokio::test)]
+async fn test_sync_to_from_gcs() {
+    let gcs = LocalGcsServer::new().await;
+    let tmp_workspace_dir = tempfile::tempdir().unwrap();
+
+    do_test_sync(
+        tmp_workspace_dir.path(),
+        &DatasetRefRemote::from(&gcs.url),
+        &DatasetRefRemote::from(&gcs.url),
+        None,
+        gcs.object_store_config.clone(),
+        AuthorizationExpectations::default(),
+    )
+    .await;
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[test_group::group(containerized)]
+#[test_log::test(tokio::test)]
+async fn test_sync_to_from_azure() {
+    let azure = LocalAzureServer::new().await;
+    let tmp_workspace_dir = tempfile::tempdir().unwrap();
+
+    do_test_sync(
+        tmp_workspace_dir.path(),
+        &DatasetRefRemote::from(&azure.url),
+        &DatasetRefRemote::from(&azure.url),
+        None,
+        azure.object_store_config.clone(),
         AuthorizationExpectations::default(),
     )
     .await;
@@ -478,6 +651,7 @@ async fn test_sync_from_http() {
         &DatasetRefRemote::from(push_repo_url),
         &DatasetRefRemote::from(pull_repo_url),
         None,
+        ObjectStoreConfig::default(),
         AuthorizationExpectations::default(),
     )
     .await;
@@ -506,6 +680,7 @@ async fn test_sync_to_from_ipfs() {
             },
             ipfs_client,
         )),
+        ObjectStoreConfig::default(),
         AuthorizationExpectations {
             d1_reads: 7,
             ..Default::default()
@@ -19,6 +19,7 @@ use kamu::domain::*;
 use kamu::testing::*;
 use kamu::*;
 use kamu_accounts::CurrentAccountSubject;
+use messaging_outbox::DummyOutboxImpl;
 use opendatafabric::*;
 use tempfile::TempDir;
 use time_source::SystemTimeSourceDefault;
@@ -72,6 +73,7 @@ impl TransformTestHarness {
             .add_value(engine_provisioner)
             .bind::<dyn EngineProvisioner, TEngineProvisioner>()
             .add::<TransformServiceImpl>()
+            .add::<DummyOutboxImpl>()
             .add::<VerificationServiceImpl>()
             .build();
 
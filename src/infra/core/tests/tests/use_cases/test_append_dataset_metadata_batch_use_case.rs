@@ -39,6 +39,7 @@ use opendatafabric::{
     MetadataEvent,
     Multicodec,
     Multihash,
+    OffsetInterval,
 };
 use time_source::SystemTimeSourceDefault;
 
@@ -137,6 +138,83 @@ async fn test_append_dataset_metadata_batch_with_new_dependencies() {
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
 
+#[tokio::test]
+async fn test_append_dataset_metadata_batch_with_new_data() {
+    let alias_foo = DatasetAlias::new(None, DatasetName::new_unchecked("foo"));
+
+    let mut mock_outbox = MockOutbox::new();
+    mock_outbox
+        .expect_post_message_as_json()
+        .with(
+            eq(MESSAGE_PRODUCER_KAMU_CORE_DATASET_SERVICE),
+            function(|message_as_json: &serde_json::Value| {
+                matches!(
+                    serde_json::from_value::<DatasetLifecycleMessage>(message_as_json.clone()),
+                    Ok(DatasetLifecycleMessage::DataUpdated(message))
+                        if message.new_offset_interval == Some(OffsetInterval { start: 0, end: 19 })
+                )
+            }),
+        )
+        .times(1)
+        .returning(|_, _| Ok(()));
+
+    let harness = AppendDatasetMetadataBatchUseCaseHarness::new(mock_outbox);
+    let create_result_foo = harness.create_dataset(&alias_foo, DatasetKind::Root).await;
+
+    let foo_dataset = harness
+        .dataset_repo
+        .get_dataset_by_handle(&create_result_foo.dataset_handle);
+
+    let set_data_schema_block = MetadataBlock {
+        system_time: Utc::now(),
+        prev_block_hash: Some(create_result_foo.head.clone()),
+        sequence_number: 1,
+        event: MetadataEvent::SetDataSchema(MetadataFactory::set_data_schema().build()),
+    };
+    let hash_set_data_schema_block =
+        AppendDatasetMetadataBatchUseCaseHarness::hash_from_block(&set_data_schema_block);
+
+    let add_data_block_1 = MetadataBlock {
+        system_time: Utc::now(),
+        prev_block_hash: Some(hash_set_data_schema_block.clone()),
+        sequence_number: 2,
+        event: MetadataEvent::AddData(
+            MetadataFactory::add_data()
+                .some_new_data_with_offset(0, 9)
+                .build(),
+        ),
+    };
+    let hash_add_data_block_1 =
+        AppendDatasetMetadataBatchUseCaseHarness::hash_from_block(&add_data_block_1);
+
+    let add_data_block_2 = MetadataBlock {
+        system_time: Utc::now(),
+        prev_block_hash: Some(hash_add_data_block_1.clone()),
+        sequence_number: 3,
+        event: MetadataEvent::AddData(
+            MetadataFactory::add_data()
+                .some_new_data_with_offset(10, 19)
+                .build(),
+        ),
+    };
+    let hash_add_data_block_2 =
+        AppendDatasetMetadataBatchUseCaseHarness::hash_from_block(&add_data_block_2);
+
+    let new_blocks = VecDeque::from([
+        (hash_set_data_schema_block, set_data_schema_block),
+        (hash_add_data_block_1, add_data_block_1),
+        (hash_add_data_block_2, add_data_block_2),
+    ]);
+
+    let res = harness
+        .use_case
+        .execute(foo_dataset.as_ref(), new_blocks, false)
+        .await;
+    assert_matches!(res, Ok(_));
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
 struct AppendDatasetMetadataBatchUseCaseHarness {
     _temp_dir: tempfile::TempDir,
     catalog: Catalog,
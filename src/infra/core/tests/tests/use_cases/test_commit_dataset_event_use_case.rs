@@ -10,18 +10,28 @@
 use std::assert_matches::assert_matches;
 use std::sync::Arc;
 
+use chrono::{TimeZone, Utc};
 use dill::{Catalog, Component};
 use kamu::testing::{MetadataFactory, MockDatasetActionAuthorizer};
-use kamu::{CommitDatasetEventUseCaseImpl, DatasetRepositoryLocalFs, DatasetRepositoryWriter};
+use kamu::{
+    CommitDatasetEventUseCaseImpl,
+    DatasetRepositoryLocalFs,
+    DatasetRepositoryWriter,
+    EngineRegistry,
+    EngineSpec,
+};
 use kamu_accounts::CurrentAccountSubject;
 use kamu_core::auth::DatasetActionAuthorizer;
 use kamu_core::{
+    AppendError,
+    AppendValidationError,
     CommitDatasetEventUseCase,
     CommitError,
     CommitOpts,
     CreateDatasetResult,
     DatasetLifecycleMessage,
     DatasetRepository,
+    QueryDialect,
     MESSAGE_PRODUCER_KAMU_CORE_DATASET_SERVICE,
 };
 use messaging_outbox::{MockOutbox, Outbox};
@@ -121,6 +131,101 @@ async fn test_commit_event_with_new_dependencies() {
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
 
+#[tokio::test]
+async fn test_commit_event_with_unknown_engine() {
+    let alias_foo = DatasetAlias::new(None, DatasetName::new_unchecked("foo"));
+    let alias_bar = DatasetAlias::new(None, DatasetName::new_unchecked("bar"));
+
+    let mock_authorizer =
+        MockDatasetActionAuthorizer::new().expect_check_write_dataset(&alias_bar, 1, true);
+
+    let harness = CommitDatasetEventUseCaseHarness::new_with_engine_registry(
+        mock_authorizer,
+        MockOutbox::new(),
+        EngineRegistry::new([EngineSpec::new(
+            "datafusion",
+            "DataFusion",
+            QueryDialect::SqlDataFusion,
+            "datafusion:latest",
+        )]),
+    );
+    let create_result_foo = harness.create_dataset(&alias_foo, DatasetKind::Root).await;
+    let create_result_bar = harness
+        .create_dataset(&alias_bar, DatasetKind::Derivative)
+        .await;
+
+    let res = harness
+        .use_case
+        .execute(
+            &create_result_bar.dataset_handle,
+            MetadataEvent::SetTransform(
+                MetadataFactory::set_transform()
+                    .inputs_from_refs_and_aliases(vec![(
+                        create_result_foo.dataset_handle.id,
+                        alias_foo.to_string(),
+                    )])
+                    .transform(MetadataFactory::transform().engine("flink").build())
+                    .build(),
+            ),
+            CommitOpts::default(),
+        )
+        .await;
+    assert_matches!(
+        res,
+        Err(CommitError::MetadataAppendError(AppendError::InvalidBlock(
+            AppendValidationError::InvalidEvent(e)
+        ))) if e.to_string().starts_with("Invalid event: Unknown engine 'flink'")
+    );
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[tokio::test]
+async fn test_commit_event_with_new_watermark() {
+    let alias_foo = DatasetAlias::new(None, DatasetName::new_unchecked("foo"));
+    let new_watermark = Utc.with_ymd_and_hms(2050, 1, 1, 12, 0, 0).unwrap();
+
+    let mock_authorizer =
+        MockDatasetActionAuthorizer::new().expect_check_write_dataset(&alias_foo, 1, true);
+
+    let mut mock_outbox = MockOutbox::new();
+    mock_outbox
+        .expect_post_message_as_json()
+        .with(
+            eq(MESSAGE_PRODUCER_KAMU_CORE_DATASET_SERVICE),
+            function(move |message_as_json: &serde_json::Value| {
+                matches!(
+                    serde_json::from_value::<DatasetLifecycleMessage>(message_as_json.clone()),
+                    Ok(DatasetLifecycleMessage::DataUpdated(message))
+                        if message.old_head.is_some()
+                            && message.new_offset_interval.is_none()
+                            && message.new_watermark == Some(new_watermark)
+                )
+            }),
+        )
+        .times(1)
+        .returning(|_, _| Ok(()));
+
+    let harness = CommitDatasetEventUseCaseHarness::new(mock_authorizer, mock_outbox);
+    let create_result_foo = harness.create_dataset(&alias_foo, DatasetKind::Root).await;
+
+    let res = harness
+        .use_case
+        .execute(
+            &create_result_foo.dataset_handle,
+            MetadataEvent::AddData(
+                MetadataFactory::add_data()
+                    .new_watermark(Some(new_watermark))
+                    .build(),
+            ),
+            CommitOpts::default(),
+        )
+        .await;
+    assert_matches!(res, Ok(_));
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
 struct CommitDatasetEventUseCaseHarness {
     _temp_dir: tempfile::TempDir,
     catalog: Catalog,
@@ -131,14 +236,34 @@ impl CommitDatasetEventUseCaseHarness {
     fn new(
         mock_dataset_action_authorizer: MockDatasetActionAuthorizer,
         mock_outbox: MockOutbox,
+    ) -> Self {
+        Self::new_impl(mock_dataset_action_authorizer, mock_outbox, None)
+    }
+
+    fn new_with_engine_registry(
+        mock_dataset_action_authorizer: MockDatasetActionAuthorizer,
+        mock_outbox: MockOutbox,
+        engine_registry: EngineRegistry,
+    ) -> Self {
+        Self::new_impl(
+            mock_dataset_action_authorizer,
+            mock_outbox,
+            Some(engine_registry),
+        )
+    }
+
+    fn new_impl(
+        mock_dataset_action_authorizer: MockDatasetActionAuthorizer,
+        mock_outbox: MockOutbox,
+        engine_registry: Option<EngineRegistry>,
     ) -> Self {
         let tempdir = tempfile::tempdir().unwrap();
 
         let datasets_dir = tempdir.path().join("datasets");
         std::fs::create_dir(&datasets_dir).unwrap();
 
-        let catalog = dill::CatalogBuilder::new()
-            .add::<CommitDatasetEventUseCaseImpl>()
+        let mut b = dill::CatalogBuilder::new();
+        b.add::<CommitDatasetEventUseCaseImpl>()
             .add_builder(
                 DatasetRepositoryLocalFs::builder()
                     .with_root(datasets_dir)
@@ -151,8 +276,13 @@ impl CommitDatasetEventUseCaseHarness {
             .bind::<dyn DatasetActionAuthorizer, MockDatasetActionAuthorizer>()
             .add::<SystemTimeSourceDefault>()
             .add_value(mock_outbox)
-            .bind::<dyn Outbox, MockOutbox>()
-            .build();
+            .bind::<dyn Outbox, MockOutbox>();
+
+        if let Some(engine_registry) = engine_registry {
+            b.add_value(engine_registry);
+        }
+
+        let catalog = b.build();
 
         let use_case = catalog.get_one::<dyn CommitDatasetEventUseCase>().unwrap();
 
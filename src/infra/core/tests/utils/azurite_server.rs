@@ -0,0 +1,177 @@
+// Copyright Kamu Data, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+use std::time::Duration;
+
+use base64::Engine as _;
+use container_runtime::{ContainerProcess, ContainerRuntime};
+use hmac::Mac as _;
+use kamu::testing::TEST_BUCKET_NAME;
+use kamu::utils::docker_images;
+use kamu::utils::object_store_context::ObjectStoreConfig;
+use url::Url;
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+pub struct AzuriteServer {
+    pub container_name: String,
+    pub address: String,
+    pub host_port: u16,
+    #[allow(dead_code)]
+    container: ContainerProcess,
+}
+
+impl AzuriteServer {
+    pub const IMAGE: &'static str = docker_images::AZURITE;
+
+    /// Well-known development storage account built into the emulator
+    pub const ACCOUNT_NAME: &'static str = "devstoreaccount1";
+    pub const ACCOUNT_KEY: &'static str =
+        "Eby8vdM02xNOcqFlqUwJPLlmEtlCDXJ1OUzFT50uSRZ6IFsuFq2UVErCz4I6tq/K1SZFPTOtr/KBHBeksoGMGw==";
+
+    pub async fn new() -> Self {
+        let container_runtime = ContainerRuntime::default();
+
+        container_runtime
+            .ensure_image(Self::IMAGE, None)
+            .await
+            .unwrap();
+
+        let server_port = 10000;
+
+        let container = container_runtime
+            .run_attached(Self::IMAGE)
+            .random_container_name_with_prefix("kamu-test-azurite-")
+            .args([
+                "azurite-blob",
+                "--blobHost",
+                "0.0.0.0",
+                "--blobPort",
+                "10000",
+                "--inMemoryPersistence",
+                "--skipApiVersionCheck",
+            ])
+            .expose_port(server_port)
+            .stdout(std::process::Stdio::null())
+            .stderr(std::process::Stdio::null())
+            .spawn()
+            .unwrap();
+
+        let host_port = container
+            .wait_for_host_socket(server_port, Duration::from_secs(20))
+            .await
+            .unwrap();
+
+        let address = container_runtime.get_runtime_host_addr();
+
+        Self {
+            container_name: container.container_name().to_string(),
+            container,
+            address,
+            host_port,
+        }
+    }
+
+    pub fn endpoint(&self) -> String {
+        format!(
+            "http://{}:{}/{}",
+            self.address,
+            self.host_port,
+            Self::ACCOUNT_NAME
+        )
+    }
+
+    /// Creates a blob container using the Shared Key authorization scheme, as
+    /// `object_store` has no API for container management
+    pub async fn create_container(&self, container: &str) {
+        const API_VERSION: &str = "2021-08-06";
+
+        let date = chrono::Utc::now()
+            .format("%a, %d %b %Y %H:%M:%S GMT")
+            .to_string();
+
+        // Path-style (emulator) URLs repeat the account name in the resource path
+        let string_to_sign = format!(
+            "PUT\n\n\n\n\n\n\n\n\n\n\n\nx-ms-date:{date}\nx-ms-version:{API_VERSION}\n/{account}/\
+             {account}/{container}\nrestype:container",
+            account = Self::ACCOUNT_NAME,
+        );
+
+        let key = base64::engine::general_purpose::STANDARD
+            .decode(Self::ACCOUNT_KEY)
+            .unwrap();
+        let mut mac = hmac::Hmac::<sha2::Sha256>::new_from_slice(&key).unwrap();
+        mac.update(string_to_sign.as_bytes());
+        let signature =
+            base64::engine::general_purpose::STANDARD.encode(mac.finalize().into_bytes());
+
+        let response = reqwest::Client::new()
+            .put(format!("{}/{container}?restype=container", self.endpoint()))
+            .header("x-ms-date", date)
+            .header("x-ms-version", API_VERSION)
+            .header(
+                "Authorization",
+                format!("SharedKey {}:{signature}", Self::ACCOUNT_NAME),
+            )
+            .header("Content-Length", "0")
+            .send()
+            .await
+            .unwrap();
+
+        assert!(
+            response.status().is_success(),
+            "Failed to create container: {}",
+            response.text().await.unwrap()
+        );
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+pub struct LocalAzureServer {
+    pub server: AzuriteServer,
+    pub container: String,
+    pub url: Url,
+    /// Points the Azure client to the emulator using the development account
+    pub object_store_config: ObjectStoreConfig,
+}
+
+impl LocalAzureServer {
+    pub async fn new() -> Self {
+        let server = AzuriteServer::new().await;
+
+        let container = TEST_BUCKET_NAME.to_string();
+        server.create_container(&container).await;
+
+        let object_store_config = ObjectStoreConfig {
+            gcs: Vec::new(),
+            azure: vec![
+                (
+                    "azure_storage_account_name".to_string(),
+                    AzuriteServer::ACCOUNT_NAME.to_string(),
+                ),
+                (
+                    "azure_storage_account_key".to_string(),
+                    AzuriteServer::ACCOUNT_KEY.to_string(),
+                ),
+                ("azure_storage_endpoint".to_string(), server.endpoint()),
+                ("allow_http".to_string(), "true".to_string()),
+            ],
+        };
+
+        let url = Url::parse(&format!("az://{container}/")).unwrap();
+
+        Self {
+            server,
+            container,
+            url,
+            object_store_config,
+        }
+    }
+}
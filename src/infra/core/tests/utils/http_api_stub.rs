@@ -0,0 +1,50 @@
+// Copyright Kamu Data, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+use std::net::{IpAddr, Ipv4Addr, SocketAddr};
+
+use hyper::server::conn::AddrIncoming;
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Serves the provided router on a random local port in a background task
+/// for as long as the stub is alive
+pub struct HttpApiStub {
+    local_addr: SocketAddr,
+    task: tokio::task::JoinHandle<()>,
+}
+
+impl HttpApiStub {
+    pub fn new(router: axum::Router) -> Self {
+        let addr = SocketAddr::from((IpAddr::V4(Ipv4Addr::new(127, 0, 0, 1)), 0));
+
+        let bound_addr = AddrIncoming::bind(&addr).unwrap_or_else(|e| {
+            panic!("error binding to {addr}: {e}");
+        });
+
+        let server = axum::Server::builder(bound_addr).serve(router.into_make_service());
+        let local_addr = server.local_addr();
+
+        let task = tokio::spawn(async move {
+            server.await.unwrap();
+        });
+
+        Self { local_addr, task }
+    }
+
+    pub fn url(&self, path: &str) -> url::Url {
+        url::Url::parse(&format!("http://{}{}", self.local_addr, path)).unwrap()
+    }
+}
+
+impl Drop for HttpApiStub {
+    fn drop(&mut self) {
+        self.task.abort();
+    }
+}
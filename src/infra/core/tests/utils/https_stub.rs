@@ -0,0 +1,96 @@
+// Copyright Kamu Data, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+use std::sync::Arc;
+
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio_rustls::rustls;
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Serves the same body for any request over HTTPS using a self-signed
+/// certificate issued for `localhost`
+pub struct HttpsStub {
+    port: u16,
+    cert_pem: String,
+    task: tokio::task::JoinHandle<()>,
+}
+
+impl HttpsStub {
+    pub async fn new(body: &'static str) -> Self {
+        let rcgen::CertifiedKey { cert, key_pair } =
+            rcgen::generate_simple_self_signed(vec!["localhost".to_string()]).unwrap();
+
+        let config = rustls::ServerConfig::builder()
+            .with_safe_defaults()
+            .with_no_client_auth()
+            .with_single_cert(
+                vec![rustls::Certificate(cert.der().to_vec())],
+                rustls::PrivateKey(key_pair.serialize_der()),
+            )
+            .unwrap();
+        let acceptor = tokio_rustls::TlsAcceptor::from(Arc::new(config));
+
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let port = listener.local_addr().unwrap().port();
+
+        let task = tokio::spawn(async move {
+            loop {
+                let (stream, _) = listener.accept().await.unwrap();
+                let acceptor = acceptor.clone();
+
+                tokio::spawn(async move {
+                    // Handshake fails when the client does not trust the certificate
+                    let Ok(mut stream) = acceptor.accept(stream).await else {
+                        return;
+                    };
+
+                    let mut request = Vec::new();
+                    let mut buf = [0; 1024];
+                    while !request.windows(4).any(|w| w == b"\r\n\r\n") {
+                        match stream.read(&mut buf).await {
+                            Ok(0) | Err(_) => return,
+                            Ok(n) => request.extend_from_slice(&buf[..n]),
+                        }
+                    }
+
+                    let response = format!(
+                        "HTTP/1.1 200 OK\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+                        body.len()
+                    );
+                    let _ = stream.write_all(response.as_bytes()).await;
+                    let _ = stream.shutdown().await;
+                });
+            }
+        });
+
+        Self {
+            port,
+            cert_pem: cert.pem(),
+            task,
+        }
+    }
+
+    pub fn url(&self, path: &str) -> url::Url {
+        url::Url::parse(&format!("https://localhost:{}{}", self.port, path)).unwrap()
+    }
+
+    /// PEM-encoded certificate of the server that clients have to trust
+    pub fn cert_pem(&self) -> &str {
+        &self.cert_pem
+    }
+}
+
+impl Drop for HttpsStub {
+    fn drop(&mut self) {
+        self.task.abort();
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -7,18 +7,32 @@
 // the Business Source License, use of this software will be governed
 // by the Apache License, Version 2.0.
 
+#[cfg(feature = "ingest-evm")]
+mod anvil_node;
+mod azurite_server;
 #[cfg(feature = "ingest-ftp")]
 mod ftp_server;
+mod http_api_stub;
 mod http_server;
+mod https_stub;
 mod ipfs_daemon;
 #[cfg(feature = "ingest-mqtt")]
 mod mqtt_broker;
+#[cfg(feature = "ingest-sql")]
+mod sql_server;
 
 pub mod mock_engine_provisioner;
 
+#[cfg(feature = "ingest-evm")]
+pub use anvil_node::*;
+pub use azurite_server::*;
 #[cfg(feature = "ingest-ftp")]
 pub use ftp_server::*;
+pub use http_api_stub::*;
 pub use http_server::*;
+pub use https_stub::*;
 pub use ipfs_daemon::*;
 #[cfg(feature = "ingest-mqtt")]
 pub use mqtt_broker::*;
+#[cfg(feature = "ingest-sql")]
+pub use sql_server::*;
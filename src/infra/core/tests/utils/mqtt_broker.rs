@@ -22,7 +22,7 @@ pub struct MqttBroker {
 }
 
 impl MqttBroker {
-    pub const IMAGE: &'static str = docker_images::RUMQTTD;
+    pub const IMAGE: &'static str = docker_images::MOSQUITTO;
 
     pub async fn new() -> Self {
         let container_runtime = ContainerRuntime::default();
@@ -36,6 +36,8 @@ impl MqttBroker {
         let container = container_runtime
             .run_attached(Self::IMAGE)
             .random_container_name_with_prefix("kamu-test-mqtt-")
+            // Default config only accepts connections from the localhost
+            .args(["mosquitto", "-c", "/mosquitto-no-auth.conf"])
             .expose_port(server_port)
             .stdout(Stdio::null())
             .stderr(Stdio::null())
@@ -0,0 +1,146 @@
+// Copyright Kamu Data, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+use std::process::Stdio;
+use std::time::Duration;
+
+use container_runtime::*;
+use kamu::utils::docker_images;
+use sqlx::Connection;
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+const PASSWORD: &str = "test";
+const STARTUP_TIMEOUT: Duration = Duration::from_secs(60);
+
+/// Database server running in a container
+pub struct SqlServer {
+    pub container_name: String,
+    pub address: String,
+    pub host_port: u16,
+    scheme: &'static str,
+    user: &'static str,
+    database: &'static str,
+    #[allow(dead_code)]
+    container: ContainerProcess,
+}
+
+impl SqlServer {
+    pub async fn postgres() -> Self {
+        let server = Self::start(
+            docker_images::POSTGRES,
+            "kamu-test-postgres-",
+            ("POSTGRES_PASSWORD", PASSWORD),
+            5432,
+            ("postgres", "postgres", "postgres"),
+        )
+        .await;
+
+        server
+            .wait_until_ready(
+                |url| async move { sqlx::PgConnection::connect(&url).await.map(|_| ()) },
+            )
+            .await;
+
+        server
+    }
+
+    pub async fn mariadb() -> Self {
+        let server = Self::start(
+            docker_images::MARIADB,
+            "kamu-test-mariadb-",
+            ("MARIADB_ROOT_PASSWORD", PASSWORD),
+            3306,
+            ("mysql", "root", "mysql"),
+        )
+        .await;
+
+        server
+            .wait_until_ready(|url| async move {
+                sqlx::MySqlConnection::connect(&url).await.map(|_| ())
+            })
+            .await;
+
+        server
+    }
+
+    async fn start(
+        image: &str,
+        prefix: &str,
+        password_var: (&str, &str),
+        server_port: u16,
+        (scheme, user, database): (&'static str, &'static str, &'static str),
+    ) -> Self {
+        let container_runtime = ContainerRuntime::default();
+        container_runtime.ensure_image(image, None).await.unwrap();
+
+        let container = container_runtime
+            .run_attached(image)
+            .random_container_name_with_prefix(prefix)
+            .environment_var(password_var.0, password_var.1)
+            .expose_port(server_port)
+            .stdout(Stdio::null())
+            .stderr(Stdio::null())
+            .spawn()
+            .unwrap();
+
+        let host_port = container
+            .wait_for_host_socket(server_port, STARTUP_TIMEOUT)
+            .await
+            .unwrap();
+
+        let address = container_runtime.get_runtime_host_addr();
+
+        Self {
+            container_name: container.container_name().to_string(),
+            container,
+            address,
+            host_port,
+            scheme,
+            user,
+            database,
+        }
+    }
+
+    // Servers accept connections on the port before they finish initializing,
+    // so we retry until the first successful connection
+    async fn wait_until_ready<F, Fut>(&self, connect: F)
+    where
+        F: Fn(String) -> Fut,
+        Fut: std::future::Future<Output = Result<(), sqlx::Error>>,
+    {
+        let start = std::time::Instant::now();
+        loop {
+            match connect(self.url()).await {
+                Ok(()) => break,
+                Err(err) if start.elapsed() > STARTUP_TIMEOUT => {
+                    panic!("Database server did not start in time: {err}")
+                }
+                Err(_) => tokio::time::sleep(Duration::from_millis(500)).await,
+            }
+        }
+    }
+
+    pub fn url(&self) -> String {
+        format!(
+            "{}://{}:{}@{}:{}/{}",
+            self.scheme, self.user, PASSWORD, self.address, self.host_port, self.database
+        )
+    }
+
+    pub async fn execute(&self, sql: &str) {
+        if self.scheme == "postgres" {
+            let mut conn = sqlx::PgConnection::connect(&self.url()).await.unwrap();
+            sqlx::raw_sql(sql).execute(&mut conn).await.unwrap();
+        } else {
+            let mut conn = sqlx::MySqlConnection::connect(&self.url()).await.unwrap();
+            sqlx::raw_sql(sql).execute(&mut conn).await.unwrap();
+        }
+    }
+}
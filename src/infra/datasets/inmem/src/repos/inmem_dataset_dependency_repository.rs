@@ -0,0 +1,127 @@
+// Copyright Kamu Data, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+use std::collections::{BTreeMap, BTreeSet};
+use std::sync::{Arc, Mutex};
+
+use dill::*;
+use opendatafabric::DatasetID;
+
+use crate::domain::*;
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+pub struct InMemoryDatasetDependencyRepository {
+    state: Arc<Mutex<State>>,
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[derive(Default)]
+struct State {
+    upstream_ids_by_downstream_id: BTreeMap<DatasetID, BTreeSet<DatasetID>>,
+    bootstrapped: bool,
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[component(pub)]
+#[interface(dyn DatasetDependencyRepository)]
+#[scope(Singleton)]
+impl InMemoryDatasetDependencyRepository {
+    pub fn new() -> Self {
+        Self {
+            state: Arc::new(Mutex::new(State::default())),
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[async_trait::async_trait]
+impl DatasetDependencyRepository for InMemoryDatasetDependencyRepository {
+    fn list_all_dependencies(&self) -> DatasetDependencyEntryStream {
+        let entries: Vec<_> = {
+            let guard = self.state.lock().unwrap();
+            guard
+                .upstream_ids_by_downstream_id
+                .iter()
+                .flat_map(|(downstream_dataset_id, upstream_dataset_ids)| {
+                    upstream_dataset_ids.iter().map(|upstream_dataset_id| {
+                        Ok(DatasetDependencyEntry::new(
+                            downstream_dataset_id.clone(),
+                            upstream_dataset_id.clone(),
+                        ))
+                    })
+                })
+                .collect()
+        };
+
+        Box::pin(futures::stream::iter(entries))
+    }
+
+    async fn get_upstream_dependencies(
+        &self,
+        downstream_dataset_id: &DatasetID,
+    ) -> Result<Vec<DatasetID>, GetDatasetDependenciesError> {
+        let guard = self.state.lock().unwrap();
+        Ok(guard
+            .upstream_ids_by_downstream_id
+            .get(downstream_dataset_id)
+            .map(|upstream_dataset_ids| upstream_dataset_ids.iter().cloned().collect())
+            .unwrap_or_default())
+    }
+
+    async fn set_upstream_dependencies(
+        &self,
+        downstream_dataset_id: &DatasetID,
+        upstream_dataset_ids: &[DatasetID],
+    ) -> Result<(), SetDatasetDependenciesError> {
+        let mut guard = self.state.lock().unwrap();
+        if upstream_dataset_ids.is_empty() {
+            guard
+                .upstream_ids_by_downstream_id
+                .remove(downstream_dataset_id);
+        } else {
+            guard.upstream_ids_by_downstream_id.insert(
+                downstream_dataset_id.clone(),
+                upstream_dataset_ids.iter().cloned().collect(),
+            );
+        }
+        Ok(())
+    }
+
+    async fn delete_dependencies_of_dataset(
+        &self,
+        dataset_id: &DatasetID,
+    ) -> Result<(), DeleteDatasetDependenciesError> {
+        let mut guard = self.state.lock().unwrap();
+        guard.upstream_ids_by_downstream_id.remove(dataset_id);
+        guard
+            .upstream_ids_by_downstream_id
+            .retain(|_, upstream_dataset_ids| {
+                upstream_dataset_ids.remove(dataset_id);
+                !upstream_dataset_ids.is_empty()
+            });
+        Ok(())
+    }
+
+    async fn is_bootstrapped(&self) -> Result<bool, GetDatasetDependenciesError> {
+        let guard = self.state.lock().unwrap();
+        Ok(guard.bootstrapped)
+    }
+
+    async fn mark_bootstrapped(&self) -> Result<(), SetDatasetDependenciesError> {
+        let mut guard = self.state.lock().unwrap();
+        guard.bootstrapped = true;
+        Ok(())
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
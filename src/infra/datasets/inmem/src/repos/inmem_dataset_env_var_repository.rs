@@ -136,6 +136,16 @@ impl DatasetEnvVarRepository for InMemoryDatasetEnvVarRepository {
         Ok(0)
     }
 
+    async fn get_all_dataset_env_vars(&self) -> Result<Vec<DatasetEnvVar>, GetDatasetEnvVarError> {
+        let guard = self.state.lock().unwrap();
+
+        let mut dataset_env_vars: Vec<_> =
+            guard.dataset_env_vars_by_ids.values().cloned().collect();
+        dataset_env_vars.sort_by(|a, b| a.created_at.cmp(&b.created_at).then(a.id.cmp(&b.id)));
+
+        Ok(dataset_env_vars)
+    }
+
     async fn get_dataset_env_var_by_id(
         &self,
         dataset_env_var_id: &Uuid,
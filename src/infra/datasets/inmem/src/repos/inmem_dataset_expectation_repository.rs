@@ -0,0 +1,119 @@
+// Copyright Kamu Data, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+
+use database_common::DatabasePaginationOpts;
+use dill::*;
+use opendatafabric::DatasetID;
+
+use crate::domain::*;
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+pub struct InMemoryDatasetExpectationRepository {
+    state: Arc<Mutex<State>>,
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[derive(Default)]
+struct State {
+    expectations_by_dataset_id: HashMap<DatasetID, Vec<DatasetExpectation>>,
+    checks_by_dataset_id: HashMap<DatasetID, Vec<DatasetExpectationsCheck>>,
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[component(pub)]
+#[interface(dyn DatasetExpectationRepository)]
+#[scope(Singleton)]
+impl InMemoryDatasetExpectationRepository {
+    pub fn new() -> Self {
+        Self {
+            state: Arc::new(Mutex::new(State::default())),
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[async_trait::async_trait]
+impl DatasetExpectationRepository for InMemoryDatasetExpectationRepository {
+    async fn set_dataset_expectations(
+        &self,
+        dataset_id: &DatasetID,
+        expectations: &[DatasetExpectation],
+    ) -> Result<(), SetDatasetExpectationsError> {
+        let mut guard = self.state.lock().unwrap();
+        guard
+            .expectations_by_dataset_id
+            .insert(dataset_id.clone(), expectations.to_vec());
+        Ok(())
+    }
+
+    async fn get_dataset_expectations(
+        &self,
+        dataset_id: &DatasetID,
+    ) -> Result<Vec<DatasetExpectation>, GetDatasetExpectationsError> {
+        let guard = self.state.lock().unwrap();
+        Ok(guard
+            .expectations_by_dataset_id
+            .get(dataset_id)
+            .cloned()
+            .unwrap_or_default())
+    }
+
+    async fn save_expectations_check(
+        &self,
+        check: &DatasetExpectationsCheck,
+    ) -> Result<(), SaveDatasetExpectationsCheckError> {
+        let mut guard = self.state.lock().unwrap();
+        guard
+            .checks_by_dataset_id
+            .entry(check.dataset_id.clone())
+            .or_default()
+            .push(check.clone());
+        Ok(())
+    }
+
+    async fn get_expectations_checks_count_by_dataset_id(
+        &self,
+        dataset_id: &DatasetID,
+    ) -> Result<usize, GetDatasetExpectationsChecksError> {
+        let guard = self.state.lock().unwrap();
+        Ok(guard
+            .checks_by_dataset_id
+            .get(dataset_id)
+            .map_or(0, Vec::len))
+    }
+
+    async fn get_expectations_checks_by_dataset_id(
+        &self,
+        dataset_id: &DatasetID,
+        pagination: &DatabasePaginationOpts,
+    ) -> Result<Vec<DatasetExpectationsCheck>, GetDatasetExpectationsChecksError> {
+        let guard = self.state.lock().unwrap();
+        let Some(checks) = guard.checks_by_dataset_id.get(dataset_id) else {
+            return Ok(vec![]);
+        };
+
+        let mut checks = checks.clone();
+        checks.sort_by(|a, b| b.checked_at.cmp(&a.checked_at));
+
+        Ok(checks
+            .into_iter()
+            .skip(usize::try_from(pagination.offset).unwrap())
+            .take(usize::try_from(pagination.limit).unwrap())
+            .collect())
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -7,8 +7,12 @@
 // the Business Source License, use of this software will be governed
 // by the Apache License, Version 2.0.
 
+mod inmem_dataset_dependency_repository;
 mod inmem_dataset_env_var_repository;
+mod inmem_dataset_expectation_repository;
 mod inmem_dateset_entry_repository;
 
+pub use inmem_dataset_dependency_repository::*;
 pub use inmem_dataset_env_var_repository::*;
+pub use inmem_dataset_expectation_repository::*;
 pub use inmem_dateset_entry_repository::*;
@@ -0,0 +1,64 @@
+// Copyright Kamu Data, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+use database_common_macros::database_transactional_test;
+use dill::{Catalog, CatalogBuilder};
+use kamu_datasets_inmem::InMemoryDatasetDependencyRepository;
+use kamu_datasets_repo_tests::dataset_dependency_repo;
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+database_transactional_test!(
+    storage = inmem,
+    fixture = dataset_dependency_repo::test_no_dependencies_initially,
+    harness = InMemoryDatasetDependencyRepositoryHarness
+);
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+database_transactional_test!(
+    storage = inmem,
+    fixture = dataset_dependency_repo::test_set_and_replace_upstream_dependencies,
+    harness = InMemoryDatasetDependencyRepositoryHarness
+);
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+database_transactional_test!(
+    storage = inmem,
+    fixture = dataset_dependency_repo::test_delete_dependencies_of_dataset,
+    harness = InMemoryDatasetDependencyRepositoryHarness
+);
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+database_transactional_test!(
+    storage = inmem,
+    fixture = dataset_dependency_repo::test_bootstrap_marker,
+    harness = InMemoryDatasetDependencyRepositoryHarness
+);
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+struct InMemoryDatasetDependencyRepositoryHarness {
+    catalog: Catalog,
+}
+
+impl InMemoryDatasetDependencyRepositoryHarness {
+    pub fn new() -> Self {
+        let mut catalog_builder = CatalogBuilder::new();
+        catalog_builder.add::<InMemoryDatasetDependencyRepository>();
+
+        Self {
+            catalog: catalog_builder.build(),
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
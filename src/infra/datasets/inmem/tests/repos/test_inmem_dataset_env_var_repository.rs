@@ -54,6 +54,14 @@ database_transactional_test!(
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
 
+database_transactional_test!(
+    storage = inmem,
+    fixture = dataset_env_var_repo::test_get_all_dataset_env_vars,
+    harness = InMemoryDatasetEnvVarRepositoryHarness
+);
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
 struct InMemoryDatasetEnvVarRepositoryHarness {
     catalog: Catalog,
 }
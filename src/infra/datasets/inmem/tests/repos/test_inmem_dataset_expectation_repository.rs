@@ -0,0 +1,56 @@
+// Copyright Kamu Data, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+use database_common_macros::database_transactional_test;
+use dill::{Catalog, CatalogBuilder};
+use kamu_datasets_inmem::InMemoryDatasetExpectationRepository;
+use kamu_datasets_repo_tests::dataset_expectation_repo;
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+database_transactional_test!(
+    storage = inmem,
+    fixture = dataset_expectation_repo::test_missing_dataset_expectations_are_empty,
+    harness = InMemoryDatasetExpectationRepositoryHarness
+);
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+database_transactional_test!(
+    storage = inmem,
+    fixture = dataset_expectation_repo::test_set_and_replace_dataset_expectations,
+    harness = InMemoryDatasetExpectationRepositoryHarness
+);
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+database_transactional_test!(
+    storage = inmem,
+    fixture = dataset_expectation_repo::test_save_and_get_expectations_checks,
+    harness = InMemoryDatasetExpectationRepositoryHarness
+);
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+struct InMemoryDatasetExpectationRepositoryHarness {
+    catalog: Catalog,
+}
+
+impl InMemoryDatasetExpectationRepositoryHarness {
+    pub fn new() -> Self {
+        let mut catalog_builder = CatalogBuilder::new();
+        catalog_builder.add::<InMemoryDatasetExpectationRepository>();
+
+        Self {
+            catalog: catalog_builder.build(),
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -7,6 +7,10 @@
 // the Business Source License, use of this software will be governed
 // by the Apache License, Version 2.0.
 
+mod postgres_dataset_dependency_repository;
 mod postgres_dataset_env_var_repository;
+mod postgres_dataset_expectation_repository;
 
+pub use postgres_dataset_dependency_repository::*;
 pub use postgres_dataset_env_var_repository::*;
+pub use postgres_dataset_expectation_repository::*;
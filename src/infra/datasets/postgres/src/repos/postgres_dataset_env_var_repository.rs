@@ -185,6 +185,36 @@ impl DatasetEnvVarRepository for PostgresDatasetEnvVarRepository {
         ))
     }
 
+    async fn get_all_dataset_env_vars(&self) -> Result<Vec<DatasetEnvVar>, GetDatasetEnvVarError> {
+        let mut tr = self.transaction.lock().await;
+
+        let connection_mut = tr
+            .connection_mut()
+            .await
+            .map_err(GetDatasetEnvVarError::Internal)?;
+
+        let dataset_env_var_rows = sqlx::query_as!(
+            DatasetEnvVarRowModel,
+            r#"
+                SELECT
+                    id,
+                    key,
+                    value as "value: _",
+                    secret_nonce,
+                    created_at,
+                    dataset_id as "dataset_id: _"
+                FROM dataset_env_vars
+                ORDER BY created_at, id
+                "#
+        )
+        .fetch_all(connection_mut)
+        .await
+        .int_err()
+        .map_err(GetDatasetEnvVarError::Internal)?;
+
+        Ok(dataset_env_var_rows.into_iter().map(Into::into).collect())
+    }
+
     async fn get_dataset_env_var_by_id(
         &self,
         dataset_env_var_id: &Uuid,
@@ -0,0 +1,61 @@
+// Copyright Kamu Data, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+use database_common::PostgresTransactionManager;
+use database_common_macros::database_transactional_test;
+use dill::{Catalog, CatalogBuilder};
+use kamu_datasets_postgres::PostgresDatasetExpectationRepository;
+use kamu_datasets_repo_tests::dataset_expectation_repo;
+use sqlx::PgPool;
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+database_transactional_test!(
+    storage = postgres,
+    fixture = dataset_expectation_repo::test_missing_dataset_expectations_are_empty,
+    harness = PostgresDatasetExpectationRepositoryHarness
+);
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+database_transactional_test!(
+    storage = postgres,
+    fixture = dataset_expectation_repo::test_set_and_replace_dataset_expectations,
+    harness = PostgresDatasetExpectationRepositoryHarness
+);
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+database_transactional_test!(
+    storage = postgres,
+    fixture = dataset_expectation_repo::test_save_and_get_expectations_checks,
+    harness = PostgresDatasetExpectationRepositoryHarness
+);
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+struct PostgresDatasetExpectationRepositoryHarness {
+    catalog: Catalog,
+}
+
+impl PostgresDatasetExpectationRepositoryHarness {
+    pub fn new(pg_pool: PgPool) -> Self {
+        // Initialize catalog with predefined Postgres pool
+        let mut catalog_builder = CatalogBuilder::new();
+        catalog_builder.add_value(pg_pool);
+        catalog_builder.add::<PostgresTransactionManager>();
+        catalog_builder.add::<PostgresDatasetExpectationRepository>();
+
+        Self {
+            catalog: catalog_builder.build(),
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -0,0 +1,176 @@
+// Copyright Kamu Data, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+use dill::Catalog;
+use futures::TryStreamExt;
+use kamu_datasets::{DatasetDependencyEntry, DatasetDependencyRepository};
+use opendatafabric::DatasetID;
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+pub async fn test_no_dependencies_initially(catalog: &Catalog) {
+    let dataset_dependency_repo = catalog
+        .get_one::<dyn DatasetDependencyRepository>()
+        .unwrap();
+
+    let entries: Vec<_> = dataset_dependency_repo
+        .list_all_dependencies()
+        .try_collect()
+        .await
+        .unwrap();
+    assert!(entries.is_empty());
+
+    let upstream_ids = dataset_dependency_repo
+        .get_upstream_dependencies(&DatasetID::new_seeded_ed25519(b"foo"))
+        .await
+        .unwrap();
+    assert!(upstream_ids.is_empty());
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+pub async fn test_set_and_replace_upstream_dependencies(catalog: &Catalog) {
+    let dataset_dependency_repo = catalog
+        .get_one::<dyn DatasetDependencyRepository>()
+        .unwrap();
+
+    let foo_id = DatasetID::new_seeded_ed25519(b"foo");
+    let bar_id = DatasetID::new_seeded_ed25519(b"bar");
+    let baz_id = DatasetID::new_seeded_ed25519(b"baz");
+    let qux_id = DatasetID::new_seeded_ed25519(b"qux");
+
+    dataset_dependency_repo
+        .set_upstream_dependencies(&baz_id, &[foo_id.clone(), bar_id.clone()])
+        .await
+        .unwrap();
+    dataset_dependency_repo
+        .set_upstream_dependencies(&qux_id, &[baz_id.clone()])
+        .await
+        .unwrap();
+
+    assert_eq!(
+        sorted_entries(dataset_dependency_repo.as_ref()).await,
+        sorted(vec![
+            DatasetDependencyEntry::new(baz_id.clone(), foo_id.clone()),
+            DatasetDependencyEntry::new(baz_id.clone(), bar_id.clone()),
+            DatasetDependencyEntry::new(qux_id.clone(), baz_id.clone()),
+        ])
+    );
+
+    // Replace
+    dataset_dependency_repo
+        .set_upstream_dependencies(&baz_id, &[bar_id.clone()])
+        .await
+        .unwrap();
+
+    assert_eq!(
+        dataset_dependency_repo
+            .get_upstream_dependencies(&baz_id)
+            .await
+            .unwrap(),
+        vec![bar_id.clone()]
+    );
+
+    // Clear
+    dataset_dependency_repo
+        .set_upstream_dependencies(&qux_id, &[])
+        .await
+        .unwrap();
+
+    assert_eq!(
+        sorted_entries(dataset_dependency_repo.as_ref()).await,
+        vec![DatasetDependencyEntry::new(baz_id, bar_id)]
+    );
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+pub async fn test_delete_dependencies_of_dataset(catalog: &Catalog) {
+    let dataset_dependency_repo = catalog
+        .get_one::<dyn DatasetDependencyRepository>()
+        .unwrap();
+
+    let foo_id = DatasetID::new_seeded_ed25519(b"foo");
+    let bar_id = DatasetID::new_seeded_ed25519(b"bar");
+    let baz_id = DatasetID::new_seeded_ed25519(b"baz");
+    let qux_id = DatasetID::new_seeded_ed25519(b"qux");
+
+    dataset_dependency_repo
+        .set_upstream_dependencies(&baz_id, &[foo_id.clone(), bar_id.clone()])
+        .await
+        .unwrap();
+    dataset_dependency_repo
+        .set_upstream_dependencies(&qux_id, &[baz_id.clone()])
+        .await
+        .unwrap();
+
+    // Deleting a dataset in the middle removes edges in both directions
+    dataset_dependency_repo
+        .delete_dependencies_of_dataset(&baz_id)
+        .await
+        .unwrap();
+
+    assert!(sorted_entries(dataset_dependency_repo.as_ref())
+        .await
+        .is_empty());
+
+    // Idempotent
+    dataset_dependency_repo
+        .delete_dependencies_of_dataset(&baz_id)
+        .await
+        .unwrap();
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+pub async fn test_bootstrap_marker(catalog: &Catalog) {
+    let dataset_dependency_repo = catalog
+        .get_one::<dyn DatasetDependencyRepository>()
+        .unwrap();
+
+    assert!(!dataset_dependency_repo.is_bootstrapped().await.unwrap());
+
+    // Stored dependencies alone do not mean the bootstrap happened
+    dataset_dependency_repo
+        .set_upstream_dependencies(
+            &DatasetID::new_seeded_ed25519(b"bar"),
+            &[DatasetID::new_seeded_ed25519(b"foo")],
+        )
+        .await
+        .unwrap();
+    assert!(!dataset_dependency_repo.is_bootstrapped().await.unwrap());
+
+    dataset_dependency_repo.mark_bootstrapped().await.unwrap();
+    assert!(dataset_dependency_repo.is_bootstrapped().await.unwrap());
+
+    // Idempotent
+    dataset_dependency_repo.mark_bootstrapped().await.unwrap();
+    assert!(dataset_dependency_repo.is_bootstrapped().await.unwrap());
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+async fn sorted_entries(
+    dataset_dependency_repo: &dyn DatasetDependencyRepository,
+) -> Vec<DatasetDependencyEntry> {
+    let entries: Vec<_> = dataset_dependency_repo
+        .list_all_dependencies()
+        .try_collect()
+        .await
+        .unwrap();
+
+    sorted(entries)
+}
+
+fn sorted(mut entries: Vec<DatasetDependencyEntry>) -> Vec<DatasetDependencyEntry> {
+    entries.sort();
+    entries
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -19,7 +19,6 @@ use kamu_datasets::{
     DeleteDatasetEnvVarError,
     GetDatasetEnvVarError,
     ModifyDatasetEnvVarError,
-    SAMPLE_DATASET_ENV_VAR_ENCRYPTION_KEY,
 };
 use opendatafabric::DatasetID;
 use secrecy::Secret;
@@ -27,6 +26,10 @@ use uuid::Uuid;
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
 
+const TEST_ENCRYPTION_KEY: &str = "QfnEDcnUtGSW2pwVXaFPvZOwxyFm2BOC";
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
 pub async fn test_missing_dataset_env_var_not_found(catalog: &Catalog) {
     let dataset_env_var_repo = catalog.get_one::<dyn DatasetEnvVarRepository>().unwrap();
     let dataset_env_var_result = dataset_env_var_repo
@@ -73,7 +76,7 @@ pub async fn test_insert_and_get_dataset_env_var(catalog: &Catalog) {
         Utc::now().round_subsecs(6),
         &dataset_env_var_value,
         &dataset_id,
-        SAMPLE_DATASET_ENV_VAR_ENCRYPTION_KEY,
+        TEST_ENCRYPTION_KEY,
     )
     .unwrap();
     let save_result = dataset_env_var_repo
@@ -122,7 +125,7 @@ pub async fn test_insert_and_get_multiple_dataset_env_vars(catalog: &Catalog) {
         Utc::now().round_subsecs(6),
         &secret_dataset_env_var_value,
         &dataset_id,
-        SAMPLE_DATASET_ENV_VAR_ENCRYPTION_KEY,
+        TEST_ENCRYPTION_KEY,
     )
     .unwrap();
 
@@ -135,7 +138,7 @@ pub async fn test_insert_and_get_multiple_dataset_env_vars(catalog: &Catalog) {
         Utc::now().round_subsecs(6),
         &dataset_env_var_value,
         &dataset_id,
-        SAMPLE_DATASET_ENV_VAR_ENCRYPTION_KEY,
+        TEST_ENCRYPTION_KEY,
     )
     .unwrap();
 
@@ -184,7 +187,7 @@ pub async fn test_delete_dataset_env_vars(catalog: &Catalog) {
         Utc::now().round_subsecs(6),
         &DatasetEnvVarValue::Regular("foo".to_string()),
         &dataset_id,
-        SAMPLE_DATASET_ENV_VAR_ENCRYPTION_KEY,
+        TEST_ENCRYPTION_KEY,
     )
     .unwrap();
     let new_bar_dataset_env_var = DatasetEnvVar::new(
@@ -192,7 +195,7 @@ pub async fn test_delete_dataset_env_vars(catalog: &Catalog) {
         Utc::now().round_subsecs(6),
         &DatasetEnvVarValue::Regular("bar".to_string()),
         &dataset_id,
-        SAMPLE_DATASET_ENV_VAR_ENCRYPTION_KEY,
+        TEST_ENCRYPTION_KEY,
     )
     .unwrap();
     let save_result = dataset_env_var_repo
@@ -240,7 +243,7 @@ pub async fn test_modify_dataset_env_vars(catalog: &Catalog) {
         Utc::now().round_subsecs(6),
         &DatasetEnvVarValue::Regular("foo".to_string()),
         &DatasetID::new_seeded_ed25519(b"foo"),
-        SAMPLE_DATASET_ENV_VAR_ENCRYPTION_KEY,
+        TEST_ENCRYPTION_KEY,
     )
     .unwrap();
     let save_result = dataset_env_var_repo
@@ -256,7 +259,7 @@ pub async fn test_modify_dataset_env_vars(catalog: &Catalog) {
     let (new_value, new_nonce) = new_dataset_env_var
         .generate_new_value(
             &DatasetEnvVarValue::Regular("new_foo".to_string()),
-            SAMPLE_DATASET_ENV_VAR_ENCRYPTION_KEY,
+            TEST_ENCRYPTION_KEY,
         )
         .unwrap();
 
@@ -277,10 +280,57 @@ pub async fn test_modify_dataset_env_vars(catalog: &Catalog) {
     assert_eq!(db_dataset_env_var.secret_nonce, new_nonce);
     assert_eq!(
         db_dataset_env_var
-            .get_exposed_decrypted_value(SAMPLE_DATASET_ENV_VAR_ENCRYPTION_KEY)
+            .get_exposed_decrypted_value(TEST_ENCRYPTION_KEY)
             .unwrap(),
         std::str::from_utf8(new_value.as_slice()).unwrap()
     );
 }
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+pub async fn test_get_all_dataset_env_vars(catalog: &Catalog) {
+    let dataset_env_var_repo = catalog.get_one::<dyn DatasetEnvVarRepository>().unwrap();
+
+    let db_dataset_env_vars = dataset_env_var_repo
+        .get_all_dataset_env_vars()
+        .await
+        .unwrap();
+    assert!(db_dataset_env_vars.is_empty());
+
+    let now = Utc::now().round_subsecs(6);
+
+    let foo_dataset_env_var = DatasetEnvVar::new(
+        "foo",
+        now,
+        &DatasetEnvVarValue::Secret(Secret::new("foo_value".to_string())),
+        &DatasetID::new_seeded_ed25519(b"foo"),
+        TEST_ENCRYPTION_KEY,
+    )
+    .unwrap();
+    let bar_dataset_env_var = DatasetEnvVar::new(
+        "bar",
+        now + chrono::Duration::try_seconds(1).unwrap(),
+        &DatasetEnvVarValue::Regular("bar_value".to_string()),
+        &DatasetID::new_seeded_ed25519(b"bar"),
+        TEST_ENCRYPTION_KEY,
+    )
+    .unwrap();
+
+    for dataset_env_var in [&foo_dataset_env_var, &bar_dataset_env_var] {
+        dataset_env_var_repo
+            .save_dataset_env_var(dataset_env_var)
+            .await
+            .unwrap();
+    }
+
+    let db_dataset_env_vars = dataset_env_var_repo
+        .get_all_dataset_env_vars()
+        .await
+        .unwrap();
+    assert_eq!(
+        db_dataset_env_vars,
+        vec![foo_dataset_env_var, bar_dataset_env_var]
+    );
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
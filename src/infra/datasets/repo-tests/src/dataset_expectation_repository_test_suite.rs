@@ -0,0 +1,184 @@
+// Copyright Kamu Data, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+use chrono::{Duration, SubsecRound, Utc};
+use database_common::DatabasePaginationOpts;
+use dill::Catalog;
+use kamu_datasets::{
+    DatasetExpectation,
+    DatasetExpectationRepository,
+    DatasetExpectationsCheck,
+    ExpectationOutcome,
+    ExpectationPolicy,
+    ExpectationRule,
+};
+use opendatafabric::{DatasetID, Multihash};
+use uuid::Uuid;
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+pub async fn test_missing_dataset_expectations_are_empty(catalog: &Catalog) {
+    let dataset_expectation_repo = catalog
+        .get_one::<dyn DatasetExpectationRepository>()
+        .unwrap();
+    let dataset_id = DatasetID::new_seeded_ed25519(b"foo");
+
+    let expectations = dataset_expectation_repo
+        .get_dataset_expectations(&dataset_id)
+        .await
+        .unwrap();
+    assert!(expectations.is_empty());
+
+    let checks_count = dataset_expectation_repo
+        .get_expectations_checks_count_by_dataset_id(&dataset_id)
+        .await
+        .unwrap();
+    assert_eq!(checks_count, 0);
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+pub async fn test_set_and_replace_dataset_expectations(catalog: &Catalog) {
+    let dataset_expectation_repo = catalog
+        .get_one::<dyn DatasetExpectationRepository>()
+        .unwrap();
+    let dataset_id = DatasetID::new_seeded_ed25519(b"foo");
+
+    let expectations = vec![
+        DatasetExpectation {
+            name: "id-not-null".to_string(),
+            rule: ExpectationRule::NotNull {
+                column: "id".to_string(),
+            },
+            policy: ExpectationPolicy::Fail,
+        },
+        DatasetExpectation {
+            name: "price-positive".to_string(),
+            rule: ExpectationRule::Range {
+                column: "price".to_string(),
+                min: Some(0.0),
+                max: None,
+            },
+            policy: ExpectationPolicy::Quarantine,
+        },
+    ];
+
+    dataset_expectation_repo
+        .set_dataset_expectations(&dataset_id, &expectations)
+        .await
+        .unwrap();
+
+    assert_eq!(
+        dataset_expectation_repo
+            .get_dataset_expectations(&dataset_id)
+            .await
+            .unwrap(),
+        expectations
+    );
+
+    dataset_expectation_repo
+        .set_dataset_expectations(&dataset_id, &expectations[1..])
+        .await
+        .unwrap();
+
+    assert_eq!(
+        dataset_expectation_repo
+            .get_dataset_expectations(&dataset_id)
+            .await
+            .unwrap(),
+        expectations[1..]
+    );
+
+    // Other datasets are unaffected
+    assert!(dataset_expectation_repo
+        .get_dataset_expectations(&DatasetID::new_seeded_ed25519(b"bar"))
+        .await
+        .unwrap()
+        .is_empty());
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+pub async fn test_save_and_get_expectations_checks(catalog: &Catalog) {
+    let dataset_expectation_repo = catalog
+        .get_one::<dyn DatasetExpectationRepository>()
+        .unwrap();
+    let dataset_id = DatasetID::new_seeded_ed25519(b"foo");
+    let checked_at = Utc::now().round_subsecs(6);
+
+    let older_check = DatasetExpectationsCheck {
+        id: Uuid::new_v4(),
+        dataset_id: dataset_id.clone(),
+        checked_at,
+        num_records: 10,
+        num_quarantined: 0,
+        new_head: None,
+        outcomes: vec![ExpectationOutcome {
+            expectation_name: "id-not-null".to_string(),
+            policy: ExpectationPolicy::Fail,
+            num_violations: 2,
+        }],
+    };
+    let newer_check = DatasetExpectationsCheck {
+        id: Uuid::new_v4(),
+        dataset_id: dataset_id.clone(),
+        checked_at: checked_at + Duration::try_seconds(1).unwrap(),
+        num_records: 5,
+        num_quarantined: 1,
+        new_head: Some(Multihash::from_digest_sha3_256(b"head")),
+        outcomes: vec![ExpectationOutcome {
+            expectation_name: "price-positive".to_string(),
+            policy: ExpectationPolicy::Quarantine,
+            num_violations: 1,
+        }],
+    };
+
+    for check in [&older_check, &newer_check] {
+        dataset_expectation_repo
+            .save_expectations_check(check)
+            .await
+            .unwrap();
+    }
+
+    assert_eq!(
+        dataset_expectation_repo
+            .get_expectations_checks_count_by_dataset_id(&dataset_id)
+            .await
+            .unwrap(),
+        2
+    );
+
+    let checks = dataset_expectation_repo
+        .get_expectations_checks_by_dataset_id(
+            &dataset_id,
+            &DatabasePaginationOpts {
+                offset: 0,
+                limit: 5,
+            },
+        )
+        .await
+        .unwrap();
+    assert_eq!(checks, vec![newer_check.clone(), older_check.clone()]);
+    assert!(checks[1].is_rejected());
+    assert!(!checks[0].is_rejected());
+
+    let checks = dataset_expectation_repo
+        .get_expectations_checks_by_dataset_id(
+            &dataset_id,
+            &DatabasePaginationOpts {
+                offset: 1,
+                limit: 5,
+            },
+        )
+        .await
+        .unwrap();
+    assert_eq!(checks, vec![older_check]);
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
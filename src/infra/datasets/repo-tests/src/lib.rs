@@ -9,12 +9,20 @@
 
 #![feature(assert_matches)]
 
+mod dataset_dependency_repository_test_suite;
 mod dataset_entry_repository_test_suite;
 mod dataset_env_var_repository_test_suite;
+mod dataset_expectation_repository_test_suite;
 
+pub mod dataset_dependency_repo {
+    pub use crate::dataset_dependency_repository_test_suite::*;
+}
 pub mod dataset_entry_repo {
     pub use crate::dataset_entry_repository_test_suite::*;
 }
 pub mod dataset_env_var_repo {
     pub use crate::dataset_env_var_repository_test_suite::*;
 }
+pub mod dataset_expectation_repo {
+    pub use crate::dataset_expectation_repository_test_suite::*;
+}
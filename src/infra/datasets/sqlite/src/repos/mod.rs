@@ -7,8 +7,12 @@
 // the Business Source License, use of this software will be governed
 // by the Apache License, Version 2.0.
 
+mod sqlite_dataset_dependency_repository;
 mod sqlite_dataset_env_var_repository;
+mod sqlite_dataset_expectation_repository;
 mod sqlite_dateset_entry_repository;
 
+pub use sqlite_dataset_dependency_repository::*;
 pub use sqlite_dataset_env_var_repository::*;
+pub use sqlite_dataset_expectation_repository::*;
 pub use sqlite_dateset_entry_repository::*;
@@ -0,0 +1,223 @@
+// Copyright Kamu Data, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+use database_common::{TransactionRef, TransactionRefT};
+use dill::{component, interface};
+use futures::TryStreamExt;
+use internal_error::{ErrorIntoInternal, ResultIntoInternal};
+use opendatafabric::DatasetID;
+
+use crate::domain::*;
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+pub struct SqliteDatasetDependencyRepository {
+    transaction: TransactionRefT<sqlx::Sqlite>,
+}
+
+#[component(pub)]
+#[interface(dyn DatasetDependencyRepository)]
+impl SqliteDatasetDependencyRepository {
+    pub fn new(transaction: TransactionRef) -> Self {
+        Self {
+            transaction: transaction.into(),
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[async_trait::async_trait]
+impl DatasetDependencyRepository for SqliteDatasetDependencyRepository {
+    fn list_all_dependencies(&self) -> DatasetDependencyEntryStream {
+        Box::pin(async_stream::stream! {
+            let mut tr = self.transaction.lock().await;
+            let connection_mut = tr.connection_mut().await?;
+
+            let mut query_stream = sqlx::query!(
+                r#"
+                SELECT downstream_dataset_id, upstream_dataset_id
+                FROM dataset_dependencies
+                ORDER BY downstream_dataset_id, upstream_dataset_id
+                "#,
+            )
+            .try_map(|row| {
+                let downstream_dataset_id = DatasetID::from_did_str(&row.downstream_dataset_id)
+                    .map_err(|e| sqlx::Error::Decode(Box::new(e)))?;
+                let upstream_dataset_id = DatasetID::from_did_str(&row.upstream_dataset_id)
+                    .map_err(|e| sqlx::Error::Decode(Box::new(e)))?;
+
+                Ok(DatasetDependencyEntry::new(downstream_dataset_id, upstream_dataset_id))
+            })
+            .fetch(connection_mut)
+            .map_err(ErrorIntoInternal::int_err);
+
+            while let Some(entry) = query_stream.try_next().await? {
+                yield Ok(entry);
+            }
+        })
+    }
+
+    async fn get_upstream_dependencies(
+        &self,
+        downstream_dataset_id: &DatasetID,
+    ) -> Result<Vec<DatasetID>, GetDatasetDependenciesError> {
+        let mut tr = self.transaction.lock().await;
+
+        let connection_mut = tr
+            .connection_mut()
+            .await
+            .map_err(GetDatasetDependenciesError::Internal)?;
+
+        let downstream_dataset_id_string = downstream_dataset_id.to_string();
+
+        let upstream_dataset_ids = sqlx::query_scalar!(
+            r#"
+                SELECT upstream_dataset_id
+                FROM dataset_dependencies
+                WHERE downstream_dataset_id = $1
+                ORDER BY upstream_dataset_id
+                "#,
+            downstream_dataset_id_string,
+        )
+        .fetch_all(connection_mut)
+        .await
+        .int_err()
+        .map_err(GetDatasetDependenciesError::Internal)?;
+
+        upstream_dataset_ids
+            .iter()
+            .map(|id| DatasetID::from_did_str(id).int_err())
+            .collect::<Result<_, _>>()
+            .map_err(GetDatasetDependenciesError::Internal)
+    }
+
+    async fn set_upstream_dependencies(
+        &self,
+        downstream_dataset_id: &DatasetID,
+        upstream_dataset_ids: &[DatasetID],
+    ) -> Result<(), SetDatasetDependenciesError> {
+        let mut tr = self.transaction.lock().await;
+
+        let connection_mut = tr
+            .connection_mut()
+            .await
+            .map_err(SetDatasetDependenciesError::Internal)?;
+
+        let downstream_dataset_id_string = downstream_dataset_id.to_string();
+
+        sqlx::query!(
+            r#"
+                DELETE FROM dataset_dependencies
+                WHERE downstream_dataset_id = $1
+                "#,
+            downstream_dataset_id_string,
+        )
+        .execute(&mut *connection_mut)
+        .await
+        .int_err()
+        .map_err(SetDatasetDependenciesError::Internal)?;
+
+        for upstream_dataset_id in upstream_dataset_ids {
+            let upstream_dataset_id_string = upstream_dataset_id.to_string();
+
+            sqlx::query!(
+                r#"
+                    INSERT INTO dataset_dependencies (downstream_dataset_id, upstream_dataset_id)
+                        VALUES ($1, $2)
+                        ON CONFLICT DO NOTHING
+                    "#,
+                downstream_dataset_id_string,
+                upstream_dataset_id_string,
+            )
+            .execute(&mut *connection_mut)
+            .await
+            .int_err()
+            .map_err(SetDatasetDependenciesError::Internal)?;
+        }
+
+        Ok(())
+    }
+
+    async fn delete_dependencies_of_dataset(
+        &self,
+        dataset_id: &DatasetID,
+    ) -> Result<(), DeleteDatasetDependenciesError> {
+        let mut tr = self.transaction.lock().await;
+
+        let connection_mut = tr
+            .connection_mut()
+            .await
+            .map_err(DeleteDatasetDependenciesError::Internal)?;
+
+        let dataset_id_string = dataset_id.to_string();
+
+        sqlx::query!(
+            r#"
+                DELETE FROM dataset_dependencies
+                WHERE downstream_dataset_id = $1 OR upstream_dataset_id = $1
+                "#,
+            dataset_id_string,
+        )
+        .execute(connection_mut)
+        .await
+        .int_err()
+        .map_err(DeleteDatasetDependenciesError::Internal)?;
+
+        Ok(())
+    }
+
+    async fn is_bootstrapped(&self) -> Result<bool, GetDatasetDependenciesError> {
+        let mut tr = self.transaction.lock().await;
+
+        let connection_mut = tr
+            .connection_mut()
+            .await
+            .map_err(GetDatasetDependenciesError::Internal)?;
+
+        let bootstrapped_count = sqlx::query_scalar!(
+            r#"
+                SELECT
+                    count(*)
+                FROM dataset_dependencies_bootstrap
+                "#
+        )
+        .fetch_one(connection_mut)
+        .await
+        .int_err()
+        .map_err(GetDatasetDependenciesError::Internal)?;
+
+        Ok(bootstrapped_count > 0)
+    }
+
+    async fn mark_bootstrapped(&self) -> Result<(), SetDatasetDependenciesError> {
+        let mut tr = self.transaction.lock().await;
+
+        let connection_mut = tr
+            .connection_mut()
+            .await
+            .map_err(SetDatasetDependenciesError::Internal)?;
+
+        sqlx::query!(
+            r#"
+                INSERT INTO dataset_dependencies_bootstrap (id)
+                    VALUES (1)
+                    ON CONFLICT DO NOTHING
+                "#
+        )
+        .execute(connection_mut)
+        .await
+        .int_err()
+        .map_err(SetDatasetDependenciesError::Internal)?;
+
+        Ok(())
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -0,0 +1,226 @@
+// Copyright Kamu Data, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+use database_common::{DatabasePaginationOpts, TransactionRef, TransactionRefT};
+use dill::{component, interface};
+use internal_error::{InternalError, ResultIntoInternal};
+use opendatafabric::DatasetID;
+use uuid::Uuid;
+
+use crate::domain::*;
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+pub struct SqliteDatasetExpectationRepository {
+    transaction: TransactionRefT<sqlx::Sqlite>,
+}
+
+#[component(pub)]
+#[interface(dyn DatasetExpectationRepository)]
+impl SqliteDatasetExpectationRepository {
+    pub fn new(transaction: TransactionRef) -> Self {
+        Self {
+            transaction: transaction.into(),
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl DatasetExpectationRepository for SqliteDatasetExpectationRepository {
+    async fn set_dataset_expectations(
+        &self,
+        dataset_id: &DatasetID,
+        expectations: &[DatasetExpectation],
+    ) -> Result<(), SetDatasetExpectationsError> {
+        let mut tr = self.transaction.lock().await;
+
+        let connection_mut = tr
+            .connection_mut()
+            .await
+            .map_err(SetDatasetExpectationsError::Internal)?;
+
+        let dataset_id_string = dataset_id.to_string();
+        let expectations_json = serde_json::to_string(expectations)
+            .int_err()
+            .map_err(SetDatasetExpectationsError::Internal)?;
+
+        sqlx::query!(
+            r#"
+                INSERT INTO dataset_expectations (dataset_id, expectations)
+                    VALUES ($1, $2)
+                    ON CONFLICT (dataset_id) DO UPDATE SET expectations = excluded.expectations
+                "#,
+            dataset_id_string,
+            expectations_json,
+        )
+        .execute(connection_mut)
+        .await
+        .int_err()
+        .map_err(SetDatasetExpectationsError::Internal)?;
+
+        Ok(())
+    }
+
+    async fn get_dataset_expectations(
+        &self,
+        dataset_id: &DatasetID,
+    ) -> Result<Vec<DatasetExpectation>, GetDatasetExpectationsError> {
+        let mut tr = self.transaction.lock().await;
+
+        let connection_mut = tr
+            .connection_mut()
+            .await
+            .map_err(GetDatasetExpectationsError::Internal)?;
+
+        let dataset_id_string = dataset_id.to_string();
+
+        let maybe_expectations_json = sqlx::query_scalar!(
+            r#"
+                SELECT expectations
+                FROM dataset_expectations
+                WHERE dataset_id = $1
+                "#,
+            dataset_id_string,
+        )
+        .fetch_optional(connection_mut)
+        .await
+        .int_err()
+        .map_err(GetDatasetExpectationsError::Internal)?;
+
+        let Some(expectations_json) = maybe_expectations_json else {
+            return Ok(vec![]);
+        };
+
+        serde_json::from_str(&expectations_json)
+            .int_err()
+            .map_err(GetDatasetExpectationsError::Internal)
+    }
+
+    async fn save_expectations_check(
+        &self,
+        check: &DatasetExpectationsCheck,
+    ) -> Result<(), SaveDatasetExpectationsCheckError> {
+        let mut tr = self.transaction.lock().await;
+
+        let connection_mut = tr
+            .connection_mut()
+            .await
+            .map_err(SaveDatasetExpectationsCheckError::Internal)?;
+
+        let check_id = check.id;
+        let dataset_id_string = check.dataset_id.to_string();
+        let checked_at = check.checked_at;
+        let num_records = i64::try_from(check.num_records).unwrap();
+        let num_quarantined = i64::try_from(check.num_quarantined).unwrap();
+        let new_head = check.new_head.as_ref().map(ToString::to_string);
+        let outcomes_json = serde_json::to_string(&check.outcomes)
+            .int_err()
+            .map_err(SaveDatasetExpectationsCheckError::Internal)?;
+
+        sqlx::query!(
+            r#"
+                INSERT INTO dataset_expectation_checks (id, dataset_id, checked_at, num_records, num_quarantined, new_head, outcomes)
+                    VALUES ($1, $2, $3, $4, $5, $6, $7)
+                "#,
+            check_id,
+            dataset_id_string,
+            checked_at,
+            num_records,
+            num_quarantined,
+            new_head,
+            outcomes_json,
+        )
+        .execute(connection_mut)
+        .await
+        .int_err()
+        .map_err(SaveDatasetExpectationsCheckError::Internal)?;
+
+        Ok(())
+    }
+
+    async fn get_expectations_checks_count_by_dataset_id(
+        &self,
+        dataset_id: &DatasetID,
+    ) -> Result<usize, GetDatasetExpectationsChecksError> {
+        let mut tr = self.transaction.lock().await;
+
+        let connection_mut = tr
+            .connection_mut()
+            .await
+            .map_err(GetDatasetExpectationsChecksError::Internal)?;
+
+        let dataset_id_string = dataset_id.to_string();
+
+        let checks_count = sqlx::query_scalar!(
+            r#"
+                SELECT
+                    count(*)
+                FROM dataset_expectation_checks
+                WHERE dataset_id = $1
+            "#,
+            dataset_id_string,
+        )
+        .fetch_one(connection_mut)
+        .await
+        .int_err()
+        .map_err(GetDatasetExpectationsChecksError::Internal)?;
+
+        Ok(usize::try_from(checks_count).unwrap_or(0))
+    }
+
+    async fn get_expectations_checks_by_dataset_id(
+        &self,
+        dataset_id: &DatasetID,
+        pagination: &DatabasePaginationOpts,
+    ) -> Result<Vec<DatasetExpectationsCheck>, GetDatasetExpectationsChecksError> {
+        let mut tr = self.transaction.lock().await;
+
+        let connection_mut = tr
+            .connection_mut()
+            .await
+            .map_err(GetDatasetExpectationsChecksError::Internal)?;
+
+        let dataset_id_string = dataset_id.to_string();
+        let limit = pagination.limit;
+        let offset = pagination.offset;
+
+        let check_rows = sqlx::query_as!(
+            DatasetExpectationsCheckRowModel,
+            r#"
+                SELECT
+                    id as "id: Uuid",
+                    dataset_id as "dataset_id: _",
+                    checked_at as "checked_at: _",
+                    num_records,
+                    num_quarantined,
+                    new_head,
+                    outcomes
+                FROM dataset_expectation_checks
+                WHERE dataset_id = $1
+                ORDER BY checked_at DESC, id
+                LIMIT $2 OFFSET $3
+                "#,
+            dataset_id_string,
+            limit,
+            offset,
+        )
+        .fetch_all(connection_mut)
+        .await
+        .int_err()
+        .map_err(GetDatasetExpectationsChecksError::Internal)?;
+
+        check_rows
+            .into_iter()
+            .map(TryInto::try_into)
+            .collect::<Result<_, InternalError>>()
+            .map_err(GetDatasetExpectationsChecksError::Internal)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -0,0 +1,69 @@
+// Copyright Kamu Data, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+use database_common::SqliteTransactionManager;
+use database_common_macros::database_transactional_test;
+use dill::{Catalog, CatalogBuilder};
+use kamu_datasets_repo_tests::dataset_dependency_repo;
+use kamu_datasets_sqlite::SqliteDatasetDependencyRepository;
+use sqlx::SqlitePool;
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+database_transactional_test!(
+    storage = sqlite,
+    fixture = dataset_dependency_repo::test_no_dependencies_initially,
+    harness = SqliteDatasetDependencyRepositoryHarness
+);
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+database_transactional_test!(
+    storage = sqlite,
+    fixture = dataset_dependency_repo::test_set_and_replace_upstream_dependencies,
+    harness = SqliteDatasetDependencyRepositoryHarness
+);
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+database_transactional_test!(
+    storage = sqlite,
+    fixture = dataset_dependency_repo::test_delete_dependencies_of_dataset,
+    harness = SqliteDatasetDependencyRepositoryHarness
+);
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+database_transactional_test!(
+    storage = sqlite,
+    fixture = dataset_dependency_repo::test_bootstrap_marker,
+    harness = SqliteDatasetDependencyRepositoryHarness
+);
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+struct SqliteDatasetDependencyRepositoryHarness {
+    catalog: Catalog,
+}
+
+impl SqliteDatasetDependencyRepositoryHarness {
+    pub fn new(sqlite_pool: SqlitePool) -> Self {
+        // Initialize catalog with predefined Sqlite pool
+        let mut catalog_builder = CatalogBuilder::new();
+        catalog_builder.add_value(sqlite_pool);
+        catalog_builder.add::<SqliteTransactionManager>();
+        catalog_builder.add::<SqliteDatasetDependencyRepository>();
+
+        Self {
+            catalog: catalog_builder.build(),
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -0,0 +1,61 @@
+// Copyright Kamu Data, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+use database_common::SqliteTransactionManager;
+use database_common_macros::database_transactional_test;
+use dill::{Catalog, CatalogBuilder};
+use kamu_datasets_repo_tests::dataset_expectation_repo;
+use kamu_datasets_sqlite::SqliteDatasetExpectationRepository;
+use sqlx::SqlitePool;
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+database_transactional_test!(
+    storage = sqlite,
+    fixture = dataset_expectation_repo::test_missing_dataset_expectations_are_empty,
+    harness = SqliteDatasetExpectationRepositoryHarness
+);
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+database_transactional_test!(
+    storage = sqlite,
+    fixture = dataset_expectation_repo::test_set_and_replace_dataset_expectations,
+    harness = SqliteDatasetExpectationRepositoryHarness
+);
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+database_transactional_test!(
+    storage = sqlite,
+    fixture = dataset_expectation_repo::test_save_and_get_expectations_checks,
+    harness = SqliteDatasetExpectationRepositoryHarness
+);
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+struct SqliteDatasetExpectationRepositoryHarness {
+    catalog: Catalog,
+}
+
+impl SqliteDatasetExpectationRepositoryHarness {
+    pub fn new(sqlite_pool: SqlitePool) -> Self {
+        // Initialize catalog with predefined Sqlite pool
+        let mut catalog_builder = CatalogBuilder::new();
+        catalog_builder.add_value(sqlite_pool);
+        catalog_builder.add::<SqliteTransactionManager>();
+        catalog_builder.add::<SqliteDatasetExpectationRepository>();
+
+        Self {
+            catalog: catalog_builder.build(),
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -0,0 +1,239 @@
+// Copyright Kamu Data, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+use std::path::Path;
+use std::sync::Arc;
+
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion};
+use datafusion::dataframe::DataFrameWriteOptions;
+use datafusion::prelude::*;
+use kamu_ingest_datafusion::*;
+use opendatafabric as odf;
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+// Compares the cost of a snapshot merge against the full ledger with the cost
+// of a merge against the projected state (as persisted by the writer in a
+// checkpoint) as the history of the dataset grows
+
+const ORIG_ROWS: usize = 200_000;
+const CHANGED_ROWS: usize = 20_000;
+const HISTORY_LENGTHS: [usize; 3] = [1, 10, 30];
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+fn strategy() -> MergeStrategySnapshot {
+    MergeStrategySnapshot::new(
+        odf::DatasetVocabulary::default(),
+        odf::MergeStrategySnapshot {
+            primary_key: vec!["pk".to_string()],
+            compare_columns: None,
+        },
+    )
+}
+
+async fn write_batch(
+    ctx: &SessionContext,
+    path: &Path,
+    offset: Option<Vec<u64>>,
+    op: Option<Vec<u8>>,
+    pk: Vec<u64>,
+    value: Vec<u64>,
+) {
+    use datafusion::arrow::array::{ArrayRef, UInt64Array, UInt8Array};
+    use datafusion::arrow::datatypes::{DataType, Field, Schema};
+    use datafusion::arrow::record_batch::RecordBatch;
+
+    let mut fields = Vec::new();
+    let mut columns: Vec<ArrayRef> = Vec::new();
+
+    if let Some(offset) = offset {
+        fields.push(Field::new("offset", DataType::UInt64, false));
+        columns.push(Arc::new(UInt64Array::from(offset)));
+    }
+    if let Some(op) = op {
+        fields.push(Field::new("op", DataType::UInt8, false));
+        columns.push(Arc::new(UInt8Array::from(op)));
+    }
+    fields.push(Field::new("pk", DataType::UInt64, false));
+    columns.push(Arc::new(UInt64Array::from(pk)));
+    fields.push(Field::new("value", DataType::UInt64, false));
+    columns.push(Arc::new(UInt64Array::from(value)));
+
+    ctx.read_batch(RecordBatch::try_new(Arc::new(Schema::new(fields)), columns).unwrap())
+        .unwrap()
+        .write_parquet(
+            path.to_str().unwrap(),
+            DataFrameWriteOptions::new().with_single_file_output(true),
+            None,
+        )
+        .await
+        .unwrap();
+}
+
+/// Generates a ledger where every generation updates a rolling window of
+/// records, the state projected from it, and the next snapshot to merge
+async fn setup(dir: &Path, history_length: usize) {
+    let ctx = SessionContext::new();
+
+    std::fs::create_dir_all(dir.join("ledger")).unwrap();
+
+    let mut values = vec![0_u64; ORIG_ROWS];
+    let mut next_offset = 0_u64;
+
+    let mut take_offsets = |n: usize| -> Vec<u64> {
+        let offsets = (next_offset..next_offset + n as u64).collect();
+        next_offset += n as u64;
+        offsets
+    };
+
+    write_batch(
+        &ctx,
+        &dir.join("ledger").join("0.parquet"),
+        Some(take_offsets(ORIG_ROWS)),
+        Some(vec![odf::OperationType::Append as u8; ORIG_ROWS]),
+        (0..ORIG_ROWS as u64).collect(),
+        values.clone(),
+    )
+    .await;
+
+    let changed = |generation: usize| {
+        (generation * CHANGED_ROWS..(generation + 1) * CHANGED_ROWS).map(|i| i % ORIG_ROWS)
+    };
+
+    for generation in 1..=history_length {
+        let mut op = Vec::with_capacity(CHANGED_ROWS * 2);
+        let mut pk = Vec::with_capacity(CHANGED_ROWS * 2);
+        let mut value = Vec::with_capacity(CHANGED_ROWS * 2);
+
+        for i in changed(generation) {
+            op.push(odf::OperationType::CorrectFrom as u8);
+            pk.push(i as u64);
+            value.push(values[i]);
+
+            values[i] = generation as u64;
+            op.push(odf::OperationType::CorrectTo as u8);
+            pk.push(i as u64);
+            value.push(values[i]);
+        }
+
+        write_batch(
+            &ctx,
+            &dir.join("ledger").join(format!("{generation}.parquet")),
+            Some(take_offsets(op.len())),
+            Some(op),
+            pk,
+            value,
+        )
+        .await;
+    }
+
+    // Project and persist the state the same way the writer does
+    strategy()
+        .project_state(
+            ctx.read_parquet(
+                dir.join("ledger").to_str().unwrap(),
+                ParquetReadOptions::default(),
+            )
+            .await
+            .unwrap(),
+        )
+        .unwrap()
+        .unwrap()
+        .write_parquet(
+            dir.join("state.parquet").to_str().unwrap(),
+            DataFrameWriteOptions::new().with_single_file_output(true),
+            None,
+        )
+        .await
+        .unwrap();
+
+    // New snapshot changes the next window of records
+    for i in changed(history_length + 1) {
+        values[i] = u64::MAX;
+    }
+
+    write_batch(
+        &ctx,
+        &dir.join("new.parquet"),
+        None,
+        None,
+        (0..ORIG_ROWS as u64).collect(),
+        values,
+    )
+    .await;
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+async fn merge_snapshot(prev_path: &Path, new_path: &Path) {
+    let ctx = SessionContext::new_with_config(SessionConfig::new());
+
+    let prev = ctx
+        .read_parquet(prev_path.to_str().unwrap(), ParquetReadOptions::default())
+        .await
+        .unwrap();
+
+    let new = ctx
+        .read_parquet(new_path.to_str().unwrap(), ParquetReadOptions::default())
+        .await
+        .unwrap();
+
+    let res = strategy().merge(Some(prev), new).unwrap();
+
+    let res = res.cache().await.unwrap();
+
+    // Two correction events per changed row
+    assert_eq!(res.count().await.unwrap(), CHANGED_ROWS * 2);
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+fn bench(c: &mut Criterion) {
+    let tempdir = tempfile::tempdir().unwrap();
+
+    let rt = tokio::runtime::Runtime::new().unwrap();
+
+    let mut group = c.benchmark_group("merge_snapshot_incremental");
+    group.sample_size(10);
+
+    for history_length in HISTORY_LENGTHS {
+        let dir = tempdir.path().join(history_length.to_string());
+        rt.block_on(setup(&dir, history_length));
+
+        group.bench_with_input(
+            BenchmarkId::new("ledger", history_length),
+            &dir,
+            |b, dir| {
+                b.iter(|| {
+                    rt.block_on(merge_snapshot(
+                        &dir.join("ledger"),
+                        &dir.join("new.parquet"),
+                    ))
+                });
+            },
+        );
+
+        group.bench_with_input(BenchmarkId::new("state", history_length), &dir, |b, dir| {
+            b.iter(|| {
+                rt.block_on(merge_snapshot(
+                    &dir.join("state.parquet"),
+                    &dir.join("new.parquet"),
+                ))
+            });
+        });
+    }
+
+    group.finish();
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+criterion_group!(benches, bench);
+criterion_main!(benches);
@@ -0,0 +1,287 @@
+// Copyright Kamu Data, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+use datafusion::arrow::array::{Array, Int64Array};
+use datafusion::arrow::datatypes::{DataType, SchemaRef};
+use datafusion::functions_aggregate::count::{count, count_udaf};
+use datafusion::functions_aggregate::sum::sum;
+use datafusion::logical_expr::{self as expr, Operator};
+use datafusion::prelude::*;
+use internal_error::*;
+use kamu_core::ingest::*;
+use kamu_datasets::{
+    DatasetExpectation,
+    ExpectationOutcome,
+    ExpectationPolicy,
+    ExpectationRule,
+    ExpectationsCheckResult,
+};
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Evaluates declarative [`DatasetExpectation`]s against a new slice of data
+pub struct ExpectationsEvaluator<'a> {
+    expectations: &'a [DatasetExpectation],
+}
+
+/// Result of the expectations evaluation
+pub struct ExpectationsEvaluation {
+    /// Data with quarantined rows excluded
+    pub data: DataFrame,
+    /// Rows that violated expectations with [`ExpectationPolicy::Quarantine`]
+    pub quarantined: Option<DataFrame>,
+    pub check: ExpectationsCheckResult,
+}
+
+impl<'a> ExpectationsEvaluator<'a> {
+    const DUP_COLUMN_PREFIX: &'static str = "__dup_";
+
+    pub fn new(expectations: &'a [DatasetExpectation]) -> Self {
+        Self { expectations }
+    }
+
+    pub async fn evaluate(&self, df: DataFrame) -> Result<ExpectationsEvaluation, StageDataError> {
+        self.validate_columns(&df)?;
+
+        // Compute the data once as it will be scanned several times
+        let data_columns: Vec<_> = df
+            .schema()
+            .fields()
+            .iter()
+            .map(|f| f.name().clone())
+            .collect();
+        let mut df = df.cache().await.int_err()?;
+
+        // Uniqueness checks require a window over key columns that we add as
+        // temporary columns
+        for (i, expectation) in self.expectations.iter().enumerate() {
+            if let ExpectationRule::Unique { columns } = &expectation.rule {
+                df = df
+                    .with_column(
+                        &format!("{}{i}", Self::DUP_COLUMN_PREFIX),
+                        Expr::WindowFunction(expr::expr::WindowFunction {
+                            fun: expr::WindowFunctionDefinition::AggregateUDF(count_udaf()),
+                            args: vec![lit(1)],
+                            partition_by: columns
+                                .iter()
+                                .map(|c| col(Column::from_name(c)))
+                                .collect(),
+                            order_by: vec![],
+                            window_frame: expr::WindowFrame::new(None),
+                            null_treatment: None,
+                        }),
+                    )
+                    .int_err()?;
+            }
+        }
+
+        // Count violations of all row-level rules in one pass
+        let row_predicates: Vec<_> = self
+            .expectations
+            .iter()
+            .enumerate()
+            .map(|(i, e)| Self::violation_predicate(i, &e.rule))
+            .collect();
+
+        let mut aggr = vec![count(lit(1))];
+        for pred in row_predicates.iter().flatten() {
+            aggr.push(sum(when(pred.clone(), lit(1i64))
+                .otherwise(lit(0i64))
+                .int_err()?));
+        }
+
+        let aggr_len = aggr.len();
+        let batches = df
+            .clone()
+            .aggregate(vec![], aggr)
+            .int_err()?
+            .collect()
+            .await
+            .int_err()?;
+        let [batch] = batches.as_slice() else {
+            return Err(
+                format!("Expected a single batch of counts, got {}", batches.len())
+                    .int_err()
+                    .into(),
+            );
+        };
+        if batch.num_rows() != 1 || batch.num_columns() != aggr_len {
+            return Err(format!(
+                "Expected counts batch of 1 row and {aggr_len} columns, got {} rows and {} columns",
+                batch.num_rows(),
+                batch.num_columns()
+            )
+            .int_err()
+            .into());
+        }
+
+        let get_count = |i: usize| -> Result<u64, InternalError> {
+            let Some(arr) = batch.column(i).as_any().downcast_ref::<Int64Array>() else {
+                return InternalError::bail(format!(
+                    "Unexpected type of count column {i}: {}",
+                    batch.column(i).data_type()
+                ));
+            };
+            if arr.is_null(0) {
+                Ok(0)
+            } else {
+                u64::try_from(arr.value(0)).int_err()
+            }
+        };
+
+        let num_records = get_count(0)?;
+
+        let mut outcomes = Vec::with_capacity(self.expectations.len());
+        let mut column_index = 1;
+        for (expectation, pred) in self.expectations.iter().zip(&row_predicates) {
+            let num_violations = if pred.is_some() {
+                column_index += 1;
+                get_count(column_index - 1)?
+            } else if let ExpectationRule::RowCount { min, max } = &expectation.rule {
+                u64::from(
+                    min.is_some_and(|min| num_records < min)
+                        || max.is_some_and(|max| num_records > max),
+                )
+            } else {
+                unreachable!()
+            };
+
+            if num_violations != 0 && expectation.policy == ExpectationPolicy::Warn {
+                tracing::warn!(
+                    expectation = %expectation.name,
+                    num_violations,
+                    "Data quality expectation violated",
+                );
+            }
+
+            outcomes.push(ExpectationOutcome {
+                expectation_name: expectation.name.clone(),
+                policy: expectation.policy,
+                num_violations,
+            });
+        }
+
+        // Split off the quarantined rows
+        let quarantine_pred = self
+            .expectations
+            .iter()
+            .zip(&row_predicates)
+            .zip(&outcomes)
+            .filter(|((e, _), o)| e.policy == ExpectationPolicy::Quarantine && o.is_violated())
+            .filter_map(|((_, pred), _)| pred.clone())
+            .reduce(Expr::or);
+
+        let (data, quarantined, num_quarantined) = if let Some(pred) = quarantine_pred {
+            let quarantined = df.clone().filter(pred.clone()).int_err()?;
+            let data = df.filter(not(pred)).int_err()?;
+            let num_remaining = u64::try_from(data.clone().count().await.int_err()?).int_err()?;
+            (data, Some(quarantined), num_records - num_remaining)
+        } else {
+            (df, None, 0)
+        };
+
+        // Drop temporary columns
+        let data_columns: Vec<_> = data_columns.iter().map(String::as_str).collect();
+        let data = data.select_columns(&data_columns).int_err()?;
+        let quarantined = quarantined
+            .map(|df| df.select_columns(&data_columns))
+            .transpose()
+            .int_err()?;
+
+        let check = ExpectationsCheckResult {
+            num_records,
+            num_quarantined,
+            new_head: None,
+            outcomes,
+        };
+
+        if check
+            .outcomes
+            .iter()
+            .any(|o| o.policy == ExpectationPolicy::Fail && o.is_violated())
+        {
+            return Err(ExpectationsFailedError { check }.into());
+        }
+
+        Ok(ExpectationsEvaluation {
+            data,
+            quarantined,
+            check,
+        })
+    }
+
+    fn validate_columns(&self, df: &DataFrame) -> Result<(), BadInputSchemaError> {
+        for expectation in self.expectations {
+            let columns = match &expectation.rule {
+                ExpectationRule::NotNull { column }
+                | ExpectationRule::AllowedValues { column, .. }
+                | ExpectationRule::Range { column, .. }
+                | ExpectationRule::Regex { column, .. } => std::slice::from_ref(column),
+                ExpectationRule::Unique { columns } => columns.as_slice(),
+                ExpectationRule::RowCount { .. } => &[],
+            };
+
+            for column in columns {
+                if !df.schema().has_column_with_unqualified_name(column) {
+                    return Err(BadInputSchemaError::new(
+                        format!(
+                            "Expectation '{}' refers to a column that does not exist in the data: \
+                             {column}",
+                            expectation.name
+                        ),
+                        SchemaRef::new(df.schema().into()),
+                    ));
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Returns an expression that evaluates to `true` for rows violating the
+    /// rule, or `None` for slice-level rules
+    fn violation_predicate(index: usize, rule: &ExpectationRule) -> Option<Expr> {
+        let column = |name: &str| col(Column::from_name(name));
+
+        let pred = match rule {
+            ExpectationRule::NotNull { column: c } => column(c).is_null(),
+            ExpectationRule::Unique { .. } => {
+                column(&format!("{}{index}", Self::DUP_COLUMN_PREFIX)).gt(lit(1i64))
+            }
+            ExpectationRule::AllowedValues { column: c, values } => column(c).is_not_null().and(
+                cast(column(c), DataType::Utf8)
+                    .in_list(values.iter().map(|v| lit(v.as_str())).collect(), true),
+            ),
+            ExpectationRule::Range {
+                column: c,
+                min,
+                max,
+            } => {
+                let value = || cast(column(c), DataType::Float64);
+                let out_of_range = match (min, max) {
+                    (Some(min), Some(max)) => value().lt(lit(*min)).or(value().gt(lit(*max))),
+                    (Some(min), None) => value().lt(lit(*min)),
+                    (None, Some(max)) => value().gt(lit(*max)),
+                    (None, None) => lit(false),
+                };
+                column(c).is_not_null().and(out_of_range)
+            }
+            ExpectationRule::Regex { column: c, pattern } => {
+                column(c).is_not_null().and(binary_expr(
+                    cast(column(c), DataType::Utf8),
+                    Operator::RegexNotMatch,
+                    lit(pattern.as_str()),
+                ))
+            }
+            ExpectationRule::RowCount { .. } => return None,
+        };
+
+        // Treat nulls as "no violation"
+        Some(when(pred, lit(true)).otherwise(lit(false)).unwrap())
+    }
+}
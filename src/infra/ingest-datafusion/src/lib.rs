@@ -10,11 +10,13 @@
 #![feature(error_generic_member_access)]
 #![feature(let_chains)]
 
+mod expectations;
 pub mod merge_strategies;
 pub mod readers;
 mod visitor;
 mod writer;
 
+pub use expectations::*;
 pub use kamu_core::ingest::*;
 pub use merge_strategies::*;
 pub use readers::*;
@@ -84,6 +84,10 @@ impl MergeStrategySnapshot {
     /// | 3      | +C | b    | 2500       | 1      |
     /// +--------+----+------+------------+--------+
     /// ```
+    ///
+    /// The result has the same schema as the ledger and is used as the merge
+    /// state that the writer persists between ingests (see
+    /// [`MergeStrategy::project_state`]).
     pub fn project(&self, ledger: DataFrame) -> Result<DataFrame, InternalError> {
         // TODO: PERF: Re-assess implementation as it may be sub-optimal
         let rank_col = "__rank";
@@ -393,6 +397,13 @@ impl MergeStrategy for MergeStrategySnapshot {
             ))
             .collect()
     }
+
+    fn project_state(&self, ledger: DataFrame) -> Result<Option<DataFrame>, MergeError> {
+        // Projection only retains the latest +A / +C record per primary key, so
+        // projecting the state combined with the new slice yields the same result
+        // as projecting the full ledger
+        Ok(Some(self.project(ledger)?))
+    }
 }
 
 /// Helps us capture backtraces as close to the point as possible
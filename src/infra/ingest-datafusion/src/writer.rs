@@ -14,7 +14,7 @@ use std::sync::Arc;
 
 use chrono::{DateTime, TimeZone, Utc};
 use datafusion::arrow::array::Array;
-use datafusion::arrow::datatypes::{DataType, Field, SchemaRef, TimeUnit};
+use datafusion::arrow::datatypes::{DataType, Field, Schema, SchemaRef, TimeUnit};
 use datafusion::common::DFSchema;
 use datafusion::config::{ParquetColumnOptions, ParquetOptions, TableParquetOptions};
 use datafusion::dataframe::DataFrameWriteOptions;
@@ -23,19 +23,42 @@ use datafusion::prelude::*;
 use internal_error::*;
 use kamu_core::ingest::*;
 use kamu_core::*;
+use kamu_datasets::{DatasetExpectation, ExpectationsCheckResult};
 use odf::{AsTypedBlock, DatasetVocabulary, MetadataEvent};
 use opendatafabric as odf;
 
+use crate::expectations::ExpectationsEvaluator;
 use crate::visitor::SourceEventVisitor;
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
 
+/// Parquet metadata key holding the last offset covered by the merge state
+const MERGE_STATE_OFFSET_KEY: &str = "kamu.merge_state.offset";
+
+/// Parquet metadata key holding the fingerprint of the merge strategy that
+/// produced the merge state
+const MERGE_STATE_STRATEGY_KEY: &str = "kamu.merge_state.strategy";
+
+/// Prefix of the info repository entries holding records excluded by
+/// quarantine expectations
+pub const QUARANTINE_FILE_PREFIX: &str = "quarantine-";
+
+fn quarantine_file_name(system_time: DateTime<Utc>) -> String {
+    format!(
+        "{QUARANTINE_FILE_PREFIX}{}.parquet",
+        system_time.format("%Y%m%dT%H%M%S%.3fZ")
+    )
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
 /// Implementation of the [`DataWriter`] interface using Apache `DataFusion`
 /// engine
 pub struct DataWriterDataFusion {
     dataset: Arc<dyn Dataset>,
     merge_strategy: Arc<dyn MergeStrategy>,
     block_ref: BlockRef,
+    expectations: Vec<DatasetExpectation>,
 
     // Mutable
     meta: DataWriterMetadataState,
@@ -72,6 +95,7 @@ impl DataWriterDataFusion {
         dataset: Arc<dyn Dataset>,
         merge_strategy: Arc<dyn MergeStrategy>,
         block_ref: BlockRef,
+        expectations: Vec<DatasetExpectation>,
         metadata_state: DataWriterMetadataState,
     ) -> Self {
         Self {
@@ -79,6 +103,7 @@ impl DataWriterDataFusion {
             dataset,
             merge_strategy,
             block_ref,
+            expectations,
             meta: metadata_state,
         }
     }
@@ -91,6 +116,10 @@ impl DataWriterDataFusion {
         self.meta.prev_source_state.as_ref()
     }
 
+    pub fn prev_schema(&self) -> Option<&SchemaRef> {
+        self.meta.schema.as_ref()
+    }
+
     pub fn vocab(&self) -> &odf::DatasetVocabulary {
         &self.meta.vocab
     }
@@ -217,6 +246,75 @@ impl DataWriterDataFusion {
         }
     }
 
+    /// Evaluates dataset expectations against the new slice, returning data
+    /// with quarantined rows excluded. Quarantined rows are written into a
+    /// staging file that will be persisted along with the commit.
+    async fn apply_expectations(
+        &self,
+        df: DataFrame,
+        quarantine_staging_path: PathBuf,
+    ) -> Result<
+        (
+            DataFrame,
+            Option<ExpectationsCheckResult>,
+            Option<OwnedFile>,
+        ),
+        StageDataError,
+    > {
+        if self.expectations.is_empty() {
+            return Ok((df, None, None));
+        }
+
+        let evaluation = ExpectationsEvaluator::new(&self.expectations)
+            .evaluate(df)
+            .await?;
+
+        let quarantine_file = if let Some(quarantined) = evaluation.quarantined
+            && evaluation.check.num_quarantined != 0
+        {
+            quarantined
+                .write_parquet(
+                    quarantine_staging_path.as_os_str().to_str().unwrap(),
+                    DataFrameWriteOptions::new().with_single_file_output(true),
+                    None,
+                )
+                .await
+                .int_err()?;
+
+            tracing::info!(
+                path = ?quarantine_staging_path,
+                num_quarantined = evaluation.check.num_quarantined,
+                "Staged quarantined records",
+            );
+
+            Some(OwnedFile::new(quarantine_staging_path))
+        } else {
+            None
+        };
+
+        Ok((evaluation.data, Some(evaluation.check), quarantine_file))
+    }
+
+    /// Stores quarantined records in the dataset's info repository so they
+    /// survive as long as the dataset itself
+    async fn persist_quarantine_file(
+        &self,
+        quarantine_file: OwnedFile,
+        system_time: DateTime<Utc>,
+    ) -> Result<(), InternalError> {
+        let name = quarantine_file_name(system_time);
+        let data = tokio::fs::read(quarantine_file.as_path()).await.int_err()?;
+
+        self.dataset
+            .as_info_repo()
+            .set(&name, &data)
+            .await
+            .int_err()?;
+
+        tracing::info!(%name, "Persisted quarantined records");
+        Ok(())
+    }
+
     // TODO: PERF: This will not scale well as number of blocks grows
     async fn get_all_previous_data(
         &self,
@@ -256,6 +354,278 @@ impl DataWriterDataFusion {
         Ok(Some(df))
     }
 
+    /// Returns the data that the merge strategy will be applied against: the
+    /// merge state from the last checkpoint when available, falling back to
+    /// the state projected from the full ledger, or the ledger itself if the
+    /// strategy does not support state projection.
+    ///
+    /// The state is held in memory, as it's read by both the merge and the
+    /// projection of the next checkpoint.
+    async fn get_previous_data_or_state(&self) -> Result<Option<DataFrame>, StageDataError> {
+        let state = if let Some(state) = self.get_merge_state().await? {
+            state
+        } else {
+            let Some(ledger) = self.get_all_previous_data(&self.meta.data_slices).await? else {
+                return Ok(None);
+            };
+
+            match self.merge_strategy.project_state(ledger.clone())? {
+                Some(state) => state,
+                None => return Ok(Some(ledger)),
+            }
+        };
+
+        Ok(Some(state.cache().await.int_err()?))
+    }
+
+    /// Turns previously written records matching the filter that are still
+    /// live (were not retracted or corrected since) into retractions with the
+    /// same columns as the merged new data
+    async fn get_retractions(
+        &self,
+        filter: Expr,
+        new_schema: &DFSchema,
+    ) -> Result<Option<DataFrame>, InternalError> {
+        use datafusion::logical_expr as expr;
+        use datafusion::logical_expr::expr::WindowFunction;
+
+        type Op = odf::OperationType;
+
+        const ORDINAL_COLUMN: &str = "__ordinal__";
+
+        let Some(ledger) = self.get_all_previous_data(&self.meta.data_slices).await? else {
+            return Ok(None);
+        };
+
+        // The ledger is scanned only once: the filter is pushed down to the Parquet
+        // reader, which skips row groups by their statistics, and the few matching
+        // records are held in memory
+        let matching = ledger.filter(filter).int_err()?.cache().await.int_err()?;
+
+        let op_column = &self.meta.vocab.operation_type_column;
+
+        let data_columns: Vec<_> = new_schema
+            .fields()
+            .iter()
+            .filter(|f| f.name() != op_column)
+            .map(|f| col(Column::from_name(f.name())))
+            .collect();
+
+        // Every retraction or correction cancels out only one addition of an equal
+        // record, so equal records are numbered to compute the difference of
+        // multisets rather than sets
+        let numbered = |ops: [Op; 2]| -> Result<DataFrame, InternalError> {
+            let ordinal = Expr::WindowFunction(WindowFunction {
+                fun: expr::WindowFunctionDefinition::BuiltInWindowFunction(
+                    expr::BuiltInWindowFunction::RowNumber,
+                ),
+                args: vec![],
+                partition_by: data_columns.clone(),
+                order_by: vec![
+                    col(Column::from_name(&self.meta.vocab.offset_column)).sort(true, false)
+                ],
+                window_frame: expr::WindowFrame::new(Some(false)),
+                null_treatment: None,
+            })
+            .alias(ORDINAL_COLUMN);
+
+            matching
+                .clone()
+                .filter(
+                    col(Column::from_name(op_column))
+                        .in_list(ops.map(|op| lit(op as i32)).to_vec(), false),
+                )
+                .int_err()?
+                .select(
+                    data_columns
+                        .iter()
+                        .cloned()
+                        .chain(std::iter::once(ordinal))
+                        .collect(),
+                )
+                .int_err()
+        };
+
+        let added = numbered([Op::Append, Op::CorrectTo])?;
+        let removed = numbered([Op::Retract, Op::CorrectFrom])?;
+
+        let retractions = added
+            .except(removed)
+            .int_err()?
+            .select(
+                new_schema
+                    .fields()
+                    .iter()
+                    .map(|f| {
+                        if f.name() == op_column {
+                            lit(Op::Retract as i32).alias(op_column)
+                        } else {
+                            col(Column::from_name(f.name()))
+                        }
+                    })
+                    .collect::<Vec<_>>(),
+            )
+            .int_err()?;
+
+        Ok(Some(retractions))
+    }
+
+    /// Reads the merge state from the last checkpoint, provided it was produced
+    /// by the current merge strategy and covers all previous data
+    async fn get_merge_state(&self) -> Result<Option<DataFrame>, InternalError> {
+        let Some(checkpoint) = &self.meta.prev_checkpoint else {
+            return Ok(None);
+        };
+
+        let url = self
+            .dataset
+            .as_checkpoint_repo()
+            .get_internal_url(checkpoint)
+            .await
+            .to_string();
+
+        // Schema inference only reads the file footer
+        let df = self
+            .ctx
+            .read_parquet(
+                url.clone(),
+                ParquetReadOptions {
+                    schema: None,
+                    file_extension: "",
+                    file_sort_order: Vec::new(),
+                    table_partition_cols: Vec::new(),
+                    parquet_pruning: None,
+                    skip_metadata: Some(false),
+                },
+            )
+            .await
+            .int_err()?;
+
+        let metadata = df.schema().metadata();
+        let expected_offset = self.meta.prev_offset.map(|o| o.to_string());
+
+        if metadata.get(MERGE_STATE_STRATEGY_KEY) != Some(&self.merge_state_fingerprint()?)
+            || metadata.get(MERGE_STATE_OFFSET_KEY) != expected_offset.as_ref()
+        {
+            tracing::info!(
+                %checkpoint,
+                "Checkpoint does not match the current merge strategy or offset - will use the \
+                 full ledger",
+            );
+            return Ok(None);
+        }
+
+        tracing::info!(%checkpoint, "Using merge state from the checkpoint");
+
+        // Read again with the metadata stripped to avoid it leaking into the output
+        let schema = Schema::new(df.schema().fields().clone());
+
+        let df = self
+            .ctx
+            .read_parquet(
+                url,
+                ParquetReadOptions {
+                    schema: Some(&schema),
+                    file_extension: "",
+                    file_sort_order: Vec::new(),
+                    table_partition_cols: Vec::new(),
+                    parquet_pruning: None,
+                    skip_metadata: None,
+                },
+            )
+            .await
+            .int_err()?;
+
+        Ok(Some(df))
+    }
+
+    /// Projects the merge state after the new slice is appended to previous
+    /// data and writes it into a checkpoint file. Returns `None` if merge
+    /// strategy does not support state projection.
+    async fn write_merge_state(
+        &self,
+        path: PathBuf,
+        prev: Option<DataFrame>,
+        data_file: &OwnedFile,
+        new_offset: u64,
+    ) -> Result<Option<OwnedFile>, StageDataError> {
+        let new_slice = self
+            .ctx
+            .read_parquet(
+                data_file.as_path().to_str().unwrap(),
+                ParquetReadOptions {
+                    schema: None,
+                    file_extension: data_file
+                        .as_path()
+                        .extension()
+                        .unwrap_or_default()
+                        .to_str()
+                        .unwrap(),
+                    file_sort_order: Vec::new(),
+                    table_partition_cols: Vec::new(),
+                    parquet_pruning: None,
+                    skip_metadata: None,
+                },
+            )
+            .await
+            .int_err()?;
+
+        let ledger = match prev {
+            Some(prev) => prev.union(new_slice).int_err()?,
+            None => new_slice,
+        };
+
+        let Some(state) = self.merge_strategy.project_state(ledger)? else {
+            return Ok(None);
+        };
+
+        let state = state
+            .sort(vec![
+                col(Column::from_name(&self.meta.vocab.offset_column)).sort(true, true)
+            ])
+            .int_err()?;
+
+        let mut write_properties = self.get_write_properties();
+        write_properties.key_value_metadata = HashMap::from([
+            (
+                MERGE_STATE_OFFSET_KEY.to_string(),
+                Some(new_offset.to_string()),
+            ),
+            (
+                MERGE_STATE_STRATEGY_KEY.to_string(),
+                Some(self.merge_state_fingerprint()?),
+            ),
+        ]);
+
+        state
+            .write_parquet(
+                path.as_os_str().to_str().unwrap(),
+                DataFrameWriteOptions::new().with_single_file_output(true),
+                Some(write_properties),
+            )
+            .await
+            .int_err()?;
+
+        tracing::info!(?path, new_offset, "Produced merge state checkpoint");
+
+        Ok(Some(OwnedFile::new(path)))
+    }
+
+    /// Identifies the merge strategy configuration so that merge state is not
+    /// reused after the strategy of the source changes
+    fn merge_state_fingerprint(&self) -> Result<String, InternalError> {
+        // Uses the ODF serialized form, which unlike `Debug` output is stable
+        // across releases
+        let mut buf = Vec::new();
+        odf::serde::yaml::MergeStrategyDef::serialize(
+            &self.meta.merge_strategy,
+            &mut serde_json::Serializer::new(&mut buf),
+        )
+        .int_err()?;
+
+        Ok(odf::Multihash::from_digest_sha3_256(&buf).to_string())
+    }
+
     fn with_system_columns(
         &self,
         df: DataFrame,
@@ -617,6 +987,13 @@ impl DataWriter for DataWriterDataFusion {
             add_data: Some(add_data),
             new_schema: None,
             data_file: None,
+            checkpoint: self
+                .meta
+                .prev_checkpoint
+                .clone()
+                .map(CheckpointRef::Existed),
+            expectations_check: None,
+            quarantine_file: None,
         };
 
         let commit = self.commit(staged).await?;
@@ -629,20 +1006,50 @@ impl DataWriter for DataWriterDataFusion {
         new_data: Option<DataFrame>,
         opts: WriteDataOpts,
     ) -> Result<StageDataResult, StageDataError> {
-        let (add_data, new_schema, data_file) = if let Some(new_data) = new_data {
+        let mut expectations_check = None;
+        let mut quarantine_file = None;
+
+        let (add_data, new_schema, data_file, checkpoint) = if let Some(new_data) = new_data {
             self.validate_input(&new_data)?;
 
             // Normalize timestamps
             let df = self.normalize_raw_result(new_data)?;
 
+            // Check data quality expectations
+            let (df, check, quarantined) = self
+                .apply_expectations(df, opts.data_staging_path.with_extension("quarantine"))
+                .await?;
+            expectations_check = check;
+            quarantine_file = quarantined;
+
             // Merge step
-            // TODO: PERF: We could likely benefit from checkpointing here
-            let prev = self.get_all_previous_data(&self.meta.data_slices).await?;
+            let prev = self.get_previous_data_or_state().await?;
 
             // Populate event time with nulls if missing, using matching type to prev data
             let df = self.ensure_event_time_column(df, prev.as_ref().map(DataFrame::schema))?;
 
-            let df = self.merge_strategy.merge(prev, df)?;
+            // Records that are about to be retracted should not affect the merge, e.g. to
+            // not deduplicate the new records against them
+            let prev_live = match (&prev, &opts.retract_previous) {
+                (Some(prev), Some(filter)) => Some(
+                    prev.clone()
+                        .filter(filter.clone().is_not_true())
+                        .int_err()?,
+                ),
+                _ => prev.clone(),
+            };
+
+            let df = self.merge_strategy.merge(prev_live, df)?;
+
+            // Retract previously written records that the source reported as invalid
+            let df = if let Some(filter) = opts.retract_previous {
+                match self.get_retractions(filter, df.schema()).await? {
+                    Some(retractions) => retractions.union(df).int_err()?,
+                    None => df,
+                }
+            } else {
+                df
+            };
 
             tracing::debug!(
                 schema = ?df.schema(),
@@ -667,6 +1074,7 @@ impl DataWriter for DataWriterDataFusion {
             }
 
             // Write output
+            let checkpoint_staging_path = opts.data_staging_path.with_extension("checkpoint");
             let data_file = self.write_output(opts.data_staging_path, df).await?;
 
             // Prepare commit info
@@ -676,10 +1084,10 @@ impl DataWriter for DataWriterDataFusion {
             let prev_watermark = self.meta.prev_watermark;
 
             if data_file.is_none() {
-                // Empty result - carry watermark and propagate source state
+                // Empty result - carry watermark, checkpoint and propagate source state
                 (
                     AddDataParams {
-                        prev_checkpoint,
+                        prev_checkpoint: prev_checkpoint.clone(),
                         prev_offset,
                         new_offset_interval: None,
                         new_watermark: opts.new_watermark.or(prev_watermark),
@@ -687,6 +1095,7 @@ impl DataWriter for DataWriterDataFusion {
                     },
                     Some(new_schema),
                     None,
+                    prev_checkpoint.map(CheckpointRef::Existed),
                 )
             } else {
                 let (new_offset_interval, new_watermark_from_data) = self
@@ -696,6 +1105,17 @@ impl DataWriter for DataWriterDataFusion {
                     )
                     .await?;
 
+                // Persist the state to make the next merge independent of the ledger length
+                let checkpoint = self
+                    .write_merge_state(
+                        checkpoint_staging_path,
+                        prev,
+                        data_file.as_ref().unwrap(),
+                        new_offset_interval.end,
+                    )
+                    .await?
+                    .map(CheckpointRef::New);
+
                 (
                     AddDataParams {
                         prev_checkpoint,
@@ -706,6 +1126,7 @@ impl DataWriter for DataWriterDataFusion {
                     },
                     Some(new_schema),
                     data_file,
+                    checkpoint,
                 )
             }
         } else {
@@ -718,7 +1139,13 @@ impl DataWriter for DataWriterDataFusion {
                 new_source_state: opts.new_source_state,
             };
 
-            (add_data, None, None)
+            let checkpoint = self
+                .meta
+                .prev_checkpoint
+                .clone()
+                .map(CheckpointRef::Existed);
+
+            (add_data, None, None, checkpoint)
         };
 
         // Do we need to commit `SetDataSchema` event?
@@ -744,6 +1171,9 @@ impl DataWriter for DataWriterDataFusion {
                 add_data,
                 new_schema,
                 data_file,
+                checkpoint,
+                expectations_check,
+                quarantine_file,
             })
         } else {
             Err(EmptyCommitError {}.into())
@@ -755,6 +1185,8 @@ impl DataWriter for DataWriterDataFusion {
         assert!(staged.new_schema.is_some() || staged.add_data.is_some());
 
         let old_head = self.meta.head.clone();
+        let expectations_check = staged.expectations_check;
+        let quarantine_file = staged.quarantine_file;
 
         // Commit `SetDataSchema` event
         if let Some(new_schema) = staged.new_schema {
@@ -785,7 +1217,7 @@ impl DataWriter for DataWriterDataFusion {
                 .commit_add_data(
                     add_data,
                     staged.data_file,
-                    None,
+                    staged.checkpoint,
                     CommitOpts {
                         block_ref: &self.block_ref,
                         system_time: Some(staged.system_time),
@@ -829,10 +1261,16 @@ impl DataWriter for DataWriterDataFusion {
             None
         };
 
+        if let Some(quarantine_file) = quarantine_file {
+            self.persist_quarantine_file(quarantine_file, staged.system_time)
+                .await?;
+        }
+
         Ok(WriteDataResult {
             old_head,
             new_head: self.meta.head.clone(),
             add_data_block,
+            expectations_check,
         })
     }
 }
@@ -845,6 +1283,7 @@ pub struct DataWriterDataFusionBuilder {
     dataset: Arc<dyn Dataset>,
     ctx: SessionContext,
     block_ref: BlockRef,
+    expectations: Vec<DatasetExpectation>,
     metadata_state: Option<DataWriterMetadataState>,
 }
 
@@ -854,6 +1293,7 @@ impl DataWriterDataFusionBuilder {
             dataset,
             ctx,
             block_ref: BlockRef::Head,
+            expectations: Vec::new(),
             metadata_state: None,
         }
     }
@@ -862,6 +1302,14 @@ impl DataWriterDataFusionBuilder {
         Self { block_ref, ..self }
     }
 
+    /// Data quality expectations to evaluate against every new slice
+    pub fn with_expectations(self, expectations: Vec<DatasetExpectation>) -> Self {
+        Self {
+            expectations,
+            ..self
+        }
+    }
+
     pub fn metadata_state(&self) -> Option<&DataWriterMetadataState> {
         self.metadata_state.as_ref()
     }
@@ -999,6 +1447,7 @@ impl DataWriterDataFusionBuilder {
             self.dataset,
             merge_strategy,
             self.block_ref,
+            self.expectations,
             metadata_state,
         )
     }
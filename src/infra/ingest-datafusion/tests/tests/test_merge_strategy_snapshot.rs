@@ -379,6 +379,54 @@ async fn test_snapshot_merge_mix_of_changes() {
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
 
+#[test_group::group(engine, ingest, datafusion)]
+#[test_log::test(tokio::test)]
+async fn test_snapshot_merge_against_projected_state() {
+    let ctx = SessionContext::new();
+    let strat = MergeStrategySnapshot::new(
+        odf::DatasetVocabulary {
+            event_time_column: "year".to_string(),
+            ..Default::default()
+        },
+        odf::MergeStrategySnapshot {
+            primary_key: vec!["city".to_string()],
+            compare_columns: None,
+        },
+    );
+
+    let ledger = || {
+        make_ledger(
+            &ctx,
+            [
+                (Op::Append, 2020, "vancouver", 1),
+                (Op::Append, 2020, "seattle", 2),
+                (Op::CorrectFrom, 2020, "vancouver", 1),
+                (Op::CorrectTo, 2020, "vancouver", 3),
+                (Op::Append, 2020, "kyiv", 4),
+                (Op::Retract, 2020, "seattle", 0),
+            ],
+        )
+    };
+    let input = || make_input(&ctx, [("vancouver", 3), ("kyiv", 5), ("odessa", 6)]);
+
+    let state = strat.project_state(ledger()).unwrap().unwrap();
+
+    let expected = strat
+        .merge(Some(ledger()), input())
+        .unwrap()
+        .sort(strat.sort_order())
+        .unwrap();
+    let actual = strat
+        .merge(Some(state), input())
+        .unwrap()
+        .sort(strat.sort_order())
+        .unwrap();
+
+    assert_dfs_equivalent(expected, actual, false, true, true).await;
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
 #[test_group::group(engine, ingest, datafusion)]
 #[test_log::test(tokio::test)]
 async fn test_snapshot_merge_invalid_pk() {
@@ -0,0 +1,184 @@
+// Copyright Kamu Data, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+use std::collections::BTreeMap;
+use std::sync::{Arc, Mutex};
+
+use database_common::DatabasePaginationOpts;
+use dill::{component, interface, scope, Singleton};
+use internal_error::InternalError;
+
+use crate::domain::*;
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+pub struct InMemoryOutboxMessageDeadLetterRepository {
+    state: Arc<Mutex<State>>,
+}
+
+type DeadLetterKey = (OutboxMessageID, String, String);
+
+#[derive(Default)]
+struct State {
+    dead_letters: BTreeMap<DeadLetterKey, OutboxMessageDeadLetter>,
+    retry_states: BTreeMap<(String, String), OutboxConsumerRetryState>,
+}
+
+#[component(pub)]
+#[scope(Singleton)]
+#[interface(dyn OutboxMessageDeadLetterRepository)]
+impl InMemoryOutboxMessageDeadLetterRepository {
+    pub fn new() -> Self {
+        Self {
+            state: Arc::new(Mutex::new(State::default())),
+        }
+    }
+
+    fn make_key(
+        &self,
+        producer_name: &str,
+        consumer_name: &str,
+        message_id: OutboxMessageID,
+    ) -> DeadLetterKey {
+        (
+            message_id,
+            producer_name.to_string(),
+            consumer_name.to_string(),
+        )
+    }
+
+    fn not_found_error(
+        &self,
+        producer_name: &str,
+        consumer_name: &str,
+        message_id: OutboxMessageID,
+    ) -> DeadLetterNotFoundError {
+        DeadLetterNotFoundError {
+            producer_name: producer_name.to_string(),
+            consumer_name: consumer_name.to_string(),
+            message_id,
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl OutboxMessageDeadLetterRepository for InMemoryOutboxMessageDeadLetterRepository {
+    async fn save_dead_letter(
+        &self,
+        dead_letter: &OutboxMessageDeadLetter,
+    ) -> Result<(), InternalError> {
+        let key = self.make_key(
+            &dead_letter.producer_name,
+            &dead_letter.consumer_name,
+            dead_letter.message_id,
+        );
+
+        let mut guard = self.state.lock().unwrap();
+        guard.dead_letters.insert(key, dead_letter.clone());
+        Ok(())
+    }
+
+    async fn get_dead_letter(
+        &self,
+        producer_name: &str,
+        consumer_name: &str,
+        message_id: OutboxMessageID,
+    ) -> Result<OutboxMessageDeadLetter, GetDeadLetterError> {
+        let key = self.make_key(producer_name, consumer_name, message_id);
+
+        let guard = self.state.lock().unwrap();
+        guard.dead_letters.get(&key).cloned().ok_or_else(|| {
+            GetDeadLetterError::NotFound(self.not_found_error(
+                producer_name,
+                consumer_name,
+                message_id,
+            ))
+        })
+    }
+
+    async fn list_dead_letters(
+        &self,
+        pagination: &DatabasePaginationOpts,
+    ) -> Result<Vec<OutboxMessageDeadLetter>, InternalError> {
+        let guard = self.state.lock().unwrap();
+        Ok(guard
+            .dead_letters
+            .values()
+            .skip(usize::try_from(pagination.offset).unwrap())
+            .take(usize::try_from(pagination.limit).unwrap())
+            .cloned()
+            .collect())
+    }
+
+    async fn count_dead_letters(&self) -> Result<usize, InternalError> {
+        let guard = self.state.lock().unwrap();
+        Ok(guard.dead_letters.len())
+    }
+
+    async fn delete_dead_letter(
+        &self,
+        producer_name: &str,
+        consumer_name: &str,
+        message_id: OutboxMessageID,
+    ) -> Result<(), DeleteDeadLetterError> {
+        let key = self.make_key(producer_name, consumer_name, message_id);
+
+        let mut guard = self.state.lock().unwrap();
+        if guard.dead_letters.remove(&key).is_some() {
+            Ok(())
+        } else {
+            Err(DeleteDeadLetterError::NotFound(self.not_found_error(
+                producer_name,
+                consumer_name,
+                message_id,
+            )))
+        }
+    }
+
+    async fn list_retry_states(
+        &self,
+        producer_name: &str,
+    ) -> Result<Vec<OutboxConsumerRetryState>, InternalError> {
+        let guard = self.state.lock().unwrap();
+        Ok(guard
+            .retry_states
+            .values()
+            .filter(|retry_state| retry_state.producer_name == producer_name)
+            .cloned()
+            .collect())
+    }
+
+    async fn save_retry_state(
+        &self,
+        retry_state: &OutboxConsumerRetryState,
+    ) -> Result<(), InternalError> {
+        let key = (
+            retry_state.producer_name.clone(),
+            retry_state.consumer_name.clone(),
+        );
+
+        let mut guard = self.state.lock().unwrap();
+        guard.retry_states.insert(key, retry_state.clone());
+        Ok(())
+    }
+
+    async fn delete_retry_state(
+        &self,
+        producer_name: &str,
+        consumer_name: &str,
+    ) -> Result<(), InternalError> {
+        let key = (producer_name.to_string(), consumer_name.to_string());
+
+        let mut guard = self.state.lock().unwrap();
+        guard.retry_states.remove(&key);
+        Ok(())
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -118,6 +118,19 @@ impl OutboxMessageRepository for InMemoryOutboxMessageRepository {
             .map(|e| (e.0.clone(), *(e.1)))
             .collect())
     }
+
+    async fn count_producer_messages_above(
+        &self,
+        producer_name: &str,
+        above_id: OutboxMessageID,
+    ) -> Result<usize, InternalError> {
+        let guard = self.state.lock().unwrap();
+        Ok(guard
+            .messages
+            .range((Bound::Excluded(above_id), Bound::Unbounded))
+            .filter(|(_, message)| message.producer_name == producer_name)
+            .count())
+    }
 }
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
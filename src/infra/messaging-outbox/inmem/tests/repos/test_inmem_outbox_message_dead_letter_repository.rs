@@ -0,0 +1,69 @@
+// Copyright Kamu Data, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use
+// database_common_macros::database_transactional_test;
+use dill::{Catalog, CatalogBuilder};
+use kamu_messaging_outbox_inmem::InMemoryOutboxMessageDeadLetterRepository;
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+database_transactional_test!(
+    storage = inmem,
+    fixture = kamu_messaging_outbox_repo_tests::test_no_dead_letters_initially,
+    harness = InMemoryOutboxMessageDeadLetterRepositoryHarness
+);
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+database_transactional_test!(
+    storage = inmem,
+    fixture = kamu_messaging_outbox_repo_tests::test_save_and_get_dead_letter,
+    harness = InMemoryOutboxMessageDeadLetterRepositoryHarness
+);
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+database_transactional_test!(
+    storage = inmem,
+    fixture = kamu_messaging_outbox_repo_tests::test_list_dead_letters_paged,
+    harness = InMemoryOutboxMessageDeadLetterRepositoryHarness
+);
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+database_transactional_test!(
+    storage = inmem,
+    fixture = kamu_messaging_outbox_repo_tests::test_delete_dead_letter,
+    harness = InMemoryOutboxMessageDeadLetterRepositoryHarness
+);
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+database_transactional_test!(
+    storage = inmem,
+    fixture = kamu_messaging_outbox_repo_tests::test_save_and_delete_retry_states,
+    harness = InMemoryOutboxMessageDeadLetterRepositoryHarness
+);
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+struct InMemoryOutboxMessageDeadLetterRepositoryHarness {
+    catalog: Catalog,
+}
+
+impl InMemoryOutboxMessageDeadLetterRepositoryHarness {
+    pub fn new() -> Self {
+        let mut catalog_builder = CatalogBuilder::new();
+        catalog_builder.add::<InMemoryOutboxMessageDeadLetterRepository>();
+
+        Self {
+            catalog: catalog_builder.build(),
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -45,6 +45,14 @@ database_transactional_test!(
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
 
+database_transactional_test!(
+    storage = inmem,
+    fixture = kamu_messaging_outbox_repo_tests::test_count_messages_above,
+    harness = InMemoryOutboxMessageRepositoryHarness
+);
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
 struct InMemoryOutboxMessageRepositoryHarness {
     catalog: Catalog,
 }
@@ -0,0 +1,294 @@
+// Copyright Kamu Data, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+use database_common::{DatabasePaginationOpts, TransactionRef, TransactionRefT};
+use dill::{component, interface};
+use internal_error::{ErrorIntoInternal, InternalError, ResultIntoInternal};
+
+use crate::domain::*;
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+pub struct PostgresOutboxMessageDeadLetterRepository {
+    transaction: TransactionRefT<sqlx::Postgres>,
+}
+
+#[component(pub)]
+#[interface(dyn OutboxMessageDeadLetterRepository)]
+impl PostgresOutboxMessageDeadLetterRepository {
+    pub fn new(transaction: TransactionRef) -> Self {
+        Self {
+            transaction: transaction.into(),
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl OutboxMessageDeadLetterRepository for PostgresOutboxMessageDeadLetterRepository {
+    async fn save_dead_letter(
+        &self,
+        dead_letter: &OutboxMessageDeadLetter,
+    ) -> Result<(), InternalError> {
+        let mut tr = self.transaction.lock().await;
+
+        let connection_mut = tr.connection_mut().await?;
+
+        sqlx::query!(
+            r#"
+                INSERT INTO outbox_message_dead_letters (producer_name, consumer_name, message_id, content_json,
+                    attempts, last_error, dead_lettered_at)
+                    VALUES ($1, $2, $3, $4, $5, $6, $7)
+                    ON CONFLICT (producer_name, consumer_name, message_id)
+                    DO UPDATE SET attempts = $5, last_error = $6, dead_lettered_at = $7
+            "#,
+            dead_letter.producer_name,
+            dead_letter.consumer_name,
+            dead_letter.message_id.into_inner(),
+            &dead_letter.content_json,
+            i32::try_from(dead_letter.attempts).unwrap(),
+            dead_letter.last_error,
+            dead_letter.dead_lettered_at,
+        )
+        .execute(connection_mut)
+        .await
+        .int_err()?;
+
+        Ok(())
+    }
+
+    async fn get_dead_letter(
+        &self,
+        producer_name: &str,
+        consumer_name: &str,
+        message_id: OutboxMessageID,
+    ) -> Result<OutboxMessageDeadLetter, GetDeadLetterError> {
+        let mut tr = self.transaction.lock().await;
+
+        let connection_mut = tr
+            .connection_mut()
+            .await
+            .map_err(GetDeadLetterError::Internal)?;
+
+        let maybe_record = sqlx::query!(
+            r#"
+                SELECT
+                    producer_name, consumer_name, message_id, content_json, attempts, last_error, dead_lettered_at
+                FROM outbox_message_dead_letters
+                WHERE producer_name = $1 AND consumer_name = $2 AND message_id = $3
+            "#,
+            producer_name,
+            consumer_name,
+            message_id.into_inner(),
+        )
+        .fetch_optional(connection_mut)
+        .await
+        .int_err()
+        .map_err(GetDeadLetterError::Internal)?;
+
+        let Some(record) = maybe_record else {
+            return Err(GetDeadLetterError::NotFound(DeadLetterNotFoundError {
+                producer_name: producer_name.to_string(),
+                consumer_name: consumer_name.to_string(),
+                message_id,
+            }));
+        };
+
+        Ok(OutboxMessageDeadLetter {
+            producer_name: record.producer_name,
+            consumer_name: record.consumer_name,
+            message_id: OutboxMessageID::new(record.message_id),
+            content_json: record.content_json,
+            attempts: u32::try_from(record.attempts).unwrap(),
+            last_error: record.last_error,
+            dead_lettered_at: record.dead_lettered_at,
+        })
+    }
+
+    async fn list_dead_letters(
+        &self,
+        pagination: &DatabasePaginationOpts,
+    ) -> Result<Vec<OutboxMessageDeadLetter>, InternalError> {
+        let mut tr = self.transaction.lock().await;
+
+        let connection_mut = tr.connection_mut().await?;
+
+        let records = sqlx::query!(
+            r#"
+                SELECT
+                    producer_name, consumer_name, message_id, content_json, attempts, last_error, dead_lettered_at
+                FROM outbox_message_dead_letters
+                ORDER BY message_id, producer_name, consumer_name
+                LIMIT $1 OFFSET $2
+            "#,
+            pagination.limit,
+            pagination.offset,
+        )
+        .fetch_all(connection_mut)
+        .await
+        .map_err(ErrorIntoInternal::int_err)?;
+
+        Ok(records
+            .into_iter()
+            .map(|record| OutboxMessageDeadLetter {
+                producer_name: record.producer_name,
+                consumer_name: record.consumer_name,
+                message_id: OutboxMessageID::new(record.message_id),
+                content_json: record.content_json,
+                attempts: u32::try_from(record.attempts).unwrap(),
+                last_error: record.last_error,
+                dead_lettered_at: record.dead_lettered_at,
+            })
+            .collect())
+    }
+
+    async fn count_dead_letters(&self) -> Result<usize, InternalError> {
+        let mut tr = self.transaction.lock().await;
+
+        let connection_mut = tr.connection_mut().await?;
+
+        let dead_letters_count = sqlx::query_scalar!(
+            r#"
+                SELECT
+                    count(*)
+                FROM outbox_message_dead_letters
+            "#,
+        )
+        .fetch_one(connection_mut)
+        .await
+        .int_err()?;
+
+        Ok(usize::try_from(dead_letters_count.unwrap_or(0)).unwrap())
+    }
+
+    async fn delete_dead_letter(
+        &self,
+        producer_name: &str,
+        consumer_name: &str,
+        message_id: OutboxMessageID,
+    ) -> Result<(), DeleteDeadLetterError> {
+        let mut tr = self.transaction.lock().await;
+
+        let connection_mut = tr
+            .connection_mut()
+            .await
+            .map_err(DeleteDeadLetterError::Internal)?;
+
+        let res = sqlx::query!(
+            r#"
+                DELETE FROM outbox_message_dead_letters
+                    WHERE producer_name = $1 AND consumer_name = $2 AND message_id = $3
+            "#,
+            producer_name,
+            consumer_name,
+            message_id.into_inner(),
+        )
+        .execute(connection_mut)
+        .await
+        .int_err()
+        .map_err(DeleteDeadLetterError::Internal)?;
+
+        if res.rows_affected() == 0 {
+            return Err(DeleteDeadLetterError::NotFound(DeadLetterNotFoundError {
+                producer_name: producer_name.to_string(),
+                consumer_name: consumer_name.to_string(),
+                message_id,
+            }));
+        }
+
+        Ok(())
+    }
+
+    async fn list_retry_states(
+        &self,
+        producer_name: &str,
+    ) -> Result<Vec<OutboxConsumerRetryState>, InternalError> {
+        let mut tr = self.transaction.lock().await;
+
+        let connection_mut = tr.connection_mut().await?;
+
+        let records = sqlx::query!(
+            r#"
+                SELECT
+                    producer_name, consumer_name, message_id, attempts, next_attempt_at
+                FROM outbox_consumer_retries
+                WHERE producer_name = $1
+            "#,
+            producer_name,
+        )
+        .fetch_all(connection_mut)
+        .await
+        .int_err()?;
+
+        Ok(records
+            .into_iter()
+            .map(|record| OutboxConsumerRetryState {
+                producer_name: record.producer_name,
+                consumer_name: record.consumer_name,
+                message_id: OutboxMessageID::new(record.message_id),
+                attempts: u32::try_from(record.attempts).unwrap(),
+                next_attempt_at: record.next_attempt_at,
+            })
+            .collect())
+    }
+
+    async fn save_retry_state(
+        &self,
+        retry_state: &OutboxConsumerRetryState,
+    ) -> Result<(), InternalError> {
+        let mut tr = self.transaction.lock().await;
+
+        let connection_mut = tr.connection_mut().await?;
+
+        sqlx::query!(
+            r#"
+                INSERT INTO outbox_consumer_retries (producer_name, consumer_name, message_id, attempts,
+                    next_attempt_at)
+                    VALUES ($1, $2, $3, $4, $5)
+                    ON CONFLICT (producer_name, consumer_name)
+                    DO UPDATE SET message_id = $3, attempts = $4, next_attempt_at = $5
+            "#,
+            retry_state.producer_name,
+            retry_state.consumer_name,
+            retry_state.message_id.into_inner(),
+            i32::try_from(retry_state.attempts).unwrap(),
+            retry_state.next_attempt_at,
+        )
+        .execute(connection_mut)
+        .await
+        .int_err()?;
+
+        Ok(())
+    }
+
+    async fn delete_retry_state(
+        &self,
+        producer_name: &str,
+        consumer_name: &str,
+    ) -> Result<(), InternalError> {
+        let mut tr = self.transaction.lock().await;
+
+        let connection_mut = tr.connection_mut().await?;
+
+        sqlx::query!(
+            r#"
+                DELETE FROM outbox_consumer_retries
+                    WHERE producer_name = $1 AND consumer_name = $2
+            "#,
+            producer_name,
+            consumer_name,
+        )
+        .execute(connection_mut)
+        .await
+        .int_err()?;
+
+        Ok(())
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
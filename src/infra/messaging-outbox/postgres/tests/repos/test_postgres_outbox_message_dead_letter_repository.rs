@@ -0,0 +1,72 @@
+// Copyright Kamu Data, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use database_common::PostgresTransactionManager;
+use database_common_macros::database_transactional_test;
+use dill::{Catalog, CatalogBuilder};
+use kamu_messaging_outbox_postgres::PostgresOutboxMessageDeadLetterRepository;
+use sqlx::PgPool;
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+database_transactional_test!(
+    storage = postgres,
+    fixture = kamu_messaging_outbox_repo_tests::test_no_dead_letters_initially,
+    harness = PostgresOutboxMessageDeadLetterRepositoryHarness
+);
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+database_transactional_test!(
+    storage = postgres,
+    fixture = kamu_messaging_outbox_repo_tests::test_save_and_get_dead_letter,
+    harness = PostgresOutboxMessageDeadLetterRepositoryHarness
+);
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+database_transactional_test!(
+    storage = postgres,
+    fixture = kamu_messaging_outbox_repo_tests::test_list_dead_letters_paged,
+    harness = PostgresOutboxMessageDeadLetterRepositoryHarness
+);
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+database_transactional_test!(
+    storage = postgres,
+    fixture = kamu_messaging_outbox_repo_tests::test_delete_dead_letter,
+    harness = PostgresOutboxMessageDeadLetterRepositoryHarness
+);
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+database_transactional_test!(
+    storage = postgres,
+    fixture = kamu_messaging_outbox_repo_tests::test_save_and_delete_retry_states,
+    harness = PostgresOutboxMessageDeadLetterRepositoryHarness
+);
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+struct PostgresOutboxMessageDeadLetterRepositoryHarness {
+    catalog: Catalog,
+}
+
+impl PostgresOutboxMessageDeadLetterRepositoryHarness {
+    pub fn new(pg_pool: PgPool) -> Self {
+        let mut catalog_builder = CatalogBuilder::new();
+        catalog_builder.add_value(pg_pool);
+        catalog_builder.add::<PostgresTransactionManager>();
+        catalog_builder.add::<PostgresOutboxMessageDeadLetterRepository>();
+
+        Self {
+            catalog: catalog_builder.build(),
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -47,6 +47,14 @@ database_transactional_test!(
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
 
+database_transactional_test!(
+    storage = postgres,
+    fixture = kamu_messaging_outbox_repo_tests::test_count_messages_above,
+    harness = PostgresOutboxMessageRepositoryHarness
+);
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
 struct PostgresOutboxMessageRepositoryHarness {
     catalog: Catalog,
 }
@@ -0,0 +1,276 @@
+// Copyright Kamu Data, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+use std::assert_matches::assert_matches;
+
+use chrono::{TimeZone, Utc};
+use database_common::DatabasePaginationOpts;
+use dill::Catalog;
+use messaging_outbox::{
+    DeleteDeadLetterError,
+    GetDeadLetterError,
+    OutboxConsumerRetryState,
+    OutboxMessageDeadLetter,
+    OutboxMessageDeadLetterRepository,
+    OutboxMessageID,
+};
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+const TEST_CONSUMER: &str = "test-consumer";
+const TEST_PRODUCER: &str = "test-producer";
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+pub async fn test_no_dead_letters_initially(catalog: &Catalog) {
+    let dead_letter_repo = catalog
+        .get_one::<dyn OutboxMessageDeadLetterRepository>()
+        .unwrap();
+
+    let count = dead_letter_repo.count_dead_letters().await.unwrap();
+    assert_eq!(0, count);
+
+    let dead_letters = dead_letter_repo
+        .list_dead_letters(&DatabasePaginationOpts {
+            limit: 10,
+            offset: 0,
+        })
+        .await
+        .unwrap();
+    assert_eq!(0, dead_letters.len());
+
+    let res = dead_letter_repo
+        .get_dead_letter(TEST_PRODUCER, TEST_CONSUMER, OutboxMessageID::new(1))
+        .await;
+    assert_matches!(res, Err(GetDeadLetterError::NotFound(_)));
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+pub async fn test_save_and_get_dead_letter(catalog: &Catalog) {
+    let dead_letter_repo = catalog
+        .get_one::<dyn OutboxMessageDeadLetterRepository>()
+        .unwrap();
+
+    let dead_letter = make_dead_letter(TEST_CONSUMER, 5);
+    dead_letter_repo
+        .save_dead_letter(&dead_letter)
+        .await
+        .unwrap();
+
+    let res = dead_letter_repo
+        .get_dead_letter(TEST_PRODUCER, TEST_CONSUMER, OutboxMessageID::new(5))
+        .await;
+    assert_matches!(res, Ok(a_dead_letter) if a_dead_letter == dead_letter);
+
+    // Same message for another consumer is not parked
+    let res = dead_letter_repo
+        .get_dead_letter(TEST_PRODUCER, "another-consumer", OutboxMessageID::new(5))
+        .await;
+    assert_matches!(res, Err(GetDeadLetterError::NotFound(_)));
+
+    // Saving again updates the record
+    let updated_dead_letter = OutboxMessageDeadLetter {
+        attempts: dead_letter.attempts + 1,
+        last_error: "Still failing".to_string(),
+        dead_lettered_at: Utc.with_ymd_and_hms(2050, 1, 2, 12, 0, 0).unwrap(),
+        ..dead_letter
+    };
+    dead_letter_repo
+        .save_dead_letter(&updated_dead_letter)
+        .await
+        .unwrap();
+
+    let res = dead_letter_repo
+        .get_dead_letter(TEST_PRODUCER, TEST_CONSUMER, OutboxMessageID::new(5))
+        .await;
+    assert_matches!(res, Ok(a_dead_letter) if a_dead_letter == updated_dead_letter);
+
+    let count = dead_letter_repo.count_dead_letters().await.unwrap();
+    assert_eq!(1, count);
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+pub async fn test_list_dead_letters_paged(catalog: &Catalog) {
+    let dead_letter_repo = catalog
+        .get_one::<dyn OutboxMessageDeadLetterRepository>()
+        .unwrap();
+
+    let dead_letters = [
+        make_dead_letter(TEST_CONSUMER, 7),
+        make_dead_letter(TEST_CONSUMER, 3),
+        make_dead_letter("another-consumer", 3),
+        make_dead_letter(TEST_CONSUMER, 11),
+    ];
+    for dead_letter in &dead_letters {
+        dead_letter_repo
+            .save_dead_letter(dead_letter)
+            .await
+            .unwrap();
+    }
+
+    let count = dead_letter_repo.count_dead_letters().await.unwrap();
+    assert_eq!(4, count);
+
+    let page = dead_letter_repo
+        .list_dead_letters(&DatabasePaginationOpts {
+            limit: 3,
+            offset: 0,
+        })
+        .await
+        .unwrap();
+    assert_eq!(
+        page,
+        vec![
+            dead_letters[2].clone(),
+            dead_letters[1].clone(),
+            dead_letters[0].clone(),
+        ]
+    );
+
+    let page = dead_letter_repo
+        .list_dead_letters(&DatabasePaginationOpts {
+            limit: 3,
+            offset: 3,
+        })
+        .await
+        .unwrap();
+    assert_eq!(page, vec![dead_letters[3].clone()]);
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+pub async fn test_delete_dead_letter(catalog: &Catalog) {
+    let dead_letter_repo = catalog
+        .get_one::<dyn OutboxMessageDeadLetterRepository>()
+        .unwrap();
+
+    dead_letter_repo
+        .save_dead_letter(&make_dead_letter(TEST_CONSUMER, 5))
+        .await
+        .unwrap();
+
+    let res = dead_letter_repo
+        .delete_dead_letter(TEST_PRODUCER, TEST_CONSUMER, OutboxMessageID::new(5))
+        .await;
+    assert_matches!(res, Ok(()));
+
+    let res = dead_letter_repo
+        .get_dead_letter(TEST_PRODUCER, TEST_CONSUMER, OutboxMessageID::new(5))
+        .await;
+    assert_matches!(res, Err(GetDeadLetterError::NotFound(_)));
+
+    let res = dead_letter_repo
+        .delete_dead_letter(TEST_PRODUCER, TEST_CONSUMER, OutboxMessageID::new(5))
+        .await;
+    assert_matches!(
+        res,
+        Err(DeleteDeadLetterError::NotFound(e))
+            if e.producer_name == TEST_PRODUCER
+                && e.consumer_name == TEST_CONSUMER
+                && e.message_id == OutboxMessageID::new(5)
+    );
+
+    let count = dead_letter_repo.count_dead_letters().await.unwrap();
+    assert_eq!(0, count);
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+pub async fn test_save_and_delete_retry_states(catalog: &Catalog) {
+    let dead_letter_repo = catalog
+        .get_one::<dyn OutboxMessageDeadLetterRepository>()
+        .unwrap();
+
+    let retry_states = dead_letter_repo
+        .list_retry_states(TEST_PRODUCER)
+        .await
+        .unwrap();
+    assert_eq!(retry_states, []);
+
+    let retry_state = OutboxConsumerRetryState {
+        producer_name: TEST_PRODUCER.to_string(),
+        consumer_name: TEST_CONSUMER.to_string(),
+        message_id: OutboxMessageID::new(5),
+        attempts: 1,
+        next_attempt_at: Utc.with_ymd_and_hms(2050, 1, 1, 12, 1, 0).unwrap(),
+    };
+    dead_letter_repo
+        .save_retry_state(&retry_state)
+        .await
+        .unwrap();
+    dead_letter_repo
+        .save_retry_state(&OutboxConsumerRetryState {
+            producer_name: "another-producer".to_string(),
+            ..retry_state.clone()
+        })
+        .await
+        .unwrap();
+
+    let retry_states = dead_letter_repo
+        .list_retry_states(TEST_PRODUCER)
+        .await
+        .unwrap();
+    assert_eq!(retry_states, [retry_state.clone()]);
+
+    // Saving again replaces the state of the consumer
+    let updated_retry_state = OutboxConsumerRetryState {
+        message_id: OutboxMessageID::new(6),
+        attempts: 2,
+        next_attempt_at: Utc.with_ymd_and_hms(2050, 1, 1, 12, 3, 0).unwrap(),
+        ..retry_state
+    };
+    dead_letter_repo
+        .save_retry_state(&updated_retry_state)
+        .await
+        .unwrap();
+
+    let retry_states = dead_letter_repo
+        .list_retry_states(TEST_PRODUCER)
+        .await
+        .unwrap();
+    assert_eq!(retry_states, [updated_retry_state]);
+
+    // Deleting is idempotent
+    for _ in 0..2 {
+        dead_letter_repo
+            .delete_retry_state(TEST_PRODUCER, TEST_CONSUMER)
+            .await
+            .unwrap();
+    }
+
+    let retry_states = dead_letter_repo
+        .list_retry_states(TEST_PRODUCER)
+        .await
+        .unwrap();
+    assert_eq!(retry_states, []);
+
+    let retry_states = dead_letter_repo
+        .list_retry_states("another-producer")
+        .await
+        .unwrap();
+    assert_eq!(retry_states.len(), 1);
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+fn make_dead_letter(consumer_name: &str, message_id: i64) -> OutboxMessageDeadLetter {
+    OutboxMessageDeadLetter {
+        producer_name: TEST_PRODUCER.to_string(),
+        consumer_name: consumer_name.to_string(),
+        message_id: OutboxMessageID::new(message_id),
+        content_json: serde_json::json!({ "message_id": message_id }),
+        attempts: 5,
+        last_error: "Consumer failed".to_string(),
+        dead_lettered_at: Utc.with_ymd_and_hms(2050, 1, 1, 12, 0, 0).unwrap(),
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
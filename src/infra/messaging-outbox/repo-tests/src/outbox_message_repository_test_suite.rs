@@ -196,6 +196,66 @@ pub async fn test_try_reading_above_max(catalog: &Catalog) {
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
 
+pub async fn test_count_messages_above(catalog: &Catalog) {
+    let outbox_message_repo = catalog.get_one::<dyn OutboxMessageRepository>().unwrap();
+
+    for i in 1..=5 {
+        outbox_message_repo
+            .push_message(NewOutboxMessage {
+                producer_name: "A".to_string(),
+                content_json: serde_json::to_value(&MessageA {
+                    x: i,
+                    y: u64::try_from(i * 2).unwrap(),
+                })
+                .unwrap(),
+                occurred_on: Utc::now(),
+            })
+            .await
+            .unwrap();
+    }
+
+    outbox_message_repo
+        .push_message(NewOutboxMessage {
+            producer_name: "dummy".to_string(),
+            content_json: serde_json::to_value("dummy").unwrap(),
+            occurred_on: Utc::now(),
+        })
+        .await
+        .unwrap();
+
+    let count = outbox_message_repo
+        .count_producer_messages_above("A", OutboxMessageID::new(0))
+        .await
+        .unwrap();
+    assert_eq!(count, 5);
+
+    let count = outbox_message_repo
+        .count_producer_messages_above("A", OutboxMessageID::new(3))
+        .await
+        .unwrap();
+    assert_eq!(count, 2); // 4, 5
+
+    let count = outbox_message_repo
+        .count_producer_messages_above("A", OutboxMessageID::new(5))
+        .await
+        .unwrap();
+    assert_eq!(count, 0);
+
+    let count = outbox_message_repo
+        .count_producer_messages_above("dummy", OutboxMessageID::new(0))
+        .await
+        .unwrap();
+    assert_eq!(count, 1);
+
+    let count = outbox_message_repo
+        .count_producer_messages_above("unknown", OutboxMessageID::new(0))
+        .await
+        .unwrap();
+    assert_eq!(count, 0);
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
 #[derive(Debug, Serialize, Deserialize)]
 struct MessageA {
     x: i32,
@@ -8,7 +8,9 @@
 // by the Apache License, Version 2.0.
 
 mod sqlite_outbox_message_consumption_repository;
+mod sqlite_outbox_message_dead_letter_repository;
 mod sqlite_outbox_message_repository;
 
 pub use sqlite_outbox_message_consumption_repository::*;
+pub use sqlite_outbox_message_dead_letter_repository::*;
 pub use sqlite_outbox_message_repository::*;
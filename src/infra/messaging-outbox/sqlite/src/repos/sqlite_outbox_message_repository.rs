@@ -128,6 +128,33 @@ impl OutboxMessageRepository for SqliteOutboxMessageRepository {
             })
             .collect())
     }
+
+    async fn count_producer_messages_above(
+        &self,
+        producer_name: &str,
+        above_id: OutboxMessageID,
+    ) -> Result<usize, InternalError> {
+        let mut tr = self.transaction.lock().await;
+        let connection_mut = tr.connection_mut().await?;
+
+        let above_id = above_id.into_inner();
+
+        let messages_count = sqlx::query_scalar!(
+            r#"
+                SELECT
+                    count(*)
+                FROM outbox_messages
+                WHERE producer_name = $1 and message_id > $2
+            "#,
+            producer_name,
+            above_id,
+        )
+        .fetch_one(connection_mut)
+        .await
+        .int_err()?;
+
+        Ok(usize::try_from(messages_count).unwrap_or(0))
+    }
 }
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -8,4 +8,5 @@
 // by the Apache License, Version 2.0.
 
 mod test_sqlite_outbox_message_consumption_repository;
+mod test_sqlite_outbox_message_dead_letter_repository;
 mod test_sqlite_outbox_message_repository;
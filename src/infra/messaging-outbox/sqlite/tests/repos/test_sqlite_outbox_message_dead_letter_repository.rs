@@ -0,0 +1,72 @@
+// Copyright Kamu Data, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use database_common::SqliteTransactionManager;
+use database_common_macros::database_transactional_test;
+use dill::{Catalog, CatalogBuilder};
+use kamu_messaging_outbox_sqlite::SqliteOutboxMessageDeadLetterRepository;
+use sqlx::SqlitePool;
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+database_transactional_test!(
+    storage = sqlite,
+    fixture = kamu_messaging_outbox_repo_tests::test_no_dead_letters_initially,
+    harness = SqliteOutboxMessageDeadLetterRepositoryHarness
+);
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+database_transactional_test!(
+    storage = sqlite,
+    fixture = kamu_messaging_outbox_repo_tests::test_save_and_get_dead_letter,
+    harness = SqliteOutboxMessageDeadLetterRepositoryHarness
+);
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+database_transactional_test!(
+    storage = sqlite,
+    fixture = kamu_messaging_outbox_repo_tests::test_list_dead_letters_paged,
+    harness = SqliteOutboxMessageDeadLetterRepositoryHarness
+);
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+database_transactional_test!(
+    storage = sqlite,
+    fixture = kamu_messaging_outbox_repo_tests::test_delete_dead_letter,
+    harness = SqliteOutboxMessageDeadLetterRepositoryHarness
+);
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+database_transactional_test!(
+    storage = sqlite,
+    fixture = kamu_messaging_outbox_repo_tests::test_save_and_delete_retry_states,
+    harness = SqliteOutboxMessageDeadLetterRepositoryHarness
+);
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+struct SqliteOutboxMessageDeadLetterRepositoryHarness {
+    catalog: Catalog,
+}
+
+impl SqliteOutboxMessageDeadLetterRepositoryHarness {
+    pub fn new(sqlite_pool: SqlitePool) -> Self {
+        let mut catalog_builder = CatalogBuilder::new();
+        catalog_builder.add_value(sqlite_pool);
+        catalog_builder.add::<SqliteTransactionManager>();
+        catalog_builder.add::<SqliteOutboxMessageDeadLetterRepository>();
+
+        Self {
+            catalog: catalog_builder.build(),
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -63,6 +63,7 @@ pub async fn test_event_store_get_streams(catalog: &Catalog) {
             ..Probe::default()
         }
         .into(),
+        trace_parent: None,
     };
 
     let event_2 = TaskEventCreated {
@@ -73,6 +74,7 @@ pub async fn test_event_store_get_streams(catalog: &Catalog) {
             ..Probe::default()
         }
         .into(),
+        trace_parent: None,
     };
 
     let event_3 = TaskEventFinished {
@@ -139,6 +141,7 @@ pub async fn test_event_store_get_events_with_windowing(catalog: &Catalog) {
             ..Probe::default()
         }
         .into(),
+        trace_parent: None,
     };
 
     let event_2 = TaskEventRunning {
@@ -238,6 +241,7 @@ pub async fn test_event_store_get_events_by_tasks(catalog: &Catalog) {
             ..Probe::default()
         }
         .into(),
+        trace_parent: None,
     };
 
     let event_2_1 = TaskEventCreated {
@@ -248,6 +252,7 @@ pub async fn test_event_store_get_events_by_tasks(catalog: &Catalog) {
             ..Probe::default()
         }
         .into(),
+        trace_parent: None,
     };
 
     let event_1_2 = TaskEventRunning {
@@ -350,6 +355,7 @@ pub async fn test_event_store_get_dataset_tasks(catalog: &Catalog) {
             ..Probe::default()
         }
         .into(),
+        trace_parent: None,
     };
 
     let event_1_2 = TaskEventCreated {
@@ -360,6 +366,7 @@ pub async fn test_event_store_get_dataset_tasks(catalog: &Catalog) {
             ..Probe::default()
         }
         .into(),
+        trace_parent: None,
     };
 
     let event_2_1 = TaskEventCreated {
@@ -370,6 +377,7 @@ pub async fn test_event_store_get_dataset_tasks(catalog: &Catalog) {
             ..Probe::default()
         }
         .into(),
+        trace_parent: None,
     };
 
     let event_2_2 = TaskEventCreated {
@@ -380,6 +388,7 @@ pub async fn test_event_store_get_dataset_tasks(catalog: &Catalog) {
             ..Probe::default()
         }
         .into(),
+        trace_parent: None,
     };
 
     event_store
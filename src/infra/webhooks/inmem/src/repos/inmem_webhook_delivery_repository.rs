@@ -0,0 +1,172 @@
+// Copyright Kamu Data, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+
+use chrono::{DateTime, Utc};
+use database_common::DatabasePaginationOpts;
+use dill::*;
+use uuid::Uuid;
+
+use crate::domain::*;
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+pub struct InMemoryWebhookDeliveryRepository {
+    state: Arc<Mutex<State>>,
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[derive(Default)]
+struct State {
+    deliveries_by_id: HashMap<Uuid, WebhookDelivery>,
+}
+
+impl State {
+    fn deliveries_of_subscription(
+        &self,
+        subscription_id: &Uuid,
+        maybe_status: Option<WebhookDeliveryStatus>,
+    ) -> impl Iterator<Item = &WebhookDelivery> {
+        let subscription_id = *subscription_id;
+        self.deliveries_by_id.values().filter(move |d| {
+            d.subscription_id == subscription_id
+                && maybe_status.map_or(true, |status| d.status == status)
+        })
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[component(pub)]
+#[interface(dyn WebhookDeliveryRepository)]
+#[scope(Singleton)]
+impl InMemoryWebhookDeliveryRepository {
+    pub fn new() -> Self {
+        Self {
+            state: Arc::new(Mutex::new(State::default())),
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[async_trait::async_trait]
+impl WebhookDeliveryRepository for InMemoryWebhookDeliveryRepository {
+    async fn create_delivery(
+        &self,
+        delivery: &WebhookDelivery,
+    ) -> Result<(), CreateWebhookDeliveryError> {
+        let mut guard = self.state.lock().unwrap();
+        guard.deliveries_by_id.insert(delivery.id, delivery.clone());
+        Ok(())
+    }
+
+    async fn update_delivery(
+        &self,
+        delivery: &WebhookDelivery,
+    ) -> Result<(), UpdateWebhookDeliveryError> {
+        let mut guard = self.state.lock().unwrap();
+        let Some(existing) = guard.deliveries_by_id.get_mut(&delivery.id) else {
+            return Err(UpdateWebhookDeliveryError::NotFound(
+                WebhookDeliveryNotFoundError {
+                    delivery_id: delivery.id,
+                },
+            ));
+        };
+        *existing = delivery.clone();
+        Ok(())
+    }
+
+    async fn get_delivery(
+        &self,
+        delivery_id: &Uuid,
+    ) -> Result<WebhookDelivery, GetWebhookDeliveryError> {
+        let guard = self.state.lock().unwrap();
+        guard
+            .deliveries_by_id
+            .get(delivery_id)
+            .cloned()
+            .ok_or_else(|| {
+                GetWebhookDeliveryError::NotFound(WebhookDeliveryNotFoundError {
+                    delivery_id: *delivery_id,
+                })
+            })
+    }
+
+    async fn list_due_deliveries(
+        &self,
+        now: DateTime<Utc>,
+        limit: usize,
+    ) -> Result<Vec<WebhookDelivery>, ListWebhookDeliveriesError> {
+        let guard = self.state.lock().unwrap();
+        let mut deliveries: Vec<_> = guard
+            .deliveries_by_id
+            .values()
+            .filter(|d| {
+                d.status == WebhookDeliveryStatus::Pending
+                    && d.next_attempt_at.is_some_and(|t| t <= now)
+            })
+            .cloned()
+            .collect();
+        deliveries.sort_by(|a, b| {
+            a.next_attempt_at
+                .cmp(&b.next_attempt_at)
+                .then(a.id.cmp(&b.id))
+        });
+        deliveries.truncate(limit);
+        Ok(deliveries)
+    }
+
+    async fn get_deliveries_count_by_subscription(
+        &self,
+        subscription_id: &Uuid,
+        maybe_status: Option<WebhookDeliveryStatus>,
+    ) -> Result<usize, ListWebhookDeliveriesError> {
+        let guard = self.state.lock().unwrap();
+        Ok(guard
+            .deliveries_of_subscription(subscription_id, maybe_status)
+            .count())
+    }
+
+    async fn get_deliveries_by_subscription(
+        &self,
+        subscription_id: &Uuid,
+        maybe_status: Option<WebhookDeliveryStatus>,
+        pagination: &DatabasePaginationOpts,
+    ) -> Result<Vec<WebhookDelivery>, ListWebhookDeliveriesError> {
+        let guard = self.state.lock().unwrap();
+        let mut deliveries: Vec<_> = guard
+            .deliveries_of_subscription(subscription_id, maybe_status)
+            .cloned()
+            .collect();
+        deliveries.sort_by(|a, b| b.created_at.cmp(&a.created_at).then(a.id.cmp(&b.id)));
+
+        Ok(deliveries
+            .into_iter()
+            .skip(usize::try_from(pagination.offset).unwrap())
+            .take(usize::try_from(pagination.limit).unwrap())
+            .collect())
+    }
+
+    async fn delete_deliveries_by_subscription(
+        &self,
+        subscription_id: &Uuid,
+    ) -> Result<(), DeleteWebhookDeliveriesError> {
+        let mut guard = self.state.lock().unwrap();
+        guard
+            .deliveries_by_id
+            .retain(|_, d| d.subscription_id != *subscription_id);
+        Ok(())
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -0,0 +1,136 @@
+// Copyright Kamu Data, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+
+use dill::*;
+use uuid::Uuid;
+
+use crate::domain::*;
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+pub struct InMemoryWebhookSubscriptionRepository {
+    state: Arc<Mutex<State>>,
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[derive(Default)]
+struct State {
+    subscriptions_by_id: HashMap<Uuid, WebhookSubscription>,
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[component(pub)]
+#[interface(dyn WebhookSubscriptionRepository)]
+#[scope(Singleton)]
+impl InMemoryWebhookSubscriptionRepository {
+    pub fn new() -> Self {
+        Self {
+            state: Arc::new(Mutex::new(State::default())),
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[async_trait::async_trait]
+impl WebhookSubscriptionRepository for InMemoryWebhookSubscriptionRepository {
+    async fn create_subscription(
+        &self,
+        subscription: &WebhookSubscription,
+    ) -> Result<(), CreateWebhookSubscriptionError> {
+        let mut guard = self.state.lock().unwrap();
+        guard
+            .subscriptions_by_id
+            .insert(subscription.id, subscription.clone());
+        Ok(())
+    }
+
+    async fn get_subscription(
+        &self,
+        subscription_id: &Uuid,
+    ) -> Result<WebhookSubscription, GetWebhookSubscriptionError> {
+        let guard = self.state.lock().unwrap();
+        guard
+            .subscriptions_by_id
+            .get(subscription_id)
+            .cloned()
+            .ok_or_else(|| {
+                GetWebhookSubscriptionError::NotFound(WebhookSubscriptionNotFoundError {
+                    subscription_id: *subscription_id,
+                })
+            })
+    }
+
+    async fn list_subscriptions_by_scope(
+        &self,
+        scope: &WebhookSubscriptionScope,
+    ) -> Result<Vec<WebhookSubscription>, ListWebhookSubscriptionsError> {
+        let guard = self.state.lock().unwrap();
+        let mut subscriptions: Vec<_> = guard
+            .subscriptions_by_id
+            .values()
+            .filter(|s| s.scope == *scope)
+            .cloned()
+            .collect();
+        subscriptions.sort_by(|a, b| a.created_at.cmp(&b.created_at).then(a.id.cmp(&b.id)));
+        Ok(subscriptions)
+    }
+
+    async fn list_all_subscriptions(
+        &self,
+    ) -> Result<Vec<WebhookSubscription>, ListWebhookSubscriptionsError> {
+        let guard = self.state.lock().unwrap();
+        let mut subscriptions: Vec<_> = guard.subscriptions_by_id.values().cloned().collect();
+        subscriptions.sort_by(|a, b| a.created_at.cmp(&b.created_at).then(a.id.cmp(&b.id)));
+        Ok(subscriptions)
+    }
+
+    async fn update_subscription(
+        &self,
+        subscription: &WebhookSubscription,
+    ) -> Result<(), UpdateWebhookSubscriptionError> {
+        let mut guard = self.state.lock().unwrap();
+        let Some(existing) = guard.subscriptions_by_id.get_mut(&subscription.id) else {
+            return Err(UpdateWebhookSubscriptionError::NotFound(
+                WebhookSubscriptionNotFoundError {
+                    subscription_id: subscription.id,
+                },
+            ));
+        };
+
+        existing.target_url = subscription.target_url.clone();
+        existing.label.clone_from(&subscription.label);
+        existing.event_types.clone_from(&subscription.event_types);
+        existing.secret.clone_from(&subscription.secret);
+        existing.enabled = subscription.enabled;
+        Ok(())
+    }
+
+    async fn delete_subscription(
+        &self,
+        subscription_id: &Uuid,
+    ) -> Result<(), DeleteWebhookSubscriptionError> {
+        let mut guard = self.state.lock().unwrap();
+        if guard.subscriptions_by_id.remove(subscription_id).is_none() {
+            return Err(DeleteWebhookSubscriptionError::NotFound(
+                WebhookSubscriptionNotFoundError {
+                    subscription_id: *subscription_id,
+                },
+            ));
+        }
+        Ok(())
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
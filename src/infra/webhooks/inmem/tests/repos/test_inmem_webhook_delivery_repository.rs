@@ -0,0 +1,56 @@
+// Copyright Kamu Data, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+use database_common_macros::database_transactional_test;
+use dill::{Catalog, CatalogBuilder};
+use kamu_webhooks_inmem::InMemoryWebhookDeliveryRepository;
+use kamu_webhooks_repo_tests::webhook_delivery_repo;
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+database_transactional_test!(
+    storage = inmem,
+    fixture = webhook_delivery_repo::test_create_and_update_delivery,
+    harness = InMemoryWebhookDeliveryRepositoryHarness
+);
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+database_transactional_test!(
+    storage = inmem,
+    fixture = webhook_delivery_repo::test_list_due_deliveries,
+    harness = InMemoryWebhookDeliveryRepositoryHarness
+);
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+database_transactional_test!(
+    storage = inmem,
+    fixture = webhook_delivery_repo::test_deliveries_by_subscription,
+    harness = InMemoryWebhookDeliveryRepositoryHarness
+);
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+struct InMemoryWebhookDeliveryRepositoryHarness {
+    catalog: Catalog,
+}
+
+impl InMemoryWebhookDeliveryRepositoryHarness {
+    pub fn new() -> Self {
+        let mut catalog_builder = CatalogBuilder::new();
+        catalog_builder.add::<InMemoryWebhookDeliveryRepository>();
+
+        Self {
+            catalog: catalog_builder.build(),
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
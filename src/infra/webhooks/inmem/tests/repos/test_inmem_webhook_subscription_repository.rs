@@ -0,0 +1,64 @@
+// Copyright Kamu Data, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+use database_common_macros::database_transactional_test;
+use dill::{Catalog, CatalogBuilder};
+use kamu_webhooks_inmem::InMemoryWebhookSubscriptionRepository;
+use kamu_webhooks_repo_tests::webhook_subscription_repo;
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+database_transactional_test!(
+    storage = inmem,
+    fixture = webhook_subscription_repo::test_missing_subscriptions,
+    harness = InMemoryWebhookSubscriptionRepositoryHarness
+);
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+database_transactional_test!(
+    storage = inmem,
+    fixture = webhook_subscription_repo::test_create_and_list_subscriptions,
+    harness = InMemoryWebhookSubscriptionRepositoryHarness
+);
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+database_transactional_test!(
+    storage = inmem,
+    fixture = webhook_subscription_repo::test_update_subscription,
+    harness = InMemoryWebhookSubscriptionRepositoryHarness
+);
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+database_transactional_test!(
+    storage = inmem,
+    fixture = webhook_subscription_repo::test_delete_subscription,
+    harness = InMemoryWebhookSubscriptionRepositoryHarness
+);
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+struct InMemoryWebhookSubscriptionRepositoryHarness {
+    catalog: Catalog,
+}
+
+impl InMemoryWebhookSubscriptionRepositoryHarness {
+    pub fn new() -> Self {
+        let mut catalog_builder = CatalogBuilder::new();
+        catalog_builder.add::<InMemoryWebhookSubscriptionRepository>();
+
+        Self {
+            catalog: catalog_builder.build(),
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -0,0 +1,280 @@
+// Copyright Kamu Data, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+use database_common::{TransactionRef, TransactionRefT};
+use dill::{component, interface};
+use internal_error::{InternalError, ResultIntoInternal};
+use uuid::Uuid;
+
+use crate::domain::*;
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+pub struct PostgresWebhookSubscriptionRepository {
+    transaction: TransactionRefT<sqlx::Postgres>,
+}
+
+#[component(pub)]
+#[interface(dyn WebhookSubscriptionRepository)]
+impl PostgresWebhookSubscriptionRepository {
+    pub fn new(transaction: TransactionRef) -> Self {
+        Self {
+            transaction: transaction.into(),
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl WebhookSubscriptionRepository for PostgresWebhookSubscriptionRepository {
+    async fn create_subscription(
+        &self,
+        subscription: &WebhookSubscription,
+    ) -> Result<(), CreateWebhookSubscriptionError> {
+        let mut tr = self.transaction.lock().await;
+
+        let connection_mut = tr
+            .connection_mut()
+            .await
+            .map_err(CreateWebhookSubscriptionError::Internal)?;
+
+        let event_types_json = serde_json::to_string(&subscription.event_types)
+            .int_err()
+            .map_err(CreateWebhookSubscriptionError::Internal)?;
+
+        sqlx::query!(
+            r#"
+                INSERT INTO webhook_subscriptions (id, dataset_id, account_id, target_url, label, event_types, secret, enabled, created_at)
+                    VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9)
+                "#,
+            subscription.id,
+            subscription.scope.dataset_id().map(ToString::to_string),
+            subscription.scope.account_id().map(ToString::to_string),
+            subscription.target_url.as_str(),
+            subscription.label,
+            event_types_json,
+            subscription.secret,
+            subscription.enabled,
+            subscription.created_at,
+        )
+        .execute(connection_mut)
+        .await
+        .int_err()
+        .map_err(CreateWebhookSubscriptionError::Internal)?;
+
+        Ok(())
+    }
+
+    async fn get_subscription(
+        &self,
+        subscription_id: &Uuid,
+    ) -> Result<WebhookSubscription, GetWebhookSubscriptionError> {
+        let mut tr = self.transaction.lock().await;
+
+        let connection_mut = tr
+            .connection_mut()
+            .await
+            .map_err(GetWebhookSubscriptionError::Internal)?;
+
+        let maybe_subscription_row = sqlx::query_as!(
+            WebhookSubscriptionRowModel,
+            r#"
+                SELECT
+                    id,
+                    dataset_id,
+                    account_id,
+                    target_url,
+                    label,
+                    event_types,
+                    secret,
+                    enabled,
+                    created_at
+                FROM webhook_subscriptions
+                WHERE id = $1
+                "#,
+            subscription_id,
+        )
+        .fetch_optional(connection_mut)
+        .await
+        .int_err()
+        .map_err(GetWebhookSubscriptionError::Internal)?;
+
+        let Some(subscription_row) = maybe_subscription_row else {
+            return Err(GetWebhookSubscriptionError::NotFound(
+                WebhookSubscriptionNotFoundError {
+                    subscription_id: *subscription_id,
+                },
+            ));
+        };
+
+        subscription_row
+            .try_into()
+            .map_err(GetWebhookSubscriptionError::Internal)
+    }
+
+    async fn list_subscriptions_by_scope(
+        &self,
+        scope: &WebhookSubscriptionScope,
+    ) -> Result<Vec<WebhookSubscription>, ListWebhookSubscriptionsError> {
+        let mut tr = self.transaction.lock().await;
+
+        let connection_mut = tr
+            .connection_mut()
+            .await
+            .map_err(ListWebhookSubscriptionsError::Internal)?;
+
+        let subscription_rows = sqlx::query_as!(
+            WebhookSubscriptionRowModel,
+            r#"
+                SELECT
+                    id,
+                    dataset_id,
+                    account_id,
+                    target_url,
+                    label,
+                    event_types,
+                    secret,
+                    enabled,
+                    created_at
+                FROM webhook_subscriptions
+                WHERE dataset_id IS NOT DISTINCT FROM $1 AND account_id IS NOT DISTINCT FROM $2
+                ORDER BY created_at, id
+                "#,
+            scope.dataset_id().map(ToString::to_string),
+            scope.account_id().map(ToString::to_string),
+        )
+        .fetch_all(connection_mut)
+        .await
+        .int_err()
+        .map_err(ListWebhookSubscriptionsError::Internal)?;
+
+        subscription_rows
+            .into_iter()
+            .map(TryInto::try_into)
+            .collect::<Result<_, InternalError>>()
+            .map_err(ListWebhookSubscriptionsError::Internal)
+    }
+
+    async fn list_all_subscriptions(
+        &self,
+    ) -> Result<Vec<WebhookSubscription>, ListWebhookSubscriptionsError> {
+        let mut tr = self.transaction.lock().await;
+
+        let connection_mut = tr
+            .connection_mut()
+            .await
+            .map_err(ListWebhookSubscriptionsError::Internal)?;
+
+        let subscription_rows = sqlx::query_as!(
+            WebhookSubscriptionRowModel,
+            r#"
+                SELECT
+                    id,
+                    dataset_id,
+                    account_id,
+                    target_url,
+                    label,
+                    event_types,
+                    secret,
+                    enabled,
+                    created_at
+                FROM webhook_subscriptions
+                ORDER BY created_at, id
+                "#,
+        )
+        .fetch_all(connection_mut)
+        .await
+        .int_err()
+        .map_err(ListWebhookSubscriptionsError::Internal)?;
+
+        subscription_rows
+            .into_iter()
+            .map(TryInto::try_into)
+            .collect::<Result<_, InternalError>>()
+            .map_err(ListWebhookSubscriptionsError::Internal)
+    }
+
+    async fn update_subscription(
+        &self,
+        subscription: &WebhookSubscription,
+    ) -> Result<(), UpdateWebhookSubscriptionError> {
+        let mut tr = self.transaction.lock().await;
+
+        let connection_mut = tr
+            .connection_mut()
+            .await
+            .map_err(UpdateWebhookSubscriptionError::Internal)?;
+
+        let event_types_json = serde_json::to_string(&subscription.event_types)
+            .int_err()
+            .map_err(UpdateWebhookSubscriptionError::Internal)?;
+
+        let update_result = sqlx::query!(
+            r#"
+                UPDATE webhook_subscriptions
+                    SET target_url = $2, label = $3, event_types = $4, secret = $5, enabled = $6
+                    WHERE id = $1
+                "#,
+            subscription.id,
+            subscription.target_url.as_str(),
+            subscription.label,
+            event_types_json,
+            subscription.secret,
+            subscription.enabled,
+        )
+        .execute(connection_mut)
+        .await
+        .int_err()
+        .map_err(UpdateWebhookSubscriptionError::Internal)?;
+
+        if update_result.rows_affected() == 0 {
+            return Err(UpdateWebhookSubscriptionError::NotFound(
+                WebhookSubscriptionNotFoundError {
+                    subscription_id: subscription.id,
+                },
+            ));
+        }
+
+        Ok(())
+    }
+
+    async fn delete_subscription(
+        &self,
+        subscription_id: &Uuid,
+    ) -> Result<(), DeleteWebhookSubscriptionError> {
+        let mut tr = self.transaction.lock().await;
+
+        let connection_mut = tr
+            .connection_mut()
+            .await
+            .map_err(DeleteWebhookSubscriptionError::Internal)?;
+
+        let delete_result = sqlx::query!(
+            r#"
+                DELETE FROM webhook_subscriptions WHERE id = $1
+                "#,
+            subscription_id,
+        )
+        .execute(connection_mut)
+        .await
+        .int_err()
+        .map_err(DeleteWebhookSubscriptionError::Internal)?;
+
+        if delete_result.rows_affected() == 0 {
+            return Err(DeleteWebhookSubscriptionError::NotFound(
+                WebhookSubscriptionNotFoundError {
+                    subscription_id: *subscription_id,
+                },
+            ));
+        }
+
+        Ok(())
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
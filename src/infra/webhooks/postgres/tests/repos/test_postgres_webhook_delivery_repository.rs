@@ -0,0 +1,61 @@
+// Copyright Kamu Data, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+use database_common::PostgresTransactionManager;
+use database_common_macros::database_transactional_test;
+use dill::{Catalog, CatalogBuilder};
+use kamu_webhooks_postgres::PostgresWebhookDeliveryRepository;
+use kamu_webhooks_repo_tests::webhook_delivery_repo;
+use sqlx::PgPool;
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+database_transactional_test!(
+    storage = postgres,
+    fixture = webhook_delivery_repo::test_create_and_update_delivery,
+    harness = PostgresWebhookDeliveryRepositoryHarness
+);
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+database_transactional_test!(
+    storage = postgres,
+    fixture = webhook_delivery_repo::test_list_due_deliveries,
+    harness = PostgresWebhookDeliveryRepositoryHarness
+);
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+database_transactional_test!(
+    storage = postgres,
+    fixture = webhook_delivery_repo::test_deliveries_by_subscription,
+    harness = PostgresWebhookDeliveryRepositoryHarness
+);
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+struct PostgresWebhookDeliveryRepositoryHarness {
+    catalog: Catalog,
+}
+
+impl PostgresWebhookDeliveryRepositoryHarness {
+    pub fn new(pg_pool: PgPool) -> Self {
+        // Initialize catalog with predefined Postgres pool
+        let mut catalog_builder = CatalogBuilder::new();
+        catalog_builder.add_value(pg_pool);
+        catalog_builder.add::<PostgresTransactionManager>();
+        catalog_builder.add::<PostgresWebhookDeliveryRepository>();
+
+        Self {
+            catalog: catalog_builder.build(),
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -0,0 +1,225 @@
+// Copyright Kamu Data, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+use std::assert_matches::assert_matches;
+
+use chrono::{DateTime, Duration, SubsecRound, Utc};
+use database_common::DatabasePaginationOpts;
+use dill::Catalog;
+use kamu_webhooks::*;
+use opendatafabric::DatasetID;
+use uuid::Uuid;
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+pub async fn test_create_and_update_delivery(catalog: &Catalog) {
+    let delivery_repo = catalog.get_one::<dyn WebhookDeliveryRepository>().unwrap();
+
+    let now = Utc::now().round_subsecs(6);
+    let mut delivery = new_delivery(Uuid::new_v4(), now);
+
+    assert_matches!(
+        delivery_repo.get_delivery(&delivery.id).await,
+        Err(GetWebhookDeliveryError::NotFound(_))
+    );
+    assert_matches!(
+        delivery_repo.update_delivery(&delivery).await,
+        Err(UpdateWebhookDeliveryError::NotFound(_))
+    );
+
+    delivery_repo.create_delivery(&delivery).await.unwrap();
+    assert_eq!(
+        delivery_repo.get_delivery(&delivery.id).await.unwrap(),
+        delivery
+    );
+
+    delivery.on_failed(
+        now,
+        Some(503),
+        "Service unavailable".to_string(),
+        &WebhookRetryPolicy::default(),
+    );
+    delivery_repo.update_delivery(&delivery).await.unwrap();
+    assert_eq!(
+        delivery_repo.get_delivery(&delivery.id).await.unwrap(),
+        delivery
+    );
+
+    delivery.on_delivered(now + Duration::try_seconds(10).unwrap(), 204);
+    delivery_repo.update_delivery(&delivery).await.unwrap();
+    assert_eq!(
+        delivery_repo.get_delivery(&delivery.id).await.unwrap(),
+        delivery
+    );
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+pub async fn test_list_due_deliveries(catalog: &Catalog) {
+    let delivery_repo = catalog.get_one::<dyn WebhookDeliveryRepository>().unwrap();
+
+    let now = Utc::now().round_subsecs(6);
+    let subscription_id = Uuid::new_v4();
+
+    let due_earlier = new_delivery(subscription_id, now - Duration::try_minutes(2).unwrap());
+    let due_later = new_delivery(subscription_id, now - Duration::try_minutes(1).unwrap());
+    let not_due_yet = new_delivery(subscription_id, now + Duration::try_minutes(1).unwrap());
+
+    let mut delivered = new_delivery(subscription_id, now - Duration::try_minutes(3).unwrap());
+    delivered.on_delivered(now, 200);
+
+    let mut dead_lettered = new_delivery(subscription_id, now - Duration::try_minutes(3).unwrap());
+    dead_lettered.on_failed(
+        now,
+        None,
+        "Connection refused".to_string(),
+        &WebhookRetryPolicy {
+            max_attempts: 1,
+            ..WebhookRetryPolicy::default()
+        },
+    );
+
+    for delivery in [
+        &due_later,
+        &not_due_yet,
+        &delivered,
+        &due_earlier,
+        &dead_lettered,
+    ] {
+        delivery_repo.create_delivery(delivery).await.unwrap();
+    }
+
+    assert_eq!(
+        delivery_repo.list_due_deliveries(now, 10).await.unwrap(),
+        vec![due_earlier.clone(), due_later]
+    );
+    assert_eq!(
+        delivery_repo.list_due_deliveries(now, 1).await.unwrap(),
+        vec![due_earlier]
+    );
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+pub async fn test_deliveries_by_subscription(catalog: &Catalog) {
+    let delivery_repo = catalog.get_one::<dyn WebhookDeliveryRepository>().unwrap();
+
+    let now = Utc::now().round_subsecs(6);
+    let subscription_id = Uuid::new_v4();
+    let other_subscription_id = Uuid::new_v4();
+
+    let oldest = new_delivery(subscription_id, now - Duration::try_minutes(3).unwrap());
+    let mut middle = new_delivery(subscription_id, now - Duration::try_minutes(2).unwrap());
+    middle.on_failed(
+        now,
+        Some(500),
+        "Internal server error".to_string(),
+        &WebhookRetryPolicy {
+            max_attempts: 1,
+            ..WebhookRetryPolicy::default()
+        },
+    );
+    let newest = new_delivery(subscription_id, now - Duration::try_minutes(1).unwrap());
+    let other = new_delivery(other_subscription_id, now);
+
+    for delivery in [&middle, &oldest, &other, &newest] {
+        delivery_repo.create_delivery(delivery).await.unwrap();
+    }
+
+    assert_eq!(
+        delivery_repo
+            .get_deliveries_count_by_subscription(&subscription_id, None)
+            .await
+            .unwrap(),
+        3
+    );
+    assert_eq!(
+        delivery_repo
+            .get_deliveries_count_by_subscription(
+                &subscription_id,
+                Some(WebhookDeliveryStatus::DeadLettered)
+            )
+            .await
+            .unwrap(),
+        1
+    );
+
+    assert_eq!(
+        delivery_repo
+            .get_deliveries_by_subscription(
+                &subscription_id,
+                None,
+                &DatabasePaginationOpts {
+                    limit: 10,
+                    offset: 0,
+                }
+            )
+            .await
+            .unwrap(),
+        vec![newest.clone(), middle.clone(), oldest.clone()]
+    );
+    assert_eq!(
+        delivery_repo
+            .get_deliveries_by_subscription(
+                &subscription_id,
+                None,
+                &DatabasePaginationOpts {
+                    limit: 1,
+                    offset: 1,
+                }
+            )
+            .await
+            .unwrap(),
+        vec![middle.clone()]
+    );
+    assert_eq!(
+        delivery_repo
+            .get_deliveries_by_subscription(
+                &subscription_id,
+                Some(WebhookDeliveryStatus::Pending),
+                &DatabasePaginationOpts {
+                    limit: 10,
+                    offset: 0,
+                }
+            )
+            .await
+            .unwrap(),
+        vec![newest, oldest]
+    );
+
+    delivery_repo
+        .delete_deliveries_by_subscription(&subscription_id)
+        .await
+        .unwrap();
+
+    assert_eq!(
+        delivery_repo
+            .get_deliveries_count_by_subscription(&subscription_id, None)
+            .await
+            .unwrap(),
+        0
+    );
+    assert_eq!(delivery_repo.get_delivery(&other.id).await.unwrap(), other);
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+fn new_delivery(subscription_id: Uuid, created_at: DateTime<Utc>) -> WebhookDelivery {
+    let event = WebhookEvent::new(
+        WebhookEventType::FlowFailed,
+        created_at,
+        DatasetID::new_seeded_ed25519(b"foo"),
+        serde_json::json!({
+            "flowId": "1",
+        }),
+    );
+    WebhookDelivery::new(subscription_id, &event, created_at)
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -0,0 +1,239 @@
+// Copyright Kamu Data, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+use std::assert_matches::assert_matches;
+
+use chrono::{Duration, SubsecRound, Utc};
+use dill::Catalog;
+use kamu_webhooks::*;
+use opendatafabric::{AccountID, DatasetID};
+use url::Url;
+use uuid::Uuid;
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+pub async fn test_missing_subscriptions(catalog: &Catalog) {
+    let subscription_repo = catalog
+        .get_one::<dyn WebhookSubscriptionRepository>()
+        .unwrap();
+
+    let subscriptions = subscription_repo
+        .list_subscriptions_by_scope(&WebhookSubscriptionScope::Dataset(
+            DatasetID::new_seeded_ed25519(b"foo"),
+        ))
+        .await
+        .unwrap();
+    assert!(subscriptions.is_empty());
+
+    let subscription_id = Uuid::new_v4();
+    assert_matches!(
+        subscription_repo.get_subscription(&subscription_id).await,
+        Err(GetWebhookSubscriptionError::NotFound(e)) if e.subscription_id == subscription_id
+    );
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+pub async fn test_create_and_list_subscriptions(catalog: &Catalog) {
+    let subscription_repo = catalog
+        .get_one::<dyn WebhookSubscriptionRepository>()
+        .unwrap();
+
+    let dataset_scope = WebhookSubscriptionScope::Dataset(DatasetID::new_seeded_ed25519(b"foo"));
+    let account_scope = WebhookSubscriptionScope::Account(AccountID::new_seeded_ed25519(b"bar"));
+    let created_at = Utc::now().round_subsecs(6);
+
+    let dataset_subscription_1 = new_subscription(
+        dataset_scope.clone(),
+        vec![
+            WebhookEventType::DatasetDataAppended,
+            WebhookEventType::DatasetDeleted,
+        ],
+        created_at,
+    );
+    let dataset_subscription_2 = new_subscription(
+        dataset_scope.clone(),
+        vec![WebhookEventType::FlowFailed],
+        created_at + Duration::try_seconds(1).unwrap(),
+    );
+    let account_subscription = new_subscription(
+        account_scope.clone(),
+        vec![
+            WebhookEventType::FlowFailed,
+            WebhookEventType::FlowSucceeded,
+        ],
+        created_at,
+    );
+
+    for subscription in [
+        &dataset_subscription_2,
+        &dataset_subscription_1,
+        &account_subscription,
+    ] {
+        subscription_repo
+            .create_subscription(subscription)
+            .await
+            .unwrap();
+    }
+
+    assert_eq!(
+        subscription_repo
+            .get_subscription(&dataset_subscription_1.id)
+            .await
+            .unwrap(),
+        dataset_subscription_1
+    );
+    assert_eq!(
+        subscription_repo
+            .get_subscription(&account_subscription.id)
+            .await
+            .unwrap(),
+        account_subscription
+    );
+
+    assert_eq!(
+        subscription_repo
+            .list_subscriptions_by_scope(&dataset_scope)
+            .await
+            .unwrap(),
+        vec![
+            dataset_subscription_1.clone(),
+            dataset_subscription_2.clone()
+        ]
+    );
+    assert_eq!(
+        subscription_repo
+            .list_subscriptions_by_scope(&account_scope)
+            .await
+            .unwrap(),
+        vec![account_subscription.clone()]
+    );
+
+    let mut all_subscriptions = vec![
+        dataset_subscription_1,
+        dataset_subscription_2,
+        account_subscription,
+    ];
+    all_subscriptions.sort_by(|a, b| a.created_at.cmp(&b.created_at).then(a.id.cmp(&b.id)));
+    assert_eq!(
+        subscription_repo.list_all_subscriptions().await.unwrap(),
+        all_subscriptions
+    );
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+pub async fn test_update_subscription(catalog: &Catalog) {
+    let subscription_repo = catalog
+        .get_one::<dyn WebhookSubscriptionRepository>()
+        .unwrap();
+
+    let mut subscription = new_subscription(
+        WebhookSubscriptionScope::Dataset(DatasetID::new_seeded_ed25519(b"foo")),
+        vec![WebhookEventType::FlowFailed],
+        Utc::now().round_subsecs(6),
+    );
+    subscription_repo
+        .create_subscription(&subscription)
+        .await
+        .unwrap();
+
+    subscription.enabled = false;
+    subscription.label = "Renamed".to_string();
+    subscription.target_url = Url::parse("https://example.com/other-hook").unwrap();
+    subscription.event_types = vec![
+        WebhookEventType::FlowFailed,
+        WebhookEventType::FlowSucceeded,
+    ];
+    subscription.secret = WebhookSubscription::generate_secret();
+    subscription_repo
+        .update_subscription(&subscription)
+        .await
+        .unwrap();
+
+    assert_eq!(
+        subscription_repo
+            .get_subscription(&subscription.id)
+            .await
+            .unwrap(),
+        subscription
+    );
+
+    let unknown_subscription = new_subscription(
+        WebhookSubscriptionScope::Dataset(DatasetID::new_seeded_ed25519(b"foo")),
+        vec![WebhookEventType::FlowFailed],
+        Utc::now().round_subsecs(6),
+    );
+    assert_matches!(
+        subscription_repo
+            .update_subscription(&unknown_subscription)
+            .await,
+        Err(UpdateWebhookSubscriptionError::NotFound(_))
+    );
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+pub async fn test_delete_subscription(catalog: &Catalog) {
+    let subscription_repo = catalog
+        .get_one::<dyn WebhookSubscriptionRepository>()
+        .unwrap();
+
+    let scope = WebhookSubscriptionScope::Dataset(DatasetID::new_seeded_ed25519(b"foo"));
+    let subscription = new_subscription(
+        scope.clone(),
+        vec![WebhookEventType::FlowFailed],
+        Utc::now().round_subsecs(6),
+    );
+    subscription_repo
+        .create_subscription(&subscription)
+        .await
+        .unwrap();
+
+    subscription_repo
+        .delete_subscription(&subscription.id)
+        .await
+        .unwrap();
+
+    assert_matches!(
+        subscription_repo.get_subscription(&subscription.id).await,
+        Err(GetWebhookSubscriptionError::NotFound(_))
+    );
+    assert!(subscription_repo
+        .list_subscriptions_by_scope(&scope)
+        .await
+        .unwrap()
+        .is_empty());
+    assert_matches!(
+        subscription_repo
+            .delete_subscription(&subscription.id)
+            .await,
+        Err(DeleteWebhookSubscriptionError::NotFound(_))
+    );
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+fn new_subscription(
+    scope: WebhookSubscriptionScope,
+    event_types: Vec<WebhookEventType>,
+    created_at: chrono::DateTime<Utc>,
+) -> WebhookSubscription {
+    WebhookSubscription::new(
+        scope,
+        Url::parse("https://example.com/hook").unwrap(),
+        "My hook".to_string(),
+        event_types,
+        WebhookSubscription::generate_secret(),
+        created_at,
+    )
+    .unwrap()
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
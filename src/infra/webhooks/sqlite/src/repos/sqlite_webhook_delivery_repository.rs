@@ -0,0 +1,347 @@
+// Copyright Kamu Data, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+use chrono::{DateTime, Utc};
+use database_common::{DatabasePaginationOpts, TransactionRef, TransactionRefT};
+use dill::{component, interface};
+use internal_error::{InternalError, ResultIntoInternal};
+use uuid::Uuid;
+
+use crate::domain::*;
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+pub struct SqliteWebhookDeliveryRepository {
+    transaction: TransactionRefT<sqlx::Sqlite>,
+}
+
+#[component(pub)]
+#[interface(dyn WebhookDeliveryRepository)]
+impl SqliteWebhookDeliveryRepository {
+    pub fn new(transaction: TransactionRef) -> Self {
+        Self {
+            transaction: transaction.into(),
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl WebhookDeliveryRepository for SqliteWebhookDeliveryRepository {
+    async fn create_delivery(
+        &self,
+        delivery: &WebhookDelivery,
+    ) -> Result<(), CreateWebhookDeliveryError> {
+        let mut tr = self.transaction.lock().await;
+
+        let connection_mut = tr
+            .connection_mut()
+            .await
+            .map_err(CreateWebhookDeliveryError::Internal)?;
+
+        let delivery_id = delivery.id;
+        let subscription_id = delivery.subscription_id;
+        let event_id = delivery.event_id;
+        let event_type = delivery.event_type.as_str();
+        let payload_json = serde_json::to_string(&delivery.payload)
+            .int_err()
+            .map_err(CreateWebhookDeliveryError::Internal)?;
+        let status = delivery.status;
+        let attempts = i32::try_from(delivery.attempts).unwrap();
+        let next_attempt_at = delivery.next_attempt_at;
+        let last_attempt_at = delivery.last_attempt_at;
+        let last_response_code = delivery.last_response_code.map(i32::from);
+        let last_error = &delivery.last_error;
+        let created_at = delivery.created_at;
+
+        sqlx::query!(
+            r#"
+                INSERT INTO webhook_deliveries (id, subscription_id, event_id, event_type, payload, status, attempts,
+                    next_attempt_at, last_attempt_at, last_response_code, last_error, created_at)
+                    VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12)
+                "#,
+            delivery_id,
+            subscription_id,
+            event_id,
+            event_type,
+            payload_json,
+            status,
+            attempts,
+            next_attempt_at,
+            last_attempt_at,
+            last_response_code,
+            last_error,
+            created_at,
+        )
+        .execute(connection_mut)
+        .await
+        .int_err()
+        .map_err(CreateWebhookDeliveryError::Internal)?;
+
+        Ok(())
+    }
+
+    async fn update_delivery(
+        &self,
+        delivery: &WebhookDelivery,
+    ) -> Result<(), UpdateWebhookDeliveryError> {
+        let mut tr = self.transaction.lock().await;
+
+        let connection_mut = tr
+            .connection_mut()
+            .await
+            .map_err(UpdateWebhookDeliveryError::Internal)?;
+
+        let delivery_id = delivery.id;
+        let status = delivery.status;
+        let attempts = i32::try_from(delivery.attempts).unwrap();
+        let next_attempt_at = delivery.next_attempt_at;
+        let last_attempt_at = delivery.last_attempt_at;
+        let last_response_code = delivery.last_response_code.map(i32::from);
+        let last_error = &delivery.last_error;
+
+        let update_result = sqlx::query!(
+            r#"
+                UPDATE webhook_deliveries
+                    SET status = $2, attempts = $3, next_attempt_at = $4, last_attempt_at = $5,
+                        last_response_code = $6, last_error = $7
+                    WHERE id = $1
+                "#,
+            delivery_id,
+            status,
+            attempts,
+            next_attempt_at,
+            last_attempt_at,
+            last_response_code,
+            last_error,
+        )
+        .execute(connection_mut)
+        .await
+        .int_err()
+        .map_err(UpdateWebhookDeliveryError::Internal)?;
+
+        if update_result.rows_affected() == 0 {
+            return Err(UpdateWebhookDeliveryError::NotFound(
+                WebhookDeliveryNotFoundError { delivery_id },
+            ));
+        }
+
+        Ok(())
+    }
+
+    async fn get_delivery(
+        &self,
+        delivery_id: &Uuid,
+    ) -> Result<WebhookDelivery, GetWebhookDeliveryError> {
+        let mut tr = self.transaction.lock().await;
+
+        let connection_mut = tr
+            .connection_mut()
+            .await
+            .map_err(GetWebhookDeliveryError::Internal)?;
+
+        let maybe_delivery_row = sqlx::query_as!(
+            WebhookDeliveryRowModel,
+            r#"
+                SELECT
+                    id as "id: Uuid",
+                    subscription_id as "subscription_id: Uuid",
+                    event_id as "event_id: Uuid",
+                    event_type,
+                    payload,
+                    status as "status: WebhookDeliveryStatus",
+                    attempts as "attempts: i32",
+                    next_attempt_at as "next_attempt_at: _",
+                    last_attempt_at as "last_attempt_at: _",
+                    last_response_code as "last_response_code: i32",
+                    last_error,
+                    created_at as "created_at: _"
+                FROM webhook_deliveries
+                WHERE id = $1
+                "#,
+            delivery_id,
+        )
+        .fetch_optional(connection_mut)
+        .await
+        .int_err()
+        .map_err(GetWebhookDeliveryError::Internal)?;
+
+        let Some(delivery_row) = maybe_delivery_row else {
+            return Err(GetWebhookDeliveryError::NotFound(
+                WebhookDeliveryNotFoundError {
+                    delivery_id: *delivery_id,
+                },
+            ));
+        };
+
+        delivery_row
+            .try_into()
+            .map_err(GetWebhookDeliveryError::Internal)
+    }
+
+    async fn list_due_deliveries(
+        &self,
+        now: DateTime<Utc>,
+        limit: usize,
+    ) -> Result<Vec<WebhookDelivery>, ListWebhookDeliveriesError> {
+        let mut tr = self.transaction.lock().await;
+
+        let connection_mut = tr
+            .connection_mut()
+            .await
+            .map_err(ListWebhookDeliveriesError::Internal)?;
+
+        let limit = i64::try_from(limit).unwrap();
+
+        let delivery_rows = sqlx::query_as!(
+            WebhookDeliveryRowModel,
+            r#"
+                SELECT
+                    id as "id: Uuid",
+                    subscription_id as "subscription_id: Uuid",
+                    event_id as "event_id: Uuid",
+                    event_type,
+                    payload,
+                    status as "status: WebhookDeliveryStatus",
+                    attempts as "attempts: i32",
+                    next_attempt_at as "next_attempt_at: _",
+                    last_attempt_at as "last_attempt_at: _",
+                    last_response_code as "last_response_code: i32",
+                    last_error,
+                    created_at as "created_at: _"
+                FROM webhook_deliveries
+                WHERE status = 'pending' AND next_attempt_at <= $1
+                ORDER BY next_attempt_at, id
+                LIMIT $2
+                "#,
+            now,
+            limit,
+        )
+        .fetch_all(connection_mut)
+        .await
+        .int_err()
+        .map_err(ListWebhookDeliveriesError::Internal)?;
+
+        delivery_rows
+            .into_iter()
+            .map(TryInto::try_into)
+            .collect::<Result<_, InternalError>>()
+            .map_err(ListWebhookDeliveriesError::Internal)
+    }
+
+    async fn get_deliveries_count_by_subscription(
+        &self,
+        subscription_id: &Uuid,
+        maybe_status: Option<WebhookDeliveryStatus>,
+    ) -> Result<usize, ListWebhookDeliveriesError> {
+        let mut tr = self.transaction.lock().await;
+
+        let connection_mut = tr
+            .connection_mut()
+            .await
+            .map_err(ListWebhookDeliveriesError::Internal)?;
+
+        let deliveries_count = sqlx::query_scalar!(
+            r#"
+                SELECT
+                    count(*)
+                FROM webhook_deliveries
+                WHERE subscription_id = $1 AND ($2 IS NULL OR status = $2)
+            "#,
+            subscription_id,
+            maybe_status,
+        )
+        .fetch_one(connection_mut)
+        .await
+        .int_err()
+        .map_err(ListWebhookDeliveriesError::Internal)?;
+
+        Ok(usize::try_from(deliveries_count).unwrap_or(0))
+    }
+
+    async fn get_deliveries_by_subscription(
+        &self,
+        subscription_id: &Uuid,
+        maybe_status: Option<WebhookDeliveryStatus>,
+        pagination: &DatabasePaginationOpts,
+    ) -> Result<Vec<WebhookDelivery>, ListWebhookDeliveriesError> {
+        let mut tr = self.transaction.lock().await;
+
+        let connection_mut = tr
+            .connection_mut()
+            .await
+            .map_err(ListWebhookDeliveriesError::Internal)?;
+
+        let limit = pagination.limit;
+        let offset = pagination.offset;
+
+        let delivery_rows = sqlx::query_as!(
+            WebhookDeliveryRowModel,
+            r#"
+                SELECT
+                    id as "id: Uuid",
+                    subscription_id as "subscription_id: Uuid",
+                    event_id as "event_id: Uuid",
+                    event_type,
+                    payload,
+                    status as "status: WebhookDeliveryStatus",
+                    attempts as "attempts: i32",
+                    next_attempt_at as "next_attempt_at: _",
+                    last_attempt_at as "last_attempt_at: _",
+                    last_response_code as "last_response_code: i32",
+                    last_error,
+                    created_at as "created_at: _"
+                FROM webhook_deliveries
+                WHERE subscription_id = $1 AND ($2 IS NULL OR status = $2)
+                ORDER BY created_at DESC, id
+                LIMIT $3 OFFSET $4
+                "#,
+            subscription_id,
+            maybe_status,
+            limit,
+            offset,
+        )
+        .fetch_all(connection_mut)
+        .await
+        .int_err()
+        .map_err(ListWebhookDeliveriesError::Internal)?;
+
+        delivery_rows
+            .into_iter()
+            .map(TryInto::try_into)
+            .collect::<Result<_, InternalError>>()
+            .map_err(ListWebhookDeliveriesError::Internal)
+    }
+
+    async fn delete_deliveries_by_subscription(
+        &self,
+        subscription_id: &Uuid,
+    ) -> Result<(), DeleteWebhookDeliveriesError> {
+        let mut tr = self.transaction.lock().await;
+
+        let connection_mut = tr
+            .connection_mut()
+            .await
+            .map_err(DeleteWebhookDeliveriesError::Internal)?;
+
+        sqlx::query!(
+            r#"
+                DELETE FROM webhook_deliveries WHERE subscription_id = $1
+                "#,
+            subscription_id,
+        )
+        .execute(connection_mut)
+        .await
+        .int_err()
+        .map_err(DeleteWebhookDeliveriesError::Internal)?;
+
+        Ok(())
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
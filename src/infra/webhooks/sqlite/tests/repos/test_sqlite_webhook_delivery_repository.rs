@@ -0,0 +1,61 @@
+// Copyright Kamu Data, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+use database_common::SqliteTransactionManager;
+use database_common_macros::database_transactional_test;
+use dill::{Catalog, CatalogBuilder};
+use kamu_webhooks_repo_tests::webhook_delivery_repo;
+use kamu_webhooks_sqlite::SqliteWebhookDeliveryRepository;
+use sqlx::SqlitePool;
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+database_transactional_test!(
+    storage = sqlite,
+    fixture = webhook_delivery_repo::test_create_and_update_delivery,
+    harness = SqliteWebhookDeliveryRepositoryHarness
+);
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+database_transactional_test!(
+    storage = sqlite,
+    fixture = webhook_delivery_repo::test_list_due_deliveries,
+    harness = SqliteWebhookDeliveryRepositoryHarness
+);
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+database_transactional_test!(
+    storage = sqlite,
+    fixture = webhook_delivery_repo::test_deliveries_by_subscription,
+    harness = SqliteWebhookDeliveryRepositoryHarness
+);
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+struct SqliteWebhookDeliveryRepositoryHarness {
+    catalog: Catalog,
+}
+
+impl SqliteWebhookDeliveryRepositoryHarness {
+    pub fn new(sqlite_pool: SqlitePool) -> Self {
+        // Initialize catalog with predefined SQLite pool
+        let mut catalog_builder = CatalogBuilder::new();
+        catalog_builder.add_value(sqlite_pool);
+        catalog_builder.add::<SqliteTransactionManager>();
+        catalog_builder.add::<SqliteWebhookDeliveryRepository>();
+
+        Self {
+            catalog: catalog_builder.build(),
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -27,6 +27,10 @@ pub struct RunArgs {
     pub image: String,
     pub init: bool,
     pub interactive: bool,
+    /// Memory limit in the runtime's format, e.g. "512m" or "2g"
+    pub memory: Option<String>,
+    /// Number of CPUs the container can use, e.g. "1.5"
+    pub cpus: Option<String>,
     pub network: Option<String>,
     pub remove: bool,
     pub tty: bool,
@@ -78,6 +82,8 @@ impl Default for RunArgs {
             image: String::new(),
             init: false,
             interactive: false,
+            memory: None,
+            cpus: None,
             network: None,
             remove: true,
             tty: false,
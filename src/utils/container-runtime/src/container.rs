@@ -198,6 +198,16 @@ impl ContainerRunCommand {
         self
     }
 
+    pub fn memory(mut self, v: impl Into<String>) -> Self {
+        self.args.memory = Some(v.into());
+        self
+    }
+
+    pub fn cpus(mut self, v: impl Into<String>) -> Self {
+        self.args.cpus = Some(v.into());
+        self
+    }
+
     pub fn network(mut self, v: impl Into<String>) -> Self {
         self.args.network = Some(v.into());
         self
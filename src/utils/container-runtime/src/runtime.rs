@@ -149,6 +149,8 @@ impl ContainerRuntime {
         args.container_name.map(|v| cmd.arg(format!("--name={v}")));
         args.hostname.map(|v| cmd.arg(format!("--hostname={v}")));
         args.network.map(|v| cmd.arg(format!("--network={v}")));
+        args.memory.map(|v| cmd.arg(format!("--memory={v}")));
+        args.cpus.map(|v| cmd.arg(format!("--cpus={v}")));
         if args.expose_all_ports {
             cmd.arg("-P");
         }
@@ -0,0 +1,25 @@
+// Copyright Kamu Data, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+use crate::OutboxMessageID;
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Progress of a durable consumer relative to its producer
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OutboxConsumerLag {
+    pub producer_name: String,
+    pub consumer_name: String,
+    pub last_consumed_message_id: OutboxMessageID,
+    pub latest_produced_message_id: OutboxMessageID,
+    /// Number of produced messages that the consumer did not process yet
+    pub unconsumed_messages: usize,
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
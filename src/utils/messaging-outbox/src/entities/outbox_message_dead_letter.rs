@@ -0,0 +1,44 @@
+// Copyright Kamu Data, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+use chrono::{DateTime, Utc};
+
+use crate::OutboxMessageID;
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// A message that a durable consumer failed to process within the allowed
+/// number of attempts. The consumer's boundary is moved past such message, so
+/// it no longer blocks the consumption of the following ones.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OutboxMessageDeadLetter {
+    pub producer_name: String,
+    pub consumer_name: String,
+    pub message_id: OutboxMessageID,
+    pub content_json: serde_json::Value,
+    pub attempts: u32,
+    pub last_error: String,
+    pub dead_lettered_at: DateTime<Utc>,
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Failed attempts of a durable consumer to process a message, which is not
+/// dead-lettered yet. The consumer does not receive the following messages of
+/// the producer until the next attempt succeeds or the message is parked.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OutboxConsumerRetryState {
+    pub producer_name: String,
+    pub consumer_name: String,
+    pub message_id: OutboxMessageID,
+    pub attempts: u32,
+    pub next_attempt_at: DateTime<Utc>,
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -0,0 +1,101 @@
+// Copyright Kamu Data, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+use database_common::DatabasePaginationOpts;
+use internal_error::InternalError;
+use thiserror::Error;
+
+use crate::{OutboxConsumerRetryState, OutboxMessageDeadLetter, OutboxMessageID};
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[async_trait::async_trait]
+pub trait OutboxMessageDeadLetterRepository: Send + Sync {
+    /// Parks a message, or updates the record if the message is already parked
+    /// for this consumer
+    async fn save_dead_letter(
+        &self,
+        dead_letter: &OutboxMessageDeadLetter,
+    ) -> Result<(), InternalError>;
+
+    async fn get_dead_letter(
+        &self,
+        producer_name: &str,
+        consumer_name: &str,
+        message_id: OutboxMessageID,
+    ) -> Result<OutboxMessageDeadLetter, GetDeadLetterError>;
+
+    /// Lists parked messages ordered by message ID
+    async fn list_dead_letters(
+        &self,
+        pagination: &DatabasePaginationOpts,
+    ) -> Result<Vec<OutboxMessageDeadLetter>, InternalError>;
+
+    async fn count_dead_letters(&self) -> Result<usize, InternalError>;
+
+    async fn delete_dead_letter(
+        &self,
+        producer_name: &str,
+        consumer_name: &str,
+        message_id: OutboxMessageID,
+    ) -> Result<(), DeleteDeadLetterError>;
+
+    /// Lists consumers of the producer that are waiting for another attempt to
+    /// process a message
+    async fn list_retry_states(
+        &self,
+        producer_name: &str,
+    ) -> Result<Vec<OutboxConsumerRetryState>, InternalError>;
+
+    /// Records a failed attempt, replacing the previous state of the consumer
+    async fn save_retry_state(
+        &self,
+        retry_state: &OutboxConsumerRetryState,
+    ) -> Result<(), InternalError>;
+
+    /// Forgets failed attempts of the consumer, if there were any
+    async fn delete_retry_state(
+        &self,
+        producer_name: &str,
+        consumer_name: &str,
+    ) -> Result<(), InternalError>;
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[derive(Error, Debug)]
+pub enum GetDeadLetterError {
+    #[error(transparent)]
+    NotFound(DeadLetterNotFoundError),
+
+    #[error(transparent)]
+    Internal(InternalError),
+}
+
+#[derive(Error, Debug)]
+pub enum DeleteDeadLetterError {
+    #[error(transparent)]
+    NotFound(DeadLetterNotFoundError),
+
+    #[error(transparent)]
+    Internal(InternalError),
+}
+
+#[derive(Error, Debug)]
+#[error(
+    "Message {message_id} of producer '{producer_name}' is not dead-lettered for consumer \
+     '{consumer_name}'"
+)]
+pub struct DeadLetterNotFoundError {
+    pub producer_name: String,
+    pub consumer_name: String,
+    pub message_id: OutboxMessageID,
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
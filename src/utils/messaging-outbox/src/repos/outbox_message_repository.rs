@@ -27,6 +27,12 @@ pub trait OutboxMessageRepository: Send + Sync {
     async fn get_latest_message_ids_by_producer(
         &self,
     ) -> Result<Vec<(String, OutboxMessageID)>, InternalError>;
+
+    async fn count_producer_messages_above(
+        &self,
+        producer_name: &str,
+        above_id: OutboxMessageID,
+    ) -> Result<usize, InternalError>;
 }
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
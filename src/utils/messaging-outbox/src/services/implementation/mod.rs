@@ -7,10 +7,12 @@
 // the Business Source License, use of this software will be governed
 // by the Apache License, Version 2.0.
 
+mod outbox_admin_service_impl;
 mod outbox_dispatching_impl;
 mod outbox_immediate_impl;
 mod outbox_transactional_impl;
 
+pub use outbox_admin_service_impl::*;
 pub use outbox_dispatching_impl::*;
 pub use outbox_immediate_impl::*;
 pub use outbox_transactional_impl::*;
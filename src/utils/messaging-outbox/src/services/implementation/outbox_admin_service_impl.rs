@@ -0,0 +1,206 @@
+// Copyright Kamu Data, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use database_common::{DatabasePaginationOpts, DatabaseTransactionRunner};
+use dill::{component, interface, Catalog};
+use internal_error::{InternalError, ResultIntoInternal};
+
+use crate::*;
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+pub struct OutboxAdminServiceImpl {
+    catalog: Catalog,
+    message_repository: Arc<dyn OutboxMessageRepository>,
+    consumption_repository: Arc<dyn OutboxMessageConsumptionRepository>,
+    dead_letter_repository: Arc<dyn OutboxMessageDeadLetterRepository>,
+    message_dispatchers: Vec<Arc<dyn MessageDispatcher>>,
+}
+
+#[component(pub)]
+#[interface(dyn OutboxAdminService)]
+impl OutboxAdminServiceImpl {
+    pub fn new(
+        catalog: Catalog,
+        message_repository: Arc<dyn OutboxMessageRepository>,
+        consumption_repository: Arc<dyn OutboxMessageConsumptionRepository>,
+        dead_letter_repository: Arc<dyn OutboxMessageDeadLetterRepository>,
+        message_dispatchers: Vec<Arc<dyn MessageDispatcher>>,
+    ) -> Self {
+        Self {
+            catalog,
+            message_repository,
+            consumption_repository,
+            dead_letter_repository,
+            message_dispatchers,
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl OutboxAdminService for OutboxAdminServiceImpl {
+    async fn get_consumer_lags(&self) -> Result<Vec<OutboxConsumerLag>, InternalError> {
+        let latest_message_ids_by_producer = self
+            .message_repository
+            .get_latest_message_ids_by_producer()
+            .await?
+            .into_iter()
+            .collect::<HashMap<_, _>>();
+
+        use futures::TryStreamExt;
+        let boundaries: Vec<_> = self
+            .consumption_repository
+            .list_consumption_boundaries()
+            .await?
+            .try_collect()
+            .await?;
+
+        let mut lags = Vec::with_capacity(boundaries.len());
+        for boundary in boundaries {
+            let latest_produced_message_id = latest_message_ids_by_producer
+                .get(&boundary.producer_name)
+                .copied()
+                .unwrap_or_else(|| OutboxMessageID::new(0));
+
+            let unconsumed_messages =
+                if boundary.last_consumed_message_id < latest_produced_message_id {
+                    self.message_repository
+                        .count_producer_messages_above(
+                            &boundary.producer_name,
+                            boundary.last_consumed_message_id,
+                        )
+                        .await?
+                } else {
+                    0
+                };
+
+            lags.push(OutboxConsumerLag {
+                producer_name: boundary.producer_name,
+                consumer_name: boundary.consumer_name,
+                last_consumed_message_id: boundary.last_consumed_message_id,
+                latest_produced_message_id,
+                unconsumed_messages,
+            });
+        }
+
+        lags.sort_by(|a, b| {
+            (&a.producer_name, &a.consumer_name).cmp(&(&b.producer_name, &b.consumer_name))
+        });
+
+        Ok(lags)
+    }
+
+    async fn list_dead_letters(
+        &self,
+        pagination: &DatabasePaginationOpts,
+    ) -> Result<OutboxMessageDeadLettersListing, InternalError> {
+        let list = self
+            .dead_letter_repository
+            .list_dead_letters(pagination)
+            .await?;
+        let total_count = self.dead_letter_repository.count_dead_letters().await?;
+
+        Ok(OutboxMessageDeadLettersListing { list, total_count })
+    }
+
+    #[tracing::instrument(level = "info", skip_all, fields(producer_name, consumer_name, %message_id))]
+    async fn replay_dead_letter(
+        &self,
+        producer_name: &str,
+        consumer_name: &str,
+        message_id: OutboxMessageID,
+    ) -> Result<(), ReplayDeadLetterError> {
+        let mut dead_letter = self
+            .dead_letter_repository
+            .get_dead_letter(producer_name, consumer_name, message_id)
+            .await
+            .map_err(|e| match e {
+                GetDeadLetterError::NotFound(e) => ReplayDeadLetterError::NotFound(e),
+                GetDeadLetterError::Internal(e) => ReplayDeadLetterError::Internal(e),
+            })?;
+
+        let dispatcher = self
+            .message_dispatchers
+            .iter()
+            .find(|dispatcher| dispatcher.get_producer_name() == producer_name)
+            .ok_or_else(|| {
+                ReplayDeadLetterError::Internal(InternalError::new(format!(
+                    "No dispatcher for producer '{producer_name}'"
+                )))
+            })?;
+
+        let content_json = dead_letter.content_json.to_string();
+
+        // The consumer runs in a transaction of its own, so that whatever it did
+        // before failing is rolled back, while the failure is still recorded
+        let replay_result = DatabaseTransactionRunner::new(self.catalog.clone())
+            .transactional(|transaction_catalog| async move {
+                dispatcher
+                    .dispatch_message(
+                        &transaction_catalog,
+                        ConsumerFilter::SelectedConsumer(consumer_name),
+                        &content_json,
+                    )
+                    .await
+                    .map_err(|e| {
+                        tracing::warn!(error = ?e, error_msg = %e, "Replayed message failed again");
+
+                        ReplayDeadLetterError::ConsumerFailed(DeadLetterConsumerFailedError {
+                            consumer_name: consumer_name.to_string(),
+                            message_id,
+                            reason: e.reason(),
+                        })
+                    })?;
+
+                let dead_letter_repository = transaction_catalog
+                    .get_one::<dyn OutboxMessageDeadLetterRepository>()
+                    .int_err()?;
+                dead_letter_repository
+                    .delete_dead_letter(producer_name, consumer_name, message_id)
+                    .await
+                    .map_err(|e| match e {
+                        DeleteDeadLetterError::NotFound(e) => ReplayDeadLetterError::NotFound(e),
+                        DeleteDeadLetterError::Internal(e) => ReplayDeadLetterError::Internal(e),
+                    })
+            })
+            .await;
+
+        if let Err(ReplayDeadLetterError::ConsumerFailed(e)) = &replay_result {
+            dead_letter.attempts += 1;
+            dead_letter.last_error.clone_from(&e.reason);
+            self.dead_letter_repository
+                .save_dead_letter(&dead_letter)
+                .await
+                .map_err(ReplayDeadLetterError::Internal)?;
+        }
+
+        replay_result
+    }
+
+    #[tracing::instrument(level = "info", skip_all, fields(producer_name, consumer_name, %message_id))]
+    async fn skip_dead_letter(
+        &self,
+        producer_name: &str,
+        consumer_name: &str,
+        message_id: OutboxMessageID,
+    ) -> Result<(), SkipDeadLetterError> {
+        self.dead_letter_repository
+            .delete_dead_letter(producer_name, consumer_name, message_id)
+            .await
+            .map_err(|e| match e {
+                DeleteDeadLetterError::NotFound(e) => SkipDeadLetterError::NotFound(e),
+                DeleteDeadLetterError::Internal(e) => SkipDeadLetterError::Internal(e),
+            })
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
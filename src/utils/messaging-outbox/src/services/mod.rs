@@ -11,11 +11,15 @@ mod implementation;
 mod testing;
 
 mod outbox;
+mod outbox_admin_service;
 mod outbox_config;
+mod outbox_metrics;
 mod outbox_transactional_processor;
 
 pub use implementation::*;
 pub use outbox::*;
+pub use outbox_admin_service::*;
 pub use outbox_config::*;
+pub use outbox_metrics::*;
 pub use outbox_transactional_processor::*;
 pub use testing::*;
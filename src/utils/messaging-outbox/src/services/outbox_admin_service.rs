@@ -0,0 +1,87 @@
+// Copyright Kamu Data, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+use database_common::DatabasePaginationOpts;
+use internal_error::InternalError;
+use thiserror::Error;
+
+use crate::{DeadLetterNotFoundError, OutboxConsumerLag, OutboxMessageDeadLetter, OutboxMessageID};
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Inspection of durable message consumption and manual handling of messages
+/// that consumers failed to process
+#[async_trait::async_trait]
+pub trait OutboxAdminService: Send + Sync {
+    /// Reports how far each durable consumer is behind its producer
+    async fn get_consumer_lags(&self) -> Result<Vec<OutboxConsumerLag>, InternalError>;
+
+    async fn list_dead_letters(
+        &self,
+        pagination: &DatabasePaginationOpts,
+    ) -> Result<OutboxMessageDeadLettersListing, InternalError>;
+
+    /// Delivers a dead-lettered message to its consumer once again and
+    /// forgets it on success. The delivery runs in a separate transaction,
+    /// which is rolled back if the consumer fails.
+    async fn replay_dead_letter(
+        &self,
+        producer_name: &str,
+        consumer_name: &str,
+        message_id: OutboxMessageID,
+    ) -> Result<(), ReplayDeadLetterError>;
+
+    /// Forgets a dead-lettered message without delivering it
+    async fn skip_dead_letter(
+        &self,
+        producer_name: &str,
+        consumer_name: &str,
+        message_id: OutboxMessageID,
+    ) -> Result<(), SkipDeadLetterError>;
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+pub struct OutboxMessageDeadLettersListing {
+    pub list: Vec<OutboxMessageDeadLetter>,
+    pub total_count: usize,
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[derive(Error, Debug)]
+pub enum ReplayDeadLetterError {
+    #[error(transparent)]
+    NotFound(DeadLetterNotFoundError),
+
+    #[error(transparent)]
+    ConsumerFailed(DeadLetterConsumerFailedError),
+
+    #[error(transparent)]
+    Internal(#[from] InternalError),
+}
+
+#[derive(Error, Debug)]
+#[error("Consumer '{consumer_name}' failed to process message {message_id} again: {reason}")]
+pub struct DeadLetterConsumerFailedError {
+    pub consumer_name: String,
+    pub message_id: OutboxMessageID,
+    pub reason: String,
+}
+
+#[derive(Error, Debug)]
+pub enum SkipDeadLetterError {
+    #[error(transparent)]
+    NotFound(DeadLetterNotFoundError),
+
+    #[error(transparent)]
+    Internal(InternalError),
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
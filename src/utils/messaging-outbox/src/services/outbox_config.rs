@@ -18,6 +18,9 @@ pub struct OutboxConfig {
     pub awaiting_step: Duration,
     /// Defines maximum number of messages attempted to read in 1 step
     pub batch_size: i64,
+    /// Defines how durable consumers are retried when they fail to process a
+    /// message
+    pub retry_policy: OutboxRetryPolicy,
 }
 
 impl OutboxConfig {
@@ -25,8 +28,14 @@ impl OutboxConfig {
         Self {
             awaiting_step,
             batch_size,
+            retry_policy: OutboxRetryPolicy::default(),
         }
     }
+
+    pub fn with_retry_policy(mut self, retry_policy: OutboxRetryPolicy) -> Self {
+        self.retry_policy = retry_policy;
+        self
+    }
 }
 
 impl Default for OutboxConfig {
@@ -34,6 +43,39 @@ impl Default for OutboxConfig {
         Self {
             awaiting_step: Duration::seconds(1),
             batch_size: 20,
+            retry_policy: OutboxRetryPolicy::default(),
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OutboxRetryPolicy {
+    /// Number of attempts to process a message, after which it is parked in
+    /// the dead-letter storage
+    pub max_attempts: u32,
+    pub min_backoff: Duration,
+    pub max_backoff: Duration,
+}
+
+impl OutboxRetryPolicy {
+    /// Exponential backoff: delay doubles after every failed attempt
+    pub fn backoff(&self, failed_attempts: u32) -> Duration {
+        let exponent = failed_attempts.saturating_sub(1).min(30);
+        self.min_backoff
+            .checked_mul(1 << exponent)
+            .unwrap_or(self.max_backoff)
+            .min(self.max_backoff)
+    }
+}
+
+impl Default for OutboxRetryPolicy {
+    fn default() -> Self {
+        Self {
+            max_attempts: 5,
+            min_backoff: Duration::seconds(1),
+            max_backoff: Duration::minutes(5),
         }
     }
 }
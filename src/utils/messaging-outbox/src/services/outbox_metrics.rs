@@ -0,0 +1,43 @@
+// Copyright Kamu Data, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+use dill::*;
+use observability::*;
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+pub struct OutboxMetrics {
+    pub consumer_lag_messages: GaugeVec,
+}
+
+#[component(pub)]
+#[interface(dyn MetricsProvider)]
+#[scope(Singleton)]
+impl OutboxMetrics {
+    pub fn new() -> Self {
+        Self {
+            consumer_lag_messages: GaugeVec::new(
+                Opts::new(
+                    "kamu_outbox_consumer_lag_messages",
+                    "Number of produced messages that a durable consumer did not process yet",
+                ),
+                &["producer", "consumer"],
+            )
+            .unwrap(),
+        }
+    }
+}
+
+impl MetricsProvider for OutboxMetrics {
+    fn register(&self, registry: &Registry) -> Result<(), prometheus::Error> {
+        registry.register(Box::new(self.consumer_lag_messages.clone()))
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
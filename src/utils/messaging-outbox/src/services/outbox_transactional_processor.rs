@@ -13,6 +13,7 @@ use std::sync::Arc;
 use database_common::DatabaseTransactionRunner;
 use dill::{component, scope, Catalog, Singleton};
 use internal_error::{InternalError, ResultIntoInternal};
+use time_source::SystemTimeSource;
 
 use crate::*;
 
@@ -23,6 +24,7 @@ pub struct OutboxTransactionalProcessor {
     config: Arc<OutboxConfig>,
     routes_static_info: Arc<RoutesStaticInfo>,
     producer_relay_jobs: Vec<ProducerRelayJob>,
+    metrics: Option<Arc<OutboxMetrics>>,
 }
 
 #[component(pub)]
@@ -31,7 +33,9 @@ impl OutboxTransactionalProcessor {
     pub fn new(
         catalog: Catalog,
         config: Arc<OutboxConfig>,
+        time_source: Arc<dyn SystemTimeSource>,
         message_dispatchers_by_producers: Vec<Arc<dyn MessageDispatcher>>,
+        metrics: Option<Arc<OutboxMetrics>>,
     ) -> Self {
         let routes_static_info = Arc::new(Self::make_static_routes_info(
             &catalog,
@@ -43,6 +47,7 @@ impl OutboxTransactionalProcessor {
             producer_relay_jobs.push(ProducerRelayJob::new(
                 catalog.clone(),
                 config.clone(),
+                time_source.clone(),
                 routes_static_info.clone(),
                 producer_name.clone(),
                 consumer_names.clone(),
@@ -54,6 +59,7 @@ impl OutboxTransactionalProcessor {
             config,
             routes_static_info,
             producer_relay_jobs,
+            metrics,
         }
     }
 
@@ -165,6 +171,15 @@ impl OutboxTransactionalProcessor {
             .select_latest_consumption_boundaries_by_producers()
             .await?;
 
+        if let Some(metrics) = &self.metrics {
+            self.update_consumer_lag_metrics(
+                metrics,
+                &latest_message_ids_by_producer,
+                &consumption_boundaries_by_producer,
+            )
+            .await?;
+        }
+
         // Prepare iteration for each producer
         let mut producer_tasks = Vec::new();
         for producer_relay_job in &self.producer_relay_jobs {
@@ -261,6 +276,48 @@ impl OutboxTransactionalProcessor {
 
         Ok(boundaries_by_producer)
     }
+
+    async fn update_consumer_lag_metrics(
+        &self,
+        metrics: &OutboxMetrics,
+        latest_message_ids_by_producer: &HashMap<String, OutboxMessageID>,
+        consumption_boundaries_by_producer: &HashMap<String, HashMap<String, OutboxMessageID>>,
+    ) -> Result<(), InternalError> {
+        DatabaseTransactionRunner::new(self.catalog.clone())
+            .transactional_with(
+                |outbox_message_repository: Arc<dyn OutboxMessageRepository>| async move {
+                    for (producer_name, boundaries) in consumption_boundaries_by_producer {
+                        let latest_produced_message_id = latest_message_ids_by_producer
+                            .get(producer_name)
+                            .copied()
+                            .unwrap_or_else(|| OutboxMessageID::new(0));
+
+                        for (consumer_name, last_consumed_message_id) in boundaries {
+                            // Message IDs are shared by all producers, so the messages
+                            // have to be counted
+                            let lag = if *last_consumed_message_id < latest_produced_message_id {
+                                outbox_message_repository
+                                    .count_producer_messages_above(
+                                        producer_name,
+                                        *last_consumed_message_id,
+                                    )
+                                    .await?
+                            } else {
+                                0
+                            };
+
+                            #[allow(clippy::cast_precision_loss)]
+                            metrics
+                                .consumer_lag_messages
+                                .with_label_values(&[producer_name, consumer_name])
+                                .set(lag as f64);
+                        }
+                    }
+                    Ok(())
+                },
+            )
+            .await
+    }
 }
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -290,6 +347,7 @@ impl RoutesStaticInfo {
 struct ProducerRelayJob {
     catalog: Catalog,
     config: Arc<OutboxConfig>,
+    time_source: Arc<dyn SystemTimeSource>,
     relay_routes_static_info: Arc<RoutesStaticInfo>,
     producer_name: String,
     consumer_names: Vec<String>,
@@ -299,6 +357,7 @@ impl ProducerRelayJob {
     fn new(
         catalog: Catalog,
         config: Arc<OutboxConfig>,
+        time_source: Arc<dyn SystemTimeSource>,
         relay_routes_static_info: Arc<RoutesStaticInfo>,
         producer_name: String,
         consumer_names: Vec<String>,
@@ -306,6 +365,7 @@ impl ProducerRelayJob {
         Self {
             catalog,
             config,
+            time_source,
             relay_routes_static_info,
             producer_name,
             consumer_names,
@@ -340,40 +400,204 @@ impl ProducerRelayJob {
                 )
                 .await?;
 
+            // Consumers that are waiting for a retry must not see the following
+            // messages until the failed one is processed or dead-lettered
+            let mut retry_states = self.load_retry_states().await?;
+            let now = self.time_source.now();
+            let mut blocked_consumers: HashSet<String> = retry_states
+                .values()
+                .filter(|retry_state| retry_state.next_attempt_at > now)
+                .map(|retry_state| retry_state.consumer_name.clone())
+                .collect();
+
             // Feed consumers if they are behind this message
             // We must respect the sequential order of messages,
             // but individual consumers may process each message concurrently
-            for message in unprocessed_messages {
+            for message in &unprocessed_messages {
                 // Prepare consumer invocation tasks
                 let mut consumer_tasks = Vec::new();
                 for consumer_name in &self.consumer_names {
+                    if blocked_consumers.contains(consumer_name) {
+                        continue;
+                    }
                     let boundary_id = consumption_boundaries
                         .get(consumer_name)
                         .copied()
                         .unwrap_or_else(|| OutboxMessageID::new(0));
                     if boundary_id < message.message_id {
-                        consumer_tasks.push((consumer_name.as_str(), &message));
+                        consumer_tasks.push(consumer_name.as_str());
                     }
                 }
 
                 // Consume concurrently
-                use futures::{StreamExt, TryStreamExt};
-                futures::stream::iter(consumer_tasks)
-                    .map(Ok)
-                    .try_for_each_concurrent(
-                        /* limit */ None,
-                        |(consumer_name, message)| async move {
-                            self.invoke_consumer(consumer_name, message).await?;
-                            Ok(())
-                        },
-                    )
-                    .await?;
+                let consumer_results = futures::future::join_all(consumer_tasks.into_iter().map(
+                    |consumer_name| async move {
+                        let res = self.invoke_consumer(consumer_name, message).await;
+                        (consumer_name, res)
+                    },
+                ))
+                .await;
+
+                // Failures of one consumer do not affect the others
+                for (consumer_name, res) in consumer_results {
+                    match res {
+                        Ok(()) => {
+                            if retry_states.remove(consumer_name).is_some() {
+                                self.reset_retry_state(consumer_name).await?;
+                            }
+                        }
+                        Err(e) => {
+                            let is_blocked = self
+                                .handle_consumer_failure(
+                                    consumer_name,
+                                    message,
+                                    &e,
+                                    &mut retry_states,
+                                )
+                                .await?;
+                            if is_blocked {
+                                blocked_consumers.insert(consumer_name.to_string());
+                            }
+                        }
+                    }
+                }
             }
         }
 
         Ok(())
     }
 
+    /// Retry states are stored along with dead letters, so that a restart does
+    /// not reset the attempts of failing consumers
+    async fn load_retry_states(
+        &self,
+    ) -> Result<HashMap<String, OutboxConsumerRetryState>, InternalError> {
+        let retry_states = DatabaseTransactionRunner::new(self.catalog.clone())
+            .transactional_with(
+                |dead_letter_repository: Arc<dyn OutboxMessageDeadLetterRepository>| async move {
+                    dead_letter_repository
+                        .list_retry_states(&self.producer_name)
+                        .await
+                },
+            )
+            .await?;
+
+        Ok(retry_states
+            .into_iter()
+            .map(|retry_state| (retry_state.consumer_name.clone(), retry_state))
+            .collect())
+    }
+
+    async fn reset_retry_state(&self, consumer_name: &str) -> Result<(), InternalError> {
+        DatabaseTransactionRunner::new(self.catalog.clone())
+            .transactional_with(
+                |dead_letter_repository: Arc<dyn OutboxMessageDeadLetterRepository>| async move {
+                    dead_letter_repository
+                        .delete_retry_state(&self.producer_name, consumer_name)
+                        .await
+                },
+            )
+            .await
+    }
+
+    /// Either schedules another attempt to process the message, in which case
+    /// the consumer stays blocked, or parks the message as a dead letter when
+    /// attempts are exhausted
+    #[tracing::instrument(level = "debug", skip_all, fields(consumer_name, message_id = %message.message_id))]
+    async fn handle_consumer_failure(
+        &self,
+        consumer_name: &str,
+        message: &OutboxMessage,
+        error: &InternalError,
+        retry_states: &mut HashMap<String, OutboxConsumerRetryState>,
+    ) -> Result<bool, InternalError> {
+        let retry_policy = &self.config.retry_policy;
+        let now = self.time_source.now();
+
+        let attempts = match retry_states.get(consumer_name) {
+            Some(retry_state) if retry_state.message_id == message.message_id => {
+                retry_state.attempts + 1
+            }
+            _ => 1,
+        };
+
+        if attempts < retry_policy.max_attempts {
+            let retry_state = OutboxConsumerRetryState {
+                producer_name: self.producer_name.clone(),
+                consumer_name: consumer_name.to_string(),
+                message_id: message.message_id,
+                attempts,
+                next_attempt_at: now + retry_policy.backoff(attempts),
+            };
+
+            retry_states.insert(consumer_name.to_string(), retry_state.clone());
+
+            DatabaseTransactionRunner::new(self.catalog.clone())
+                .transactional_with(
+                    |dead_letter_repository: Arc<dyn OutboxMessageDeadLetterRepository>| async move {
+                        dead_letter_repository.save_retry_state(&retry_state).await
+                    },
+                )
+                .await?;
+
+            tracing::warn!(
+                producer_name = %self.producer_name,
+                consumer_name,
+                message_id = %message.message_id,
+                attempts,
+                error = ?error,
+                error_msg = %error,
+                "Consumer failed to process message, will retry",
+            );
+            return Ok(true);
+        }
+
+        tracing::error!(
+            producer_name = %self.producer_name,
+            consumer_name,
+            message_id = %message.message_id,
+            attempts,
+            error = ?error,
+            error_msg = %error,
+            "Consumer failed to process message, moving it to dead letters",
+        );
+
+        let dead_letter = OutboxMessageDeadLetter {
+            producer_name: message.producer_name.clone(),
+            consumer_name: consumer_name.to_string(),
+            message_id: message.message_id,
+            content_json: message.content_json.clone(),
+            attempts,
+            last_error: error.reason(),
+            dead_lettered_at: now,
+        };
+
+        DatabaseTransactionRunner::new(self.catalog.clone())
+            .transactional_with2(
+                |dead_letter_repository: Arc<dyn OutboxMessageDeadLetterRepository>,
+                 consumption_repository: Arc<dyn OutboxMessageConsumptionRepository>| async move {
+                    dead_letter_repository.save_dead_letter(&dead_letter).await?;
+                    dead_letter_repository
+                        .delete_retry_state(&dead_letter.producer_name, &dead_letter.consumer_name)
+                        .await?;
+
+                    // Unblock the following messages
+                    consumption_repository
+                        .update_consumption_boundary(OutboxMessageConsumptionBoundary {
+                            consumer_name: dead_letter.consumer_name,
+                            producer_name: dead_letter.producer_name,
+                            last_consumed_message_id: dead_letter.message_id,
+                        })
+                        .await
+                        .int_err()
+                },
+            )
+            .await?;
+
+        retry_states.remove(consumer_name);
+        Ok(false)
+    }
+
     fn determine_processed_boundary_id(
         &self,
         consumption_boundaries: &HashMap<String, OutboxMessageID>,
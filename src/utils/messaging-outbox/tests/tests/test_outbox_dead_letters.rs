@@ -0,0 +1,489 @@
+// Copyright Kamu Data, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+use std::assert_matches::assert_matches;
+use std::sync::{Arc, Mutex};
+
+use chrono::{DateTime, Duration, TimeZone, Utc};
+use database_common::{DatabasePaginationOpts, NoOpDatabasePlugin};
+use dill::*;
+use internal_error::InternalError;
+use kamu_messaging_outbox_inmem::{
+    InMemoryOutboxMessageConsumptionRepository,
+    InMemoryOutboxMessageDeadLetterRepository,
+    InMemoryOutboxMessageRepository,
+};
+use messaging_outbox::*;
+use serde::{Deserialize, Serialize};
+use time_source::{SystemTimeSource, SystemTimeSourceStub};
+
+use crate::{test_message_consumer, test_message_type};
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+const TEST_PRODUCER_D: &str = "TEST-PRODUCER-D";
+const HEALTHY_CONSUMER: &str = "TestMessageConsumerD";
+const FLAKY_CONSUMER: &str = "TestMessageConsumerFlaky";
+
+test_message_type!(D);
+
+test_message_consumer!(D, D, TEST_PRODUCER_D, Durable);
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[test_log::test(tokio::test)]
+async fn test_failed_message_is_retried_after_backoff() {
+    let harness = DeadLettersHarness::new();
+    harness.flaky_consumer.fail_next(1);
+
+    let message_1 = harness.post_message("foo").await;
+    let message_2 = harness.post_message("bar").await;
+
+    // Flaky consumer fails on the 1st message, healthy consumer is not affected
+    harness.run_iteration().await;
+    assert_eq!(
+        harness.healthy_messages(),
+        [message_1.clone(), message_2.clone()]
+    );
+    assert_eq!(harness.flaky_consumer.get_messages(), []);
+    assert_eq!(harness.flaky_consumer.attempts(), 1);
+    harness.check_consumption_boundaries(2, 0).await;
+
+    // Backoff did not pass yet, no attempts
+    harness.advance_time(Duration::seconds(30));
+    harness.run_iteration().await;
+    assert_eq!(harness.flaky_consumer.attempts(), 1);
+    harness.check_consumption_boundaries(2, 0).await;
+
+    // Backoff passed, messages are processed in order
+    harness.advance_time(Duration::seconds(30));
+    harness.run_iteration().await;
+    assert_eq!(harness.flaky_consumer.attempts(), 3);
+    assert_eq!(
+        harness.flaky_consumer.get_messages(),
+        [message_1, message_2]
+    );
+    harness.check_consumption_boundaries(2, 2).await;
+
+    assert_eq!(harness.list_dead_letters().await, []);
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[test_log::test(tokio::test)]
+async fn test_message_is_dead_lettered_after_max_attempts() {
+    let harness = DeadLettersHarness::new();
+    harness.flaky_consumer.fail_next(3);
+
+    let message_1 = harness.post_message("foo").await;
+    let message_2 = harness.post_message("bar").await;
+
+    // 1st attempt
+    harness.run_iteration().await;
+    harness.check_consumption_boundaries(2, 0).await;
+
+    // 2nd attempt, after 1 minute
+    harness.advance_time(Duration::minutes(1));
+    harness.run_iteration().await;
+    assert_eq!(harness.flaky_consumer.attempts(), 2);
+    harness.check_consumption_boundaries(2, 0).await;
+
+    // Backoff doubles
+    harness.advance_time(Duration::minutes(1));
+    harness.run_iteration().await;
+    assert_eq!(harness.flaky_consumer.attempts(), 2);
+
+    // 3rd attempt is the last one: message is parked, and the next one is
+    // delivered
+    harness.advance_time(Duration::minutes(1));
+    harness.run_iteration().await;
+    assert_eq!(harness.flaky_consumer.attempts(), 4);
+    assert_eq!(harness.flaky_consumer.get_messages(), [message_2.clone()]);
+    harness.check_consumption_boundaries(2, 2).await;
+
+    let dead_letters = harness.list_dead_letters().await;
+    assert_eq!(dead_letters.len(), 1);
+    let dead_letter = &dead_letters[0];
+    assert_eq!(dead_letter.producer_name, TEST_PRODUCER_D);
+    assert_eq!(dead_letter.consumer_name, FLAKY_CONSUMER);
+    assert_eq!(dead_letter.message_id, OutboxMessageID::new(1));
+    assert_eq!(dead_letter.attempts, 3);
+    assert_eq!(
+        dead_letter.last_error,
+        "Internal error: Flaky consumer failure"
+    );
+    assert_eq!(dead_letter.dead_lettered_at, harness.time_source.now());
+    assert_eq!(
+        serde_json::from_value::<TestMessageD>(dead_letter.content_json.clone()).unwrap(),
+        message_1
+    );
+
+    assert_eq!(harness.healthy_messages(), [message_1, message_2]);
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[test_log::test(tokio::test)]
+async fn test_retry_state_survives_restart() {
+    let harness = DeadLettersHarness::new();
+    harness.flaky_consumer.fail_next(3);
+
+    harness.post_message("foo").await;
+
+    // 1st attempt
+    harness.run_iteration().await;
+    assert_eq!(
+        harness.list_retry_states().await,
+        [OutboxConsumerRetryState {
+            producer_name: TEST_PRODUCER_D.to_string(),
+            consumer_name: FLAKY_CONSUMER.to_string(),
+            message_id: OutboxMessageID::new(1),
+            attempts: 1,
+            next_attempt_at: start_time() + Duration::minutes(1),
+        }]
+    );
+
+    // A restarted processor keeps the backoff and counts the attempts further
+    let restarted_processor = harness.make_processor();
+    restarted_processor
+        .run_single_iteration_only()
+        .await
+        .unwrap();
+    assert_eq!(harness.flaky_consumer.attempts(), 1);
+
+    harness.advance_time(Duration::minutes(1));
+    restarted_processor
+        .run_single_iteration_only()
+        .await
+        .unwrap();
+    harness.advance_time(Duration::minutes(2));
+    restarted_processor
+        .run_single_iteration_only()
+        .await
+        .unwrap();
+    assert_eq!(harness.flaky_consumer.attempts(), 3);
+
+    let dead_letters = harness.list_dead_letters().await;
+    assert_eq!(dead_letters.len(), 1);
+    assert_eq!(dead_letters[0].attempts, 3);
+    assert_eq!(harness.list_retry_states().await, []);
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[test_log::test(tokio::test)]
+async fn test_replay_dead_letter() {
+    let harness = DeadLettersHarness::new();
+    harness.flaky_consumer.fail_next(3);
+
+    let message = harness.post_message("foo").await;
+    harness.exhaust_attempts().await;
+    assert_eq!(harness.list_dead_letters().await.len(), 1);
+
+    // Consumer keeps failing
+    harness.flaky_consumer.fail_next(1);
+    let res = harness
+        .admin_service
+        .replay_dead_letter(TEST_PRODUCER_D, FLAKY_CONSUMER, OutboxMessageID::new(1))
+        .await;
+    assert_matches!(
+        res,
+        Err(ReplayDeadLetterError::ConsumerFailed(e))
+            if e.consumer_name == FLAKY_CONSUMER && e.reason == "Internal error: Flaky consumer failure"
+    );
+    let dead_letters = harness.list_dead_letters().await;
+    assert_eq!(dead_letters.len(), 1);
+    assert_eq!(dead_letters[0].attempts, 4);
+
+    // Consumer is fixed
+    let res = harness
+        .admin_service
+        .replay_dead_letter(TEST_PRODUCER_D, FLAKY_CONSUMER, OutboxMessageID::new(1))
+        .await;
+    assert_matches!(res, Ok(()));
+    assert_eq!(harness.flaky_consumer.get_messages(), [message]);
+    assert_eq!(harness.list_dead_letters().await, []);
+
+    // Nothing to replay anymore
+    let res = harness
+        .admin_service
+        .replay_dead_letter(TEST_PRODUCER_D, FLAKY_CONSUMER, OutboxMessageID::new(1))
+        .await;
+    assert_matches!(res, Err(ReplayDeadLetterError::NotFound(_)));
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[test_log::test(tokio::test)]
+async fn test_skip_dead_letter() {
+    let harness = DeadLettersHarness::new();
+    harness.flaky_consumer.fail_next(3);
+
+    harness.post_message("foo").await;
+    harness.exhaust_attempts().await;
+    assert_eq!(harness.list_dead_letters().await.len(), 1);
+
+    let res = harness
+        .admin_service
+        .skip_dead_letter(TEST_PRODUCER_D, FLAKY_CONSUMER, OutboxMessageID::new(1))
+        .await;
+    assert_matches!(res, Ok(()));
+    assert_eq!(harness.flaky_consumer.get_messages(), []);
+    assert_eq!(harness.list_dead_letters().await, []);
+
+    let res = harness
+        .admin_service
+        .skip_dead_letter(TEST_PRODUCER_D, FLAKY_CONSUMER, OutboxMessageID::new(1))
+        .await;
+    assert_matches!(res, Err(SkipDeadLetterError::NotFound(_)));
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[test_log::test(tokio::test)]
+async fn test_consumer_lags() {
+    let harness = DeadLettersHarness::new();
+    harness.flaky_consumer.fail_next(1);
+
+    harness.post_message("foo").await;
+    harness.post_message("bar").await;
+    harness.post_message("baz").await;
+
+    harness.run_iteration().await;
+
+    let lags = harness.admin_service.get_consumer_lags().await.unwrap();
+    assert_eq!(
+        lags,
+        [
+            OutboxConsumerLag {
+                producer_name: TEST_PRODUCER_D.to_string(),
+                consumer_name: HEALTHY_CONSUMER.to_string(),
+                last_consumed_message_id: OutboxMessageID::new(3),
+                latest_produced_message_id: OutboxMessageID::new(3),
+                unconsumed_messages: 0,
+            },
+            OutboxConsumerLag {
+                producer_name: TEST_PRODUCER_D.to_string(),
+                consumer_name: FLAKY_CONSUMER.to_string(),
+                last_consumed_message_id: OutboxMessageID::new(0),
+                latest_produced_message_id: OutboxMessageID::new(3),
+                unconsumed_messages: 3,
+            },
+        ]
+    );
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+struct TestMessageConsumerFlaky {
+    state: Arc<Mutex<FlakyConsumerState>>,
+}
+
+#[derive(Default)]
+struct FlakyConsumerState {
+    failures_left: usize,
+    attempts: usize,
+    captured_messages: Vec<TestMessageD>,
+}
+
+#[component(pub)]
+#[scope(Singleton)]
+#[interface(dyn MessageConsumer)]
+#[interface(dyn MessageConsumerT<TestMessageD>)]
+#[meta(MessageConsumerMeta {
+    consumer_name: FLAKY_CONSUMER,
+    feeding_producers: &[TEST_PRODUCER_D],
+    durability: MessageConsumptionDurability::Durable,
+})]
+impl TestMessageConsumerFlaky {
+    fn new() -> Self {
+        Self {
+            state: Arc::new(Mutex::new(FlakyConsumerState::default())),
+        }
+    }
+
+    fn fail_next(&self, failures: usize) {
+        self.state.lock().unwrap().failures_left = failures;
+    }
+
+    fn attempts(&self) -> usize {
+        self.state.lock().unwrap().attempts
+    }
+
+    fn get_messages(&self) -> Vec<TestMessageD> {
+        self.state.lock().unwrap().captured_messages.clone()
+    }
+}
+
+impl MessageConsumer for TestMessageConsumerFlaky {}
+
+#[async_trait::async_trait]
+impl MessageConsumerT<TestMessageD> for TestMessageConsumerFlaky {
+    async fn consume_message(
+        &self,
+        _: &Catalog,
+        message: &TestMessageD,
+    ) -> Result<(), InternalError> {
+        let mut guard = self.state.lock().unwrap();
+        guard.attempts += 1;
+        if guard.failures_left > 0 {
+            guard.failures_left -= 1;
+            return Err(InternalError::new("Flaky consumer failure"));
+        }
+        guard.captured_messages.push(message.clone());
+        Ok(())
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+struct DeadLettersHarness {
+    catalog: Catalog,
+    outbox_processor: Arc<OutboxTransactionalProcessor>,
+    outbox: Arc<dyn Outbox>,
+    admin_service: Arc<dyn OutboxAdminService>,
+    consumption_repository: Arc<dyn OutboxMessageConsumptionRepository>,
+    flaky_consumer: Arc<TestMessageConsumerFlaky>,
+    time_source: Arc<SystemTimeSourceStub>,
+}
+
+impl DeadLettersHarness {
+    fn new() -> Self {
+        let mut b = CatalogBuilder::new();
+        b.add::<OutboxTransactionalProcessor>();
+        b.add_value(
+            OutboxConfig::default().with_retry_policy(OutboxRetryPolicy {
+                max_attempts: 3,
+                min_backoff: Duration::minutes(1),
+                max_backoff: Duration::minutes(10),
+            }),
+        );
+        b.add::<InMemoryOutboxMessageRepository>();
+        b.add::<InMemoryOutboxMessageConsumptionRepository>();
+        b.add::<InMemoryOutboxMessageDeadLetterRepository>();
+        b.add::<OutboxTransactionalImpl>();
+        b.bind::<dyn Outbox, OutboxTransactionalImpl>();
+        b.add::<OutboxAdminServiceImpl>();
+        b.add_value(SystemTimeSourceStub::new_set(start_time()));
+        b.bind::<dyn SystemTimeSource, SystemTimeSourceStub>();
+
+        b.add::<TestMessageConsumerD>();
+        b.add::<TestMessageConsumerFlaky>();
+
+        register_message_dispatcher::<TestMessageD>(&mut b, TEST_PRODUCER_D);
+
+        NoOpDatabasePlugin::init_database_components(&mut b);
+
+        let catalog = b.build();
+
+        Self {
+            outbox_processor: catalog.get_one().unwrap(),
+            outbox: catalog.get_one().unwrap(),
+            admin_service: catalog.get_one().unwrap(),
+            consumption_repository: catalog.get_one().unwrap(),
+            flaky_consumer: catalog.get_one().unwrap(),
+            time_source: catalog.get_one().unwrap(),
+            catalog,
+        }
+    }
+
+    async fn post_message(&self, body: &str) -> TestMessageD {
+        let message = TestMessageD {
+            body: body.to_string(),
+        };
+        self.outbox
+            .post_message(TEST_PRODUCER_D, message.clone())
+            .await
+            .unwrap();
+        message
+    }
+
+    async fn run_iteration(&self) {
+        self.outbox_processor
+            .run_single_iteration_only()
+            .await
+            .unwrap();
+    }
+
+    /// Runs the processor until the flaky consumer gives up on the 1st message
+    async fn exhaust_attempts(&self) {
+        self.run_iteration().await;
+        self.advance_time(Duration::minutes(1));
+        self.run_iteration().await;
+        self.advance_time(Duration::minutes(2));
+        self.run_iteration().await;
+    }
+
+    fn advance_time(&self, delta: Duration) {
+        self.time_source.set(self.time_source.now() + delta);
+    }
+
+    fn healthy_messages(&self) -> Vec<TestMessageD> {
+        self.catalog
+            .get_one::<TestMessageConsumerD>()
+            .unwrap()
+            .get_messages()
+    }
+
+    /// Creates another processor instance, as if the process was restarted
+    fn make_processor(&self) -> OutboxTransactionalProcessor {
+        OutboxTransactionalProcessor::new(
+            self.catalog.clone(),
+            self.catalog.get_one().unwrap(),
+            self.time_source.clone(),
+            self.catalog.get::<AllOf<dyn MessageDispatcher>>().unwrap(),
+            None,
+        )
+    }
+
+    async fn list_retry_states(&self) -> Vec<OutboxConsumerRetryState> {
+        self.catalog
+            .get_one::<dyn OutboxMessageDeadLetterRepository>()
+            .unwrap()
+            .list_retry_states(TEST_PRODUCER_D)
+            .await
+            .unwrap()
+    }
+
+    async fn list_dead_letters(&self) -> Vec<OutboxMessageDeadLetter> {
+        self.admin_service
+            .list_dead_letters(&DatabasePaginationOpts {
+                limit: 100,
+                offset: 0,
+            })
+            .await
+            .unwrap()
+            .list
+    }
+
+    async fn check_consumption_boundaries(&self, healthy_boundary: i64, flaky_boundary: i64) {
+        for (consumer_name, expected_boundary) in [
+            (HEALTHY_CONSUMER, healthy_boundary),
+            (FLAKY_CONSUMER, flaky_boundary),
+        ] {
+            let boundary = self
+                .consumption_repository
+                .find_consumption_boundary(consumer_name, TEST_PRODUCER_D)
+                .await
+                .unwrap()
+                .unwrap();
+            assert_eq!(
+                boundary.last_consumed_message_id,
+                OutboxMessageID::new(expected_boundary),
+                "Unexpected boundary of {consumer_name}"
+            );
+        }
+    }
+}
+
+fn start_time() -> DateTime<Utc> {
+    Utc.with_ymd_and_hms(2050, 1, 1, 12, 0, 0).unwrap()
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
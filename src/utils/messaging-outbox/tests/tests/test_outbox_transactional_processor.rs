@@ -267,6 +267,64 @@ async fn test_deliver_messages_with_partial_consumption() {
         .await;
 }
 
+#[test_log::test(tokio::test)]
+async fn test_consumer_lag_metrics() {
+    let harness = TransactionalOutboxProcessorHarness::new();
+    let metrics = harness.catalog.get_one::<OutboxMetrics>().unwrap();
+    let lag = |producer: &str, consumer: &str| {
+        metrics
+            .consumer_lag_messages
+            .with_label_values(&[producer, consumer])
+            .get()
+            .to_string()
+    };
+
+    for body in ["foo", "bar"] {
+        harness
+            .outbox
+            .post_message(
+                TEST_PRODUCER_A,
+                TestMessageA {
+                    body: body.to_string(),
+                },
+            )
+            .await
+            .unwrap();
+    }
+    harness
+        .outbox
+        .post_message(
+            TEST_PRODUCER_C,
+            TestMessageC {
+                body: "baz".to_string(),
+            },
+        )
+        .await
+        .unwrap();
+
+    // Lag is measured before the messages are relayed
+    harness
+        .outbox_processor
+        .run_single_iteration_only()
+        .await
+        .unwrap();
+
+    assert_eq!(lag(TEST_PRODUCER_A, "TestMessageConsumerA"), "2");
+    assert_eq!(lag(TEST_PRODUCER_B, "TestMessageConsumerB"), "0");
+    assert_eq!(lag(TEST_PRODUCER_C, "TestMessageConsumerC1"), "1");
+    assert_eq!(lag(TEST_PRODUCER_C, "TestMessageConsumerC2"), "1");
+
+    harness
+        .outbox_processor
+        .run_single_iteration_only()
+        .await
+        .unwrap();
+
+    assert_eq!(lag(TEST_PRODUCER_A, "TestMessageConsumerA"), "0");
+    assert_eq!(lag(TEST_PRODUCER_C, "TestMessageConsumerC1"), "0");
+    assert_eq!(lag(TEST_PRODUCER_C, "TestMessageConsumerC2"), "0");
+}
+
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
 
 struct TransactionalOutboxProcessorHarness {
@@ -286,6 +344,7 @@ impl TransactionalOutboxProcessorHarness {
         b.add::<OutboxTransactionalImpl>();
         b.bind::<dyn Outbox, OutboxTransactionalImpl>();
         b.add::<SystemTimeSourceDefault>();
+        b.add::<OutboxMetrics>();
 
         b.add::<TestMessageConsumerA>();
         b.add::<TestMessageConsumerB>();
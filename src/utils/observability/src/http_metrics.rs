@@ -0,0 +1,126 @@
+// Copyright Kamu Data, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+use std::sync::Arc;
+use std::time::Instant;
+
+use axum::extract::{Extension, MatchedPath, State};
+use axum::middleware::Next;
+use axum::response::{IntoResponse, Response};
+use dill::{component, interface, scope, Catalog, Singleton};
+
+use crate::*;
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+pub struct HttpMetrics {
+    pub request_duration_seconds: HistogramVec,
+    pub graphql_operation_duration_seconds: HistogramVec,
+}
+
+#[component(pub)]
+#[interface(dyn MetricsProvider)]
+#[scope(Singleton)]
+impl HttpMetrics {
+    pub fn new() -> Self {
+        Self {
+            request_duration_seconds: HistogramVec::new(
+                HistogramOpts::new(
+                    "kamu_http_request_duration_seconds",
+                    "Duration of HTTP requests (including GraphQL) by route",
+                )
+                .buckets(DEFAULT_DURATION_BUCKETS.to_vec()),
+                &["method", "route", "status"],
+            )
+            .unwrap(),
+            graphql_operation_duration_seconds: HistogramVec::new(
+                HistogramOpts::new(
+                    "kamu_graphql_operation_duration_seconds",
+                    "Duration of GraphQL operations by operation name and outcome",
+                )
+                .buckets(DEFAULT_DURATION_BUCKETS.to_vec()),
+                &["operation", "outcome"],
+            )
+            .unwrap(),
+        }
+    }
+
+    /// Records the duration of a GraphQL operation, operations without a name
+    /// are accounted as `<anonymous>`
+    pub fn observe_graphql_operation(
+        &self,
+        operation_name: Option<&str>,
+        is_ok: bool,
+        duration: std::time::Duration,
+    ) {
+        self.graphql_operation_duration_seconds
+            .with_label_values(&[
+                operation_name.unwrap_or("<anonymous>"),
+                if is_ok { "success" } else { "error" },
+            ])
+            .observe(duration.as_secs_f64());
+    }
+}
+
+impl MetricsProvider for HttpMetrics {
+    fn register(&self, registry: &Registry) -> Result<(), prometheus::Error> {
+        registry.register(Box::new(self.request_duration_seconds.clone()))?;
+        registry.register(Box::new(self.graphql_operation_duration_seconds.clone()))
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Records the duration of requests by their route template, so it has to be
+/// added via `Router::route_layer` for the matched route to be known.
+///
+/// Note that for WebSocket routes the duration covers only the upgrade
+/// handshake.
+pub async fn http_metrics_middleware<B>(
+    State(metrics): State<Arc<HttpMetrics>>,
+    request: http::Request<B>,
+    next: Next<B>,
+) -> Response {
+    let method = request.method().clone();
+    let route = request
+        .extensions()
+        .get::<MatchedPath>()
+        .map_or_else(|| "<unmatched>".to_string(), |p| p.as_str().to_string());
+
+    let start = Instant::now();
+    let response = next.run(request).await;
+
+    metrics
+        .request_duration_seconds
+        .with_label_values(&[method.as_str(), &route, response.status().as_str()])
+        .observe(start.elapsed().as_secs_f64());
+
+    response
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Exposes metrics of all [`MetricsProvider`]s registered in the catalog
+pub async fn metrics_handler(Extension(catalog): Extension<Catalog>) -> Response {
+    let providers = catalog.get::<dill::AllOf<dyn MetricsProvider>>().unwrap();
+
+    match encode_metrics(&providers) {
+        Ok(body) => (
+            [(http::header::CONTENT_TYPE, prometheus::TEXT_FORMAT)],
+            body,
+        )
+            .into_response(),
+        Err(err) => {
+            tracing::error!(error = ?err, "Failed to encode metrics");
+            http::StatusCode::INTERNAL_SERVER_ERROR.into_response()
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
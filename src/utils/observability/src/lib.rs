@@ -0,0 +1,17 @@
+// Copyright Kamu Data, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+mod http_metrics;
+mod metrics;
+pub mod otlp;
+mod trace_context;
+
+pub use http_metrics::*;
+pub use metrics::*;
+pub use trace_context::*;
@@ -0,0 +1,56 @@
+// Copyright Kamu Data, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+use std::sync::Arc;
+
+use internal_error::{InternalError, ResultIntoInternal};
+pub use prometheus;
+pub use prometheus::{
+    Counter,
+    CounterVec,
+    Gauge,
+    GaugeVec,
+    Histogram,
+    HistogramOpts,
+    HistogramVec,
+    Opts,
+    Registry,
+};
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Buckets suitable for durations of requests and background jobs in seconds
+pub const DEFAULT_DURATION_BUCKETS: &[f64] = &[
+    0.005, 0.01, 0.025, 0.05, 0.1, 0.25, 0.5, 1.0, 2.5, 5.0, 10.0, 30.0, 60.0, 300.0,
+];
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Implemented by components that expose metrics via the `/metrics` endpoint.
+///
+/// Providers are registered in the catalog and all of them are collected when
+/// the metrics are scraped.
+pub trait MetricsProvider: Send + Sync {
+    /// Registers all collectors of the provider in the registry
+    fn register(&self, registry: &Registry) -> Result<(), prometheus::Error>;
+}
+
+/// Renders metrics of all providers in the Prometheus text exposition format
+pub fn encode_metrics(providers: &[Arc<dyn MetricsProvider>]) -> Result<String, InternalError> {
+    let registry = Registry::new();
+    for provider in providers {
+        provider.register(&registry).int_err()?;
+    }
+
+    prometheus::TextEncoder::new()
+        .encode_to_string(&registry.gather())
+        .int_err()
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
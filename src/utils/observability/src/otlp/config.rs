@@ -0,0 +1,163 @@
+// Copyright Kamu Data, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+use std::time::Duration;
+
+use url::Url;
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+pub const ENV_OTEL_SERVICE_NAME: &str = "OTEL_SERVICE_NAME";
+pub const ENV_OTEL_EXPORTER_OTLP_ENDPOINT: &str = "OTEL_EXPORTER_OTLP_ENDPOINT";
+pub const ENV_OTEL_EXPORTER_OTLP_TRACES_ENDPOINT: &str = "OTEL_EXPORTER_OTLP_TRACES_ENDPOINT";
+pub const ENV_OTEL_EXPORTER_OTLP_PROTOCOL: &str = "OTEL_EXPORTER_OTLP_PROTOCOL";
+pub const ENV_OTEL_EXPORTER_OTLP_HEADERS: &str = "OTEL_EXPORTER_OTLP_HEADERS";
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[derive(Debug, Clone)]
+pub struct OtlpConfig {
+    /// Collector endpoint, e.g. `http://localhost:4317` for gRPC or
+    /// `http://localhost:4318` for HTTP
+    pub endpoint: Url,
+    pub protocol: OtlpProtocol,
+    /// Reported as the `service.name` resource attribute
+    pub service_name: String,
+    /// Reported as the `service.version` resource attribute
+    pub service_version: Option<String>,
+    /// Extra headers (gRPC metadata) sent with every export request, e.g. for
+    /// authentication
+    pub headers: Vec<(String, String)>,
+    /// Maximum number of spans sent in one export request
+    pub max_batch_size: usize,
+    /// Maximum time a finished span waits in the batch before it's exported
+    pub batch_timeout: Duration,
+    /// Spans finished while the queue is full are dropped
+    pub max_queue_size: usize,
+    pub export_timeout: Duration,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OtlpProtocol {
+    Grpc,
+    /// Binary protobuf over HTTP
+    HttpProtobuf,
+}
+
+impl OtlpConfig {
+    pub fn new(endpoint: Url, protocol: OtlpProtocol, service_name: impl Into<String>) -> Self {
+        Self {
+            endpoint,
+            protocol,
+            service_name: service_name.into(),
+            service_version: None,
+            headers: Vec::new(),
+            max_batch_size: 512,
+            batch_timeout: Duration::from_secs(5),
+            max_queue_size: 4096,
+            export_timeout: Duration::from_secs(10),
+        }
+    }
+
+    /// Applies standard `OTEL_*` environment variables on top of the
+    /// configured values. Exporting becomes enabled when the endpoint is
+    /// specified in either place.
+    pub fn from_env_or(
+        endpoint: Option<Url>,
+        protocol: Option<OtlpProtocol>,
+        service_name: &str,
+    ) -> Result<Option<Self>, OtlpConfigError> {
+        let env = |name: &str| std::env::var(name).ok().filter(|v| !v.is_empty());
+
+        let protocol = match env(ENV_OTEL_EXPORTER_OTLP_PROTOCOL) {
+            Some(v) => v.parse()?,
+            None => protocol.unwrap_or(OtlpProtocol::Grpc),
+        };
+
+        let endpoint = if let Some(v) = env(ENV_OTEL_EXPORTER_OTLP_TRACES_ENDPOINT) {
+            Some(Self::parse_url(&v)?)
+        } else if let Some(v) = env(ENV_OTEL_EXPORTER_OTLP_ENDPOINT) {
+            // Per specification, the signal-specific path is appended only to the
+            // generic endpoint
+            let url = Self::parse_url(&v)?;
+            Some(match protocol {
+                OtlpProtocol::Grpc => url,
+                OtlpProtocol::HttpProtobuf => Self::with_traces_path(url),
+            })
+        } else {
+            endpoint
+        };
+
+        let Some(endpoint) = endpoint else {
+            return Ok(None);
+        };
+
+        let service_name = env(ENV_OTEL_SERVICE_NAME).unwrap_or_else(|| service_name.to_string());
+
+        let mut cfg = Self::new(endpoint, protocol, service_name);
+
+        if let Some(headers) = env(ENV_OTEL_EXPORTER_OTLP_HEADERS) {
+            cfg.headers = Self::parse_headers(&headers)?;
+        }
+
+        Ok(Some(cfg))
+    }
+
+    fn parse_url(value: &str) -> Result<Url, OtlpConfigError> {
+        Url::parse(value).map_err(|_| OtlpConfigError::InvalidEndpoint(value.to_string()))
+    }
+
+    fn with_traces_path(mut url: Url) -> Url {
+        let path = format!("{}/v1/traces", url.path().trim_end_matches('/'));
+        url.set_path(&path);
+        url
+    }
+
+    /// Parses headers in the `key1=value1,key2=value2` format
+    fn parse_headers(value: &str) -> Result<Vec<(String, String)>, OtlpConfigError> {
+        value
+            .split(',')
+            .filter(|pair| !pair.trim().is_empty())
+            .map(|pair| {
+                let (k, v) = pair
+                    .split_once('=')
+                    .ok_or_else(|| OtlpConfigError::InvalidHeaders(value.to_string()))?;
+                Ok((k.trim().to_string(), v.trim().to_string()))
+            })
+            .collect()
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+impl std::str::FromStr for OtlpProtocol {
+    type Err = OtlpConfigError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "grpc" => Ok(Self::Grpc),
+            "http/protobuf" => Ok(Self::HttpProtobuf),
+            _ => Err(OtlpConfigError::UnsupportedProtocol(s.to_string())),
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[derive(Debug, thiserror::Error)]
+pub enum OtlpConfigError {
+    #[error("Invalid OTLP endpoint: {0}")]
+    InvalidEndpoint(String),
+    #[error("Unsupported OTLP protocol '{0}', expected 'grpc' or 'http/protobuf'")]
+    UnsupportedProtocol(String),
+    #[error("Invalid OTLP headers '{0}', expected 'key1=value1,key2=value2'")]
+    InvalidHeaders(String),
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -0,0 +1,108 @@
+// Copyright Kamu Data, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+use opentelemetry::trace::{TraceError, TracerProvider as _};
+use opentelemetry::KeyValue;
+use opentelemetry_otlp::WithExportConfig;
+use opentelemetry_sdk::trace::{BatchConfigBuilder, BatchSpanProcessor, TracerProvider};
+use opentelemetry_sdk::{runtime, Resource};
+use tonic::metadata::{MetadataKey, MetadataMap, MetadataValue};
+use tracing::Subscriber;
+use tracing_subscriber::registry::LookupSpan;
+
+use super::{OtlpConfig, OtlpProtocol};
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+pub type OtlpLayer<S> =
+    tracing_opentelemetry::OpenTelemetryLayer<S, opentelemetry_sdk::trace::Tracer>;
+
+/// Creates a layer that exports spans to the OpenTelemetry collector in
+/// batches. Has to be called within the Tokio runtime, which runs the export.
+/// Spans that were not exported yet are flushed when the returned guard is
+/// dropped.
+pub fn new_layer<S>(config: OtlpConfig) -> Result<(OtlpLayer<S>, OtlpGuard), TraceError>
+where
+    S: Subscriber + for<'a> LookupSpan<'a>,
+{
+    let exporter = match config.protocol {
+        OtlpProtocol::Grpc => opentelemetry_otlp::new_exporter()
+            .tonic()
+            .with_endpoint(config.endpoint.as_str())
+            .with_timeout(config.export_timeout)
+            .with_metadata(grpc_metadata(&config.headers)?)
+            .build_span_exporter()?,
+        OtlpProtocol::HttpProtobuf => opentelemetry_otlp::new_exporter()
+            .http()
+            .with_endpoint(config.endpoint.as_str())
+            .with_timeout(config.export_timeout)
+            .with_headers(config.headers.iter().cloned().collect())
+            .build_span_exporter()?,
+    };
+
+    let mut resource_attributes = vec![KeyValue::new("service.name", config.service_name)];
+    if let Some(service_version) = config.service_version {
+        resource_attributes.push(KeyValue::new("service.version", service_version));
+    }
+
+    let span_processor = BatchSpanProcessor::builder(exporter, runtime::Tokio)
+        .with_batch_config(
+            BatchConfigBuilder::default()
+                .with_max_export_batch_size(config.max_batch_size)
+                .with_scheduled_delay(config.batch_timeout)
+                .with_max_queue_size(config.max_queue_size)
+                .with_max_export_timeout(config.export_timeout)
+                .build(),
+        )
+        .build();
+
+    // Sampling decision of the remote parent is respected by the default sampler
+    let provider = TracerProvider::builder()
+        .with_config(
+            opentelemetry_sdk::trace::config().with_resource(Resource::new(resource_attributes)),
+        )
+        .with_span_processor(span_processor)
+        .build();
+
+    let layer = tracing_opentelemetry::layer().with_tracer(provider.tracer("tracing"));
+
+    Ok((layer, OtlpGuard { provider }))
+}
+
+fn grpc_metadata(headers: &[(String, String)]) -> Result<MetadataMap, TraceError> {
+    let mut metadata = MetadataMap::new();
+    for (key, value) in headers {
+        let key = MetadataKey::from_bytes(key.as_bytes())
+            .map_err(|_| TraceError::from(format!("Invalid OTLP header name '{key}'")))?;
+        let value = MetadataValue::try_from(value.as_str())
+            .map_err(|_| TraceError::from(format!("Invalid OTLP header value for '{key}'")))?;
+        metadata.insert(key, value);
+    }
+    Ok(metadata)
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Flushes the remaining spans and stops the exporter when dropped
+#[must_use]
+pub struct OtlpGuard {
+    provider: TracerProvider,
+}
+
+impl Drop for OtlpGuard {
+    fn drop(&mut self) {
+        for res in self.provider.force_flush() {
+            if let Err(err) = res {
+                eprintln!("Failed to flush OTLP spans: {err}");
+            }
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -0,0 +1,115 @@
+// Copyright Kamu Data, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+use std::collections::HashMap;
+
+use opentelemetry::propagation::{Extractor, TextMapPropagator};
+use opentelemetry::trace::{SpanContext, SpanId, TraceContextExt, TraceId};
+use opentelemetry_sdk::propagation::TraceContextPropagator;
+use tracing_opentelemetry::OpenTelemetrySpanExt;
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Name of the W3C Trace Context header
+pub const TRACEPARENT_HEADER: &str = "traceparent";
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Identifies a span that can be continued in another task or process, see
+/// [W3C Trace Context](https://www.w3.org/TR/trace-context/)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TraceContext(SpanContext);
+
+impl TraceContext {
+    /// Parses the value of the `traceparent` header, returning `None` if it's
+    /// malformed or refers to invalid (all-zero) identifiers
+    pub fn from_traceparent(value: &str) -> Option<Self> {
+        let carrier = HashMap::from([(TRACEPARENT_HEADER.to_string(), value.trim().to_string())]);
+        Self::extract(&carrier)
+    }
+
+    /// Formats the context as the value of the `traceparent` header
+    pub fn to_traceparent(&self) -> String {
+        let cx = opentelemetry::Context::new().with_remote_span_context(self.0.clone());
+
+        let mut carrier = HashMap::new();
+        TraceContextPropagator::new().inject_context(&cx, &mut carrier);
+        carrier.remove(TRACEPARENT_HEADER).unwrap_or_default()
+    }
+
+    pub fn trace_id(&self) -> TraceId {
+        self.0.trace_id()
+    }
+
+    pub fn span_id(&self) -> SpanId {
+        self.0.span_id()
+    }
+
+    pub fn is_sampled(&self) -> bool {
+        self.0.is_sampled()
+    }
+
+    fn extract(carrier: &dyn Extractor) -> Option<Self> {
+        let cx = TraceContextPropagator::new().extract(carrier);
+        let span_context = cx.span().span_context().clone();
+        span_context.is_valid().then_some(Self(span_context))
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Returns the context of the current span, or `None` if spans are not
+/// exported
+pub fn current_trace_context() -> Option<TraceContext> {
+    let cx = tracing::Span::current().context();
+    let span_context = cx.span().span_context().clone();
+    span_context
+        .is_valid()
+        .then_some(TraceContext(span_context))
+}
+
+/// Makes the span a child of a span from another task or process. Has to be
+/// called before the span is entered.
+pub fn set_remote_parent(span: &tracing::Span, parent: TraceContext) {
+    span.set_parent(opentelemetry::Context::new().with_remote_span_context(parent.0));
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Creates a span for an incoming request, continuing the trace of the caller
+/// if the request carries the `traceparent` header
+pub fn make_http_request_span<B>(request: &http::Request<B>) -> tracing::Span {
+    let span = tracing::info_span!(
+        "http_request",
+        otel.name = %format!("{} {}", request.method(), request.uri().path()),
+        otel.kind = "server",
+        http.method = %request.method(),
+        http.uri = %request.uri(),
+    );
+
+    if let Some(parent) = TraceContext::extract(&HeaderExtractor(request.headers())) {
+        set_remote_parent(&span, parent);
+    }
+
+    span
+}
+
+struct HeaderExtractor<'a>(&'a http::HeaderMap);
+
+impl Extractor for HeaderExtractor<'_> {
+    fn get(&self, key: &str) -> Option<&str> {
+        self.0.get(key).and_then(|v| v.to_str().ok())
+    }
+
+    fn keys(&self) -> Vec<&str> {
+        self.0.keys().map(http::HeaderName::as_str).collect()
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
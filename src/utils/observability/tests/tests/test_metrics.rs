@@ -0,0 +1,103 @@
+// Copyright Kamu Data, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+use std::sync::Arc;
+use std::time::Duration;
+
+use indoc::indoc;
+use observability::*;
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[test]
+fn test_metrics_of_all_providers_are_encoded() {
+    let metrics = TestMetrics::new();
+
+    metrics.counter.with_label_values(&["a"]).inc();
+    metrics
+        .counter
+        .with_label_values(&["b \"quoted\""])
+        .inc_by(2.5);
+    metrics.counter.with_label_values(&["a"]).inc();
+    metrics.gauge.set(7.0);
+
+    let http_metrics = HttpMetrics::new();
+    http_metrics.observe_graphql_operation(Some("Datasets"), true, Duration::from_millis(50));
+    http_metrics.observe_graphql_operation(None, false, Duration::from_millis(50));
+
+    let encoded = encode_metrics(&[
+        Arc::new(metrics) as Arc<dyn MetricsProvider>,
+        Arc::new(http_metrics),
+    ])
+    .unwrap();
+
+    assert!(
+        encoded.contains(indoc!(
+            r#"
+            # HELP test_counter_total Test counter
+            # TYPE test_counter_total counter
+            test_counter_total{name="a"} 2
+            test_counter_total{name="b \"quoted\""} 2.5
+            # HELP test_gauge Test gauge
+            # TYPE test_gauge gauge
+            test_gauge 7
+            "#
+        )),
+        "{encoded}"
+    );
+    assert!(
+        encoded.contains(
+            "kamu_graphql_operation_duration_seconds_count{operation=\"Datasets\",outcome=\"\
+             success\"} 1\n"
+        ),
+        "{encoded}"
+    );
+    assert!(
+        encoded.contains(
+            "kamu_graphql_operation_duration_seconds_count{operation=\"<anonymous>\",outcome=\"\
+             error\"} 1\n"
+        ),
+        "{encoded}"
+    );
+}
+
+#[test]
+fn test_duplicate_metrics_are_rejected() {
+    let encoded = encode_metrics(&[
+        Arc::new(TestMetrics::new()) as Arc<dyn MetricsProvider>,
+        Arc::new(TestMetrics::new()),
+    ]);
+    assert!(encoded.is_err());
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+struct TestMetrics {
+    counter: CounterVec,
+    gauge: Gauge,
+}
+
+impl TestMetrics {
+    fn new() -> Self {
+        Self {
+            counter: CounterVec::new(Opts::new("test_counter_total", "Test counter"), &["name"])
+                .unwrap(),
+            gauge: Gauge::new("test_gauge", "Test gauge").unwrap(),
+        }
+    }
+}
+
+impl MetricsProvider for TestMetrics {
+    fn register(&self, registry: &Registry) -> Result<(), prometheus::Error> {
+        registry.register(Box::new(self.counter.clone()))?;
+        registry.register(Box::new(self.gauge.clone()))
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
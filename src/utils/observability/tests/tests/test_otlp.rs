@@ -0,0 +1,372 @@
+// Copyright Kamu Data, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+use std::process::Stdio;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use container_runtime::ContainerRuntime;
+use observability::otlp::*;
+use observability::*;
+use opentelemetry_proto::tonic::collector::trace::v1::trace_service_server::{
+    TraceService,
+    TraceServiceServer,
+};
+use opentelemetry_proto::tonic::collector::trace::v1::{
+    ExportTraceServiceRequest,
+    ExportTraceServiceResponse,
+};
+use opentelemetry_proto::tonic::common::v1::any_value::Value;
+use opentelemetry_proto::tonic::trace::v1::{span, status, Span};
+use prost::Message;
+use tracing_subscriber::layer::SubscriberExt;
+use url::Url;
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+const REMOTE_TRACEPARENT: &str = "00-0af7651916cd43dd8448eb211c80319c-b7ad6b7169203331-01";
+
+const OTEL_COLLECTOR_IMAGE: &str = "docker.io/otel/opentelemetry-collector:0.103.1";
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[test]
+fn test_traceparent_roundtrip() {
+    let ctx = TraceContext::from_traceparent(REMOTE_TRACEPARENT).unwrap();
+    assert_eq!(
+        ctx.trace_id().to_string(),
+        "0af7651916cd43dd8448eb211c80319c"
+    );
+    assert_eq!(ctx.span_id().to_string(), "b7ad6b7169203331");
+    assert!(ctx.is_sampled());
+    assert_eq!(ctx.to_traceparent(), REMOTE_TRACEPARENT);
+
+    let not_sampled =
+        TraceContext::from_traceparent("00-0af7651916cd43dd8448eb211c80319c-b7ad6b7169203331-00")
+            .unwrap();
+    assert!(!not_sampled.is_sampled());
+}
+
+#[test]
+fn test_traceparent_invalid() {
+    for traceparent in [
+        "",
+        "00-0af7651916cd43dd8448eb211c80319c-b7ad6b7169203331",
+        "00-0af7651916cd43dd8448eb211c80319c-b7ad6b716920333-01",
+        "00-00000000000000000000000000000000-b7ad6b7169203331-01",
+        "00-0af7651916cd43dd8448eb211c80319c-0000000000000000-01",
+        "00-0af7651916cd43dd8448eb211c80319c-b7ad6b7169203331-01-extra",
+        "ff-0af7651916cd43dd8448eb211c80319c-b7ad6b7169203331-01",
+        "00-0af7651916cd43dd8448eb211c80319x-b7ad6b7169203331-01",
+    ] {
+        assert_eq!(
+            TraceContext::from_traceparent(traceparent),
+            None,
+            "{traceparent}"
+        );
+    }
+}
+
+#[test]
+fn test_trace_context_unavailable_without_exporter() {
+    let subscriber = tracing_subscriber::registry();
+
+    tracing::subscriber::with_default(subscriber, || {
+        let span = tracing::info_span!("request");
+        set_remote_parent(
+            &span,
+            TraceContext::from_traceparent(REMOTE_TRACEPARENT).unwrap(),
+        );
+        let _guard = span.enter();
+
+        assert_eq!(current_trace_context(), None);
+    });
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_spans_exported_over_http() {
+    let collector = TestCollector::start_http();
+
+    let child_ctx = emit_test_spans(OtlpConfig::new(
+        collector.endpoint.clone(),
+        OtlpProtocol::HttpProtobuf,
+        "test-service",
+    ))
+    .await;
+
+    collector.assert_test_spans(&child_ctx);
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_spans_exported_over_grpc() {
+    let collector = TestCollector::start_grpc().await;
+
+    let mut config = OtlpConfig::new(
+        collector.endpoint.clone(),
+        OtlpProtocol::Grpc,
+        "test-service",
+    );
+    config.headers = vec![("x-api-key".to_string(), "secret".to_string())];
+
+    let child_ctx = emit_test_spans(config).await;
+
+    collector.assert_test_spans(&child_ctx);
+    assert_eq!(
+        *collector.api_keys.lock().unwrap(),
+        [Some("secret".to_string())]
+    );
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[test_group::group(containerized)]
+#[tokio::test(flavor = "multi_thread")]
+async fn test_spans_exported_to_otel_collector() {
+    let container_runtime = ContainerRuntime::default();
+    container_runtime
+        .ensure_image(OTEL_COLLECTOR_IMAGE, None)
+        .await
+        .unwrap();
+
+    let tempdir = tempfile::tempdir().unwrap();
+    let config_path = tempdir.path().join("config.yaml");
+    std::fs::write(
+        &config_path,
+        indoc::indoc!(
+            r#"
+            receivers:
+              otlp:
+                protocols:
+                  grpc:
+                    endpoint: 0.0.0.0:4317
+            exporters:
+              debug:
+                verbosity: detailed
+            service:
+              pipelines:
+                traces:
+                  receivers: [otlp]
+                  exporters: [debug]
+            "#
+        ),
+    )
+    .unwrap();
+
+    // The debug exporter prints the received spans to the collector log
+    let log_path = tempdir.path().join("collector.log");
+    let log_file = std::fs::File::create(&log_path).unwrap();
+
+    let container = container_runtime
+        .run_attached(OTEL_COLLECTOR_IMAGE)
+        .random_container_name_with_prefix("kamu-test-otel-collector-")
+        .volume((config_path, "/etc/otelcol/config.yaml"))
+        .expose_port(4317)
+        .stdout(Stdio::null())
+        .stderr(log_file)
+        .spawn()
+        .unwrap();
+
+    let host_port = container
+        .wait_for_host_socket(4317, Duration::from_secs(30))
+        .await
+        .unwrap();
+
+    let endpoint = Url::parse(&format!(
+        "http://{}:{host_port}",
+        container_runtime.get_runtime_host_addr()
+    ))
+    .unwrap();
+
+    let child_ctx = emit_test_spans(OtlpConfig::new(
+        endpoint,
+        OtlpProtocol::Grpc,
+        "test-service",
+    ))
+    .await;
+
+    let expected_trace_id = child_ctx.trace_id().to_string();
+    let deadline = tokio::time::Instant::now() + Duration::from_secs(30);
+    loop {
+        let log = std::fs::read_to_string(&log_path).unwrap();
+        if log.contains("renamed") && log.contains(&expected_trace_id) {
+            break;
+        }
+        assert!(
+            tokio::time::Instant::now() < deadline,
+            "Spans were not received by the collector:\n{log}"
+        );
+        tokio::time::sleep(Duration::from_millis(500)).await;
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Emits a server span continuing the remote trace with a child span that
+/// logs an error, then flushes the exporter. Returns the context of the child.
+async fn emit_test_spans(config: OtlpConfig) -> TraceContext {
+    let (layer, guard) = new_layer(config).unwrap();
+    let subscriber = tracing_subscriber::registry().with(layer);
+
+    let child_ctx = tracing::subscriber::with_default(subscriber, || {
+        let request_span = tracing::info_span!("request", otel.kind = "server", answer = 42);
+        set_remote_parent(
+            &request_span,
+            TraceContext::from_traceparent(REMOTE_TRACEPARENT).unwrap(),
+        );
+        let _request_guard = request_span.enter();
+
+        let child_span = tracing::info_span!("child", otel.name = "renamed");
+        let _child_guard = child_span.enter();
+
+        tracing::error!(reason = "test", "Something failed");
+
+        current_trace_context().unwrap()
+    });
+
+    // Flushes the batch
+    tokio::task::spawn_blocking(move || drop(guard))
+        .await
+        .unwrap();
+
+    child_ctx
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[derive(Clone)]
+struct TestCollector {
+    endpoint: Url,
+    requests: Arc<Mutex<Vec<ExportTraceServiceRequest>>>,
+    api_keys: Arc<Mutex<Vec<Option<String>>>>,
+}
+
+impl TestCollector {
+    fn new(endpoint: Url) -> Self {
+        Self {
+            endpoint,
+            requests: Arc::default(),
+            api_keys: Arc::default(),
+        }
+    }
+
+    fn start_http() -> Self {
+        let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+        let addr = listener.local_addr().unwrap();
+
+        let collector = Self::new(Url::parse(&format!("http://{addr}/v1/traces")).unwrap());
+
+        let app = axum::Router::new().route(
+            "/v1/traces",
+            axum::routing::post({
+                let requests = collector.requests.clone();
+                move |body: axum::body::Bytes| async move {
+                    let request = ExportTraceServiceRequest::decode(body).unwrap();
+                    requests.lock().unwrap().push(request);
+                    ExportTraceServiceResponse::default().encode_to_vec()
+                }
+            }),
+        );
+
+        let server = axum::Server::from_tcp(listener)
+            .unwrap()
+            .serve(app.into_make_service());
+        tokio::spawn(server);
+
+        collector
+    }
+
+    async fn start_grpc() -> Self {
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+
+        let collector = Self::new(Url::parse(&format!("http://{addr}")).unwrap());
+
+        let server = tonic::transport::Server::builder()
+            .add_service(TraceServiceServer::new(collector.clone()))
+            .serve_with_incoming(tokio_stream::wrappers::TcpListenerStream::new(listener));
+        tokio::spawn(server);
+
+        collector
+    }
+
+    fn assert_test_spans(&self, child_ctx: &TraceContext) {
+        let remote_parent = TraceContext::from_traceparent(REMOTE_TRACEPARENT).unwrap();
+
+        let requests = self.requests.lock().unwrap();
+        assert_eq!(requests.len(), 1);
+
+        let resource_spans = &requests[0].resource_spans[0];
+        let resource_attributes = &resource_spans.resource.as_ref().unwrap().attributes;
+        assert!(resource_attributes.iter().any(|kv| kv.key == "service.name"
+            && kv.value.as_ref().and_then(|v| v.value.as_ref())
+                == Some(&Value::StringValue("test-service".to_string()))));
+
+        // Spans are exported in the order they are closed
+        let [child, request] = &resource_spans.scope_spans[0].spans[..] else {
+            panic!("Expected two spans");
+        };
+
+        assert_eq!(request.name, "request");
+        assert_eq!(request.kind, i32::from(span::SpanKind::Server));
+        assert_eq!(request.trace_id, remote_parent.trace_id().to_bytes());
+        assert_eq!(request.parent_span_id, remote_parent.span_id().to_bytes());
+        assert_eq!(attribute(request, "answer"), Some(&Value::IntValue(42)));
+
+        assert_eq!(child.name, "renamed");
+        assert_eq!(child.kind, i32::from(span::SpanKind::Internal));
+        assert_eq!(child.trace_id, remote_parent.trace_id().to_bytes());
+        assert_eq!(child.parent_span_id, request.span_id);
+        assert_eq!(child.span_id, child_ctx.span_id().to_bytes());
+        assert_eq!(child_ctx.trace_id(), remote_parent.trace_id());
+        assert!(child.start_time_unix_nano <= child.end_time_unix_nano);
+
+        assert_eq!(child.events.len(), 1);
+        assert_eq!(child.events[0].name, "Something failed");
+        assert!(child.events[0]
+            .attributes
+            .iter()
+            .any(|kv| kv.key == "reason"
+                && kv.value.as_ref().and_then(|v| v.value.as_ref())
+                    == Some(&Value::StringValue("test".to_string()))));
+        assert_eq!(
+            child.status.as_ref().map(|s| s.code),
+            Some(i32::from(status::StatusCode::Error))
+        );
+    }
+}
+
+#[tonic::async_trait]
+impl TraceService for TestCollector {
+    async fn export(
+        &self,
+        request: tonic::Request<ExportTraceServiceRequest>,
+    ) -> Result<tonic::Response<ExportTraceServiceResponse>, tonic::Status> {
+        self.api_keys.lock().unwrap().push(
+            request
+                .metadata()
+                .get("x-api-key")
+                .and_then(|v| v.to_str().ok())
+                .map(ToString::to_string),
+        );
+        self.requests.lock().unwrap().push(request.into_inner());
+        Ok(tonic::Response::new(ExportTraceServiceResponse::default()))
+    }
+}
+
+fn attribute<'a>(span: &'a Span, key: &str) -> Option<&'a Value> {
+    span.attributes
+        .iter()
+        .find(|kv| kv.key == key)
+        .and_then(|kv| kv.value.as_ref())
+        .and_then(|v| v.value.as_ref())
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////